@@ -0,0 +1,793 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::mem::size_of;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{value_size, EvalResult, Interpreter, ITERATIONS_PER_CHECK};
+use crate::object::{IterationKind, IteratorState, ObjectKind, ObjectRef, PropertyKey};
+use crate::scope::Scope;
+use crate::types::{JsString, Value};
+use super::iterator::create_iterator;
+use super::string::MAX_LENGTH as MAX_STRING_LENGTH;
+use super::{argument, define_method, link_constructor, make_native_function, new_array, Intrinsics};
+
+/// Largest length an array can have, 2^32 - 1.
+const MAX_LENGTH: f64 = 4294967295.0;
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let prototype = &intrinsics.array_prototype;
+    let function_prototype = &intrinsics.function_prototype;
+
+    let constructor = make_native_function(function_prototype, "Array", 1, Rc::new(|interpreter, _, args| {
+        match args {
+            [Value::Float(length)] => {
+                if length.fract() != 0.0 || *length < 0.0 || *length > MAX_LENGTH {
+                    return Err(interpreter.throw_error(ErrorKind::RangeError, "Invalid array length"));
+                }
+
+                interpreter.reserve((*length as usize).saturating_mul(size_of::<Value>()))?;
+                interpreter.step_by(*length as usize)?;
+                Ok(interpreter.create_array(vec![Value::None; *length as usize]))
+            }
+            _ => new_array(interpreter, args.to_vec()),
+        }
+    }));
+
+    link_constructor(&constructor, prototype);
+
+    define_method(&constructor, function_prototype, "isArray", 1, |_, _, args| {
+        Ok(Value::Boolean(matches!(argument(args, 0), Value::Object(object) if object.is_array())))
+    });
+
+    define_method(&constructor, function_prototype, "of", 0, |interpreter, _, args| {
+        new_array(interpreter, args.to_vec())
+    });
+
+    define_method(&constructor, function_prototype, "from", 1, array_from);
+
+    define_method(prototype, function_prototype, "push", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "push")?;
+        interpreter.reserve(args.iter().map(|arg| size_of::<Value>() + value_size(arg)).sum())?;
+
+        let dense = array.borrow().dense_elements().is_some();
+
+        if let (true, ObjectKind::Array(elements)) = (dense, &mut array.borrow_mut().kind) {
+            elements.extend_from_slice(args);
+            return Ok(Value::Float(elements.len() as f64));
+        }
+
+        // a sparse array or an array-like object only has the elements past its end written
+        let target = Value::Object(array.clone());
+        let length = length_of(interpreter, &array)?;
+
+        for (i, arg) in args.iter().enumerate() {
+            interpreter.set_property(&target, length + i, arg.clone())?;
+        }
+
+        let length = length + args.len();
+        interpreter.set_property(&target, "length", Value::Float(length as f64))?;
+
+        Ok(Value::Float(length as f64))
+    });
+
+    define_method(prototype, function_prototype, "pop", 0, |interpreter, this, _| {
+        let array = this_object(interpreter, this, "pop")?;
+
+        let dense = array.borrow().dense_elements().is_some();
+
+        if let (true, ObjectKind::Array(elements)) = (dense, &mut array.borrow_mut().kind) {
+            return Ok(elements.pop().unwrap_or(Value::None));
+        }
+
+        let target = Value::Object(array.clone());
+        let length = length_of(interpreter, &array)?;
+
+        let last = match length.checked_sub(1) {
+            Some(index) => {
+                let last = interpreter.get_property(&target, index)?;
+                array.borrow_mut().delete_own_property(&PropertyKey::from(index));
+                interpreter.set_property(&target, "length", Value::Float(index as f64))?;
+                last
+            },
+            None => {
+                interpreter.set_property(&target, "length", Value::Float(0.0))?;
+                Value::None
+            },
+        };
+
+        Ok(last)
+    });
+
+    define_method(prototype, function_prototype, "shift", 0, |interpreter, this, _| {
+        let array = this_object(interpreter, this, "shift")?;
+        let mut elements = elements_of(interpreter, &array)?;
+
+        if elements.is_empty() {
+            return Ok(Value::None);
+        }
+
+        let first = elements.remove(0);
+        write_back(interpreter, &array, elements)?;
+
+        Ok(first)
+    });
+
+    define_method(prototype, function_prototype, "unshift", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "unshift")?;
+        let mut elements = elements_of(interpreter, &array)?;
+
+        elements.splice(0..0, args.iter().cloned());
+        let length = elements.len();
+        write_back(interpreter, &array, elements)?;
+
+        Ok(Value::Float(length as f64))
+    });
+
+    define_method(prototype, function_prototype, "splice", 2, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "splice")?;
+        let mut elements = elements_of(interpreter, &array)?;
+        let length = elements.len();
+
+        let start = relative_index(interpreter, &argument(args, 0), length, 0)?;
+
+        let delete_count = match args.len() {
+            0 => 0,
+            1 => length - start,
+            _ => {
+                let count = interpreter.to_number(&args[1])?;
+                (if count.is_nan() { 0.0 } else { count.trunc() }).clamp(0.0, (length - start) as f64) as usize
+            }
+        };
+
+        let items = args.iter().skip(2).cloned();
+        let removed: Vec<Value> = elements.splice(start..start + delete_count, items).collect();
+        write_back(interpreter, &array, elements)?;
+
+        new_array(interpreter, removed)
+    });
+
+    define_method(prototype, function_prototype, "slice", 2, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "slice")?;
+        let elements = elements_of(interpreter, &array)?;
+        let length = elements.len();
+
+        let start = relative_index(interpreter, &argument(args, 0), length, 0)?;
+        let end = relative_index(interpreter, &argument(args, 1), length, length)?;
+
+        let slice = match start < end {
+            true => elements[start..end].to_vec(),
+            false => Vec::new(),
+        };
+
+        new_array(interpreter, slice)
+    });
+
+    define_method(prototype, function_prototype, "concat", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "concat")?;
+        let mut elements = elements_of(interpreter, &array)?;
+        let mut added = 0;
+
+        // the result is reserved before it's put together, since it can be far bigger than each part
+        for arg in args {
+            added += match arg {
+                Value::Object(object) if object.is_array() => length_of(interpreter, object)?,
+                _ => 1,
+            };
+        }
+
+        interpreter.reserve(elements.len().saturating_add(added).saturating_mul(size_of::<Value>()))?;
+        interpreter.step_by(added)?;
+
+        for arg in args {
+            match arg {
+                Value::Object(object) if object.is_array() => elements.extend(elements_of(interpreter, object)?),
+                _ => elements.push(arg.clone()),
+            }
+        }
+
+        interpreter.reserve_object()?;
+        Ok(interpreter.create_array(elements))
+    });
+
+    define_method(prototype, function_prototype, "join", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "join")?;
+
+        let separator = match argument(args, 0) {
+            Value::None => ",".into(),
+            separator => interpreter.to_js_string(&separator)?,
+        };
+
+        join(interpreter, &array, &separator)
+    });
+
+    define_method(prototype, function_prototype, "toString", 0, |interpreter, this, _| {
+        let array = this_object(interpreter, this, "toString")?;
+        join(interpreter, &array, &",".into())
+    });
+
+    define_method(prototype, function_prototype, "indexOf", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "indexOf")?;
+        let (length, elements) = present_elements(interpreter, &array)?;
+        let start = relative_index(interpreter, &argument(args, 1), length, 0)?;
+        let target = argument(args, 0);
+
+        let found = find_element(interpreter, elements.iter(), |index, element| index >= start && Interpreter::strict_equals(element, &target))?;
+        Ok(Value::Float(found.map_or(-1.0, |index| index as f64)))
+    });
+
+    define_method(prototype, function_prototype, "lastIndexOf", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "lastIndexOf")?;
+        let (length, elements) = present_elements(interpreter, &array)?;
+        let target = argument(args, 0);
+
+        let end = match args.get(1) {
+            Some(from) => match relative_index(interpreter, from, length, 0)? {
+                from if from < length => from + 1,
+                _ => length,
+            },
+            None => length,
+        };
+
+        let found = find_element(interpreter, elements.iter().rev(), |index, element| index < end && Interpreter::strict_equals(element, &target))?;
+        Ok(Value::Float(found.map_or(-1.0, |index| index as f64)))
+    });
+
+    define_method(prototype, function_prototype, "includes", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "includes")?;
+        let (length, elements) = present_elements(interpreter, &array)?;
+        let start = relative_index(interpreter, &argument(args, 1), length, 0)?;
+        let target = argument(args, 0);
+
+        // holes read as undefined
+        let has_holes = elements.len() - elements.partition_point(|(index, _)| *index < start) < length - start;
+        let found = find_element(interpreter, elements.iter(), |index, element| index >= start && same_value_zero(element, &target))?;
+        Ok(Value::Boolean(found.is_some() || (has_holes && target == Value::None)))
+    });
+
+    define_method(prototype, function_prototype, "at", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "at")?;
+        let length = length_of(interpreter, &array)? as f64;
+        let index = interpreter.to_number(&argument(args, 0))?;
+        let index = if index.is_nan() { 0.0 } else { index.trunc() };
+        let index = if index < 0.0 { length + index } else { index };
+
+        match index >= 0.0 && index < length {
+            true => interpreter.get_property(this, index as usize),
+            false => Ok(Value::None),
+        }
+    });
+
+    define_method(prototype, function_prototype, "reverse", 0, |interpreter, this, _| {
+        let array = this_object(interpreter, this, "reverse")?;
+        let mut elements = elements_of(interpreter, &array)?;
+
+        elements.reverse();
+        write_back(interpreter, &array, elements)?;
+
+        Ok(this.clone())
+    });
+
+    define_method(prototype, function_prototype, "fill", 1, |interpreter, this, args| {
+        let array = this_object(interpreter, this, "fill")?;
+        let mut elements = elements_of(interpreter, &array)?;
+        let length = elements.len();
+
+        let start = relative_index(interpreter, &argument(args, 1), length, 0)?;
+        let end = relative_index(interpreter, &argument(args, 2), length, length)?;
+
+        for (i, element) in elements.iter_mut().take(end).skip(start).enumerate() {
+            if i.is_multiple_of(ITERATIONS_PER_CHECK) {
+                interpreter.check_limits()?;
+            }
+
+            *element = argument(args, 0);
+        }
+
+        write_back(interpreter, &array, elements)?;
+        Ok(this.clone())
+    });
+
+    define_method(prototype, function_prototype, "sort", 1, |interpreter, this, args| {
+        let comparator = argument(args, 0);
+
+        if !comparator.is_callable() && comparator != Value::None {
+            let message = "The comparison function must be either a function or undefined";
+            return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let array = this_object(interpreter, this, "sort")?;
+        let elements = elements_of(interpreter, &array)?;
+        let sorted = merge_sort(interpreter, elements, &comparator)?;
+        write_back(interpreter, &array, sorted)?;
+
+        Ok(this.clone())
+    });
+
+    define_method(prototype, function_prototype, "forEach", 1, |interpreter, this, args| {
+        for_each_element(interpreter, this, args, "forEach", true, |_, _, _| Ok(None))?;
+        Ok(Value::None)
+    });
+
+    define_method(prototype, function_prototype, "map", 1, |interpreter, this, args| {
+        let mut mapped = Vec::new();
+
+        for_each_element(interpreter, this, args, "map", true, |index, _, result| {
+            mapped.push((index, result));
+            Ok(None)
+        })?;
+
+        // the result has holes where the array has them
+        let array = this_object(interpreter, this, "map")?;
+        let length = length_of(interpreter, &array)?;
+
+        if mapped.len() == length {
+            return new_array(interpreter, mapped.into_iter().map(|(_, result)| result).collect());
+        }
+
+        let result = new_array(interpreter, Vec::new())?;
+        interpreter.set_property(&result, "length", Value::Float(length as f64))?;
+
+        for (index, value) in mapped {
+            interpreter.set_property(&result, index, value)?;
+        }
+
+        Ok(result)
+    });
+
+    define_method(prototype, function_prototype, "filter", 1, |interpreter, this, args| {
+        let mut kept = Vec::new();
+
+        for_each_element(interpreter, this, args, "filter", true, |_, element, result| {
+            if result.is_truthy() {
+                kept.push(element);
+            }
+
+            Ok(None)
+        })?;
+
+        new_array(interpreter, kept)
+    });
+
+    define_method(prototype, function_prototype, "some", 1, |interpreter, this, args| {
+        let found = for_each_element(interpreter, this, args, "some", true, |_, _, result| {
+            Ok(result.is_truthy().then_some(Value::Boolean(true)))
+        })?;
+
+        Ok(found.unwrap_or(Value::Boolean(false)))
+    });
+
+    define_method(prototype, function_prototype, "every", 1, |interpreter, this, args| {
+        let failed = for_each_element(interpreter, this, args, "every", true, |_, _, result| {
+            Ok((!result.is_truthy()).then_some(Value::Boolean(false)))
+        })?;
+
+        Ok(failed.unwrap_or(Value::Boolean(true)))
+    });
+
+    define_method(prototype, function_prototype, "find", 1, |interpreter, this, args| {
+        let found = for_each_element(interpreter, this, args, "find", false, |_, element, result| {
+            Ok(result.is_truthy().then_some(element))
+        })?;
+
+        Ok(found.unwrap_or(Value::None))
+    });
+
+    define_method(prototype, function_prototype, "findIndex", 1, |interpreter, this, args| {
+        let found = for_each_element(interpreter, this, args, "findIndex", false, |index, _, result| {
+            Ok(result.is_truthy().then_some(Value::Float(index as f64)))
+        })?;
+
+        Ok(found.unwrap_or(Value::Float(-1.0)))
+    });
+
+    define_method(prototype, function_prototype, "reduce", 1, |interpreter, this, args| {
+        reduce(interpreter, this, args, false)
+    });
+
+    define_method(prototype, function_prototype, "reduceRight", 1, |interpreter, this, args| {
+        reduce(interpreter, this, args, true)
+    });
+
+    for (name, kind) in [("keys", IterationKind::Keys), ("values", IterationKind::Values), ("entries", IterationKind::Entries)] {
+        define_method(prototype, function_prototype, name, 0, move |interpreter, this, _| {
+            this_object(interpreter, this, name)?;
+            Ok(create_iterator(interpreter, IteratorState::Array(this.clone(), 0, kind)))
+        });
+    }
+
+    // `values` doubles as the iterator method, the same function object like in the spec
+    let values = prototype.get("values").unwrap_or(Value::None);
+    prototype.set_hidden(intrinsics.symbol_iterator.clone(), values);
+
+    scope.borrow_mut().define("Array".to_string(), Value::Object(constructor));
+}
+
+fn this_object(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<ObjectRef> {
+    match this {
+        Value::Object(object) => Ok(object.clone()),
+        _ => {
+            let message = format!("Array.prototype.{} called on {}", method, this.to_display_string());
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        }
+    }
+}
+
+fn length_of(interpreter: &mut Interpreter, object: &ObjectRef) -> EvalResult<usize> {
+    if let Some(length) = object.borrow().array_length() {
+        return Ok(length);
+    }
+
+    let length = interpreter.get_property(&Value::Object(object.clone()), "length")?;
+    let length = interpreter.to_number(&length)?;
+
+    match length.is_nan() {
+        true => Ok(0),
+        false => Ok(length.clamp(0.0, MAX_LENGTH) as usize),
+    }
+}
+
+/// A snapshot of the elements of an array or array-like object. Going through them counts as a
+/// step for each against the limits, which covers the methods that work on all of them.
+fn elements_of(interpreter: &mut Interpreter, object: &ObjectRef) -> EvalResult<Vec<Value>> {
+    let length = length_of(interpreter, object)?;
+    interpreter.step_by(length)?;
+
+    if let Some(elements) = object.borrow().dense_elements() {
+        return Ok(elements.clone());
+    }
+
+    interpreter.reserve(length.saturating_mul(size_of::<Value>()))?;
+    let mut elements = Vec::new();
+
+    // without a memory limit a sparse array can still ask for more than there is
+    if elements.try_reserve_exact(length).is_err() {
+        return Err(interpreter.throw_error(ErrorKind::RangeError, "Out of memory"));
+    }
+
+    elements.resize(length, Value::None);
+    let target = Value::Object(object.clone());
+
+    for (i, index) in stored_indices(object, length).into_iter().enumerate() {
+        if i.is_multiple_of(ITERATIONS_PER_CHECK) {
+            interpreter.check_limits()?;
+        }
+
+        elements[index] = interpreter.get_property(&target, index)?;
+    }
+
+    Ok(elements)
+}
+
+/// The length of an array or array-like object and the elements it has, by index. Holes are left
+/// out, so a sparse array takes time and memory in proportion to the elements it holds.
+fn present_elements(interpreter: &mut Interpreter, object: &ObjectRef) -> EvalResult<(usize, Vec<(usize, Value)>)> {
+    let length = length_of(interpreter, object)?;
+
+    if let Some(elements) = object.borrow().dense_elements() {
+        interpreter.step_by(length)?;
+        return Ok((length, elements.iter().cloned().enumerate().collect()));
+    }
+
+    let indices = stored_indices(object, length);
+    interpreter.step_by(indices.len())?;
+    interpreter.reserve(indices.len().saturating_mul(size_of::<(usize, Value)>()))?;
+
+    let target = Value::Object(object.clone());
+    let mut elements = Vec::with_capacity(indices.len());
+
+    for (i, index) in indices.into_iter().enumerate() {
+        if i.is_multiple_of(ITERATIONS_PER_CHECK) {
+            interpreter.check_limits()?;
+        }
+
+        elements.push((index, interpreter.get_property(&target, index)?));
+    }
+
+    Ok((length, elements))
+}
+
+/// The index of the first of `elements` that `matches`, looking at the limits as it searches.
+fn find_element<'a>(
+    interpreter: &mut Interpreter,
+    elements: impl Iterator<Item = &'a (usize, Value)>,
+    matches: impl Fn(usize, &Value) -> bool,
+) -> EvalResult<Option<usize>> {
+    for (i, (index, element)) in elements.enumerate() {
+        if i.is_multiple_of(ITERATIONS_PER_CHECK) {
+            interpreter.check_limits()?;
+        }
+
+        if matches(*index, element) {
+            return Ok(Some(*index));
+        }
+    }
+
+    Ok(None)
+}
+
+/// The indices below `length` that `object` or one of its prototypes holds an element at, in
+/// ascending order.
+fn stored_indices(object: &ObjectRef, length: usize) -> Vec<usize> {
+    let mut indices = Vec::new();
+    let mut current = Some(object.clone());
+
+    while let Some(object) = current {
+        let object = object.borrow();
+
+        if let ObjectKind::Array(elements) = &object.kind {
+            indices.extend(0..elements.len().min(length));
+        }
+
+        indices.extend(object.properties.keys().iter().filter_map(PropertyKey::as_array_index).filter(|index| *index < length));
+        current = object.prototype.clone();
+    }
+
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+/// Stores `elements` back into the array or array-like object they were read from.
+fn write_back(interpreter: &mut Interpreter, object: &ObjectRef, elements: Vec<Value>) -> EvalResult<()> {
+    // a sparse array is written element by element like any other object
+    if object.borrow().dense_elements().is_some() {
+        let growth = elements.len().saturating_sub(length_of(interpreter, object)?);
+        interpreter.reserve(growth * size_of::<Value>())?;
+
+        if let ObjectKind::Array(current) = &mut object.borrow_mut().kind {
+            *current = elements;
+            return Ok(());
+        }
+    }
+
+    let target = Value::Object(object.clone());
+    let length = elements.len();
+
+    for (i, element) in elements.into_iter().enumerate() {
+        if i.is_multiple_of(ITERATIONS_PER_CHECK) {
+            interpreter.check_limits()?;
+        }
+
+        interpreter.set_property(&target, i, element)?;
+    }
+
+    interpreter.set_property(&target, "length", Value::Float(length as f64))
+}
+
+/// Resolves a possibly negative index argument against `length`, clamping it to `0..=length`.
+fn relative_index(interpreter: &mut Interpreter, value: &Value, length: usize, default: usize) -> EvalResult<usize> {
+    if *value == Value::None {
+        return Ok(default);
+    }
+
+    let index = interpreter.to_number(value)?;
+    let index = if index.is_nan() { 0.0 } else { index.trunc() };
+    let length = length as f64;
+
+    match index < 0.0 {
+        true => Ok((length + index).max(0.0) as usize),
+        false => Ok(index.min(length) as usize),
+    }
+}
+
+fn same_value_zero(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Float(left), Value::Float(right)) if left.is_nan() && right.is_nan() => true,
+        _ => Interpreter::strict_equals(left, right),
+    }
+}
+
+/// Calls the callback of an iteration method such as `map` for each element, passing its result
+/// to `visit`. Stops early and returns the value once `visit` produces one. Methods that
+/// `skip_holes` only visit the elements a sparse array holds.
+fn for_each_element(
+    interpreter: &mut Interpreter,
+    this: &Value,
+    args: &[Value],
+    method: &str,
+    skip_holes: bool,
+    mut visit: impl FnMut(usize, Value, Value) -> EvalResult<Option<Value>>,
+) -> EvalResult<Option<Value>> {
+    let array = this_object(interpreter, this, method)?;
+    let callback = argument(args, 0);
+    let this_arg = argument(args, 1);
+
+    if !callback.is_callable() {
+        let message = format!("{} is not a function", callback.to_display_string());
+        return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+    }
+
+    let length = length_of(interpreter, &array)?;
+
+    let indices: Box<dyn Iterator<Item = usize>> = match skip_holes && array.borrow().dense_elements().is_none() {
+        true => Box::new(stored_indices(&array, length).into_iter()),
+        false => Box::new(0..length),
+    };
+
+    for index in indices {
+        let element = interpreter.get_property(this, index)?;
+        let result = interpreter.call_function(&callback, this_arg.clone(), &[element.clone(), Value::Float(index as f64), this.clone()])?;
+
+        if let Some(value) = visit(index, element, result)? {
+            return Ok(Some(value));
+        }
+    }
+
+    Ok(None)
+}
+
+fn reduce(interpreter: &mut Interpreter, this: &Value, args: &[Value], from_right: bool) -> EvalResult {
+    let method = if from_right { "reduceRight" } else { "reduce" };
+    let array = this_object(interpreter, this, method)?;
+    let callback = argument(args, 0);
+
+    if !callback.is_callable() {
+        let message = format!("{} is not a function", callback.to_display_string());
+        return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+    }
+
+    let length = length_of(interpreter, &array)?;
+
+    // holes are skipped, so only the elements a sparse array holds are visited
+    let indices: Vec<usize> = match array.borrow().dense_elements() {
+        Some(_) => (0..length).collect(),
+        None => stored_indices(&array, length),
+    };
+
+    let mut indices: Box<dyn Iterator<Item = usize>> = match from_right {
+        true => Box::new(indices.into_iter().rev()),
+        false => Box::new(indices.into_iter()),
+    };
+
+    let mut accumulator = match args.get(1) {
+        Some(initial) => initial.clone(),
+        None => match indices.next() {
+            Some(index) => interpreter.get_property(this, index)?,
+            None => return Err(interpreter.throw_error(ErrorKind::TypeError, "Reduce of empty array with no initial value")),
+        },
+    };
+
+    for index in indices {
+        let element = interpreter.get_property(this, index)?;
+        let args = [accumulator, element, Value::Float(index as f64), this.clone()];
+        accumulator = interpreter.call_function(&callback, Value::None, &args)?;
+    }
+
+    Ok(accumulator)
+}
+
+fn join(interpreter: &mut Interpreter, array: &ObjectRef, separator: &JsString) -> EvalResult {
+    let (length, elements) = present_elements(interpreter, array)?;
+    let mut parts = Vec::with_capacity(elements.len());
+
+    for (i, (index, element)) in elements.into_iter().enumerate() {
+        if i.is_multiple_of(ITERATIONS_PER_CHECK) {
+            interpreter.check_limits()?;
+        }
+
+        match element {
+            Value::Null | Value::None => (),
+            element => parts.push((index, interpreter.to_js_string(&element)?)),
+        }
+    }
+
+    // holes, null and undefined join as empty strings, which leaves runs of separators
+    let separators = length.saturating_sub(1);
+    let units = parts.iter().map(|(_, part)| part.utf16_len()).sum::<usize>().saturating_add(separator.utf16_len().saturating_mul(separators));
+
+    if units > MAX_STRING_LENGTH {
+        return Err(interpreter.throw_error(ErrorKind::RangeError, "Invalid string length"));
+    }
+
+    interpreter.reserve(parts.iter().map(|(_, part)| part.len()).sum::<usize>() + separator.len() * separators)?;
+
+    if parts.len() == length {
+        let parts: Vec<JsString> = parts.into_iter().map(|(_, part)| part).collect();
+        return Ok(Value::StringLiteral(JsString::join(&parts, separator)));
+    }
+
+    let mut pieces = Vec::with_capacity(parts.len() * 2 + 1);
+    let mut next = 0;
+
+    for (index, part) in parts {
+        pieces.push(separator.repeat(index - next));
+        pieces.push(part);
+        next = index;
+    }
+
+    pieces.push(separator.repeat(separators - next));
+    Ok(Value::StringLiteral(JsString::join(&pieces, &JsString::default())))
+}
+
+/// Stable merge sort that can call back into script code to compare elements.
+fn merge_sort(interpreter: &mut Interpreter, mut values: Vec<Value>, comparator: &Value) -> EvalResult<Vec<Value>> {
+    if values.len() <= 1 {
+        return Ok(values);
+    }
+
+    let right = values.split_off(values.len() / 2);
+    let left = merge_sort(interpreter, values, comparator)?;
+    let right = merge_sort(interpreter, right, comparator)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        // ties keep the element from the left half first, which makes the sort stable
+        match compare(interpreter, comparator, r, l)? {
+            Ordering::Less => merged.push(right.next().unwrap()),
+            _ => merged.push(left.next().unwrap()),
+        }
+    }
+
+    merged.extend(left);
+    merged.extend(right);
+
+    Ok(merged)
+}
+
+fn compare(interpreter: &mut Interpreter, comparator: &Value, left: &Value, right: &Value) -> EvalResult<Ordering> {
+    interpreter.step()?;
+
+    // undefined always sorts to the end, without consulting the comparator
+    match (left, right) {
+        (Value::None, Value::None) => return Ok(Ordering::Equal),
+        (Value::None, _) => return Ok(Ordering::Greater),
+        (_, Value::None) => return Ok(Ordering::Less),
+        _ => (),
+    }
+
+    if comparator.is_callable() {
+        let result = interpreter.call_function(comparator, Value::None, &[left.clone(), right.clone()])?;
+        let result = interpreter.to_number(&result)?;
+
+        return Ok(result.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+    }
+
+    let left = interpreter.to_js_string(left)?;
+    let right = interpreter.to_js_string(right)?;
+
+    Ok(left.cmp(&right))
+}
+
+/// `Array.from(items, mapFn, thisArg)`, accepting both iterables and array-like objects.
+fn array_from(interpreter: &mut Interpreter, _: &Value, args: &[Value]) -> EvalResult {
+    let items = argument(args, 0);
+    let map_fn = argument(args, 1);
+    let this_arg = argument(args, 2);
+
+    if map_fn != Value::None && !map_fn.is_callable() {
+        let message = format!("{} is not a function", map_fn.to_display_string());
+        return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+    }
+
+    let iterator = match &items {
+        Value::Null | Value::None => {
+            let message = format!("{} is not iterable", items.to_display_string());
+            return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+        }
+        _ => {
+            let symbol = interpreter.intrinsics().symbol_iterator.clone();
+            interpreter.get_property(&items, symbol)?
+        }
+    };
+
+    let values = match (&items, iterator.is_callable()) {
+        (_, true) => interpreter.iterate_to_vec(&items)?,
+        (Value::Object(object), false) => elements_of(interpreter, object)?,
+        _ => Vec::new(),
+    };
+
+    if !map_fn.is_callable() {
+        return new_array(interpreter, values);
+    }
+
+    let mut mapped = Vec::with_capacity(values.len());
+
+    for (index, value) in values.into_iter().enumerate() {
+        mapped.push(interpreter.call_function(&map_fn, this_arg.clone(), &[value, Value::Float(index as f64)])?);
+    }
+
+    new_array(interpreter, mapped)
+}
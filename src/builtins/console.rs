@@ -0,0 +1,322 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::interpreter::{EvalResult, Interpreter, LogLevel};
+use crate::object::{Object, ObjectKind, ObjectRef, PropertyKey};
+use crate::scope::Scope;
+use crate::types::{number_to_string, Value};
+use super::{argument, define_method, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let function_prototype = &intrinsics.function_prototype;
+    let console = ObjectRef::new(Object::new(Some(intrinsics.object_prototype.clone()), ObjectKind::Ordinary));
+
+    let levels = [
+        ("log", LogLevel::Log),
+        ("info", LogLevel::Info),
+        ("debug", LogLevel::Debug),
+        ("warn", LogLevel::Warn),
+        ("error", LogLevel::Error),
+    ];
+
+    for (name, level) in levels {
+        define_method(&console, function_prototype, name, 0, move |interpreter, _, args| {
+            let message = format_args(interpreter, args)?;
+            interpreter.print(level, &message);
+            Ok(Value::None)
+        });
+    }
+
+    define_method(&console, function_prototype, "assert", 0, |interpreter, _, args| {
+        if argument(args, 0).is_truthy() {
+            return Ok(Value::None);
+        }
+
+        let mut data = args.get(1..).unwrap_or_default().to_vec();
+
+        match data.first_mut() {
+            Some(Value::StringLiteral(message)) => *message = format!("Assertion failed: {}", message).into(),
+            _ => data.insert(0, Value::StringLiteral("Assertion failed".into())),
+        }
+
+        let message = format_args(interpreter, &data)?;
+        interpreter.print(LogLevel::Warn, &message);
+        Ok(Value::None)
+    });
+
+    for name in ["group", "groupCollapsed"] {
+        define_method(&console, function_prototype, name, 0, |interpreter, _, args| {
+            if !args.is_empty() {
+                let message = format_args(interpreter, args)?;
+                interpreter.print(LogLevel::Log, &message);
+            }
+
+            interpreter.start_group();
+            Ok(Value::None)
+        });
+    }
+
+    define_method(&console, function_prototype, "groupEnd", 0, |interpreter, _, _| {
+        interpreter.end_group();
+        Ok(Value::None)
+    });
+
+    define_method(&console, function_prototype, "count", 0, |interpreter, _, args| {
+        let label = label(interpreter, args)?;
+        let count = interpreter.count(&label);
+        interpreter.print(LogLevel::Log, &format!("{}: {}", label, count));
+        Ok(Value::None)
+    });
+
+    define_method(&console, function_prototype, "countReset", 0, |interpreter, _, args| {
+        let label = label(interpreter, args)?;
+
+        if !interpreter.reset_count(&label) {
+            interpreter.print(LogLevel::Warn, &format!("Count for '{}' does not exist", label));
+        }
+
+        Ok(Value::None)
+    });
+
+    define_method(&console, function_prototype, "time", 0, |interpreter, _, args| {
+        let label = label(interpreter, args)?;
+
+        if !interpreter.start_console_timer(&label) {
+            interpreter.print(LogLevel::Warn, &format!("Warning: Label '{}' already exists for console.time()", label));
+        }
+
+        Ok(Value::None)
+    });
+
+    for (name, end) in [("timeEnd", true), ("timeLog", false)] {
+        define_method(&console, function_prototype, name, 0, move |interpreter, _, args| {
+            let label = label(interpreter, args)?;
+
+            let elapsed = match interpreter.console_timer_elapsed(&label, end) {
+                Some(elapsed) => elapsed,
+                None => {
+                    let message = format!("Warning: No such label '{}' for console.{}()", label, name);
+                    interpreter.print(LogLevel::Warn, &message);
+                    return Ok(Value::None);
+                },
+            };
+
+            let mut message = format!("{}: {}", label, format_time(elapsed));
+
+            if !end && args.len() > 1 {
+                message = format!("{} {}", message, format_args(interpreter, &args[1..])?);
+            }
+
+            interpreter.print(LogLevel::Log, &message);
+            Ok(Value::None)
+        });
+    }
+
+    define_method(&console, function_prototype, "table", 1, |interpreter, _, args| {
+        let message = match argument(args, 0) {
+            Value::Object(_) => table(interpreter, &argument(args, 0), &argument(args, 1)),
+            _ => format_args(interpreter, args)?,
+        };
+
+        interpreter.print(LogLevel::Log, &message);
+        Ok(Value::None)
+    });
+
+    scope.borrow_mut().define("console".to_string(), Value::Object(console));
+}
+
+/// Formats the arguments of `console.log`: a leading string may contain `printf`-like
+/// substitutions, the rest is inspected and joined with spaces.
+fn format_args(interpreter: &mut Interpreter, args: &[Value]) -> EvalResult<String> {
+    let mut output = String::new();
+    let mut rest = args;
+
+    if let Some(Value::StringLiteral(format)) = args.first() {
+        rest = &args[1..];
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let specifier = match (c, chars.peek()) {
+                ('%', Some(&specifier)) => specifier,
+                _ => {
+                    output.push(c);
+                    continue;
+                },
+            };
+
+            if specifier == '%' {
+                chars.next();
+                output.push('%');
+                continue;
+            }
+
+            let value = match (rest.first(), "sdifoOc".contains(specifier)) {
+                (Some(value), true) => value.clone(),
+                _ => {
+                    output.push(c);
+                    continue;
+                },
+            };
+
+            chars.next();
+            rest = &rest[1..];
+
+            match specifier {
+                's' => match &value {
+                    Value::StringLiteral(string) => output.push_str(string),
+                    Value::Object(_) | Value::Float(_) => output.push_str(&interpreter.inspect(&value)),
+                    value => output.push_str(&value.to_display_string()),
+                },
+                'd' | 'i' | 'f' => {
+                    let number = match value {
+                        Value::Symbol(_) => f64::NAN,
+                        value => interpreter.to_number(&value)?,
+                    };
+
+                    let number = match specifier {
+                        'i' => number.trunc(),
+                        _ => number,
+                    };
+
+                    output.push_str(&interpreter.inspect(&Value::Float(number)));
+                },
+                'o' | 'O' => output.push_str(&interpreter.inspect(&value)),
+                // css styles only mean something in browsers
+                _ => (),
+            }
+        }
+    }
+
+    for (index, value) in rest.iter().enumerate() {
+        // the format string counts as the first argument
+        if index > 0 || rest.len() < args.len() {
+            output.push(' ');
+        }
+
+        match value {
+            Value::StringLiteral(string) => output.push_str(string),
+            value => output.push_str(&interpreter.inspect(value)),
+        }
+    }
+
+    Ok(output)
+}
+
+/// The label `console.count` and `console.time` are called with, `default` when there is none.
+fn label(interpreter: &mut Interpreter, args: &[Value]) -> EvalResult<String> {
+    match argument(args, 0) {
+        Value::None => Ok("default".to_string()),
+        label => Ok(interpreter.to_js_string(&label)?.to_string()),
+    }
+}
+
+fn format_time(ms: u64) -> String {
+    match ms {
+        ms if ms >= 1000 => format!("{}s", number_to_string(ms as f64 / 1000.0)),
+        ms => format!("{}ms", ms),
+    }
+}
+
+/// Renders the rows of `data` as a table, with a column for each property of the rows that are
+/// objects and a `Values` column for the rest. `properties` picks the columns to show.
+fn table(interpreter: &mut Interpreter, data: &Value, properties: &Value) -> String {
+    let Value::Object(data) = data else {
+        return interpreter.inspect(data);
+    };
+
+    let filter: Option<Vec<PropertyKey>> = match properties {
+        Value::Object(properties) => match &properties.borrow().kind {
+            ObjectKind::Array(elements) => Some(elements.iter().map(|element| PropertyKey::from(element.to_display_string())).collect()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let mut columns: Vec<PropertyKey> = filter.clone().unwrap_or_default();
+    let mut rows = Vec::new();
+    let mut has_values = false;
+
+    for key in enumerable_keys(data) {
+        let value = data.get_own(key.clone()).unwrap_or(Value::None);
+        let mut cells = Vec::new();
+
+        match &value {
+            Value::Object(row) if !row.is_callable() => {
+                for key in enumerable_keys(row) {
+                    if filter.as_ref().is_some_and(|filter| !filter.contains(&key)) {
+                        continue;
+                    }
+
+                    if !columns.contains(&key) {
+                        columns.push(key.clone());
+                    }
+
+                    cells.push((key.clone(), interpreter.inspect(&row.get_own(key).unwrap_or(Value::None))));
+                }
+
+                rows.push((key, cells, None));
+            },
+            value => {
+                has_values = true;
+                rows.push((key, cells, Some(interpreter.inspect(value))));
+            },
+        }
+    }
+
+    let mut header = vec!["(index)".to_string()];
+    header.extend(columns.iter().map(|column| match column {
+        PropertyKey::String(name) => name.to_string(),
+        PropertyKey::Symbol(symbol) => symbol.to_string(),
+    }));
+
+    if has_values {
+        header.push("Values".to_string());
+    }
+
+    let mut lines = vec![header];
+
+    for (key, cells, value) in rows {
+        let mut line = vec![key.as_str().map(str::to_string).unwrap_or_default()];
+
+        for column in &columns {
+            let cell = cells.iter().find(|(key, _)| key == column).map(|(_, cell)| cell.clone());
+            line.push(cell.unwrap_or_default());
+        }
+
+        if has_values {
+            line.push(value.unwrap_or_default());
+        }
+
+        lines.push(line);
+    }
+
+    let widths: Vec<usize> = (0..lines[0].len())
+        .map(|column| lines.iter().map(|line| line[column].chars().count()).max().unwrap_or(0) + 2)
+        .collect();
+
+    let border = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|width| "─".repeat(*width)).collect();
+        format!("{}{}{}", left, segments.join(middle), right)
+    };
+
+    let render = |line: &Vec<String>| {
+        let cells: Vec<String> = line.iter().zip(&widths)
+            .map(|(cell, width)| format!(" {}{}", cell, " ".repeat(width - cell.chars().count() - 1)))
+            .collect();
+
+        format!("│{}│", cells.join("│"))
+    };
+
+    let mut output = vec![border("┌", "┬", "┐"), render(&lines[0]), border("├", "┼", "┤")];
+    output.extend(lines[1..].iter().map(render));
+    output.push(border("└", "┴", "┘"));
+    output.join("\n")
+}
+
+fn enumerable_keys(object: &ObjectRef) -> Vec<PropertyKey> {
+    let object = object.borrow();
+
+    object.own_keys().into_iter()
+        .filter(|key| matches!(key, PropertyKey::String(_)))
+        .filter(|key| object.get_own_property(key).is_some_and(|property| property.enumerable))
+        .collect()
+}
@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{Object, ObjectKind, ObjectRef};
+use crate::scope::Scope;
+use crate::types::Value;
+use super::{argument, define_method, link_constructor, make_native_function, new_array};
+
+/// Installs `Error` and its subclasses, returning their prototypes in `ErrorKind::ALL` order.
+pub fn install(scope: &Rc<RefCell<Scope>>, object_prototype: &ObjectRef, function_prototype: &ObjectRef) -> [ObjectRef; 6] {
+    let mut base_constructor: Option<ObjectRef> = None;
+    let mut base_prototype: Option<ObjectRef> = None;
+
+    ErrorKind::ALL.map(|kind| {
+        let prototype = ObjectRef::new(Object::new(
+            Some(base_prototype.clone().unwrap_or_else(|| object_prototype.clone())),
+            ObjectKind::Ordinary,
+        ));
+
+        prototype.set_hidden("name", Value::StringLiteral(kind.name().into()));
+        prototype.set_hidden("message", Value::StringLiteral(String::new().into()));
+
+        let length = match kind {
+            ErrorKind::AggregateError => 2,
+            _ => 1,
+        };
+
+        let constructor = make_native_function(function_prototype, kind.name(), length, Rc::new(move |interpreter, _, args| {
+            construct_error(interpreter, kind, args)
+        }));
+
+        // TypeError and friends inherit static members from Error
+        if let Some(base) = &base_constructor {
+            constructor.borrow_mut().prototype = Some(base.clone());
+        }
+
+        link_constructor(&constructor, &prototype);
+
+        if kind == ErrorKind::Error {
+            define_method(&prototype, function_prototype, "toString", 0, error_to_string);
+            base_constructor = Some(constructor.clone());
+            base_prototype = Some(prototype.clone());
+        }
+
+        scope.borrow_mut().define(kind.name().to_string(), Value::Object(constructor));
+        prototype
+    })
+}
+
+/// `Error(message, options)`, which creates a new error whether or not it is called with `new`.
+/// `AggregateError` takes the errors it collects before those.
+fn construct_error(interpreter: &mut Interpreter, kind: ErrorKind, args: &[Value]) -> EvalResult {
+    let (errors, args) = match kind {
+        ErrorKind::AggregateError => (Some(argument(args, 0)), args.get(1..).unwrap_or_default()),
+        _ => (None, args),
+    };
+
+    let prototype = match interpreter.new_target() {
+        Some(target) => match interpreter.get_property(&target, "prototype")? {
+            Value::Object(prototype) => prototype,
+            _ => interpreter.intrinsics().error_prototype(kind).clone(),
+        },
+        None => interpreter.intrinsics().error_prototype(kind).clone(),
+    };
+
+    let message = match argument(args, 0) {
+        Value::None => None,
+        message => Some(interpreter.to_js_string(&message)?.to_string()),
+    };
+
+    let error = interpreter.create_error_object(prototype, message);
+
+    if let Value::Object(options) = argument(args, 1) {
+        if options.has_property("cause") {
+            let cause = interpreter.get_property(&Value::Object(options), "cause")?;
+            error.set_hidden("cause", cause);
+        }
+    }
+
+    if let Some(errors) = errors {
+        let errors = interpreter.iterate_to_vec(&errors)?;
+        let errors = new_array(interpreter, errors)?;
+        error.set_hidden("errors", errors);
+    }
+
+    Ok(Value::Object(error))
+}
+
+fn error_to_string(interpreter: &mut Interpreter, this: &Value, _: &[Value]) -> EvalResult {
+    if !matches!(this, Value::Object(_)) {
+        return Err(interpreter.throw_error(ErrorKind::TypeError, "Error.prototype.toString called on non-object"));
+    }
+
+    let name = match interpreter.get_property(this, "name")? {
+        Value::None => "Error".to_string(),
+        name => interpreter.to_js_string(&name)?.to_string(),
+    };
+
+    let message = match interpreter.get_property(this, "message")? {
+        Value::None => String::new(),
+        message => interpreter.to_js_string(&message)?.to_string(),
+    };
+
+    let string = match (name.is_empty(), message.is_empty()) {
+        (true, _) => message,
+        (_, true) => name,
+        _ => format!("{}: {}", name, message),
+    };
+
+    Ok(Value::StringLiteral(string.into()))
+}
@@ -0,0 +1,26 @@
+use crate::error::ErrorKind;
+use crate::object::{ObjectKind, ObjectRef};
+use crate::types::Value;
+use super::define_method;
+
+pub fn install(function_prototype: &ObjectRef) {
+    define_method(function_prototype, function_prototype, "toString", 0, |interpreter, this, _| {
+        let name = match this {
+            Value::Object(object) if object.is_callable() => match object.get("name") {
+                Some(Value::StringLiteral(name)) => name,
+                _ => "".into(),
+            },
+            _ => {
+                let message = "Function.prototype.toString requires that 'this' be a Function";
+                return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+            }
+        };
+
+        let is_native = this.as_object().is_some_and(|object| matches!(object.borrow().kind, ObjectKind::NativeFunction(_)));
+
+        match is_native {
+            true => Ok(Value::StringLiteral(format!("function {}() {{ [native code] }}", name).into())),
+            false => Ok(Value::StringLiteral(format!("function {}() {{ [code] }}", name).into())),
+        }
+    });
+}
@@ -0,0 +1,37 @@
+use crate::interpreter::ResumeMode;
+use super::{argument, define_method, define_to_string_tag, Intrinsics};
+
+pub fn install(intrinsics: &Intrinsics) {
+    let prototype = &intrinsics.generator_prototype;
+    let function_prototype = &intrinsics.function_prototype;
+
+    define_method(prototype, function_prototype, "next", 1, |interpreter, this, args| {
+        interpreter.resume_generator(this, ResumeMode::Next, argument(args, 0))
+    });
+
+    define_method(prototype, function_prototype, "return", 1, |interpreter, this, args| {
+        interpreter.resume_generator(this, ResumeMode::Return, argument(args, 0))
+    });
+
+    define_method(prototype, function_prototype, "throw", 1, |interpreter, this, args| {
+        interpreter.resume_generator(this, ResumeMode::Throw, argument(args, 0))
+    });
+
+    define_to_string_tag(intrinsics, prototype, "Generator");
+
+    let prototype = &intrinsics.async_generator_prototype;
+
+    define_method(prototype, function_prototype, "next", 1, |interpreter, this, args| {
+        Ok(interpreter.resume_async_generator(this, ResumeMode::Next, argument(args, 0)))
+    });
+
+    define_method(prototype, function_prototype, "return", 1, |interpreter, this, args| {
+        Ok(interpreter.resume_async_generator(this, ResumeMode::Return, argument(args, 0)))
+    });
+
+    define_method(prototype, function_prototype, "throw", 1, |interpreter, this, args| {
+        Ok(interpreter.resume_async_generator(this, ResumeMode::Throw, argument(args, 0)))
+    });
+
+    define_to_string_tag(intrinsics, prototype, "AsyncGenerator");
+}
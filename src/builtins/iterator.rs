@@ -0,0 +1,168 @@
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{IterationKind, IteratorState, Object, ObjectKind};
+use crate::regexp::advance;
+use crate::types::Value;
+use super::{define_method, define_symbol_method, define_to_string_tag, new_array, Intrinsics};
+
+pub fn install(intrinsics: &Intrinsics) {
+    let function_prototype = &intrinsics.function_prototype;
+
+    define_symbol_method(&intrinsics.iterator_prototype, function_prototype, &intrinsics.symbol_iterator, 0, |_, this, _| {
+        Ok(this.clone())
+    });
+
+    define_symbol_method(&intrinsics.async_iterator_prototype, function_prototype, &intrinsics.symbol_async_iterator, 0, |_, this, _| {
+        Ok(this.clone())
+    });
+
+    let prototypes = [
+        (&intrinsics.array_iterator_prototype, "Array Iterator"),
+        (&intrinsics.string_iterator_prototype, "String Iterator"),
+        (&intrinsics.map_iterator_prototype, "Map Iterator"),
+        (&intrinsics.set_iterator_prototype, "Set Iterator"),
+        (&intrinsics.regexp_string_iterator_prototype, "RegExp String Iterator"),
+    ];
+
+    for (prototype, tag) in prototypes {
+        define_method(prototype, function_prototype, "next", 0, iterator_next);
+        define_to_string_tag(intrinsics, prototype, tag);
+    }
+}
+
+/// Creates one of the built-in iterators, inheriting from the prototype matching its state.
+pub fn create_iterator(interpreter: &mut Interpreter, state: IteratorState) -> Value {
+    let intrinsics = interpreter.intrinsics();
+
+    let prototype = match &state {
+        IteratorState::Array(..) | IteratorState::Done => &intrinsics.array_iterator_prototype,
+        IteratorState::String(..) => &intrinsics.string_iterator_prototype,
+        IteratorState::Map(..) => &intrinsics.map_iterator_prototype,
+        IteratorState::Set(..) => &intrinsics.set_iterator_prototype,
+        IteratorState::RegExp(..) => &intrinsics.regexp_string_iterator_prototype,
+    };
+
+    Value::Object(interpreter.allocate_object(Object::new(Some(prototype.clone()), ObjectKind::Iterator(state))))
+}
+
+fn iterator_next(interpreter: &mut Interpreter, this: &Value, _: &[Value]) -> EvalResult {
+    let iterator = match this {
+        Value::Object(object) if matches!(object.borrow().kind, ObjectKind::Iterator(_)) => object.clone(),
+        _ => {
+            let message = format!("next method called on incompatible receiver {}", this.to_display_string());
+            return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+        }
+    };
+
+    // the state is taken out while stepping, since reading an array-like may run script code
+    let state = match &mut iterator.borrow_mut().kind {
+        ObjectKind::Iterator(state) => std::mem::replace(state, IteratorState::Done),
+        _ => IteratorState::Done,
+    };
+
+    let (value, state) = step(interpreter, state)?;
+    let done = matches!(state, IteratorState::Done);
+
+    if let ObjectKind::Iterator(slot) = &mut iterator.borrow_mut().kind {
+        *slot = state;
+    }
+
+    Ok(interpreter.create_iter_result(value, done))
+}
+
+/// Produces the next value of a built-in iterator along with its updated state.
+fn step(interpreter: &mut Interpreter, state: IteratorState) -> EvalResult<(Value, IteratorState)> {
+    let next = match state {
+        IteratorState::Array(target, index, kind) => {
+            let length = match &target {
+                Value::Object(object) => object.borrow().array_length(),
+                _ => None,
+            };
+
+            let length = match length {
+                Some(length) => length,
+                None => {
+                    let length = interpreter.get_property(&target, "length")?;
+                    interpreter.to_number(&length)?.max(0.0) as usize
+                }
+            };
+
+            match index < length {
+                true => {
+                    let value = match kind {
+                        IterationKind::Keys => Value::Float(index as f64),
+                        IterationKind::Values => interpreter.get_property(&target, index)?,
+                        IterationKind::Entries => {
+                            let value = interpreter.get_property(&target, index)?;
+                            new_array(interpreter, vec![Value::Float(index as f64), value])?
+                        }
+                    };
+
+                    Some((value, IteratorState::Array(target, index + 1, kind)))
+                }
+                false => None,
+            }
+        }
+        IteratorState::String(string, position) => string
+            .code_point_from(position)
+            .map(|(c, position)| (Value::StringLiteral(c), IteratorState::String(string, position))),
+        IteratorState::Map(map, position, kind) => {
+            let entry = match &map.borrow().kind {
+                ObjectKind::Map(entries) => entries.entry_from(position).map(|(next, key, value)| (next, key.clone(), value.clone())),
+                _ => None,
+            };
+
+            match entry {
+                Some((position, key, value)) => {
+                    let value = match kind {
+                        IterationKind::Keys => key,
+                        IterationKind::Values => value,
+                        IterationKind::Entries => new_array(interpreter, vec![key, value])?,
+                    };
+
+                    Some((value, IteratorState::Map(map, position, kind)))
+                },
+                None => None,
+            }
+        }
+        IteratorState::Set(set, position, kind) => {
+            let entry = match &set.borrow().kind {
+                ObjectKind::Set(entries) => entries.entry_from(position).map(|(next, value, _)| (next, value.clone())),
+                _ => None,
+            };
+
+            match entry {
+                Some((position, value)) => {
+                    let value = match kind {
+                        IterationKind::Entries => new_array(interpreter, vec![value.clone(), value])?,
+                        _ => value,
+                    };
+
+                    Some((value, IteratorState::Set(set, position, kind)))
+                },
+                None => None,
+            }
+        }
+        IteratorState::RegExp(regexp, string, global) => match super::regexp::exec(interpreter, &regexp, &string)? {
+            Value::Null => None,
+            // a regular expression that isn't global only gives its first match
+            found if !global => Some((found, IteratorState::Done)),
+            found => {
+                let matched = interpreter.get_property(&found, 0)?;
+
+                // an empty match would be found again, so the next search starts after it
+                if interpreter.to_js_string(&matched)?.is_empty() {
+                    let index = interpreter.get_property(&Value::Object(regexp.clone()), "lastIndex")?;
+                    let index = interpreter.to_number(&index)?.max(0.0) as usize;
+                    let unicode = super::regexp::regexp_of(&Value::Object(regexp.clone())).is_some_and(|regex| regex.flags().unicode);
+                    super::regexp::set_last_index(interpreter, &regexp, advance(&string.to_utf16(), index, unicode))?;
+                }
+
+                Some((found, IteratorState::RegExp(regexp, string, global)))
+            },
+        },
+        IteratorState::Done => None,
+    };
+
+    Ok(next.unwrap_or((Value::None, IteratorState::Done)))
+}
@@ -0,0 +1,141 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{entry_size, EvalResult, Interpreter};
+use crate::object::{IterationKind, IteratorState, Object, ObjectKind, ObjectRef, OrderedMap};
+use crate::scope::Scope;
+use crate::types::Value;
+use super::iterator::create_iterator;
+use super::{argument, define_getter, define_method, define_to_string_tag, link_constructor, make_native_function, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let function_prototype = &intrinsics.function_prototype;
+    let prototype = ObjectRef::new(Object::new(Some(intrinsics.object_prototype.clone()), ObjectKind::Ordinary));
+
+    let constructor = make_native_function(function_prototype, "Map", 0, Rc::new(|interpreter, this, args| {
+        let map = match (interpreter.new_target(), this) {
+            (Some(_), Value::Object(map)) => map.clone(),
+            _ => return Err(interpreter.throw_error(ErrorKind::TypeError, "Constructor Map requires 'new'")),
+        };
+
+        let mut entries = OrderedMap::default();
+        let iterable = argument(args, 0);
+
+        if !iterable.is_nullish() {
+            for entry in interpreter.iterate_to_vec(&iterable)? {
+                if !matches!(entry, Value::Object(_)) {
+                    let message = format!("Iterator value {} is not an entry object", entry.to_display_string());
+                    return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+                }
+
+                let key = interpreter.get_property(&entry, "0")?;
+                let value = interpreter.get_property(&entry, "1")?;
+
+                if !entries.has(&key) {
+                    interpreter.reserve(entry_size(&key, &value))?;
+                }
+
+                entries.set(key, value);
+            }
+        }
+
+        map.borrow_mut().kind = ObjectKind::Map(entries);
+        Ok(Value::Object(map))
+    }));
+
+    link_constructor(&constructor, &prototype);
+
+    define_method(&prototype, function_prototype, "get", 1, |interpreter, this, args| {
+        with_map(interpreter, this, "get", |map| map.get(&argument(args, 0)).cloned().unwrap_or(Value::None))
+    });
+
+    define_method(&prototype, function_prototype, "set", 2, |interpreter, this, args| {
+        let (key, value) = (argument(args, 0), argument(args, 1));
+
+        // like properties, only new entries are charged, not values replaced
+        if !with_map(interpreter, this, "set", |map| map.has(&key))? {
+            interpreter.reserve(entry_size(&key, &value))?;
+        }
+
+        with_map(interpreter, this, "set", |map| map.set(key, value))?;
+        Ok(this.clone())
+    });
+
+    define_method(&prototype, function_prototype, "has", 1, |interpreter, this, args| {
+        with_map(interpreter, this, "has", |map| Value::Boolean(map.has(&argument(args, 0))))
+    });
+
+    define_method(&prototype, function_prototype, "delete", 1, |interpreter, this, args| {
+        with_map(interpreter, this, "delete", |map| Value::Boolean(map.delete(&argument(args, 0))))
+    });
+
+    define_method(&prototype, function_prototype, "clear", 0, |interpreter, this, _| {
+        with_map(interpreter, this, "clear", |map| map.clear())?;
+        Ok(Value::None)
+    });
+
+    define_getter(&prototype, function_prototype, "size", |interpreter, this, _| {
+        with_map(interpreter, this, "size", |map| Value::Float(map.len() as f64))
+    });
+
+    define_method(&prototype, function_prototype, "forEach", 1, |interpreter, this, args| {
+        let callback = argument(args, 0);
+
+        if !callback.is_callable() {
+            let message = format!("{} is not a function", callback.to_display_string());
+            return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let map = this_map(interpreter, this, "forEach")?;
+        let mut position = 0;
+
+        // entries added during the walk are visited, deleted ones are skipped
+        loop {
+            let entry = match &map.borrow().kind {
+                ObjectKind::Map(entries) => entries.entry_from(position).map(|(next, key, value)| (next, key.clone(), value.clone())),
+                _ => None,
+            };
+
+            let Some((next, key, value)) = entry else {
+                return Ok(Value::None);
+            };
+
+            position = next;
+            interpreter.call_function(&callback, argument(args, 1), &[value, key, this.clone()])?;
+        }
+    });
+
+    for (name, kind) in [("keys", IterationKind::Keys), ("values", IterationKind::Values), ("entries", IterationKind::Entries)] {
+        define_method(&prototype, function_prototype, name, 0, move |interpreter, this, _| {
+            let map = this_map(interpreter, this, name)?;
+            Ok(create_iterator(interpreter, IteratorState::Map(map, 0, kind)))
+        });
+    }
+
+    let entries = prototype.get("entries").unwrap_or(Value::None);
+    prototype.set_hidden(intrinsics.symbol_iterator.clone(), entries);
+    define_to_string_tag(intrinsics, &prototype, "Map");
+
+    scope.borrow_mut().define("Map".to_string(), Value::Object(constructor));
+}
+
+fn this_map(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<ObjectRef> {
+    match this {
+        Value::Object(object) if matches!(object.borrow().kind, ObjectKind::Map(_)) => Ok(object.clone()),
+        _ => {
+            let message = format!("Method Map.prototype.{} called on incompatible receiver {}", method, this.to_display_string());
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        }
+    }
+}
+
+/// Runs `f` on the entries of the `Map` that `this` refers to.
+fn with_map<T>(interpreter: &mut Interpreter, this: &Value, method: &str, f: impl FnOnce(&mut OrderedMap) -> T) -> EvalResult<T> {
+    let map = this_map(interpreter, this, method)?;
+    let mut map = map.borrow_mut();
+
+    match &mut map.kind {
+        ObjectKind::Map(entries) => Ok(f(entries)),
+        _ => unreachable!("this_map only accepts maps"),
+    }
+}
@@ -0,0 +1,158 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{Object, ObjectKind, ObjectRef, Property};
+use crate::scope::Scope;
+use crate::types::{exponentiate, Value};
+use super::{argument, define_method, define_to_string_tag, Intrinsics};
+
+type NumberFunction = fn(f64) -> f64;
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let function_prototype = &intrinsics.function_prototype;
+    let math = ObjectRef::new(Object::new(Some(intrinsics.object_prototype.clone()), ObjectKind::Ordinary));
+
+    let constants = [
+        ("E", std::f64::consts::E),
+        ("LN10", std::f64::consts::LN_10),
+        ("LN2", std::f64::consts::LN_2),
+        ("LOG10E", std::f64::consts::LOG10_E),
+        ("LOG2E", std::f64::consts::LOG2_E),
+        ("PI", std::f64::consts::PI),
+        ("SQRT1_2", std::f64::consts::FRAC_1_SQRT_2),
+        ("SQRT2", std::f64::consts::SQRT_2),
+    ];
+
+    for (name, value) in constants {
+        let property = Property { writable: false, configurable: false, ..Property::hidden(Value::Float(value)) };
+        math.borrow_mut().properties.insert(name, property);
+    }
+
+    let unary: [(&str, NumberFunction); 27] = [
+        ("abs", f64::abs),
+        ("acos", f64::acos),
+        ("acosh", f64::acosh),
+        ("asin", f64::asin),
+        ("asinh", f64::asinh),
+        ("atan", f64::atan),
+        ("atanh", f64::atanh),
+        ("cbrt", f64::cbrt),
+        ("ceil", f64::ceil),
+        ("cos", f64::cos),
+        ("cosh", f64::cosh),
+        ("exp", f64::exp),
+        ("expm1", f64::exp_m1),
+        ("floor", f64::floor),
+        ("fround", |x| x as f32 as f64),
+        ("log", f64::ln),
+        ("log1p", f64::ln_1p),
+        ("log10", f64::log10),
+        ("log2", f64::log2),
+        ("round", round),
+        ("sign", sign),
+        ("sin", f64::sin),
+        ("sinh", f64::sinh),
+        ("sqrt", f64::sqrt),
+        ("tan", f64::tan),
+        ("tanh", f64::tanh),
+        ("trunc", f64::trunc),
+    ];
+
+    for (name, function) in unary {
+        define_method(&math, function_prototype, name, 1, move |interpreter, _, args| {
+            let x = interpreter.to_number(&argument(args, 0))?;
+            Ok(Value::Float(function(x)))
+        });
+    }
+
+    define_method(&math, function_prototype, "atan2", 2, |interpreter, _, args| {
+        let y = interpreter.to_number(&argument(args, 0))?;
+        let x = interpreter.to_number(&argument(args, 1))?;
+        Ok(Value::Float(y.atan2(x)))
+    });
+
+    define_method(&math, function_prototype, "pow", 2, |interpreter, _, args| {
+        let base = interpreter.to_number(&argument(args, 0))?;
+        let exponent = interpreter.to_number(&argument(args, 1))?;
+        Ok(Value::Float(exponentiate(base, exponent)))
+    });
+
+    define_method(&math, function_prototype, "max", 2, |interpreter, _, args| {
+        let numbers = to_numbers(interpreter, args)?;
+        Ok(Value::Float(numbers.into_iter().fold(f64::NEG_INFINITY, |max, x| match max.is_nan() || x.is_nan() {
+            true => f64::NAN,
+            // +0 is larger than -0, which compare equal
+            false if x == max => match x.is_sign_positive() { true => x, false => max },
+            false => x.max(max),
+        })))
+    });
+
+    define_method(&math, function_prototype, "min", 2, |interpreter, _, args| {
+        let numbers = to_numbers(interpreter, args)?;
+        Ok(Value::Float(numbers.into_iter().fold(f64::INFINITY, |min, x| match min.is_nan() || x.is_nan() {
+            true => f64::NAN,
+            false if x == min => match x.is_sign_negative() { true => x, false => min },
+            false => x.min(min),
+        })))
+    });
+
+    define_method(&math, function_prototype, "hypot", 2, |interpreter, _, args| {
+        let numbers = to_numbers(interpreter, args)?;
+
+        // an infinite argument wins over a NaN one
+        if numbers.iter().any(|x| x.is_infinite()) {
+            return Ok(Value::Float(f64::INFINITY));
+        }
+
+        Ok(Value::Float(numbers.into_iter().fold(0.0, f64::hypot)))
+    });
+
+    define_method(&math, function_prototype, "clz32", 1, |interpreter, _, args| {
+        let x = interpreter.to_uint32(&argument(args, 0))?;
+        Ok(Value::Float(x.leading_zeros() as f64))
+    });
+
+    define_method(&math, function_prototype, "imul", 2, |interpreter, _, args| {
+        let a = interpreter.to_int32(&argument(args, 0))?;
+        let b = interpreter.to_int32(&argument(args, 1))?;
+        Ok(Value::Float(a.wrapping_mul(b) as f64))
+    });
+
+    define_method(&math, function_prototype, "random", 0, |interpreter, _, _| {
+        Ok(Value::Float(interpreter.random()))
+    });
+
+    define_to_string_tag(intrinsics, &math, "Math");
+
+    scope.borrow_mut().define("Math".to_string(), Value::Object(math));
+}
+
+/// Converts every argument, in order, before any of them is looked at.
+fn to_numbers(interpreter: &mut Interpreter, args: &[Value]) -> EvalResult<Vec<f64>> {
+    args.iter().map(|arg| interpreter.to_number(arg)).collect()
+}
+
+/// Rounds halfway cases up rather than away from zero, keeping the sign of zero.
+fn round(x: f64) -> f64 {
+    if !x.is_finite() || x.fract() == 0.0 {
+        return x;
+    }
+
+    if (-0.5..0.0).contains(&x) {
+        return -0.0;
+    }
+
+    let floor = x.floor();
+
+    match x - floor >= 0.5 {
+        true => floor + 1.0,
+        false => floor,
+    }
+}
+
+fn sign(x: f64) -> f64 {
+    match x.is_nan() || x == 0.0 {
+        true => x,
+        false => x.signum(),
+    }
+}
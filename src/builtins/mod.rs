@@ -0,0 +1,218 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{NativeFunction, Object, ObjectKind, ObjectRef, Property, PropertyKey, Symbol};
+use crate::scope::Scope;
+use crate::types::Value;
+
+mod array;
+mod console;
+mod error;
+mod function;
+mod generator;
+mod iterator;
+mod map;
+mod math;
+mod object;
+mod promise;
+mod regexp;
+mod set;
+mod string;
+mod symbol;
+mod timers;
+
+pub use object::own_enumerable_keys;
+
+/// Objects the interpreter needs direct access to, such as the prototypes of built-in types.
+pub struct Intrinsics {
+    pub object_prototype: ObjectRef,
+    pub function_prototype: ObjectRef,
+    pub error_prototype: ObjectRef,
+    pub type_error_prototype: ObjectRef,
+    pub range_error_prototype: ObjectRef,
+    pub reference_error_prototype: ObjectRef,
+    pub syntax_error_prototype: ObjectRef,
+    pub aggregate_error_prototype: ObjectRef,
+    pub array_prototype: ObjectRef,
+    pub string_prototype: ObjectRef,
+    pub regexp_prototype: ObjectRef,
+    pub symbol_prototype: ObjectRef,
+    pub iterator_prototype: ObjectRef,
+    pub array_iterator_prototype: ObjectRef,
+    pub string_iterator_prototype: ObjectRef,
+    pub map_iterator_prototype: ObjectRef,
+    pub set_iterator_prototype: ObjectRef,
+    pub regexp_string_iterator_prototype: ObjectRef,
+    pub generator_prototype: ObjectRef,
+    pub async_iterator_prototype: ObjectRef,
+    pub async_generator_prototype: ObjectRef,
+    pub promise_prototype: ObjectRef,
+    // well-known symbols
+    pub symbol_iterator: Symbol,
+    pub symbol_async_iterator: Symbol,
+    pub symbol_to_string_tag: Symbol,
+}
+
+impl Intrinsics {
+    pub fn error_prototype(&self, kind: ErrorKind) -> &ObjectRef {
+        match kind {
+            ErrorKind::Error => &self.error_prototype,
+            ErrorKind::TypeError => &self.type_error_prototype,
+            ErrorKind::RangeError => &self.range_error_prototype,
+            ErrorKind::ReferenceError => &self.reference_error_prototype,
+            ErrorKind::SyntaxError => &self.syntax_error_prototype,
+            ErrorKind::AggregateError => &self.aggregate_error_prototype,
+        }
+    }
+}
+
+/// Creates the built-in objects and defines the global bindings for them in `scope`.
+pub fn install(scope: &Rc<RefCell<Scope>>) -> Intrinsics {
+    let object_prototype = ObjectRef::new(Object::new(None, ObjectKind::Ordinary));
+    let function_prototype = make_native_function_with_prototype(Some(object_prototype.clone()), "", 0, Rc::new(|_, _, _| Ok(Value::None)));
+
+    let [error_prototype, type_error_prototype, range_error_prototype, reference_error_prototype, syntax_error_prototype, aggregate_error_prototype] =
+        error::install(scope, &object_prototype, &function_prototype);
+
+    let new_prototype = |prototype: &ObjectRef| ObjectRef::new(Object::new(Some(prototype.clone()), ObjectKind::Ordinary));
+    let iterator_prototype = new_prototype(&object_prototype);
+    let async_iterator_prototype = new_prototype(&object_prototype);
+
+    let intrinsics = Intrinsics {
+        error_prototype,
+        type_error_prototype,
+        range_error_prototype,
+        reference_error_prototype,
+        syntax_error_prototype,
+        aggregate_error_prototype,
+        array_prototype: ObjectRef::new(Object::new(Some(object_prototype.clone()), ObjectKind::Array(Vec::new()))),
+        string_prototype: new_prototype(&object_prototype),
+        regexp_prototype: new_prototype(&object_prototype),
+        symbol_prototype: new_prototype(&object_prototype),
+        array_iterator_prototype: new_prototype(&iterator_prototype),
+        string_iterator_prototype: new_prototype(&iterator_prototype),
+        map_iterator_prototype: new_prototype(&iterator_prototype),
+        set_iterator_prototype: new_prototype(&iterator_prototype),
+        regexp_string_iterator_prototype: new_prototype(&iterator_prototype),
+        generator_prototype: new_prototype(&iterator_prototype),
+        async_generator_prototype: new_prototype(&async_iterator_prototype),
+        promise_prototype: new_prototype(&object_prototype),
+        iterator_prototype,
+        async_iterator_prototype,
+        symbol_iterator: Symbol::new(Some("Symbol.iterator".to_string())),
+        symbol_async_iterator: Symbol::new(Some("Symbol.asyncIterator".to_string())),
+        symbol_to_string_tag: Symbol::new(Some("Symbol.toStringTag".to_string())),
+        object_prototype,
+        function_prototype,
+    };
+
+    object::install(scope, &intrinsics);
+    function::install(&intrinsics.function_prototype);
+    symbol::install(scope, &intrinsics);
+    iterator::install(&intrinsics);
+    generator::install(&intrinsics);
+    array::install(scope, &intrinsics);
+    regexp::install(scope, &intrinsics);
+    string::install(scope, &intrinsics);
+    math::install(scope, &intrinsics);
+    map::install(scope, &intrinsics);
+    set::install(scope, &intrinsics);
+    promise::install(scope, &intrinsics);
+    timers::install(scope, &intrinsics);
+    console::install(scope, &intrinsics);
+
+    let mut global = scope.borrow_mut();
+    global.define("undefined".to_string(), Value::None);
+    global.define("NaN".to_string(), Value::Float(f64::NAN));
+    global.define("Infinity".to_string(), Value::Float(f64::INFINITY));
+
+    intrinsics
+}
+
+fn make_native_function_with_prototype(prototype: Option<ObjectRef>, name: &str, length: usize, function: NativeFunction) -> ObjectRef {
+    let object = ObjectRef::new(Object::new(prototype, ObjectKind::NativeFunction(function)));
+
+    {
+        let mut borrowed = object.borrow_mut();
+        borrowed.properties.insert("length", Property::readonly(Value::Float(length as f64)));
+        borrowed.properties.insert("name", Property::readonly(Value::StringLiteral(name.into())));
+    }
+
+    object
+}
+
+pub fn make_native_function(function_prototype: &ObjectRef, name: &str, length: usize, function: NativeFunction) -> ObjectRef {
+    make_native_function_with_prototype(Some(function_prototype.clone()), name, length, function)
+}
+
+/// Defines a non-enumerable native method on `target`.
+pub fn define_method(
+    target: &ObjectRef,
+    function_prototype: &ObjectRef,
+    name: &str,
+    length: usize,
+    function: impl Fn(&mut Interpreter, &Value, &[Value]) -> EvalResult + 'static,
+) {
+    let function = make_native_function(function_prototype, name, length, Rc::new(function));
+    target.set_hidden(name, Value::Object(function));
+}
+
+/// Defines a non-enumerable native method keyed by a symbol, named `[description]` like the spec's
+/// methods such as `[Symbol.iterator]`.
+pub fn define_symbol_method(
+    target: &ObjectRef,
+    function_prototype: &ObjectRef,
+    symbol: &Symbol,
+    length: usize,
+    function: impl Fn(&mut Interpreter, &Value, &[Value]) -> EvalResult + 'static,
+) {
+    let name = format!("[{}]", symbol.description().unwrap_or_default());
+    let function = make_native_function(function_prototype, &name, length, Rc::new(function));
+    target.set_hidden(symbol.clone(), Value::Object(function));
+}
+
+/// Defines a non-enumerable accessor on `target` with a native getter and no setter.
+pub fn define_getter(
+    target: &ObjectRef,
+    function_prototype: &ObjectRef,
+    key: impl Into<PropertyKey>,
+    function: impl Fn(&mut Interpreter, &Value, &[Value]) -> EvalResult + 'static,
+) {
+    let key = key.into();
+    let name = match &key {
+        PropertyKey::String(name) => format!("get {}", name),
+        PropertyKey::Symbol(symbol) => format!("get [{}]", symbol.description().unwrap_or_default()),
+    };
+
+    let getter = make_native_function(function_prototype, &name, 0, Rc::new(function));
+    target.borrow_mut().define_own_property(key, Property::accessor(Some(Value::Object(getter)), None, false));
+}
+
+/// Gives instances inheriting from `prototype` a `[object tag]` string from `Object.prototype.toString`.
+pub fn define_to_string_tag(intrinsics: &Intrinsics, prototype: &ObjectRef, tag: &str) {
+    let key = intrinsics.symbol_to_string_tag.clone();
+    prototype.borrow_mut().define_own_property(key, Property::readonly(Value::StringLiteral(tag.into())));
+}
+
+/// Makes `constructor.prototype` and `prototype.constructor` point at each other.
+pub fn link_constructor(constructor: &ObjectRef, prototype: &ObjectRef) {
+    constructor.borrow_mut().properties.insert(
+        "prototype",
+        Property { writable: false, configurable: false, ..Property::hidden(Value::Object(prototype.clone())) },
+    );
+    prototype.set_hidden("constructor", Value::Object(constructor.clone()));
+}
+
+/// The argument at `index`, or `undefined` when fewer arguments were passed.
+pub fn argument(args: &[Value], index: usize) -> Value {
+    args.get(index).cloned().unwrap_or(Value::None)
+}
+
+/// Creates an array of `elements` once the memory they take up is reserved, for the builtins that
+/// give a new array.
+pub fn new_array(interpreter: &mut Interpreter, elements: Vec<Value>) -> EvalResult {
+    interpreter.reserve_object()?;
+    interpreter.reserve(elements.capacity() * std::mem::size_of::<Value>())?;
+    Ok(interpreter.create_array(elements))
+}
@@ -0,0 +1,119 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{ObjectKind, ObjectRef, PropertyKey};
+use crate::scope::Scope;
+use crate::types::Value;
+use super::{argument, define_method, link_constructor, make_native_function, new_array, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let object_prototype = &intrinsics.object_prototype;
+    let function_prototype = &intrinsics.function_prototype;
+
+    let constructor = make_native_function(function_prototype, "Object", 1, Rc::new(|interpreter, _, args| {
+        match argument(args, 0) {
+            value @ Value::Object(_) => Ok(value),
+            _ => Ok(Value::Object(interpreter.create_object())),
+        }
+    }));
+
+    link_constructor(&constructor, object_prototype);
+
+    define_method(&constructor, function_prototype, "keys", 1, |interpreter, _, args| {
+        let keys = own_enumerable_keys(interpreter, &argument(args, 0))?;
+        let keys = keys.into_iter().map(|key| Value::StringLiteral(key.into())).collect();
+        new_array(interpreter, keys)
+    });
+
+    define_method(&constructor, function_prototype, "values", 1, |interpreter, _, args| {
+        let object = argument(args, 0);
+        let mut values = Vec::new();
+
+        for key in own_enumerable_keys(interpreter, &object)? {
+            values.push(interpreter.get_property(&object, key)?);
+        }
+
+        new_array(interpreter, values)
+    });
+
+    define_method(&constructor, function_prototype, "entries", 1, |interpreter, _, args| {
+        let object = argument(args, 0);
+        let mut entries = Vec::new();
+
+        for key in own_enumerable_keys(interpreter, &object)? {
+            let value = interpreter.get_property(&object, &key)?;
+            entries.push(new_array(interpreter, vec![Value::StringLiteral(key.into()), value])?);
+        }
+
+        new_array(interpreter, entries)
+    });
+
+    define_method(object_prototype, function_prototype, "toString", 0, |interpreter, this, _| {
+        let tag = match this {
+            Value::None => "Undefined",
+            Value::Null => "Null",
+            Value::Object(object) => {
+                let key = interpreter.intrinsics().symbol_to_string_tag.clone();
+
+                if let Value::StringLiteral(tag) = interpreter.get_property(this, key)? {
+                    return Ok(Value::StringLiteral(format!("[object {}]", tag).into()));
+                }
+
+                match object.borrow().kind {
+                    ObjectKind::Function(_) | ObjectKind::NativeFunction(_) => "Function",
+                    ObjectKind::Error => "Error",
+                    ObjectKind::Arguments => "Arguments",
+                    ObjectKind::Array(_) => "Array",
+                    ObjectKind::RegExp(_) => "RegExp",
+                    _ => "Object",
+                }
+            },
+            Value::Float(_) => "Number",
+            Value::StringLiteral(_) => "String",
+            Value::Boolean(_) => "Boolean",
+            Value::Symbol(_) => "Symbol",
+        };
+
+        Ok(Value::StringLiteral(format!("[object {}]", tag).into()))
+    });
+
+    define_method(object_prototype, function_prototype, "valueOf", 0, |_, this, _| Ok(this.clone()));
+
+    define_method(object_prototype, function_prototype, "hasOwnProperty", 1, |interpreter, this, args| {
+        let key = interpreter.to_property_key(&argument(args, 0))?;
+
+        match this {
+            Value::Object(object) => Ok(Value::Boolean(object.borrow().get_own_property(&key).is_some())),
+            _ => Ok(Value::Boolean(false)),
+        }
+    });
+
+    scope.borrow_mut().define("Object".to_string(), Value::Object(constructor));
+}
+
+/// The own enumerable string keys of `value`, in the order `Object.keys` lists them.
+pub fn own_enumerable_keys(interpreter: &mut Interpreter, value: &Value) -> EvalResult<Vec<Rc<str>>> {
+    let object: ObjectRef = match value {
+        Value::Object(object) => object.clone(),
+        Value::Null | Value::None => {
+            return Err(interpreter.throw_error(ErrorKind::TypeError, "Cannot convert undefined or null to object"));
+        },
+        Value::StringLiteral(string) => return Ok((0..string.utf16_len()).map(|i| i.to_string().into()).collect()),
+        _ => return Ok(Vec::new()),
+    };
+
+    let object = object.borrow();
+
+    let keys = object
+        .own_keys()
+        .into_iter()
+        .filter(|key| object.get_own_property(key).is_some_and(|property| property.enumerable))
+        .filter_map(|key| match key {
+            PropertyKey::String(key) => Some(key),
+            PropertyKey::Symbol(_) => None,
+        })
+        .collect();
+
+    Ok(keys)
+}
@@ -0,0 +1,251 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{ObjectKind, ObjectRef, PromiseState};
+use crate::scope::Scope;
+use crate::types::{Signal, Value};
+use super::{argument, define_method, define_to_string_tag, link_constructor, make_native_function, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let function_prototype = &intrinsics.function_prototype;
+    let prototype = &intrinsics.promise_prototype;
+
+    let constructor = make_native_function(function_prototype, "Promise", 1, Rc::new(|interpreter, this, args| {
+        let promise = match (interpreter.new_target(), this) {
+            (Some(_), Value::Object(promise)) => promise.clone(),
+            _ => return Err(interpreter.throw_error(ErrorKind::TypeError, "Promise constructor cannot be invoked without 'new'")),
+        };
+
+        let executor = argument(args, 0);
+
+        if !executor.is_callable() {
+            let message = format!("Promise resolver {} is not a function", executor.to_display_string());
+            return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+        }
+
+        promise.borrow_mut().kind = ObjectKind::Promise(PromiseState::Pending(Vec::new()), false);
+        let (resolve, reject) = interpreter.create_resolving_functions(&promise);
+
+        // an executor that throws rejects the promise, unless it already resolved it
+        match interpreter.call_function(&executor, Value::None, &[resolve, reject.clone()]) {
+            Err(Signal::Throw(error)) => {
+                interpreter.call_function(&reject, Value::None, &[error])?;
+            },
+            Err(signal) => return Err(signal),
+            Ok(_) => (),
+        }
+
+        Ok(Value::Object(promise))
+    }));
+
+    link_constructor(&constructor, prototype);
+
+    define_method(prototype, function_prototype, "then", 2, |interpreter, this, args| {
+        let promise = match this {
+            Value::Object(promise) if Interpreter::is_promise(this) => promise.clone(),
+            _ => {
+                let message = format!("Method Promise.prototype.then called on incompatible receiver {}", this.to_display_string());
+                return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+            },
+        };
+
+        let result = interpreter.create_promise();
+        interpreter.perform_then(&promise, argument(args, 0), argument(args, 1), Some(result.clone()));
+        Ok(Value::Object(result))
+    });
+
+    define_method(prototype, function_prototype, "catch", 1, |interpreter, this, args| {
+        invoke_then(interpreter, this, &[Value::None, argument(args, 0)])
+    });
+
+    define_method(prototype, function_prototype, "finally", 1, |interpreter, this, args| {
+        let on_finally = argument(args, 0);
+
+        let handlers = match on_finally.is_callable() {
+            true => [finally_handler(interpreter, on_finally.clone(), false), finally_handler(interpreter, on_finally, true)],
+            false => [on_finally.clone(), on_finally],
+        };
+
+        invoke_then(interpreter, this, &handlers)
+    });
+
+    define_to_string_tag(intrinsics, prototype, "Promise");
+
+    define_method(&constructor, function_prototype, "resolve", 1, |interpreter, _, args| {
+        Ok(Value::Object(interpreter.promise_resolve(argument(args, 0))))
+    });
+
+    define_method(&constructor, function_prototype, "reject", 1, |interpreter, _, args| {
+        let promise = interpreter.create_promise();
+        interpreter.reject_promise(&promise, argument(args, 0));
+        Ok(Value::Object(promise))
+    });
+
+    let combinators = [
+        ("all", Combinator::All),
+        ("allSettled", Combinator::AllSettled),
+        ("race", Combinator::Race),
+        ("any", Combinator::Any),
+    ];
+
+    for (name, combinator) in combinators {
+        define_method(&constructor, function_prototype, name, 1, move |interpreter, _, args| {
+            let result = interpreter.create_promise();
+
+            match combine(interpreter, &result, combinator, &argument(args, 0)) {
+                Ok(()) => (),
+                Err(Signal::Throw(error)) => interpreter.reject_promise(&result, error),
+                Err(signal) => return Err(signal),
+            }
+
+            Ok(Value::Object(result))
+        });
+    }
+
+    scope.borrow_mut().define("Promise".to_string(), Value::Object(constructor));
+}
+
+/// Calls `this.then(...)`, which `catch` and `finally` go through so that subclasses and
+/// thenables can intercept them.
+fn invoke_then(interpreter: &mut Interpreter, this: &Value, args: &[Value]) -> EvalResult {
+    let then = interpreter.get_property(this, "then")?;
+    interpreter.call_function(&then, this.clone(), args)
+}
+
+/// One of the callbacks `finally` attaches: runs `on_finally`, waits for the promise it returns
+/// and then passes on the original outcome.
+fn finally_handler(interpreter: &mut Interpreter, on_finally: Value, rejected: bool) -> Value {
+    interpreter.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+        let outcome = argument(args, 0);
+        let result = interpreter.call_function(&on_finally, Value::None, &[])?;
+        let promise = Value::Object(interpreter.promise_resolve(result));
+
+        let pass_on = interpreter.create_native_function("", 0, Rc::new(move |_, _, _| match rejected {
+            true => Err(Signal::Throw(outcome.clone())),
+            false => Ok(outcome.clone()),
+        }));
+
+        invoke_then(interpreter, &promise, &[pass_on])
+    }))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Combinator {
+    All,
+    AllSettled,
+    Race,
+    Any,
+}
+
+/// The outcomes collected by `Promise.all` and friends.
+struct Combination {
+    result: ObjectRef,
+    combinator: Combinator,
+    values: RefCell<Vec<Value>>,
+    // elements without an outcome yet, plus one while the iterable is still being read
+    remaining: Cell<usize>,
+}
+
+impl Combination {
+    fn count_down(&self, interpreter: &mut Interpreter) {
+        self.remaining.set(self.remaining.get() - 1);
+
+        if self.remaining.get() > 0 {
+            return;
+        }
+
+        let values = interpreter.create_array(self.values.borrow().clone());
+
+        match self.combinator {
+            Combinator::All | Combinator::AllSettled => interpreter.resolve_promise(&self.result, values),
+            Combinator::Any => {
+                let error = interpreter.create_error(ErrorKind::AggregateError, "All promises were rejected");
+
+                if let Value::Object(error) = &error {
+                    error.set_hidden("errors", values);
+                }
+
+                interpreter.reject_promise(&self.result, error);
+            },
+            Combinator::Race => (),
+        }
+    }
+}
+
+fn combine(interpreter: &mut Interpreter, result: &ObjectRef, combinator: Combinator, iterable: &Value) -> EvalResult<()> {
+    let record = interpreter.get_iterator(iterable)?;
+    let (resolve, reject) = interpreter.create_resolving_functions(result);
+
+    let combination = Rc::new(Combination {
+        result: result.clone(),
+        combinator,
+        values: RefCell::new(Vec::new()),
+        remaining: Cell::new(1),
+    });
+
+    while let Some(value) = interpreter.iterator_step(&record)? {
+        // the values are charged as they come, the array they end up in is made once all settle
+        if let Err(signal) = interpreter.reserve(std::mem::size_of::<Value>()) {
+            return interpreter.iterator_close(&record, Err(signal));
+        }
+
+        let index = combination.values.borrow().len();
+        combination.values.borrow_mut().push(Value::None);
+        combination.remaining.set(combination.remaining.get() + 1);
+
+        let handlers = match combinator {
+            Combinator::All => [element_handler(interpreter, &combination, index, false), reject.clone()],
+            Combinator::AllSettled => {
+                [element_handler(interpreter, &combination, index, false), element_handler(interpreter, &combination, index, true)]
+            },
+            Combinator::Race => [resolve.clone(), reject.clone()],
+            Combinator::Any => [resolve.clone(), element_handler(interpreter, &combination, index, true)],
+        };
+
+        let promise = Value::Object(interpreter.promise_resolve(value));
+
+        if let Err(signal) = invoke_then(interpreter, &promise, &handlers) {
+            return interpreter.iterator_close(&record, Err(signal));
+        }
+    }
+
+    combination.count_down(interpreter);
+    Ok(())
+}
+
+/// Records the outcome of the element at `index`, the first time it is called.
+fn element_handler(interpreter: &mut Interpreter, combination: &Rc<Combination>, index: usize, rejected: bool) -> Value {
+    let combination = combination.clone();
+    let called = Cell::new(false);
+
+    interpreter.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+        if called.replace(true) {
+            return Ok(Value::None);
+        }
+
+        let value = match combination.combinator {
+            Combinator::AllSettled => {
+                let outcome = interpreter.create_object();
+
+                match rejected {
+                    true => {
+                        outcome.set("status", Value::StringLiteral("rejected".into()));
+                        outcome.set("reason", argument(args, 0));
+                    },
+                    false => {
+                        outcome.set("status", Value::StringLiteral("fulfilled".into()));
+                        outcome.set("value", argument(args, 0));
+                    },
+                }
+
+                Value::Object(outcome)
+            },
+            _ => argument(args, 0),
+        };
+
+        combination.values.borrow_mut()[index] = value;
+        combination.count_down(interpreter);
+        Ok(Value::None)
+    }))
+}
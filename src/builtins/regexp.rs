@@ -0,0 +1,277 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{Object, ObjectKind, ObjectRef};
+use crate::regexp::{advance, Captures, Flags, Regex};
+use crate::scope::Scope;
+use crate::types::{JsString, Value};
+use super::{argument, define_getter, define_method, link_constructor, make_native_function, new_array, Intrinsics};
+
+type FlagGetter = fn(Flags) -> bool;
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let prototype = &intrinsics.regexp_prototype;
+    let function_prototype = &intrinsics.function_prototype;
+
+    let constructor = make_native_function(function_prototype, "RegExp", 2, Rc::new(|interpreter, _, args| {
+        let (pattern, flags) = (argument(args, 0), argument(args, 1));
+
+        // called without `new`, a regular expression passed on its own is returned as it is
+        if interpreter.new_target().is_none() && regexp_of(&pattern).is_some() && flags == Value::None {
+            return Ok(pattern);
+        }
+
+        let (source, flags) = match (regexp_of(&pattern), flags) {
+            (Some(regex), Value::None) => (regex.source().clone(), regex.flags().to_string()),
+            (Some(regex), flags) => (regex.source().clone(), interpreter.to_js_string(&flags)?.to_string()),
+            (None, flags) => (
+                match pattern {
+                    Value::None => JsString::default(),
+                    pattern => interpreter.to_js_string(&pattern)?,
+                },
+                match flags {
+                    Value::None => String::new(),
+                    flags => interpreter.to_js_string(&flags)?.to_string(),
+                },
+            ),
+        };
+
+        create(interpreter, source, &flags)
+    }));
+
+    link_constructor(&constructor, prototype);
+
+    define_method(prototype, function_prototype, "exec", 1, |interpreter, this, args| {
+        let regexp = this_regexp(interpreter, this, "RegExp.prototype.exec")?;
+        let string = interpreter.to_js_string(&argument(args, 0))?;
+        exec(interpreter, &regexp, &string)
+    });
+
+    define_method(prototype, function_prototype, "test", 1, |interpreter, this, args| {
+        let regexp = this_regexp(interpreter, this, "RegExp.prototype.test")?;
+        let string = interpreter.to_js_string(&argument(args, 0))?;
+        Ok(Value::Boolean(exec_captures(interpreter, &regexp, &string.to_utf16())?.is_some()))
+    });
+
+    define_method(prototype, function_prototype, "toString", 0, |interpreter, this, _| {
+        if !matches!(this, Value::Object(_)) {
+            return Err(interpreter.throw_error(ErrorKind::TypeError, "RegExp.prototype.toString requires that 'this' be an Object"));
+        }
+
+        let source = interpreter.get_property(this, "source")?;
+        let flags = interpreter.get_property(this, "flags")?;
+        let string = format!("/{}/{}", interpreter.to_js_string(&source)?, interpreter.to_js_string(&flags)?);
+        Ok(Value::StringLiteral(string.into()))
+    });
+
+    // the accessors give the defaults on the prototype itself, which isn't a regular expression
+    define_getter(prototype, function_prototype, "source", |interpreter, this, _| {
+        match regexp_of(this) {
+            Some(regex) => Ok(Value::StringLiteral(regex.escaped_source().into())),
+            None if is_prototype(interpreter, this) => Ok(Value::StringLiteral("(?:)".into())),
+            None => Err(interpreter.throw_error(ErrorKind::TypeError, "RegExp.prototype.source getter called on non-RegExp object")),
+        }
+    });
+
+    define_getter(prototype, function_prototype, "flags", |interpreter, this, _| {
+        match regexp_of(this) {
+            Some(regex) => Ok(Value::StringLiteral(regex.flags().to_string().into())),
+            None if matches!(this, Value::Object(_)) => Ok(Value::StringLiteral(JsString::default())),
+            None => Err(interpreter.throw_error(ErrorKind::TypeError, "RegExp.prototype.flags getter called on non-object")),
+        }
+    });
+
+    let flags: [(&str, FlagGetter); 7] = [
+        ("hasIndices", |flags| flags.has_indices),
+        ("global", |flags| flags.global),
+        ("ignoreCase", |flags| flags.ignore_case),
+        ("multiline", |flags| flags.multiline),
+        ("dotAll", |flags| flags.dot_all),
+        ("unicode", |flags| flags.unicode),
+        ("sticky", |flags| flags.sticky),
+    ];
+
+    for (name, flag) in flags {
+        define_getter(prototype, function_prototype, name, move |interpreter, this, _| {
+            match regexp_of(this) {
+                Some(regex) => Ok(Value::Boolean(flag(regex.flags()))),
+                None if is_prototype(interpreter, this) => Ok(Value::None),
+                None => {
+                    let message = format!("RegExp.prototype.{} getter called on non-RegExp object", name);
+                    Err(interpreter.throw_error(ErrorKind::TypeError, message))
+                },
+            }
+        });
+    }
+
+    scope.borrow_mut().define("RegExp".to_string(), Value::Object(constructor));
+}
+
+/// Compiles a regular expression the way the `RegExp` constructor does, throwing a SyntaxError
+/// for a pattern or flags that aren't valid.
+pub fn create(interpreter: &mut Interpreter, source: JsString, flags: &str) -> EvalResult {
+    let Some(parsed) = Flags::parse(flags) else {
+        let message = format!("Invalid flags supplied to RegExp constructor '{}'", flags);
+        return Err(interpreter.throw_error(ErrorKind::SyntaxError, message));
+    };
+
+    match Regex::new(source, parsed) {
+        Ok(regex) => {
+            interpreter.reserve_object()?;
+            Ok(interpreter.create_regexp(Rc::new(regex)))
+        },
+        Err(message) => Err(interpreter.throw_error(ErrorKind::SyntaxError, message)),
+    }
+}
+
+/// The regular expression `value` holds, if it's a `RegExp` object.
+pub fn regexp_of(value: &Value) -> Option<Rc<Regex>> {
+    match value {
+        Value::Object(object) => match &object.borrow().kind {
+            ObjectKind::RegExp(regex) => Some(regex.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn this_regexp(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<ObjectRef> {
+    match this {
+        Value::Object(object) if regexp_of(this).is_some() => Ok(object.clone()),
+        _ => {
+            let message = format!("{} called on incompatible receiver {}", method, this.to_display_string());
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        },
+    }
+}
+
+fn is_prototype(interpreter: &Interpreter, value: &Value) -> bool {
+    matches!(value, Value::Object(object) if object.ptr_eq(&interpreter.intrinsics().regexp_prototype))
+}
+
+/// Reads `lastIndex` as a position, which is never negative.
+fn last_index(interpreter: &mut Interpreter, regexp: &ObjectRef) -> EvalResult<usize> {
+    let index = interpreter.get_property(&Value::Object(regexp.clone()), "lastIndex")?;
+    let index = interpreter.to_number(&index)?;
+    Ok(if index.is_nan() || index <= 0.0 { 0 } else { index.min(usize::MAX as f64) as usize })
+}
+
+pub fn set_last_index(interpreter: &mut Interpreter, regexp: &ObjectRef, index: usize) -> EvalResult<()> {
+    interpreter.set_property(&Value::Object(regexp.clone()), "lastIndex", Value::Float(index as f64))
+}
+
+/// Looks for the next match in `input`, which global and sticky regular expressions look for from
+/// their `lastIndex` and move it past. Long searches count against the limits of the script.
+pub fn exec_captures(interpreter: &mut Interpreter, regexp: &ObjectRef, input: &[u16]) -> EvalResult<Option<Captures>> {
+    let regex = regexp_of(&Value::Object(regexp.clone())).expect("exec_captures is only given regular expressions");
+    let flags = regex.flags();
+    let last_index = last_index(interpreter, regexp)?;
+
+    let start = match flags.global || flags.sticky {
+        true => last_index,
+        false => 0,
+    };
+
+    let found = match start <= input.len() {
+        true => regex.find_from(input, start, flags.sticky, &mut |steps| interpreter.step_by(steps))?,
+        false => None,
+    };
+
+    if flags.global || flags.sticky {
+        let end = found.as_ref().and_then(|captures| captures[0].as_ref()).map_or(0, |whole| whole.end);
+        set_last_index(interpreter, regexp, end)?;
+    }
+
+    Ok(found)
+}
+
+/// `RegExp.prototype.exec`, which gives the next match as an array or null.
+pub fn exec(interpreter: &mut Interpreter, regexp: &ObjectRef, string: &JsString) -> EvalResult {
+    let input = string.to_utf16();
+
+    match exec_captures(interpreter, regexp, &input)? {
+        Some(captures) => {
+            let regex = regexp_of(&Value::Object(regexp.clone())).expect("exec is only given regular expressions");
+            match_array(interpreter, &regex, string, &input, &captures)
+        },
+        None => Ok(Value::Null),
+    }
+}
+
+/// Every match in `input` from the start, as `match` and `replace` find them for a global regular
+/// expression. An empty match moves `lastIndex` on by a character, so that the next one is found.
+pub fn exec_all(interpreter: &mut Interpreter, regexp: &ObjectRef, input: &[u16]) -> EvalResult<Vec<Captures>> {
+    let unicode = regexp_of(&Value::Object(regexp.clone())).is_some_and(|regex| regex.flags().unicode);
+    let mut matches = Vec::new();
+    let mut held = 0;
+
+    set_last_index(interpreter, regexp, 0)?;
+
+    while let Some(captures) = exec_captures(interpreter, regexp, input)? {
+        if captures[0].as_ref().is_some_and(|whole| whole.is_empty()) {
+            let index = last_index(interpreter, regexp)?;
+            set_last_index(interpreter, regexp, advance(input, index, unicode))?;
+        }
+
+        let bytes = std::mem::size_of::<Captures>() + captures.capacity() * std::mem::size_of::<Option<std::ops::Range<usize>>>();
+        interpreter.reserve_held(held, bytes)?;
+        held += bytes;
+        matches.push(captures);
+    }
+
+    Ok(matches)
+}
+
+/// The array `exec` gives for a match: the matched text and what each group captured, along with
+/// the `index` of the match, the `input`, the named `groups` and, with the `d` flag, the `indices`.
+pub fn match_array(interpreter: &mut Interpreter, regex: &Regex, string: &JsString, input: &[u16], captures: &Captures) -> EvalResult {
+    let substring = |capture: &Option<std::ops::Range<usize>>| match capture {
+        Some(range) => Value::StringLiteral(JsString::from_utf16(&input[range.clone()])),
+        None => Value::None,
+    };
+
+    let values: Vec<Value> = captures.iter().map(substring).collect();
+    let groups = named_groups(interpreter, regex, &values);
+    let array = new_array(interpreter, values)?;
+    let Value::Object(object) = &array else { unreachable!("create_array makes an object") };
+
+    let index = captures[0].as_ref().map_or(0, |whole| whole.start);
+    object.set("index", Value::Float(index as f64));
+    object.set("input", Value::StringLiteral(string.clone()));
+    object.set("groups", groups);
+
+    if regex.flags().has_indices {
+        let pairs = captures.iter().map(|capture| match capture {
+            Some(range) => new_array(interpreter, vec![Value::Float(range.start as f64), Value::Float(range.end as f64)]),
+            None => Ok(Value::None),
+        }).collect::<EvalResult<Vec<Value>>>()?;
+
+        let groups = named_groups(interpreter, regex, &pairs);
+        let indices = new_array(interpreter, pairs)?;
+
+        if let Value::Object(indices) = &indices {
+            indices.set("groups", groups);
+        }
+
+        object.set("indices", indices);
+    }
+
+    Ok(array)
+}
+
+/// An object with no prototype that maps the name of each named group to the value for its capture
+/// in `values`, or undefined when there are no named groups.
+pub fn named_groups(interpreter: &mut Interpreter, regex: &Regex, values: &[Value]) -> Value {
+    if regex.group_names().is_empty() {
+        return Value::None;
+    }
+
+    let groups = interpreter.allocate_object(Object::new(None, ObjectKind::Ordinary));
+
+    for (name, index) in regex.group_names() {
+        groups.set(name.as_str(), values[*index].clone());
+    }
+
+    Value::Object(groups)
+}
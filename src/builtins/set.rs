@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{entry_size, EvalResult, Interpreter};
+use crate::object::{IterationKind, IteratorState, Object, ObjectKind, ObjectRef, OrderedMap};
+use crate::scope::Scope;
+use crate::types::Value;
+use super::iterator::create_iterator;
+use super::{argument, define_getter, define_method, define_to_string_tag, link_constructor, make_native_function, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let function_prototype = &intrinsics.function_prototype;
+    let prototype = ObjectRef::new(Object::new(Some(intrinsics.object_prototype.clone()), ObjectKind::Ordinary));
+
+    let constructor = make_native_function(function_prototype, "Set", 0, Rc::new(|interpreter, this, args| {
+        let set = match (interpreter.new_target(), this) {
+            (Some(_), Value::Object(set)) => set.clone(),
+            _ => return Err(interpreter.throw_error(ErrorKind::TypeError, "Constructor Set requires 'new'")),
+        };
+
+        let mut entries = OrderedMap::default();
+        let iterable = argument(args, 0);
+
+        if !iterable.is_nullish() {
+            for value in interpreter.iterate_to_vec(&iterable)? {
+                if !entries.has(&value) {
+                    interpreter.reserve(entry_size(&value, &Value::None))?;
+                }
+
+                entries.set(value, Value::None);
+            }
+        }
+
+        set.borrow_mut().kind = ObjectKind::Set(entries);
+        Ok(Value::Object(set))
+    }));
+
+    link_constructor(&constructor, &prototype);
+
+    define_method(&prototype, function_prototype, "add", 1, |interpreter, this, args| {
+        let value = argument(args, 0);
+
+        if !with_set(interpreter, this, "add", |set| set.has(&value))? {
+            interpreter.reserve(entry_size(&value, &Value::None))?;
+        }
+
+        with_set(interpreter, this, "add", |set| set.set(value, Value::None))?;
+        Ok(this.clone())
+    });
+
+    define_method(&prototype, function_prototype, "has", 1, |interpreter, this, args| {
+        with_set(interpreter, this, "has", |set| Value::Boolean(set.has(&argument(args, 0))))
+    });
+
+    define_method(&prototype, function_prototype, "delete", 1, |interpreter, this, args| {
+        with_set(interpreter, this, "delete", |set| Value::Boolean(set.delete(&argument(args, 0))))
+    });
+
+    define_method(&prototype, function_prototype, "clear", 0, |interpreter, this, _| {
+        with_set(interpreter, this, "clear", |set| set.clear())?;
+        Ok(Value::None)
+    });
+
+    define_getter(&prototype, function_prototype, "size", |interpreter, this, _| {
+        with_set(interpreter, this, "size", |set| Value::Float(set.len() as f64))
+    });
+
+    define_method(&prototype, function_prototype, "forEach", 1, |interpreter, this, args| {
+        let callback = argument(args, 0);
+
+        if !callback.is_callable() {
+            let message = format!("{} is not a function", callback.to_display_string());
+            return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let set = this_set(interpreter, this, "forEach")?;
+        let mut position = 0;
+
+        // entries added during the walk are visited, deleted ones are skipped
+        loop {
+            let entry = match &set.borrow().kind {
+                ObjectKind::Set(entries) => entries.entry_from(position).map(|(next, value, _)| (next, value.clone())),
+                _ => None,
+            };
+
+            let Some((next, value)) = entry else {
+                return Ok(Value::None);
+            };
+
+            position = next;
+            interpreter.call_function(&callback, argument(args, 1), &[value.clone(), value, this.clone()])?;
+        }
+    });
+
+    for (name, kind) in [("values", IterationKind::Values), ("entries", IterationKind::Entries)] {
+        define_method(&prototype, function_prototype, name, 0, move |interpreter, this, _| {
+            let set = this_set(interpreter, this, name)?;
+            Ok(create_iterator(interpreter, IteratorState::Set(set, 0, kind)))
+        });
+    }
+
+    // like in the spec, `keys` and the iterator method are the same function object as `values`
+    let values = prototype.get("values").unwrap_or(Value::None);
+    prototype.set_hidden("keys", values.clone());
+    prototype.set_hidden(intrinsics.symbol_iterator.clone(), values);
+    define_to_string_tag(intrinsics, &prototype, "Set");
+
+    scope.borrow_mut().define("Set".to_string(), Value::Object(constructor));
+}
+
+fn this_set(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<ObjectRef> {
+    match this {
+        Value::Object(object) if matches!(object.borrow().kind, ObjectKind::Set(_)) => Ok(object.clone()),
+        _ => {
+            let message = format!("Method Set.prototype.{} called on incompatible receiver {}", method, this.to_display_string());
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        }
+    }
+}
+
+/// Runs `f` on the entries of the `Set` that `this` refers to.
+fn with_set<T>(interpreter: &mut Interpreter, this: &Value, method: &str, f: impl FnOnce(&mut OrderedMap) -> T) -> EvalResult<T> {
+    let set = this_set(interpreter, this, method)?;
+    let mut set = set.borrow_mut();
+
+    match &mut set.kind {
+        ObjectKind::Set(entries) => Ok(f(entries)),
+        _ => unreachable!("this_set only accepts sets"),
+    }
+}
@@ -0,0 +1,888 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{value_size, EvalResult, Interpreter, ITERATIONS_PER_CHECK};
+use crate::object::{IteratorState, ObjectRef};
+use crate::regexp::{advance, Captures, Regex};
+use crate::scope::Scope;
+use crate::types::{JsString, Value};
+use super::iterator::create_iterator;
+use super::regexp::{create, exec, exec_all, exec_captures, named_groups, regexp_of, set_last_index};
+use super::{argument, define_method, define_symbol_method, link_constructor, make_native_function, Intrinsics};
+
+mod normalize;
+
+use normalize::{normalize, Form};
+
+/// Longest string scripts can make, in code units.
+pub const MAX_LENGTH: usize = (1 << 29) - 24;
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let prototype = &intrinsics.string_prototype;
+    let function_prototype = &intrinsics.function_prototype;
+
+    // there are no String wrapper objects, so `new String(x)` also produces a primitive
+    let constructor = make_native_function(function_prototype, "String", 1, Rc::new(|interpreter, _, args| {
+        match args.first() {
+            None => Ok(Value::StringLiteral(JsString::default())),
+            Some(Value::Symbol(symbol)) => Ok(Value::StringLiteral(symbol.to_string().into())),
+            Some(value) => Ok(Value::StringLiteral(interpreter.to_js_string(value)?)),
+        }
+    }));
+
+    link_constructor(&constructor, prototype);
+
+    define_method(&constructor, function_prototype, "fromCharCode", 1, |interpreter, _, args| {
+        let mut units = Vec::with_capacity(args.len());
+
+        for arg in args {
+            units.push(interpreter.to_uint32(arg)? as u16);
+        }
+
+        Ok(Value::StringLiteral(JsString::from_utf16(&units)))
+    });
+
+    // `String.raw({ raw: ["a", "c"] }, "b")` is "abc", the way a tagged template would call it
+    define_method(&constructor, function_prototype, "raw", 1, |interpreter, _, args| {
+        let raw = interpreter.get_property(&argument(args, 0), "raw")?;
+        let length = interpreter.get_property(&raw, "length")?;
+        let length = to_integer(interpreter, &length)?.max(0.0) as usize;
+        let mut result = JsString::default();
+
+        for i in 0..length {
+            let segment = interpreter.get_property(&raw, i)?;
+            result = result.concat(&interpreter.to_js_string(&segment)?);
+
+            if let Some(substitution) = args.get(i + 1).filter(|_| i + 1 < length) {
+                result = result.concat(&interpreter.to_js_string(substitution)?);
+            }
+        }
+
+        Ok(Value::StringLiteral(result))
+    });
+
+    define_method(prototype, function_prototype, "toString", 0, |interpreter, this, _| {
+        Ok(Value::StringLiteral(this_string(interpreter, this, "String.prototype.toString")?))
+    });
+
+    define_method(prototype, function_prototype, "valueOf", 0, |interpreter, this, _| {
+        Ok(Value::StringLiteral(this_string(interpreter, this, "String.prototype.valueOf")?))
+    });
+
+    define_method(prototype, function_prototype, "charAt", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "charAt")?;
+        let position = to_integer(interpreter, &argument(args, 0))?;
+
+        match position >= 0.0 && position < string.utf16_len() as f64 {
+            true => Ok(Value::StringLiteral(string.substring(position as usize, position as usize + 1))),
+            false => Ok(Value::StringLiteral(JsString::default())),
+        }
+    });
+
+    define_method(prototype, function_prototype, "charCodeAt", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "charCodeAt")?;
+        let position = to_integer(interpreter, &argument(args, 0))?;
+
+        match position >= 0.0 {
+            true => Ok(Value::Float(string.code_unit_at(position as usize).map_or(f64::NAN, f64::from))),
+            false => Ok(Value::Float(f64::NAN)),
+        }
+    });
+
+    define_method(prototype, function_prototype, "codePointAt", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "codePointAt")?;
+        let position = to_integer(interpreter, &argument(args, 0))?;
+        let units = string.to_utf16();
+
+        if position < 0.0 || position >= units.len() as f64 {
+            return Ok(Value::None);
+        }
+
+        let position = position as usize;
+        let code_point = char::decode_utf16(units[position..].iter().copied()).next().map(|c| match c {
+            Ok(c) => c as u32,
+            Err(error) => error.unpaired_surrogate() as u32,
+        });
+
+        Ok(code_point.map_or(Value::None, |code_point| Value::Float(code_point as f64)))
+    });
+
+    define_method(prototype, function_prototype, "at", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "at")?;
+        let length = string.utf16_len() as f64;
+        let index = to_integer(interpreter, &argument(args, 0))?;
+        let index = if index < 0.0 { length + index } else { index };
+
+        match index >= 0.0 && index < length {
+            true => Ok(Value::StringLiteral(string.substring(index as usize, index as usize + 1))),
+            false => Ok(Value::None),
+        }
+    });
+
+    define_method(prototype, function_prototype, "slice", 2, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "slice")?;
+        let length = string.utf16_len();
+        let start = relative_position(interpreter, &argument(args, 0), length, 0)?;
+        let end = relative_position(interpreter, &argument(args, 1), length, length)?;
+
+        Ok(Value::StringLiteral(string.substring(start, end.max(start))))
+    });
+
+    define_method(prototype, function_prototype, "substring", 2, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "substring")?;
+        let length = string.utf16_len();
+        let start = clamped_position(interpreter, &argument(args, 0), length, 0)?;
+        let end = clamped_position(interpreter, &argument(args, 1), length, length)?;
+
+        Ok(Value::StringLiteral(string.substring(start.min(end), start.max(end))))
+    });
+
+    define_method(prototype, function_prototype, "indexOf", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "indexOf")?;
+        let search = interpreter.to_js_string(&argument(args, 0))?;
+        let units = string.to_utf16();
+        interpreter.step_by(units.len())?;
+        let start = clamped_position(interpreter, &argument(args, 1), units.len(), 0)?;
+
+        Ok(Value::Float(find(interpreter, &units, &search.to_utf16(), start)?.map_or(-1.0, |index| index as f64)))
+    });
+
+    define_method(prototype, function_prototype, "lastIndexOf", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "lastIndexOf")?;
+        let search = interpreter.to_js_string(&argument(args, 0))?;
+        let (units, search) = (string.to_utf16(), search.to_utf16());
+        interpreter.step_by(units.len())?;
+
+        // a missing position, or one that isn't a number, searches from the end
+        let position = interpreter.to_number(&argument(args, 1))?;
+        let position = if position.is_nan() { f64::INFINITY } else { position.trunc() };
+        let start = position.clamp(0.0, units.len() as f64) as usize;
+
+        let stride = search_stride(&search);
+        let mut found = None;
+
+        for (i, index) in (0..=start.min(units.len().saturating_sub(search.len()))).rev().enumerate() {
+            if (i + 1).is_multiple_of(stride) {
+                interpreter.check_limits()?;
+            }
+
+            if units[index..].starts_with(&search) {
+                found = Some(index);
+                break;
+            }
+        }
+
+        Ok(Value::Float(found.map_or(-1.0, |index| index as f64)))
+    });
+
+    define_method(prototype, function_prototype, "includes", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "includes")?;
+        reject_regexp(interpreter, &argument(args, 0), "includes")?;
+        let search = interpreter.to_js_string(&argument(args, 0))?;
+        let units = string.to_utf16();
+        let start = clamped_position(interpreter, &argument(args, 1), units.len(), 0)?;
+        interpreter.step_by(units.len())?;
+
+        Ok(Value::Boolean(find(interpreter, &units, &search.to_utf16(), start)?.is_some()))
+    });
+
+    define_method(prototype, function_prototype, "startsWith", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "startsWith")?;
+        reject_regexp(interpreter, &argument(args, 0), "startsWith")?;
+        let search = interpreter.to_js_string(&argument(args, 0))?;
+        let units = string.to_utf16();
+        let start = clamped_position(interpreter, &argument(args, 1), units.len(), 0)?;
+
+        Ok(Value::Boolean(units[start..].starts_with(&search.to_utf16())))
+    });
+
+    define_method(prototype, function_prototype, "endsWith", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "endsWith")?;
+        reject_regexp(interpreter, &argument(args, 0), "endsWith")?;
+        let search = interpreter.to_js_string(&argument(args, 0))?;
+        let units = string.to_utf16();
+        let end = clamped_position(interpreter, &argument(args, 1), units.len(), units.len())?;
+
+        Ok(Value::Boolean(units[..end].ends_with(&search.to_utf16())))
+    });
+
+    define_method(prototype, function_prototype, "split", 2, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "split")?;
+
+        let limit = match argument(args, 1) {
+            Value::None => u32::MAX,
+            limit => interpreter.to_uint32(&limit)?,
+        } as usize;
+
+        if let Some(regex) = regexp_of(&argument(args, 0)) {
+            let parts = match limit {
+                0 => Vec::new(),
+                _ => split_regexp(interpreter, &regex, &string, limit)?,
+            };
+
+            interpreter.reserve_object()?;
+            return Ok(interpreter.create_array(parts));
+        }
+
+        let separator = match argument(args, 0) {
+            Value::None => None,
+            separator => Some(interpreter.to_js_string(&separator)?),
+        };
+
+        let parts = match separator {
+            _ if limit == 0 => Vec::new(),
+            None => vec![Value::StringLiteral(string)],
+            Some(separator) => {
+                interpreter.step_by(string.len())?;
+                split(interpreter, &string, &separator, limit)?
+            },
+        };
+
+        interpreter.reserve_object()?;
+        Ok(interpreter.create_array(parts))
+    });
+
+    define_method(prototype, function_prototype, "trim", 0, |interpreter, this, _| {
+        let string = coerce_this(interpreter, this, "trim")?;
+        Ok(Value::StringLiteral(trim(&string, true, true)))
+    });
+
+    define_method(prototype, function_prototype, "trimStart", 0, |interpreter, this, _| {
+        let string = coerce_this(interpreter, this, "trimStart")?;
+        Ok(Value::StringLiteral(trim(&string, true, false)))
+    });
+
+    define_method(prototype, function_prototype, "trimEnd", 0, |interpreter, this, _| {
+        let string = coerce_this(interpreter, this, "trimEnd")?;
+        Ok(Value::StringLiteral(trim(&string, false, true)))
+    });
+
+    define_method(prototype, function_prototype, "padStart", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "padStart")?;
+        pad(interpreter, string, args, true)
+    });
+
+    define_method(prototype, function_prototype, "padEnd", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "padEnd")?;
+        pad(interpreter, string, args, false)
+    });
+
+    define_method(prototype, function_prototype, "repeat", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "repeat")?;
+        let count = to_integer(interpreter, &argument(args, 0))?;
+
+        if count < 0.0 || count.is_infinite() {
+            let message = format!("Invalid count value: {}", argument(args, 0).to_display_string());
+            return Err(interpreter.throw_error(ErrorKind::RangeError, message));
+        }
+
+        let units = string.to_utf16();
+        let length = units.len() as f64 * count;
+
+        if length > MAX_LENGTH as f64 {
+            return Err(interpreter.throw_error(ErrorKind::RangeError, "Invalid string length"));
+        }
+
+        interpreter.reserve(string.len() * count as usize)?;
+        interpreter.step_by(length as usize)?;
+        Ok(Value::StringLiteral(JsString::from_utf16(&cycle(interpreter, &units, length as usize)?)))
+    });
+
+    define_method(prototype, function_prototype, "match", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "match")?;
+        let (regexp, regex) = regexp_argument(interpreter, &argument(args, 0), "")?;
+
+        if !regex.flags().global {
+            return exec(interpreter, &regexp, &string);
+        }
+
+        let units = string.to_utf16();
+        let matches = exec_all(interpreter, &regexp, &units)?;
+
+        if matches.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        let mut matched = Vec::new();
+        let mut held = 0;
+
+        for whole in matches.into_iter().filter_map(|captures| captures[0].clone()) {
+            reserve_part(interpreter, &mut held, Value::StringLiteral(JsString::from_utf16(&units[whole])), &mut matched)?;
+        }
+
+        interpreter.reserve_object()?;
+        Ok(interpreter.create_array(matched))
+    });
+
+    define_method(prototype, function_prototype, "matchAll", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "matchAll")?;
+
+        // the iterator works on a copy, so that matching doesn't move the `lastIndex` of the original
+        let (regexp, regex) = match as_regexp(&argument(args, 0)) {
+            Some((_, regex)) if !regex.flags().global => {
+                let message = "String.prototype.matchAll called with a non-global RegExp argument";
+                return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+            },
+            Some((original, regex)) => {
+                let last_index = interpreter.get_property(&Value::Object(original), "lastIndex")?;
+                let copy = create(interpreter, regex.source().clone(), &regex.flags().to_string())?;
+                interpreter.set_property(&copy, "lastIndex", last_index)?;
+                as_regexp(&copy).expect("create makes a regular expression")
+            },
+            None => regexp_argument(interpreter, &argument(args, 0), "g")?,
+        };
+
+        Ok(create_iterator(interpreter, IteratorState::RegExp(regexp, string, regex.flags().global)))
+    });
+
+    define_method(prototype, function_prototype, "search", 1, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "search")?;
+        let (regexp, _) = regexp_argument(interpreter, &argument(args, 0), "")?;
+
+        // the search starts from the beginning, and leaves `lastIndex` as it was
+        let previous = interpreter.get_property(&Value::Object(regexp.clone()), "lastIndex")?;
+        set_last_index(interpreter, &regexp, 0)?;
+        let found = exec_captures(interpreter, &regexp, &string.to_utf16())?;
+        interpreter.set_property(&Value::Object(regexp), "lastIndex", previous)?;
+
+        let index = found.and_then(|captures| captures[0].clone()).map_or(-1.0, |whole| whole.start as f64);
+        Ok(Value::Float(index))
+    });
+
+    define_method(prototype, function_prototype, "replace", 2, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "replace")?;
+        let units = string.to_utf16();
+
+        if let Some((regexp, regex)) = as_regexp(&argument(args, 0)) {
+            let matches = regexp_matches(interpreter, &regexp, &regex, &units)?;
+            return replace(interpreter, &string, &matches, Some(&regex), &argument(args, 1));
+        }
+
+        let search = interpreter.to_js_string(&argument(args, 0))?.to_utf16().into_owned();
+        let matches: Vec<Captures> = find(interpreter, &units, &search, 0)?.map(|found| vec![Some(found..found + search.len())]).into_iter().collect();
+
+        replace(interpreter, &string, &matches, None, &argument(args, 1))
+    });
+
+    define_method(prototype, function_prototype, "replaceAll", 2, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "replaceAll")?;
+        let units = string.to_utf16();
+
+        if let Some((regexp, regex)) = as_regexp(&argument(args, 0)) {
+            if !regex.flags().global {
+                return Err(interpreter.throw_error(ErrorKind::TypeError, "replaceAll must be called with a global RegExp"));
+            }
+
+            let matches = regexp_matches(interpreter, &regexp, &regex, &units)?;
+            return replace(interpreter, &string, &matches, Some(&regex), &argument(args, 1));
+        }
+
+        let search = interpreter.to_js_string(&argument(args, 0))?.to_utf16().into_owned();
+
+        // an empty search matches between every code unit, and at both ends
+        let mut matches = Vec::new();
+        let mut position = find(interpreter, &units, &search, 0)?;
+
+        while let Some(found) = position {
+            if matches.len().is_multiple_of(ITERATIONS_PER_CHECK) {
+                interpreter.check_limits()?;
+            }
+
+            matches.push(vec![Some(found..found + search.len())]);
+            position = find(interpreter, &units, &search, found + search.len().max(1))?;
+        }
+
+        replace(interpreter, &string, &matches, None, &argument(args, 1))
+    });
+
+    define_method(prototype, function_prototype, "toUpperCase", 0, |interpreter, this, _| {
+        let string = coerce_this(interpreter, this, "toUpperCase")?;
+        interpreter.step_by(string.len())?;
+        Ok(Value::StringLiteral(map_text(&string, str::to_uppercase)))
+    });
+
+    define_method(prototype, function_prototype, "toLowerCase", 0, |interpreter, this, _| {
+        let string = coerce_this(interpreter, this, "toLowerCase")?;
+        interpreter.step_by(string.len())?;
+        Ok(Value::StringLiteral(map_text(&string, str::to_lowercase)))
+    });
+
+    define_method(prototype, function_prototype, "normalize", 0, |interpreter, this, args| {
+        let string = coerce_this(interpreter, this, "normalize")?;
+
+        let form = match argument(args, 0) {
+            Value::None => Some(Form::Nfc),
+            form => Form::from_name(&interpreter.to_js_string(&form)?),
+        };
+
+        let Some(form) = form else {
+            return Err(interpreter.throw_error(ErrorKind::RangeError, "The normalization form should be one of NFC, NFD, NFKC, NFKD."));
+        };
+
+        interpreter.step_by(string.len())?;
+        Ok(Value::StringLiteral(map_text(&string, |text| normalize(text, form))))
+    });
+
+    define_symbol_method(prototype, function_prototype, &intrinsics.symbol_iterator, 0, |interpreter, this, _| {
+        let string = coerce_this(interpreter, this, "[Symbol.iterator]")?;
+        Ok(create_iterator(interpreter, IteratorState::String(string, 0)))
+    });
+
+    scope.borrow_mut().define("String".to_string(), Value::Object(constructor));
+}
+
+fn this_string(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<JsString> {
+    match this {
+        Value::StringLiteral(string) => Ok(string.clone()),
+        _ => {
+            let message = format!("{} requires that 'this' be a String", method);
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        }
+    }
+}
+
+/// `this` as a string, for the methods that work on any value but null and undefined.
+fn coerce_this(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<JsString> {
+    match this {
+        Value::Null | Value::None => {
+            let message = format!("String.prototype.{} called on null or undefined", method);
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        },
+        _ => interpreter.to_js_string(this),
+    }
+}
+
+/// The value as a `RegExp` object along with its regular expression, if it's one.
+fn as_regexp(value: &Value) -> Option<(ObjectRef, Rc<Regex>)> {
+    match value {
+        Value::Object(object) => regexp_of(value).map(|regex| (object.clone(), regex)),
+        _ => None,
+    }
+}
+
+/// The regular expression that `match`, `matchAll` and `search` use for their argument, which is
+/// compiled with `flags` when it isn't one already.
+fn regexp_argument(interpreter: &mut Interpreter, value: &Value, flags: &str) -> EvalResult<(ObjectRef, Rc<Regex>)> {
+    if let Some(found) = as_regexp(value) {
+        return Ok(found);
+    }
+
+    let source = match value {
+        Value::None => JsString::default(),
+        value => interpreter.to_js_string(value)?,
+    };
+
+    let regexp = create(interpreter, source, flags)?;
+    Ok(as_regexp(&regexp).expect("create makes a regular expression"))
+}
+
+/// The matches that `replace` replaces: every one for a global regular expression, otherwise the
+/// first.
+fn regexp_matches(interpreter: &mut Interpreter, regexp: &ObjectRef, regex: &Regex, units: &[u16]) -> EvalResult<Vec<Captures>> {
+    match regex.flags().global {
+        true => exec_all(interpreter, regexp, units),
+        false => Ok(exec_captures(interpreter, regexp, units)?.into_iter().collect()),
+    }
+}
+
+fn reject_regexp(interpreter: &mut Interpreter, value: &Value, method: &str) -> EvalResult<()> {
+    match regexp_of(value) {
+        Some(_) => {
+            let message = format!("First argument to String.prototype.{} must not be a regular expression", method);
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        },
+        None => Ok(()),
+    }
+}
+
+/// `ToIntegerOrInfinity`, which truncates and treats NaN as 0.
+fn to_integer(interpreter: &mut Interpreter, value: &Value) -> EvalResult<f64> {
+    let number = interpreter.to_number(value)?;
+    Ok(if number.is_nan() { 0.0 } else { number.trunc() })
+}
+
+/// A position in a string of `length` code units, counted from the end when it's negative.
+fn relative_position(interpreter: &mut Interpreter, value: &Value, length: usize, default: usize) -> EvalResult<usize> {
+    if *value == Value::None {
+        return Ok(default);
+    }
+
+    let position = to_integer(interpreter, value)?;
+    let position = if position < 0.0 { length as f64 + position } else { position };
+    Ok(position.clamp(0.0, length as f64) as usize)
+}
+
+/// A position in a string of `length` code units, where negative ones are the start.
+fn clamped_position(interpreter: &mut Interpreter, value: &Value, length: usize, default: usize) -> EvalResult<usize> {
+    if *value == Value::None {
+        return Ok(default);
+    }
+
+    Ok(to_integer(interpreter, value)?.clamp(0.0, length as f64) as usize)
+}
+
+/// Where `search` first occurs in `units` at or after `start`, looking at the limits as it goes.
+fn find(interpreter: &mut Interpreter, units: &[u16], search: &[u16], start: usize) -> EvalResult<Option<usize>> {
+    if start > units.len() || search.len() > units.len() {
+        return Ok(None);
+    }
+
+    let stride = search_stride(search);
+
+    for (i, index) in (start..=units.len() - search.len()).enumerate() {
+        if (i + 1).is_multiple_of(stride) {
+            interpreter.check_limits()?;
+        }
+
+        if units[index..].starts_with(search) {
+            return Ok(Some(index));
+        }
+    }
+
+    Ok(None)
+}
+
+/// How many positions a search for `search` tries between looking at the limits, since trying
+/// each can compare every code unit of it.
+fn search_stride(search: &[u16]) -> usize {
+    (ITERATIONS_PER_CHECK / search.len().max(1)).max(1)
+}
+
+/// `length` code units of `units` over and over, copied a chunk at a time so that the limits are
+/// looked at as it goes.
+fn cycle(interpreter: &mut Interpreter, units: &[u16], length: usize) -> EvalResult<Vec<u16>> {
+    let mut result = Vec::with_capacity(length);
+    result.extend(units.iter().copied().take(length));
+
+    while !units.is_empty() && result.len() < length {
+        interpreter.check_limits()?;
+
+        // what's there so far is whole copies of `units`, so copying from the start carries on
+        let copies = (result.len().min(ITERATIONS_PER_CHECK) / units.len()).max(1);
+        let chunk = (copies * units.len()).min(length - result.len());
+        result.extend_from_within(..chunk);
+    }
+
+    Ok(result)
+}
+
+/// Splits around `separator`, charging for the parts and the array slots they'll take up.
+fn split(interpreter: &mut Interpreter, string: &JsString, separator: &JsString, limit: usize) -> EvalResult<Vec<Value>> {
+    let units = string.to_utf16();
+    let separator = separator.to_utf16();
+
+    // an empty separator splits between every code unit
+    if separator.is_empty() {
+        let count = units.len().min(limit);
+        interpreter.reserve(count * std::mem::size_of::<Value>() + string.size())?;
+        let mut parts = Vec::with_capacity(count);
+
+        for unit in units.iter().take(limit) {
+            if parts.len().is_multiple_of(ITERATIONS_PER_CHECK) {
+                interpreter.check_limits()?;
+            }
+
+            parts.push(Value::StringLiteral(JsString::from_utf16(std::slice::from_ref(unit))));
+        }
+
+        return Ok(parts);
+    }
+
+    let part = |range: std::ops::Range<usize>| Value::StringLiteral(JsString::from_utf16(&units[range]));
+    let mut parts = Vec::new();
+    let (mut start, mut held) = (0, 0);
+
+    while let Some(found) = find(interpreter, &units, &separator, start)? {
+        if parts.len() == limit {
+            return Ok(parts);
+        }
+
+        if parts.len().is_multiple_of(ITERATIONS_PER_CHECK) {
+            interpreter.check_limits()?;
+        }
+
+        reserve_part(interpreter, &mut held, part(start..found), &mut parts)?;
+        start = found + separator.len();
+    }
+
+    if parts.len() < limit {
+        reserve_part(interpreter, &mut held, part(start..units.len()), &mut parts)?;
+    }
+
+    Ok(parts)
+}
+
+/// Adds `part` to the elements of an array about to be made, reserving it and its slot on top of
+/// the `held` bytes reserved for those before it.
+fn reserve_part(interpreter: &mut Interpreter, held: &mut usize, part: Value, parts: &mut Vec<Value>) -> EvalResult<()> {
+    let bytes = std::mem::size_of::<Value>() + value_size(&part);
+    interpreter.reserve_held(*held, bytes)?;
+    *held += bytes;
+    parts.push(part);
+    Ok(())
+}
+
+/// Splits around the matches of `regex`, which also adds what their groups captured. An empty
+/// match where the last part ended is skipped, so that the parts aren't empty.
+fn split_regexp(interpreter: &mut Interpreter, regex: &Regex, string: &JsString, limit: usize) -> EvalResult<Vec<Value>> {
+    let units = string.to_utf16();
+    let part = |range: std::ops::Range<usize>| Value::StringLiteral(JsString::from_utf16(&units[range]));
+
+    if units.is_empty() {
+        let found = regex.find_from(&units, 0, true, &mut |steps| interpreter.step_by(steps))?;
+        return Ok(if found.is_some() { Vec::new() } else { vec![Value::StringLiteral(string.clone())] });
+    }
+
+    let mut parts = Vec::new();
+    let (mut end, mut position, mut held) = (0, 0, 0);
+
+    while position < units.len() {
+        let Some(captures) = regex.find_from(&units, position, false, &mut |steps| interpreter.step_by(steps))? else {
+            break;
+        };
+
+        let whole = captures[0].clone().unwrap_or_default();
+
+        if whole.start >= units.len() {
+            break;
+        }
+
+        if whole.end == end {
+            position = advance(&units, whole.start, regex.flags().unicode);
+            continue;
+        }
+
+        reserve_part(interpreter, &mut held, part(end..whole.start), &mut parts)?;
+
+        for capture in &captures[1..] {
+            if parts.len() == limit {
+                return Ok(parts);
+            }
+
+            reserve_part(interpreter, &mut held, capture.clone().map_or(Value::None, part), &mut parts)?;
+        }
+
+        if parts.len() == limit {
+            return Ok(parts);
+        }
+
+        (end, position) = (whole.end, whole.end);
+    }
+
+    reserve_part(interpreter, &mut held, part(end..units.len()), &mut parts)?;
+    Ok(parts)
+}
+
+/// Whitespace and line terminators as JavaScript defines them, which unlike Unicode's include the
+/// byte order mark but not the next line character.
+fn is_whitespace(unit: u16) -> bool {
+    match char::from_u32(unit as u32) {
+        Some('\u{feff}') => true,
+        Some('\u{85}') => false,
+        Some(c) => c.is_whitespace(),
+        None => false,
+    }
+}
+
+fn trim(string: &JsString, start: bool, end: bool) -> JsString {
+    let units = string.to_utf16();
+    let mut range = 0..units.len();
+
+    if start {
+        range.start = units.iter().position(|unit| !is_whitespace(*unit)).unwrap_or(units.len());
+    }
+
+    if end {
+        range.end = units.iter().rposition(|unit| !is_whitespace(*unit)).map_or(range.start, |index| index + 1).max(range.start);
+    }
+
+    string.substring(range.start, range.end)
+}
+
+fn pad(interpreter: &mut Interpreter, string: JsString, args: &[Value], at_start: bool) -> EvalResult {
+    let target = to_integer(interpreter, &argument(args, 0))?;
+    let length = string.utf16_len();
+
+    let filler = match argument(args, 1) {
+        Value::None => JsString::from(" "),
+        filler => interpreter.to_js_string(&filler)?,
+    };
+
+    if target <= length as f64 || filler.is_empty() {
+        return Ok(Value::StringLiteral(string));
+    }
+
+    if target > MAX_LENGTH as f64 {
+        return Err(interpreter.throw_error(ErrorKind::RangeError, "Invalid string length"));
+    }
+
+    let missing = target as usize - length;
+    interpreter.step_by(missing)?;
+    interpreter.reserve(missing * 2)?;
+
+    let filler = JsString::from_utf16(&cycle(interpreter, &filler.to_utf16(), missing)?);
+
+    match at_start {
+        true => Ok(Value::StringLiteral(filler.concat(&string))),
+        false => Ok(Value::StringLiteral(string.concat(&filler))),
+    }
+}
+
+/// Replaces each of `matches` with what `replacement` gives for it: its return value when it's a
+/// function, otherwise the string with its `$` patterns filled in. The names of the groups come
+/// from `regex`, for the matches of a regular expression.
+fn replace(interpreter: &mut Interpreter, string: &JsString, matches: &[Captures], regex: Option<&Regex>, replacement: &Value) -> EvalResult {
+    let units = string.to_utf16();
+    let names = regex.map_or(&[][..], |regex| regex.group_names());
+
+    let template = match replacement.is_callable() {
+        true => None,
+        false => Some(interpreter.to_js_string(replacement)?.to_utf16().into_owned()),
+    };
+
+    let mut result = Vec::with_capacity(units.len());
+    let mut end = 0;
+
+    for captures in matches {
+        let whole = captures[0].clone().unwrap_or_default();
+        result.extend_from_slice(&units[end..whole.start]);
+
+        let replaced = match &template {
+            Some(template) => substitute(template, &units, captures, names),
+            None => {
+                let mut args: Vec<Value> = captures.iter()
+                    .map(|capture| capture.clone().map_or(Value::None, |range| Value::StringLiteral(JsString::from_utf16(&units[range]))))
+                    .collect();
+
+                // named groups add an object with what each of them captured
+                let groups = match regex {
+                    Some(regex) if !names.is_empty() => Some(named_groups(interpreter, regex, &args)),
+                    _ => None,
+                };
+
+                args.extend([Value::Float(whole.start as f64), Value::StringLiteral(string.clone())]);
+                args.extend(groups);
+
+                let replaced = interpreter.call_function(replacement, Value::None, &args)?;
+                interpreter.to_js_string(&replaced)?.to_utf16().into_owned()
+            },
+        };
+
+        result.extend_from_slice(&replaced);
+        end = whole.end;
+    }
+
+    result.extend_from_slice(&units[end..]);
+    interpreter.reserve(result.len() * 2)?;
+    interpreter.step_by(units.len() + result.len())?;
+
+    Ok(Value::StringLiteral(JsString::from_utf16(&result)))
+}
+
+/// Fills in the `$` patterns of a replacement template for a match: `$$` for a dollar sign, `$&`
+/// for the match, `` $` `` and `$'` for what comes before and after it, `$1` to `$99` for what the
+/// groups captured and, when there are named groups, `$<name>` for those.
+fn substitute(template: &[u16], units: &[u16], captures: &Captures, names: &[(String, usize)]) -> Vec<u16> {
+    let whole = captures[0].clone().unwrap_or_default();
+    let captured = |group: usize| captures[group].clone().map_or(&[][..], |range| &units[range]);
+    let digit = |index: usize| template.get(index).and_then(|unit| char::from_u32(*unit as u32)?.to_digit(10)).map(|digit| digit as usize);
+    let is_group = |group: &usize| (1..captures.len()).contains(group);
+
+    let mut result = Vec::with_capacity(template.len());
+    let mut i = 0;
+
+    while i < template.len() {
+        let next = template.get(i + 1).and_then(|unit| char::from_u32(*unit as u32));
+
+        if template[i] != b'$' as u16 {
+            result.push(template[i]);
+            i += 1;
+            continue;
+        }
+
+        let length = match next {
+            Some('$') => {
+                result.push(b'$' as u16);
+                2
+            },
+            Some('&') => {
+                result.extend_from_slice(&units[whole.clone()]);
+                2
+            },
+            Some('`') => {
+                result.extend_from_slice(&units[..whole.start]);
+                2
+            },
+            Some('\'') => {
+                result.extend_from_slice(&units[whole.end..]);
+                2
+            },
+            // two digits are read as one group number when there are that many groups
+            Some('0'..='9') => {
+                let first = digit(i + 1).unwrap_or_default();
+
+                match digit(i + 2).map(|second| first * 10 + second).filter(is_group) {
+                    Some(group) => {
+                        result.extend_from_slice(captured(group));
+                        3
+                    },
+                    None if is_group(&first) => {
+                        result.extend_from_slice(captured(first));
+                        2
+                    },
+                    None => {
+                        result.push(b'$' as u16);
+                        1
+                    },
+                }
+            },
+            Some('<') if !names.is_empty() => match template[i + 2..].iter().position(|unit| *unit == b'>' as u16) {
+                Some(name_length) => {
+                    let name = String::from_utf16_lossy(&template[i + 2..i + 2 + name_length]);
+
+                    if let Some((_, group)) = names.iter().find(|(group_name, _)| *group_name == name) {
+                        result.extend_from_slice(captured(*group));
+                    }
+
+                    name_length + 3
+                },
+                None => {
+                    result.push(b'$' as u16);
+                    1
+                },
+            },
+            _ => {
+                result.push(b'$' as u16);
+                1
+            },
+        };
+
+        i += length;
+    }
+
+    result
+}
+
+/// Applies `f` to the text of `string` between its lone surrogates, which stay as they are.
+fn map_text(string: &JsString, f: impl Fn(&str) -> String) -> JsString {
+    if string.is_well_formed() {
+        return f(string).into();
+    }
+
+    let mut units = Vec::new();
+    let mut text = String::new();
+
+    for c in char::decode_utf16(string.to_utf16().iter().copied()) {
+        match c {
+            Ok(c) => text.push(c),
+            Err(error) => {
+                units.extend(f(&text).encode_utf16());
+                units.push(error.unpaired_surrogate());
+                text.clear();
+            },
+        }
+    }
+
+    units.extend(f(&text).encode_utf16());
+    JsString::from_utf16(&units)
+}
@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+
+mod tables;
+
+use tables::{COMBINING_CLASSES, COMPOSITIONS, DECOMPOSITIONS};
+
+/// The forms `String.prototype.normalize` puts text in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl Form {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "NFC" => Some(Form::Nfc),
+            "NFD" => Some(Form::Nfd),
+            "NFKC" => Some(Form::Nfkc),
+            "NFKD" => Some(Form::Nfkd),
+            _ => None,
+        }
+    }
+}
+
+// Hangul syllables are made of a leading consonant, a vowel and an optional trailing consonant
+const SYLLABLE_BASE: u32 = 0xAC00;
+const LEADING_BASE: u32 = 0x1100;
+const VOWEL_BASE: u32 = 0x1161;
+const TRAILING_BASE: u32 = 0x11A7;
+const LEADING_COUNT: u32 = 19;
+const VOWEL_COUNT: u32 = 21;
+const TRAILING_COUNT: u32 = 28;
+const SYLLABLE_COUNT: u32 = LEADING_COUNT * VOWEL_COUNT * TRAILING_COUNT;
+
+/// Puts `text` in normalization form `form`, following the decompositions, combining classes and
+/// compositions of the Unicode Character Database.
+pub fn normalize(text: &str, form: Form) -> String {
+    let compatibility = matches!(form, Form::Nfkc | Form::Nfkd);
+    let mut chars = Vec::with_capacity(text.len());
+
+    for c in text.chars() {
+        decompose(c, compatibility, &mut chars);
+    }
+
+    reorder(&mut chars);
+
+    if matches!(form, Form::Nfc | Form::Nfkc) {
+        chars = compose(&chars);
+    }
+
+    chars.into_iter().collect()
+}
+
+fn decompose(c: char, compatibility: bool, chars: &mut Vec<char>) {
+    let code = c as u32;
+
+    if (SYLLABLE_BASE..SYLLABLE_BASE + SYLLABLE_COUNT).contains(&code) {
+        let index = code - SYLLABLE_BASE;
+        let trailing = index % TRAILING_COUNT;
+
+        chars.extend(char::from_u32(LEADING_BASE + index / (VOWEL_COUNT * TRAILING_COUNT)));
+        chars.extend(char::from_u32(VOWEL_BASE + index % (VOWEL_COUNT * TRAILING_COUNT) / TRAILING_COUNT));
+
+        if trailing != 0 {
+            chars.extend(char::from_u32(TRAILING_BASE + trailing));
+        }
+
+        return;
+    }
+
+    if let Ok(index) = DECOMPOSITIONS.binary_search_by_key(&c, |(c, _, _)| *c) {
+        let (_, compatibility_only, decomposed) = DECOMPOSITIONS[index];
+
+        if compatibility || !compatibility_only {
+            for c in decomposed.chars() {
+                decompose(c, compatibility, chars);
+            }
+
+            return;
+        }
+    }
+
+    chars.push(c);
+}
+
+/// The canonical combining class of `c`, which says which combining marks can be reordered.
+/// Everything else counts as a starter.
+fn combining_class(c: char) -> u8 {
+    let found = COMBINING_CLASSES.binary_search_by(|(start, end, _)| {
+        if *end < c {
+            Ordering::Less
+        } else if *start > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+
+    found.map_or(0, |index| COMBINING_CLASSES[index].2)
+}
+
+/// Sorts each run of combining marks by their combining class, keeping the order of marks of the
+/// same class.
+fn reorder(chars: &mut [char]) {
+    let mut start = 0;
+
+    while start < chars.len() {
+        let length = chars[start..].iter().take_while(|c| combining_class(**c) != 0).count();
+
+        if length > 1 {
+            chars[start..start + length].sort_by_key(|c| combining_class(*c));
+        }
+
+        start += length.max(1);
+    }
+}
+
+fn compose(chars: &[char]) -> Vec<char> {
+    let mut composed: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    // the class of the last mark kept after the starter, which blocks marks of the same or a
+    // lower class from composing with it
+    let mut last_class = 0;
+
+    for &c in chars {
+        let class = combining_class(c);
+
+        if let Some(index) = starter {
+            let adjacent = composed.len() == index + 1;
+
+            if adjacent || (last_class != 0 && last_class < class) {
+                if let Some(pair) = compose_pair(composed[index], c) {
+                    composed[index] = pair;
+                    continue;
+                }
+            }
+        }
+
+        match class {
+            0 => {
+                starter = Some(composed.len());
+                last_class = 0;
+            },
+            class => last_class = class,
+        }
+
+        composed.push(c);
+    }
+
+    composed
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first_code, second_code) = (first as u32, second as u32);
+
+    if (LEADING_BASE..LEADING_BASE + LEADING_COUNT).contains(&first_code) && (VOWEL_BASE..VOWEL_BASE + VOWEL_COUNT).contains(&second_code) {
+        let index = (first_code - LEADING_BASE) * VOWEL_COUNT + (second_code - VOWEL_BASE);
+        return char::from_u32(SYLLABLE_BASE + index * TRAILING_COUNT);
+    }
+
+    let syllable = first_code.wrapping_sub(SYLLABLE_BASE);
+
+    if syllable < SYLLABLE_COUNT && syllable % TRAILING_COUNT == 0 && (TRAILING_BASE + 1..TRAILING_BASE + TRAILING_COUNT).contains(&second_code) {
+        return char::from_u32(first_code + second_code - TRAILING_BASE);
+    }
+
+    let index = COMPOSITIONS.binary_search_by_key(&(first, second), |(first, second, _)| (*first, *second)).ok()?;
+    Some(COMPOSITIONS[index].2)
+}
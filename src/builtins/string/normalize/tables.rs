@@ -0,0 +1,7132 @@
+// Generated from the Unicode Character Database 14.0.0.
+
+/// The ranges of characters with a non-zero canonical combining class, and their class.
+pub static COMBINING_CLASSES: [(char, char, u8); 382] = [
+    ('\u{300}', '\u{314}', 230),
+    ('\u{315}', '\u{315}', 232),
+    ('\u{316}', '\u{319}', 220),
+    ('\u{31a}', '\u{31a}', 232),
+    ('\u{31b}', '\u{31b}', 216),
+    ('\u{31c}', '\u{320}', 220),
+    ('\u{321}', '\u{322}', 202),
+    ('\u{323}', '\u{326}', 220),
+    ('\u{327}', '\u{328}', 202),
+    ('\u{329}', '\u{333}', 220),
+    ('\u{334}', '\u{338}', 1),
+    ('\u{339}', '\u{33c}', 220),
+    ('\u{33d}', '\u{344}', 230),
+    ('\u{345}', '\u{345}', 240),
+    ('\u{346}', '\u{346}', 230),
+    ('\u{347}', '\u{349}', 220),
+    ('\u{34a}', '\u{34c}', 230),
+    ('\u{34d}', '\u{34e}', 220),
+    ('\u{350}', '\u{352}', 230),
+    ('\u{353}', '\u{356}', 220),
+    ('\u{357}', '\u{357}', 230),
+    ('\u{358}', '\u{358}', 232),
+    ('\u{359}', '\u{35a}', 220),
+    ('\u{35b}', '\u{35b}', 230),
+    ('\u{35c}', '\u{35c}', 233),
+    ('\u{35d}', '\u{35e}', 234),
+    ('\u{35f}', '\u{35f}', 233),
+    ('\u{360}', '\u{361}', 234),
+    ('\u{362}', '\u{362}', 233),
+    ('\u{363}', '\u{36f}', 230),
+    ('\u{483}', '\u{487}', 230),
+    ('\u{591}', '\u{591}', 220),
+    ('\u{592}', '\u{595}', 230),
+    ('\u{596}', '\u{596}', 220),
+    ('\u{597}', '\u{599}', 230),
+    ('\u{59a}', '\u{59a}', 222),
+    ('\u{59b}', '\u{59b}', 220),
+    ('\u{59c}', '\u{5a1}', 230),
+    ('\u{5a2}', '\u{5a7}', 220),
+    ('\u{5a8}', '\u{5a9}', 230),
+    ('\u{5aa}', '\u{5aa}', 220),
+    ('\u{5ab}', '\u{5ac}', 230),
+    ('\u{5ad}', '\u{5ad}', 222),
+    ('\u{5ae}', '\u{5ae}', 228),
+    ('\u{5af}', '\u{5af}', 230),
+    ('\u{5b0}', '\u{5b0}', 10),
+    ('\u{5b1}', '\u{5b1}', 11),
+    ('\u{5b2}', '\u{5b2}', 12),
+    ('\u{5b3}', '\u{5b3}', 13),
+    ('\u{5b4}', '\u{5b4}', 14),
+    ('\u{5b5}', '\u{5b5}', 15),
+    ('\u{5b6}', '\u{5b6}', 16),
+    ('\u{5b7}', '\u{5b7}', 17),
+    ('\u{5b8}', '\u{5b8}', 18),
+    ('\u{5b9}', '\u{5ba}', 19),
+    ('\u{5bb}', '\u{5bb}', 20),
+    ('\u{5bc}', '\u{5bc}', 21),
+    ('\u{5bd}', '\u{5bd}', 22),
+    ('\u{5bf}', '\u{5bf}', 23),
+    ('\u{5c1}', '\u{5c1}', 24),
+    ('\u{5c2}', '\u{5c2}', 25),
+    ('\u{5c4}', '\u{5c4}', 230),
+    ('\u{5c5}', '\u{5c5}', 220),
+    ('\u{5c7}', '\u{5c7}', 18),
+    ('\u{610}', '\u{617}', 230),
+    ('\u{618}', '\u{618}', 30),
+    ('\u{619}', '\u{619}', 31),
+    ('\u{61a}', '\u{61a}', 32),
+    ('\u{64b}', '\u{64b}', 27),
+    ('\u{64c}', '\u{64c}', 28),
+    ('\u{64d}', '\u{64d}', 29),
+    ('\u{64e}', '\u{64e}', 30),
+    ('\u{64f}', '\u{64f}', 31),
+    ('\u{650}', '\u{650}', 32),
+    ('\u{651}', '\u{651}', 33),
+    ('\u{652}', '\u{652}', 34),
+    ('\u{653}', '\u{654}', 230),
+    ('\u{655}', '\u{656}', 220),
+    ('\u{657}', '\u{65b}', 230),
+    ('\u{65c}', '\u{65c}', 220),
+    ('\u{65d}', '\u{65e}', 230),
+    ('\u{65f}', '\u{65f}', 220),
+    ('\u{670}', '\u{670}', 35),
+    ('\u{6d6}', '\u{6dc}', 230),
+    ('\u{6df}', '\u{6e2}', 230),
+    ('\u{6e3}', '\u{6e3}', 220),
+    ('\u{6e4}', '\u{6e4}', 230),
+    ('\u{6e7}', '\u{6e8}', 230),
+    ('\u{6ea}', '\u{6ea}', 220),
+    ('\u{6eb}', '\u{6ec}', 230),
+    ('\u{6ed}', '\u{6ed}', 220),
+    ('\u{711}', '\u{711}', 36),
+    ('\u{730}', '\u{730}', 230),
+    ('\u{731}', '\u{731}', 220),
+    ('\u{732}', '\u{733}', 230),
+    ('\u{734}', '\u{734}', 220),
+    ('\u{735}', '\u{736}', 230),
+    ('\u{737}', '\u{739}', 220),
+    ('\u{73a}', '\u{73a}', 230),
+    ('\u{73b}', '\u{73c}', 220),
+    ('\u{73d}', '\u{73d}', 230),
+    ('\u{73e}', '\u{73e}', 220),
+    ('\u{73f}', '\u{741}', 230),
+    ('\u{742}', '\u{742}', 220),
+    ('\u{743}', '\u{743}', 230),
+    ('\u{744}', '\u{744}', 220),
+    ('\u{745}', '\u{745}', 230),
+    ('\u{746}', '\u{746}', 220),
+    ('\u{747}', '\u{747}', 230),
+    ('\u{748}', '\u{748}', 220),
+    ('\u{749}', '\u{74a}', 230),
+    ('\u{7eb}', '\u{7f1}', 230),
+    ('\u{7f2}', '\u{7f2}', 220),
+    ('\u{7f3}', '\u{7f3}', 230),
+    ('\u{7fd}', '\u{7fd}', 220),
+    ('\u{816}', '\u{819}', 230),
+    ('\u{81b}', '\u{823}', 230),
+    ('\u{825}', '\u{827}', 230),
+    ('\u{829}', '\u{82d}', 230),
+    ('\u{859}', '\u{85b}', 220),
+    ('\u{898}', '\u{898}', 230),
+    ('\u{899}', '\u{89b}', 220),
+    ('\u{89c}', '\u{89f}', 230),
+    ('\u{8ca}', '\u{8ce}', 230),
+    ('\u{8cf}', '\u{8d3}', 220),
+    ('\u{8d4}', '\u{8e1}', 230),
+    ('\u{8e3}', '\u{8e3}', 220),
+    ('\u{8e4}', '\u{8e5}', 230),
+    ('\u{8e6}', '\u{8e6}', 220),
+    ('\u{8e7}', '\u{8e8}', 230),
+    ('\u{8e9}', '\u{8e9}', 220),
+    ('\u{8ea}', '\u{8ec}', 230),
+    ('\u{8ed}', '\u{8ef}', 220),
+    ('\u{8f0}', '\u{8f0}', 27),
+    ('\u{8f1}', '\u{8f1}', 28),
+    ('\u{8f2}', '\u{8f2}', 29),
+    ('\u{8f3}', '\u{8f5}', 230),
+    ('\u{8f6}', '\u{8f6}', 220),
+    ('\u{8f7}', '\u{8f8}', 230),
+    ('\u{8f9}', '\u{8fa}', 220),
+    ('\u{8fb}', '\u{8ff}', 230),
+    ('\u{93c}', '\u{93c}', 7),
+    ('\u{94d}', '\u{94d}', 9),
+    ('\u{951}', '\u{951}', 230),
+    ('\u{952}', '\u{952}', 220),
+    ('\u{953}', '\u{954}', 230),
+    ('\u{9bc}', '\u{9bc}', 7),
+    ('\u{9cd}', '\u{9cd}', 9),
+    ('\u{9fe}', '\u{9fe}', 230),
+    ('\u{a3c}', '\u{a3c}', 7),
+    ('\u{a4d}', '\u{a4d}', 9),
+    ('\u{abc}', '\u{abc}', 7),
+    ('\u{acd}', '\u{acd}', 9),
+    ('\u{b3c}', '\u{b3c}', 7),
+    ('\u{b4d}', '\u{b4d}', 9),
+    ('\u{bcd}', '\u{bcd}', 9),
+    ('\u{c3c}', '\u{c3c}', 7),
+    ('\u{c4d}', '\u{c4d}', 9),
+    ('\u{c55}', '\u{c55}', 84),
+    ('\u{c56}', '\u{c56}', 91),
+    ('\u{cbc}', '\u{cbc}', 7),
+    ('\u{ccd}', '\u{ccd}', 9),
+    ('\u{d3b}', '\u{d3c}', 9),
+    ('\u{d4d}', '\u{d4d}', 9),
+    ('\u{dca}', '\u{dca}', 9),
+    ('\u{e38}', '\u{e39}', 103),
+    ('\u{e3a}', '\u{e3a}', 9),
+    ('\u{e48}', '\u{e4b}', 107),
+    ('\u{eb8}', '\u{eb9}', 118),
+    ('\u{eba}', '\u{eba}', 9),
+    ('\u{ec8}', '\u{ecb}', 122),
+    ('\u{f18}', '\u{f19}', 220),
+    ('\u{f35}', '\u{f35}', 220),
+    ('\u{f37}', '\u{f37}', 220),
+    ('\u{f39}', '\u{f39}', 216),
+    ('\u{f71}', '\u{f71}', 129),
+    ('\u{f72}', '\u{f72}', 130),
+    ('\u{f74}', '\u{f74}', 132),
+    ('\u{f7a}', '\u{f7d}', 130),
+    ('\u{f80}', '\u{f80}', 130),
+    ('\u{f82}', '\u{f83}', 230),
+    ('\u{f84}', '\u{f84}', 9),
+    ('\u{f86}', '\u{f87}', 230),
+    ('\u{fc6}', '\u{fc6}', 220),
+    ('\u{1037}', '\u{1037}', 7),
+    ('\u{1039}', '\u{103a}', 9),
+    ('\u{108d}', '\u{108d}', 220),
+    ('\u{135d}', '\u{135f}', 230),
+    ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9),
+    ('\u{17d2}', '\u{17d2}', 9),
+    ('\u{17dd}', '\u{17dd}', 230),
+    ('\u{18a9}', '\u{18a9}', 228),
+    ('\u{1939}', '\u{1939}', 222),
+    ('\u{193a}', '\u{193a}', 230),
+    ('\u{193b}', '\u{193b}', 220),
+    ('\u{1a17}', '\u{1a17}', 230),
+    ('\u{1a18}', '\u{1a18}', 220),
+    ('\u{1a60}', '\u{1a60}', 9),
+    ('\u{1a75}', '\u{1a7c}', 230),
+    ('\u{1a7f}', '\u{1a7f}', 220),
+    ('\u{1ab0}', '\u{1ab4}', 230),
+    ('\u{1ab5}', '\u{1aba}', 220),
+    ('\u{1abb}', '\u{1abc}', 230),
+    ('\u{1abd}', '\u{1abd}', 220),
+    ('\u{1abf}', '\u{1ac0}', 220),
+    ('\u{1ac1}', '\u{1ac2}', 230),
+    ('\u{1ac3}', '\u{1ac4}', 220),
+    ('\u{1ac5}', '\u{1ac9}', 230),
+    ('\u{1aca}', '\u{1aca}', 220),
+    ('\u{1acb}', '\u{1ace}', 230),
+    ('\u{1b34}', '\u{1b34}', 7),
+    ('\u{1b44}', '\u{1b44}', 9),
+    ('\u{1b6b}', '\u{1b6b}', 230),
+    ('\u{1b6c}', '\u{1b6c}', 220),
+    ('\u{1b6d}', '\u{1b73}', 230),
+    ('\u{1baa}', '\u{1bab}', 9),
+    ('\u{1be6}', '\u{1be6}', 7),
+    ('\u{1bf2}', '\u{1bf3}', 9),
+    ('\u{1c37}', '\u{1c37}', 7),
+    ('\u{1cd0}', '\u{1cd2}', 230),
+    ('\u{1cd4}', '\u{1cd4}', 1),
+    ('\u{1cd5}', '\u{1cd9}', 220),
+    ('\u{1cda}', '\u{1cdb}', 230),
+    ('\u{1cdc}', '\u{1cdf}', 220),
+    ('\u{1ce0}', '\u{1ce0}', 230),
+    ('\u{1ce2}', '\u{1ce8}', 1),
+    ('\u{1ced}', '\u{1ced}', 220),
+    ('\u{1cf4}', '\u{1cf4}', 230),
+    ('\u{1cf8}', '\u{1cf9}', 230),
+    ('\u{1dc0}', '\u{1dc1}', 230),
+    ('\u{1dc2}', '\u{1dc2}', 220),
+    ('\u{1dc3}', '\u{1dc9}', 230),
+    ('\u{1dca}', '\u{1dca}', 220),
+    ('\u{1dcb}', '\u{1dcc}', 230),
+    ('\u{1dcd}', '\u{1dcd}', 234),
+    ('\u{1dce}', '\u{1dce}', 214),
+    ('\u{1dcf}', '\u{1dcf}', 220),
+    ('\u{1dd0}', '\u{1dd0}', 202),
+    ('\u{1dd1}', '\u{1df5}', 230),
+    ('\u{1df6}', '\u{1df6}', 232),
+    ('\u{1df7}', '\u{1df8}', 228),
+    ('\u{1df9}', '\u{1df9}', 220),
+    ('\u{1dfa}', '\u{1dfa}', 218),
+    ('\u{1dfb}', '\u{1dfb}', 230),
+    ('\u{1dfc}', '\u{1dfc}', 233),
+    ('\u{1dfd}', '\u{1dfd}', 220),
+    ('\u{1dfe}', '\u{1dfe}', 230),
+    ('\u{1dff}', '\u{1dff}', 220),
+    ('\u{20d0}', '\u{20d1}', 230),
+    ('\u{20d2}', '\u{20d3}', 1),
+    ('\u{20d4}', '\u{20d7}', 230),
+    ('\u{20d8}', '\u{20da}', 1),
+    ('\u{20db}', '\u{20dc}', 230),
+    ('\u{20e1}', '\u{20e1}', 230),
+    ('\u{20e5}', '\u{20e6}', 1),
+    ('\u{20e7}', '\u{20e7}', 230),
+    ('\u{20e8}', '\u{20e8}', 220),
+    ('\u{20e9}', '\u{20e9}', 230),
+    ('\u{20ea}', '\u{20eb}', 1),
+    ('\u{20ec}', '\u{20ef}', 220),
+    ('\u{20f0}', '\u{20f0}', 230),
+    ('\u{2cef}', '\u{2cf1}', 230),
+    ('\u{2d7f}', '\u{2d7f}', 9),
+    ('\u{2de0}', '\u{2dff}', 230),
+    ('\u{302a}', '\u{302a}', 218),
+    ('\u{302b}', '\u{302b}', 228),
+    ('\u{302c}', '\u{302c}', 232),
+    ('\u{302d}', '\u{302d}', 222),
+    ('\u{302e}', '\u{302f}', 224),
+    ('\u{3099}', '\u{309a}', 8),
+    ('\u{a66f}', '\u{a66f}', 230),
+    ('\u{a674}', '\u{a67d}', 230),
+    ('\u{a69e}', '\u{a69f}', 230),
+    ('\u{a6f0}', '\u{a6f1}', 230),
+    ('\u{a806}', '\u{a806}', 9),
+    ('\u{a82c}', '\u{a82c}', 9),
+    ('\u{a8c4}', '\u{a8c4}', 9),
+    ('\u{a8e0}', '\u{a8f1}', 230),
+    ('\u{a92b}', '\u{a92d}', 220),
+    ('\u{a953}', '\u{a953}', 9),
+    ('\u{a9b3}', '\u{a9b3}', 7),
+    ('\u{a9c0}', '\u{a9c0}', 9),
+    ('\u{aab0}', '\u{aab0}', 230),
+    ('\u{aab2}', '\u{aab3}', 230),
+    ('\u{aab4}', '\u{aab4}', 220),
+    ('\u{aab7}', '\u{aab8}', 230),
+    ('\u{aabe}', '\u{aabf}', 230),
+    ('\u{aac1}', '\u{aac1}', 230),
+    ('\u{aaf6}', '\u{aaf6}', 9),
+    ('\u{abed}', '\u{abed}', 9),
+    ('\u{fb1e}', '\u{fb1e}', 26),
+    ('\u{fe20}', '\u{fe26}', 230),
+    ('\u{fe27}', '\u{fe2d}', 220),
+    ('\u{fe2e}', '\u{fe2f}', 230),
+    ('\u{101fd}', '\u{101fd}', 220),
+    ('\u{102e0}', '\u{102e0}', 220),
+    ('\u{10376}', '\u{1037a}', 230),
+    ('\u{10a0d}', '\u{10a0d}', 220),
+    ('\u{10a0f}', '\u{10a0f}', 230),
+    ('\u{10a38}', '\u{10a38}', 230),
+    ('\u{10a39}', '\u{10a39}', 1),
+    ('\u{10a3a}', '\u{10a3a}', 220),
+    ('\u{10a3f}', '\u{10a3f}', 9),
+    ('\u{10ae5}', '\u{10ae5}', 230),
+    ('\u{10ae6}', '\u{10ae6}', 220),
+    ('\u{10d24}', '\u{10d27}', 230),
+    ('\u{10eab}', '\u{10eac}', 230),
+    ('\u{10f46}', '\u{10f47}', 220),
+    ('\u{10f48}', '\u{10f4a}', 230),
+    ('\u{10f4b}', '\u{10f4b}', 220),
+    ('\u{10f4c}', '\u{10f4c}', 230),
+    ('\u{10f4d}', '\u{10f50}', 220),
+    ('\u{10f82}', '\u{10f82}', 230),
+    ('\u{10f83}', '\u{10f83}', 220),
+    ('\u{10f84}', '\u{10f84}', 230),
+    ('\u{10f85}', '\u{10f85}', 220),
+    ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9),
+    ('\u{1107f}', '\u{1107f}', 9),
+    ('\u{110b9}', '\u{110b9}', 9),
+    ('\u{110ba}', '\u{110ba}', 7),
+    ('\u{11100}', '\u{11102}', 230),
+    ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7),
+    ('\u{111c0}', '\u{111c0}', 9),
+    ('\u{111ca}', '\u{111ca}', 7),
+    ('\u{11235}', '\u{11235}', 9),
+    ('\u{11236}', '\u{11236}', 7),
+    ('\u{112e9}', '\u{112e9}', 7),
+    ('\u{112ea}', '\u{112ea}', 9),
+    ('\u{1133b}', '\u{1133c}', 7),
+    ('\u{1134d}', '\u{1134d}', 9),
+    ('\u{11366}', '\u{1136c}', 230),
+    ('\u{11370}', '\u{11374}', 230),
+    ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7),
+    ('\u{1145e}', '\u{1145e}', 230),
+    ('\u{114c2}', '\u{114c2}', 9),
+    ('\u{114c3}', '\u{114c3}', 7),
+    ('\u{115bf}', '\u{115bf}', 9),
+    ('\u{115c0}', '\u{115c0}', 7),
+    ('\u{1163f}', '\u{1163f}', 9),
+    ('\u{116b6}', '\u{116b6}', 9),
+    ('\u{116b7}', '\u{116b7}', 7),
+    ('\u{1172b}', '\u{1172b}', 9),
+    ('\u{11839}', '\u{11839}', 9),
+    ('\u{1183a}', '\u{1183a}', 7),
+    ('\u{1193d}', '\u{1193e}', 9),
+    ('\u{11943}', '\u{11943}', 7),
+    ('\u{119e0}', '\u{119e0}', 9),
+    ('\u{11a34}', '\u{11a34}', 9),
+    ('\u{11a47}', '\u{11a47}', 9),
+    ('\u{11a99}', '\u{11a99}', 9),
+    ('\u{11c3f}', '\u{11c3f}', 9),
+    ('\u{11d42}', '\u{11d42}', 7),
+    ('\u{11d44}', '\u{11d45}', 9),
+    ('\u{11d97}', '\u{11d97}', 9),
+    ('\u{16af0}', '\u{16af4}', 1),
+    ('\u{16b30}', '\u{16b36}', 230),
+    ('\u{16ff0}', '\u{16ff1}', 6),
+    ('\u{1bc9e}', '\u{1bc9e}', 1),
+    ('\u{1d165}', '\u{1d166}', 216),
+    ('\u{1d167}', '\u{1d169}', 1),
+    ('\u{1d16d}', '\u{1d16d}', 226),
+    ('\u{1d16e}', '\u{1d172}', 216),
+    ('\u{1d17b}', '\u{1d182}', 220),
+    ('\u{1d185}', '\u{1d189}', 230),
+    ('\u{1d18a}', '\u{1d18b}', 220),
+    ('\u{1d1aa}', '\u{1d1ad}', 230),
+    ('\u{1d242}', '\u{1d244}', 230),
+    ('\u{1e000}', '\u{1e006}', 230),
+    ('\u{1e008}', '\u{1e018}', 230),
+    ('\u{1e01b}', '\u{1e021}', 230),
+    ('\u{1e023}', '\u{1e024}', 230),
+    ('\u{1e026}', '\u{1e02a}', 230),
+    ('\u{1e130}', '\u{1e136}', 230),
+    ('\u{1e2ae}', '\u{1e2ae}', 230),
+    ('\u{1e2ec}', '\u{1e2ef}', 230),
+    ('\u{1e8d0}', '\u{1e8d6}', 220),
+    ('\u{1e944}', '\u{1e949}', 230),
+    ('\u{1e94a}', '\u{1e94a}', 7),
+];
+
+/// The decomposition of each character that has one, one level deep, and whether it is only a
+/// compatibility decomposition. Hangul syllables are left to the algorithm.
+pub static DECOMPOSITIONS: [(char, bool, &str); 5795] = [
+    ('\u{a0}', true, "\u{20}"),
+    ('\u{a8}', true, "\u{20}\u{308}"),
+    ('\u{aa}', true, "\u{61}"),
+    ('\u{af}', true, "\u{20}\u{304}"),
+    ('\u{b2}', true, "\u{32}"),
+    ('\u{b3}', true, "\u{33}"),
+    ('\u{b4}', true, "\u{20}\u{301}"),
+    ('\u{b5}', true, "\u{3bc}"),
+    ('\u{b8}', true, "\u{20}\u{327}"),
+    ('\u{b9}', true, "\u{31}"),
+    ('\u{ba}', true, "\u{6f}"),
+    ('\u{bc}', true, "\u{31}\u{2044}\u{34}"),
+    ('\u{bd}', true, "\u{31}\u{2044}\u{32}"),
+    ('\u{be}', true, "\u{33}\u{2044}\u{34}"),
+    ('\u{c0}', false, "\u{41}\u{300}"),
+    ('\u{c1}', false, "\u{41}\u{301}"),
+    ('\u{c2}', false, "\u{41}\u{302}"),
+    ('\u{c3}', false, "\u{41}\u{303}"),
+    ('\u{c4}', false, "\u{41}\u{308}"),
+    ('\u{c5}', false, "\u{41}\u{30a}"),
+    ('\u{c7}', false, "\u{43}\u{327}"),
+    ('\u{c8}', false, "\u{45}\u{300}"),
+    ('\u{c9}', false, "\u{45}\u{301}"),
+    ('\u{ca}', false, "\u{45}\u{302}"),
+    ('\u{cb}', false, "\u{45}\u{308}"),
+    ('\u{cc}', false, "\u{49}\u{300}"),
+    ('\u{cd}', false, "\u{49}\u{301}"),
+    ('\u{ce}', false, "\u{49}\u{302}"),
+    ('\u{cf}', false, "\u{49}\u{308}"),
+    ('\u{d1}', false, "\u{4e}\u{303}"),
+    ('\u{d2}', false, "\u{4f}\u{300}"),
+    ('\u{d3}', false, "\u{4f}\u{301}"),
+    ('\u{d4}', false, "\u{4f}\u{302}"),
+    ('\u{d5}', false, "\u{4f}\u{303}"),
+    ('\u{d6}', false, "\u{4f}\u{308}"),
+    ('\u{d9}', false, "\u{55}\u{300}"),
+    ('\u{da}', false, "\u{55}\u{301}"),
+    ('\u{db}', false, "\u{55}\u{302}"),
+    ('\u{dc}', false, "\u{55}\u{308}"),
+    ('\u{dd}', false, "\u{59}\u{301}"),
+    ('\u{e0}', false, "\u{61}\u{300}"),
+    ('\u{e1}', false, "\u{61}\u{301}"),
+    ('\u{e2}', false, "\u{61}\u{302}"),
+    ('\u{e3}', false, "\u{61}\u{303}"),
+    ('\u{e4}', false, "\u{61}\u{308}"),
+    ('\u{e5}', false, "\u{61}\u{30a}"),
+    ('\u{e7}', false, "\u{63}\u{327}"),
+    ('\u{e8}', false, "\u{65}\u{300}"),
+    ('\u{e9}', false, "\u{65}\u{301}"),
+    ('\u{ea}', false, "\u{65}\u{302}"),
+    ('\u{eb}', false, "\u{65}\u{308}"),
+    ('\u{ec}', false, "\u{69}\u{300}"),
+    ('\u{ed}', false, "\u{69}\u{301}"),
+    ('\u{ee}', false, "\u{69}\u{302}"),
+    ('\u{ef}', false, "\u{69}\u{308}"),
+    ('\u{f1}', false, "\u{6e}\u{303}"),
+    ('\u{f2}', false, "\u{6f}\u{300}"),
+    ('\u{f3}', false, "\u{6f}\u{301}"),
+    ('\u{f4}', false, "\u{6f}\u{302}"),
+    ('\u{f5}', false, "\u{6f}\u{303}"),
+    ('\u{f6}', false, "\u{6f}\u{308}"),
+    ('\u{f9}', false, "\u{75}\u{300}"),
+    ('\u{fa}', false, "\u{75}\u{301}"),
+    ('\u{fb}', false, "\u{75}\u{302}"),
+    ('\u{fc}', false, "\u{75}\u{308}"),
+    ('\u{fd}', false, "\u{79}\u{301}"),
+    ('\u{ff}', false, "\u{79}\u{308}"),
+    ('\u{100}', false, "\u{41}\u{304}"),
+    ('\u{101}', false, "\u{61}\u{304}"),
+    ('\u{102}', false, "\u{41}\u{306}"),
+    ('\u{103}', false, "\u{61}\u{306}"),
+    ('\u{104}', false, "\u{41}\u{328}"),
+    ('\u{105}', false, "\u{61}\u{328}"),
+    ('\u{106}', false, "\u{43}\u{301}"),
+    ('\u{107}', false, "\u{63}\u{301}"),
+    ('\u{108}', false, "\u{43}\u{302}"),
+    ('\u{109}', false, "\u{63}\u{302}"),
+    ('\u{10a}', false, "\u{43}\u{307}"),
+    ('\u{10b}', false, "\u{63}\u{307}"),
+    ('\u{10c}', false, "\u{43}\u{30c}"),
+    ('\u{10d}', false, "\u{63}\u{30c}"),
+    ('\u{10e}', false, "\u{44}\u{30c}"),
+    ('\u{10f}', false, "\u{64}\u{30c}"),
+    ('\u{112}', false, "\u{45}\u{304}"),
+    ('\u{113}', false, "\u{65}\u{304}"),
+    ('\u{114}', false, "\u{45}\u{306}"),
+    ('\u{115}', false, "\u{65}\u{306}"),
+    ('\u{116}', false, "\u{45}\u{307}"),
+    ('\u{117}', false, "\u{65}\u{307}"),
+    ('\u{118}', false, "\u{45}\u{328}"),
+    ('\u{119}', false, "\u{65}\u{328}"),
+    ('\u{11a}', false, "\u{45}\u{30c}"),
+    ('\u{11b}', false, "\u{65}\u{30c}"),
+    ('\u{11c}', false, "\u{47}\u{302}"),
+    ('\u{11d}', false, "\u{67}\u{302}"),
+    ('\u{11e}', false, "\u{47}\u{306}"),
+    ('\u{11f}', false, "\u{67}\u{306}"),
+    ('\u{120}', false, "\u{47}\u{307}"),
+    ('\u{121}', false, "\u{67}\u{307}"),
+    ('\u{122}', false, "\u{47}\u{327}"),
+    ('\u{123}', false, "\u{67}\u{327}"),
+    ('\u{124}', false, "\u{48}\u{302}"),
+    ('\u{125}', false, "\u{68}\u{302}"),
+    ('\u{128}', false, "\u{49}\u{303}"),
+    ('\u{129}', false, "\u{69}\u{303}"),
+    ('\u{12a}', false, "\u{49}\u{304}"),
+    ('\u{12b}', false, "\u{69}\u{304}"),
+    ('\u{12c}', false, "\u{49}\u{306}"),
+    ('\u{12d}', false, "\u{69}\u{306}"),
+    ('\u{12e}', false, "\u{49}\u{328}"),
+    ('\u{12f}', false, "\u{69}\u{328}"),
+    ('\u{130}', false, "\u{49}\u{307}"),
+    ('\u{132}', true, "\u{49}\u{4a}"),
+    ('\u{133}', true, "\u{69}\u{6a}"),
+    ('\u{134}', false, "\u{4a}\u{302}"),
+    ('\u{135}', false, "\u{6a}\u{302}"),
+    ('\u{136}', false, "\u{4b}\u{327}"),
+    ('\u{137}', false, "\u{6b}\u{327}"),
+    ('\u{139}', false, "\u{4c}\u{301}"),
+    ('\u{13a}', false, "\u{6c}\u{301}"),
+    ('\u{13b}', false, "\u{4c}\u{327}"),
+    ('\u{13c}', false, "\u{6c}\u{327}"),
+    ('\u{13d}', false, "\u{4c}\u{30c}"),
+    ('\u{13e}', false, "\u{6c}\u{30c}"),
+    ('\u{13f}', true, "\u{4c}\u{b7}"),
+    ('\u{140}', true, "\u{6c}\u{b7}"),
+    ('\u{143}', false, "\u{4e}\u{301}"),
+    ('\u{144}', false, "\u{6e}\u{301}"),
+    ('\u{145}', false, "\u{4e}\u{327}"),
+    ('\u{146}', false, "\u{6e}\u{327}"),
+    ('\u{147}', false, "\u{4e}\u{30c}"),
+    ('\u{148}', false, "\u{6e}\u{30c}"),
+    ('\u{149}', true, "\u{2bc}\u{6e}"),
+    ('\u{14c}', false, "\u{4f}\u{304}"),
+    ('\u{14d}', false, "\u{6f}\u{304}"),
+    ('\u{14e}', false, "\u{4f}\u{306}"),
+    ('\u{14f}', false, "\u{6f}\u{306}"),
+    ('\u{150}', false, "\u{4f}\u{30b}"),
+    ('\u{151}', false, "\u{6f}\u{30b}"),
+    ('\u{154}', false, "\u{52}\u{301}"),
+    ('\u{155}', false, "\u{72}\u{301}"),
+    ('\u{156}', false, "\u{52}\u{327}"),
+    ('\u{157}', false, "\u{72}\u{327}"),
+    ('\u{158}', false, "\u{52}\u{30c}"),
+    ('\u{159}', false, "\u{72}\u{30c}"),
+    ('\u{15a}', false, "\u{53}\u{301}"),
+    ('\u{15b}', false, "\u{73}\u{301}"),
+    ('\u{15c}', false, "\u{53}\u{302}"),
+    ('\u{15d}', false, "\u{73}\u{302}"),
+    ('\u{15e}', false, "\u{53}\u{327}"),
+    ('\u{15f}', false, "\u{73}\u{327}"),
+    ('\u{160}', false, "\u{53}\u{30c}"),
+    ('\u{161}', false, "\u{73}\u{30c}"),
+    ('\u{162}', false, "\u{54}\u{327}"),
+    ('\u{163}', false, "\u{74}\u{327}"),
+    ('\u{164}', false, "\u{54}\u{30c}"),
+    ('\u{165}', false, "\u{74}\u{30c}"),
+    ('\u{168}', false, "\u{55}\u{303}"),
+    ('\u{169}', false, "\u{75}\u{303}"),
+    ('\u{16a}', false, "\u{55}\u{304}"),
+    ('\u{16b}', false, "\u{75}\u{304}"),
+    ('\u{16c}', false, "\u{55}\u{306}"),
+    ('\u{16d}', false, "\u{75}\u{306}"),
+    ('\u{16e}', false, "\u{55}\u{30a}"),
+    ('\u{16f}', false, "\u{75}\u{30a}"),
+    ('\u{170}', false, "\u{55}\u{30b}"),
+    ('\u{171}', false, "\u{75}\u{30b}"),
+    ('\u{172}', false, "\u{55}\u{328}"),
+    ('\u{173}', false, "\u{75}\u{328}"),
+    ('\u{174}', false, "\u{57}\u{302}"),
+    ('\u{175}', false, "\u{77}\u{302}"),
+    ('\u{176}', false, "\u{59}\u{302}"),
+    ('\u{177}', false, "\u{79}\u{302}"),
+    ('\u{178}', false, "\u{59}\u{308}"),
+    ('\u{179}', false, "\u{5a}\u{301}"),
+    ('\u{17a}', false, "\u{7a}\u{301}"),
+    ('\u{17b}', false, "\u{5a}\u{307}"),
+    ('\u{17c}', false, "\u{7a}\u{307}"),
+    ('\u{17d}', false, "\u{5a}\u{30c}"),
+    ('\u{17e}', false, "\u{7a}\u{30c}"),
+    ('\u{17f}', true, "\u{73}"),
+    ('\u{1a0}', false, "\u{4f}\u{31b}"),
+    ('\u{1a1}', false, "\u{6f}\u{31b}"),
+    ('\u{1af}', false, "\u{55}\u{31b}"),
+    ('\u{1b0}', false, "\u{75}\u{31b}"),
+    ('\u{1c4}', true, "\u{44}\u{17d}"),
+    ('\u{1c5}', true, "\u{44}\u{17e}"),
+    ('\u{1c6}', true, "\u{64}\u{17e}"),
+    ('\u{1c7}', true, "\u{4c}\u{4a}"),
+    ('\u{1c8}', true, "\u{4c}\u{6a}"),
+    ('\u{1c9}', true, "\u{6c}\u{6a}"),
+    ('\u{1ca}', true, "\u{4e}\u{4a}"),
+    ('\u{1cb}', true, "\u{4e}\u{6a}"),
+    ('\u{1cc}', true, "\u{6e}\u{6a}"),
+    ('\u{1cd}', false, "\u{41}\u{30c}"),
+    ('\u{1ce}', false, "\u{61}\u{30c}"),
+    ('\u{1cf}', false, "\u{49}\u{30c}"),
+    ('\u{1d0}', false, "\u{69}\u{30c}"),
+    ('\u{1d1}', false, "\u{4f}\u{30c}"),
+    ('\u{1d2}', false, "\u{6f}\u{30c}"),
+    ('\u{1d3}', false, "\u{55}\u{30c}"),
+    ('\u{1d4}', false, "\u{75}\u{30c}"),
+    ('\u{1d5}', false, "\u{dc}\u{304}"),
+    ('\u{1d6}', false, "\u{fc}\u{304}"),
+    ('\u{1d7}', false, "\u{dc}\u{301}"),
+    ('\u{1d8}', false, "\u{fc}\u{301}"),
+    ('\u{1d9}', false, "\u{dc}\u{30c}"),
+    ('\u{1da}', false, "\u{fc}\u{30c}"),
+    ('\u{1db}', false, "\u{dc}\u{300}"),
+    ('\u{1dc}', false, "\u{fc}\u{300}"),
+    ('\u{1de}', false, "\u{c4}\u{304}"),
+    ('\u{1df}', false, "\u{e4}\u{304}"),
+    ('\u{1e0}', false, "\u{226}\u{304}"),
+    ('\u{1e1}', false, "\u{227}\u{304}"),
+    ('\u{1e2}', false, "\u{c6}\u{304}"),
+    ('\u{1e3}', false, "\u{e6}\u{304}"),
+    ('\u{1e6}', false, "\u{47}\u{30c}"),
+    ('\u{1e7}', false, "\u{67}\u{30c}"),
+    ('\u{1e8}', false, "\u{4b}\u{30c}"),
+    ('\u{1e9}', false, "\u{6b}\u{30c}"),
+    ('\u{1ea}', false, "\u{4f}\u{328}"),
+    ('\u{1eb}', false, "\u{6f}\u{328}"),
+    ('\u{1ec}', false, "\u{1ea}\u{304}"),
+    ('\u{1ed}', false, "\u{1eb}\u{304}"),
+    ('\u{1ee}', false, "\u{1b7}\u{30c}"),
+    ('\u{1ef}', false, "\u{292}\u{30c}"),
+    ('\u{1f0}', false, "\u{6a}\u{30c}"),
+    ('\u{1f1}', true, "\u{44}\u{5a}"),
+    ('\u{1f2}', true, "\u{44}\u{7a}"),
+    ('\u{1f3}', true, "\u{64}\u{7a}"),
+    ('\u{1f4}', false, "\u{47}\u{301}"),
+    ('\u{1f5}', false, "\u{67}\u{301}"),
+    ('\u{1f8}', false, "\u{4e}\u{300}"),
+    ('\u{1f9}', false, "\u{6e}\u{300}"),
+    ('\u{1fa}', false, "\u{c5}\u{301}"),
+    ('\u{1fb}', false, "\u{e5}\u{301}"),
+    ('\u{1fc}', false, "\u{c6}\u{301}"),
+    ('\u{1fd}', false, "\u{e6}\u{301}"),
+    ('\u{1fe}', false, "\u{d8}\u{301}"),
+    ('\u{1ff}', false, "\u{f8}\u{301}"),
+    ('\u{200}', false, "\u{41}\u{30f}"),
+    ('\u{201}', false, "\u{61}\u{30f}"),
+    ('\u{202}', false, "\u{41}\u{311}"),
+    ('\u{203}', false, "\u{61}\u{311}"),
+    ('\u{204}', false, "\u{45}\u{30f}"),
+    ('\u{205}', false, "\u{65}\u{30f}"),
+    ('\u{206}', false, "\u{45}\u{311}"),
+    ('\u{207}', false, "\u{65}\u{311}"),
+    ('\u{208}', false, "\u{49}\u{30f}"),
+    ('\u{209}', false, "\u{69}\u{30f}"),
+    ('\u{20a}', false, "\u{49}\u{311}"),
+    ('\u{20b}', false, "\u{69}\u{311}"),
+    ('\u{20c}', false, "\u{4f}\u{30f}"),
+    ('\u{20d}', false, "\u{6f}\u{30f}"),
+    ('\u{20e}', false, "\u{4f}\u{311}"),
+    ('\u{20f}', false, "\u{6f}\u{311}"),
+    ('\u{210}', false, "\u{52}\u{30f}"),
+    ('\u{211}', false, "\u{72}\u{30f}"),
+    ('\u{212}', false, "\u{52}\u{311}"),
+    ('\u{213}', false, "\u{72}\u{311}"),
+    ('\u{214}', false, "\u{55}\u{30f}"),
+    ('\u{215}', false, "\u{75}\u{30f}"),
+    ('\u{216}', false, "\u{55}\u{311}"),
+    ('\u{217}', false, "\u{75}\u{311}"),
+    ('\u{218}', false, "\u{53}\u{326}"),
+    ('\u{219}', false, "\u{73}\u{326}"),
+    ('\u{21a}', false, "\u{54}\u{326}"),
+    ('\u{21b}', false, "\u{74}\u{326}"),
+    ('\u{21e}', false, "\u{48}\u{30c}"),
+    ('\u{21f}', false, "\u{68}\u{30c}"),
+    ('\u{226}', false, "\u{41}\u{307}"),
+    ('\u{227}', false, "\u{61}\u{307}"),
+    ('\u{228}', false, "\u{45}\u{327}"),
+    ('\u{229}', false, "\u{65}\u{327}"),
+    ('\u{22a}', false, "\u{d6}\u{304}"),
+    ('\u{22b}', false, "\u{f6}\u{304}"),
+    ('\u{22c}', false, "\u{d5}\u{304}"),
+    ('\u{22d}', false, "\u{f5}\u{304}"),
+    ('\u{22e}', false, "\u{4f}\u{307}"),
+    ('\u{22f}', false, "\u{6f}\u{307}"),
+    ('\u{230}', false, "\u{22e}\u{304}"),
+    ('\u{231}', false, "\u{22f}\u{304}"),
+    ('\u{232}', false, "\u{59}\u{304}"),
+    ('\u{233}', false, "\u{79}\u{304}"),
+    ('\u{2b0}', true, "\u{68}"),
+    ('\u{2b1}', true, "\u{266}"),
+    ('\u{2b2}', true, "\u{6a}"),
+    ('\u{2b3}', true, "\u{72}"),
+    ('\u{2b4}', true, "\u{279}"),
+    ('\u{2b5}', true, "\u{27b}"),
+    ('\u{2b6}', true, "\u{281}"),
+    ('\u{2b7}', true, "\u{77}"),
+    ('\u{2b8}', true, "\u{79}"),
+    ('\u{2d8}', true, "\u{20}\u{306}"),
+    ('\u{2d9}', true, "\u{20}\u{307}"),
+    ('\u{2da}', true, "\u{20}\u{30a}"),
+    ('\u{2db}', true, "\u{20}\u{328}"),
+    ('\u{2dc}', true, "\u{20}\u{303}"),
+    ('\u{2dd}', true, "\u{20}\u{30b}"),
+    ('\u{2e0}', true, "\u{263}"),
+    ('\u{2e1}', true, "\u{6c}"),
+    ('\u{2e2}', true, "\u{73}"),
+    ('\u{2e3}', true, "\u{78}"),
+    ('\u{2e4}', true, "\u{295}"),
+    ('\u{340}', false, "\u{300}"),
+    ('\u{341}', false, "\u{301}"),
+    ('\u{343}', false, "\u{313}"),
+    ('\u{344}', false, "\u{308}\u{301}"),
+    ('\u{374}', false, "\u{2b9}"),
+    ('\u{37a}', true, "\u{20}\u{345}"),
+    ('\u{37e}', false, "\u{3b}"),
+    ('\u{384}', true, "\u{20}\u{301}"),
+    ('\u{385}', false, "\u{a8}\u{301}"),
+    ('\u{386}', false, "\u{391}\u{301}"),
+    ('\u{387}', false, "\u{b7}"),
+    ('\u{388}', false, "\u{395}\u{301}"),
+    ('\u{389}', false, "\u{397}\u{301}"),
+    ('\u{38a}', false, "\u{399}\u{301}"),
+    ('\u{38c}', false, "\u{39f}\u{301}"),
+    ('\u{38e}', false, "\u{3a5}\u{301}"),
+    ('\u{38f}', false, "\u{3a9}\u{301}"),
+    ('\u{390}', false, "\u{3ca}\u{301}"),
+    ('\u{3aa}', false, "\u{399}\u{308}"),
+    ('\u{3ab}', false, "\u{3a5}\u{308}"),
+    ('\u{3ac}', false, "\u{3b1}\u{301}"),
+    ('\u{3ad}', false, "\u{3b5}\u{301}"),
+    ('\u{3ae}', false, "\u{3b7}\u{301}"),
+    ('\u{3af}', false, "\u{3b9}\u{301}"),
+    ('\u{3b0}', false, "\u{3cb}\u{301}"),
+    ('\u{3ca}', false, "\u{3b9}\u{308}"),
+    ('\u{3cb}', false, "\u{3c5}\u{308}"),
+    ('\u{3cc}', false, "\u{3bf}\u{301}"),
+    ('\u{3cd}', false, "\u{3c5}\u{301}"),
+    ('\u{3ce}', false, "\u{3c9}\u{301}"),
+    ('\u{3d0}', true, "\u{3b2}"),
+    ('\u{3d1}', true, "\u{3b8}"),
+    ('\u{3d2}', true, "\u{3a5}"),
+    ('\u{3d3}', false, "\u{3d2}\u{301}"),
+    ('\u{3d4}', false, "\u{3d2}\u{308}"),
+    ('\u{3d5}', true, "\u{3c6}"),
+    ('\u{3d6}', true, "\u{3c0}"),
+    ('\u{3f0}', true, "\u{3ba}"),
+    ('\u{3f1}', true, "\u{3c1}"),
+    ('\u{3f2}', true, "\u{3c2}"),
+    ('\u{3f4}', true, "\u{398}"),
+    ('\u{3f5}', true, "\u{3b5}"),
+    ('\u{3f9}', true, "\u{3a3}"),
+    ('\u{400}', false, "\u{415}\u{300}"),
+    ('\u{401}', false, "\u{415}\u{308}"),
+    ('\u{403}', false, "\u{413}\u{301}"),
+    ('\u{407}', false, "\u{406}\u{308}"),
+    ('\u{40c}', false, "\u{41a}\u{301}"),
+    ('\u{40d}', false, "\u{418}\u{300}"),
+    ('\u{40e}', false, "\u{423}\u{306}"),
+    ('\u{419}', false, "\u{418}\u{306}"),
+    ('\u{439}', false, "\u{438}\u{306}"),
+    ('\u{450}', false, "\u{435}\u{300}"),
+    ('\u{451}', false, "\u{435}\u{308}"),
+    ('\u{453}', false, "\u{433}\u{301}"),
+    ('\u{457}', false, "\u{456}\u{308}"),
+    ('\u{45c}', false, "\u{43a}\u{301}"),
+    ('\u{45d}', false, "\u{438}\u{300}"),
+    ('\u{45e}', false, "\u{443}\u{306}"),
+    ('\u{476}', false, "\u{474}\u{30f}"),
+    ('\u{477}', false, "\u{475}\u{30f}"),
+    ('\u{4c1}', false, "\u{416}\u{306}"),
+    ('\u{4c2}', false, "\u{436}\u{306}"),
+    ('\u{4d0}', false, "\u{410}\u{306}"),
+    ('\u{4d1}', false, "\u{430}\u{306}"),
+    ('\u{4d2}', false, "\u{410}\u{308}"),
+    ('\u{4d3}', false, "\u{430}\u{308}"),
+    ('\u{4d6}', false, "\u{415}\u{306}"),
+    ('\u{4d7}', false, "\u{435}\u{306}"),
+    ('\u{4da}', false, "\u{4d8}\u{308}"),
+    ('\u{4db}', false, "\u{4d9}\u{308}"),
+    ('\u{4dc}', false, "\u{416}\u{308}"),
+    ('\u{4dd}', false, "\u{436}\u{308}"),
+    ('\u{4de}', false, "\u{417}\u{308}"),
+    ('\u{4df}', false, "\u{437}\u{308}"),
+    ('\u{4e2}', false, "\u{418}\u{304}"),
+    ('\u{4e3}', false, "\u{438}\u{304}"),
+    ('\u{4e4}', false, "\u{418}\u{308}"),
+    ('\u{4e5}', false, "\u{438}\u{308}"),
+    ('\u{4e6}', false, "\u{41e}\u{308}"),
+    ('\u{4e7}', false, "\u{43e}\u{308}"),
+    ('\u{4ea}', false, "\u{4e8}\u{308}"),
+    ('\u{4eb}', false, "\u{4e9}\u{308}"),
+    ('\u{4ec}', false, "\u{42d}\u{308}"),
+    ('\u{4ed}', false, "\u{44d}\u{308}"),
+    ('\u{4ee}', false, "\u{423}\u{304}"),
+    ('\u{4ef}', false, "\u{443}\u{304}"),
+    ('\u{4f0}', false, "\u{423}\u{308}"),
+    ('\u{4f1}', false, "\u{443}\u{308}"),
+    ('\u{4f2}', false, "\u{423}\u{30b}"),
+    ('\u{4f3}', false, "\u{443}\u{30b}"),
+    ('\u{4f4}', false, "\u{427}\u{308}"),
+    ('\u{4f5}', false, "\u{447}\u{308}"),
+    ('\u{4f8}', false, "\u{42b}\u{308}"),
+    ('\u{4f9}', false, "\u{44b}\u{308}"),
+    ('\u{587}', true, "\u{565}\u{582}"),
+    ('\u{622}', false, "\u{627}\u{653}"),
+    ('\u{623}', false, "\u{627}\u{654}"),
+    ('\u{624}', false, "\u{648}\u{654}"),
+    ('\u{625}', false, "\u{627}\u{655}"),
+    ('\u{626}', false, "\u{64a}\u{654}"),
+    ('\u{675}', true, "\u{627}\u{674}"),
+    ('\u{676}', true, "\u{648}\u{674}"),
+    ('\u{677}', true, "\u{6c7}\u{674}"),
+    ('\u{678}', true, "\u{64a}\u{674}"),
+    ('\u{6c0}', false, "\u{6d5}\u{654}"),
+    ('\u{6c2}', false, "\u{6c1}\u{654}"),
+    ('\u{6d3}', false, "\u{6d2}\u{654}"),
+    ('\u{929}', false, "\u{928}\u{93c}"),
+    ('\u{931}', false, "\u{930}\u{93c}"),
+    ('\u{934}', false, "\u{933}\u{93c}"),
+    ('\u{958}', false, "\u{915}\u{93c}"),
+    ('\u{959}', false, "\u{916}\u{93c}"),
+    ('\u{95a}', false, "\u{917}\u{93c}"),
+    ('\u{95b}', false, "\u{91c}\u{93c}"),
+    ('\u{95c}', false, "\u{921}\u{93c}"),
+    ('\u{95d}', false, "\u{922}\u{93c}"),
+    ('\u{95e}', false, "\u{92b}\u{93c}"),
+    ('\u{95f}', false, "\u{92f}\u{93c}"),
+    ('\u{9cb}', false, "\u{9c7}\u{9be}"),
+    ('\u{9cc}', false, "\u{9c7}\u{9d7}"),
+    ('\u{9dc}', false, "\u{9a1}\u{9bc}"),
+    ('\u{9dd}', false, "\u{9a2}\u{9bc}"),
+    ('\u{9df}', false, "\u{9af}\u{9bc}"),
+    ('\u{a33}', false, "\u{a32}\u{a3c}"),
+    ('\u{a36}', false, "\u{a38}\u{a3c}"),
+    ('\u{a59}', false, "\u{a16}\u{a3c}"),
+    ('\u{a5a}', false, "\u{a17}\u{a3c}"),
+    ('\u{a5b}', false, "\u{a1c}\u{a3c}"),
+    ('\u{a5e}', false, "\u{a2b}\u{a3c}"),
+    ('\u{b48}', false, "\u{b47}\u{b56}"),
+    ('\u{b4b}', false, "\u{b47}\u{b3e}"),
+    ('\u{b4c}', false, "\u{b47}\u{b57}"),
+    ('\u{b5c}', false, "\u{b21}\u{b3c}"),
+    ('\u{b5d}', false, "\u{b22}\u{b3c}"),
+    ('\u{b94}', false, "\u{b92}\u{bd7}"),
+    ('\u{bca}', false, "\u{bc6}\u{bbe}"),
+    ('\u{bcb}', false, "\u{bc7}\u{bbe}"),
+    ('\u{bcc}', false, "\u{bc6}\u{bd7}"),
+    ('\u{c48}', false, "\u{c46}\u{c56}"),
+    ('\u{cc0}', false, "\u{cbf}\u{cd5}"),
+    ('\u{cc7}', false, "\u{cc6}\u{cd5}"),
+    ('\u{cc8}', false, "\u{cc6}\u{cd6}"),
+    ('\u{cca}', false, "\u{cc6}\u{cc2}"),
+    ('\u{ccb}', false, "\u{cca}\u{cd5}"),
+    ('\u{d4a}', false, "\u{d46}\u{d3e}"),
+    ('\u{d4b}', false, "\u{d47}\u{d3e}"),
+    ('\u{d4c}', false, "\u{d46}\u{d57}"),
+    ('\u{dda}', false, "\u{dd9}\u{dca}"),
+    ('\u{ddc}', false, "\u{dd9}\u{dcf}"),
+    ('\u{ddd}', false, "\u{ddc}\u{dca}"),
+    ('\u{dde}', false, "\u{dd9}\u{ddf}"),
+    ('\u{e33}', true, "\u{e4d}\u{e32}"),
+    ('\u{eb3}', true, "\u{ecd}\u{eb2}"),
+    ('\u{edc}', true, "\u{eab}\u{e99}"),
+    ('\u{edd}', true, "\u{eab}\u{ea1}"),
+    ('\u{f0c}', true, "\u{f0b}"),
+    ('\u{f43}', false, "\u{f42}\u{fb7}"),
+    ('\u{f4d}', false, "\u{f4c}\u{fb7}"),
+    ('\u{f52}', false, "\u{f51}\u{fb7}"),
+    ('\u{f57}', false, "\u{f56}\u{fb7}"),
+    ('\u{f5c}', false, "\u{f5b}\u{fb7}"),
+    ('\u{f69}', false, "\u{f40}\u{fb5}"),
+    ('\u{f73}', false, "\u{f71}\u{f72}"),
+    ('\u{f75}', false, "\u{f71}\u{f74}"),
+    ('\u{f76}', false, "\u{fb2}\u{f80}"),
+    ('\u{f77}', true, "\u{fb2}\u{f81}"),
+    ('\u{f78}', false, "\u{fb3}\u{f80}"),
+    ('\u{f79}', true, "\u{fb3}\u{f81}"),
+    ('\u{f81}', false, "\u{f71}\u{f80}"),
+    ('\u{f93}', false, "\u{f92}\u{fb7}"),
+    ('\u{f9d}', false, "\u{f9c}\u{fb7}"),
+    ('\u{fa2}', false, "\u{fa1}\u{fb7}"),
+    ('\u{fa7}', false, "\u{fa6}\u{fb7}"),
+    ('\u{fac}', false, "\u{fab}\u{fb7}"),
+    ('\u{fb9}', false, "\u{f90}\u{fb5}"),
+    ('\u{1026}', false, "\u{1025}\u{102e}"),
+    ('\u{10fc}', true, "\u{10dc}"),
+    ('\u{1b06}', false, "\u{1b05}\u{1b35}"),
+    ('\u{1b08}', false, "\u{1b07}\u{1b35}"),
+    ('\u{1b0a}', false, "\u{1b09}\u{1b35}"),
+    ('\u{1b0c}', false, "\u{1b0b}\u{1b35}"),
+    ('\u{1b0e}', false, "\u{1b0d}\u{1b35}"),
+    ('\u{1b12}', false, "\u{1b11}\u{1b35}"),
+    ('\u{1b3b}', false, "\u{1b3a}\u{1b35}"),
+    ('\u{1b3d}', false, "\u{1b3c}\u{1b35}"),
+    ('\u{1b40}', false, "\u{1b3e}\u{1b35}"),
+    ('\u{1b41}', false, "\u{1b3f}\u{1b35}"),
+    ('\u{1b43}', false, "\u{1b42}\u{1b35}"),
+    ('\u{1d2c}', true, "\u{41}"),
+    ('\u{1d2d}', true, "\u{c6}"),
+    ('\u{1d2e}', true, "\u{42}"),
+    ('\u{1d30}', true, "\u{44}"),
+    ('\u{1d31}', true, "\u{45}"),
+    ('\u{1d32}', true, "\u{18e}"),
+    ('\u{1d33}', true, "\u{47}"),
+    ('\u{1d34}', true, "\u{48}"),
+    ('\u{1d35}', true, "\u{49}"),
+    ('\u{1d36}', true, "\u{4a}"),
+    ('\u{1d37}', true, "\u{4b}"),
+    ('\u{1d38}', true, "\u{4c}"),
+    ('\u{1d39}', true, "\u{4d}"),
+    ('\u{1d3a}', true, "\u{4e}"),
+    ('\u{1d3c}', true, "\u{4f}"),
+    ('\u{1d3d}', true, "\u{222}"),
+    ('\u{1d3e}', true, "\u{50}"),
+    ('\u{1d3f}', true, "\u{52}"),
+    ('\u{1d40}', true, "\u{54}"),
+    ('\u{1d41}', true, "\u{55}"),
+    ('\u{1d42}', true, "\u{57}"),
+    ('\u{1d43}', true, "\u{61}"),
+    ('\u{1d44}', true, "\u{250}"),
+    ('\u{1d45}', true, "\u{251}"),
+    ('\u{1d46}', true, "\u{1d02}"),
+    ('\u{1d47}', true, "\u{62}"),
+    ('\u{1d48}', true, "\u{64}"),
+    ('\u{1d49}', true, "\u{65}"),
+    ('\u{1d4a}', true, "\u{259}"),
+    ('\u{1d4b}', true, "\u{25b}"),
+    ('\u{1d4c}', true, "\u{25c}"),
+    ('\u{1d4d}', true, "\u{67}"),
+    ('\u{1d4f}', true, "\u{6b}"),
+    ('\u{1d50}', true, "\u{6d}"),
+    ('\u{1d51}', true, "\u{14b}"),
+    ('\u{1d52}', true, "\u{6f}"),
+    ('\u{1d53}', true, "\u{254}"),
+    ('\u{1d54}', true, "\u{1d16}"),
+    ('\u{1d55}', true, "\u{1d17}"),
+    ('\u{1d56}', true, "\u{70}"),
+    ('\u{1d57}', true, "\u{74}"),
+    ('\u{1d58}', true, "\u{75}"),
+    ('\u{1d59}', true, "\u{1d1d}"),
+    ('\u{1d5a}', true, "\u{26f}"),
+    ('\u{1d5b}', true, "\u{76}"),
+    ('\u{1d5c}', true, "\u{1d25}"),
+    ('\u{1d5d}', true, "\u{3b2}"),
+    ('\u{1d5e}', true, "\u{3b3}"),
+    ('\u{1d5f}', true, "\u{3b4}"),
+    ('\u{1d60}', true, "\u{3c6}"),
+    ('\u{1d61}', true, "\u{3c7}"),
+    ('\u{1d62}', true, "\u{69}"),
+    ('\u{1d63}', true, "\u{72}"),
+    ('\u{1d64}', true, "\u{75}"),
+    ('\u{1d65}', true, "\u{76}"),
+    ('\u{1d66}', true, "\u{3b2}"),
+    ('\u{1d67}', true, "\u{3b3}"),
+    ('\u{1d68}', true, "\u{3c1}"),
+    ('\u{1d69}', true, "\u{3c6}"),
+    ('\u{1d6a}', true, "\u{3c7}"),
+    ('\u{1d78}', true, "\u{43d}"),
+    ('\u{1d9b}', true, "\u{252}"),
+    ('\u{1d9c}', true, "\u{63}"),
+    ('\u{1d9d}', true, "\u{255}"),
+    ('\u{1d9e}', true, "\u{f0}"),
+    ('\u{1d9f}', true, "\u{25c}"),
+    ('\u{1da0}', true, "\u{66}"),
+    ('\u{1da1}', true, "\u{25f}"),
+    ('\u{1da2}', true, "\u{261}"),
+    ('\u{1da3}', true, "\u{265}"),
+    ('\u{1da4}', true, "\u{268}"),
+    ('\u{1da5}', true, "\u{269}"),
+    ('\u{1da6}', true, "\u{26a}"),
+    ('\u{1da7}', true, "\u{1d7b}"),
+    ('\u{1da8}', true, "\u{29d}"),
+    ('\u{1da9}', true, "\u{26d}"),
+    ('\u{1daa}', true, "\u{1d85}"),
+    ('\u{1dab}', true, "\u{29f}"),
+    ('\u{1dac}', true, "\u{271}"),
+    ('\u{1dad}', true, "\u{270}"),
+    ('\u{1dae}', true, "\u{272}"),
+    ('\u{1daf}', true, "\u{273}"),
+    ('\u{1db0}', true, "\u{274}"),
+    ('\u{1db1}', true, "\u{275}"),
+    ('\u{1db2}', true, "\u{278}"),
+    ('\u{1db3}', true, "\u{282}"),
+    ('\u{1db4}', true, "\u{283}"),
+    ('\u{1db5}', true, "\u{1ab}"),
+    ('\u{1db6}', true, "\u{289}"),
+    ('\u{1db7}', true, "\u{28a}"),
+    ('\u{1db8}', true, "\u{1d1c}"),
+    ('\u{1db9}', true, "\u{28b}"),
+    ('\u{1dba}', true, "\u{28c}"),
+    ('\u{1dbb}', true, "\u{7a}"),
+    ('\u{1dbc}', true, "\u{290}"),
+    ('\u{1dbd}', true, "\u{291}"),
+    ('\u{1dbe}', true, "\u{292}"),
+    ('\u{1dbf}', true, "\u{3b8}"),
+    ('\u{1e00}', false, "\u{41}\u{325}"),
+    ('\u{1e01}', false, "\u{61}\u{325}"),
+    ('\u{1e02}', false, "\u{42}\u{307}"),
+    ('\u{1e03}', false, "\u{62}\u{307}"),
+    ('\u{1e04}', false, "\u{42}\u{323}"),
+    ('\u{1e05}', false, "\u{62}\u{323}"),
+    ('\u{1e06}', false, "\u{42}\u{331}"),
+    ('\u{1e07}', false, "\u{62}\u{331}"),
+    ('\u{1e08}', false, "\u{c7}\u{301}"),
+    ('\u{1e09}', false, "\u{e7}\u{301}"),
+    ('\u{1e0a}', false, "\u{44}\u{307}"),
+    ('\u{1e0b}', false, "\u{64}\u{307}"),
+    ('\u{1e0c}', false, "\u{44}\u{323}"),
+    ('\u{1e0d}', false, "\u{64}\u{323}"),
+    ('\u{1e0e}', false, "\u{44}\u{331}"),
+    ('\u{1e0f}', false, "\u{64}\u{331}"),
+    ('\u{1e10}', false, "\u{44}\u{327}"),
+    ('\u{1e11}', false, "\u{64}\u{327}"),
+    ('\u{1e12}', false, "\u{44}\u{32d}"),
+    ('\u{1e13}', false, "\u{64}\u{32d}"),
+    ('\u{1e14}', false, "\u{112}\u{300}"),
+    ('\u{1e15}', false, "\u{113}\u{300}"),
+    ('\u{1e16}', false, "\u{112}\u{301}"),
+    ('\u{1e17}', false, "\u{113}\u{301}"),
+    ('\u{1e18}', false, "\u{45}\u{32d}"),
+    ('\u{1e19}', false, "\u{65}\u{32d}"),
+    ('\u{1e1a}', false, "\u{45}\u{330}"),
+    ('\u{1e1b}', false, "\u{65}\u{330}"),
+    ('\u{1e1c}', false, "\u{228}\u{306}"),
+    ('\u{1e1d}', false, "\u{229}\u{306}"),
+    ('\u{1e1e}', false, "\u{46}\u{307}"),
+    ('\u{1e1f}', false, "\u{66}\u{307}"),
+    ('\u{1e20}', false, "\u{47}\u{304}"),
+    ('\u{1e21}', false, "\u{67}\u{304}"),
+    ('\u{1e22}', false, "\u{48}\u{307}"),
+    ('\u{1e23}', false, "\u{68}\u{307}"),
+    ('\u{1e24}', false, "\u{48}\u{323}"),
+    ('\u{1e25}', false, "\u{68}\u{323}"),
+    ('\u{1e26}', false, "\u{48}\u{308}"),
+    ('\u{1e27}', false, "\u{68}\u{308}"),
+    ('\u{1e28}', false, "\u{48}\u{327}"),
+    ('\u{1e29}', false, "\u{68}\u{327}"),
+    ('\u{1e2a}', false, "\u{48}\u{32e}"),
+    ('\u{1e2b}', false, "\u{68}\u{32e}"),
+    ('\u{1e2c}', false, "\u{49}\u{330}"),
+    ('\u{1e2d}', false, "\u{69}\u{330}"),
+    ('\u{1e2e}', false, "\u{cf}\u{301}"),
+    ('\u{1e2f}', false, "\u{ef}\u{301}"),
+    ('\u{1e30}', false, "\u{4b}\u{301}"),
+    ('\u{1e31}', false, "\u{6b}\u{301}"),
+    ('\u{1e32}', false, "\u{4b}\u{323}"),
+    ('\u{1e33}', false, "\u{6b}\u{323}"),
+    ('\u{1e34}', false, "\u{4b}\u{331}"),
+    ('\u{1e35}', false, "\u{6b}\u{331}"),
+    ('\u{1e36}', false, "\u{4c}\u{323}"),
+    ('\u{1e37}', false, "\u{6c}\u{323}"),
+    ('\u{1e38}', false, "\u{1e36}\u{304}"),
+    ('\u{1e39}', false, "\u{1e37}\u{304}"),
+    ('\u{1e3a}', false, "\u{4c}\u{331}"),
+    ('\u{1e3b}', false, "\u{6c}\u{331}"),
+    ('\u{1e3c}', false, "\u{4c}\u{32d}"),
+    ('\u{1e3d}', false, "\u{6c}\u{32d}"),
+    ('\u{1e3e}', false, "\u{4d}\u{301}"),
+    ('\u{1e3f}', false, "\u{6d}\u{301}"),
+    ('\u{1e40}', false, "\u{4d}\u{307}"),
+    ('\u{1e41}', false, "\u{6d}\u{307}"),
+    ('\u{1e42}', false, "\u{4d}\u{323}"),
+    ('\u{1e43}', false, "\u{6d}\u{323}"),
+    ('\u{1e44}', false, "\u{4e}\u{307}"),
+    ('\u{1e45}', false, "\u{6e}\u{307}"),
+    ('\u{1e46}', false, "\u{4e}\u{323}"),
+    ('\u{1e47}', false, "\u{6e}\u{323}"),
+    ('\u{1e48}', false, "\u{4e}\u{331}"),
+    ('\u{1e49}', false, "\u{6e}\u{331}"),
+    ('\u{1e4a}', false, "\u{4e}\u{32d}"),
+    ('\u{1e4b}', false, "\u{6e}\u{32d}"),
+    ('\u{1e4c}', false, "\u{d5}\u{301}"),
+    ('\u{1e4d}', false, "\u{f5}\u{301}"),
+    ('\u{1e4e}', false, "\u{d5}\u{308}"),
+    ('\u{1e4f}', false, "\u{f5}\u{308}"),
+    ('\u{1e50}', false, "\u{14c}\u{300}"),
+    ('\u{1e51}', false, "\u{14d}\u{300}"),
+    ('\u{1e52}', false, "\u{14c}\u{301}"),
+    ('\u{1e53}', false, "\u{14d}\u{301}"),
+    ('\u{1e54}', false, "\u{50}\u{301}"),
+    ('\u{1e55}', false, "\u{70}\u{301}"),
+    ('\u{1e56}', false, "\u{50}\u{307}"),
+    ('\u{1e57}', false, "\u{70}\u{307}"),
+    ('\u{1e58}', false, "\u{52}\u{307}"),
+    ('\u{1e59}', false, "\u{72}\u{307}"),
+    ('\u{1e5a}', false, "\u{52}\u{323}"),
+    ('\u{1e5b}', false, "\u{72}\u{323}"),
+    ('\u{1e5c}', false, "\u{1e5a}\u{304}"),
+    ('\u{1e5d}', false, "\u{1e5b}\u{304}"),
+    ('\u{1e5e}', false, "\u{52}\u{331}"),
+    ('\u{1e5f}', false, "\u{72}\u{331}"),
+    ('\u{1e60}', false, "\u{53}\u{307}"),
+    ('\u{1e61}', false, "\u{73}\u{307}"),
+    ('\u{1e62}', false, "\u{53}\u{323}"),
+    ('\u{1e63}', false, "\u{73}\u{323}"),
+    ('\u{1e64}', false, "\u{15a}\u{307}"),
+    ('\u{1e65}', false, "\u{15b}\u{307}"),
+    ('\u{1e66}', false, "\u{160}\u{307}"),
+    ('\u{1e67}', false, "\u{161}\u{307}"),
+    ('\u{1e68}', false, "\u{1e62}\u{307}"),
+    ('\u{1e69}', false, "\u{1e63}\u{307}"),
+    ('\u{1e6a}', false, "\u{54}\u{307}"),
+    ('\u{1e6b}', false, "\u{74}\u{307}"),
+    ('\u{1e6c}', false, "\u{54}\u{323}"),
+    ('\u{1e6d}', false, "\u{74}\u{323}"),
+    ('\u{1e6e}', false, "\u{54}\u{331}"),
+    ('\u{1e6f}', false, "\u{74}\u{331}"),
+    ('\u{1e70}', false, "\u{54}\u{32d}"),
+    ('\u{1e71}', false, "\u{74}\u{32d}"),
+    ('\u{1e72}', false, "\u{55}\u{324}"),
+    ('\u{1e73}', false, "\u{75}\u{324}"),
+    ('\u{1e74}', false, "\u{55}\u{330}"),
+    ('\u{1e75}', false, "\u{75}\u{330}"),
+    ('\u{1e76}', false, "\u{55}\u{32d}"),
+    ('\u{1e77}', false, "\u{75}\u{32d}"),
+    ('\u{1e78}', false, "\u{168}\u{301}"),
+    ('\u{1e79}', false, "\u{169}\u{301}"),
+    ('\u{1e7a}', false, "\u{16a}\u{308}"),
+    ('\u{1e7b}', false, "\u{16b}\u{308}"),
+    ('\u{1e7c}', false, "\u{56}\u{303}"),
+    ('\u{1e7d}', false, "\u{76}\u{303}"),
+    ('\u{1e7e}', false, "\u{56}\u{323}"),
+    ('\u{1e7f}', false, "\u{76}\u{323}"),
+    ('\u{1e80}', false, "\u{57}\u{300}"),
+    ('\u{1e81}', false, "\u{77}\u{300}"),
+    ('\u{1e82}', false, "\u{57}\u{301}"),
+    ('\u{1e83}', false, "\u{77}\u{301}"),
+    ('\u{1e84}', false, "\u{57}\u{308}"),
+    ('\u{1e85}', false, "\u{77}\u{308}"),
+    ('\u{1e86}', false, "\u{57}\u{307}"),
+    ('\u{1e87}', false, "\u{77}\u{307}"),
+    ('\u{1e88}', false, "\u{57}\u{323}"),
+    ('\u{1e89}', false, "\u{77}\u{323}"),
+    ('\u{1e8a}', false, "\u{58}\u{307}"),
+    ('\u{1e8b}', false, "\u{78}\u{307}"),
+    ('\u{1e8c}', false, "\u{58}\u{308}"),
+    ('\u{1e8d}', false, "\u{78}\u{308}"),
+    ('\u{1e8e}', false, "\u{59}\u{307}"),
+    ('\u{1e8f}', false, "\u{79}\u{307}"),
+    ('\u{1e90}', false, "\u{5a}\u{302}"),
+    ('\u{1e91}', false, "\u{7a}\u{302}"),
+    ('\u{1e92}', false, "\u{5a}\u{323}"),
+    ('\u{1e93}', false, "\u{7a}\u{323}"),
+    ('\u{1e94}', false, "\u{5a}\u{331}"),
+    ('\u{1e95}', false, "\u{7a}\u{331}"),
+    ('\u{1e96}', false, "\u{68}\u{331}"),
+    ('\u{1e97}', false, "\u{74}\u{308}"),
+    ('\u{1e98}', false, "\u{77}\u{30a}"),
+    ('\u{1e99}', false, "\u{79}\u{30a}"),
+    ('\u{1e9a}', true, "\u{61}\u{2be}"),
+    ('\u{1e9b}', false, "\u{17f}\u{307}"),
+    ('\u{1ea0}', false, "\u{41}\u{323}"),
+    ('\u{1ea1}', false, "\u{61}\u{323}"),
+    ('\u{1ea2}', false, "\u{41}\u{309}"),
+    ('\u{1ea3}', false, "\u{61}\u{309}"),
+    ('\u{1ea4}', false, "\u{c2}\u{301}"),
+    ('\u{1ea5}', false, "\u{e2}\u{301}"),
+    ('\u{1ea6}', false, "\u{c2}\u{300}"),
+    ('\u{1ea7}', false, "\u{e2}\u{300}"),
+    ('\u{1ea8}', false, "\u{c2}\u{309}"),
+    ('\u{1ea9}', false, "\u{e2}\u{309}"),
+    ('\u{1eaa}', false, "\u{c2}\u{303}"),
+    ('\u{1eab}', false, "\u{e2}\u{303}"),
+    ('\u{1eac}', false, "\u{1ea0}\u{302}"),
+    ('\u{1ead}', false, "\u{1ea1}\u{302}"),
+    ('\u{1eae}', false, "\u{102}\u{301}"),
+    ('\u{1eaf}', false, "\u{103}\u{301}"),
+    ('\u{1eb0}', false, "\u{102}\u{300}"),
+    ('\u{1eb1}', false, "\u{103}\u{300}"),
+    ('\u{1eb2}', false, "\u{102}\u{309}"),
+    ('\u{1eb3}', false, "\u{103}\u{309}"),
+    ('\u{1eb4}', false, "\u{102}\u{303}"),
+    ('\u{1eb5}', false, "\u{103}\u{303}"),
+    ('\u{1eb6}', false, "\u{1ea0}\u{306}"),
+    ('\u{1eb7}', false, "\u{1ea1}\u{306}"),
+    ('\u{1eb8}', false, "\u{45}\u{323}"),
+    ('\u{1eb9}', false, "\u{65}\u{323}"),
+    ('\u{1eba}', false, "\u{45}\u{309}"),
+    ('\u{1ebb}', false, "\u{65}\u{309}"),
+    ('\u{1ebc}', false, "\u{45}\u{303}"),
+    ('\u{1ebd}', false, "\u{65}\u{303}"),
+    ('\u{1ebe}', false, "\u{ca}\u{301}"),
+    ('\u{1ebf}', false, "\u{ea}\u{301}"),
+    ('\u{1ec0}', false, "\u{ca}\u{300}"),
+    ('\u{1ec1}', false, "\u{ea}\u{300}"),
+    ('\u{1ec2}', false, "\u{ca}\u{309}"),
+    ('\u{1ec3}', false, "\u{ea}\u{309}"),
+    ('\u{1ec4}', false, "\u{ca}\u{303}"),
+    ('\u{1ec5}', false, "\u{ea}\u{303}"),
+    ('\u{1ec6}', false, "\u{1eb8}\u{302}"),
+    ('\u{1ec7}', false, "\u{1eb9}\u{302}"),
+    ('\u{1ec8}', false, "\u{49}\u{309}"),
+    ('\u{1ec9}', false, "\u{69}\u{309}"),
+    ('\u{1eca}', false, "\u{49}\u{323}"),
+    ('\u{1ecb}', false, "\u{69}\u{323}"),
+    ('\u{1ecc}', false, "\u{4f}\u{323}"),
+    ('\u{1ecd}', false, "\u{6f}\u{323}"),
+    ('\u{1ece}', false, "\u{4f}\u{309}"),
+    ('\u{1ecf}', false, "\u{6f}\u{309}"),
+    ('\u{1ed0}', false, "\u{d4}\u{301}"),
+    ('\u{1ed1}', false, "\u{f4}\u{301}"),
+    ('\u{1ed2}', false, "\u{d4}\u{300}"),
+    ('\u{1ed3}', false, "\u{f4}\u{300}"),
+    ('\u{1ed4}', false, "\u{d4}\u{309}"),
+    ('\u{1ed5}', false, "\u{f4}\u{309}"),
+    ('\u{1ed6}', false, "\u{d4}\u{303}"),
+    ('\u{1ed7}', false, "\u{f4}\u{303}"),
+    ('\u{1ed8}', false, "\u{1ecc}\u{302}"),
+    ('\u{1ed9}', false, "\u{1ecd}\u{302}"),
+    ('\u{1eda}', false, "\u{1a0}\u{301}"),
+    ('\u{1edb}', false, "\u{1a1}\u{301}"),
+    ('\u{1edc}', false, "\u{1a0}\u{300}"),
+    ('\u{1edd}', false, "\u{1a1}\u{300}"),
+    ('\u{1ede}', false, "\u{1a0}\u{309}"),
+    ('\u{1edf}', false, "\u{1a1}\u{309}"),
+    ('\u{1ee0}', false, "\u{1a0}\u{303}"),
+    ('\u{1ee1}', false, "\u{1a1}\u{303}"),
+    ('\u{1ee2}', false, "\u{1a0}\u{323}"),
+    ('\u{1ee3}', false, "\u{1a1}\u{323}"),
+    ('\u{1ee4}', false, "\u{55}\u{323}"),
+    ('\u{1ee5}', false, "\u{75}\u{323}"),
+    ('\u{1ee6}', false, "\u{55}\u{309}"),
+    ('\u{1ee7}', false, "\u{75}\u{309}"),
+    ('\u{1ee8}', false, "\u{1af}\u{301}"),
+    ('\u{1ee9}', false, "\u{1b0}\u{301}"),
+    ('\u{1eea}', false, "\u{1af}\u{300}"),
+    ('\u{1eeb}', false, "\u{1b0}\u{300}"),
+    ('\u{1eec}', false, "\u{1af}\u{309}"),
+    ('\u{1eed}', false, "\u{1b0}\u{309}"),
+    ('\u{1eee}', false, "\u{1af}\u{303}"),
+    ('\u{1eef}', false, "\u{1b0}\u{303}"),
+    ('\u{1ef0}', false, "\u{1af}\u{323}"),
+    ('\u{1ef1}', false, "\u{1b0}\u{323}"),
+    ('\u{1ef2}', false, "\u{59}\u{300}"),
+    ('\u{1ef3}', false, "\u{79}\u{300}"),
+    ('\u{1ef4}', false, "\u{59}\u{323}"),
+    ('\u{1ef5}', false, "\u{79}\u{323}"),
+    ('\u{1ef6}', false, "\u{59}\u{309}"),
+    ('\u{1ef7}', false, "\u{79}\u{309}"),
+    ('\u{1ef8}', false, "\u{59}\u{303}"),
+    ('\u{1ef9}', false, "\u{79}\u{303}"),
+    ('\u{1f00}', false, "\u{3b1}\u{313}"),
+    ('\u{1f01}', false, "\u{3b1}\u{314}"),
+    ('\u{1f02}', false, "\u{1f00}\u{300}"),
+    ('\u{1f03}', false, "\u{1f01}\u{300}"),
+    ('\u{1f04}', false, "\u{1f00}\u{301}"),
+    ('\u{1f05}', false, "\u{1f01}\u{301}"),
+    ('\u{1f06}', false, "\u{1f00}\u{342}"),
+    ('\u{1f07}', false, "\u{1f01}\u{342}"),
+    ('\u{1f08}', false, "\u{391}\u{313}"),
+    ('\u{1f09}', false, "\u{391}\u{314}"),
+    ('\u{1f0a}', false, "\u{1f08}\u{300}"),
+    ('\u{1f0b}', false, "\u{1f09}\u{300}"),
+    ('\u{1f0c}', false, "\u{1f08}\u{301}"),
+    ('\u{1f0d}', false, "\u{1f09}\u{301}"),
+    ('\u{1f0e}', false, "\u{1f08}\u{342}"),
+    ('\u{1f0f}', false, "\u{1f09}\u{342}"),
+    ('\u{1f10}', false, "\u{3b5}\u{313}"),
+    ('\u{1f11}', false, "\u{3b5}\u{314}"),
+    ('\u{1f12}', false, "\u{1f10}\u{300}"),
+    ('\u{1f13}', false, "\u{1f11}\u{300}"),
+    ('\u{1f14}', false, "\u{1f10}\u{301}"),
+    ('\u{1f15}', false, "\u{1f11}\u{301}"),
+    ('\u{1f18}', false, "\u{395}\u{313}"),
+    ('\u{1f19}', false, "\u{395}\u{314}"),
+    ('\u{1f1a}', false, "\u{1f18}\u{300}"),
+    ('\u{1f1b}', false, "\u{1f19}\u{300}"),
+    ('\u{1f1c}', false, "\u{1f18}\u{301}"),
+    ('\u{1f1d}', false, "\u{1f19}\u{301}"),
+    ('\u{1f20}', false, "\u{3b7}\u{313}"),
+    ('\u{1f21}', false, "\u{3b7}\u{314}"),
+    ('\u{1f22}', false, "\u{1f20}\u{300}"),
+    ('\u{1f23}', false, "\u{1f21}\u{300}"),
+    ('\u{1f24}', false, "\u{1f20}\u{301}"),
+    ('\u{1f25}', false, "\u{1f21}\u{301}"),
+    ('\u{1f26}', false, "\u{1f20}\u{342}"),
+    ('\u{1f27}', false, "\u{1f21}\u{342}"),
+    ('\u{1f28}', false, "\u{397}\u{313}"),
+    ('\u{1f29}', false, "\u{397}\u{314}"),
+    ('\u{1f2a}', false, "\u{1f28}\u{300}"),
+    ('\u{1f2b}', false, "\u{1f29}\u{300}"),
+    ('\u{1f2c}', false, "\u{1f28}\u{301}"),
+    ('\u{1f2d}', false, "\u{1f29}\u{301}"),
+    ('\u{1f2e}', false, "\u{1f28}\u{342}"),
+    ('\u{1f2f}', false, "\u{1f29}\u{342}"),
+    ('\u{1f30}', false, "\u{3b9}\u{313}"),
+    ('\u{1f31}', false, "\u{3b9}\u{314}"),
+    ('\u{1f32}', false, "\u{1f30}\u{300}"),
+    ('\u{1f33}', false, "\u{1f31}\u{300}"),
+    ('\u{1f34}', false, "\u{1f30}\u{301}"),
+    ('\u{1f35}', false, "\u{1f31}\u{301}"),
+    ('\u{1f36}', false, "\u{1f30}\u{342}"),
+    ('\u{1f37}', false, "\u{1f31}\u{342}"),
+    ('\u{1f38}', false, "\u{399}\u{313}"),
+    ('\u{1f39}', false, "\u{399}\u{314}"),
+    ('\u{1f3a}', false, "\u{1f38}\u{300}"),
+    ('\u{1f3b}', false, "\u{1f39}\u{300}"),
+    ('\u{1f3c}', false, "\u{1f38}\u{301}"),
+    ('\u{1f3d}', false, "\u{1f39}\u{301}"),
+    ('\u{1f3e}', false, "\u{1f38}\u{342}"),
+    ('\u{1f3f}', false, "\u{1f39}\u{342}"),
+    ('\u{1f40}', false, "\u{3bf}\u{313}"),
+    ('\u{1f41}', false, "\u{3bf}\u{314}"),
+    ('\u{1f42}', false, "\u{1f40}\u{300}"),
+    ('\u{1f43}', false, "\u{1f41}\u{300}"),
+    ('\u{1f44}', false, "\u{1f40}\u{301}"),
+    ('\u{1f45}', false, "\u{1f41}\u{301}"),
+    ('\u{1f48}', false, "\u{39f}\u{313}"),
+    ('\u{1f49}', false, "\u{39f}\u{314}"),
+    ('\u{1f4a}', false, "\u{1f48}\u{300}"),
+    ('\u{1f4b}', false, "\u{1f49}\u{300}"),
+    ('\u{1f4c}', false, "\u{1f48}\u{301}"),
+    ('\u{1f4d}', false, "\u{1f49}\u{301}"),
+    ('\u{1f50}', false, "\u{3c5}\u{313}"),
+    ('\u{1f51}', false, "\u{3c5}\u{314}"),
+    ('\u{1f52}', false, "\u{1f50}\u{300}"),
+    ('\u{1f53}', false, "\u{1f51}\u{300}"),
+    ('\u{1f54}', false, "\u{1f50}\u{301}"),
+    ('\u{1f55}', false, "\u{1f51}\u{301}"),
+    ('\u{1f56}', false, "\u{1f50}\u{342}"),
+    ('\u{1f57}', false, "\u{1f51}\u{342}"),
+    ('\u{1f59}', false, "\u{3a5}\u{314}"),
+    ('\u{1f5b}', false, "\u{1f59}\u{300}"),
+    ('\u{1f5d}', false, "\u{1f59}\u{301}"),
+    ('\u{1f5f}', false, "\u{1f59}\u{342}"),
+    ('\u{1f60}', false, "\u{3c9}\u{313}"),
+    ('\u{1f61}', false, "\u{3c9}\u{314}"),
+    ('\u{1f62}', false, "\u{1f60}\u{300}"),
+    ('\u{1f63}', false, "\u{1f61}\u{300}"),
+    ('\u{1f64}', false, "\u{1f60}\u{301}"),
+    ('\u{1f65}', false, "\u{1f61}\u{301}"),
+    ('\u{1f66}', false, "\u{1f60}\u{342}"),
+    ('\u{1f67}', false, "\u{1f61}\u{342}"),
+    ('\u{1f68}', false, "\u{3a9}\u{313}"),
+    ('\u{1f69}', false, "\u{3a9}\u{314}"),
+    ('\u{1f6a}', false, "\u{1f68}\u{300}"),
+    ('\u{1f6b}', false, "\u{1f69}\u{300}"),
+    ('\u{1f6c}', false, "\u{1f68}\u{301}"),
+    ('\u{1f6d}', false, "\u{1f69}\u{301}"),
+    ('\u{1f6e}', false, "\u{1f68}\u{342}"),
+    ('\u{1f6f}', false, "\u{1f69}\u{342}"),
+    ('\u{1f70}', false, "\u{3b1}\u{300}"),
+    ('\u{1f71}', false, "\u{3ac}"),
+    ('\u{1f72}', false, "\u{3b5}\u{300}"),
+    ('\u{1f73}', false, "\u{3ad}"),
+    ('\u{1f74}', false, "\u{3b7}\u{300}"),
+    ('\u{1f75}', false, "\u{3ae}"),
+    ('\u{1f76}', false, "\u{3b9}\u{300}"),
+    ('\u{1f77}', false, "\u{3af}"),
+    ('\u{1f78}', false, "\u{3bf}\u{300}"),
+    ('\u{1f79}', false, "\u{3cc}"),
+    ('\u{1f7a}', false, "\u{3c5}\u{300}"),
+    ('\u{1f7b}', false, "\u{3cd}"),
+    ('\u{1f7c}', false, "\u{3c9}\u{300}"),
+    ('\u{1f7d}', false, "\u{3ce}"),
+    ('\u{1f80}', false, "\u{1f00}\u{345}"),
+    ('\u{1f81}', false, "\u{1f01}\u{345}"),
+    ('\u{1f82}', false, "\u{1f02}\u{345}"),
+    ('\u{1f83}', false, "\u{1f03}\u{345}"),
+    ('\u{1f84}', false, "\u{1f04}\u{345}"),
+    ('\u{1f85}', false, "\u{1f05}\u{345}"),
+    ('\u{1f86}', false, "\u{1f06}\u{345}"),
+    ('\u{1f87}', false, "\u{1f07}\u{345}"),
+    ('\u{1f88}', false, "\u{1f08}\u{345}"),
+    ('\u{1f89}', false, "\u{1f09}\u{345}"),
+    ('\u{1f8a}', false, "\u{1f0a}\u{345}"),
+    ('\u{1f8b}', false, "\u{1f0b}\u{345}"),
+    ('\u{1f8c}', false, "\u{1f0c}\u{345}"),
+    ('\u{1f8d}', false, "\u{1f0d}\u{345}"),
+    ('\u{1f8e}', false, "\u{1f0e}\u{345}"),
+    ('\u{1f8f}', false, "\u{1f0f}\u{345}"),
+    ('\u{1f90}', false, "\u{1f20}\u{345}"),
+    ('\u{1f91}', false, "\u{1f21}\u{345}"),
+    ('\u{1f92}', false, "\u{1f22}\u{345}"),
+    ('\u{1f93}', false, "\u{1f23}\u{345}"),
+    ('\u{1f94}', false, "\u{1f24}\u{345}"),
+    ('\u{1f95}', false, "\u{1f25}\u{345}"),
+    ('\u{1f96}', false, "\u{1f26}\u{345}"),
+    ('\u{1f97}', false, "\u{1f27}\u{345}"),
+    ('\u{1f98}', false, "\u{1f28}\u{345}"),
+    ('\u{1f99}', false, "\u{1f29}\u{345}"),
+    ('\u{1f9a}', false, "\u{1f2a}\u{345}"),
+    ('\u{1f9b}', false, "\u{1f2b}\u{345}"),
+    ('\u{1f9c}', false, "\u{1f2c}\u{345}"),
+    ('\u{1f9d}', false, "\u{1f2d}\u{345}"),
+    ('\u{1f9e}', false, "\u{1f2e}\u{345}"),
+    ('\u{1f9f}', false, "\u{1f2f}\u{345}"),
+    ('\u{1fa0}', false, "\u{1f60}\u{345}"),
+    ('\u{1fa1}', false, "\u{1f61}\u{345}"),
+    ('\u{1fa2}', false, "\u{1f62}\u{345}"),
+    ('\u{1fa3}', false, "\u{1f63}\u{345}"),
+    ('\u{1fa4}', false, "\u{1f64}\u{345}"),
+    ('\u{1fa5}', false, "\u{1f65}\u{345}"),
+    ('\u{1fa6}', false, "\u{1f66}\u{345}"),
+    ('\u{1fa7}', false, "\u{1f67}\u{345}"),
+    ('\u{1fa8}', false, "\u{1f68}\u{345}"),
+    ('\u{1fa9}', false, "\u{1f69}\u{345}"),
+    ('\u{1faa}', false, "\u{1f6a}\u{345}"),
+    ('\u{1fab}', false, "\u{1f6b}\u{345}"),
+    ('\u{1fac}', false, "\u{1f6c}\u{345}"),
+    ('\u{1fad}', false, "\u{1f6d}\u{345}"),
+    ('\u{1fae}', false, "\u{1f6e}\u{345}"),
+    ('\u{1faf}', false, "\u{1f6f}\u{345}"),
+    ('\u{1fb0}', false, "\u{3b1}\u{306}"),
+    ('\u{1fb1}', false, "\u{3b1}\u{304}"),
+    ('\u{1fb2}', false, "\u{1f70}\u{345}"),
+    ('\u{1fb3}', false, "\u{3b1}\u{345}"),
+    ('\u{1fb4}', false, "\u{3ac}\u{345}"),
+    ('\u{1fb6}', false, "\u{3b1}\u{342}"),
+    ('\u{1fb7}', false, "\u{1fb6}\u{345}"),
+    ('\u{1fb8}', false, "\u{391}\u{306}"),
+    ('\u{1fb9}', false, "\u{391}\u{304}"),
+    ('\u{1fba}', false, "\u{391}\u{300}"),
+    ('\u{1fbb}', false, "\u{386}"),
+    ('\u{1fbc}', false, "\u{391}\u{345}"),
+    ('\u{1fbd}', true, "\u{20}\u{313}"),
+    ('\u{1fbe}', false, "\u{3b9}"),
+    ('\u{1fbf}', true, "\u{20}\u{313}"),
+    ('\u{1fc0}', true, "\u{20}\u{342}"),
+    ('\u{1fc1}', false, "\u{a8}\u{342}"),
+    ('\u{1fc2}', false, "\u{1f74}\u{345}"),
+    ('\u{1fc3}', false, "\u{3b7}\u{345}"),
+    ('\u{1fc4}', false, "\u{3ae}\u{345}"),
+    ('\u{1fc6}', false, "\u{3b7}\u{342}"),
+    ('\u{1fc7}', false, "\u{1fc6}\u{345}"),
+    ('\u{1fc8}', false, "\u{395}\u{300}"),
+    ('\u{1fc9}', false, "\u{388}"),
+    ('\u{1fca}', false, "\u{397}\u{300}"),
+    ('\u{1fcb}', false, "\u{389}"),
+    ('\u{1fcc}', false, "\u{397}\u{345}"),
+    ('\u{1fcd}', false, "\u{1fbf}\u{300}"),
+    ('\u{1fce}', false, "\u{1fbf}\u{301}"),
+    ('\u{1fcf}', false, "\u{1fbf}\u{342}"),
+    ('\u{1fd0}', false, "\u{3b9}\u{306}"),
+    ('\u{1fd1}', false, "\u{3b9}\u{304}"),
+    ('\u{1fd2}', false, "\u{3ca}\u{300}"),
+    ('\u{1fd3}', false, "\u{390}"),
+    ('\u{1fd6}', false, "\u{3b9}\u{342}"),
+    ('\u{1fd7}', false, "\u{3ca}\u{342}"),
+    ('\u{1fd8}', false, "\u{399}\u{306}"),
+    ('\u{1fd9}', false, "\u{399}\u{304}"),
+    ('\u{1fda}', false, "\u{399}\u{300}"),
+    ('\u{1fdb}', false, "\u{38a}"),
+    ('\u{1fdd}', false, "\u{1ffe}\u{300}"),
+    ('\u{1fde}', false, "\u{1ffe}\u{301}"),
+    ('\u{1fdf}', false, "\u{1ffe}\u{342}"),
+    ('\u{1fe0}', false, "\u{3c5}\u{306}"),
+    ('\u{1fe1}', false, "\u{3c5}\u{304}"),
+    ('\u{1fe2}', false, "\u{3cb}\u{300}"),
+    ('\u{1fe3}', false, "\u{3b0}"),
+    ('\u{1fe4}', false, "\u{3c1}\u{313}"),
+    ('\u{1fe5}', false, "\u{3c1}\u{314}"),
+    ('\u{1fe6}', false, "\u{3c5}\u{342}"),
+    ('\u{1fe7}', false, "\u{3cb}\u{342}"),
+    ('\u{1fe8}', false, "\u{3a5}\u{306}"),
+    ('\u{1fe9}', false, "\u{3a5}\u{304}"),
+    ('\u{1fea}', false, "\u{3a5}\u{300}"),
+    ('\u{1feb}', false, "\u{38e}"),
+    ('\u{1fec}', false, "\u{3a1}\u{314}"),
+    ('\u{1fed}', false, "\u{a8}\u{300}"),
+    ('\u{1fee}', false, "\u{385}"),
+    ('\u{1fef}', false, "\u{60}"),
+    ('\u{1ff2}', false, "\u{1f7c}\u{345}"),
+    ('\u{1ff3}', false, "\u{3c9}\u{345}"),
+    ('\u{1ff4}', false, "\u{3ce}\u{345}"),
+    ('\u{1ff6}', false, "\u{3c9}\u{342}"),
+    ('\u{1ff7}', false, "\u{1ff6}\u{345}"),
+    ('\u{1ff8}', false, "\u{39f}\u{300}"),
+    ('\u{1ff9}', false, "\u{38c}"),
+    ('\u{1ffa}', false, "\u{3a9}\u{300}"),
+    ('\u{1ffb}', false, "\u{38f}"),
+    ('\u{1ffc}', false, "\u{3a9}\u{345}"),
+    ('\u{1ffd}', false, "\u{b4}"),
+    ('\u{1ffe}', true, "\u{20}\u{314}"),
+    ('\u{2000}', false, "\u{2002}"),
+    ('\u{2001}', false, "\u{2003}"),
+    ('\u{2002}', true, "\u{20}"),
+    ('\u{2003}', true, "\u{20}"),
+    ('\u{2004}', true, "\u{20}"),
+    ('\u{2005}', true, "\u{20}"),
+    ('\u{2006}', true, "\u{20}"),
+    ('\u{2007}', true, "\u{20}"),
+    ('\u{2008}', true, "\u{20}"),
+    ('\u{2009}', true, "\u{20}"),
+    ('\u{200a}', true, "\u{20}"),
+    ('\u{2011}', true, "\u{2010}"),
+    ('\u{2017}', true, "\u{20}\u{333}"),
+    ('\u{2024}', true, "\u{2e}"),
+    ('\u{2025}', true, "\u{2e}\u{2e}"),
+    ('\u{2026}', true, "\u{2e}\u{2e}\u{2e}"),
+    ('\u{202f}', true, "\u{20}"),
+    ('\u{2033}', true, "\u{2032}\u{2032}"),
+    ('\u{2034}', true, "\u{2032}\u{2032}\u{2032}"),
+    ('\u{2036}', true, "\u{2035}\u{2035}"),
+    ('\u{2037}', true, "\u{2035}\u{2035}\u{2035}"),
+    ('\u{203c}', true, "\u{21}\u{21}"),
+    ('\u{203e}', true, "\u{20}\u{305}"),
+    ('\u{2047}', true, "\u{3f}\u{3f}"),
+    ('\u{2048}', true, "\u{3f}\u{21}"),
+    ('\u{2049}', true, "\u{21}\u{3f}"),
+    ('\u{2057}', true, "\u{2032}\u{2032}\u{2032}\u{2032}"),
+    ('\u{205f}', true, "\u{20}"),
+    ('\u{2070}', true, "\u{30}"),
+    ('\u{2071}', true, "\u{69}"),
+    ('\u{2074}', true, "\u{34}"),
+    ('\u{2075}', true, "\u{35}"),
+    ('\u{2076}', true, "\u{36}"),
+    ('\u{2077}', true, "\u{37}"),
+    ('\u{2078}', true, "\u{38}"),
+    ('\u{2079}', true, "\u{39}"),
+    ('\u{207a}', true, "\u{2b}"),
+    ('\u{207b}', true, "\u{2212}"),
+    ('\u{207c}', true, "\u{3d}"),
+    ('\u{207d}', true, "\u{28}"),
+    ('\u{207e}', true, "\u{29}"),
+    ('\u{207f}', true, "\u{6e}"),
+    ('\u{2080}', true, "\u{30}"),
+    ('\u{2081}', true, "\u{31}"),
+    ('\u{2082}', true, "\u{32}"),
+    ('\u{2083}', true, "\u{33}"),
+    ('\u{2084}', true, "\u{34}"),
+    ('\u{2085}', true, "\u{35}"),
+    ('\u{2086}', true, "\u{36}"),
+    ('\u{2087}', true, "\u{37}"),
+    ('\u{2088}', true, "\u{38}"),
+    ('\u{2089}', true, "\u{39}"),
+    ('\u{208a}', true, "\u{2b}"),
+    ('\u{208b}', true, "\u{2212}"),
+    ('\u{208c}', true, "\u{3d}"),
+    ('\u{208d}', true, "\u{28}"),
+    ('\u{208e}', true, "\u{29}"),
+    ('\u{2090}', true, "\u{61}"),
+    ('\u{2091}', true, "\u{65}"),
+    ('\u{2092}', true, "\u{6f}"),
+    ('\u{2093}', true, "\u{78}"),
+    ('\u{2094}', true, "\u{259}"),
+    ('\u{2095}', true, "\u{68}"),
+    ('\u{2096}', true, "\u{6b}"),
+    ('\u{2097}', true, "\u{6c}"),
+    ('\u{2098}', true, "\u{6d}"),
+    ('\u{2099}', true, "\u{6e}"),
+    ('\u{209a}', true, "\u{70}"),
+    ('\u{209b}', true, "\u{73}"),
+    ('\u{209c}', true, "\u{74}"),
+    ('\u{20a8}', true, "\u{52}\u{73}"),
+    ('\u{2100}', true, "\u{61}\u{2f}\u{63}"),
+    ('\u{2101}', true, "\u{61}\u{2f}\u{73}"),
+    ('\u{2102}', true, "\u{43}"),
+    ('\u{2103}', true, "\u{b0}\u{43}"),
+    ('\u{2105}', true, "\u{63}\u{2f}\u{6f}"),
+    ('\u{2106}', true, "\u{63}\u{2f}\u{75}"),
+    ('\u{2107}', true, "\u{190}"),
+    ('\u{2109}', true, "\u{b0}\u{46}"),
+    ('\u{210a}', true, "\u{67}"),
+    ('\u{210b}', true, "\u{48}"),
+    ('\u{210c}', true, "\u{48}"),
+    ('\u{210d}', true, "\u{48}"),
+    ('\u{210e}', true, "\u{68}"),
+    ('\u{210f}', true, "\u{127}"),
+    ('\u{2110}', true, "\u{49}"),
+    ('\u{2111}', true, "\u{49}"),
+    ('\u{2112}', true, "\u{4c}"),
+    ('\u{2113}', true, "\u{6c}"),
+    ('\u{2115}', true, "\u{4e}"),
+    ('\u{2116}', true, "\u{4e}\u{6f}"),
+    ('\u{2119}', true, "\u{50}"),
+    ('\u{211a}', true, "\u{51}"),
+    ('\u{211b}', true, "\u{52}"),
+    ('\u{211c}', true, "\u{52}"),
+    ('\u{211d}', true, "\u{52}"),
+    ('\u{2120}', true, "\u{53}\u{4d}"),
+    ('\u{2121}', true, "\u{54}\u{45}\u{4c}"),
+    ('\u{2122}', true, "\u{54}\u{4d}"),
+    ('\u{2124}', true, "\u{5a}"),
+    ('\u{2126}', false, "\u{3a9}"),
+    ('\u{2128}', true, "\u{5a}"),
+    ('\u{212a}', false, "\u{4b}"),
+    ('\u{212b}', false, "\u{c5}"),
+    ('\u{212c}', true, "\u{42}"),
+    ('\u{212d}', true, "\u{43}"),
+    ('\u{212f}', true, "\u{65}"),
+    ('\u{2130}', true, "\u{45}"),
+    ('\u{2131}', true, "\u{46}"),
+    ('\u{2133}', true, "\u{4d}"),
+    ('\u{2134}', true, "\u{6f}"),
+    ('\u{2135}', true, "\u{5d0}"),
+    ('\u{2136}', true, "\u{5d1}"),
+    ('\u{2137}', true, "\u{5d2}"),
+    ('\u{2138}', true, "\u{5d3}"),
+    ('\u{2139}', true, "\u{69}"),
+    ('\u{213b}', true, "\u{46}\u{41}\u{58}"),
+    ('\u{213c}', true, "\u{3c0}"),
+    ('\u{213d}', true, "\u{3b3}"),
+    ('\u{213e}', true, "\u{393}"),
+    ('\u{213f}', true, "\u{3a0}"),
+    ('\u{2140}', true, "\u{2211}"),
+    ('\u{2145}', true, "\u{44}"),
+    ('\u{2146}', true, "\u{64}"),
+    ('\u{2147}', true, "\u{65}"),
+    ('\u{2148}', true, "\u{69}"),
+    ('\u{2149}', true, "\u{6a}"),
+    ('\u{2150}', true, "\u{31}\u{2044}\u{37}"),
+    ('\u{2151}', true, "\u{31}\u{2044}\u{39}"),
+    ('\u{2152}', true, "\u{31}\u{2044}\u{31}\u{30}"),
+    ('\u{2153}', true, "\u{31}\u{2044}\u{33}"),
+    ('\u{2154}', true, "\u{32}\u{2044}\u{33}"),
+    ('\u{2155}', true, "\u{31}\u{2044}\u{35}"),
+    ('\u{2156}', true, "\u{32}\u{2044}\u{35}"),
+    ('\u{2157}', true, "\u{33}\u{2044}\u{35}"),
+    ('\u{2158}', true, "\u{34}\u{2044}\u{35}"),
+    ('\u{2159}', true, "\u{31}\u{2044}\u{36}"),
+    ('\u{215a}', true, "\u{35}\u{2044}\u{36}"),
+    ('\u{215b}', true, "\u{31}\u{2044}\u{38}"),
+    ('\u{215c}', true, "\u{33}\u{2044}\u{38}"),
+    ('\u{215d}', true, "\u{35}\u{2044}\u{38}"),
+    ('\u{215e}', true, "\u{37}\u{2044}\u{38}"),
+    ('\u{215f}', true, "\u{31}\u{2044}"),
+    ('\u{2160}', true, "\u{49}"),
+    ('\u{2161}', true, "\u{49}\u{49}"),
+    ('\u{2162}', true, "\u{49}\u{49}\u{49}"),
+    ('\u{2163}', true, "\u{49}\u{56}"),
+    ('\u{2164}', true, "\u{56}"),
+    ('\u{2165}', true, "\u{56}\u{49}"),
+    ('\u{2166}', true, "\u{56}\u{49}\u{49}"),
+    ('\u{2167}', true, "\u{56}\u{49}\u{49}\u{49}"),
+    ('\u{2168}', true, "\u{49}\u{58}"),
+    ('\u{2169}', true, "\u{58}"),
+    ('\u{216a}', true, "\u{58}\u{49}"),
+    ('\u{216b}', true, "\u{58}\u{49}\u{49}"),
+    ('\u{216c}', true, "\u{4c}"),
+    ('\u{216d}', true, "\u{43}"),
+    ('\u{216e}', true, "\u{44}"),
+    ('\u{216f}', true, "\u{4d}"),
+    ('\u{2170}', true, "\u{69}"),
+    ('\u{2171}', true, "\u{69}\u{69}"),
+    ('\u{2172}', true, "\u{69}\u{69}\u{69}"),
+    ('\u{2173}', true, "\u{69}\u{76}"),
+    ('\u{2174}', true, "\u{76}"),
+    ('\u{2175}', true, "\u{76}\u{69}"),
+    ('\u{2176}', true, "\u{76}\u{69}\u{69}"),
+    ('\u{2177}', true, "\u{76}\u{69}\u{69}\u{69}"),
+    ('\u{2178}', true, "\u{69}\u{78}"),
+    ('\u{2179}', true, "\u{78}"),
+    ('\u{217a}', true, "\u{78}\u{69}"),
+    ('\u{217b}', true, "\u{78}\u{69}\u{69}"),
+    ('\u{217c}', true, "\u{6c}"),
+    ('\u{217d}', true, "\u{63}"),
+    ('\u{217e}', true, "\u{64}"),
+    ('\u{217f}', true, "\u{6d}"),
+    ('\u{2189}', true, "\u{30}\u{2044}\u{33}"),
+    ('\u{219a}', false, "\u{2190}\u{338}"),
+    ('\u{219b}', false, "\u{2192}\u{338}"),
+    ('\u{21ae}', false, "\u{2194}\u{338}"),
+    ('\u{21cd}', false, "\u{21d0}\u{338}"),
+    ('\u{21ce}', false, "\u{21d4}\u{338}"),
+    ('\u{21cf}', false, "\u{21d2}\u{338}"),
+    ('\u{2204}', false, "\u{2203}\u{338}"),
+    ('\u{2209}', false, "\u{2208}\u{338}"),
+    ('\u{220c}', false, "\u{220b}\u{338}"),
+    ('\u{2224}', false, "\u{2223}\u{338}"),
+    ('\u{2226}', false, "\u{2225}\u{338}"),
+    ('\u{222c}', true, "\u{222b}\u{222b}"),
+    ('\u{222d}', true, "\u{222b}\u{222b}\u{222b}"),
+    ('\u{222f}', true, "\u{222e}\u{222e}"),
+    ('\u{2230}', true, "\u{222e}\u{222e}\u{222e}"),
+    ('\u{2241}', false, "\u{223c}\u{338}"),
+    ('\u{2244}', false, "\u{2243}\u{338}"),
+    ('\u{2247}', false, "\u{2245}\u{338}"),
+    ('\u{2249}', false, "\u{2248}\u{338}"),
+    ('\u{2260}', false, "\u{3d}\u{338}"),
+    ('\u{2262}', false, "\u{2261}\u{338}"),
+    ('\u{226d}', false, "\u{224d}\u{338}"),
+    ('\u{226e}', false, "\u{3c}\u{338}"),
+    ('\u{226f}', false, "\u{3e}\u{338}"),
+    ('\u{2270}', false, "\u{2264}\u{338}"),
+    ('\u{2271}', false, "\u{2265}\u{338}"),
+    ('\u{2274}', false, "\u{2272}\u{338}"),
+    ('\u{2275}', false, "\u{2273}\u{338}"),
+    ('\u{2278}', false, "\u{2276}\u{338}"),
+    ('\u{2279}', false, "\u{2277}\u{338}"),
+    ('\u{2280}', false, "\u{227a}\u{338}"),
+    ('\u{2281}', false, "\u{227b}\u{338}"),
+    ('\u{2284}', false, "\u{2282}\u{338}"),
+    ('\u{2285}', false, "\u{2283}\u{338}"),
+    ('\u{2288}', false, "\u{2286}\u{338}"),
+    ('\u{2289}', false, "\u{2287}\u{338}"),
+    ('\u{22ac}', false, "\u{22a2}\u{338}"),
+    ('\u{22ad}', false, "\u{22a8}\u{338}"),
+    ('\u{22ae}', false, "\u{22a9}\u{338}"),
+    ('\u{22af}', false, "\u{22ab}\u{338}"),
+    ('\u{22e0}', false, "\u{227c}\u{338}"),
+    ('\u{22e1}', false, "\u{227d}\u{338}"),
+    ('\u{22e2}', false, "\u{2291}\u{338}"),
+    ('\u{22e3}', false, "\u{2292}\u{338}"),
+    ('\u{22ea}', false, "\u{22b2}\u{338}"),
+    ('\u{22eb}', false, "\u{22b3}\u{338}"),
+    ('\u{22ec}', false, "\u{22b4}\u{338}"),
+    ('\u{22ed}', false, "\u{22b5}\u{338}"),
+    ('\u{2329}', false, "\u{3008}"),
+    ('\u{232a}', false, "\u{3009}"),
+    ('\u{2460}', true, "\u{31}"),
+    ('\u{2461}', true, "\u{32}"),
+    ('\u{2462}', true, "\u{33}"),
+    ('\u{2463}', true, "\u{34}"),
+    ('\u{2464}', true, "\u{35}"),
+    ('\u{2465}', true, "\u{36}"),
+    ('\u{2466}', true, "\u{37}"),
+    ('\u{2467}', true, "\u{38}"),
+    ('\u{2468}', true, "\u{39}"),
+    ('\u{2469}', true, "\u{31}\u{30}"),
+    ('\u{246a}', true, "\u{31}\u{31}"),
+    ('\u{246b}', true, "\u{31}\u{32}"),
+    ('\u{246c}', true, "\u{31}\u{33}"),
+    ('\u{246d}', true, "\u{31}\u{34}"),
+    ('\u{246e}', true, "\u{31}\u{35}"),
+    ('\u{246f}', true, "\u{31}\u{36}"),
+    ('\u{2470}', true, "\u{31}\u{37}"),
+    ('\u{2471}', true, "\u{31}\u{38}"),
+    ('\u{2472}', true, "\u{31}\u{39}"),
+    ('\u{2473}', true, "\u{32}\u{30}"),
+    ('\u{2474}', true, "\u{28}\u{31}\u{29}"),
+    ('\u{2475}', true, "\u{28}\u{32}\u{29}"),
+    ('\u{2476}', true, "\u{28}\u{33}\u{29}"),
+    ('\u{2477}', true, "\u{28}\u{34}\u{29}"),
+    ('\u{2478}', true, "\u{28}\u{35}\u{29}"),
+    ('\u{2479}', true, "\u{28}\u{36}\u{29}"),
+    ('\u{247a}', true, "\u{28}\u{37}\u{29}"),
+    ('\u{247b}', true, "\u{28}\u{38}\u{29}"),
+    ('\u{247c}', true, "\u{28}\u{39}\u{29}"),
+    ('\u{247d}', true, "\u{28}\u{31}\u{30}\u{29}"),
+    ('\u{247e}', true, "\u{28}\u{31}\u{31}\u{29}"),
+    ('\u{247f}', true, "\u{28}\u{31}\u{32}\u{29}"),
+    ('\u{2480}', true, "\u{28}\u{31}\u{33}\u{29}"),
+    ('\u{2481}', true, "\u{28}\u{31}\u{34}\u{29}"),
+    ('\u{2482}', true, "\u{28}\u{31}\u{35}\u{29}"),
+    ('\u{2483}', true, "\u{28}\u{31}\u{36}\u{29}"),
+    ('\u{2484}', true, "\u{28}\u{31}\u{37}\u{29}"),
+    ('\u{2485}', true, "\u{28}\u{31}\u{38}\u{29}"),
+    ('\u{2486}', true, "\u{28}\u{31}\u{39}\u{29}"),
+    ('\u{2487}', true, "\u{28}\u{32}\u{30}\u{29}"),
+    ('\u{2488}', true, "\u{31}\u{2e}"),
+    ('\u{2489}', true, "\u{32}\u{2e}"),
+    ('\u{248a}', true, "\u{33}\u{2e}"),
+    ('\u{248b}', true, "\u{34}\u{2e}"),
+    ('\u{248c}', true, "\u{35}\u{2e}"),
+    ('\u{248d}', true, "\u{36}\u{2e}"),
+    ('\u{248e}', true, "\u{37}\u{2e}"),
+    ('\u{248f}', true, "\u{38}\u{2e}"),
+    ('\u{2490}', true, "\u{39}\u{2e}"),
+    ('\u{2491}', true, "\u{31}\u{30}\u{2e}"),
+    ('\u{2492}', true, "\u{31}\u{31}\u{2e}"),
+    ('\u{2493}', true, "\u{31}\u{32}\u{2e}"),
+    ('\u{2494}', true, "\u{31}\u{33}\u{2e}"),
+    ('\u{2495}', true, "\u{31}\u{34}\u{2e}"),
+    ('\u{2496}', true, "\u{31}\u{35}\u{2e}"),
+    ('\u{2497}', true, "\u{31}\u{36}\u{2e}"),
+    ('\u{2498}', true, "\u{31}\u{37}\u{2e}"),
+    ('\u{2499}', true, "\u{31}\u{38}\u{2e}"),
+    ('\u{249a}', true, "\u{31}\u{39}\u{2e}"),
+    ('\u{249b}', true, "\u{32}\u{30}\u{2e}"),
+    ('\u{249c}', true, "\u{28}\u{61}\u{29}"),
+    ('\u{249d}', true, "\u{28}\u{62}\u{29}"),
+    ('\u{249e}', true, "\u{28}\u{63}\u{29}"),
+    ('\u{249f}', true, "\u{28}\u{64}\u{29}"),
+    ('\u{24a0}', true, "\u{28}\u{65}\u{29}"),
+    ('\u{24a1}', true, "\u{28}\u{66}\u{29}"),
+    ('\u{24a2}', true, "\u{28}\u{67}\u{29}"),
+    ('\u{24a3}', true, "\u{28}\u{68}\u{29}"),
+    ('\u{24a4}', true, "\u{28}\u{69}\u{29}"),
+    ('\u{24a5}', true, "\u{28}\u{6a}\u{29}"),
+    ('\u{24a6}', true, "\u{28}\u{6b}\u{29}"),
+    ('\u{24a7}', true, "\u{28}\u{6c}\u{29}"),
+    ('\u{24a8}', true, "\u{28}\u{6d}\u{29}"),
+    ('\u{24a9}', true, "\u{28}\u{6e}\u{29}"),
+    ('\u{24aa}', true, "\u{28}\u{6f}\u{29}"),
+    ('\u{24ab}', true, "\u{28}\u{70}\u{29}"),
+    ('\u{24ac}', true, "\u{28}\u{71}\u{29}"),
+    ('\u{24ad}', true, "\u{28}\u{72}\u{29}"),
+    ('\u{24ae}', true, "\u{28}\u{73}\u{29}"),
+    ('\u{24af}', true, "\u{28}\u{74}\u{29}"),
+    ('\u{24b0}', true, "\u{28}\u{75}\u{29}"),
+    ('\u{24b1}', true, "\u{28}\u{76}\u{29}"),
+    ('\u{24b2}', true, "\u{28}\u{77}\u{29}"),
+    ('\u{24b3}', true, "\u{28}\u{78}\u{29}"),
+    ('\u{24b4}', true, "\u{28}\u{79}\u{29}"),
+    ('\u{24b5}', true, "\u{28}\u{7a}\u{29}"),
+    ('\u{24b6}', true, "\u{41}"),
+    ('\u{24b7}', true, "\u{42}"),
+    ('\u{24b8}', true, "\u{43}"),
+    ('\u{24b9}', true, "\u{44}"),
+    ('\u{24ba}', true, "\u{45}"),
+    ('\u{24bb}', true, "\u{46}"),
+    ('\u{24bc}', true, "\u{47}"),
+    ('\u{24bd}', true, "\u{48}"),
+    ('\u{24be}', true, "\u{49}"),
+    ('\u{24bf}', true, "\u{4a}"),
+    ('\u{24c0}', true, "\u{4b}"),
+    ('\u{24c1}', true, "\u{4c}"),
+    ('\u{24c2}', true, "\u{4d}"),
+    ('\u{24c3}', true, "\u{4e}"),
+    ('\u{24c4}', true, "\u{4f}"),
+    ('\u{24c5}', true, "\u{50}"),
+    ('\u{24c6}', true, "\u{51}"),
+    ('\u{24c7}', true, "\u{52}"),
+    ('\u{24c8}', true, "\u{53}"),
+    ('\u{24c9}', true, "\u{54}"),
+    ('\u{24ca}', true, "\u{55}"),
+    ('\u{24cb}', true, "\u{56}"),
+    ('\u{24cc}', true, "\u{57}"),
+    ('\u{24cd}', true, "\u{58}"),
+    ('\u{24ce}', true, "\u{59}"),
+    ('\u{24cf}', true, "\u{5a}"),
+    ('\u{24d0}', true, "\u{61}"),
+    ('\u{24d1}', true, "\u{62}"),
+    ('\u{24d2}', true, "\u{63}"),
+    ('\u{24d3}', true, "\u{64}"),
+    ('\u{24d4}', true, "\u{65}"),
+    ('\u{24d5}', true, "\u{66}"),
+    ('\u{24d6}', true, "\u{67}"),
+    ('\u{24d7}', true, "\u{68}"),
+    ('\u{24d8}', true, "\u{69}"),
+    ('\u{24d9}', true, "\u{6a}"),
+    ('\u{24da}', true, "\u{6b}"),
+    ('\u{24db}', true, "\u{6c}"),
+    ('\u{24dc}', true, "\u{6d}"),
+    ('\u{24dd}', true, "\u{6e}"),
+    ('\u{24de}', true, "\u{6f}"),
+    ('\u{24df}', true, "\u{70}"),
+    ('\u{24e0}', true, "\u{71}"),
+    ('\u{24e1}', true, "\u{72}"),
+    ('\u{24e2}', true, "\u{73}"),
+    ('\u{24e3}', true, "\u{74}"),
+    ('\u{24e4}', true, "\u{75}"),
+    ('\u{24e5}', true, "\u{76}"),
+    ('\u{24e6}', true, "\u{77}"),
+    ('\u{24e7}', true, "\u{78}"),
+    ('\u{24e8}', true, "\u{79}"),
+    ('\u{24e9}', true, "\u{7a}"),
+    ('\u{24ea}', true, "\u{30}"),
+    ('\u{2a0c}', true, "\u{222b}\u{222b}\u{222b}\u{222b}"),
+    ('\u{2a74}', true, "\u{3a}\u{3a}\u{3d}"),
+    ('\u{2a75}', true, "\u{3d}\u{3d}"),
+    ('\u{2a76}', true, "\u{3d}\u{3d}\u{3d}"),
+    ('\u{2adc}', false, "\u{2add}\u{338}"),
+    ('\u{2c7c}', true, "\u{6a}"),
+    ('\u{2c7d}', true, "\u{56}"),
+    ('\u{2d6f}', true, "\u{2d61}"),
+    ('\u{2e9f}', true, "\u{6bcd}"),
+    ('\u{2ef3}', true, "\u{9f9f}"),
+    ('\u{2f00}', true, "\u{4e00}"),
+    ('\u{2f01}', true, "\u{4e28}"),
+    ('\u{2f02}', true, "\u{4e36}"),
+    ('\u{2f03}', true, "\u{4e3f}"),
+    ('\u{2f04}', true, "\u{4e59}"),
+    ('\u{2f05}', true, "\u{4e85}"),
+    ('\u{2f06}', true, "\u{4e8c}"),
+    ('\u{2f07}', true, "\u{4ea0}"),
+    ('\u{2f08}', true, "\u{4eba}"),
+    ('\u{2f09}', true, "\u{513f}"),
+    ('\u{2f0a}', true, "\u{5165}"),
+    ('\u{2f0b}', true, "\u{516b}"),
+    ('\u{2f0c}', true, "\u{5182}"),
+    ('\u{2f0d}', true, "\u{5196}"),
+    ('\u{2f0e}', true, "\u{51ab}"),
+    ('\u{2f0f}', true, "\u{51e0}"),
+    ('\u{2f10}', true, "\u{51f5}"),
+    ('\u{2f11}', true, "\u{5200}"),
+    ('\u{2f12}', true, "\u{529b}"),
+    ('\u{2f13}', true, "\u{52f9}"),
+    ('\u{2f14}', true, "\u{5315}"),
+    ('\u{2f15}', true, "\u{531a}"),
+    ('\u{2f16}', true, "\u{5338}"),
+    ('\u{2f17}', true, "\u{5341}"),
+    ('\u{2f18}', true, "\u{535c}"),
+    ('\u{2f19}', true, "\u{5369}"),
+    ('\u{2f1a}', true, "\u{5382}"),
+    ('\u{2f1b}', true, "\u{53b6}"),
+    ('\u{2f1c}', true, "\u{53c8}"),
+    ('\u{2f1d}', true, "\u{53e3}"),
+    ('\u{2f1e}', true, "\u{56d7}"),
+    ('\u{2f1f}', true, "\u{571f}"),
+    ('\u{2f20}', true, "\u{58eb}"),
+    ('\u{2f21}', true, "\u{5902}"),
+    ('\u{2f22}', true, "\u{590a}"),
+    ('\u{2f23}', true, "\u{5915}"),
+    ('\u{2f24}', true, "\u{5927}"),
+    ('\u{2f25}', true, "\u{5973}"),
+    ('\u{2f26}', true, "\u{5b50}"),
+    ('\u{2f27}', true, "\u{5b80}"),
+    ('\u{2f28}', true, "\u{5bf8}"),
+    ('\u{2f29}', true, "\u{5c0f}"),
+    ('\u{2f2a}', true, "\u{5c22}"),
+    ('\u{2f2b}', true, "\u{5c38}"),
+    ('\u{2f2c}', true, "\u{5c6e}"),
+    ('\u{2f2d}', true, "\u{5c71}"),
+    ('\u{2f2e}', true, "\u{5ddb}"),
+    ('\u{2f2f}', true, "\u{5de5}"),
+    ('\u{2f30}', true, "\u{5df1}"),
+    ('\u{2f31}', true, "\u{5dfe}"),
+    ('\u{2f32}', true, "\u{5e72}"),
+    ('\u{2f33}', true, "\u{5e7a}"),
+    ('\u{2f34}', true, "\u{5e7f}"),
+    ('\u{2f35}', true, "\u{5ef4}"),
+    ('\u{2f36}', true, "\u{5efe}"),
+    ('\u{2f37}', true, "\u{5f0b}"),
+    ('\u{2f38}', true, "\u{5f13}"),
+    ('\u{2f39}', true, "\u{5f50}"),
+    ('\u{2f3a}', true, "\u{5f61}"),
+    ('\u{2f3b}', true, "\u{5f73}"),
+    ('\u{2f3c}', true, "\u{5fc3}"),
+    ('\u{2f3d}', true, "\u{6208}"),
+    ('\u{2f3e}', true, "\u{6236}"),
+    ('\u{2f3f}', true, "\u{624b}"),
+    ('\u{2f40}', true, "\u{652f}"),
+    ('\u{2f41}', true, "\u{6534}"),
+    ('\u{2f42}', true, "\u{6587}"),
+    ('\u{2f43}', true, "\u{6597}"),
+    ('\u{2f44}', true, "\u{65a4}"),
+    ('\u{2f45}', true, "\u{65b9}"),
+    ('\u{2f46}', true, "\u{65e0}"),
+    ('\u{2f47}', true, "\u{65e5}"),
+    ('\u{2f48}', true, "\u{66f0}"),
+    ('\u{2f49}', true, "\u{6708}"),
+    ('\u{2f4a}', true, "\u{6728}"),
+    ('\u{2f4b}', true, "\u{6b20}"),
+    ('\u{2f4c}', true, "\u{6b62}"),
+    ('\u{2f4d}', true, "\u{6b79}"),
+    ('\u{2f4e}', true, "\u{6bb3}"),
+    ('\u{2f4f}', true, "\u{6bcb}"),
+    ('\u{2f50}', true, "\u{6bd4}"),
+    ('\u{2f51}', true, "\u{6bdb}"),
+    ('\u{2f52}', true, "\u{6c0f}"),
+    ('\u{2f53}', true, "\u{6c14}"),
+    ('\u{2f54}', true, "\u{6c34}"),
+    ('\u{2f55}', true, "\u{706b}"),
+    ('\u{2f56}', true, "\u{722a}"),
+    ('\u{2f57}', true, "\u{7236}"),
+    ('\u{2f58}', true, "\u{723b}"),
+    ('\u{2f59}', true, "\u{723f}"),
+    ('\u{2f5a}', true, "\u{7247}"),
+    ('\u{2f5b}', true, "\u{7259}"),
+    ('\u{2f5c}', true, "\u{725b}"),
+    ('\u{2f5d}', true, "\u{72ac}"),
+    ('\u{2f5e}', true, "\u{7384}"),
+    ('\u{2f5f}', true, "\u{7389}"),
+    ('\u{2f60}', true, "\u{74dc}"),
+    ('\u{2f61}', true, "\u{74e6}"),
+    ('\u{2f62}', true, "\u{7518}"),
+    ('\u{2f63}', true, "\u{751f}"),
+    ('\u{2f64}', true, "\u{7528}"),
+    ('\u{2f65}', true, "\u{7530}"),
+    ('\u{2f66}', true, "\u{758b}"),
+    ('\u{2f67}', true, "\u{7592}"),
+    ('\u{2f68}', true, "\u{7676}"),
+    ('\u{2f69}', true, "\u{767d}"),
+    ('\u{2f6a}', true, "\u{76ae}"),
+    ('\u{2f6b}', true, "\u{76bf}"),
+    ('\u{2f6c}', true, "\u{76ee}"),
+    ('\u{2f6d}', true, "\u{77db}"),
+    ('\u{2f6e}', true, "\u{77e2}"),
+    ('\u{2f6f}', true, "\u{77f3}"),
+    ('\u{2f70}', true, "\u{793a}"),
+    ('\u{2f71}', true, "\u{79b8}"),
+    ('\u{2f72}', true, "\u{79be}"),
+    ('\u{2f73}', true, "\u{7a74}"),
+    ('\u{2f74}', true, "\u{7acb}"),
+    ('\u{2f75}', true, "\u{7af9}"),
+    ('\u{2f76}', true, "\u{7c73}"),
+    ('\u{2f77}', true, "\u{7cf8}"),
+    ('\u{2f78}', true, "\u{7f36}"),
+    ('\u{2f79}', true, "\u{7f51}"),
+    ('\u{2f7a}', true, "\u{7f8a}"),
+    ('\u{2f7b}', true, "\u{7fbd}"),
+    ('\u{2f7c}', true, "\u{8001}"),
+    ('\u{2f7d}', true, "\u{800c}"),
+    ('\u{2f7e}', true, "\u{8012}"),
+    ('\u{2f7f}', true, "\u{8033}"),
+    ('\u{2f80}', true, "\u{807f}"),
+    ('\u{2f81}', true, "\u{8089}"),
+    ('\u{2f82}', true, "\u{81e3}"),
+    ('\u{2f83}', true, "\u{81ea}"),
+    ('\u{2f84}', true, "\u{81f3}"),
+    ('\u{2f85}', true, "\u{81fc}"),
+    ('\u{2f86}', true, "\u{820c}"),
+    ('\u{2f87}', true, "\u{821b}"),
+    ('\u{2f88}', true, "\u{821f}"),
+    ('\u{2f89}', true, "\u{826e}"),
+    ('\u{2f8a}', true, "\u{8272}"),
+    ('\u{2f8b}', true, "\u{8278}"),
+    ('\u{2f8c}', true, "\u{864d}"),
+    ('\u{2f8d}', true, "\u{866b}"),
+    ('\u{2f8e}', true, "\u{8840}"),
+    ('\u{2f8f}', true, "\u{884c}"),
+    ('\u{2f90}', true, "\u{8863}"),
+    ('\u{2f91}', true, "\u{897e}"),
+    ('\u{2f92}', true, "\u{898b}"),
+    ('\u{2f93}', true, "\u{89d2}"),
+    ('\u{2f94}', true, "\u{8a00}"),
+    ('\u{2f95}', true, "\u{8c37}"),
+    ('\u{2f96}', true, "\u{8c46}"),
+    ('\u{2f97}', true, "\u{8c55}"),
+    ('\u{2f98}', true, "\u{8c78}"),
+    ('\u{2f99}', true, "\u{8c9d}"),
+    ('\u{2f9a}', true, "\u{8d64}"),
+    ('\u{2f9b}', true, "\u{8d70}"),
+    ('\u{2f9c}', true, "\u{8db3}"),
+    ('\u{2f9d}', true, "\u{8eab}"),
+    ('\u{2f9e}', true, "\u{8eca}"),
+    ('\u{2f9f}', true, "\u{8f9b}"),
+    ('\u{2fa0}', true, "\u{8fb0}"),
+    ('\u{2fa1}', true, "\u{8fb5}"),
+    ('\u{2fa2}', true, "\u{9091}"),
+    ('\u{2fa3}', true, "\u{9149}"),
+    ('\u{2fa4}', true, "\u{91c6}"),
+    ('\u{2fa5}', true, "\u{91cc}"),
+    ('\u{2fa6}', true, "\u{91d1}"),
+    ('\u{2fa7}', true, "\u{9577}"),
+    ('\u{2fa8}', true, "\u{9580}"),
+    ('\u{2fa9}', true, "\u{961c}"),
+    ('\u{2faa}', true, "\u{96b6}"),
+    ('\u{2fab}', true, "\u{96b9}"),
+    ('\u{2fac}', true, "\u{96e8}"),
+    ('\u{2fad}', true, "\u{9751}"),
+    ('\u{2fae}', true, "\u{975e}"),
+    ('\u{2faf}', true, "\u{9762}"),
+    ('\u{2fb0}', true, "\u{9769}"),
+    ('\u{2fb1}', true, "\u{97cb}"),
+    ('\u{2fb2}', true, "\u{97ed}"),
+    ('\u{2fb3}', true, "\u{97f3}"),
+    ('\u{2fb4}', true, "\u{9801}"),
+    ('\u{2fb5}', true, "\u{98a8}"),
+    ('\u{2fb6}', true, "\u{98db}"),
+    ('\u{2fb7}', true, "\u{98df}"),
+    ('\u{2fb8}', true, "\u{9996}"),
+    ('\u{2fb9}', true, "\u{9999}"),
+    ('\u{2fba}', true, "\u{99ac}"),
+    ('\u{2fbb}', true, "\u{9aa8}"),
+    ('\u{2fbc}', true, "\u{9ad8}"),
+    ('\u{2fbd}', true, "\u{9adf}"),
+    ('\u{2fbe}', true, "\u{9b25}"),
+    ('\u{2fbf}', true, "\u{9b2f}"),
+    ('\u{2fc0}', true, "\u{9b32}"),
+    ('\u{2fc1}', true, "\u{9b3c}"),
+    ('\u{2fc2}', true, "\u{9b5a}"),
+    ('\u{2fc3}', true, "\u{9ce5}"),
+    ('\u{2fc4}', true, "\u{9e75}"),
+    ('\u{2fc5}', true, "\u{9e7f}"),
+    ('\u{2fc6}', true, "\u{9ea5}"),
+    ('\u{2fc7}', true, "\u{9ebb}"),
+    ('\u{2fc8}', true, "\u{9ec3}"),
+    ('\u{2fc9}', true, "\u{9ecd}"),
+    ('\u{2fca}', true, "\u{9ed1}"),
+    ('\u{2fcb}', true, "\u{9ef9}"),
+    ('\u{2fcc}', true, "\u{9efd}"),
+    ('\u{2fcd}', true, "\u{9f0e}"),
+    ('\u{2fce}', true, "\u{9f13}"),
+    ('\u{2fcf}', true, "\u{9f20}"),
+    ('\u{2fd0}', true, "\u{9f3b}"),
+    ('\u{2fd1}', true, "\u{9f4a}"),
+    ('\u{2fd2}', true, "\u{9f52}"),
+    ('\u{2fd3}', true, "\u{9f8d}"),
+    ('\u{2fd4}', true, "\u{9f9c}"),
+    ('\u{2fd5}', true, "\u{9fa0}"),
+    ('\u{3000}', true, "\u{20}"),
+    ('\u{3036}', true, "\u{3012}"),
+    ('\u{3038}', true, "\u{5341}"),
+    ('\u{3039}', true, "\u{5344}"),
+    ('\u{303a}', true, "\u{5345}"),
+    ('\u{304c}', false, "\u{304b}\u{3099}"),
+    ('\u{304e}', false, "\u{304d}\u{3099}"),
+    ('\u{3050}', false, "\u{304f}\u{3099}"),
+    ('\u{3052}', false, "\u{3051}\u{3099}"),
+    ('\u{3054}', false, "\u{3053}\u{3099}"),
+    ('\u{3056}', false, "\u{3055}\u{3099}"),
+    ('\u{3058}', false, "\u{3057}\u{3099}"),
+    ('\u{305a}', false, "\u{3059}\u{3099}"),
+    ('\u{305c}', false, "\u{305b}\u{3099}"),
+    ('\u{305e}', false, "\u{305d}\u{3099}"),
+    ('\u{3060}', false, "\u{305f}\u{3099}"),
+    ('\u{3062}', false, "\u{3061}\u{3099}"),
+    ('\u{3065}', false, "\u{3064}\u{3099}"),
+    ('\u{3067}', false, "\u{3066}\u{3099}"),
+    ('\u{3069}', false, "\u{3068}\u{3099}"),
+    ('\u{3070}', false, "\u{306f}\u{3099}"),
+    ('\u{3071}', false, "\u{306f}\u{309a}"),
+    ('\u{3073}', false, "\u{3072}\u{3099}"),
+    ('\u{3074}', false, "\u{3072}\u{309a}"),
+    ('\u{3076}', false, "\u{3075}\u{3099}"),
+    ('\u{3077}', false, "\u{3075}\u{309a}"),
+    ('\u{3079}', false, "\u{3078}\u{3099}"),
+    ('\u{307a}', false, "\u{3078}\u{309a}"),
+    ('\u{307c}', false, "\u{307b}\u{3099}"),
+    ('\u{307d}', false, "\u{307b}\u{309a}"),
+    ('\u{3094}', false, "\u{3046}\u{3099}"),
+    ('\u{309b}', true, "\u{20}\u{3099}"),
+    ('\u{309c}', true, "\u{20}\u{309a}"),
+    ('\u{309e}', false, "\u{309d}\u{3099}"),
+    ('\u{309f}', true, "\u{3088}\u{308a}"),
+    ('\u{30ac}', false, "\u{30ab}\u{3099}"),
+    ('\u{30ae}', false, "\u{30ad}\u{3099}"),
+    ('\u{30b0}', false, "\u{30af}\u{3099}"),
+    ('\u{30b2}', false, "\u{30b1}\u{3099}"),
+    ('\u{30b4}', false, "\u{30b3}\u{3099}"),
+    ('\u{30b6}', false, "\u{30b5}\u{3099}"),
+    ('\u{30b8}', false, "\u{30b7}\u{3099}"),
+    ('\u{30ba}', false, "\u{30b9}\u{3099}"),
+    ('\u{30bc}', false, "\u{30bb}\u{3099}"),
+    ('\u{30be}', false, "\u{30bd}\u{3099}"),
+    ('\u{30c0}', false, "\u{30bf}\u{3099}"),
+    ('\u{30c2}', false, "\u{30c1}\u{3099}"),
+    ('\u{30c5}', false, "\u{30c4}\u{3099}"),
+    ('\u{30c7}', false, "\u{30c6}\u{3099}"),
+    ('\u{30c9}', false, "\u{30c8}\u{3099}"),
+    ('\u{30d0}', false, "\u{30cf}\u{3099}"),
+    ('\u{30d1}', false, "\u{30cf}\u{309a}"),
+    ('\u{30d3}', false, "\u{30d2}\u{3099}"),
+    ('\u{30d4}', false, "\u{30d2}\u{309a}"),
+    ('\u{30d6}', false, "\u{30d5}\u{3099}"),
+    ('\u{30d7}', false, "\u{30d5}\u{309a}"),
+    ('\u{30d9}', false, "\u{30d8}\u{3099}"),
+    ('\u{30da}', false, "\u{30d8}\u{309a}"),
+    ('\u{30dc}', false, "\u{30db}\u{3099}"),
+    ('\u{30dd}', false, "\u{30db}\u{309a}"),
+    ('\u{30f4}', false, "\u{30a6}\u{3099}"),
+    ('\u{30f7}', false, "\u{30ef}\u{3099}"),
+    ('\u{30f8}', false, "\u{30f0}\u{3099}"),
+    ('\u{30f9}', false, "\u{30f1}\u{3099}"),
+    ('\u{30fa}', false, "\u{30f2}\u{3099}"),
+    ('\u{30fe}', false, "\u{30fd}\u{3099}"),
+    ('\u{30ff}', true, "\u{30b3}\u{30c8}"),
+    ('\u{3131}', true, "\u{1100}"),
+    ('\u{3132}', true, "\u{1101}"),
+    ('\u{3133}', true, "\u{11aa}"),
+    ('\u{3134}', true, "\u{1102}"),
+    ('\u{3135}', true, "\u{11ac}"),
+    ('\u{3136}', true, "\u{11ad}"),
+    ('\u{3137}', true, "\u{1103}"),
+    ('\u{3138}', true, "\u{1104}"),
+    ('\u{3139}', true, "\u{1105}"),
+    ('\u{313a}', true, "\u{11b0}"),
+    ('\u{313b}', true, "\u{11b1}"),
+    ('\u{313c}', true, "\u{11b2}"),
+    ('\u{313d}', true, "\u{11b3}"),
+    ('\u{313e}', true, "\u{11b4}"),
+    ('\u{313f}', true, "\u{11b5}"),
+    ('\u{3140}', true, "\u{111a}"),
+    ('\u{3141}', true, "\u{1106}"),
+    ('\u{3142}', true, "\u{1107}"),
+    ('\u{3143}', true, "\u{1108}"),
+    ('\u{3144}', true, "\u{1121}"),
+    ('\u{3145}', true, "\u{1109}"),
+    ('\u{3146}', true, "\u{110a}"),
+    ('\u{3147}', true, "\u{110b}"),
+    ('\u{3148}', true, "\u{110c}"),
+    ('\u{3149}', true, "\u{110d}"),
+    ('\u{314a}', true, "\u{110e}"),
+    ('\u{314b}', true, "\u{110f}"),
+    ('\u{314c}', true, "\u{1110}"),
+    ('\u{314d}', true, "\u{1111}"),
+    ('\u{314e}', true, "\u{1112}"),
+    ('\u{314f}', true, "\u{1161}"),
+    ('\u{3150}', true, "\u{1162}"),
+    ('\u{3151}', true, "\u{1163}"),
+    ('\u{3152}', true, "\u{1164}"),
+    ('\u{3153}', true, "\u{1165}"),
+    ('\u{3154}', true, "\u{1166}"),
+    ('\u{3155}', true, "\u{1167}"),
+    ('\u{3156}', true, "\u{1168}"),
+    ('\u{3157}', true, "\u{1169}"),
+    ('\u{3158}', true, "\u{116a}"),
+    ('\u{3159}', true, "\u{116b}"),
+    ('\u{315a}', true, "\u{116c}"),
+    ('\u{315b}', true, "\u{116d}"),
+    ('\u{315c}', true, "\u{116e}"),
+    ('\u{315d}', true, "\u{116f}"),
+    ('\u{315e}', true, "\u{1170}"),
+    ('\u{315f}', true, "\u{1171}"),
+    ('\u{3160}', true, "\u{1172}"),
+    ('\u{3161}', true, "\u{1173}"),
+    ('\u{3162}', true, "\u{1174}"),
+    ('\u{3163}', true, "\u{1175}"),
+    ('\u{3164}', true, "\u{1160}"),
+    ('\u{3165}', true, "\u{1114}"),
+    ('\u{3166}', true, "\u{1115}"),
+    ('\u{3167}', true, "\u{11c7}"),
+    ('\u{3168}', true, "\u{11c8}"),
+    ('\u{3169}', true, "\u{11cc}"),
+    ('\u{316a}', true, "\u{11ce}"),
+    ('\u{316b}', true, "\u{11d3}"),
+    ('\u{316c}', true, "\u{11d7}"),
+    ('\u{316d}', true, "\u{11d9}"),
+    ('\u{316e}', true, "\u{111c}"),
+    ('\u{316f}', true, "\u{11dd}"),
+    ('\u{3170}', true, "\u{11df}"),
+    ('\u{3171}', true, "\u{111d}"),
+    ('\u{3172}', true, "\u{111e}"),
+    ('\u{3173}', true, "\u{1120}"),
+    ('\u{3174}', true, "\u{1122}"),
+    ('\u{3175}', true, "\u{1123}"),
+    ('\u{3176}', true, "\u{1127}"),
+    ('\u{3177}', true, "\u{1129}"),
+    ('\u{3178}', true, "\u{112b}"),
+    ('\u{3179}', true, "\u{112c}"),
+    ('\u{317a}', true, "\u{112d}"),
+    ('\u{317b}', true, "\u{112e}"),
+    ('\u{317c}', true, "\u{112f}"),
+    ('\u{317d}', true, "\u{1132}"),
+    ('\u{317e}', true, "\u{1136}"),
+    ('\u{317f}', true, "\u{1140}"),
+    ('\u{3180}', true, "\u{1147}"),
+    ('\u{3181}', true, "\u{114c}"),
+    ('\u{3182}', true, "\u{11f1}"),
+    ('\u{3183}', true, "\u{11f2}"),
+    ('\u{3184}', true, "\u{1157}"),
+    ('\u{3185}', true, "\u{1158}"),
+    ('\u{3186}', true, "\u{1159}"),
+    ('\u{3187}', true, "\u{1184}"),
+    ('\u{3188}', true, "\u{1185}"),
+    ('\u{3189}', true, "\u{1188}"),
+    ('\u{318a}', true, "\u{1191}"),
+    ('\u{318b}', true, "\u{1192}"),
+    ('\u{318c}', true, "\u{1194}"),
+    ('\u{318d}', true, "\u{119e}"),
+    ('\u{318e}', true, "\u{11a1}"),
+    ('\u{3192}', true, "\u{4e00}"),
+    ('\u{3193}', true, "\u{4e8c}"),
+    ('\u{3194}', true, "\u{4e09}"),
+    ('\u{3195}', true, "\u{56db}"),
+    ('\u{3196}', true, "\u{4e0a}"),
+    ('\u{3197}', true, "\u{4e2d}"),
+    ('\u{3198}', true, "\u{4e0b}"),
+    ('\u{3199}', true, "\u{7532}"),
+    ('\u{319a}', true, "\u{4e59}"),
+    ('\u{319b}', true, "\u{4e19}"),
+    ('\u{319c}', true, "\u{4e01}"),
+    ('\u{319d}', true, "\u{5929}"),
+    ('\u{319e}', true, "\u{5730}"),
+    ('\u{319f}', true, "\u{4eba}"),
+    ('\u{3200}', true, "\u{28}\u{1100}\u{29}"),
+    ('\u{3201}', true, "\u{28}\u{1102}\u{29}"),
+    ('\u{3202}', true, "\u{28}\u{1103}\u{29}"),
+    ('\u{3203}', true, "\u{28}\u{1105}\u{29}"),
+    ('\u{3204}', true, "\u{28}\u{1106}\u{29}"),
+    ('\u{3205}', true, "\u{28}\u{1107}\u{29}"),
+    ('\u{3206}', true, "\u{28}\u{1109}\u{29}"),
+    ('\u{3207}', true, "\u{28}\u{110b}\u{29}"),
+    ('\u{3208}', true, "\u{28}\u{110c}\u{29}"),
+    ('\u{3209}', true, "\u{28}\u{110e}\u{29}"),
+    ('\u{320a}', true, "\u{28}\u{110f}\u{29}"),
+    ('\u{320b}', true, "\u{28}\u{1110}\u{29}"),
+    ('\u{320c}', true, "\u{28}\u{1111}\u{29}"),
+    ('\u{320d}', true, "\u{28}\u{1112}\u{29}"),
+    ('\u{320e}', true, "\u{28}\u{1100}\u{1161}\u{29}"),
+    ('\u{320f}', true, "\u{28}\u{1102}\u{1161}\u{29}"),
+    ('\u{3210}', true, "\u{28}\u{1103}\u{1161}\u{29}"),
+    ('\u{3211}', true, "\u{28}\u{1105}\u{1161}\u{29}"),
+    ('\u{3212}', true, "\u{28}\u{1106}\u{1161}\u{29}"),
+    ('\u{3213}', true, "\u{28}\u{1107}\u{1161}\u{29}"),
+    ('\u{3214}', true, "\u{28}\u{1109}\u{1161}\u{29}"),
+    ('\u{3215}', true, "\u{28}\u{110b}\u{1161}\u{29}"),
+    ('\u{3216}', true, "\u{28}\u{110c}\u{1161}\u{29}"),
+    ('\u{3217}', true, "\u{28}\u{110e}\u{1161}\u{29}"),
+    ('\u{3218}', true, "\u{28}\u{110f}\u{1161}\u{29}"),
+    ('\u{3219}', true, "\u{28}\u{1110}\u{1161}\u{29}"),
+    ('\u{321a}', true, "\u{28}\u{1111}\u{1161}\u{29}"),
+    ('\u{321b}', true, "\u{28}\u{1112}\u{1161}\u{29}"),
+    ('\u{321c}', true, "\u{28}\u{110c}\u{116e}\u{29}"),
+    ('\u{321d}', true, "\u{28}\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab}\u{29}"),
+    ('\u{321e}', true, "\u{28}\u{110b}\u{1169}\u{1112}\u{116e}\u{29}"),
+    ('\u{3220}', true, "\u{28}\u{4e00}\u{29}"),
+    ('\u{3221}', true, "\u{28}\u{4e8c}\u{29}"),
+    ('\u{3222}', true, "\u{28}\u{4e09}\u{29}"),
+    ('\u{3223}', true, "\u{28}\u{56db}\u{29}"),
+    ('\u{3224}', true, "\u{28}\u{4e94}\u{29}"),
+    ('\u{3225}', true, "\u{28}\u{516d}\u{29}"),
+    ('\u{3226}', true, "\u{28}\u{4e03}\u{29}"),
+    ('\u{3227}', true, "\u{28}\u{516b}\u{29}"),
+    ('\u{3228}', true, "\u{28}\u{4e5d}\u{29}"),
+    ('\u{3229}', true, "\u{28}\u{5341}\u{29}"),
+    ('\u{322a}', true, "\u{28}\u{6708}\u{29}"),
+    ('\u{322b}', true, "\u{28}\u{706b}\u{29}"),
+    ('\u{322c}', true, "\u{28}\u{6c34}\u{29}"),
+    ('\u{322d}', true, "\u{28}\u{6728}\u{29}"),
+    ('\u{322e}', true, "\u{28}\u{91d1}\u{29}"),
+    ('\u{322f}', true, "\u{28}\u{571f}\u{29}"),
+    ('\u{3230}', true, "\u{28}\u{65e5}\u{29}"),
+    ('\u{3231}', true, "\u{28}\u{682a}\u{29}"),
+    ('\u{3232}', true, "\u{28}\u{6709}\u{29}"),
+    ('\u{3233}', true, "\u{28}\u{793e}\u{29}"),
+    ('\u{3234}', true, "\u{28}\u{540d}\u{29}"),
+    ('\u{3235}', true, "\u{28}\u{7279}\u{29}"),
+    ('\u{3236}', true, "\u{28}\u{8ca1}\u{29}"),
+    ('\u{3237}', true, "\u{28}\u{795d}\u{29}"),
+    ('\u{3238}', true, "\u{28}\u{52b4}\u{29}"),
+    ('\u{3239}', true, "\u{28}\u{4ee3}\u{29}"),
+    ('\u{323a}', true, "\u{28}\u{547c}\u{29}"),
+    ('\u{323b}', true, "\u{28}\u{5b66}\u{29}"),
+    ('\u{323c}', true, "\u{28}\u{76e3}\u{29}"),
+    ('\u{323d}', true, "\u{28}\u{4f01}\u{29}"),
+    ('\u{323e}', true, "\u{28}\u{8cc7}\u{29}"),
+    ('\u{323f}', true, "\u{28}\u{5354}\u{29}"),
+    ('\u{3240}', true, "\u{28}\u{796d}\u{29}"),
+    ('\u{3241}', true, "\u{28}\u{4f11}\u{29}"),
+    ('\u{3242}', true, "\u{28}\u{81ea}\u{29}"),
+    ('\u{3243}', true, "\u{28}\u{81f3}\u{29}"),
+    ('\u{3244}', true, "\u{554f}"),
+    ('\u{3245}', true, "\u{5e7c}"),
+    ('\u{3246}', true, "\u{6587}"),
+    ('\u{3247}', true, "\u{7b8f}"),
+    ('\u{3250}', true, "\u{50}\u{54}\u{45}"),
+    ('\u{3251}', true, "\u{32}\u{31}"),
+    ('\u{3252}', true, "\u{32}\u{32}"),
+    ('\u{3253}', true, "\u{32}\u{33}"),
+    ('\u{3254}', true, "\u{32}\u{34}"),
+    ('\u{3255}', true, "\u{32}\u{35}"),
+    ('\u{3256}', true, "\u{32}\u{36}"),
+    ('\u{3257}', true, "\u{32}\u{37}"),
+    ('\u{3258}', true, "\u{32}\u{38}"),
+    ('\u{3259}', true, "\u{32}\u{39}"),
+    ('\u{325a}', true, "\u{33}\u{30}"),
+    ('\u{325b}', true, "\u{33}\u{31}"),
+    ('\u{325c}', true, "\u{33}\u{32}"),
+    ('\u{325d}', true, "\u{33}\u{33}"),
+    ('\u{325e}', true, "\u{33}\u{34}"),
+    ('\u{325f}', true, "\u{33}\u{35}"),
+    ('\u{3260}', true, "\u{1100}"),
+    ('\u{3261}', true, "\u{1102}"),
+    ('\u{3262}', true, "\u{1103}"),
+    ('\u{3263}', true, "\u{1105}"),
+    ('\u{3264}', true, "\u{1106}"),
+    ('\u{3265}', true, "\u{1107}"),
+    ('\u{3266}', true, "\u{1109}"),
+    ('\u{3267}', true, "\u{110b}"),
+    ('\u{3268}', true, "\u{110c}"),
+    ('\u{3269}', true, "\u{110e}"),
+    ('\u{326a}', true, "\u{110f}"),
+    ('\u{326b}', true, "\u{1110}"),
+    ('\u{326c}', true, "\u{1111}"),
+    ('\u{326d}', true, "\u{1112}"),
+    ('\u{326e}', true, "\u{1100}\u{1161}"),
+    ('\u{326f}', true, "\u{1102}\u{1161}"),
+    ('\u{3270}', true, "\u{1103}\u{1161}"),
+    ('\u{3271}', true, "\u{1105}\u{1161}"),
+    ('\u{3272}', true, "\u{1106}\u{1161}"),
+    ('\u{3273}', true, "\u{1107}\u{1161}"),
+    ('\u{3274}', true, "\u{1109}\u{1161}"),
+    ('\u{3275}', true, "\u{110b}\u{1161}"),
+    ('\u{3276}', true, "\u{110c}\u{1161}"),
+    ('\u{3277}', true, "\u{110e}\u{1161}"),
+    ('\u{3278}', true, "\u{110f}\u{1161}"),
+    ('\u{3279}', true, "\u{1110}\u{1161}"),
+    ('\u{327a}', true, "\u{1111}\u{1161}"),
+    ('\u{327b}', true, "\u{1112}\u{1161}"),
+    ('\u{327c}', true, "\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169}"),
+    ('\u{327d}', true, "\u{110c}\u{116e}\u{110b}\u{1174}"),
+    ('\u{327e}', true, "\u{110b}\u{116e}"),
+    ('\u{3280}', true, "\u{4e00}"),
+    ('\u{3281}', true, "\u{4e8c}"),
+    ('\u{3282}', true, "\u{4e09}"),
+    ('\u{3283}', true, "\u{56db}"),
+    ('\u{3284}', true, "\u{4e94}"),
+    ('\u{3285}', true, "\u{516d}"),
+    ('\u{3286}', true, "\u{4e03}"),
+    ('\u{3287}', true, "\u{516b}"),
+    ('\u{3288}', true, "\u{4e5d}"),
+    ('\u{3289}', true, "\u{5341}"),
+    ('\u{328a}', true, "\u{6708}"),
+    ('\u{328b}', true, "\u{706b}"),
+    ('\u{328c}', true, "\u{6c34}"),
+    ('\u{328d}', true, "\u{6728}"),
+    ('\u{328e}', true, "\u{91d1}"),
+    ('\u{328f}', true, "\u{571f}"),
+    ('\u{3290}', true, "\u{65e5}"),
+    ('\u{3291}', true, "\u{682a}"),
+    ('\u{3292}', true, "\u{6709}"),
+    ('\u{3293}', true, "\u{793e}"),
+    ('\u{3294}', true, "\u{540d}"),
+    ('\u{3295}', true, "\u{7279}"),
+    ('\u{3296}', true, "\u{8ca1}"),
+    ('\u{3297}', true, "\u{795d}"),
+    ('\u{3298}', true, "\u{52b4}"),
+    ('\u{3299}', true, "\u{79d8}"),
+    ('\u{329a}', true, "\u{7537}"),
+    ('\u{329b}', true, "\u{5973}"),
+    ('\u{329c}', true, "\u{9069}"),
+    ('\u{329d}', true, "\u{512a}"),
+    ('\u{329e}', true, "\u{5370}"),
+    ('\u{329f}', true, "\u{6ce8}"),
+    ('\u{32a0}', true, "\u{9805}"),
+    ('\u{32a1}', true, "\u{4f11}"),
+    ('\u{32a2}', true, "\u{5199}"),
+    ('\u{32a3}', true, "\u{6b63}"),
+    ('\u{32a4}', true, "\u{4e0a}"),
+    ('\u{32a5}', true, "\u{4e2d}"),
+    ('\u{32a6}', true, "\u{4e0b}"),
+    ('\u{32a7}', true, "\u{5de6}"),
+    ('\u{32a8}', true, "\u{53f3}"),
+    ('\u{32a9}', true, "\u{533b}"),
+    ('\u{32aa}', true, "\u{5b97}"),
+    ('\u{32ab}', true, "\u{5b66}"),
+    ('\u{32ac}', true, "\u{76e3}"),
+    ('\u{32ad}', true, "\u{4f01}"),
+    ('\u{32ae}', true, "\u{8cc7}"),
+    ('\u{32af}', true, "\u{5354}"),
+    ('\u{32b0}', true, "\u{591c}"),
+    ('\u{32b1}', true, "\u{33}\u{36}"),
+    ('\u{32b2}', true, "\u{33}\u{37}"),
+    ('\u{32b3}', true, "\u{33}\u{38}"),
+    ('\u{32b4}', true, "\u{33}\u{39}"),
+    ('\u{32b5}', true, "\u{34}\u{30}"),
+    ('\u{32b6}', true, "\u{34}\u{31}"),
+    ('\u{32b7}', true, "\u{34}\u{32}"),
+    ('\u{32b8}', true, "\u{34}\u{33}"),
+    ('\u{32b9}', true, "\u{34}\u{34}"),
+    ('\u{32ba}', true, "\u{34}\u{35}"),
+    ('\u{32bb}', true, "\u{34}\u{36}"),
+    ('\u{32bc}', true, "\u{34}\u{37}"),
+    ('\u{32bd}', true, "\u{34}\u{38}"),
+    ('\u{32be}', true, "\u{34}\u{39}"),
+    ('\u{32bf}', true, "\u{35}\u{30}"),
+    ('\u{32c0}', true, "\u{31}\u{6708}"),
+    ('\u{32c1}', true, "\u{32}\u{6708}"),
+    ('\u{32c2}', true, "\u{33}\u{6708}"),
+    ('\u{32c3}', true, "\u{34}\u{6708}"),
+    ('\u{32c4}', true, "\u{35}\u{6708}"),
+    ('\u{32c5}', true, "\u{36}\u{6708}"),
+    ('\u{32c6}', true, "\u{37}\u{6708}"),
+    ('\u{32c7}', true, "\u{38}\u{6708}"),
+    ('\u{32c8}', true, "\u{39}\u{6708}"),
+    ('\u{32c9}', true, "\u{31}\u{30}\u{6708}"),
+    ('\u{32ca}', true, "\u{31}\u{31}\u{6708}"),
+    ('\u{32cb}', true, "\u{31}\u{32}\u{6708}"),
+    ('\u{32cc}', true, "\u{48}\u{67}"),
+    ('\u{32cd}', true, "\u{65}\u{72}\u{67}"),
+    ('\u{32ce}', true, "\u{65}\u{56}"),
+    ('\u{32cf}', true, "\u{4c}\u{54}\u{44}"),
+    ('\u{32d0}', true, "\u{30a2}"),
+    ('\u{32d1}', true, "\u{30a4}"),
+    ('\u{32d2}', true, "\u{30a6}"),
+    ('\u{32d3}', true, "\u{30a8}"),
+    ('\u{32d4}', true, "\u{30aa}"),
+    ('\u{32d5}', true, "\u{30ab}"),
+    ('\u{32d6}', true, "\u{30ad}"),
+    ('\u{32d7}', true, "\u{30af}"),
+    ('\u{32d8}', true, "\u{30b1}"),
+    ('\u{32d9}', true, "\u{30b3}"),
+    ('\u{32da}', true, "\u{30b5}"),
+    ('\u{32db}', true, "\u{30b7}"),
+    ('\u{32dc}', true, "\u{30b9}"),
+    ('\u{32dd}', true, "\u{30bb}"),
+    ('\u{32de}', true, "\u{30bd}"),
+    ('\u{32df}', true, "\u{30bf}"),
+    ('\u{32e0}', true, "\u{30c1}"),
+    ('\u{32e1}', true, "\u{30c4}"),
+    ('\u{32e2}', true, "\u{30c6}"),
+    ('\u{32e3}', true, "\u{30c8}"),
+    ('\u{32e4}', true, "\u{30ca}"),
+    ('\u{32e5}', true, "\u{30cb}"),
+    ('\u{32e6}', true, "\u{30cc}"),
+    ('\u{32e7}', true, "\u{30cd}"),
+    ('\u{32e8}', true, "\u{30ce}"),
+    ('\u{32e9}', true, "\u{30cf}"),
+    ('\u{32ea}', true, "\u{30d2}"),
+    ('\u{32eb}', true, "\u{30d5}"),
+    ('\u{32ec}', true, "\u{30d8}"),
+    ('\u{32ed}', true, "\u{30db}"),
+    ('\u{32ee}', true, "\u{30de}"),
+    ('\u{32ef}', true, "\u{30df}"),
+    ('\u{32f0}', true, "\u{30e0}"),
+    ('\u{32f1}', true, "\u{30e1}"),
+    ('\u{32f2}', true, "\u{30e2}"),
+    ('\u{32f3}', true, "\u{30e4}"),
+    ('\u{32f4}', true, "\u{30e6}"),
+    ('\u{32f5}', true, "\u{30e8}"),
+    ('\u{32f6}', true, "\u{30e9}"),
+    ('\u{32f7}', true, "\u{30ea}"),
+    ('\u{32f8}', true, "\u{30eb}"),
+    ('\u{32f9}', true, "\u{30ec}"),
+    ('\u{32fa}', true, "\u{30ed}"),
+    ('\u{32fb}', true, "\u{30ef}"),
+    ('\u{32fc}', true, "\u{30f0}"),
+    ('\u{32fd}', true, "\u{30f1}"),
+    ('\u{32fe}', true, "\u{30f2}"),
+    ('\u{32ff}', true, "\u{4ee4}\u{548c}"),
+    ('\u{3300}', true, "\u{30a2}\u{30d1}\u{30fc}\u{30c8}"),
+    ('\u{3301}', true, "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"),
+    ('\u{3302}', true, "\u{30a2}\u{30f3}\u{30da}\u{30a2}"),
+    ('\u{3303}', true, "\u{30a2}\u{30fc}\u{30eb}"),
+    ('\u{3304}', true, "\u{30a4}\u{30cb}\u{30f3}\u{30b0}"),
+    ('\u{3305}', true, "\u{30a4}\u{30f3}\u{30c1}"),
+    ('\u{3306}', true, "\u{30a6}\u{30a9}\u{30f3}"),
+    ('\u{3307}', true, "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c9}"),
+    ('\u{3308}', true, "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"),
+    ('\u{3309}', true, "\u{30aa}\u{30f3}\u{30b9}"),
+    ('\u{330a}', true, "\u{30aa}\u{30fc}\u{30e0}"),
+    ('\u{330b}', true, "\u{30ab}\u{30a4}\u{30ea}"),
+    ('\u{330c}', true, "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"),
+    ('\u{330d}', true, "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"),
+    ('\u{330e}', true, "\u{30ac}\u{30ed}\u{30f3}"),
+    ('\u{330f}', true, "\u{30ac}\u{30f3}\u{30de}"),
+    ('\u{3310}', true, "\u{30ae}\u{30ac}"),
+    ('\u{3311}', true, "\u{30ae}\u{30cb}\u{30fc}"),
+    ('\u{3312}', true, "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"),
+    ('\u{3313}', true, "\u{30ae}\u{30eb}\u{30c0}\u{30fc}"),
+    ('\u{3314}', true, "\u{30ad}\u{30ed}"),
+    ('\u{3315}', true, "\u{30ad}\u{30ed}\u{30b0}\u{30e9}\u{30e0}"),
+    ('\u{3316}', true, "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    ('\u{3317}', true, "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"),
+    ('\u{3318}', true, "\u{30b0}\u{30e9}\u{30e0}"),
+    ('\u{3319}', true, "\u{30b0}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"),
+    ('\u{331a}', true, "\u{30af}\u{30eb}\u{30bc}\u{30a4}\u{30ed}"),
+    ('\u{331b}', true, "\u{30af}\u{30ed}\u{30fc}\u{30cd}"),
+    ('\u{331c}', true, "\u{30b1}\u{30fc}\u{30b9}"),
+    ('\u{331d}', true, "\u{30b3}\u{30eb}\u{30ca}"),
+    ('\u{331e}', true, "\u{30b3}\u{30fc}\u{30dd}"),
+    ('\u{331f}', true, "\u{30b5}\u{30a4}\u{30af}\u{30eb}"),
+    ('\u{3320}', true, "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"),
+    ('\u{3321}', true, "\u{30b7}\u{30ea}\u{30f3}\u{30b0}"),
+    ('\u{3322}', true, "\u{30bb}\u{30f3}\u{30c1}"),
+    ('\u{3323}', true, "\u{30bb}\u{30f3}\u{30c8}"),
+    ('\u{3324}', true, "\u{30c0}\u{30fc}\u{30b9}"),
+    ('\u{3325}', true, "\u{30c7}\u{30b7}"),
+    ('\u{3326}', true, "\u{30c9}\u{30eb}"),
+    ('\u{3327}', true, "\u{30c8}\u{30f3}"),
+    ('\u{3328}', true, "\u{30ca}\u{30ce}"),
+    ('\u{3329}', true, "\u{30ce}\u{30c3}\u{30c8}"),
+    ('\u{332a}', true, "\u{30cf}\u{30a4}\u{30c4}"),
+    ('\u{332b}', true, "\u{30d1}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"),
+    ('\u{332c}', true, "\u{30d1}\u{30fc}\u{30c4}"),
+    ('\u{332d}', true, "\u{30d0}\u{30fc}\u{30ec}\u{30eb}"),
+    ('\u{332e}', true, "\u{30d4}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"),
+    ('\u{332f}', true, "\u{30d4}\u{30af}\u{30eb}"),
+    ('\u{3330}', true, "\u{30d4}\u{30b3}"),
+    ('\u{3331}', true, "\u{30d3}\u{30eb}"),
+    ('\u{3332}', true, "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c9}"),
+    ('\u{3333}', true, "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    ('\u{3334}', true, "\u{30d6}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"),
+    ('\u{3335}', true, "\u{30d5}\u{30e9}\u{30f3}"),
+    ('\u{3336}', true, "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"),
+    ('\u{3337}', true, "\u{30da}\u{30bd}"),
+    ('\u{3338}', true, "\u{30da}\u{30cb}\u{30d2}"),
+    ('\u{3339}', true, "\u{30d8}\u{30eb}\u{30c4}"),
+    ('\u{333a}', true, "\u{30da}\u{30f3}\u{30b9}"),
+    ('\u{333b}', true, "\u{30da}\u{30fc}\u{30b8}"),
+    ('\u{333c}', true, "\u{30d9}\u{30fc}\u{30bf}"),
+    ('\u{333d}', true, "\u{30dd}\u{30a4}\u{30f3}\u{30c8}"),
+    ('\u{333e}', true, "\u{30dc}\u{30eb}\u{30c8}"),
+    ('\u{333f}', true, "\u{30db}\u{30f3}"),
+    ('\u{3340}', true, "\u{30dd}\u{30f3}\u{30c9}"),
+    ('\u{3341}', true, "\u{30db}\u{30fc}\u{30eb}"),
+    ('\u{3342}', true, "\u{30db}\u{30fc}\u{30f3}"),
+    ('\u{3343}', true, "\u{30de}\u{30a4}\u{30af}\u{30ed}"),
+    ('\u{3344}', true, "\u{30de}\u{30a4}\u{30eb}"),
+    ('\u{3345}', true, "\u{30de}\u{30c3}\u{30cf}"),
+    ('\u{3346}', true, "\u{30de}\u{30eb}\u{30af}"),
+    ('\u{3347}', true, "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"),
+    ('\u{3348}', true, "\u{30df}\u{30af}\u{30ed}\u{30f3}"),
+    ('\u{3349}', true, "\u{30df}\u{30ea}"),
+    ('\u{334a}', true, "\u{30df}\u{30ea}\u{30d0}\u{30fc}\u{30eb}"),
+    ('\u{334b}', true, "\u{30e1}\u{30ac}"),
+    ('\u{334c}', true, "\u{30e1}\u{30ac}\u{30c8}\u{30f3}"),
+    ('\u{334d}', true, "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    ('\u{334e}', true, "\u{30e4}\u{30fc}\u{30c9}"),
+    ('\u{334f}', true, "\u{30e4}\u{30fc}\u{30eb}"),
+    ('\u{3350}', true, "\u{30e6}\u{30a2}\u{30f3}"),
+    ('\u{3351}', true, "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"),
+    ('\u{3352}', true, "\u{30ea}\u{30e9}"),
+    ('\u{3353}', true, "\u{30eb}\u{30d4}\u{30fc}"),
+    ('\u{3354}', true, "\u{30eb}\u{30fc}\u{30d6}\u{30eb}"),
+    ('\u{3355}', true, "\u{30ec}\u{30e0}"),
+    ('\u{3356}', true, "\u{30ec}\u{30f3}\u{30c8}\u{30b2}\u{30f3}"),
+    ('\u{3357}', true, "\u{30ef}\u{30c3}\u{30c8}"),
+    ('\u{3358}', true, "\u{30}\u{70b9}"),
+    ('\u{3359}', true, "\u{31}\u{70b9}"),
+    ('\u{335a}', true, "\u{32}\u{70b9}"),
+    ('\u{335b}', true, "\u{33}\u{70b9}"),
+    ('\u{335c}', true, "\u{34}\u{70b9}"),
+    ('\u{335d}', true, "\u{35}\u{70b9}"),
+    ('\u{335e}', true, "\u{36}\u{70b9}"),
+    ('\u{335f}', true, "\u{37}\u{70b9}"),
+    ('\u{3360}', true, "\u{38}\u{70b9}"),
+    ('\u{3361}', true, "\u{39}\u{70b9}"),
+    ('\u{3362}', true, "\u{31}\u{30}\u{70b9}"),
+    ('\u{3363}', true, "\u{31}\u{31}\u{70b9}"),
+    ('\u{3364}', true, "\u{31}\u{32}\u{70b9}"),
+    ('\u{3365}', true, "\u{31}\u{33}\u{70b9}"),
+    ('\u{3366}', true, "\u{31}\u{34}\u{70b9}"),
+    ('\u{3367}', true, "\u{31}\u{35}\u{70b9}"),
+    ('\u{3368}', true, "\u{31}\u{36}\u{70b9}"),
+    ('\u{3369}', true, "\u{31}\u{37}\u{70b9}"),
+    ('\u{336a}', true, "\u{31}\u{38}\u{70b9}"),
+    ('\u{336b}', true, "\u{31}\u{39}\u{70b9}"),
+    ('\u{336c}', true, "\u{32}\u{30}\u{70b9}"),
+    ('\u{336d}', true, "\u{32}\u{31}\u{70b9}"),
+    ('\u{336e}', true, "\u{32}\u{32}\u{70b9}"),
+    ('\u{336f}', true, "\u{32}\u{33}\u{70b9}"),
+    ('\u{3370}', true, "\u{32}\u{34}\u{70b9}"),
+    ('\u{3371}', true, "\u{68}\u{50}\u{61}"),
+    ('\u{3372}', true, "\u{64}\u{61}"),
+    ('\u{3373}', true, "\u{41}\u{55}"),
+    ('\u{3374}', true, "\u{62}\u{61}\u{72}"),
+    ('\u{3375}', true, "\u{6f}\u{56}"),
+    ('\u{3376}', true, "\u{70}\u{63}"),
+    ('\u{3377}', true, "\u{64}\u{6d}"),
+    ('\u{3378}', true, "\u{64}\u{6d}\u{b2}"),
+    ('\u{3379}', true, "\u{64}\u{6d}\u{b3}"),
+    ('\u{337a}', true, "\u{49}\u{55}"),
+    ('\u{337b}', true, "\u{5e73}\u{6210}"),
+    ('\u{337c}', true, "\u{662d}\u{548c}"),
+    ('\u{337d}', true, "\u{5927}\u{6b63}"),
+    ('\u{337e}', true, "\u{660e}\u{6cbb}"),
+    ('\u{337f}', true, "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"),
+    ('\u{3380}', true, "\u{70}\u{41}"),
+    ('\u{3381}', true, "\u{6e}\u{41}"),
+    ('\u{3382}', true, "\u{3bc}\u{41}"),
+    ('\u{3383}', true, "\u{6d}\u{41}"),
+    ('\u{3384}', true, "\u{6b}\u{41}"),
+    ('\u{3385}', true, "\u{4b}\u{42}"),
+    ('\u{3386}', true, "\u{4d}\u{42}"),
+    ('\u{3387}', true, "\u{47}\u{42}"),
+    ('\u{3388}', true, "\u{63}\u{61}\u{6c}"),
+    ('\u{3389}', true, "\u{6b}\u{63}\u{61}\u{6c}"),
+    ('\u{338a}', true, "\u{70}\u{46}"),
+    ('\u{338b}', true, "\u{6e}\u{46}"),
+    ('\u{338c}', true, "\u{3bc}\u{46}"),
+    ('\u{338d}', true, "\u{3bc}\u{67}"),
+    ('\u{338e}', true, "\u{6d}\u{67}"),
+    ('\u{338f}', true, "\u{6b}\u{67}"),
+    ('\u{3390}', true, "\u{48}\u{7a}"),
+    ('\u{3391}', true, "\u{6b}\u{48}\u{7a}"),
+    ('\u{3392}', true, "\u{4d}\u{48}\u{7a}"),
+    ('\u{3393}', true, "\u{47}\u{48}\u{7a}"),
+    ('\u{3394}', true, "\u{54}\u{48}\u{7a}"),
+    ('\u{3395}', true, "\u{3bc}\u{2113}"),
+    ('\u{3396}', true, "\u{6d}\u{2113}"),
+    ('\u{3397}', true, "\u{64}\u{2113}"),
+    ('\u{3398}', true, "\u{6b}\u{2113}"),
+    ('\u{3399}', true, "\u{66}\u{6d}"),
+    ('\u{339a}', true, "\u{6e}\u{6d}"),
+    ('\u{339b}', true, "\u{3bc}\u{6d}"),
+    ('\u{339c}', true, "\u{6d}\u{6d}"),
+    ('\u{339d}', true, "\u{63}\u{6d}"),
+    ('\u{339e}', true, "\u{6b}\u{6d}"),
+    ('\u{339f}', true, "\u{6d}\u{6d}\u{b2}"),
+    ('\u{33a0}', true, "\u{63}\u{6d}\u{b2}"),
+    ('\u{33a1}', true, "\u{6d}\u{b2}"),
+    ('\u{33a2}', true, "\u{6b}\u{6d}\u{b2}"),
+    ('\u{33a3}', true, "\u{6d}\u{6d}\u{b3}"),
+    ('\u{33a4}', true, "\u{63}\u{6d}\u{b3}"),
+    ('\u{33a5}', true, "\u{6d}\u{b3}"),
+    ('\u{33a6}', true, "\u{6b}\u{6d}\u{b3}"),
+    ('\u{33a7}', true, "\u{6d}\u{2215}\u{73}"),
+    ('\u{33a8}', true, "\u{6d}\u{2215}\u{73}\u{b2}"),
+    ('\u{33a9}', true, "\u{50}\u{61}"),
+    ('\u{33aa}', true, "\u{6b}\u{50}\u{61}"),
+    ('\u{33ab}', true, "\u{4d}\u{50}\u{61}"),
+    ('\u{33ac}', true, "\u{47}\u{50}\u{61}"),
+    ('\u{33ad}', true, "\u{72}\u{61}\u{64}"),
+    ('\u{33ae}', true, "\u{72}\u{61}\u{64}\u{2215}\u{73}"),
+    ('\u{33af}', true, "\u{72}\u{61}\u{64}\u{2215}\u{73}\u{b2}"),
+    ('\u{33b0}', true, "\u{70}\u{73}"),
+    ('\u{33b1}', true, "\u{6e}\u{73}"),
+    ('\u{33b2}', true, "\u{3bc}\u{73}"),
+    ('\u{33b3}', true, "\u{6d}\u{73}"),
+    ('\u{33b4}', true, "\u{70}\u{56}"),
+    ('\u{33b5}', true, "\u{6e}\u{56}"),
+    ('\u{33b6}', true, "\u{3bc}\u{56}"),
+    ('\u{33b7}', true, "\u{6d}\u{56}"),
+    ('\u{33b8}', true, "\u{6b}\u{56}"),
+    ('\u{33b9}', true, "\u{4d}\u{56}"),
+    ('\u{33ba}', true, "\u{70}\u{57}"),
+    ('\u{33bb}', true, "\u{6e}\u{57}"),
+    ('\u{33bc}', true, "\u{3bc}\u{57}"),
+    ('\u{33bd}', true, "\u{6d}\u{57}"),
+    ('\u{33be}', true, "\u{6b}\u{57}"),
+    ('\u{33bf}', true, "\u{4d}\u{57}"),
+    ('\u{33c0}', true, "\u{6b}\u{3a9}"),
+    ('\u{33c1}', true, "\u{4d}\u{3a9}"),
+    ('\u{33c2}', true, "\u{61}\u{2e}\u{6d}\u{2e}"),
+    ('\u{33c3}', true, "\u{42}\u{71}"),
+    ('\u{33c4}', true, "\u{63}\u{63}"),
+    ('\u{33c5}', true, "\u{63}\u{64}"),
+    ('\u{33c6}', true, "\u{43}\u{2215}\u{6b}\u{67}"),
+    ('\u{33c7}', true, "\u{43}\u{6f}\u{2e}"),
+    ('\u{33c8}', true, "\u{64}\u{42}"),
+    ('\u{33c9}', true, "\u{47}\u{79}"),
+    ('\u{33ca}', true, "\u{68}\u{61}"),
+    ('\u{33cb}', true, "\u{48}\u{50}"),
+    ('\u{33cc}', true, "\u{69}\u{6e}"),
+    ('\u{33cd}', true, "\u{4b}\u{4b}"),
+    ('\u{33ce}', true, "\u{4b}\u{4d}"),
+    ('\u{33cf}', true, "\u{6b}\u{74}"),
+    ('\u{33d0}', true, "\u{6c}\u{6d}"),
+    ('\u{33d1}', true, "\u{6c}\u{6e}"),
+    ('\u{33d2}', true, "\u{6c}\u{6f}\u{67}"),
+    ('\u{33d3}', true, "\u{6c}\u{78}"),
+    ('\u{33d4}', true, "\u{6d}\u{62}"),
+    ('\u{33d5}', true, "\u{6d}\u{69}\u{6c}"),
+    ('\u{33d6}', true, "\u{6d}\u{6f}\u{6c}"),
+    ('\u{33d7}', true, "\u{50}\u{48}"),
+    ('\u{33d8}', true, "\u{70}\u{2e}\u{6d}\u{2e}"),
+    ('\u{33d9}', true, "\u{50}\u{50}\u{4d}"),
+    ('\u{33da}', true, "\u{50}\u{52}"),
+    ('\u{33db}', true, "\u{73}\u{72}"),
+    ('\u{33dc}', true, "\u{53}\u{76}"),
+    ('\u{33dd}', true, "\u{57}\u{62}"),
+    ('\u{33de}', true, "\u{56}\u{2215}\u{6d}"),
+    ('\u{33df}', true, "\u{41}\u{2215}\u{6d}"),
+    ('\u{33e0}', true, "\u{31}\u{65e5}"),
+    ('\u{33e1}', true, "\u{32}\u{65e5}"),
+    ('\u{33e2}', true, "\u{33}\u{65e5}"),
+    ('\u{33e3}', true, "\u{34}\u{65e5}"),
+    ('\u{33e4}', true, "\u{35}\u{65e5}"),
+    ('\u{33e5}', true, "\u{36}\u{65e5}"),
+    ('\u{33e6}', true, "\u{37}\u{65e5}"),
+    ('\u{33e7}', true, "\u{38}\u{65e5}"),
+    ('\u{33e8}', true, "\u{39}\u{65e5}"),
+    ('\u{33e9}', true, "\u{31}\u{30}\u{65e5}"),
+    ('\u{33ea}', true, "\u{31}\u{31}\u{65e5}"),
+    ('\u{33eb}', true, "\u{31}\u{32}\u{65e5}"),
+    ('\u{33ec}', true, "\u{31}\u{33}\u{65e5}"),
+    ('\u{33ed}', true, "\u{31}\u{34}\u{65e5}"),
+    ('\u{33ee}', true, "\u{31}\u{35}\u{65e5}"),
+    ('\u{33ef}', true, "\u{31}\u{36}\u{65e5}"),
+    ('\u{33f0}', true, "\u{31}\u{37}\u{65e5}"),
+    ('\u{33f1}', true, "\u{31}\u{38}\u{65e5}"),
+    ('\u{33f2}', true, "\u{31}\u{39}\u{65e5}"),
+    ('\u{33f3}', true, "\u{32}\u{30}\u{65e5}"),
+    ('\u{33f4}', true, "\u{32}\u{31}\u{65e5}"),
+    ('\u{33f5}', true, "\u{32}\u{32}\u{65e5}"),
+    ('\u{33f6}', true, "\u{32}\u{33}\u{65e5}"),
+    ('\u{33f7}', true, "\u{32}\u{34}\u{65e5}"),
+    ('\u{33f8}', true, "\u{32}\u{35}\u{65e5}"),
+    ('\u{33f9}', true, "\u{32}\u{36}\u{65e5}"),
+    ('\u{33fa}', true, "\u{32}\u{37}\u{65e5}"),
+    ('\u{33fb}', true, "\u{32}\u{38}\u{65e5}"),
+    ('\u{33fc}', true, "\u{32}\u{39}\u{65e5}"),
+    ('\u{33fd}', true, "\u{33}\u{30}\u{65e5}"),
+    ('\u{33fe}', true, "\u{33}\u{31}\u{65e5}"),
+    ('\u{33ff}', true, "\u{67}\u{61}\u{6c}"),
+    ('\u{a69c}', true, "\u{44a}"),
+    ('\u{a69d}', true, "\u{44c}"),
+    ('\u{a770}', true, "\u{a76f}"),
+    ('\u{a7f2}', true, "\u{43}"),
+    ('\u{a7f3}', true, "\u{46}"),
+    ('\u{a7f4}', true, "\u{51}"),
+    ('\u{a7f8}', true, "\u{126}"),
+    ('\u{a7f9}', true, "\u{153}"),
+    ('\u{ab5c}', true, "\u{a727}"),
+    ('\u{ab5d}', true, "\u{ab37}"),
+    ('\u{ab5e}', true, "\u{26b}"),
+    ('\u{ab5f}', true, "\u{ab52}"),
+    ('\u{ab69}', true, "\u{28d}"),
+    ('\u{f900}', false, "\u{8c48}"),
+    ('\u{f901}', false, "\u{66f4}"),
+    ('\u{f902}', false, "\u{8eca}"),
+    ('\u{f903}', false, "\u{8cc8}"),
+    ('\u{f904}', false, "\u{6ed1}"),
+    ('\u{f905}', false, "\u{4e32}"),
+    ('\u{f906}', false, "\u{53e5}"),
+    ('\u{f907}', false, "\u{9f9c}"),
+    ('\u{f908}', false, "\u{9f9c}"),
+    ('\u{f909}', false, "\u{5951}"),
+    ('\u{f90a}', false, "\u{91d1}"),
+    ('\u{f90b}', false, "\u{5587}"),
+    ('\u{f90c}', false, "\u{5948}"),
+    ('\u{f90d}', false, "\u{61f6}"),
+    ('\u{f90e}', false, "\u{7669}"),
+    ('\u{f90f}', false, "\u{7f85}"),
+    ('\u{f910}', false, "\u{863f}"),
+    ('\u{f911}', false, "\u{87ba}"),
+    ('\u{f912}', false, "\u{88f8}"),
+    ('\u{f913}', false, "\u{908f}"),
+    ('\u{f914}', false, "\u{6a02}"),
+    ('\u{f915}', false, "\u{6d1b}"),
+    ('\u{f916}', false, "\u{70d9}"),
+    ('\u{f917}', false, "\u{73de}"),
+    ('\u{f918}', false, "\u{843d}"),
+    ('\u{f919}', false, "\u{916a}"),
+    ('\u{f91a}', false, "\u{99f1}"),
+    ('\u{f91b}', false, "\u{4e82}"),
+    ('\u{f91c}', false, "\u{5375}"),
+    ('\u{f91d}', false, "\u{6b04}"),
+    ('\u{f91e}', false, "\u{721b}"),
+    ('\u{f91f}', false, "\u{862d}"),
+    ('\u{f920}', false, "\u{9e1e}"),
+    ('\u{f921}', false, "\u{5d50}"),
+    ('\u{f922}', false, "\u{6feb}"),
+    ('\u{f923}', false, "\u{85cd}"),
+    ('\u{f924}', false, "\u{8964}"),
+    ('\u{f925}', false, "\u{62c9}"),
+    ('\u{f926}', false, "\u{81d8}"),
+    ('\u{f927}', false, "\u{881f}"),
+    ('\u{f928}', false, "\u{5eca}"),
+    ('\u{f929}', false, "\u{6717}"),
+    ('\u{f92a}', false, "\u{6d6a}"),
+    ('\u{f92b}', false, "\u{72fc}"),
+    ('\u{f92c}', false, "\u{90ce}"),
+    ('\u{f92d}', false, "\u{4f86}"),
+    ('\u{f92e}', false, "\u{51b7}"),
+    ('\u{f92f}', false, "\u{52de}"),
+    ('\u{f930}', false, "\u{64c4}"),
+    ('\u{f931}', false, "\u{6ad3}"),
+    ('\u{f932}', false, "\u{7210}"),
+    ('\u{f933}', false, "\u{76e7}"),
+    ('\u{f934}', false, "\u{8001}"),
+    ('\u{f935}', false, "\u{8606}"),
+    ('\u{f936}', false, "\u{865c}"),
+    ('\u{f937}', false, "\u{8def}"),
+    ('\u{f938}', false, "\u{9732}"),
+    ('\u{f939}', false, "\u{9b6f}"),
+    ('\u{f93a}', false, "\u{9dfa}"),
+    ('\u{f93b}', false, "\u{788c}"),
+    ('\u{f93c}', false, "\u{797f}"),
+    ('\u{f93d}', false, "\u{7da0}"),
+    ('\u{f93e}', false, "\u{83c9}"),
+    ('\u{f93f}', false, "\u{9304}"),
+    ('\u{f940}', false, "\u{9e7f}"),
+    ('\u{f941}', false, "\u{8ad6}"),
+    ('\u{f942}', false, "\u{58df}"),
+    ('\u{f943}', false, "\u{5f04}"),
+    ('\u{f944}', false, "\u{7c60}"),
+    ('\u{f945}', false, "\u{807e}"),
+    ('\u{f946}', false, "\u{7262}"),
+    ('\u{f947}', false, "\u{78ca}"),
+    ('\u{f948}', false, "\u{8cc2}"),
+    ('\u{f949}', false, "\u{96f7}"),
+    ('\u{f94a}', false, "\u{58d8}"),
+    ('\u{f94b}', false, "\u{5c62}"),
+    ('\u{f94c}', false, "\u{6a13}"),
+    ('\u{f94d}', false, "\u{6dda}"),
+    ('\u{f94e}', false, "\u{6f0f}"),
+    ('\u{f94f}', false, "\u{7d2f}"),
+    ('\u{f950}', false, "\u{7e37}"),
+    ('\u{f951}', false, "\u{964b}"),
+    ('\u{f952}', false, "\u{52d2}"),
+    ('\u{f953}', false, "\u{808b}"),
+    ('\u{f954}', false, "\u{51dc}"),
+    ('\u{f955}', false, "\u{51cc}"),
+    ('\u{f956}', false, "\u{7a1c}"),
+    ('\u{f957}', false, "\u{7dbe}"),
+    ('\u{f958}', false, "\u{83f1}"),
+    ('\u{f959}', false, "\u{9675}"),
+    ('\u{f95a}', false, "\u{8b80}"),
+    ('\u{f95b}', false, "\u{62cf}"),
+    ('\u{f95c}', false, "\u{6a02}"),
+    ('\u{f95d}', false, "\u{8afe}"),
+    ('\u{f95e}', false, "\u{4e39}"),
+    ('\u{f95f}', false, "\u{5be7}"),
+    ('\u{f960}', false, "\u{6012}"),
+    ('\u{f961}', false, "\u{7387}"),
+    ('\u{f962}', false, "\u{7570}"),
+    ('\u{f963}', false, "\u{5317}"),
+    ('\u{f964}', false, "\u{78fb}"),
+    ('\u{f965}', false, "\u{4fbf}"),
+    ('\u{f966}', false, "\u{5fa9}"),
+    ('\u{f967}', false, "\u{4e0d}"),
+    ('\u{f968}', false, "\u{6ccc}"),
+    ('\u{f969}', false, "\u{6578}"),
+    ('\u{f96a}', false, "\u{7d22}"),
+    ('\u{f96b}', false, "\u{53c3}"),
+    ('\u{f96c}', false, "\u{585e}"),
+    ('\u{f96d}', false, "\u{7701}"),
+    ('\u{f96e}', false, "\u{8449}"),
+    ('\u{f96f}', false, "\u{8aaa}"),
+    ('\u{f970}', false, "\u{6bba}"),
+    ('\u{f971}', false, "\u{8fb0}"),
+    ('\u{f972}', false, "\u{6c88}"),
+    ('\u{f973}', false, "\u{62fe}"),
+    ('\u{f974}', false, "\u{82e5}"),
+    ('\u{f975}', false, "\u{63a0}"),
+    ('\u{f976}', false, "\u{7565}"),
+    ('\u{f977}', false, "\u{4eae}"),
+    ('\u{f978}', false, "\u{5169}"),
+    ('\u{f979}', false, "\u{51c9}"),
+    ('\u{f97a}', false, "\u{6881}"),
+    ('\u{f97b}', false, "\u{7ce7}"),
+    ('\u{f97c}', false, "\u{826f}"),
+    ('\u{f97d}', false, "\u{8ad2}"),
+    ('\u{f97e}', false, "\u{91cf}"),
+    ('\u{f97f}', false, "\u{52f5}"),
+    ('\u{f980}', false, "\u{5442}"),
+    ('\u{f981}', false, "\u{5973}"),
+    ('\u{f982}', false, "\u{5eec}"),
+    ('\u{f983}', false, "\u{65c5}"),
+    ('\u{f984}', false, "\u{6ffe}"),
+    ('\u{f985}', false, "\u{792a}"),
+    ('\u{f986}', false, "\u{95ad}"),
+    ('\u{f987}', false, "\u{9a6a}"),
+    ('\u{f988}', false, "\u{9e97}"),
+    ('\u{f989}', false, "\u{9ece}"),
+    ('\u{f98a}', false, "\u{529b}"),
+    ('\u{f98b}', false, "\u{66c6}"),
+    ('\u{f98c}', false, "\u{6b77}"),
+    ('\u{f98d}', false, "\u{8f62}"),
+    ('\u{f98e}', false, "\u{5e74}"),
+    ('\u{f98f}', false, "\u{6190}"),
+    ('\u{f990}', false, "\u{6200}"),
+    ('\u{f991}', false, "\u{649a}"),
+    ('\u{f992}', false, "\u{6f23}"),
+    ('\u{f993}', false, "\u{7149}"),
+    ('\u{f994}', false, "\u{7489}"),
+    ('\u{f995}', false, "\u{79ca}"),
+    ('\u{f996}', false, "\u{7df4}"),
+    ('\u{f997}', false, "\u{806f}"),
+    ('\u{f998}', false, "\u{8f26}"),
+    ('\u{f999}', false, "\u{84ee}"),
+    ('\u{f99a}', false, "\u{9023}"),
+    ('\u{f99b}', false, "\u{934a}"),
+    ('\u{f99c}', false, "\u{5217}"),
+    ('\u{f99d}', false, "\u{52a3}"),
+    ('\u{f99e}', false, "\u{54bd}"),
+    ('\u{f99f}', false, "\u{70c8}"),
+    ('\u{f9a0}', false, "\u{88c2}"),
+    ('\u{f9a1}', false, "\u{8aaa}"),
+    ('\u{f9a2}', false, "\u{5ec9}"),
+    ('\u{f9a3}', false, "\u{5ff5}"),
+    ('\u{f9a4}', false, "\u{637b}"),
+    ('\u{f9a5}', false, "\u{6bae}"),
+    ('\u{f9a6}', false, "\u{7c3e}"),
+    ('\u{f9a7}', false, "\u{7375}"),
+    ('\u{f9a8}', false, "\u{4ee4}"),
+    ('\u{f9a9}', false, "\u{56f9}"),
+    ('\u{f9aa}', false, "\u{5be7}"),
+    ('\u{f9ab}', false, "\u{5dba}"),
+    ('\u{f9ac}', false, "\u{601c}"),
+    ('\u{f9ad}', false, "\u{73b2}"),
+    ('\u{f9ae}', false, "\u{7469}"),
+    ('\u{f9af}', false, "\u{7f9a}"),
+    ('\u{f9b0}', false, "\u{8046}"),
+    ('\u{f9b1}', false, "\u{9234}"),
+    ('\u{f9b2}', false, "\u{96f6}"),
+    ('\u{f9b3}', false, "\u{9748}"),
+    ('\u{f9b4}', false, "\u{9818}"),
+    ('\u{f9b5}', false, "\u{4f8b}"),
+    ('\u{f9b6}', false, "\u{79ae}"),
+    ('\u{f9b7}', false, "\u{91b4}"),
+    ('\u{f9b8}', false, "\u{96b8}"),
+    ('\u{f9b9}', false, "\u{60e1}"),
+    ('\u{f9ba}', false, "\u{4e86}"),
+    ('\u{f9bb}', false, "\u{50da}"),
+    ('\u{f9bc}', false, "\u{5bee}"),
+    ('\u{f9bd}', false, "\u{5c3f}"),
+    ('\u{f9be}', false, "\u{6599}"),
+    ('\u{f9bf}', false, "\u{6a02}"),
+    ('\u{f9c0}', false, "\u{71ce}"),
+    ('\u{f9c1}', false, "\u{7642}"),
+    ('\u{f9c2}', false, "\u{84fc}"),
+    ('\u{f9c3}', false, "\u{907c}"),
+    ('\u{f9c4}', false, "\u{9f8d}"),
+    ('\u{f9c5}', false, "\u{6688}"),
+    ('\u{f9c6}', false, "\u{962e}"),
+    ('\u{f9c7}', false, "\u{5289}"),
+    ('\u{f9c8}', false, "\u{677b}"),
+    ('\u{f9c9}', false, "\u{67f3}"),
+    ('\u{f9ca}', false, "\u{6d41}"),
+    ('\u{f9cb}', false, "\u{6e9c}"),
+    ('\u{f9cc}', false, "\u{7409}"),
+    ('\u{f9cd}', false, "\u{7559}"),
+    ('\u{f9ce}', false, "\u{786b}"),
+    ('\u{f9cf}', false, "\u{7d10}"),
+    ('\u{f9d0}', false, "\u{985e}"),
+    ('\u{f9d1}', false, "\u{516d}"),
+    ('\u{f9d2}', false, "\u{622e}"),
+    ('\u{f9d3}', false, "\u{9678}"),
+    ('\u{f9d4}', false, "\u{502b}"),
+    ('\u{f9d5}', false, "\u{5d19}"),
+    ('\u{f9d6}', false, "\u{6dea}"),
+    ('\u{f9d7}', false, "\u{8f2a}"),
+    ('\u{f9d8}', false, "\u{5f8b}"),
+    ('\u{f9d9}', false, "\u{6144}"),
+    ('\u{f9da}', false, "\u{6817}"),
+    ('\u{f9db}', false, "\u{7387}"),
+    ('\u{f9dc}', false, "\u{9686}"),
+    ('\u{f9dd}', false, "\u{5229}"),
+    ('\u{f9de}', false, "\u{540f}"),
+    ('\u{f9df}', false, "\u{5c65}"),
+    ('\u{f9e0}', false, "\u{6613}"),
+    ('\u{f9e1}', false, "\u{674e}"),
+    ('\u{f9e2}', false, "\u{68a8}"),
+    ('\u{f9e3}', false, "\u{6ce5}"),
+    ('\u{f9e4}', false, "\u{7406}"),
+    ('\u{f9e5}', false, "\u{75e2}"),
+    ('\u{f9e6}', false, "\u{7f79}"),
+    ('\u{f9e7}', false, "\u{88cf}"),
+    ('\u{f9e8}', false, "\u{88e1}"),
+    ('\u{f9e9}', false, "\u{91cc}"),
+    ('\u{f9ea}', false, "\u{96e2}"),
+    ('\u{f9eb}', false, "\u{533f}"),
+    ('\u{f9ec}', false, "\u{6eba}"),
+    ('\u{f9ed}', false, "\u{541d}"),
+    ('\u{f9ee}', false, "\u{71d0}"),
+    ('\u{f9ef}', false, "\u{7498}"),
+    ('\u{f9f0}', false, "\u{85fa}"),
+    ('\u{f9f1}', false, "\u{96a3}"),
+    ('\u{f9f2}', false, "\u{9c57}"),
+    ('\u{f9f3}', false, "\u{9e9f}"),
+    ('\u{f9f4}', false, "\u{6797}"),
+    ('\u{f9f5}', false, "\u{6dcb}"),
+    ('\u{f9f6}', false, "\u{81e8}"),
+    ('\u{f9f7}', false, "\u{7acb}"),
+    ('\u{f9f8}', false, "\u{7b20}"),
+    ('\u{f9f9}', false, "\u{7c92}"),
+    ('\u{f9fa}', false, "\u{72c0}"),
+    ('\u{f9fb}', false, "\u{7099}"),
+    ('\u{f9fc}', false, "\u{8b58}"),
+    ('\u{f9fd}', false, "\u{4ec0}"),
+    ('\u{f9fe}', false, "\u{8336}"),
+    ('\u{f9ff}', false, "\u{523a}"),
+    ('\u{fa00}', false, "\u{5207}"),
+    ('\u{fa01}', false, "\u{5ea6}"),
+    ('\u{fa02}', false, "\u{62d3}"),
+    ('\u{fa03}', false, "\u{7cd6}"),
+    ('\u{fa04}', false, "\u{5b85}"),
+    ('\u{fa05}', false, "\u{6d1e}"),
+    ('\u{fa06}', false, "\u{66b4}"),
+    ('\u{fa07}', false, "\u{8f3b}"),
+    ('\u{fa08}', false, "\u{884c}"),
+    ('\u{fa09}', false, "\u{964d}"),
+    ('\u{fa0a}', false, "\u{898b}"),
+    ('\u{fa0b}', false, "\u{5ed3}"),
+    ('\u{fa0c}', false, "\u{5140}"),
+    ('\u{fa0d}', false, "\u{55c0}"),
+    ('\u{fa10}', false, "\u{585a}"),
+    ('\u{fa12}', false, "\u{6674}"),
+    ('\u{fa15}', false, "\u{51de}"),
+    ('\u{fa16}', false, "\u{732a}"),
+    ('\u{fa17}', false, "\u{76ca}"),
+    ('\u{fa18}', false, "\u{793c}"),
+    ('\u{fa19}', false, "\u{795e}"),
+    ('\u{fa1a}', false, "\u{7965}"),
+    ('\u{fa1b}', false, "\u{798f}"),
+    ('\u{fa1c}', false, "\u{9756}"),
+    ('\u{fa1d}', false, "\u{7cbe}"),
+    ('\u{fa1e}', false, "\u{7fbd}"),
+    ('\u{fa20}', false, "\u{8612}"),
+    ('\u{fa22}', false, "\u{8af8}"),
+    ('\u{fa25}', false, "\u{9038}"),
+    ('\u{fa26}', false, "\u{90fd}"),
+    ('\u{fa2a}', false, "\u{98ef}"),
+    ('\u{fa2b}', false, "\u{98fc}"),
+    ('\u{fa2c}', false, "\u{9928}"),
+    ('\u{fa2d}', false, "\u{9db4}"),
+    ('\u{fa2e}', false, "\u{90de}"),
+    ('\u{fa2f}', false, "\u{96b7}"),
+    ('\u{fa30}', false, "\u{4fae}"),
+    ('\u{fa31}', false, "\u{50e7}"),
+    ('\u{fa32}', false, "\u{514d}"),
+    ('\u{fa33}', false, "\u{52c9}"),
+    ('\u{fa34}', false, "\u{52e4}"),
+    ('\u{fa35}', false, "\u{5351}"),
+    ('\u{fa36}', false, "\u{559d}"),
+    ('\u{fa37}', false, "\u{5606}"),
+    ('\u{fa38}', false, "\u{5668}"),
+    ('\u{fa39}', false, "\u{5840}"),
+    ('\u{fa3a}', false, "\u{58a8}"),
+    ('\u{fa3b}', false, "\u{5c64}"),
+    ('\u{fa3c}', false, "\u{5c6e}"),
+    ('\u{fa3d}', false, "\u{6094}"),
+    ('\u{fa3e}', false, "\u{6168}"),
+    ('\u{fa3f}', false, "\u{618e}"),
+    ('\u{fa40}', false, "\u{61f2}"),
+    ('\u{fa41}', false, "\u{654f}"),
+    ('\u{fa42}', false, "\u{65e2}"),
+    ('\u{fa43}', false, "\u{6691}"),
+    ('\u{fa44}', false, "\u{6885}"),
+    ('\u{fa45}', false, "\u{6d77}"),
+    ('\u{fa46}', false, "\u{6e1a}"),
+    ('\u{fa47}', false, "\u{6f22}"),
+    ('\u{fa48}', false, "\u{716e}"),
+    ('\u{fa49}', false, "\u{722b}"),
+    ('\u{fa4a}', false, "\u{7422}"),
+    ('\u{fa4b}', false, "\u{7891}"),
+    ('\u{fa4c}', false, "\u{793e}"),
+    ('\u{fa4d}', false, "\u{7949}"),
+    ('\u{fa4e}', false, "\u{7948}"),
+    ('\u{fa4f}', false, "\u{7950}"),
+    ('\u{fa50}', false, "\u{7956}"),
+    ('\u{fa51}', false, "\u{795d}"),
+    ('\u{fa52}', false, "\u{798d}"),
+    ('\u{fa53}', false, "\u{798e}"),
+    ('\u{fa54}', false, "\u{7a40}"),
+    ('\u{fa55}', false, "\u{7a81}"),
+    ('\u{fa56}', false, "\u{7bc0}"),
+    ('\u{fa57}', false, "\u{7df4}"),
+    ('\u{fa58}', false, "\u{7e09}"),
+    ('\u{fa59}', false, "\u{7e41}"),
+    ('\u{fa5a}', false, "\u{7f72}"),
+    ('\u{fa5b}', false, "\u{8005}"),
+    ('\u{fa5c}', false, "\u{81ed}"),
+    ('\u{fa5d}', false, "\u{8279}"),
+    ('\u{fa5e}', false, "\u{8279}"),
+    ('\u{fa5f}', false, "\u{8457}"),
+    ('\u{fa60}', false, "\u{8910}"),
+    ('\u{fa61}', false, "\u{8996}"),
+    ('\u{fa62}', false, "\u{8b01}"),
+    ('\u{fa63}', false, "\u{8b39}"),
+    ('\u{fa64}', false, "\u{8cd3}"),
+    ('\u{fa65}', false, "\u{8d08}"),
+    ('\u{fa66}', false, "\u{8fb6}"),
+    ('\u{fa67}', false, "\u{9038}"),
+    ('\u{fa68}', false, "\u{96e3}"),
+    ('\u{fa69}', false, "\u{97ff}"),
+    ('\u{fa6a}', false, "\u{983b}"),
+    ('\u{fa6b}', false, "\u{6075}"),
+    ('\u{fa6c}', false, "\u{242ee}"),
+    ('\u{fa6d}', false, "\u{8218}"),
+    ('\u{fa70}', false, "\u{4e26}"),
+    ('\u{fa71}', false, "\u{51b5}"),
+    ('\u{fa72}', false, "\u{5168}"),
+    ('\u{fa73}', false, "\u{4f80}"),
+    ('\u{fa74}', false, "\u{5145}"),
+    ('\u{fa75}', false, "\u{5180}"),
+    ('\u{fa76}', false, "\u{52c7}"),
+    ('\u{fa77}', false, "\u{52fa}"),
+    ('\u{fa78}', false, "\u{559d}"),
+    ('\u{fa79}', false, "\u{5555}"),
+    ('\u{fa7a}', false, "\u{5599}"),
+    ('\u{fa7b}', false, "\u{55e2}"),
+    ('\u{fa7c}', false, "\u{585a}"),
+    ('\u{fa7d}', false, "\u{58b3}"),
+    ('\u{fa7e}', false, "\u{5944}"),
+    ('\u{fa7f}', false, "\u{5954}"),
+    ('\u{fa80}', false, "\u{5a62}"),
+    ('\u{fa81}', false, "\u{5b28}"),
+    ('\u{fa82}', false, "\u{5ed2}"),
+    ('\u{fa83}', false, "\u{5ed9}"),
+    ('\u{fa84}', false, "\u{5f69}"),
+    ('\u{fa85}', false, "\u{5fad}"),
+    ('\u{fa86}', false, "\u{60d8}"),
+    ('\u{fa87}', false, "\u{614e}"),
+    ('\u{fa88}', false, "\u{6108}"),
+    ('\u{fa89}', false, "\u{618e}"),
+    ('\u{fa8a}', false, "\u{6160}"),
+    ('\u{fa8b}', false, "\u{61f2}"),
+    ('\u{fa8c}', false, "\u{6234}"),
+    ('\u{fa8d}', false, "\u{63c4}"),
+    ('\u{fa8e}', false, "\u{641c}"),
+    ('\u{fa8f}', false, "\u{6452}"),
+    ('\u{fa90}', false, "\u{6556}"),
+    ('\u{fa91}', false, "\u{6674}"),
+    ('\u{fa92}', false, "\u{6717}"),
+    ('\u{fa93}', false, "\u{671b}"),
+    ('\u{fa94}', false, "\u{6756}"),
+    ('\u{fa95}', false, "\u{6b79}"),
+    ('\u{fa96}', false, "\u{6bba}"),
+    ('\u{fa97}', false, "\u{6d41}"),
+    ('\u{fa98}', false, "\u{6edb}"),
+    ('\u{fa99}', false, "\u{6ecb}"),
+    ('\u{fa9a}', false, "\u{6f22}"),
+    ('\u{fa9b}', false, "\u{701e}"),
+    ('\u{fa9c}', false, "\u{716e}"),
+    ('\u{fa9d}', false, "\u{77a7}"),
+    ('\u{fa9e}', false, "\u{7235}"),
+    ('\u{fa9f}', false, "\u{72af}"),
+    ('\u{faa0}', false, "\u{732a}"),
+    ('\u{faa1}', false, "\u{7471}"),
+    ('\u{faa2}', false, "\u{7506}"),
+    ('\u{faa3}', false, "\u{753b}"),
+    ('\u{faa4}', false, "\u{761d}"),
+    ('\u{faa5}', false, "\u{761f}"),
+    ('\u{faa6}', false, "\u{76ca}"),
+    ('\u{faa7}', false, "\u{76db}"),
+    ('\u{faa8}', false, "\u{76f4}"),
+    ('\u{faa9}', false, "\u{774a}"),
+    ('\u{faaa}', false, "\u{7740}"),
+    ('\u{faab}', false, "\u{78cc}"),
+    ('\u{faac}', false, "\u{7ab1}"),
+    ('\u{faad}', false, "\u{7bc0}"),
+    ('\u{faae}', false, "\u{7c7b}"),
+    ('\u{faaf}', false, "\u{7d5b}"),
+    ('\u{fab0}', false, "\u{7df4}"),
+    ('\u{fab1}', false, "\u{7f3e}"),
+    ('\u{fab2}', false, "\u{8005}"),
+    ('\u{fab3}', false, "\u{8352}"),
+    ('\u{fab4}', false, "\u{83ef}"),
+    ('\u{fab5}', false, "\u{8779}"),
+    ('\u{fab6}', false, "\u{8941}"),
+    ('\u{fab7}', false, "\u{8986}"),
+    ('\u{fab8}', false, "\u{8996}"),
+    ('\u{fab9}', false, "\u{8abf}"),
+    ('\u{faba}', false, "\u{8af8}"),
+    ('\u{fabb}', false, "\u{8acb}"),
+    ('\u{fabc}', false, "\u{8b01}"),
+    ('\u{fabd}', false, "\u{8afe}"),
+    ('\u{fabe}', false, "\u{8aed}"),
+    ('\u{fabf}', false, "\u{8b39}"),
+    ('\u{fac0}', false, "\u{8b8a}"),
+    ('\u{fac1}', false, "\u{8d08}"),
+    ('\u{fac2}', false, "\u{8f38}"),
+    ('\u{fac3}', false, "\u{9072}"),
+    ('\u{fac4}', false, "\u{9199}"),
+    ('\u{fac5}', false, "\u{9276}"),
+    ('\u{fac6}', false, "\u{967c}"),
+    ('\u{fac7}', false, "\u{96e3}"),
+    ('\u{fac8}', false, "\u{9756}"),
+    ('\u{fac9}', false, "\u{97db}"),
+    ('\u{faca}', false, "\u{97ff}"),
+    ('\u{facb}', false, "\u{980b}"),
+    ('\u{facc}', false, "\u{983b}"),
+    ('\u{facd}', false, "\u{9b12}"),
+    ('\u{face}', false, "\u{9f9c}"),
+    ('\u{facf}', false, "\u{2284a}"),
+    ('\u{fad0}', false, "\u{22844}"),
+    ('\u{fad1}', false, "\u{233d5}"),
+    ('\u{fad2}', false, "\u{3b9d}"),
+    ('\u{fad3}', false, "\u{4018}"),
+    ('\u{fad4}', false, "\u{4039}"),
+    ('\u{fad5}', false, "\u{25249}"),
+    ('\u{fad6}', false, "\u{25cd0}"),
+    ('\u{fad7}', false, "\u{27ed3}"),
+    ('\u{fad8}', false, "\u{9f43}"),
+    ('\u{fad9}', false, "\u{9f8e}"),
+    ('\u{fb00}', true, "\u{66}\u{66}"),
+    ('\u{fb01}', true, "\u{66}\u{69}"),
+    ('\u{fb02}', true, "\u{66}\u{6c}"),
+    ('\u{fb03}', true, "\u{66}\u{66}\u{69}"),
+    ('\u{fb04}', true, "\u{66}\u{66}\u{6c}"),
+    ('\u{fb05}', true, "\u{17f}\u{74}"),
+    ('\u{fb06}', true, "\u{73}\u{74}"),
+    ('\u{fb13}', true, "\u{574}\u{576}"),
+    ('\u{fb14}', true, "\u{574}\u{565}"),
+    ('\u{fb15}', true, "\u{574}\u{56b}"),
+    ('\u{fb16}', true, "\u{57e}\u{576}"),
+    ('\u{fb17}', true, "\u{574}\u{56d}"),
+    ('\u{fb1d}', false, "\u{5d9}\u{5b4}"),
+    ('\u{fb1f}', false, "\u{5f2}\u{5b7}"),
+    ('\u{fb20}', true, "\u{5e2}"),
+    ('\u{fb21}', true, "\u{5d0}"),
+    ('\u{fb22}', true, "\u{5d3}"),
+    ('\u{fb23}', true, "\u{5d4}"),
+    ('\u{fb24}', true, "\u{5db}"),
+    ('\u{fb25}', true, "\u{5dc}"),
+    ('\u{fb26}', true, "\u{5dd}"),
+    ('\u{fb27}', true, "\u{5e8}"),
+    ('\u{fb28}', true, "\u{5ea}"),
+    ('\u{fb29}', true, "\u{2b}"),
+    ('\u{fb2a}', false, "\u{5e9}\u{5c1}"),
+    ('\u{fb2b}', false, "\u{5e9}\u{5c2}"),
+    ('\u{fb2c}', false, "\u{fb49}\u{5c1}"),
+    ('\u{fb2d}', false, "\u{fb49}\u{5c2}"),
+    ('\u{fb2e}', false, "\u{5d0}\u{5b7}"),
+    ('\u{fb2f}', false, "\u{5d0}\u{5b8}"),
+    ('\u{fb30}', false, "\u{5d0}\u{5bc}"),
+    ('\u{fb31}', false, "\u{5d1}\u{5bc}"),
+    ('\u{fb32}', false, "\u{5d2}\u{5bc}"),
+    ('\u{fb33}', false, "\u{5d3}\u{5bc}"),
+    ('\u{fb34}', false, "\u{5d4}\u{5bc}"),
+    ('\u{fb35}', false, "\u{5d5}\u{5bc}"),
+    ('\u{fb36}', false, "\u{5d6}\u{5bc}"),
+    ('\u{fb38}', false, "\u{5d8}\u{5bc}"),
+    ('\u{fb39}', false, "\u{5d9}\u{5bc}"),
+    ('\u{fb3a}', false, "\u{5da}\u{5bc}"),
+    ('\u{fb3b}', false, "\u{5db}\u{5bc}"),
+    ('\u{fb3c}', false, "\u{5dc}\u{5bc}"),
+    ('\u{fb3e}', false, "\u{5de}\u{5bc}"),
+    ('\u{fb40}', false, "\u{5e0}\u{5bc}"),
+    ('\u{fb41}', false, "\u{5e1}\u{5bc}"),
+    ('\u{fb43}', false, "\u{5e3}\u{5bc}"),
+    ('\u{fb44}', false, "\u{5e4}\u{5bc}"),
+    ('\u{fb46}', false, "\u{5e6}\u{5bc}"),
+    ('\u{fb47}', false, "\u{5e7}\u{5bc}"),
+    ('\u{fb48}', false, "\u{5e8}\u{5bc}"),
+    ('\u{fb49}', false, "\u{5e9}\u{5bc}"),
+    ('\u{fb4a}', false, "\u{5ea}\u{5bc}"),
+    ('\u{fb4b}', false, "\u{5d5}\u{5b9}"),
+    ('\u{fb4c}', false, "\u{5d1}\u{5bf}"),
+    ('\u{fb4d}', false, "\u{5db}\u{5bf}"),
+    ('\u{fb4e}', false, "\u{5e4}\u{5bf}"),
+    ('\u{fb4f}', true, "\u{5d0}\u{5dc}"),
+    ('\u{fb50}', true, "\u{671}"),
+    ('\u{fb51}', true, "\u{671}"),
+    ('\u{fb52}', true, "\u{67b}"),
+    ('\u{fb53}', true, "\u{67b}"),
+    ('\u{fb54}', true, "\u{67b}"),
+    ('\u{fb55}', true, "\u{67b}"),
+    ('\u{fb56}', true, "\u{67e}"),
+    ('\u{fb57}', true, "\u{67e}"),
+    ('\u{fb58}', true, "\u{67e}"),
+    ('\u{fb59}', true, "\u{67e}"),
+    ('\u{fb5a}', true, "\u{680}"),
+    ('\u{fb5b}', true, "\u{680}"),
+    ('\u{fb5c}', true, "\u{680}"),
+    ('\u{fb5d}', true, "\u{680}"),
+    ('\u{fb5e}', true, "\u{67a}"),
+    ('\u{fb5f}', true, "\u{67a}"),
+    ('\u{fb60}', true, "\u{67a}"),
+    ('\u{fb61}', true, "\u{67a}"),
+    ('\u{fb62}', true, "\u{67f}"),
+    ('\u{fb63}', true, "\u{67f}"),
+    ('\u{fb64}', true, "\u{67f}"),
+    ('\u{fb65}', true, "\u{67f}"),
+    ('\u{fb66}', true, "\u{679}"),
+    ('\u{fb67}', true, "\u{679}"),
+    ('\u{fb68}', true, "\u{679}"),
+    ('\u{fb69}', true, "\u{679}"),
+    ('\u{fb6a}', true, "\u{6a4}"),
+    ('\u{fb6b}', true, "\u{6a4}"),
+    ('\u{fb6c}', true, "\u{6a4}"),
+    ('\u{fb6d}', true, "\u{6a4}"),
+    ('\u{fb6e}', true, "\u{6a6}"),
+    ('\u{fb6f}', true, "\u{6a6}"),
+    ('\u{fb70}', true, "\u{6a6}"),
+    ('\u{fb71}', true, "\u{6a6}"),
+    ('\u{fb72}', true, "\u{684}"),
+    ('\u{fb73}', true, "\u{684}"),
+    ('\u{fb74}', true, "\u{684}"),
+    ('\u{fb75}', true, "\u{684}"),
+    ('\u{fb76}', true, "\u{683}"),
+    ('\u{fb77}', true, "\u{683}"),
+    ('\u{fb78}', true, "\u{683}"),
+    ('\u{fb79}', true, "\u{683}"),
+    ('\u{fb7a}', true, "\u{686}"),
+    ('\u{fb7b}', true, "\u{686}"),
+    ('\u{fb7c}', true, "\u{686}"),
+    ('\u{fb7d}', true, "\u{686}"),
+    ('\u{fb7e}', true, "\u{687}"),
+    ('\u{fb7f}', true, "\u{687}"),
+    ('\u{fb80}', true, "\u{687}"),
+    ('\u{fb81}', true, "\u{687}"),
+    ('\u{fb82}', true, "\u{68d}"),
+    ('\u{fb83}', true, "\u{68d}"),
+    ('\u{fb84}', true, "\u{68c}"),
+    ('\u{fb85}', true, "\u{68c}"),
+    ('\u{fb86}', true, "\u{68e}"),
+    ('\u{fb87}', true, "\u{68e}"),
+    ('\u{fb88}', true, "\u{688}"),
+    ('\u{fb89}', true, "\u{688}"),
+    ('\u{fb8a}', true, "\u{698}"),
+    ('\u{fb8b}', true, "\u{698}"),
+    ('\u{fb8c}', true, "\u{691}"),
+    ('\u{fb8d}', true, "\u{691}"),
+    ('\u{fb8e}', true, "\u{6a9}"),
+    ('\u{fb8f}', true, "\u{6a9}"),
+    ('\u{fb90}', true, "\u{6a9}"),
+    ('\u{fb91}', true, "\u{6a9}"),
+    ('\u{fb92}', true, "\u{6af}"),
+    ('\u{fb93}', true, "\u{6af}"),
+    ('\u{fb94}', true, "\u{6af}"),
+    ('\u{fb95}', true, "\u{6af}"),
+    ('\u{fb96}', true, "\u{6b3}"),
+    ('\u{fb97}', true, "\u{6b3}"),
+    ('\u{fb98}', true, "\u{6b3}"),
+    ('\u{fb99}', true, "\u{6b3}"),
+    ('\u{fb9a}', true, "\u{6b1}"),
+    ('\u{fb9b}', true, "\u{6b1}"),
+    ('\u{fb9c}', true, "\u{6b1}"),
+    ('\u{fb9d}', true, "\u{6b1}"),
+    ('\u{fb9e}', true, "\u{6ba}"),
+    ('\u{fb9f}', true, "\u{6ba}"),
+    ('\u{fba0}', true, "\u{6bb}"),
+    ('\u{fba1}', true, "\u{6bb}"),
+    ('\u{fba2}', true, "\u{6bb}"),
+    ('\u{fba3}', true, "\u{6bb}"),
+    ('\u{fba4}', true, "\u{6c0}"),
+    ('\u{fba5}', true, "\u{6c0}"),
+    ('\u{fba6}', true, "\u{6c1}"),
+    ('\u{fba7}', true, "\u{6c1}"),
+    ('\u{fba8}', true, "\u{6c1}"),
+    ('\u{fba9}', true, "\u{6c1}"),
+    ('\u{fbaa}', true, "\u{6be}"),
+    ('\u{fbab}', true, "\u{6be}"),
+    ('\u{fbac}', true, "\u{6be}"),
+    ('\u{fbad}', true, "\u{6be}"),
+    ('\u{fbae}', true, "\u{6d2}"),
+    ('\u{fbaf}', true, "\u{6d2}"),
+    ('\u{fbb0}', true, "\u{6d3}"),
+    ('\u{fbb1}', true, "\u{6d3}"),
+    ('\u{fbd3}', true, "\u{6ad}"),
+    ('\u{fbd4}', true, "\u{6ad}"),
+    ('\u{fbd5}', true, "\u{6ad}"),
+    ('\u{fbd6}', true, "\u{6ad}"),
+    ('\u{fbd7}', true, "\u{6c7}"),
+    ('\u{fbd8}', true, "\u{6c7}"),
+    ('\u{fbd9}', true, "\u{6c6}"),
+    ('\u{fbda}', true, "\u{6c6}"),
+    ('\u{fbdb}', true, "\u{6c8}"),
+    ('\u{fbdc}', true, "\u{6c8}"),
+    ('\u{fbdd}', true, "\u{677}"),
+    ('\u{fbde}', true, "\u{6cb}"),
+    ('\u{fbdf}', true, "\u{6cb}"),
+    ('\u{fbe0}', true, "\u{6c5}"),
+    ('\u{fbe1}', true, "\u{6c5}"),
+    ('\u{fbe2}', true, "\u{6c9}"),
+    ('\u{fbe3}', true, "\u{6c9}"),
+    ('\u{fbe4}', true, "\u{6d0}"),
+    ('\u{fbe5}', true, "\u{6d0}"),
+    ('\u{fbe6}', true, "\u{6d0}"),
+    ('\u{fbe7}', true, "\u{6d0}"),
+    ('\u{fbe8}', true, "\u{649}"),
+    ('\u{fbe9}', true, "\u{649}"),
+    ('\u{fbea}', true, "\u{626}\u{627}"),
+    ('\u{fbeb}', true, "\u{626}\u{627}"),
+    ('\u{fbec}', true, "\u{626}\u{6d5}"),
+    ('\u{fbed}', true, "\u{626}\u{6d5}"),
+    ('\u{fbee}', true, "\u{626}\u{648}"),
+    ('\u{fbef}', true, "\u{626}\u{648}"),
+    ('\u{fbf0}', true, "\u{626}\u{6c7}"),
+    ('\u{fbf1}', true, "\u{626}\u{6c7}"),
+    ('\u{fbf2}', true, "\u{626}\u{6c6}"),
+    ('\u{fbf3}', true, "\u{626}\u{6c6}"),
+    ('\u{fbf4}', true, "\u{626}\u{6c8}"),
+    ('\u{fbf5}', true, "\u{626}\u{6c8}"),
+    ('\u{fbf6}', true, "\u{626}\u{6d0}"),
+    ('\u{fbf7}', true, "\u{626}\u{6d0}"),
+    ('\u{fbf8}', true, "\u{626}\u{6d0}"),
+    ('\u{fbf9}', true, "\u{626}\u{649}"),
+    ('\u{fbfa}', true, "\u{626}\u{649}"),
+    ('\u{fbfb}', true, "\u{626}\u{649}"),
+    ('\u{fbfc}', true, "\u{6cc}"),
+    ('\u{fbfd}', true, "\u{6cc}"),
+    ('\u{fbfe}', true, "\u{6cc}"),
+    ('\u{fbff}', true, "\u{6cc}"),
+    ('\u{fc00}', true, "\u{626}\u{62c}"),
+    ('\u{fc01}', true, "\u{626}\u{62d}"),
+    ('\u{fc02}', true, "\u{626}\u{645}"),
+    ('\u{fc03}', true, "\u{626}\u{649}"),
+    ('\u{fc04}', true, "\u{626}\u{64a}"),
+    ('\u{fc05}', true, "\u{628}\u{62c}"),
+    ('\u{fc06}', true, "\u{628}\u{62d}"),
+    ('\u{fc07}', true, "\u{628}\u{62e}"),
+    ('\u{fc08}', true, "\u{628}\u{645}"),
+    ('\u{fc09}', true, "\u{628}\u{649}"),
+    ('\u{fc0a}', true, "\u{628}\u{64a}"),
+    ('\u{fc0b}', true, "\u{62a}\u{62c}"),
+    ('\u{fc0c}', true, "\u{62a}\u{62d}"),
+    ('\u{fc0d}', true, "\u{62a}\u{62e}"),
+    ('\u{fc0e}', true, "\u{62a}\u{645}"),
+    ('\u{fc0f}', true, "\u{62a}\u{649}"),
+    ('\u{fc10}', true, "\u{62a}\u{64a}"),
+    ('\u{fc11}', true, "\u{62b}\u{62c}"),
+    ('\u{fc12}', true, "\u{62b}\u{645}"),
+    ('\u{fc13}', true, "\u{62b}\u{649}"),
+    ('\u{fc14}', true, "\u{62b}\u{64a}"),
+    ('\u{fc15}', true, "\u{62c}\u{62d}"),
+    ('\u{fc16}', true, "\u{62c}\u{645}"),
+    ('\u{fc17}', true, "\u{62d}\u{62c}"),
+    ('\u{fc18}', true, "\u{62d}\u{645}"),
+    ('\u{fc19}', true, "\u{62e}\u{62c}"),
+    ('\u{fc1a}', true, "\u{62e}\u{62d}"),
+    ('\u{fc1b}', true, "\u{62e}\u{645}"),
+    ('\u{fc1c}', true, "\u{633}\u{62c}"),
+    ('\u{fc1d}', true, "\u{633}\u{62d}"),
+    ('\u{fc1e}', true, "\u{633}\u{62e}"),
+    ('\u{fc1f}', true, "\u{633}\u{645}"),
+    ('\u{fc20}', true, "\u{635}\u{62d}"),
+    ('\u{fc21}', true, "\u{635}\u{645}"),
+    ('\u{fc22}', true, "\u{636}\u{62c}"),
+    ('\u{fc23}', true, "\u{636}\u{62d}"),
+    ('\u{fc24}', true, "\u{636}\u{62e}"),
+    ('\u{fc25}', true, "\u{636}\u{645}"),
+    ('\u{fc26}', true, "\u{637}\u{62d}"),
+    ('\u{fc27}', true, "\u{637}\u{645}"),
+    ('\u{fc28}', true, "\u{638}\u{645}"),
+    ('\u{fc29}', true, "\u{639}\u{62c}"),
+    ('\u{fc2a}', true, "\u{639}\u{645}"),
+    ('\u{fc2b}', true, "\u{63a}\u{62c}"),
+    ('\u{fc2c}', true, "\u{63a}\u{645}"),
+    ('\u{fc2d}', true, "\u{641}\u{62c}"),
+    ('\u{fc2e}', true, "\u{641}\u{62d}"),
+    ('\u{fc2f}', true, "\u{641}\u{62e}"),
+    ('\u{fc30}', true, "\u{641}\u{645}"),
+    ('\u{fc31}', true, "\u{641}\u{649}"),
+    ('\u{fc32}', true, "\u{641}\u{64a}"),
+    ('\u{fc33}', true, "\u{642}\u{62d}"),
+    ('\u{fc34}', true, "\u{642}\u{645}"),
+    ('\u{fc35}', true, "\u{642}\u{649}"),
+    ('\u{fc36}', true, "\u{642}\u{64a}"),
+    ('\u{fc37}', true, "\u{643}\u{627}"),
+    ('\u{fc38}', true, "\u{643}\u{62c}"),
+    ('\u{fc39}', true, "\u{643}\u{62d}"),
+    ('\u{fc3a}', true, "\u{643}\u{62e}"),
+    ('\u{fc3b}', true, "\u{643}\u{644}"),
+    ('\u{fc3c}', true, "\u{643}\u{645}"),
+    ('\u{fc3d}', true, "\u{643}\u{649}"),
+    ('\u{fc3e}', true, "\u{643}\u{64a}"),
+    ('\u{fc3f}', true, "\u{644}\u{62c}"),
+    ('\u{fc40}', true, "\u{644}\u{62d}"),
+    ('\u{fc41}', true, "\u{644}\u{62e}"),
+    ('\u{fc42}', true, "\u{644}\u{645}"),
+    ('\u{fc43}', true, "\u{644}\u{649}"),
+    ('\u{fc44}', true, "\u{644}\u{64a}"),
+    ('\u{fc45}', true, "\u{645}\u{62c}"),
+    ('\u{fc46}', true, "\u{645}\u{62d}"),
+    ('\u{fc47}', true, "\u{645}\u{62e}"),
+    ('\u{fc48}', true, "\u{645}\u{645}"),
+    ('\u{fc49}', true, "\u{645}\u{649}"),
+    ('\u{fc4a}', true, "\u{645}\u{64a}"),
+    ('\u{fc4b}', true, "\u{646}\u{62c}"),
+    ('\u{fc4c}', true, "\u{646}\u{62d}"),
+    ('\u{fc4d}', true, "\u{646}\u{62e}"),
+    ('\u{fc4e}', true, "\u{646}\u{645}"),
+    ('\u{fc4f}', true, "\u{646}\u{649}"),
+    ('\u{fc50}', true, "\u{646}\u{64a}"),
+    ('\u{fc51}', true, "\u{647}\u{62c}"),
+    ('\u{fc52}', true, "\u{647}\u{645}"),
+    ('\u{fc53}', true, "\u{647}\u{649}"),
+    ('\u{fc54}', true, "\u{647}\u{64a}"),
+    ('\u{fc55}', true, "\u{64a}\u{62c}"),
+    ('\u{fc56}', true, "\u{64a}\u{62d}"),
+    ('\u{fc57}', true, "\u{64a}\u{62e}"),
+    ('\u{fc58}', true, "\u{64a}\u{645}"),
+    ('\u{fc59}', true, "\u{64a}\u{649}"),
+    ('\u{fc5a}', true, "\u{64a}\u{64a}"),
+    ('\u{fc5b}', true, "\u{630}\u{670}"),
+    ('\u{fc5c}', true, "\u{631}\u{670}"),
+    ('\u{fc5d}', true, "\u{649}\u{670}"),
+    ('\u{fc5e}', true, "\u{20}\u{64c}\u{651}"),
+    ('\u{fc5f}', true, "\u{20}\u{64d}\u{651}"),
+    ('\u{fc60}', true, "\u{20}\u{64e}\u{651}"),
+    ('\u{fc61}', true, "\u{20}\u{64f}\u{651}"),
+    ('\u{fc62}', true, "\u{20}\u{650}\u{651}"),
+    ('\u{fc63}', true, "\u{20}\u{651}\u{670}"),
+    ('\u{fc64}', true, "\u{626}\u{631}"),
+    ('\u{fc65}', true, "\u{626}\u{632}"),
+    ('\u{fc66}', true, "\u{626}\u{645}"),
+    ('\u{fc67}', true, "\u{626}\u{646}"),
+    ('\u{fc68}', true, "\u{626}\u{649}"),
+    ('\u{fc69}', true, "\u{626}\u{64a}"),
+    ('\u{fc6a}', true, "\u{628}\u{631}"),
+    ('\u{fc6b}', true, "\u{628}\u{632}"),
+    ('\u{fc6c}', true, "\u{628}\u{645}"),
+    ('\u{fc6d}', true, "\u{628}\u{646}"),
+    ('\u{fc6e}', true, "\u{628}\u{649}"),
+    ('\u{fc6f}', true, "\u{628}\u{64a}"),
+    ('\u{fc70}', true, "\u{62a}\u{631}"),
+    ('\u{fc71}', true, "\u{62a}\u{632}"),
+    ('\u{fc72}', true, "\u{62a}\u{645}"),
+    ('\u{fc73}', true, "\u{62a}\u{646}"),
+    ('\u{fc74}', true, "\u{62a}\u{649}"),
+    ('\u{fc75}', true, "\u{62a}\u{64a}"),
+    ('\u{fc76}', true, "\u{62b}\u{631}"),
+    ('\u{fc77}', true, "\u{62b}\u{632}"),
+    ('\u{fc78}', true, "\u{62b}\u{645}"),
+    ('\u{fc79}', true, "\u{62b}\u{646}"),
+    ('\u{fc7a}', true, "\u{62b}\u{649}"),
+    ('\u{fc7b}', true, "\u{62b}\u{64a}"),
+    ('\u{fc7c}', true, "\u{641}\u{649}"),
+    ('\u{fc7d}', true, "\u{641}\u{64a}"),
+    ('\u{fc7e}', true, "\u{642}\u{649}"),
+    ('\u{fc7f}', true, "\u{642}\u{64a}"),
+    ('\u{fc80}', true, "\u{643}\u{627}"),
+    ('\u{fc81}', true, "\u{643}\u{644}"),
+    ('\u{fc82}', true, "\u{643}\u{645}"),
+    ('\u{fc83}', true, "\u{643}\u{649}"),
+    ('\u{fc84}', true, "\u{643}\u{64a}"),
+    ('\u{fc85}', true, "\u{644}\u{645}"),
+    ('\u{fc86}', true, "\u{644}\u{649}"),
+    ('\u{fc87}', true, "\u{644}\u{64a}"),
+    ('\u{fc88}', true, "\u{645}\u{627}"),
+    ('\u{fc89}', true, "\u{645}\u{645}"),
+    ('\u{fc8a}', true, "\u{646}\u{631}"),
+    ('\u{fc8b}', true, "\u{646}\u{632}"),
+    ('\u{fc8c}', true, "\u{646}\u{645}"),
+    ('\u{fc8d}', true, "\u{646}\u{646}"),
+    ('\u{fc8e}', true, "\u{646}\u{649}"),
+    ('\u{fc8f}', true, "\u{646}\u{64a}"),
+    ('\u{fc90}', true, "\u{649}\u{670}"),
+    ('\u{fc91}', true, "\u{64a}\u{631}"),
+    ('\u{fc92}', true, "\u{64a}\u{632}"),
+    ('\u{fc93}', true, "\u{64a}\u{645}"),
+    ('\u{fc94}', true, "\u{64a}\u{646}"),
+    ('\u{fc95}', true, "\u{64a}\u{649}"),
+    ('\u{fc96}', true, "\u{64a}\u{64a}"),
+    ('\u{fc97}', true, "\u{626}\u{62c}"),
+    ('\u{fc98}', true, "\u{626}\u{62d}"),
+    ('\u{fc99}', true, "\u{626}\u{62e}"),
+    ('\u{fc9a}', true, "\u{626}\u{645}"),
+    ('\u{fc9b}', true, "\u{626}\u{647}"),
+    ('\u{fc9c}', true, "\u{628}\u{62c}"),
+    ('\u{fc9d}', true, "\u{628}\u{62d}"),
+    ('\u{fc9e}', true, "\u{628}\u{62e}"),
+    ('\u{fc9f}', true, "\u{628}\u{645}"),
+    ('\u{fca0}', true, "\u{628}\u{647}"),
+    ('\u{fca1}', true, "\u{62a}\u{62c}"),
+    ('\u{fca2}', true, "\u{62a}\u{62d}"),
+    ('\u{fca3}', true, "\u{62a}\u{62e}"),
+    ('\u{fca4}', true, "\u{62a}\u{645}"),
+    ('\u{fca5}', true, "\u{62a}\u{647}"),
+    ('\u{fca6}', true, "\u{62b}\u{645}"),
+    ('\u{fca7}', true, "\u{62c}\u{62d}"),
+    ('\u{fca8}', true, "\u{62c}\u{645}"),
+    ('\u{fca9}', true, "\u{62d}\u{62c}"),
+    ('\u{fcaa}', true, "\u{62d}\u{645}"),
+    ('\u{fcab}', true, "\u{62e}\u{62c}"),
+    ('\u{fcac}', true, "\u{62e}\u{645}"),
+    ('\u{fcad}', true, "\u{633}\u{62c}"),
+    ('\u{fcae}', true, "\u{633}\u{62d}"),
+    ('\u{fcaf}', true, "\u{633}\u{62e}"),
+    ('\u{fcb0}', true, "\u{633}\u{645}"),
+    ('\u{fcb1}', true, "\u{635}\u{62d}"),
+    ('\u{fcb2}', true, "\u{635}\u{62e}"),
+    ('\u{fcb3}', true, "\u{635}\u{645}"),
+    ('\u{fcb4}', true, "\u{636}\u{62c}"),
+    ('\u{fcb5}', true, "\u{636}\u{62d}"),
+    ('\u{fcb6}', true, "\u{636}\u{62e}"),
+    ('\u{fcb7}', true, "\u{636}\u{645}"),
+    ('\u{fcb8}', true, "\u{637}\u{62d}"),
+    ('\u{fcb9}', true, "\u{638}\u{645}"),
+    ('\u{fcba}', true, "\u{639}\u{62c}"),
+    ('\u{fcbb}', true, "\u{639}\u{645}"),
+    ('\u{fcbc}', true, "\u{63a}\u{62c}"),
+    ('\u{fcbd}', true, "\u{63a}\u{645}"),
+    ('\u{fcbe}', true, "\u{641}\u{62c}"),
+    ('\u{fcbf}', true, "\u{641}\u{62d}"),
+    ('\u{fcc0}', true, "\u{641}\u{62e}"),
+    ('\u{fcc1}', true, "\u{641}\u{645}"),
+    ('\u{fcc2}', true, "\u{642}\u{62d}"),
+    ('\u{fcc3}', true, "\u{642}\u{645}"),
+    ('\u{fcc4}', true, "\u{643}\u{62c}"),
+    ('\u{fcc5}', true, "\u{643}\u{62d}"),
+    ('\u{fcc6}', true, "\u{643}\u{62e}"),
+    ('\u{fcc7}', true, "\u{643}\u{644}"),
+    ('\u{fcc8}', true, "\u{643}\u{645}"),
+    ('\u{fcc9}', true, "\u{644}\u{62c}"),
+    ('\u{fcca}', true, "\u{644}\u{62d}"),
+    ('\u{fccb}', true, "\u{644}\u{62e}"),
+    ('\u{fccc}', true, "\u{644}\u{645}"),
+    ('\u{fccd}', true, "\u{644}\u{647}"),
+    ('\u{fcce}', true, "\u{645}\u{62c}"),
+    ('\u{fccf}', true, "\u{645}\u{62d}"),
+    ('\u{fcd0}', true, "\u{645}\u{62e}"),
+    ('\u{fcd1}', true, "\u{645}\u{645}"),
+    ('\u{fcd2}', true, "\u{646}\u{62c}"),
+    ('\u{fcd3}', true, "\u{646}\u{62d}"),
+    ('\u{fcd4}', true, "\u{646}\u{62e}"),
+    ('\u{fcd5}', true, "\u{646}\u{645}"),
+    ('\u{fcd6}', true, "\u{646}\u{647}"),
+    ('\u{fcd7}', true, "\u{647}\u{62c}"),
+    ('\u{fcd8}', true, "\u{647}\u{645}"),
+    ('\u{fcd9}', true, "\u{647}\u{670}"),
+    ('\u{fcda}', true, "\u{64a}\u{62c}"),
+    ('\u{fcdb}', true, "\u{64a}\u{62d}"),
+    ('\u{fcdc}', true, "\u{64a}\u{62e}"),
+    ('\u{fcdd}', true, "\u{64a}\u{645}"),
+    ('\u{fcde}', true, "\u{64a}\u{647}"),
+    ('\u{fcdf}', true, "\u{626}\u{645}"),
+    ('\u{fce0}', true, "\u{626}\u{647}"),
+    ('\u{fce1}', true, "\u{628}\u{645}"),
+    ('\u{fce2}', true, "\u{628}\u{647}"),
+    ('\u{fce3}', true, "\u{62a}\u{645}"),
+    ('\u{fce4}', true, "\u{62a}\u{647}"),
+    ('\u{fce5}', true, "\u{62b}\u{645}"),
+    ('\u{fce6}', true, "\u{62b}\u{647}"),
+    ('\u{fce7}', true, "\u{633}\u{645}"),
+    ('\u{fce8}', true, "\u{633}\u{647}"),
+    ('\u{fce9}', true, "\u{634}\u{645}"),
+    ('\u{fcea}', true, "\u{634}\u{647}"),
+    ('\u{fceb}', true, "\u{643}\u{644}"),
+    ('\u{fcec}', true, "\u{643}\u{645}"),
+    ('\u{fced}', true, "\u{644}\u{645}"),
+    ('\u{fcee}', true, "\u{646}\u{645}"),
+    ('\u{fcef}', true, "\u{646}\u{647}"),
+    ('\u{fcf0}', true, "\u{64a}\u{645}"),
+    ('\u{fcf1}', true, "\u{64a}\u{647}"),
+    ('\u{fcf2}', true, "\u{640}\u{64e}\u{651}"),
+    ('\u{fcf3}', true, "\u{640}\u{64f}\u{651}"),
+    ('\u{fcf4}', true, "\u{640}\u{650}\u{651}"),
+    ('\u{fcf5}', true, "\u{637}\u{649}"),
+    ('\u{fcf6}', true, "\u{637}\u{64a}"),
+    ('\u{fcf7}', true, "\u{639}\u{649}"),
+    ('\u{fcf8}', true, "\u{639}\u{64a}"),
+    ('\u{fcf9}', true, "\u{63a}\u{649}"),
+    ('\u{fcfa}', true, "\u{63a}\u{64a}"),
+    ('\u{fcfb}', true, "\u{633}\u{649}"),
+    ('\u{fcfc}', true, "\u{633}\u{64a}"),
+    ('\u{fcfd}', true, "\u{634}\u{649}"),
+    ('\u{fcfe}', true, "\u{634}\u{64a}"),
+    ('\u{fcff}', true, "\u{62d}\u{649}"),
+    ('\u{fd00}', true, "\u{62d}\u{64a}"),
+    ('\u{fd01}', true, "\u{62c}\u{649}"),
+    ('\u{fd02}', true, "\u{62c}\u{64a}"),
+    ('\u{fd03}', true, "\u{62e}\u{649}"),
+    ('\u{fd04}', true, "\u{62e}\u{64a}"),
+    ('\u{fd05}', true, "\u{635}\u{649}"),
+    ('\u{fd06}', true, "\u{635}\u{64a}"),
+    ('\u{fd07}', true, "\u{636}\u{649}"),
+    ('\u{fd08}', true, "\u{636}\u{64a}"),
+    ('\u{fd09}', true, "\u{634}\u{62c}"),
+    ('\u{fd0a}', true, "\u{634}\u{62d}"),
+    ('\u{fd0b}', true, "\u{634}\u{62e}"),
+    ('\u{fd0c}', true, "\u{634}\u{645}"),
+    ('\u{fd0d}', true, "\u{634}\u{631}"),
+    ('\u{fd0e}', true, "\u{633}\u{631}"),
+    ('\u{fd0f}', true, "\u{635}\u{631}"),
+    ('\u{fd10}', true, "\u{636}\u{631}"),
+    ('\u{fd11}', true, "\u{637}\u{649}"),
+    ('\u{fd12}', true, "\u{637}\u{64a}"),
+    ('\u{fd13}', true, "\u{639}\u{649}"),
+    ('\u{fd14}', true, "\u{639}\u{64a}"),
+    ('\u{fd15}', true, "\u{63a}\u{649}"),
+    ('\u{fd16}', true, "\u{63a}\u{64a}"),
+    ('\u{fd17}', true, "\u{633}\u{649}"),
+    ('\u{fd18}', true, "\u{633}\u{64a}"),
+    ('\u{fd19}', true, "\u{634}\u{649}"),
+    ('\u{fd1a}', true, "\u{634}\u{64a}"),
+    ('\u{fd1b}', true, "\u{62d}\u{649}"),
+    ('\u{fd1c}', true, "\u{62d}\u{64a}"),
+    ('\u{fd1d}', true, "\u{62c}\u{649}"),
+    ('\u{fd1e}', true, "\u{62c}\u{64a}"),
+    ('\u{fd1f}', true, "\u{62e}\u{649}"),
+    ('\u{fd20}', true, "\u{62e}\u{64a}"),
+    ('\u{fd21}', true, "\u{635}\u{649}"),
+    ('\u{fd22}', true, "\u{635}\u{64a}"),
+    ('\u{fd23}', true, "\u{636}\u{649}"),
+    ('\u{fd24}', true, "\u{636}\u{64a}"),
+    ('\u{fd25}', true, "\u{634}\u{62c}"),
+    ('\u{fd26}', true, "\u{634}\u{62d}"),
+    ('\u{fd27}', true, "\u{634}\u{62e}"),
+    ('\u{fd28}', true, "\u{634}\u{645}"),
+    ('\u{fd29}', true, "\u{634}\u{631}"),
+    ('\u{fd2a}', true, "\u{633}\u{631}"),
+    ('\u{fd2b}', true, "\u{635}\u{631}"),
+    ('\u{fd2c}', true, "\u{636}\u{631}"),
+    ('\u{fd2d}', true, "\u{634}\u{62c}"),
+    ('\u{fd2e}', true, "\u{634}\u{62d}"),
+    ('\u{fd2f}', true, "\u{634}\u{62e}"),
+    ('\u{fd30}', true, "\u{634}\u{645}"),
+    ('\u{fd31}', true, "\u{633}\u{647}"),
+    ('\u{fd32}', true, "\u{634}\u{647}"),
+    ('\u{fd33}', true, "\u{637}\u{645}"),
+    ('\u{fd34}', true, "\u{633}\u{62c}"),
+    ('\u{fd35}', true, "\u{633}\u{62d}"),
+    ('\u{fd36}', true, "\u{633}\u{62e}"),
+    ('\u{fd37}', true, "\u{634}\u{62c}"),
+    ('\u{fd38}', true, "\u{634}\u{62d}"),
+    ('\u{fd39}', true, "\u{634}\u{62e}"),
+    ('\u{fd3a}', true, "\u{637}\u{645}"),
+    ('\u{fd3b}', true, "\u{638}\u{645}"),
+    ('\u{fd3c}', true, "\u{627}\u{64b}"),
+    ('\u{fd3d}', true, "\u{627}\u{64b}"),
+    ('\u{fd50}', true, "\u{62a}\u{62c}\u{645}"),
+    ('\u{fd51}', true, "\u{62a}\u{62d}\u{62c}"),
+    ('\u{fd52}', true, "\u{62a}\u{62d}\u{62c}"),
+    ('\u{fd53}', true, "\u{62a}\u{62d}\u{645}"),
+    ('\u{fd54}', true, "\u{62a}\u{62e}\u{645}"),
+    ('\u{fd55}', true, "\u{62a}\u{645}\u{62c}"),
+    ('\u{fd56}', true, "\u{62a}\u{645}\u{62d}"),
+    ('\u{fd57}', true, "\u{62a}\u{645}\u{62e}"),
+    ('\u{fd58}', true, "\u{62c}\u{645}\u{62d}"),
+    ('\u{fd59}', true, "\u{62c}\u{645}\u{62d}"),
+    ('\u{fd5a}', true, "\u{62d}\u{645}\u{64a}"),
+    ('\u{fd5b}', true, "\u{62d}\u{645}\u{649}"),
+    ('\u{fd5c}', true, "\u{633}\u{62d}\u{62c}"),
+    ('\u{fd5d}', true, "\u{633}\u{62c}\u{62d}"),
+    ('\u{fd5e}', true, "\u{633}\u{62c}\u{649}"),
+    ('\u{fd5f}', true, "\u{633}\u{645}\u{62d}"),
+    ('\u{fd60}', true, "\u{633}\u{645}\u{62d}"),
+    ('\u{fd61}', true, "\u{633}\u{645}\u{62c}"),
+    ('\u{fd62}', true, "\u{633}\u{645}\u{645}"),
+    ('\u{fd63}', true, "\u{633}\u{645}\u{645}"),
+    ('\u{fd64}', true, "\u{635}\u{62d}\u{62d}"),
+    ('\u{fd65}', true, "\u{635}\u{62d}\u{62d}"),
+    ('\u{fd66}', true, "\u{635}\u{645}\u{645}"),
+    ('\u{fd67}', true, "\u{634}\u{62d}\u{645}"),
+    ('\u{fd68}', true, "\u{634}\u{62d}\u{645}"),
+    ('\u{fd69}', true, "\u{634}\u{62c}\u{64a}"),
+    ('\u{fd6a}', true, "\u{634}\u{645}\u{62e}"),
+    ('\u{fd6b}', true, "\u{634}\u{645}\u{62e}"),
+    ('\u{fd6c}', true, "\u{634}\u{645}\u{645}"),
+    ('\u{fd6d}', true, "\u{634}\u{645}\u{645}"),
+    ('\u{fd6e}', true, "\u{636}\u{62d}\u{649}"),
+    ('\u{fd6f}', true, "\u{636}\u{62e}\u{645}"),
+    ('\u{fd70}', true, "\u{636}\u{62e}\u{645}"),
+    ('\u{fd71}', true, "\u{637}\u{645}\u{62d}"),
+    ('\u{fd72}', true, "\u{637}\u{645}\u{62d}"),
+    ('\u{fd73}', true, "\u{637}\u{645}\u{645}"),
+    ('\u{fd74}', true, "\u{637}\u{645}\u{64a}"),
+    ('\u{fd75}', true, "\u{639}\u{62c}\u{645}"),
+    ('\u{fd76}', true, "\u{639}\u{645}\u{645}"),
+    ('\u{fd77}', true, "\u{639}\u{645}\u{645}"),
+    ('\u{fd78}', true, "\u{639}\u{645}\u{649}"),
+    ('\u{fd79}', true, "\u{63a}\u{645}\u{645}"),
+    ('\u{fd7a}', true, "\u{63a}\u{645}\u{64a}"),
+    ('\u{fd7b}', true, "\u{63a}\u{645}\u{649}"),
+    ('\u{fd7c}', true, "\u{641}\u{62e}\u{645}"),
+    ('\u{fd7d}', true, "\u{641}\u{62e}\u{645}"),
+    ('\u{fd7e}', true, "\u{642}\u{645}\u{62d}"),
+    ('\u{fd7f}', true, "\u{642}\u{645}\u{645}"),
+    ('\u{fd80}', true, "\u{644}\u{62d}\u{645}"),
+    ('\u{fd81}', true, "\u{644}\u{62d}\u{64a}"),
+    ('\u{fd82}', true, "\u{644}\u{62d}\u{649}"),
+    ('\u{fd83}', true, "\u{644}\u{62c}\u{62c}"),
+    ('\u{fd84}', true, "\u{644}\u{62c}\u{62c}"),
+    ('\u{fd85}', true, "\u{644}\u{62e}\u{645}"),
+    ('\u{fd86}', true, "\u{644}\u{62e}\u{645}"),
+    ('\u{fd87}', true, "\u{644}\u{645}\u{62d}"),
+    ('\u{fd88}', true, "\u{644}\u{645}\u{62d}"),
+    ('\u{fd89}', true, "\u{645}\u{62d}\u{62c}"),
+    ('\u{fd8a}', true, "\u{645}\u{62d}\u{645}"),
+    ('\u{fd8b}', true, "\u{645}\u{62d}\u{64a}"),
+    ('\u{fd8c}', true, "\u{645}\u{62c}\u{62d}"),
+    ('\u{fd8d}', true, "\u{645}\u{62c}\u{645}"),
+    ('\u{fd8e}', true, "\u{645}\u{62e}\u{62c}"),
+    ('\u{fd8f}', true, "\u{645}\u{62e}\u{645}"),
+    ('\u{fd92}', true, "\u{645}\u{62c}\u{62e}"),
+    ('\u{fd93}', true, "\u{647}\u{645}\u{62c}"),
+    ('\u{fd94}', true, "\u{647}\u{645}\u{645}"),
+    ('\u{fd95}', true, "\u{646}\u{62d}\u{645}"),
+    ('\u{fd96}', true, "\u{646}\u{62d}\u{649}"),
+    ('\u{fd97}', true, "\u{646}\u{62c}\u{645}"),
+    ('\u{fd98}', true, "\u{646}\u{62c}\u{645}"),
+    ('\u{fd99}', true, "\u{646}\u{62c}\u{649}"),
+    ('\u{fd9a}', true, "\u{646}\u{645}\u{64a}"),
+    ('\u{fd9b}', true, "\u{646}\u{645}\u{649}"),
+    ('\u{fd9c}', true, "\u{64a}\u{645}\u{645}"),
+    ('\u{fd9d}', true, "\u{64a}\u{645}\u{645}"),
+    ('\u{fd9e}', true, "\u{628}\u{62e}\u{64a}"),
+    ('\u{fd9f}', true, "\u{62a}\u{62c}\u{64a}"),
+    ('\u{fda0}', true, "\u{62a}\u{62c}\u{649}"),
+    ('\u{fda1}', true, "\u{62a}\u{62e}\u{64a}"),
+    ('\u{fda2}', true, "\u{62a}\u{62e}\u{649}"),
+    ('\u{fda3}', true, "\u{62a}\u{645}\u{64a}"),
+    ('\u{fda4}', true, "\u{62a}\u{645}\u{649}"),
+    ('\u{fda5}', true, "\u{62c}\u{645}\u{64a}"),
+    ('\u{fda6}', true, "\u{62c}\u{62d}\u{649}"),
+    ('\u{fda7}', true, "\u{62c}\u{645}\u{649}"),
+    ('\u{fda8}', true, "\u{633}\u{62e}\u{649}"),
+    ('\u{fda9}', true, "\u{635}\u{62d}\u{64a}"),
+    ('\u{fdaa}', true, "\u{634}\u{62d}\u{64a}"),
+    ('\u{fdab}', true, "\u{636}\u{62d}\u{64a}"),
+    ('\u{fdac}', true, "\u{644}\u{62c}\u{64a}"),
+    ('\u{fdad}', true, "\u{644}\u{645}\u{64a}"),
+    ('\u{fdae}', true, "\u{64a}\u{62d}\u{64a}"),
+    ('\u{fdaf}', true, "\u{64a}\u{62c}\u{64a}"),
+    ('\u{fdb0}', true, "\u{64a}\u{645}\u{64a}"),
+    ('\u{fdb1}', true, "\u{645}\u{645}\u{64a}"),
+    ('\u{fdb2}', true, "\u{642}\u{645}\u{64a}"),
+    ('\u{fdb3}', true, "\u{646}\u{62d}\u{64a}"),
+    ('\u{fdb4}', true, "\u{642}\u{645}\u{62d}"),
+    ('\u{fdb5}', true, "\u{644}\u{62d}\u{645}"),
+    ('\u{fdb6}', true, "\u{639}\u{645}\u{64a}"),
+    ('\u{fdb7}', true, "\u{643}\u{645}\u{64a}"),
+    ('\u{fdb8}', true, "\u{646}\u{62c}\u{62d}"),
+    ('\u{fdb9}', true, "\u{645}\u{62e}\u{64a}"),
+    ('\u{fdba}', true, "\u{644}\u{62c}\u{645}"),
+    ('\u{fdbb}', true, "\u{643}\u{645}\u{645}"),
+    ('\u{fdbc}', true, "\u{644}\u{62c}\u{645}"),
+    ('\u{fdbd}', true, "\u{646}\u{62c}\u{62d}"),
+    ('\u{fdbe}', true, "\u{62c}\u{62d}\u{64a}"),
+    ('\u{fdbf}', true, "\u{62d}\u{62c}\u{64a}"),
+    ('\u{fdc0}', true, "\u{645}\u{62c}\u{64a}"),
+    ('\u{fdc1}', true, "\u{641}\u{645}\u{64a}"),
+    ('\u{fdc2}', true, "\u{628}\u{62d}\u{64a}"),
+    ('\u{fdc3}', true, "\u{643}\u{645}\u{645}"),
+    ('\u{fdc4}', true, "\u{639}\u{62c}\u{645}"),
+    ('\u{fdc5}', true, "\u{635}\u{645}\u{645}"),
+    ('\u{fdc6}', true, "\u{633}\u{62e}\u{64a}"),
+    ('\u{fdc7}', true, "\u{646}\u{62c}\u{64a}"),
+    ('\u{fdf0}', true, "\u{635}\u{644}\u{6d2}"),
+    ('\u{fdf1}', true, "\u{642}\u{644}\u{6d2}"),
+    ('\u{fdf2}', true, "\u{627}\u{644}\u{644}\u{647}"),
+    ('\u{fdf3}', true, "\u{627}\u{643}\u{628}\u{631}"),
+    ('\u{fdf4}', true, "\u{645}\u{62d}\u{645}\u{62f}"),
+    ('\u{fdf5}', true, "\u{635}\u{644}\u{639}\u{645}"),
+    ('\u{fdf6}', true, "\u{631}\u{633}\u{648}\u{644}"),
+    ('\u{fdf7}', true, "\u{639}\u{644}\u{64a}\u{647}"),
+    ('\u{fdf8}', true, "\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{fdf9}', true, "\u{635}\u{644}\u{649}"),
+    ('\u{fdfa}', true, "\u{635}\u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64a}\u{647}\u{20}\u{648}\u{633}\u{644}\u{645}"),
+    ('\u{fdfb}', true, "\u{62c}\u{644}\u{20}\u{62c}\u{644}\u{627}\u{644}\u{647}"),
+    ('\u{fdfc}', true, "\u{631}\u{6cc}\u{627}\u{644}"),
+    ('\u{fe10}', true, "\u{2c}"),
+    ('\u{fe11}', true, "\u{3001}"),
+    ('\u{fe12}', true, "\u{3002}"),
+    ('\u{fe13}', true, "\u{3a}"),
+    ('\u{fe14}', true, "\u{3b}"),
+    ('\u{fe15}', true, "\u{21}"),
+    ('\u{fe16}', true, "\u{3f}"),
+    ('\u{fe17}', true, "\u{3016}"),
+    ('\u{fe18}', true, "\u{3017}"),
+    ('\u{fe19}', true, "\u{2026}"),
+    ('\u{fe30}', true, "\u{2025}"),
+    ('\u{fe31}', true, "\u{2014}"),
+    ('\u{fe32}', true, "\u{2013}"),
+    ('\u{fe33}', true, "\u{5f}"),
+    ('\u{fe34}', true, "\u{5f}"),
+    ('\u{fe35}', true, "\u{28}"),
+    ('\u{fe36}', true, "\u{29}"),
+    ('\u{fe37}', true, "\u{7b}"),
+    ('\u{fe38}', true, "\u{7d}"),
+    ('\u{fe39}', true, "\u{3014}"),
+    ('\u{fe3a}', true, "\u{3015}"),
+    ('\u{fe3b}', true, "\u{3010}"),
+    ('\u{fe3c}', true, "\u{3011}"),
+    ('\u{fe3d}', true, "\u{300a}"),
+    ('\u{fe3e}', true, "\u{300b}"),
+    ('\u{fe3f}', true, "\u{3008}"),
+    ('\u{fe40}', true, "\u{3009}"),
+    ('\u{fe41}', true, "\u{300c}"),
+    ('\u{fe42}', true, "\u{300d}"),
+    ('\u{fe43}', true, "\u{300e}"),
+    ('\u{fe44}', true, "\u{300f}"),
+    ('\u{fe47}', true, "\u{5b}"),
+    ('\u{fe48}', true, "\u{5d}"),
+    ('\u{fe49}', true, "\u{203e}"),
+    ('\u{fe4a}', true, "\u{203e}"),
+    ('\u{fe4b}', true, "\u{203e}"),
+    ('\u{fe4c}', true, "\u{203e}"),
+    ('\u{fe4d}', true, "\u{5f}"),
+    ('\u{fe4e}', true, "\u{5f}"),
+    ('\u{fe4f}', true, "\u{5f}"),
+    ('\u{fe50}', true, "\u{2c}"),
+    ('\u{fe51}', true, "\u{3001}"),
+    ('\u{fe52}', true, "\u{2e}"),
+    ('\u{fe54}', true, "\u{3b}"),
+    ('\u{fe55}', true, "\u{3a}"),
+    ('\u{fe56}', true, "\u{3f}"),
+    ('\u{fe57}', true, "\u{21}"),
+    ('\u{fe58}', true, "\u{2014}"),
+    ('\u{fe59}', true, "\u{28}"),
+    ('\u{fe5a}', true, "\u{29}"),
+    ('\u{fe5b}', true, "\u{7b}"),
+    ('\u{fe5c}', true, "\u{7d}"),
+    ('\u{fe5d}', true, "\u{3014}"),
+    ('\u{fe5e}', true, "\u{3015}"),
+    ('\u{fe5f}', true, "\u{23}"),
+    ('\u{fe60}', true, "\u{26}"),
+    ('\u{fe61}', true, "\u{2a}"),
+    ('\u{fe62}', true, "\u{2b}"),
+    ('\u{fe63}', true, "\u{2d}"),
+    ('\u{fe64}', true, "\u{3c}"),
+    ('\u{fe65}', true, "\u{3e}"),
+    ('\u{fe66}', true, "\u{3d}"),
+    ('\u{fe68}', true, "\u{5c}"),
+    ('\u{fe69}', true, "\u{24}"),
+    ('\u{fe6a}', true, "\u{25}"),
+    ('\u{fe6b}', true, "\u{40}"),
+    ('\u{fe70}', true, "\u{20}\u{64b}"),
+    ('\u{fe71}', true, "\u{640}\u{64b}"),
+    ('\u{fe72}', true, "\u{20}\u{64c}"),
+    ('\u{fe74}', true, "\u{20}\u{64d}"),
+    ('\u{fe76}', true, "\u{20}\u{64e}"),
+    ('\u{fe77}', true, "\u{640}\u{64e}"),
+    ('\u{fe78}', true, "\u{20}\u{64f}"),
+    ('\u{fe79}', true, "\u{640}\u{64f}"),
+    ('\u{fe7a}', true, "\u{20}\u{650}"),
+    ('\u{fe7b}', true, "\u{640}\u{650}"),
+    ('\u{fe7c}', true, "\u{20}\u{651}"),
+    ('\u{fe7d}', true, "\u{640}\u{651}"),
+    ('\u{fe7e}', true, "\u{20}\u{652}"),
+    ('\u{fe7f}', true, "\u{640}\u{652}"),
+    ('\u{fe80}', true, "\u{621}"),
+    ('\u{fe81}', true, "\u{622}"),
+    ('\u{fe82}', true, "\u{622}"),
+    ('\u{fe83}', true, "\u{623}"),
+    ('\u{fe84}', true, "\u{623}"),
+    ('\u{fe85}', true, "\u{624}"),
+    ('\u{fe86}', true, "\u{624}"),
+    ('\u{fe87}', true, "\u{625}"),
+    ('\u{fe88}', true, "\u{625}"),
+    ('\u{fe89}', true, "\u{626}"),
+    ('\u{fe8a}', true, "\u{626}"),
+    ('\u{fe8b}', true, "\u{626}"),
+    ('\u{fe8c}', true, "\u{626}"),
+    ('\u{fe8d}', true, "\u{627}"),
+    ('\u{fe8e}', true, "\u{627}"),
+    ('\u{fe8f}', true, "\u{628}"),
+    ('\u{fe90}', true, "\u{628}"),
+    ('\u{fe91}', true, "\u{628}"),
+    ('\u{fe92}', true, "\u{628}"),
+    ('\u{fe93}', true, "\u{629}"),
+    ('\u{fe94}', true, "\u{629}"),
+    ('\u{fe95}', true, "\u{62a}"),
+    ('\u{fe96}', true, "\u{62a}"),
+    ('\u{fe97}', true, "\u{62a}"),
+    ('\u{fe98}', true, "\u{62a}"),
+    ('\u{fe99}', true, "\u{62b}"),
+    ('\u{fe9a}', true, "\u{62b}"),
+    ('\u{fe9b}', true, "\u{62b}"),
+    ('\u{fe9c}', true, "\u{62b}"),
+    ('\u{fe9d}', true, "\u{62c}"),
+    ('\u{fe9e}', true, "\u{62c}"),
+    ('\u{fe9f}', true, "\u{62c}"),
+    ('\u{fea0}', true, "\u{62c}"),
+    ('\u{fea1}', true, "\u{62d}"),
+    ('\u{fea2}', true, "\u{62d}"),
+    ('\u{fea3}', true, "\u{62d}"),
+    ('\u{fea4}', true, "\u{62d}"),
+    ('\u{fea5}', true, "\u{62e}"),
+    ('\u{fea6}', true, "\u{62e}"),
+    ('\u{fea7}', true, "\u{62e}"),
+    ('\u{fea8}', true, "\u{62e}"),
+    ('\u{fea9}', true, "\u{62f}"),
+    ('\u{feaa}', true, "\u{62f}"),
+    ('\u{feab}', true, "\u{630}"),
+    ('\u{feac}', true, "\u{630}"),
+    ('\u{fead}', true, "\u{631}"),
+    ('\u{feae}', true, "\u{631}"),
+    ('\u{feaf}', true, "\u{632}"),
+    ('\u{feb0}', true, "\u{632}"),
+    ('\u{feb1}', true, "\u{633}"),
+    ('\u{feb2}', true, "\u{633}"),
+    ('\u{feb3}', true, "\u{633}"),
+    ('\u{feb4}', true, "\u{633}"),
+    ('\u{feb5}', true, "\u{634}"),
+    ('\u{feb6}', true, "\u{634}"),
+    ('\u{feb7}', true, "\u{634}"),
+    ('\u{feb8}', true, "\u{634}"),
+    ('\u{feb9}', true, "\u{635}"),
+    ('\u{feba}', true, "\u{635}"),
+    ('\u{febb}', true, "\u{635}"),
+    ('\u{febc}', true, "\u{635}"),
+    ('\u{febd}', true, "\u{636}"),
+    ('\u{febe}', true, "\u{636}"),
+    ('\u{febf}', true, "\u{636}"),
+    ('\u{fec0}', true, "\u{636}"),
+    ('\u{fec1}', true, "\u{637}"),
+    ('\u{fec2}', true, "\u{637}"),
+    ('\u{fec3}', true, "\u{637}"),
+    ('\u{fec4}', true, "\u{637}"),
+    ('\u{fec5}', true, "\u{638}"),
+    ('\u{fec6}', true, "\u{638}"),
+    ('\u{fec7}', true, "\u{638}"),
+    ('\u{fec8}', true, "\u{638}"),
+    ('\u{fec9}', true, "\u{639}"),
+    ('\u{feca}', true, "\u{639}"),
+    ('\u{fecb}', true, "\u{639}"),
+    ('\u{fecc}', true, "\u{639}"),
+    ('\u{fecd}', true, "\u{63a}"),
+    ('\u{fece}', true, "\u{63a}"),
+    ('\u{fecf}', true, "\u{63a}"),
+    ('\u{fed0}', true, "\u{63a}"),
+    ('\u{fed1}', true, "\u{641}"),
+    ('\u{fed2}', true, "\u{641}"),
+    ('\u{fed3}', true, "\u{641}"),
+    ('\u{fed4}', true, "\u{641}"),
+    ('\u{fed5}', true, "\u{642}"),
+    ('\u{fed6}', true, "\u{642}"),
+    ('\u{fed7}', true, "\u{642}"),
+    ('\u{fed8}', true, "\u{642}"),
+    ('\u{fed9}', true, "\u{643}"),
+    ('\u{feda}', true, "\u{643}"),
+    ('\u{fedb}', true, "\u{643}"),
+    ('\u{fedc}', true, "\u{643}"),
+    ('\u{fedd}', true, "\u{644}"),
+    ('\u{fede}', true, "\u{644}"),
+    ('\u{fedf}', true, "\u{644}"),
+    ('\u{fee0}', true, "\u{644}"),
+    ('\u{fee1}', true, "\u{645}"),
+    ('\u{fee2}', true, "\u{645}"),
+    ('\u{fee3}', true, "\u{645}"),
+    ('\u{fee4}', true, "\u{645}"),
+    ('\u{fee5}', true, "\u{646}"),
+    ('\u{fee6}', true, "\u{646}"),
+    ('\u{fee7}', true, "\u{646}"),
+    ('\u{fee8}', true, "\u{646}"),
+    ('\u{fee9}', true, "\u{647}"),
+    ('\u{feea}', true, "\u{647}"),
+    ('\u{feeb}', true, "\u{647}"),
+    ('\u{feec}', true, "\u{647}"),
+    ('\u{feed}', true, "\u{648}"),
+    ('\u{feee}', true, "\u{648}"),
+    ('\u{feef}', true, "\u{649}"),
+    ('\u{fef0}', true, "\u{649}"),
+    ('\u{fef1}', true, "\u{64a}"),
+    ('\u{fef2}', true, "\u{64a}"),
+    ('\u{fef3}', true, "\u{64a}"),
+    ('\u{fef4}', true, "\u{64a}"),
+    ('\u{fef5}', true, "\u{644}\u{622}"),
+    ('\u{fef6}', true, "\u{644}\u{622}"),
+    ('\u{fef7}', true, "\u{644}\u{623}"),
+    ('\u{fef8}', true, "\u{644}\u{623}"),
+    ('\u{fef9}', true, "\u{644}\u{625}"),
+    ('\u{fefa}', true, "\u{644}\u{625}"),
+    ('\u{fefb}', true, "\u{644}\u{627}"),
+    ('\u{fefc}', true, "\u{644}\u{627}"),
+    ('\u{ff01}', true, "\u{21}"),
+    ('\u{ff02}', true, "\u{22}"),
+    ('\u{ff03}', true, "\u{23}"),
+    ('\u{ff04}', true, "\u{24}"),
+    ('\u{ff05}', true, "\u{25}"),
+    ('\u{ff06}', true, "\u{26}"),
+    ('\u{ff07}', true, "\u{27}"),
+    ('\u{ff08}', true, "\u{28}"),
+    ('\u{ff09}', true, "\u{29}"),
+    ('\u{ff0a}', true, "\u{2a}"),
+    ('\u{ff0b}', true, "\u{2b}"),
+    ('\u{ff0c}', true, "\u{2c}"),
+    ('\u{ff0d}', true, "\u{2d}"),
+    ('\u{ff0e}', true, "\u{2e}"),
+    ('\u{ff0f}', true, "\u{2f}"),
+    ('\u{ff10}', true, "\u{30}"),
+    ('\u{ff11}', true, "\u{31}"),
+    ('\u{ff12}', true, "\u{32}"),
+    ('\u{ff13}', true, "\u{33}"),
+    ('\u{ff14}', true, "\u{34}"),
+    ('\u{ff15}', true, "\u{35}"),
+    ('\u{ff16}', true, "\u{36}"),
+    ('\u{ff17}', true, "\u{37}"),
+    ('\u{ff18}', true, "\u{38}"),
+    ('\u{ff19}', true, "\u{39}"),
+    ('\u{ff1a}', true, "\u{3a}"),
+    ('\u{ff1b}', true, "\u{3b}"),
+    ('\u{ff1c}', true, "\u{3c}"),
+    ('\u{ff1d}', true, "\u{3d}"),
+    ('\u{ff1e}', true, "\u{3e}"),
+    ('\u{ff1f}', true, "\u{3f}"),
+    ('\u{ff20}', true, "\u{40}"),
+    ('\u{ff21}', true, "\u{41}"),
+    ('\u{ff22}', true, "\u{42}"),
+    ('\u{ff23}', true, "\u{43}"),
+    ('\u{ff24}', true, "\u{44}"),
+    ('\u{ff25}', true, "\u{45}"),
+    ('\u{ff26}', true, "\u{46}"),
+    ('\u{ff27}', true, "\u{47}"),
+    ('\u{ff28}', true, "\u{48}"),
+    ('\u{ff29}', true, "\u{49}"),
+    ('\u{ff2a}', true, "\u{4a}"),
+    ('\u{ff2b}', true, "\u{4b}"),
+    ('\u{ff2c}', true, "\u{4c}"),
+    ('\u{ff2d}', true, "\u{4d}"),
+    ('\u{ff2e}', true, "\u{4e}"),
+    ('\u{ff2f}', true, "\u{4f}"),
+    ('\u{ff30}', true, "\u{50}"),
+    ('\u{ff31}', true, "\u{51}"),
+    ('\u{ff32}', true, "\u{52}"),
+    ('\u{ff33}', true, "\u{53}"),
+    ('\u{ff34}', true, "\u{54}"),
+    ('\u{ff35}', true, "\u{55}"),
+    ('\u{ff36}', true, "\u{56}"),
+    ('\u{ff37}', true, "\u{57}"),
+    ('\u{ff38}', true, "\u{58}"),
+    ('\u{ff39}', true, "\u{59}"),
+    ('\u{ff3a}', true, "\u{5a}"),
+    ('\u{ff3b}', true, "\u{5b}"),
+    ('\u{ff3c}', true, "\u{5c}"),
+    ('\u{ff3d}', true, "\u{5d}"),
+    ('\u{ff3e}', true, "\u{5e}"),
+    ('\u{ff3f}', true, "\u{5f}"),
+    ('\u{ff40}', true, "\u{60}"),
+    ('\u{ff41}', true, "\u{61}"),
+    ('\u{ff42}', true, "\u{62}"),
+    ('\u{ff43}', true, "\u{63}"),
+    ('\u{ff44}', true, "\u{64}"),
+    ('\u{ff45}', true, "\u{65}"),
+    ('\u{ff46}', true, "\u{66}"),
+    ('\u{ff47}', true, "\u{67}"),
+    ('\u{ff48}', true, "\u{68}"),
+    ('\u{ff49}', true, "\u{69}"),
+    ('\u{ff4a}', true, "\u{6a}"),
+    ('\u{ff4b}', true, "\u{6b}"),
+    ('\u{ff4c}', true, "\u{6c}"),
+    ('\u{ff4d}', true, "\u{6d}"),
+    ('\u{ff4e}', true, "\u{6e}"),
+    ('\u{ff4f}', true, "\u{6f}"),
+    ('\u{ff50}', true, "\u{70}"),
+    ('\u{ff51}', true, "\u{71}"),
+    ('\u{ff52}', true, "\u{72}"),
+    ('\u{ff53}', true, "\u{73}"),
+    ('\u{ff54}', true, "\u{74}"),
+    ('\u{ff55}', true, "\u{75}"),
+    ('\u{ff56}', true, "\u{76}"),
+    ('\u{ff57}', true, "\u{77}"),
+    ('\u{ff58}', true, "\u{78}"),
+    ('\u{ff59}', true, "\u{79}"),
+    ('\u{ff5a}', true, "\u{7a}"),
+    ('\u{ff5b}', true, "\u{7b}"),
+    ('\u{ff5c}', true, "\u{7c}"),
+    ('\u{ff5d}', true, "\u{7d}"),
+    ('\u{ff5e}', true, "\u{7e}"),
+    ('\u{ff5f}', true, "\u{2985}"),
+    ('\u{ff60}', true, "\u{2986}"),
+    ('\u{ff61}', true, "\u{3002}"),
+    ('\u{ff62}', true, "\u{300c}"),
+    ('\u{ff63}', true, "\u{300d}"),
+    ('\u{ff64}', true, "\u{3001}"),
+    ('\u{ff65}', true, "\u{30fb}"),
+    ('\u{ff66}', true, "\u{30f2}"),
+    ('\u{ff67}', true, "\u{30a1}"),
+    ('\u{ff68}', true, "\u{30a3}"),
+    ('\u{ff69}', true, "\u{30a5}"),
+    ('\u{ff6a}', true, "\u{30a7}"),
+    ('\u{ff6b}', true, "\u{30a9}"),
+    ('\u{ff6c}', true, "\u{30e3}"),
+    ('\u{ff6d}', true, "\u{30e5}"),
+    ('\u{ff6e}', true, "\u{30e7}"),
+    ('\u{ff6f}', true, "\u{30c3}"),
+    ('\u{ff70}', true, "\u{30fc}"),
+    ('\u{ff71}', true, "\u{30a2}"),
+    ('\u{ff72}', true, "\u{30a4}"),
+    ('\u{ff73}', true, "\u{30a6}"),
+    ('\u{ff74}', true, "\u{30a8}"),
+    ('\u{ff75}', true, "\u{30aa}"),
+    ('\u{ff76}', true, "\u{30ab}"),
+    ('\u{ff77}', true, "\u{30ad}"),
+    ('\u{ff78}', true, "\u{30af}"),
+    ('\u{ff79}', true, "\u{30b1}"),
+    ('\u{ff7a}', true, "\u{30b3}"),
+    ('\u{ff7b}', true, "\u{30b5}"),
+    ('\u{ff7c}', true, "\u{30b7}"),
+    ('\u{ff7d}', true, "\u{30b9}"),
+    ('\u{ff7e}', true, "\u{30bb}"),
+    ('\u{ff7f}', true, "\u{30bd}"),
+    ('\u{ff80}', true, "\u{30bf}"),
+    ('\u{ff81}', true, "\u{30c1}"),
+    ('\u{ff82}', true, "\u{30c4}"),
+    ('\u{ff83}', true, "\u{30c6}"),
+    ('\u{ff84}', true, "\u{30c8}"),
+    ('\u{ff85}', true, "\u{30ca}"),
+    ('\u{ff86}', true, "\u{30cb}"),
+    ('\u{ff87}', true, "\u{30cc}"),
+    ('\u{ff88}', true, "\u{30cd}"),
+    ('\u{ff89}', true, "\u{30ce}"),
+    ('\u{ff8a}', true, "\u{30cf}"),
+    ('\u{ff8b}', true, "\u{30d2}"),
+    ('\u{ff8c}', true, "\u{30d5}"),
+    ('\u{ff8d}', true, "\u{30d8}"),
+    ('\u{ff8e}', true, "\u{30db}"),
+    ('\u{ff8f}', true, "\u{30de}"),
+    ('\u{ff90}', true, "\u{30df}"),
+    ('\u{ff91}', true, "\u{30e0}"),
+    ('\u{ff92}', true, "\u{30e1}"),
+    ('\u{ff93}', true, "\u{30e2}"),
+    ('\u{ff94}', true, "\u{30e4}"),
+    ('\u{ff95}', true, "\u{30e6}"),
+    ('\u{ff96}', true, "\u{30e8}"),
+    ('\u{ff97}', true, "\u{30e9}"),
+    ('\u{ff98}', true, "\u{30ea}"),
+    ('\u{ff99}', true, "\u{30eb}"),
+    ('\u{ff9a}', true, "\u{30ec}"),
+    ('\u{ff9b}', true, "\u{30ed}"),
+    ('\u{ff9c}', true, "\u{30ef}"),
+    ('\u{ff9d}', true, "\u{30f3}"),
+    ('\u{ff9e}', true, "\u{3099}"),
+    ('\u{ff9f}', true, "\u{309a}"),
+    ('\u{ffa0}', true, "\u{3164}"),
+    ('\u{ffa1}', true, "\u{3131}"),
+    ('\u{ffa2}', true, "\u{3132}"),
+    ('\u{ffa3}', true, "\u{3133}"),
+    ('\u{ffa4}', true, "\u{3134}"),
+    ('\u{ffa5}', true, "\u{3135}"),
+    ('\u{ffa6}', true, "\u{3136}"),
+    ('\u{ffa7}', true, "\u{3137}"),
+    ('\u{ffa8}', true, "\u{3138}"),
+    ('\u{ffa9}', true, "\u{3139}"),
+    ('\u{ffaa}', true, "\u{313a}"),
+    ('\u{ffab}', true, "\u{313b}"),
+    ('\u{ffac}', true, "\u{313c}"),
+    ('\u{ffad}', true, "\u{313d}"),
+    ('\u{ffae}', true, "\u{313e}"),
+    ('\u{ffaf}', true, "\u{313f}"),
+    ('\u{ffb0}', true, "\u{3140}"),
+    ('\u{ffb1}', true, "\u{3141}"),
+    ('\u{ffb2}', true, "\u{3142}"),
+    ('\u{ffb3}', true, "\u{3143}"),
+    ('\u{ffb4}', true, "\u{3144}"),
+    ('\u{ffb5}', true, "\u{3145}"),
+    ('\u{ffb6}', true, "\u{3146}"),
+    ('\u{ffb7}', true, "\u{3147}"),
+    ('\u{ffb8}', true, "\u{3148}"),
+    ('\u{ffb9}', true, "\u{3149}"),
+    ('\u{ffba}', true, "\u{314a}"),
+    ('\u{ffbb}', true, "\u{314b}"),
+    ('\u{ffbc}', true, "\u{314c}"),
+    ('\u{ffbd}', true, "\u{314d}"),
+    ('\u{ffbe}', true, "\u{314e}"),
+    ('\u{ffc2}', true, "\u{314f}"),
+    ('\u{ffc3}', true, "\u{3150}"),
+    ('\u{ffc4}', true, "\u{3151}"),
+    ('\u{ffc5}', true, "\u{3152}"),
+    ('\u{ffc6}', true, "\u{3153}"),
+    ('\u{ffc7}', true, "\u{3154}"),
+    ('\u{ffca}', true, "\u{3155}"),
+    ('\u{ffcb}', true, "\u{3156}"),
+    ('\u{ffcc}', true, "\u{3157}"),
+    ('\u{ffcd}', true, "\u{3158}"),
+    ('\u{ffce}', true, "\u{3159}"),
+    ('\u{ffcf}', true, "\u{315a}"),
+    ('\u{ffd2}', true, "\u{315b}"),
+    ('\u{ffd3}', true, "\u{315c}"),
+    ('\u{ffd4}', true, "\u{315d}"),
+    ('\u{ffd5}', true, "\u{315e}"),
+    ('\u{ffd6}', true, "\u{315f}"),
+    ('\u{ffd7}', true, "\u{3160}"),
+    ('\u{ffda}', true, "\u{3161}"),
+    ('\u{ffdb}', true, "\u{3162}"),
+    ('\u{ffdc}', true, "\u{3163}"),
+    ('\u{ffe0}', true, "\u{a2}"),
+    ('\u{ffe1}', true, "\u{a3}"),
+    ('\u{ffe2}', true, "\u{ac}"),
+    ('\u{ffe3}', true, "\u{af}"),
+    ('\u{ffe4}', true, "\u{a6}"),
+    ('\u{ffe5}', true, "\u{a5}"),
+    ('\u{ffe6}', true, "\u{20a9}"),
+    ('\u{ffe8}', true, "\u{2502}"),
+    ('\u{ffe9}', true, "\u{2190}"),
+    ('\u{ffea}', true, "\u{2191}"),
+    ('\u{ffeb}', true, "\u{2192}"),
+    ('\u{ffec}', true, "\u{2193}"),
+    ('\u{ffed}', true, "\u{25a0}"),
+    ('\u{ffee}', true, "\u{25cb}"),
+    ('\u{10781}', true, "\u{2d0}"),
+    ('\u{10782}', true, "\u{2d1}"),
+    ('\u{10783}', true, "\u{e6}"),
+    ('\u{10784}', true, "\u{299}"),
+    ('\u{10785}', true, "\u{253}"),
+    ('\u{10787}', true, "\u{2a3}"),
+    ('\u{10788}', true, "\u{ab66}"),
+    ('\u{10789}', true, "\u{2a5}"),
+    ('\u{1078a}', true, "\u{2a4}"),
+    ('\u{1078b}', true, "\u{256}"),
+    ('\u{1078c}', true, "\u{257}"),
+    ('\u{1078d}', true, "\u{1d91}"),
+    ('\u{1078e}', true, "\u{258}"),
+    ('\u{1078f}', true, "\u{25e}"),
+    ('\u{10790}', true, "\u{2a9}"),
+    ('\u{10791}', true, "\u{264}"),
+    ('\u{10792}', true, "\u{262}"),
+    ('\u{10793}', true, "\u{260}"),
+    ('\u{10794}', true, "\u{29b}"),
+    ('\u{10795}', true, "\u{127}"),
+    ('\u{10796}', true, "\u{29c}"),
+    ('\u{10797}', true, "\u{267}"),
+    ('\u{10798}', true, "\u{284}"),
+    ('\u{10799}', true, "\u{2aa}"),
+    ('\u{1079a}', true, "\u{2ab}"),
+    ('\u{1079b}', true, "\u{26c}"),
+    ('\u{1079c}', true, "\u{1df04}"),
+    ('\u{1079d}', true, "\u{a78e}"),
+    ('\u{1079e}', true, "\u{26e}"),
+    ('\u{1079f}', true, "\u{1df05}"),
+    ('\u{107a0}', true, "\u{28e}"),
+    ('\u{107a1}', true, "\u{1df06}"),
+    ('\u{107a2}', true, "\u{f8}"),
+    ('\u{107a3}', true, "\u{276}"),
+    ('\u{107a4}', true, "\u{277}"),
+    ('\u{107a5}', true, "\u{71}"),
+    ('\u{107a6}', true, "\u{27a}"),
+    ('\u{107a7}', true, "\u{1df08}"),
+    ('\u{107a8}', true, "\u{27d}"),
+    ('\u{107a9}', true, "\u{27e}"),
+    ('\u{107aa}', true, "\u{280}"),
+    ('\u{107ab}', true, "\u{2a8}"),
+    ('\u{107ac}', true, "\u{2a6}"),
+    ('\u{107ad}', true, "\u{ab67}"),
+    ('\u{107ae}', true, "\u{2a7}"),
+    ('\u{107af}', true, "\u{288}"),
+    ('\u{107b0}', true, "\u{2c71}"),
+    ('\u{107b2}', true, "\u{28f}"),
+    ('\u{107b3}', true, "\u{2a1}"),
+    ('\u{107b4}', true, "\u{2a2}"),
+    ('\u{107b5}', true, "\u{298}"),
+    ('\u{107b6}', true, "\u{1c0}"),
+    ('\u{107b7}', true, "\u{1c1}"),
+    ('\u{107b8}', true, "\u{1c2}"),
+    ('\u{107b9}', true, "\u{1df0a}"),
+    ('\u{107ba}', true, "\u{1df1e}"),
+    ('\u{1109a}', false, "\u{11099}\u{110ba}"),
+    ('\u{1109c}', false, "\u{1109b}\u{110ba}"),
+    ('\u{110ab}', false, "\u{110a5}\u{110ba}"),
+    ('\u{1112e}', false, "\u{11131}\u{11127}"),
+    ('\u{1112f}', false, "\u{11132}\u{11127}"),
+    ('\u{1134b}', false, "\u{11347}\u{1133e}"),
+    ('\u{1134c}', false, "\u{11347}\u{11357}"),
+    ('\u{114bb}', false, "\u{114b9}\u{114ba}"),
+    ('\u{114bc}', false, "\u{114b9}\u{114b0}"),
+    ('\u{114be}', false, "\u{114b9}\u{114bd}"),
+    ('\u{115ba}', false, "\u{115b8}\u{115af}"),
+    ('\u{115bb}', false, "\u{115b9}\u{115af}"),
+    ('\u{11938}', false, "\u{11935}\u{11930}"),
+    ('\u{1d15e}', false, "\u{1d157}\u{1d165}"),
+    ('\u{1d15f}', false, "\u{1d158}\u{1d165}"),
+    ('\u{1d160}', false, "\u{1d15f}\u{1d16e}"),
+    ('\u{1d161}', false, "\u{1d15f}\u{1d16f}"),
+    ('\u{1d162}', false, "\u{1d15f}\u{1d170}"),
+    ('\u{1d163}', false, "\u{1d15f}\u{1d171}"),
+    ('\u{1d164}', false, "\u{1d15f}\u{1d172}"),
+    ('\u{1d1bb}', false, "\u{1d1b9}\u{1d165}"),
+    ('\u{1d1bc}', false, "\u{1d1ba}\u{1d165}"),
+    ('\u{1d1bd}', false, "\u{1d1bb}\u{1d16e}"),
+    ('\u{1d1be}', false, "\u{1d1bc}\u{1d16e}"),
+    ('\u{1d1bf}', false, "\u{1d1bb}\u{1d16f}"),
+    ('\u{1d1c0}', false, "\u{1d1bc}\u{1d16f}"),
+    ('\u{1d400}', true, "\u{41}"),
+    ('\u{1d401}', true, "\u{42}"),
+    ('\u{1d402}', true, "\u{43}"),
+    ('\u{1d403}', true, "\u{44}"),
+    ('\u{1d404}', true, "\u{45}"),
+    ('\u{1d405}', true, "\u{46}"),
+    ('\u{1d406}', true, "\u{47}"),
+    ('\u{1d407}', true, "\u{48}"),
+    ('\u{1d408}', true, "\u{49}"),
+    ('\u{1d409}', true, "\u{4a}"),
+    ('\u{1d40a}', true, "\u{4b}"),
+    ('\u{1d40b}', true, "\u{4c}"),
+    ('\u{1d40c}', true, "\u{4d}"),
+    ('\u{1d40d}', true, "\u{4e}"),
+    ('\u{1d40e}', true, "\u{4f}"),
+    ('\u{1d40f}', true, "\u{50}"),
+    ('\u{1d410}', true, "\u{51}"),
+    ('\u{1d411}', true, "\u{52}"),
+    ('\u{1d412}', true, "\u{53}"),
+    ('\u{1d413}', true, "\u{54}"),
+    ('\u{1d414}', true, "\u{55}"),
+    ('\u{1d415}', true, "\u{56}"),
+    ('\u{1d416}', true, "\u{57}"),
+    ('\u{1d417}', true, "\u{58}"),
+    ('\u{1d418}', true, "\u{59}"),
+    ('\u{1d419}', true, "\u{5a}"),
+    ('\u{1d41a}', true, "\u{61}"),
+    ('\u{1d41b}', true, "\u{62}"),
+    ('\u{1d41c}', true, "\u{63}"),
+    ('\u{1d41d}', true, "\u{64}"),
+    ('\u{1d41e}', true, "\u{65}"),
+    ('\u{1d41f}', true, "\u{66}"),
+    ('\u{1d420}', true, "\u{67}"),
+    ('\u{1d421}', true, "\u{68}"),
+    ('\u{1d422}', true, "\u{69}"),
+    ('\u{1d423}', true, "\u{6a}"),
+    ('\u{1d424}', true, "\u{6b}"),
+    ('\u{1d425}', true, "\u{6c}"),
+    ('\u{1d426}', true, "\u{6d}"),
+    ('\u{1d427}', true, "\u{6e}"),
+    ('\u{1d428}', true, "\u{6f}"),
+    ('\u{1d429}', true, "\u{70}"),
+    ('\u{1d42a}', true, "\u{71}"),
+    ('\u{1d42b}', true, "\u{72}"),
+    ('\u{1d42c}', true, "\u{73}"),
+    ('\u{1d42d}', true, "\u{74}"),
+    ('\u{1d42e}', true, "\u{75}"),
+    ('\u{1d42f}', true, "\u{76}"),
+    ('\u{1d430}', true, "\u{77}"),
+    ('\u{1d431}', true, "\u{78}"),
+    ('\u{1d432}', true, "\u{79}"),
+    ('\u{1d433}', true, "\u{7a}"),
+    ('\u{1d434}', true, "\u{41}"),
+    ('\u{1d435}', true, "\u{42}"),
+    ('\u{1d436}', true, "\u{43}"),
+    ('\u{1d437}', true, "\u{44}"),
+    ('\u{1d438}', true, "\u{45}"),
+    ('\u{1d439}', true, "\u{46}"),
+    ('\u{1d43a}', true, "\u{47}"),
+    ('\u{1d43b}', true, "\u{48}"),
+    ('\u{1d43c}', true, "\u{49}"),
+    ('\u{1d43d}', true, "\u{4a}"),
+    ('\u{1d43e}', true, "\u{4b}"),
+    ('\u{1d43f}', true, "\u{4c}"),
+    ('\u{1d440}', true, "\u{4d}"),
+    ('\u{1d441}', true, "\u{4e}"),
+    ('\u{1d442}', true, "\u{4f}"),
+    ('\u{1d443}', true, "\u{50}"),
+    ('\u{1d444}', true, "\u{51}"),
+    ('\u{1d445}', true, "\u{52}"),
+    ('\u{1d446}', true, "\u{53}"),
+    ('\u{1d447}', true, "\u{54}"),
+    ('\u{1d448}', true, "\u{55}"),
+    ('\u{1d449}', true, "\u{56}"),
+    ('\u{1d44a}', true, "\u{57}"),
+    ('\u{1d44b}', true, "\u{58}"),
+    ('\u{1d44c}', true, "\u{59}"),
+    ('\u{1d44d}', true, "\u{5a}"),
+    ('\u{1d44e}', true, "\u{61}"),
+    ('\u{1d44f}', true, "\u{62}"),
+    ('\u{1d450}', true, "\u{63}"),
+    ('\u{1d451}', true, "\u{64}"),
+    ('\u{1d452}', true, "\u{65}"),
+    ('\u{1d453}', true, "\u{66}"),
+    ('\u{1d454}', true, "\u{67}"),
+    ('\u{1d456}', true, "\u{69}"),
+    ('\u{1d457}', true, "\u{6a}"),
+    ('\u{1d458}', true, "\u{6b}"),
+    ('\u{1d459}', true, "\u{6c}"),
+    ('\u{1d45a}', true, "\u{6d}"),
+    ('\u{1d45b}', true, "\u{6e}"),
+    ('\u{1d45c}', true, "\u{6f}"),
+    ('\u{1d45d}', true, "\u{70}"),
+    ('\u{1d45e}', true, "\u{71}"),
+    ('\u{1d45f}', true, "\u{72}"),
+    ('\u{1d460}', true, "\u{73}"),
+    ('\u{1d461}', true, "\u{74}"),
+    ('\u{1d462}', true, "\u{75}"),
+    ('\u{1d463}', true, "\u{76}"),
+    ('\u{1d464}', true, "\u{77}"),
+    ('\u{1d465}', true, "\u{78}"),
+    ('\u{1d466}', true, "\u{79}"),
+    ('\u{1d467}', true, "\u{7a}"),
+    ('\u{1d468}', true, "\u{41}"),
+    ('\u{1d469}', true, "\u{42}"),
+    ('\u{1d46a}', true, "\u{43}"),
+    ('\u{1d46b}', true, "\u{44}"),
+    ('\u{1d46c}', true, "\u{45}"),
+    ('\u{1d46d}', true, "\u{46}"),
+    ('\u{1d46e}', true, "\u{47}"),
+    ('\u{1d46f}', true, "\u{48}"),
+    ('\u{1d470}', true, "\u{49}"),
+    ('\u{1d471}', true, "\u{4a}"),
+    ('\u{1d472}', true, "\u{4b}"),
+    ('\u{1d473}', true, "\u{4c}"),
+    ('\u{1d474}', true, "\u{4d}"),
+    ('\u{1d475}', true, "\u{4e}"),
+    ('\u{1d476}', true, "\u{4f}"),
+    ('\u{1d477}', true, "\u{50}"),
+    ('\u{1d478}', true, "\u{51}"),
+    ('\u{1d479}', true, "\u{52}"),
+    ('\u{1d47a}', true, "\u{53}"),
+    ('\u{1d47b}', true, "\u{54}"),
+    ('\u{1d47c}', true, "\u{55}"),
+    ('\u{1d47d}', true, "\u{56}"),
+    ('\u{1d47e}', true, "\u{57}"),
+    ('\u{1d47f}', true, "\u{58}"),
+    ('\u{1d480}', true, "\u{59}"),
+    ('\u{1d481}', true, "\u{5a}"),
+    ('\u{1d482}', true, "\u{61}"),
+    ('\u{1d483}', true, "\u{62}"),
+    ('\u{1d484}', true, "\u{63}"),
+    ('\u{1d485}', true, "\u{64}"),
+    ('\u{1d486}', true, "\u{65}"),
+    ('\u{1d487}', true, "\u{66}"),
+    ('\u{1d488}', true, "\u{67}"),
+    ('\u{1d489}', true, "\u{68}"),
+    ('\u{1d48a}', true, "\u{69}"),
+    ('\u{1d48b}', true, "\u{6a}"),
+    ('\u{1d48c}', true, "\u{6b}"),
+    ('\u{1d48d}', true, "\u{6c}"),
+    ('\u{1d48e}', true, "\u{6d}"),
+    ('\u{1d48f}', true, "\u{6e}"),
+    ('\u{1d490}', true, "\u{6f}"),
+    ('\u{1d491}', true, "\u{70}"),
+    ('\u{1d492}', true, "\u{71}"),
+    ('\u{1d493}', true, "\u{72}"),
+    ('\u{1d494}', true, "\u{73}"),
+    ('\u{1d495}', true, "\u{74}"),
+    ('\u{1d496}', true, "\u{75}"),
+    ('\u{1d497}', true, "\u{76}"),
+    ('\u{1d498}', true, "\u{77}"),
+    ('\u{1d499}', true, "\u{78}"),
+    ('\u{1d49a}', true, "\u{79}"),
+    ('\u{1d49b}', true, "\u{7a}"),
+    ('\u{1d49c}', true, "\u{41}"),
+    ('\u{1d49e}', true, "\u{43}"),
+    ('\u{1d49f}', true, "\u{44}"),
+    ('\u{1d4a2}', true, "\u{47}"),
+    ('\u{1d4a5}', true, "\u{4a}"),
+    ('\u{1d4a6}', true, "\u{4b}"),
+    ('\u{1d4a9}', true, "\u{4e}"),
+    ('\u{1d4aa}', true, "\u{4f}"),
+    ('\u{1d4ab}', true, "\u{50}"),
+    ('\u{1d4ac}', true, "\u{51}"),
+    ('\u{1d4ae}', true, "\u{53}"),
+    ('\u{1d4af}', true, "\u{54}"),
+    ('\u{1d4b0}', true, "\u{55}"),
+    ('\u{1d4b1}', true, "\u{56}"),
+    ('\u{1d4b2}', true, "\u{57}"),
+    ('\u{1d4b3}', true, "\u{58}"),
+    ('\u{1d4b4}', true, "\u{59}"),
+    ('\u{1d4b5}', true, "\u{5a}"),
+    ('\u{1d4b6}', true, "\u{61}"),
+    ('\u{1d4b7}', true, "\u{62}"),
+    ('\u{1d4b8}', true, "\u{63}"),
+    ('\u{1d4b9}', true, "\u{64}"),
+    ('\u{1d4bb}', true, "\u{66}"),
+    ('\u{1d4bd}', true, "\u{68}"),
+    ('\u{1d4be}', true, "\u{69}"),
+    ('\u{1d4bf}', true, "\u{6a}"),
+    ('\u{1d4c0}', true, "\u{6b}"),
+    ('\u{1d4c1}', true, "\u{6c}"),
+    ('\u{1d4c2}', true, "\u{6d}"),
+    ('\u{1d4c3}', true, "\u{6e}"),
+    ('\u{1d4c5}', true, "\u{70}"),
+    ('\u{1d4c6}', true, "\u{71}"),
+    ('\u{1d4c7}', true, "\u{72}"),
+    ('\u{1d4c8}', true, "\u{73}"),
+    ('\u{1d4c9}', true, "\u{74}"),
+    ('\u{1d4ca}', true, "\u{75}"),
+    ('\u{1d4cb}', true, "\u{76}"),
+    ('\u{1d4cc}', true, "\u{77}"),
+    ('\u{1d4cd}', true, "\u{78}"),
+    ('\u{1d4ce}', true, "\u{79}"),
+    ('\u{1d4cf}', true, "\u{7a}"),
+    ('\u{1d4d0}', true, "\u{41}"),
+    ('\u{1d4d1}', true, "\u{42}"),
+    ('\u{1d4d2}', true, "\u{43}"),
+    ('\u{1d4d3}', true, "\u{44}"),
+    ('\u{1d4d4}', true, "\u{45}"),
+    ('\u{1d4d5}', true, "\u{46}"),
+    ('\u{1d4d6}', true, "\u{47}"),
+    ('\u{1d4d7}', true, "\u{48}"),
+    ('\u{1d4d8}', true, "\u{49}"),
+    ('\u{1d4d9}', true, "\u{4a}"),
+    ('\u{1d4da}', true, "\u{4b}"),
+    ('\u{1d4db}', true, "\u{4c}"),
+    ('\u{1d4dc}', true, "\u{4d}"),
+    ('\u{1d4dd}', true, "\u{4e}"),
+    ('\u{1d4de}', true, "\u{4f}"),
+    ('\u{1d4df}', true, "\u{50}"),
+    ('\u{1d4e0}', true, "\u{51}"),
+    ('\u{1d4e1}', true, "\u{52}"),
+    ('\u{1d4e2}', true, "\u{53}"),
+    ('\u{1d4e3}', true, "\u{54}"),
+    ('\u{1d4e4}', true, "\u{55}"),
+    ('\u{1d4e5}', true, "\u{56}"),
+    ('\u{1d4e6}', true, "\u{57}"),
+    ('\u{1d4e7}', true, "\u{58}"),
+    ('\u{1d4e8}', true, "\u{59}"),
+    ('\u{1d4e9}', true, "\u{5a}"),
+    ('\u{1d4ea}', true, "\u{61}"),
+    ('\u{1d4eb}', true, "\u{62}"),
+    ('\u{1d4ec}', true, "\u{63}"),
+    ('\u{1d4ed}', true, "\u{64}"),
+    ('\u{1d4ee}', true, "\u{65}"),
+    ('\u{1d4ef}', true, "\u{66}"),
+    ('\u{1d4f0}', true, "\u{67}"),
+    ('\u{1d4f1}', true, "\u{68}"),
+    ('\u{1d4f2}', true, "\u{69}"),
+    ('\u{1d4f3}', true, "\u{6a}"),
+    ('\u{1d4f4}', true, "\u{6b}"),
+    ('\u{1d4f5}', true, "\u{6c}"),
+    ('\u{1d4f6}', true, "\u{6d}"),
+    ('\u{1d4f7}', true, "\u{6e}"),
+    ('\u{1d4f8}', true, "\u{6f}"),
+    ('\u{1d4f9}', true, "\u{70}"),
+    ('\u{1d4fa}', true, "\u{71}"),
+    ('\u{1d4fb}', true, "\u{72}"),
+    ('\u{1d4fc}', true, "\u{73}"),
+    ('\u{1d4fd}', true, "\u{74}"),
+    ('\u{1d4fe}', true, "\u{75}"),
+    ('\u{1d4ff}', true, "\u{76}"),
+    ('\u{1d500}', true, "\u{77}"),
+    ('\u{1d501}', true, "\u{78}"),
+    ('\u{1d502}', true, "\u{79}"),
+    ('\u{1d503}', true, "\u{7a}"),
+    ('\u{1d504}', true, "\u{41}"),
+    ('\u{1d505}', true, "\u{42}"),
+    ('\u{1d507}', true, "\u{44}"),
+    ('\u{1d508}', true, "\u{45}"),
+    ('\u{1d509}', true, "\u{46}"),
+    ('\u{1d50a}', true, "\u{47}"),
+    ('\u{1d50d}', true, "\u{4a}"),
+    ('\u{1d50e}', true, "\u{4b}"),
+    ('\u{1d50f}', true, "\u{4c}"),
+    ('\u{1d510}', true, "\u{4d}"),
+    ('\u{1d511}', true, "\u{4e}"),
+    ('\u{1d512}', true, "\u{4f}"),
+    ('\u{1d513}', true, "\u{50}"),
+    ('\u{1d514}', true, "\u{51}"),
+    ('\u{1d516}', true, "\u{53}"),
+    ('\u{1d517}', true, "\u{54}"),
+    ('\u{1d518}', true, "\u{55}"),
+    ('\u{1d519}', true, "\u{56}"),
+    ('\u{1d51a}', true, "\u{57}"),
+    ('\u{1d51b}', true, "\u{58}"),
+    ('\u{1d51c}', true, "\u{59}"),
+    ('\u{1d51e}', true, "\u{61}"),
+    ('\u{1d51f}', true, "\u{62}"),
+    ('\u{1d520}', true, "\u{63}"),
+    ('\u{1d521}', true, "\u{64}"),
+    ('\u{1d522}', true, "\u{65}"),
+    ('\u{1d523}', true, "\u{66}"),
+    ('\u{1d524}', true, "\u{67}"),
+    ('\u{1d525}', true, "\u{68}"),
+    ('\u{1d526}', true, "\u{69}"),
+    ('\u{1d527}', true, "\u{6a}"),
+    ('\u{1d528}', true, "\u{6b}"),
+    ('\u{1d529}', true, "\u{6c}"),
+    ('\u{1d52a}', true, "\u{6d}"),
+    ('\u{1d52b}', true, "\u{6e}"),
+    ('\u{1d52c}', true, "\u{6f}"),
+    ('\u{1d52d}', true, "\u{70}"),
+    ('\u{1d52e}', true, "\u{71}"),
+    ('\u{1d52f}', true, "\u{72}"),
+    ('\u{1d530}', true, "\u{73}"),
+    ('\u{1d531}', true, "\u{74}"),
+    ('\u{1d532}', true, "\u{75}"),
+    ('\u{1d533}', true, "\u{76}"),
+    ('\u{1d534}', true, "\u{77}"),
+    ('\u{1d535}', true, "\u{78}"),
+    ('\u{1d536}', true, "\u{79}"),
+    ('\u{1d537}', true, "\u{7a}"),
+    ('\u{1d538}', true, "\u{41}"),
+    ('\u{1d539}', true, "\u{42}"),
+    ('\u{1d53b}', true, "\u{44}"),
+    ('\u{1d53c}', true, "\u{45}"),
+    ('\u{1d53d}', true, "\u{46}"),
+    ('\u{1d53e}', true, "\u{47}"),
+    ('\u{1d540}', true, "\u{49}"),
+    ('\u{1d541}', true, "\u{4a}"),
+    ('\u{1d542}', true, "\u{4b}"),
+    ('\u{1d543}', true, "\u{4c}"),
+    ('\u{1d544}', true, "\u{4d}"),
+    ('\u{1d546}', true, "\u{4f}"),
+    ('\u{1d54a}', true, "\u{53}"),
+    ('\u{1d54b}', true, "\u{54}"),
+    ('\u{1d54c}', true, "\u{55}"),
+    ('\u{1d54d}', true, "\u{56}"),
+    ('\u{1d54e}', true, "\u{57}"),
+    ('\u{1d54f}', true, "\u{58}"),
+    ('\u{1d550}', true, "\u{59}"),
+    ('\u{1d552}', true, "\u{61}"),
+    ('\u{1d553}', true, "\u{62}"),
+    ('\u{1d554}', true, "\u{63}"),
+    ('\u{1d555}', true, "\u{64}"),
+    ('\u{1d556}', true, "\u{65}"),
+    ('\u{1d557}', true, "\u{66}"),
+    ('\u{1d558}', true, "\u{67}"),
+    ('\u{1d559}', true, "\u{68}"),
+    ('\u{1d55a}', true, "\u{69}"),
+    ('\u{1d55b}', true, "\u{6a}"),
+    ('\u{1d55c}', true, "\u{6b}"),
+    ('\u{1d55d}', true, "\u{6c}"),
+    ('\u{1d55e}', true, "\u{6d}"),
+    ('\u{1d55f}', true, "\u{6e}"),
+    ('\u{1d560}', true, "\u{6f}"),
+    ('\u{1d561}', true, "\u{70}"),
+    ('\u{1d562}', true, "\u{71}"),
+    ('\u{1d563}', true, "\u{72}"),
+    ('\u{1d564}', true, "\u{73}"),
+    ('\u{1d565}', true, "\u{74}"),
+    ('\u{1d566}', true, "\u{75}"),
+    ('\u{1d567}', true, "\u{76}"),
+    ('\u{1d568}', true, "\u{77}"),
+    ('\u{1d569}', true, "\u{78}"),
+    ('\u{1d56a}', true, "\u{79}"),
+    ('\u{1d56b}', true, "\u{7a}"),
+    ('\u{1d56c}', true, "\u{41}"),
+    ('\u{1d56d}', true, "\u{42}"),
+    ('\u{1d56e}', true, "\u{43}"),
+    ('\u{1d56f}', true, "\u{44}"),
+    ('\u{1d570}', true, "\u{45}"),
+    ('\u{1d571}', true, "\u{46}"),
+    ('\u{1d572}', true, "\u{47}"),
+    ('\u{1d573}', true, "\u{48}"),
+    ('\u{1d574}', true, "\u{49}"),
+    ('\u{1d575}', true, "\u{4a}"),
+    ('\u{1d576}', true, "\u{4b}"),
+    ('\u{1d577}', true, "\u{4c}"),
+    ('\u{1d578}', true, "\u{4d}"),
+    ('\u{1d579}', true, "\u{4e}"),
+    ('\u{1d57a}', true, "\u{4f}"),
+    ('\u{1d57b}', true, "\u{50}"),
+    ('\u{1d57c}', true, "\u{51}"),
+    ('\u{1d57d}', true, "\u{52}"),
+    ('\u{1d57e}', true, "\u{53}"),
+    ('\u{1d57f}', true, "\u{54}"),
+    ('\u{1d580}', true, "\u{55}"),
+    ('\u{1d581}', true, "\u{56}"),
+    ('\u{1d582}', true, "\u{57}"),
+    ('\u{1d583}', true, "\u{58}"),
+    ('\u{1d584}', true, "\u{59}"),
+    ('\u{1d585}', true, "\u{5a}"),
+    ('\u{1d586}', true, "\u{61}"),
+    ('\u{1d587}', true, "\u{62}"),
+    ('\u{1d588}', true, "\u{63}"),
+    ('\u{1d589}', true, "\u{64}"),
+    ('\u{1d58a}', true, "\u{65}"),
+    ('\u{1d58b}', true, "\u{66}"),
+    ('\u{1d58c}', true, "\u{67}"),
+    ('\u{1d58d}', true, "\u{68}"),
+    ('\u{1d58e}', true, "\u{69}"),
+    ('\u{1d58f}', true, "\u{6a}"),
+    ('\u{1d590}', true, "\u{6b}"),
+    ('\u{1d591}', true, "\u{6c}"),
+    ('\u{1d592}', true, "\u{6d}"),
+    ('\u{1d593}', true, "\u{6e}"),
+    ('\u{1d594}', true, "\u{6f}"),
+    ('\u{1d595}', true, "\u{70}"),
+    ('\u{1d596}', true, "\u{71}"),
+    ('\u{1d597}', true, "\u{72}"),
+    ('\u{1d598}', true, "\u{73}"),
+    ('\u{1d599}', true, "\u{74}"),
+    ('\u{1d59a}', true, "\u{75}"),
+    ('\u{1d59b}', true, "\u{76}"),
+    ('\u{1d59c}', true, "\u{77}"),
+    ('\u{1d59d}', true, "\u{78}"),
+    ('\u{1d59e}', true, "\u{79}"),
+    ('\u{1d59f}', true, "\u{7a}"),
+    ('\u{1d5a0}', true, "\u{41}"),
+    ('\u{1d5a1}', true, "\u{42}"),
+    ('\u{1d5a2}', true, "\u{43}"),
+    ('\u{1d5a3}', true, "\u{44}"),
+    ('\u{1d5a4}', true, "\u{45}"),
+    ('\u{1d5a5}', true, "\u{46}"),
+    ('\u{1d5a6}', true, "\u{47}"),
+    ('\u{1d5a7}', true, "\u{48}"),
+    ('\u{1d5a8}', true, "\u{49}"),
+    ('\u{1d5a9}', true, "\u{4a}"),
+    ('\u{1d5aa}', true, "\u{4b}"),
+    ('\u{1d5ab}', true, "\u{4c}"),
+    ('\u{1d5ac}', true, "\u{4d}"),
+    ('\u{1d5ad}', true, "\u{4e}"),
+    ('\u{1d5ae}', true, "\u{4f}"),
+    ('\u{1d5af}', true, "\u{50}"),
+    ('\u{1d5b0}', true, "\u{51}"),
+    ('\u{1d5b1}', true, "\u{52}"),
+    ('\u{1d5b2}', true, "\u{53}"),
+    ('\u{1d5b3}', true, "\u{54}"),
+    ('\u{1d5b4}', true, "\u{55}"),
+    ('\u{1d5b5}', true, "\u{56}"),
+    ('\u{1d5b6}', true, "\u{57}"),
+    ('\u{1d5b7}', true, "\u{58}"),
+    ('\u{1d5b8}', true, "\u{59}"),
+    ('\u{1d5b9}', true, "\u{5a}"),
+    ('\u{1d5ba}', true, "\u{61}"),
+    ('\u{1d5bb}', true, "\u{62}"),
+    ('\u{1d5bc}', true, "\u{63}"),
+    ('\u{1d5bd}', true, "\u{64}"),
+    ('\u{1d5be}', true, "\u{65}"),
+    ('\u{1d5bf}', true, "\u{66}"),
+    ('\u{1d5c0}', true, "\u{67}"),
+    ('\u{1d5c1}', true, "\u{68}"),
+    ('\u{1d5c2}', true, "\u{69}"),
+    ('\u{1d5c3}', true, "\u{6a}"),
+    ('\u{1d5c4}', true, "\u{6b}"),
+    ('\u{1d5c5}', true, "\u{6c}"),
+    ('\u{1d5c6}', true, "\u{6d}"),
+    ('\u{1d5c7}', true, "\u{6e}"),
+    ('\u{1d5c8}', true, "\u{6f}"),
+    ('\u{1d5c9}', true, "\u{70}"),
+    ('\u{1d5ca}', true, "\u{71}"),
+    ('\u{1d5cb}', true, "\u{72}"),
+    ('\u{1d5cc}', true, "\u{73}"),
+    ('\u{1d5cd}', true, "\u{74}"),
+    ('\u{1d5ce}', true, "\u{75}"),
+    ('\u{1d5cf}', true, "\u{76}"),
+    ('\u{1d5d0}', true, "\u{77}"),
+    ('\u{1d5d1}', true, "\u{78}"),
+    ('\u{1d5d2}', true, "\u{79}"),
+    ('\u{1d5d3}', true, "\u{7a}"),
+    ('\u{1d5d4}', true, "\u{41}"),
+    ('\u{1d5d5}', true, "\u{42}"),
+    ('\u{1d5d6}', true, "\u{43}"),
+    ('\u{1d5d7}', true, "\u{44}"),
+    ('\u{1d5d8}', true, "\u{45}"),
+    ('\u{1d5d9}', true, "\u{46}"),
+    ('\u{1d5da}', true, "\u{47}"),
+    ('\u{1d5db}', true, "\u{48}"),
+    ('\u{1d5dc}', true, "\u{49}"),
+    ('\u{1d5dd}', true, "\u{4a}"),
+    ('\u{1d5de}', true, "\u{4b}"),
+    ('\u{1d5df}', true, "\u{4c}"),
+    ('\u{1d5e0}', true, "\u{4d}"),
+    ('\u{1d5e1}', true, "\u{4e}"),
+    ('\u{1d5e2}', true, "\u{4f}"),
+    ('\u{1d5e3}', true, "\u{50}"),
+    ('\u{1d5e4}', true, "\u{51}"),
+    ('\u{1d5e5}', true, "\u{52}"),
+    ('\u{1d5e6}', true, "\u{53}"),
+    ('\u{1d5e7}', true, "\u{54}"),
+    ('\u{1d5e8}', true, "\u{55}"),
+    ('\u{1d5e9}', true, "\u{56}"),
+    ('\u{1d5ea}', true, "\u{57}"),
+    ('\u{1d5eb}', true, "\u{58}"),
+    ('\u{1d5ec}', true, "\u{59}"),
+    ('\u{1d5ed}', true, "\u{5a}"),
+    ('\u{1d5ee}', true, "\u{61}"),
+    ('\u{1d5ef}', true, "\u{62}"),
+    ('\u{1d5f0}', true, "\u{63}"),
+    ('\u{1d5f1}', true, "\u{64}"),
+    ('\u{1d5f2}', true, "\u{65}"),
+    ('\u{1d5f3}', true, "\u{66}"),
+    ('\u{1d5f4}', true, "\u{67}"),
+    ('\u{1d5f5}', true, "\u{68}"),
+    ('\u{1d5f6}', true, "\u{69}"),
+    ('\u{1d5f7}', true, "\u{6a}"),
+    ('\u{1d5f8}', true, "\u{6b}"),
+    ('\u{1d5f9}', true, "\u{6c}"),
+    ('\u{1d5fa}', true, "\u{6d}"),
+    ('\u{1d5fb}', true, "\u{6e}"),
+    ('\u{1d5fc}', true, "\u{6f}"),
+    ('\u{1d5fd}', true, "\u{70}"),
+    ('\u{1d5fe}', true, "\u{71}"),
+    ('\u{1d5ff}', true, "\u{72}"),
+    ('\u{1d600}', true, "\u{73}"),
+    ('\u{1d601}', true, "\u{74}"),
+    ('\u{1d602}', true, "\u{75}"),
+    ('\u{1d603}', true, "\u{76}"),
+    ('\u{1d604}', true, "\u{77}"),
+    ('\u{1d605}', true, "\u{78}"),
+    ('\u{1d606}', true, "\u{79}"),
+    ('\u{1d607}', true, "\u{7a}"),
+    ('\u{1d608}', true, "\u{41}"),
+    ('\u{1d609}', true, "\u{42}"),
+    ('\u{1d60a}', true, "\u{43}"),
+    ('\u{1d60b}', true, "\u{44}"),
+    ('\u{1d60c}', true, "\u{45}"),
+    ('\u{1d60d}', true, "\u{46}"),
+    ('\u{1d60e}', true, "\u{47}"),
+    ('\u{1d60f}', true, "\u{48}"),
+    ('\u{1d610}', true, "\u{49}"),
+    ('\u{1d611}', true, "\u{4a}"),
+    ('\u{1d612}', true, "\u{4b}"),
+    ('\u{1d613}', true, "\u{4c}"),
+    ('\u{1d614}', true, "\u{4d}"),
+    ('\u{1d615}', true, "\u{4e}"),
+    ('\u{1d616}', true, "\u{4f}"),
+    ('\u{1d617}', true, "\u{50}"),
+    ('\u{1d618}', true, "\u{51}"),
+    ('\u{1d619}', true, "\u{52}"),
+    ('\u{1d61a}', true, "\u{53}"),
+    ('\u{1d61b}', true, "\u{54}"),
+    ('\u{1d61c}', true, "\u{55}"),
+    ('\u{1d61d}', true, "\u{56}"),
+    ('\u{1d61e}', true, "\u{57}"),
+    ('\u{1d61f}', true, "\u{58}"),
+    ('\u{1d620}', true, "\u{59}"),
+    ('\u{1d621}', true, "\u{5a}"),
+    ('\u{1d622}', true, "\u{61}"),
+    ('\u{1d623}', true, "\u{62}"),
+    ('\u{1d624}', true, "\u{63}"),
+    ('\u{1d625}', true, "\u{64}"),
+    ('\u{1d626}', true, "\u{65}"),
+    ('\u{1d627}', true, "\u{66}"),
+    ('\u{1d628}', true, "\u{67}"),
+    ('\u{1d629}', true, "\u{68}"),
+    ('\u{1d62a}', true, "\u{69}"),
+    ('\u{1d62b}', true, "\u{6a}"),
+    ('\u{1d62c}', true, "\u{6b}"),
+    ('\u{1d62d}', true, "\u{6c}"),
+    ('\u{1d62e}', true, "\u{6d}"),
+    ('\u{1d62f}', true, "\u{6e}"),
+    ('\u{1d630}', true, "\u{6f}"),
+    ('\u{1d631}', true, "\u{70}"),
+    ('\u{1d632}', true, "\u{71}"),
+    ('\u{1d633}', true, "\u{72}"),
+    ('\u{1d634}', true, "\u{73}"),
+    ('\u{1d635}', true, "\u{74}"),
+    ('\u{1d636}', true, "\u{75}"),
+    ('\u{1d637}', true, "\u{76}"),
+    ('\u{1d638}', true, "\u{77}"),
+    ('\u{1d639}', true, "\u{78}"),
+    ('\u{1d63a}', true, "\u{79}"),
+    ('\u{1d63b}', true, "\u{7a}"),
+    ('\u{1d63c}', true, "\u{41}"),
+    ('\u{1d63d}', true, "\u{42}"),
+    ('\u{1d63e}', true, "\u{43}"),
+    ('\u{1d63f}', true, "\u{44}"),
+    ('\u{1d640}', true, "\u{45}"),
+    ('\u{1d641}', true, "\u{46}"),
+    ('\u{1d642}', true, "\u{47}"),
+    ('\u{1d643}', true, "\u{48}"),
+    ('\u{1d644}', true, "\u{49}"),
+    ('\u{1d645}', true, "\u{4a}"),
+    ('\u{1d646}', true, "\u{4b}"),
+    ('\u{1d647}', true, "\u{4c}"),
+    ('\u{1d648}', true, "\u{4d}"),
+    ('\u{1d649}', true, "\u{4e}"),
+    ('\u{1d64a}', true, "\u{4f}"),
+    ('\u{1d64b}', true, "\u{50}"),
+    ('\u{1d64c}', true, "\u{51}"),
+    ('\u{1d64d}', true, "\u{52}"),
+    ('\u{1d64e}', true, "\u{53}"),
+    ('\u{1d64f}', true, "\u{54}"),
+    ('\u{1d650}', true, "\u{55}"),
+    ('\u{1d651}', true, "\u{56}"),
+    ('\u{1d652}', true, "\u{57}"),
+    ('\u{1d653}', true, "\u{58}"),
+    ('\u{1d654}', true, "\u{59}"),
+    ('\u{1d655}', true, "\u{5a}"),
+    ('\u{1d656}', true, "\u{61}"),
+    ('\u{1d657}', true, "\u{62}"),
+    ('\u{1d658}', true, "\u{63}"),
+    ('\u{1d659}', true, "\u{64}"),
+    ('\u{1d65a}', true, "\u{65}"),
+    ('\u{1d65b}', true, "\u{66}"),
+    ('\u{1d65c}', true, "\u{67}"),
+    ('\u{1d65d}', true, "\u{68}"),
+    ('\u{1d65e}', true, "\u{69}"),
+    ('\u{1d65f}', true, "\u{6a}"),
+    ('\u{1d660}', true, "\u{6b}"),
+    ('\u{1d661}', true, "\u{6c}"),
+    ('\u{1d662}', true, "\u{6d}"),
+    ('\u{1d663}', true, "\u{6e}"),
+    ('\u{1d664}', true, "\u{6f}"),
+    ('\u{1d665}', true, "\u{70}"),
+    ('\u{1d666}', true, "\u{71}"),
+    ('\u{1d667}', true, "\u{72}"),
+    ('\u{1d668}', true, "\u{73}"),
+    ('\u{1d669}', true, "\u{74}"),
+    ('\u{1d66a}', true, "\u{75}"),
+    ('\u{1d66b}', true, "\u{76}"),
+    ('\u{1d66c}', true, "\u{77}"),
+    ('\u{1d66d}', true, "\u{78}"),
+    ('\u{1d66e}', true, "\u{79}"),
+    ('\u{1d66f}', true, "\u{7a}"),
+    ('\u{1d670}', true, "\u{41}"),
+    ('\u{1d671}', true, "\u{42}"),
+    ('\u{1d672}', true, "\u{43}"),
+    ('\u{1d673}', true, "\u{44}"),
+    ('\u{1d674}', true, "\u{45}"),
+    ('\u{1d675}', true, "\u{46}"),
+    ('\u{1d676}', true, "\u{47}"),
+    ('\u{1d677}', true, "\u{48}"),
+    ('\u{1d678}', true, "\u{49}"),
+    ('\u{1d679}', true, "\u{4a}"),
+    ('\u{1d67a}', true, "\u{4b}"),
+    ('\u{1d67b}', true, "\u{4c}"),
+    ('\u{1d67c}', true, "\u{4d}"),
+    ('\u{1d67d}', true, "\u{4e}"),
+    ('\u{1d67e}', true, "\u{4f}"),
+    ('\u{1d67f}', true, "\u{50}"),
+    ('\u{1d680}', true, "\u{51}"),
+    ('\u{1d681}', true, "\u{52}"),
+    ('\u{1d682}', true, "\u{53}"),
+    ('\u{1d683}', true, "\u{54}"),
+    ('\u{1d684}', true, "\u{55}"),
+    ('\u{1d685}', true, "\u{56}"),
+    ('\u{1d686}', true, "\u{57}"),
+    ('\u{1d687}', true, "\u{58}"),
+    ('\u{1d688}', true, "\u{59}"),
+    ('\u{1d689}', true, "\u{5a}"),
+    ('\u{1d68a}', true, "\u{61}"),
+    ('\u{1d68b}', true, "\u{62}"),
+    ('\u{1d68c}', true, "\u{63}"),
+    ('\u{1d68d}', true, "\u{64}"),
+    ('\u{1d68e}', true, "\u{65}"),
+    ('\u{1d68f}', true, "\u{66}"),
+    ('\u{1d690}', true, "\u{67}"),
+    ('\u{1d691}', true, "\u{68}"),
+    ('\u{1d692}', true, "\u{69}"),
+    ('\u{1d693}', true, "\u{6a}"),
+    ('\u{1d694}', true, "\u{6b}"),
+    ('\u{1d695}', true, "\u{6c}"),
+    ('\u{1d696}', true, "\u{6d}"),
+    ('\u{1d697}', true, "\u{6e}"),
+    ('\u{1d698}', true, "\u{6f}"),
+    ('\u{1d699}', true, "\u{70}"),
+    ('\u{1d69a}', true, "\u{71}"),
+    ('\u{1d69b}', true, "\u{72}"),
+    ('\u{1d69c}', true, "\u{73}"),
+    ('\u{1d69d}', true, "\u{74}"),
+    ('\u{1d69e}', true, "\u{75}"),
+    ('\u{1d69f}', true, "\u{76}"),
+    ('\u{1d6a0}', true, "\u{77}"),
+    ('\u{1d6a1}', true, "\u{78}"),
+    ('\u{1d6a2}', true, "\u{79}"),
+    ('\u{1d6a3}', true, "\u{7a}"),
+    ('\u{1d6a4}', true, "\u{131}"),
+    ('\u{1d6a5}', true, "\u{237}"),
+    ('\u{1d6a8}', true, "\u{391}"),
+    ('\u{1d6a9}', true, "\u{392}"),
+    ('\u{1d6aa}', true, "\u{393}"),
+    ('\u{1d6ab}', true, "\u{394}"),
+    ('\u{1d6ac}', true, "\u{395}"),
+    ('\u{1d6ad}', true, "\u{396}"),
+    ('\u{1d6ae}', true, "\u{397}"),
+    ('\u{1d6af}', true, "\u{398}"),
+    ('\u{1d6b0}', true, "\u{399}"),
+    ('\u{1d6b1}', true, "\u{39a}"),
+    ('\u{1d6b2}', true, "\u{39b}"),
+    ('\u{1d6b3}', true, "\u{39c}"),
+    ('\u{1d6b4}', true, "\u{39d}"),
+    ('\u{1d6b5}', true, "\u{39e}"),
+    ('\u{1d6b6}', true, "\u{39f}"),
+    ('\u{1d6b7}', true, "\u{3a0}"),
+    ('\u{1d6b8}', true, "\u{3a1}"),
+    ('\u{1d6b9}', true, "\u{3f4}"),
+    ('\u{1d6ba}', true, "\u{3a3}"),
+    ('\u{1d6bb}', true, "\u{3a4}"),
+    ('\u{1d6bc}', true, "\u{3a5}"),
+    ('\u{1d6bd}', true, "\u{3a6}"),
+    ('\u{1d6be}', true, "\u{3a7}"),
+    ('\u{1d6bf}', true, "\u{3a8}"),
+    ('\u{1d6c0}', true, "\u{3a9}"),
+    ('\u{1d6c1}', true, "\u{2207}"),
+    ('\u{1d6c2}', true, "\u{3b1}"),
+    ('\u{1d6c3}', true, "\u{3b2}"),
+    ('\u{1d6c4}', true, "\u{3b3}"),
+    ('\u{1d6c5}', true, "\u{3b4}"),
+    ('\u{1d6c6}', true, "\u{3b5}"),
+    ('\u{1d6c7}', true, "\u{3b6}"),
+    ('\u{1d6c8}', true, "\u{3b7}"),
+    ('\u{1d6c9}', true, "\u{3b8}"),
+    ('\u{1d6ca}', true, "\u{3b9}"),
+    ('\u{1d6cb}', true, "\u{3ba}"),
+    ('\u{1d6cc}', true, "\u{3bb}"),
+    ('\u{1d6cd}', true, "\u{3bc}"),
+    ('\u{1d6ce}', true, "\u{3bd}"),
+    ('\u{1d6cf}', true, "\u{3be}"),
+    ('\u{1d6d0}', true, "\u{3bf}"),
+    ('\u{1d6d1}', true, "\u{3c0}"),
+    ('\u{1d6d2}', true, "\u{3c1}"),
+    ('\u{1d6d3}', true, "\u{3c2}"),
+    ('\u{1d6d4}', true, "\u{3c3}"),
+    ('\u{1d6d5}', true, "\u{3c4}"),
+    ('\u{1d6d6}', true, "\u{3c5}"),
+    ('\u{1d6d7}', true, "\u{3c6}"),
+    ('\u{1d6d8}', true, "\u{3c7}"),
+    ('\u{1d6d9}', true, "\u{3c8}"),
+    ('\u{1d6da}', true, "\u{3c9}"),
+    ('\u{1d6db}', true, "\u{2202}"),
+    ('\u{1d6dc}', true, "\u{3f5}"),
+    ('\u{1d6dd}', true, "\u{3d1}"),
+    ('\u{1d6de}', true, "\u{3f0}"),
+    ('\u{1d6df}', true, "\u{3d5}"),
+    ('\u{1d6e0}', true, "\u{3f1}"),
+    ('\u{1d6e1}', true, "\u{3d6}"),
+    ('\u{1d6e2}', true, "\u{391}"),
+    ('\u{1d6e3}', true, "\u{392}"),
+    ('\u{1d6e4}', true, "\u{393}"),
+    ('\u{1d6e5}', true, "\u{394}"),
+    ('\u{1d6e6}', true, "\u{395}"),
+    ('\u{1d6e7}', true, "\u{396}"),
+    ('\u{1d6e8}', true, "\u{397}"),
+    ('\u{1d6e9}', true, "\u{398}"),
+    ('\u{1d6ea}', true, "\u{399}"),
+    ('\u{1d6eb}', true, "\u{39a}"),
+    ('\u{1d6ec}', true, "\u{39b}"),
+    ('\u{1d6ed}', true, "\u{39c}"),
+    ('\u{1d6ee}', true, "\u{39d}"),
+    ('\u{1d6ef}', true, "\u{39e}"),
+    ('\u{1d6f0}', true, "\u{39f}"),
+    ('\u{1d6f1}', true, "\u{3a0}"),
+    ('\u{1d6f2}', true, "\u{3a1}"),
+    ('\u{1d6f3}', true, "\u{3f4}"),
+    ('\u{1d6f4}', true, "\u{3a3}"),
+    ('\u{1d6f5}', true, "\u{3a4}"),
+    ('\u{1d6f6}', true, "\u{3a5}"),
+    ('\u{1d6f7}', true, "\u{3a6}"),
+    ('\u{1d6f8}', true, "\u{3a7}"),
+    ('\u{1d6f9}', true, "\u{3a8}"),
+    ('\u{1d6fa}', true, "\u{3a9}"),
+    ('\u{1d6fb}', true, "\u{2207}"),
+    ('\u{1d6fc}', true, "\u{3b1}"),
+    ('\u{1d6fd}', true, "\u{3b2}"),
+    ('\u{1d6fe}', true, "\u{3b3}"),
+    ('\u{1d6ff}', true, "\u{3b4}"),
+    ('\u{1d700}', true, "\u{3b5}"),
+    ('\u{1d701}', true, "\u{3b6}"),
+    ('\u{1d702}', true, "\u{3b7}"),
+    ('\u{1d703}', true, "\u{3b8}"),
+    ('\u{1d704}', true, "\u{3b9}"),
+    ('\u{1d705}', true, "\u{3ba}"),
+    ('\u{1d706}', true, "\u{3bb}"),
+    ('\u{1d707}', true, "\u{3bc}"),
+    ('\u{1d708}', true, "\u{3bd}"),
+    ('\u{1d709}', true, "\u{3be}"),
+    ('\u{1d70a}', true, "\u{3bf}"),
+    ('\u{1d70b}', true, "\u{3c0}"),
+    ('\u{1d70c}', true, "\u{3c1}"),
+    ('\u{1d70d}', true, "\u{3c2}"),
+    ('\u{1d70e}', true, "\u{3c3}"),
+    ('\u{1d70f}', true, "\u{3c4}"),
+    ('\u{1d710}', true, "\u{3c5}"),
+    ('\u{1d711}', true, "\u{3c6}"),
+    ('\u{1d712}', true, "\u{3c7}"),
+    ('\u{1d713}', true, "\u{3c8}"),
+    ('\u{1d714}', true, "\u{3c9}"),
+    ('\u{1d715}', true, "\u{2202}"),
+    ('\u{1d716}', true, "\u{3f5}"),
+    ('\u{1d717}', true, "\u{3d1}"),
+    ('\u{1d718}', true, "\u{3f0}"),
+    ('\u{1d719}', true, "\u{3d5}"),
+    ('\u{1d71a}', true, "\u{3f1}"),
+    ('\u{1d71b}', true, "\u{3d6}"),
+    ('\u{1d71c}', true, "\u{391}"),
+    ('\u{1d71d}', true, "\u{392}"),
+    ('\u{1d71e}', true, "\u{393}"),
+    ('\u{1d71f}', true, "\u{394}"),
+    ('\u{1d720}', true, "\u{395}"),
+    ('\u{1d721}', true, "\u{396}"),
+    ('\u{1d722}', true, "\u{397}"),
+    ('\u{1d723}', true, "\u{398}"),
+    ('\u{1d724}', true, "\u{399}"),
+    ('\u{1d725}', true, "\u{39a}"),
+    ('\u{1d726}', true, "\u{39b}"),
+    ('\u{1d727}', true, "\u{39c}"),
+    ('\u{1d728}', true, "\u{39d}"),
+    ('\u{1d729}', true, "\u{39e}"),
+    ('\u{1d72a}', true, "\u{39f}"),
+    ('\u{1d72b}', true, "\u{3a0}"),
+    ('\u{1d72c}', true, "\u{3a1}"),
+    ('\u{1d72d}', true, "\u{3f4}"),
+    ('\u{1d72e}', true, "\u{3a3}"),
+    ('\u{1d72f}', true, "\u{3a4}"),
+    ('\u{1d730}', true, "\u{3a5}"),
+    ('\u{1d731}', true, "\u{3a6}"),
+    ('\u{1d732}', true, "\u{3a7}"),
+    ('\u{1d733}', true, "\u{3a8}"),
+    ('\u{1d734}', true, "\u{3a9}"),
+    ('\u{1d735}', true, "\u{2207}"),
+    ('\u{1d736}', true, "\u{3b1}"),
+    ('\u{1d737}', true, "\u{3b2}"),
+    ('\u{1d738}', true, "\u{3b3}"),
+    ('\u{1d739}', true, "\u{3b4}"),
+    ('\u{1d73a}', true, "\u{3b5}"),
+    ('\u{1d73b}', true, "\u{3b6}"),
+    ('\u{1d73c}', true, "\u{3b7}"),
+    ('\u{1d73d}', true, "\u{3b8}"),
+    ('\u{1d73e}', true, "\u{3b9}"),
+    ('\u{1d73f}', true, "\u{3ba}"),
+    ('\u{1d740}', true, "\u{3bb}"),
+    ('\u{1d741}', true, "\u{3bc}"),
+    ('\u{1d742}', true, "\u{3bd}"),
+    ('\u{1d743}', true, "\u{3be}"),
+    ('\u{1d744}', true, "\u{3bf}"),
+    ('\u{1d745}', true, "\u{3c0}"),
+    ('\u{1d746}', true, "\u{3c1}"),
+    ('\u{1d747}', true, "\u{3c2}"),
+    ('\u{1d748}', true, "\u{3c3}"),
+    ('\u{1d749}', true, "\u{3c4}"),
+    ('\u{1d74a}', true, "\u{3c5}"),
+    ('\u{1d74b}', true, "\u{3c6}"),
+    ('\u{1d74c}', true, "\u{3c7}"),
+    ('\u{1d74d}', true, "\u{3c8}"),
+    ('\u{1d74e}', true, "\u{3c9}"),
+    ('\u{1d74f}', true, "\u{2202}"),
+    ('\u{1d750}', true, "\u{3f5}"),
+    ('\u{1d751}', true, "\u{3d1}"),
+    ('\u{1d752}', true, "\u{3f0}"),
+    ('\u{1d753}', true, "\u{3d5}"),
+    ('\u{1d754}', true, "\u{3f1}"),
+    ('\u{1d755}', true, "\u{3d6}"),
+    ('\u{1d756}', true, "\u{391}"),
+    ('\u{1d757}', true, "\u{392}"),
+    ('\u{1d758}', true, "\u{393}"),
+    ('\u{1d759}', true, "\u{394}"),
+    ('\u{1d75a}', true, "\u{395}"),
+    ('\u{1d75b}', true, "\u{396}"),
+    ('\u{1d75c}', true, "\u{397}"),
+    ('\u{1d75d}', true, "\u{398}"),
+    ('\u{1d75e}', true, "\u{399}"),
+    ('\u{1d75f}', true, "\u{39a}"),
+    ('\u{1d760}', true, "\u{39b}"),
+    ('\u{1d761}', true, "\u{39c}"),
+    ('\u{1d762}', true, "\u{39d}"),
+    ('\u{1d763}', true, "\u{39e}"),
+    ('\u{1d764}', true, "\u{39f}"),
+    ('\u{1d765}', true, "\u{3a0}"),
+    ('\u{1d766}', true, "\u{3a1}"),
+    ('\u{1d767}', true, "\u{3f4}"),
+    ('\u{1d768}', true, "\u{3a3}"),
+    ('\u{1d769}', true, "\u{3a4}"),
+    ('\u{1d76a}', true, "\u{3a5}"),
+    ('\u{1d76b}', true, "\u{3a6}"),
+    ('\u{1d76c}', true, "\u{3a7}"),
+    ('\u{1d76d}', true, "\u{3a8}"),
+    ('\u{1d76e}', true, "\u{3a9}"),
+    ('\u{1d76f}', true, "\u{2207}"),
+    ('\u{1d770}', true, "\u{3b1}"),
+    ('\u{1d771}', true, "\u{3b2}"),
+    ('\u{1d772}', true, "\u{3b3}"),
+    ('\u{1d773}', true, "\u{3b4}"),
+    ('\u{1d774}', true, "\u{3b5}"),
+    ('\u{1d775}', true, "\u{3b6}"),
+    ('\u{1d776}', true, "\u{3b7}"),
+    ('\u{1d777}', true, "\u{3b8}"),
+    ('\u{1d778}', true, "\u{3b9}"),
+    ('\u{1d779}', true, "\u{3ba}"),
+    ('\u{1d77a}', true, "\u{3bb}"),
+    ('\u{1d77b}', true, "\u{3bc}"),
+    ('\u{1d77c}', true, "\u{3bd}"),
+    ('\u{1d77d}', true, "\u{3be}"),
+    ('\u{1d77e}', true, "\u{3bf}"),
+    ('\u{1d77f}', true, "\u{3c0}"),
+    ('\u{1d780}', true, "\u{3c1}"),
+    ('\u{1d781}', true, "\u{3c2}"),
+    ('\u{1d782}', true, "\u{3c3}"),
+    ('\u{1d783}', true, "\u{3c4}"),
+    ('\u{1d784}', true, "\u{3c5}"),
+    ('\u{1d785}', true, "\u{3c6}"),
+    ('\u{1d786}', true, "\u{3c7}"),
+    ('\u{1d787}', true, "\u{3c8}"),
+    ('\u{1d788}', true, "\u{3c9}"),
+    ('\u{1d789}', true, "\u{2202}"),
+    ('\u{1d78a}', true, "\u{3f5}"),
+    ('\u{1d78b}', true, "\u{3d1}"),
+    ('\u{1d78c}', true, "\u{3f0}"),
+    ('\u{1d78d}', true, "\u{3d5}"),
+    ('\u{1d78e}', true, "\u{3f1}"),
+    ('\u{1d78f}', true, "\u{3d6}"),
+    ('\u{1d790}', true, "\u{391}"),
+    ('\u{1d791}', true, "\u{392}"),
+    ('\u{1d792}', true, "\u{393}"),
+    ('\u{1d793}', true, "\u{394}"),
+    ('\u{1d794}', true, "\u{395}"),
+    ('\u{1d795}', true, "\u{396}"),
+    ('\u{1d796}', true, "\u{397}"),
+    ('\u{1d797}', true, "\u{398}"),
+    ('\u{1d798}', true, "\u{399}"),
+    ('\u{1d799}', true, "\u{39a}"),
+    ('\u{1d79a}', true, "\u{39b}"),
+    ('\u{1d79b}', true, "\u{39c}"),
+    ('\u{1d79c}', true, "\u{39d}"),
+    ('\u{1d79d}', true, "\u{39e}"),
+    ('\u{1d79e}', true, "\u{39f}"),
+    ('\u{1d79f}', true, "\u{3a0}"),
+    ('\u{1d7a0}', true, "\u{3a1}"),
+    ('\u{1d7a1}', true, "\u{3f4}"),
+    ('\u{1d7a2}', true, "\u{3a3}"),
+    ('\u{1d7a3}', true, "\u{3a4}"),
+    ('\u{1d7a4}', true, "\u{3a5}"),
+    ('\u{1d7a5}', true, "\u{3a6}"),
+    ('\u{1d7a6}', true, "\u{3a7}"),
+    ('\u{1d7a7}', true, "\u{3a8}"),
+    ('\u{1d7a8}', true, "\u{3a9}"),
+    ('\u{1d7a9}', true, "\u{2207}"),
+    ('\u{1d7aa}', true, "\u{3b1}"),
+    ('\u{1d7ab}', true, "\u{3b2}"),
+    ('\u{1d7ac}', true, "\u{3b3}"),
+    ('\u{1d7ad}', true, "\u{3b4}"),
+    ('\u{1d7ae}', true, "\u{3b5}"),
+    ('\u{1d7af}', true, "\u{3b6}"),
+    ('\u{1d7b0}', true, "\u{3b7}"),
+    ('\u{1d7b1}', true, "\u{3b8}"),
+    ('\u{1d7b2}', true, "\u{3b9}"),
+    ('\u{1d7b3}', true, "\u{3ba}"),
+    ('\u{1d7b4}', true, "\u{3bb}"),
+    ('\u{1d7b5}', true, "\u{3bc}"),
+    ('\u{1d7b6}', true, "\u{3bd}"),
+    ('\u{1d7b7}', true, "\u{3be}"),
+    ('\u{1d7b8}', true, "\u{3bf}"),
+    ('\u{1d7b9}', true, "\u{3c0}"),
+    ('\u{1d7ba}', true, "\u{3c1}"),
+    ('\u{1d7bb}', true, "\u{3c2}"),
+    ('\u{1d7bc}', true, "\u{3c3}"),
+    ('\u{1d7bd}', true, "\u{3c4}"),
+    ('\u{1d7be}', true, "\u{3c5}"),
+    ('\u{1d7bf}', true, "\u{3c6}"),
+    ('\u{1d7c0}', true, "\u{3c7}"),
+    ('\u{1d7c1}', true, "\u{3c8}"),
+    ('\u{1d7c2}', true, "\u{3c9}"),
+    ('\u{1d7c3}', true, "\u{2202}"),
+    ('\u{1d7c4}', true, "\u{3f5}"),
+    ('\u{1d7c5}', true, "\u{3d1}"),
+    ('\u{1d7c6}', true, "\u{3f0}"),
+    ('\u{1d7c7}', true, "\u{3d5}"),
+    ('\u{1d7c8}', true, "\u{3f1}"),
+    ('\u{1d7c9}', true, "\u{3d6}"),
+    ('\u{1d7ca}', true, "\u{3dc}"),
+    ('\u{1d7cb}', true, "\u{3dd}"),
+    ('\u{1d7ce}', true, "\u{30}"),
+    ('\u{1d7cf}', true, "\u{31}"),
+    ('\u{1d7d0}', true, "\u{32}"),
+    ('\u{1d7d1}', true, "\u{33}"),
+    ('\u{1d7d2}', true, "\u{34}"),
+    ('\u{1d7d3}', true, "\u{35}"),
+    ('\u{1d7d4}', true, "\u{36}"),
+    ('\u{1d7d5}', true, "\u{37}"),
+    ('\u{1d7d6}', true, "\u{38}"),
+    ('\u{1d7d7}', true, "\u{39}"),
+    ('\u{1d7d8}', true, "\u{30}"),
+    ('\u{1d7d9}', true, "\u{31}"),
+    ('\u{1d7da}', true, "\u{32}"),
+    ('\u{1d7db}', true, "\u{33}"),
+    ('\u{1d7dc}', true, "\u{34}"),
+    ('\u{1d7dd}', true, "\u{35}"),
+    ('\u{1d7de}', true, "\u{36}"),
+    ('\u{1d7df}', true, "\u{37}"),
+    ('\u{1d7e0}', true, "\u{38}"),
+    ('\u{1d7e1}', true, "\u{39}"),
+    ('\u{1d7e2}', true, "\u{30}"),
+    ('\u{1d7e3}', true, "\u{31}"),
+    ('\u{1d7e4}', true, "\u{32}"),
+    ('\u{1d7e5}', true, "\u{33}"),
+    ('\u{1d7e6}', true, "\u{34}"),
+    ('\u{1d7e7}', true, "\u{35}"),
+    ('\u{1d7e8}', true, "\u{36}"),
+    ('\u{1d7e9}', true, "\u{37}"),
+    ('\u{1d7ea}', true, "\u{38}"),
+    ('\u{1d7eb}', true, "\u{39}"),
+    ('\u{1d7ec}', true, "\u{30}"),
+    ('\u{1d7ed}', true, "\u{31}"),
+    ('\u{1d7ee}', true, "\u{32}"),
+    ('\u{1d7ef}', true, "\u{33}"),
+    ('\u{1d7f0}', true, "\u{34}"),
+    ('\u{1d7f1}', true, "\u{35}"),
+    ('\u{1d7f2}', true, "\u{36}"),
+    ('\u{1d7f3}', true, "\u{37}"),
+    ('\u{1d7f4}', true, "\u{38}"),
+    ('\u{1d7f5}', true, "\u{39}"),
+    ('\u{1d7f6}', true, "\u{30}"),
+    ('\u{1d7f7}', true, "\u{31}"),
+    ('\u{1d7f8}', true, "\u{32}"),
+    ('\u{1d7f9}', true, "\u{33}"),
+    ('\u{1d7fa}', true, "\u{34}"),
+    ('\u{1d7fb}', true, "\u{35}"),
+    ('\u{1d7fc}', true, "\u{36}"),
+    ('\u{1d7fd}', true, "\u{37}"),
+    ('\u{1d7fe}', true, "\u{38}"),
+    ('\u{1d7ff}', true, "\u{39}"),
+    ('\u{1ee00}', true, "\u{627}"),
+    ('\u{1ee01}', true, "\u{628}"),
+    ('\u{1ee02}', true, "\u{62c}"),
+    ('\u{1ee03}', true, "\u{62f}"),
+    ('\u{1ee05}', true, "\u{648}"),
+    ('\u{1ee06}', true, "\u{632}"),
+    ('\u{1ee07}', true, "\u{62d}"),
+    ('\u{1ee08}', true, "\u{637}"),
+    ('\u{1ee09}', true, "\u{64a}"),
+    ('\u{1ee0a}', true, "\u{643}"),
+    ('\u{1ee0b}', true, "\u{644}"),
+    ('\u{1ee0c}', true, "\u{645}"),
+    ('\u{1ee0d}', true, "\u{646}"),
+    ('\u{1ee0e}', true, "\u{633}"),
+    ('\u{1ee0f}', true, "\u{639}"),
+    ('\u{1ee10}', true, "\u{641}"),
+    ('\u{1ee11}', true, "\u{635}"),
+    ('\u{1ee12}', true, "\u{642}"),
+    ('\u{1ee13}', true, "\u{631}"),
+    ('\u{1ee14}', true, "\u{634}"),
+    ('\u{1ee15}', true, "\u{62a}"),
+    ('\u{1ee16}', true, "\u{62b}"),
+    ('\u{1ee17}', true, "\u{62e}"),
+    ('\u{1ee18}', true, "\u{630}"),
+    ('\u{1ee19}', true, "\u{636}"),
+    ('\u{1ee1a}', true, "\u{638}"),
+    ('\u{1ee1b}', true, "\u{63a}"),
+    ('\u{1ee1c}', true, "\u{66e}"),
+    ('\u{1ee1d}', true, "\u{6ba}"),
+    ('\u{1ee1e}', true, "\u{6a1}"),
+    ('\u{1ee1f}', true, "\u{66f}"),
+    ('\u{1ee21}', true, "\u{628}"),
+    ('\u{1ee22}', true, "\u{62c}"),
+    ('\u{1ee24}', true, "\u{647}"),
+    ('\u{1ee27}', true, "\u{62d}"),
+    ('\u{1ee29}', true, "\u{64a}"),
+    ('\u{1ee2a}', true, "\u{643}"),
+    ('\u{1ee2b}', true, "\u{644}"),
+    ('\u{1ee2c}', true, "\u{645}"),
+    ('\u{1ee2d}', true, "\u{646}"),
+    ('\u{1ee2e}', true, "\u{633}"),
+    ('\u{1ee2f}', true, "\u{639}"),
+    ('\u{1ee30}', true, "\u{641}"),
+    ('\u{1ee31}', true, "\u{635}"),
+    ('\u{1ee32}', true, "\u{642}"),
+    ('\u{1ee34}', true, "\u{634}"),
+    ('\u{1ee35}', true, "\u{62a}"),
+    ('\u{1ee36}', true, "\u{62b}"),
+    ('\u{1ee37}', true, "\u{62e}"),
+    ('\u{1ee39}', true, "\u{636}"),
+    ('\u{1ee3b}', true, "\u{63a}"),
+    ('\u{1ee42}', true, "\u{62c}"),
+    ('\u{1ee47}', true, "\u{62d}"),
+    ('\u{1ee49}', true, "\u{64a}"),
+    ('\u{1ee4b}', true, "\u{644}"),
+    ('\u{1ee4d}', true, "\u{646}"),
+    ('\u{1ee4e}', true, "\u{633}"),
+    ('\u{1ee4f}', true, "\u{639}"),
+    ('\u{1ee51}', true, "\u{635}"),
+    ('\u{1ee52}', true, "\u{642}"),
+    ('\u{1ee54}', true, "\u{634}"),
+    ('\u{1ee57}', true, "\u{62e}"),
+    ('\u{1ee59}', true, "\u{636}"),
+    ('\u{1ee5b}', true, "\u{63a}"),
+    ('\u{1ee5d}', true, "\u{6ba}"),
+    ('\u{1ee5f}', true, "\u{66f}"),
+    ('\u{1ee61}', true, "\u{628}"),
+    ('\u{1ee62}', true, "\u{62c}"),
+    ('\u{1ee64}', true, "\u{647}"),
+    ('\u{1ee67}', true, "\u{62d}"),
+    ('\u{1ee68}', true, "\u{637}"),
+    ('\u{1ee69}', true, "\u{64a}"),
+    ('\u{1ee6a}', true, "\u{643}"),
+    ('\u{1ee6c}', true, "\u{645}"),
+    ('\u{1ee6d}', true, "\u{646}"),
+    ('\u{1ee6e}', true, "\u{633}"),
+    ('\u{1ee6f}', true, "\u{639}"),
+    ('\u{1ee70}', true, "\u{641}"),
+    ('\u{1ee71}', true, "\u{635}"),
+    ('\u{1ee72}', true, "\u{642}"),
+    ('\u{1ee74}', true, "\u{634}"),
+    ('\u{1ee75}', true, "\u{62a}"),
+    ('\u{1ee76}', true, "\u{62b}"),
+    ('\u{1ee77}', true, "\u{62e}"),
+    ('\u{1ee79}', true, "\u{636}"),
+    ('\u{1ee7a}', true, "\u{638}"),
+    ('\u{1ee7b}', true, "\u{63a}"),
+    ('\u{1ee7c}', true, "\u{66e}"),
+    ('\u{1ee7e}', true, "\u{6a1}"),
+    ('\u{1ee80}', true, "\u{627}"),
+    ('\u{1ee81}', true, "\u{628}"),
+    ('\u{1ee82}', true, "\u{62c}"),
+    ('\u{1ee83}', true, "\u{62f}"),
+    ('\u{1ee84}', true, "\u{647}"),
+    ('\u{1ee85}', true, "\u{648}"),
+    ('\u{1ee86}', true, "\u{632}"),
+    ('\u{1ee87}', true, "\u{62d}"),
+    ('\u{1ee88}', true, "\u{637}"),
+    ('\u{1ee89}', true, "\u{64a}"),
+    ('\u{1ee8b}', true, "\u{644}"),
+    ('\u{1ee8c}', true, "\u{645}"),
+    ('\u{1ee8d}', true, "\u{646}"),
+    ('\u{1ee8e}', true, "\u{633}"),
+    ('\u{1ee8f}', true, "\u{639}"),
+    ('\u{1ee90}', true, "\u{641}"),
+    ('\u{1ee91}', true, "\u{635}"),
+    ('\u{1ee92}', true, "\u{642}"),
+    ('\u{1ee93}', true, "\u{631}"),
+    ('\u{1ee94}', true, "\u{634}"),
+    ('\u{1ee95}', true, "\u{62a}"),
+    ('\u{1ee96}', true, "\u{62b}"),
+    ('\u{1ee97}', true, "\u{62e}"),
+    ('\u{1ee98}', true, "\u{630}"),
+    ('\u{1ee99}', true, "\u{636}"),
+    ('\u{1ee9a}', true, "\u{638}"),
+    ('\u{1ee9b}', true, "\u{63a}"),
+    ('\u{1eea1}', true, "\u{628}"),
+    ('\u{1eea2}', true, "\u{62c}"),
+    ('\u{1eea3}', true, "\u{62f}"),
+    ('\u{1eea5}', true, "\u{648}"),
+    ('\u{1eea6}', true, "\u{632}"),
+    ('\u{1eea7}', true, "\u{62d}"),
+    ('\u{1eea8}', true, "\u{637}"),
+    ('\u{1eea9}', true, "\u{64a}"),
+    ('\u{1eeab}', true, "\u{644}"),
+    ('\u{1eeac}', true, "\u{645}"),
+    ('\u{1eead}', true, "\u{646}"),
+    ('\u{1eeae}', true, "\u{633}"),
+    ('\u{1eeaf}', true, "\u{639}"),
+    ('\u{1eeb0}', true, "\u{641}"),
+    ('\u{1eeb1}', true, "\u{635}"),
+    ('\u{1eeb2}', true, "\u{642}"),
+    ('\u{1eeb3}', true, "\u{631}"),
+    ('\u{1eeb4}', true, "\u{634}"),
+    ('\u{1eeb5}', true, "\u{62a}"),
+    ('\u{1eeb6}', true, "\u{62b}"),
+    ('\u{1eeb7}', true, "\u{62e}"),
+    ('\u{1eeb8}', true, "\u{630}"),
+    ('\u{1eeb9}', true, "\u{636}"),
+    ('\u{1eeba}', true, "\u{638}"),
+    ('\u{1eebb}', true, "\u{63a}"),
+    ('\u{1f100}', true, "\u{30}\u{2e}"),
+    ('\u{1f101}', true, "\u{30}\u{2c}"),
+    ('\u{1f102}', true, "\u{31}\u{2c}"),
+    ('\u{1f103}', true, "\u{32}\u{2c}"),
+    ('\u{1f104}', true, "\u{33}\u{2c}"),
+    ('\u{1f105}', true, "\u{34}\u{2c}"),
+    ('\u{1f106}', true, "\u{35}\u{2c}"),
+    ('\u{1f107}', true, "\u{36}\u{2c}"),
+    ('\u{1f108}', true, "\u{37}\u{2c}"),
+    ('\u{1f109}', true, "\u{38}\u{2c}"),
+    ('\u{1f10a}', true, "\u{39}\u{2c}"),
+    ('\u{1f110}', true, "\u{28}\u{41}\u{29}"),
+    ('\u{1f111}', true, "\u{28}\u{42}\u{29}"),
+    ('\u{1f112}', true, "\u{28}\u{43}\u{29}"),
+    ('\u{1f113}', true, "\u{28}\u{44}\u{29}"),
+    ('\u{1f114}', true, "\u{28}\u{45}\u{29}"),
+    ('\u{1f115}', true, "\u{28}\u{46}\u{29}"),
+    ('\u{1f116}', true, "\u{28}\u{47}\u{29}"),
+    ('\u{1f117}', true, "\u{28}\u{48}\u{29}"),
+    ('\u{1f118}', true, "\u{28}\u{49}\u{29}"),
+    ('\u{1f119}', true, "\u{28}\u{4a}\u{29}"),
+    ('\u{1f11a}', true, "\u{28}\u{4b}\u{29}"),
+    ('\u{1f11b}', true, "\u{28}\u{4c}\u{29}"),
+    ('\u{1f11c}', true, "\u{28}\u{4d}\u{29}"),
+    ('\u{1f11d}', true, "\u{28}\u{4e}\u{29}"),
+    ('\u{1f11e}', true, "\u{28}\u{4f}\u{29}"),
+    ('\u{1f11f}', true, "\u{28}\u{50}\u{29}"),
+    ('\u{1f120}', true, "\u{28}\u{51}\u{29}"),
+    ('\u{1f121}', true, "\u{28}\u{52}\u{29}"),
+    ('\u{1f122}', true, "\u{28}\u{53}\u{29}"),
+    ('\u{1f123}', true, "\u{28}\u{54}\u{29}"),
+    ('\u{1f124}', true, "\u{28}\u{55}\u{29}"),
+    ('\u{1f125}', true, "\u{28}\u{56}\u{29}"),
+    ('\u{1f126}', true, "\u{28}\u{57}\u{29}"),
+    ('\u{1f127}', true, "\u{28}\u{58}\u{29}"),
+    ('\u{1f128}', true, "\u{28}\u{59}\u{29}"),
+    ('\u{1f129}', true, "\u{28}\u{5a}\u{29}"),
+    ('\u{1f12a}', true, "\u{3014}\u{53}\u{3015}"),
+    ('\u{1f12b}', true, "\u{43}"),
+    ('\u{1f12c}', true, "\u{52}"),
+    ('\u{1f12d}', true, "\u{43}\u{44}"),
+    ('\u{1f12e}', true, "\u{57}\u{5a}"),
+    ('\u{1f130}', true, "\u{41}"),
+    ('\u{1f131}', true, "\u{42}"),
+    ('\u{1f132}', true, "\u{43}"),
+    ('\u{1f133}', true, "\u{44}"),
+    ('\u{1f134}', true, "\u{45}"),
+    ('\u{1f135}', true, "\u{46}"),
+    ('\u{1f136}', true, "\u{47}"),
+    ('\u{1f137}', true, "\u{48}"),
+    ('\u{1f138}', true, "\u{49}"),
+    ('\u{1f139}', true, "\u{4a}"),
+    ('\u{1f13a}', true, "\u{4b}"),
+    ('\u{1f13b}', true, "\u{4c}"),
+    ('\u{1f13c}', true, "\u{4d}"),
+    ('\u{1f13d}', true, "\u{4e}"),
+    ('\u{1f13e}', true, "\u{4f}"),
+    ('\u{1f13f}', true, "\u{50}"),
+    ('\u{1f140}', true, "\u{51}"),
+    ('\u{1f141}', true, "\u{52}"),
+    ('\u{1f142}', true, "\u{53}"),
+    ('\u{1f143}', true, "\u{54}"),
+    ('\u{1f144}', true, "\u{55}"),
+    ('\u{1f145}', true, "\u{56}"),
+    ('\u{1f146}', true, "\u{57}"),
+    ('\u{1f147}', true, "\u{58}"),
+    ('\u{1f148}', true, "\u{59}"),
+    ('\u{1f149}', true, "\u{5a}"),
+    ('\u{1f14a}', true, "\u{48}\u{56}"),
+    ('\u{1f14b}', true, "\u{4d}\u{56}"),
+    ('\u{1f14c}', true, "\u{53}\u{44}"),
+    ('\u{1f14d}', true, "\u{53}\u{53}"),
+    ('\u{1f14e}', true, "\u{50}\u{50}\u{56}"),
+    ('\u{1f14f}', true, "\u{57}\u{43}"),
+    ('\u{1f16a}', true, "\u{4d}\u{43}"),
+    ('\u{1f16b}', true, "\u{4d}\u{44}"),
+    ('\u{1f16c}', true, "\u{4d}\u{52}"),
+    ('\u{1f190}', true, "\u{44}\u{4a}"),
+    ('\u{1f200}', true, "\u{307b}\u{304b}"),
+    ('\u{1f201}', true, "\u{30b3}\u{30b3}"),
+    ('\u{1f202}', true, "\u{30b5}"),
+    ('\u{1f210}', true, "\u{624b}"),
+    ('\u{1f211}', true, "\u{5b57}"),
+    ('\u{1f212}', true, "\u{53cc}"),
+    ('\u{1f213}', true, "\u{30c7}"),
+    ('\u{1f214}', true, "\u{4e8c}"),
+    ('\u{1f215}', true, "\u{591a}"),
+    ('\u{1f216}', true, "\u{89e3}"),
+    ('\u{1f217}', true, "\u{5929}"),
+    ('\u{1f218}', true, "\u{4ea4}"),
+    ('\u{1f219}', true, "\u{6620}"),
+    ('\u{1f21a}', true, "\u{7121}"),
+    ('\u{1f21b}', true, "\u{6599}"),
+    ('\u{1f21c}', true, "\u{524d}"),
+    ('\u{1f21d}', true, "\u{5f8c}"),
+    ('\u{1f21e}', true, "\u{518d}"),
+    ('\u{1f21f}', true, "\u{65b0}"),
+    ('\u{1f220}', true, "\u{521d}"),
+    ('\u{1f221}', true, "\u{7d42}"),
+    ('\u{1f222}', true, "\u{751f}"),
+    ('\u{1f223}', true, "\u{8ca9}"),
+    ('\u{1f224}', true, "\u{58f0}"),
+    ('\u{1f225}', true, "\u{5439}"),
+    ('\u{1f226}', true, "\u{6f14}"),
+    ('\u{1f227}', true, "\u{6295}"),
+    ('\u{1f228}', true, "\u{6355}"),
+    ('\u{1f229}', true, "\u{4e00}"),
+    ('\u{1f22a}', true, "\u{4e09}"),
+    ('\u{1f22b}', true, "\u{904a}"),
+    ('\u{1f22c}', true, "\u{5de6}"),
+    ('\u{1f22d}', true, "\u{4e2d}"),
+    ('\u{1f22e}', true, "\u{53f3}"),
+    ('\u{1f22f}', true, "\u{6307}"),
+    ('\u{1f230}', true, "\u{8d70}"),
+    ('\u{1f231}', true, "\u{6253}"),
+    ('\u{1f232}', true, "\u{7981}"),
+    ('\u{1f233}', true, "\u{7a7a}"),
+    ('\u{1f234}', true, "\u{5408}"),
+    ('\u{1f235}', true, "\u{6e80}"),
+    ('\u{1f236}', true, "\u{6709}"),
+    ('\u{1f237}', true, "\u{6708}"),
+    ('\u{1f238}', true, "\u{7533}"),
+    ('\u{1f239}', true, "\u{5272}"),
+    ('\u{1f23a}', true, "\u{55b6}"),
+    ('\u{1f23b}', true, "\u{914d}"),
+    ('\u{1f240}', true, "\u{3014}\u{672c}\u{3015}"),
+    ('\u{1f241}', true, "\u{3014}\u{4e09}\u{3015}"),
+    ('\u{1f242}', true, "\u{3014}\u{4e8c}\u{3015}"),
+    ('\u{1f243}', true, "\u{3014}\u{5b89}\u{3015}"),
+    ('\u{1f244}', true, "\u{3014}\u{70b9}\u{3015}"),
+    ('\u{1f245}', true, "\u{3014}\u{6253}\u{3015}"),
+    ('\u{1f246}', true, "\u{3014}\u{76d7}\u{3015}"),
+    ('\u{1f247}', true, "\u{3014}\u{52dd}\u{3015}"),
+    ('\u{1f248}', true, "\u{3014}\u{6557}\u{3015}"),
+    ('\u{1f250}', true, "\u{5f97}"),
+    ('\u{1f251}', true, "\u{53ef}"),
+    ('\u{1fbf0}', true, "\u{30}"),
+    ('\u{1fbf1}', true, "\u{31}"),
+    ('\u{1fbf2}', true, "\u{32}"),
+    ('\u{1fbf3}', true, "\u{33}"),
+    ('\u{1fbf4}', true, "\u{34}"),
+    ('\u{1fbf5}', true, "\u{35}"),
+    ('\u{1fbf6}', true, "\u{36}"),
+    ('\u{1fbf7}', true, "\u{37}"),
+    ('\u{1fbf8}', true, "\u{38}"),
+    ('\u{1fbf9}', true, "\u{39}"),
+    ('\u{2f800}', false, "\u{4e3d}"),
+    ('\u{2f801}', false, "\u{4e38}"),
+    ('\u{2f802}', false, "\u{4e41}"),
+    ('\u{2f803}', false, "\u{20122}"),
+    ('\u{2f804}', false, "\u{4f60}"),
+    ('\u{2f805}', false, "\u{4fae}"),
+    ('\u{2f806}', false, "\u{4fbb}"),
+    ('\u{2f807}', false, "\u{5002}"),
+    ('\u{2f808}', false, "\u{507a}"),
+    ('\u{2f809}', false, "\u{5099}"),
+    ('\u{2f80a}', false, "\u{50e7}"),
+    ('\u{2f80b}', false, "\u{50cf}"),
+    ('\u{2f80c}', false, "\u{349e}"),
+    ('\u{2f80d}', false, "\u{2063a}"),
+    ('\u{2f80e}', false, "\u{514d}"),
+    ('\u{2f80f}', false, "\u{5154}"),
+    ('\u{2f810}', false, "\u{5164}"),
+    ('\u{2f811}', false, "\u{5177}"),
+    ('\u{2f812}', false, "\u{2051c}"),
+    ('\u{2f813}', false, "\u{34b9}"),
+    ('\u{2f814}', false, "\u{5167}"),
+    ('\u{2f815}', false, "\u{518d}"),
+    ('\u{2f816}', false, "\u{2054b}"),
+    ('\u{2f817}', false, "\u{5197}"),
+    ('\u{2f818}', false, "\u{51a4}"),
+    ('\u{2f819}', false, "\u{4ecc}"),
+    ('\u{2f81a}', false, "\u{51ac}"),
+    ('\u{2f81b}', false, "\u{51b5}"),
+    ('\u{2f81c}', false, "\u{291df}"),
+    ('\u{2f81d}', false, "\u{51f5}"),
+    ('\u{2f81e}', false, "\u{5203}"),
+    ('\u{2f81f}', false, "\u{34df}"),
+    ('\u{2f820}', false, "\u{523b}"),
+    ('\u{2f821}', false, "\u{5246}"),
+    ('\u{2f822}', false, "\u{5272}"),
+    ('\u{2f823}', false, "\u{5277}"),
+    ('\u{2f824}', false, "\u{3515}"),
+    ('\u{2f825}', false, "\u{52c7}"),
+    ('\u{2f826}', false, "\u{52c9}"),
+    ('\u{2f827}', false, "\u{52e4}"),
+    ('\u{2f828}', false, "\u{52fa}"),
+    ('\u{2f829}', false, "\u{5305}"),
+    ('\u{2f82a}', false, "\u{5306}"),
+    ('\u{2f82b}', false, "\u{5317}"),
+    ('\u{2f82c}', false, "\u{5349}"),
+    ('\u{2f82d}', false, "\u{5351}"),
+    ('\u{2f82e}', false, "\u{535a}"),
+    ('\u{2f82f}', false, "\u{5373}"),
+    ('\u{2f830}', false, "\u{537d}"),
+    ('\u{2f831}', false, "\u{537f}"),
+    ('\u{2f832}', false, "\u{537f}"),
+    ('\u{2f833}', false, "\u{537f}"),
+    ('\u{2f834}', false, "\u{20a2c}"),
+    ('\u{2f835}', false, "\u{7070}"),
+    ('\u{2f836}', false, "\u{53ca}"),
+    ('\u{2f837}', false, "\u{53df}"),
+    ('\u{2f838}', false, "\u{20b63}"),
+    ('\u{2f839}', false, "\u{53eb}"),
+    ('\u{2f83a}', false, "\u{53f1}"),
+    ('\u{2f83b}', false, "\u{5406}"),
+    ('\u{2f83c}', false, "\u{549e}"),
+    ('\u{2f83d}', false, "\u{5438}"),
+    ('\u{2f83e}', false, "\u{5448}"),
+    ('\u{2f83f}', false, "\u{5468}"),
+    ('\u{2f840}', false, "\u{54a2}"),
+    ('\u{2f841}', false, "\u{54f6}"),
+    ('\u{2f842}', false, "\u{5510}"),
+    ('\u{2f843}', false, "\u{5553}"),
+    ('\u{2f844}', false, "\u{5563}"),
+    ('\u{2f845}', false, "\u{5584}"),
+    ('\u{2f846}', false, "\u{5584}"),
+    ('\u{2f847}', false, "\u{5599}"),
+    ('\u{2f848}', false, "\u{55ab}"),
+    ('\u{2f849}', false, "\u{55b3}"),
+    ('\u{2f84a}', false, "\u{55c2}"),
+    ('\u{2f84b}', false, "\u{5716}"),
+    ('\u{2f84c}', false, "\u{5606}"),
+    ('\u{2f84d}', false, "\u{5717}"),
+    ('\u{2f84e}', false, "\u{5651}"),
+    ('\u{2f84f}', false, "\u{5674}"),
+    ('\u{2f850}', false, "\u{5207}"),
+    ('\u{2f851}', false, "\u{58ee}"),
+    ('\u{2f852}', false, "\u{57ce}"),
+    ('\u{2f853}', false, "\u{57f4}"),
+    ('\u{2f854}', false, "\u{580d}"),
+    ('\u{2f855}', false, "\u{578b}"),
+    ('\u{2f856}', false, "\u{5832}"),
+    ('\u{2f857}', false, "\u{5831}"),
+    ('\u{2f858}', false, "\u{58ac}"),
+    ('\u{2f859}', false, "\u{214e4}"),
+    ('\u{2f85a}', false, "\u{58f2}"),
+    ('\u{2f85b}', false, "\u{58f7}"),
+    ('\u{2f85c}', false, "\u{5906}"),
+    ('\u{2f85d}', false, "\u{591a}"),
+    ('\u{2f85e}', false, "\u{5922}"),
+    ('\u{2f85f}', false, "\u{5962}"),
+    ('\u{2f860}', false, "\u{216a8}"),
+    ('\u{2f861}', false, "\u{216ea}"),
+    ('\u{2f862}', false, "\u{59ec}"),
+    ('\u{2f863}', false, "\u{5a1b}"),
+    ('\u{2f864}', false, "\u{5a27}"),
+    ('\u{2f865}', false, "\u{59d8}"),
+    ('\u{2f866}', false, "\u{5a66}"),
+    ('\u{2f867}', false, "\u{36ee}"),
+    ('\u{2f868}', false, "\u{36fc}"),
+    ('\u{2f869}', false, "\u{5b08}"),
+    ('\u{2f86a}', false, "\u{5b3e}"),
+    ('\u{2f86b}', false, "\u{5b3e}"),
+    ('\u{2f86c}', false, "\u{219c8}"),
+    ('\u{2f86d}', false, "\u{5bc3}"),
+    ('\u{2f86e}', false, "\u{5bd8}"),
+    ('\u{2f86f}', false, "\u{5be7}"),
+    ('\u{2f870}', false, "\u{5bf3}"),
+    ('\u{2f871}', false, "\u{21b18}"),
+    ('\u{2f872}', false, "\u{5bff}"),
+    ('\u{2f873}', false, "\u{5c06}"),
+    ('\u{2f874}', false, "\u{5f53}"),
+    ('\u{2f875}', false, "\u{5c22}"),
+    ('\u{2f876}', false, "\u{3781}"),
+    ('\u{2f877}', false, "\u{5c60}"),
+    ('\u{2f878}', false, "\u{5c6e}"),
+    ('\u{2f879}', false, "\u{5cc0}"),
+    ('\u{2f87a}', false, "\u{5c8d}"),
+    ('\u{2f87b}', false, "\u{21de4}"),
+    ('\u{2f87c}', false, "\u{5d43}"),
+    ('\u{2f87d}', false, "\u{21de6}"),
+    ('\u{2f87e}', false, "\u{5d6e}"),
+    ('\u{2f87f}', false, "\u{5d6b}"),
+    ('\u{2f880}', false, "\u{5d7c}"),
+    ('\u{2f881}', false, "\u{5de1}"),
+    ('\u{2f882}', false, "\u{5de2}"),
+    ('\u{2f883}', false, "\u{382f}"),
+    ('\u{2f884}', false, "\u{5dfd}"),
+    ('\u{2f885}', false, "\u{5e28}"),
+    ('\u{2f886}', false, "\u{5e3d}"),
+    ('\u{2f887}', false, "\u{5e69}"),
+    ('\u{2f888}', false, "\u{3862}"),
+    ('\u{2f889}', false, "\u{22183}"),
+    ('\u{2f88a}', false, "\u{387c}"),
+    ('\u{2f88b}', false, "\u{5eb0}"),
+    ('\u{2f88c}', false, "\u{5eb3}"),
+    ('\u{2f88d}', false, "\u{5eb6}"),
+    ('\u{2f88e}', false, "\u{5eca}"),
+    ('\u{2f88f}', false, "\u{2a392}"),
+    ('\u{2f890}', false, "\u{5efe}"),
+    ('\u{2f891}', false, "\u{22331}"),
+    ('\u{2f892}', false, "\u{22331}"),
+    ('\u{2f893}', false, "\u{8201}"),
+    ('\u{2f894}', false, "\u{5f22}"),
+    ('\u{2f895}', false, "\u{5f22}"),
+    ('\u{2f896}', false, "\u{38c7}"),
+    ('\u{2f897}', false, "\u{232b8}"),
+    ('\u{2f898}', false, "\u{261da}"),
+    ('\u{2f899}', false, "\u{5f62}"),
+    ('\u{2f89a}', false, "\u{5f6b}"),
+    ('\u{2f89b}', false, "\u{38e3}"),
+    ('\u{2f89c}', false, "\u{5f9a}"),
+    ('\u{2f89d}', false, "\u{5fcd}"),
+    ('\u{2f89e}', false, "\u{5fd7}"),
+    ('\u{2f89f}', false, "\u{5ff9}"),
+    ('\u{2f8a0}', false, "\u{6081}"),
+    ('\u{2f8a1}', false, "\u{393a}"),
+    ('\u{2f8a2}', false, "\u{391c}"),
+    ('\u{2f8a3}', false, "\u{6094}"),
+    ('\u{2f8a4}', false, "\u{226d4}"),
+    ('\u{2f8a5}', false, "\u{60c7}"),
+    ('\u{2f8a6}', false, "\u{6148}"),
+    ('\u{2f8a7}', false, "\u{614c}"),
+    ('\u{2f8a8}', false, "\u{614e}"),
+    ('\u{2f8a9}', false, "\u{614c}"),
+    ('\u{2f8aa}', false, "\u{617a}"),
+    ('\u{2f8ab}', false, "\u{618e}"),
+    ('\u{2f8ac}', false, "\u{61b2}"),
+    ('\u{2f8ad}', false, "\u{61a4}"),
+    ('\u{2f8ae}', false, "\u{61af}"),
+    ('\u{2f8af}', false, "\u{61de}"),
+    ('\u{2f8b0}', false, "\u{61f2}"),
+    ('\u{2f8b1}', false, "\u{61f6}"),
+    ('\u{2f8b2}', false, "\u{6210}"),
+    ('\u{2f8b3}', false, "\u{621b}"),
+    ('\u{2f8b4}', false, "\u{625d}"),
+    ('\u{2f8b5}', false, "\u{62b1}"),
+    ('\u{2f8b6}', false, "\u{62d4}"),
+    ('\u{2f8b7}', false, "\u{6350}"),
+    ('\u{2f8b8}', false, "\u{22b0c}"),
+    ('\u{2f8b9}', false, "\u{633d}"),
+    ('\u{2f8ba}', false, "\u{62fc}"),
+    ('\u{2f8bb}', false, "\u{6368}"),
+    ('\u{2f8bc}', false, "\u{6383}"),
+    ('\u{2f8bd}', false, "\u{63e4}"),
+    ('\u{2f8be}', false, "\u{22bf1}"),
+    ('\u{2f8bf}', false, "\u{6422}"),
+    ('\u{2f8c0}', false, "\u{63c5}"),
+    ('\u{2f8c1}', false, "\u{63a9}"),
+    ('\u{2f8c2}', false, "\u{3a2e}"),
+    ('\u{2f8c3}', false, "\u{6469}"),
+    ('\u{2f8c4}', false, "\u{647e}"),
+    ('\u{2f8c5}', false, "\u{649d}"),
+    ('\u{2f8c6}', false, "\u{6477}"),
+    ('\u{2f8c7}', false, "\u{3a6c}"),
+    ('\u{2f8c8}', false, "\u{654f}"),
+    ('\u{2f8c9}', false, "\u{656c}"),
+    ('\u{2f8ca}', false, "\u{2300a}"),
+    ('\u{2f8cb}', false, "\u{65e3}"),
+    ('\u{2f8cc}', false, "\u{66f8}"),
+    ('\u{2f8cd}', false, "\u{6649}"),
+    ('\u{2f8ce}', false, "\u{3b19}"),
+    ('\u{2f8cf}', false, "\u{6691}"),
+    ('\u{2f8d0}', false, "\u{3b08}"),
+    ('\u{2f8d1}', false, "\u{3ae4}"),
+    ('\u{2f8d2}', false, "\u{5192}"),
+    ('\u{2f8d3}', false, "\u{5195}"),
+    ('\u{2f8d4}', false, "\u{6700}"),
+    ('\u{2f8d5}', false, "\u{669c}"),
+    ('\u{2f8d6}', false, "\u{80ad}"),
+    ('\u{2f8d7}', false, "\u{43d9}"),
+    ('\u{2f8d8}', false, "\u{6717}"),
+    ('\u{2f8d9}', false, "\u{671b}"),
+    ('\u{2f8da}', false, "\u{6721}"),
+    ('\u{2f8db}', false, "\u{675e}"),
+    ('\u{2f8dc}', false, "\u{6753}"),
+    ('\u{2f8dd}', false, "\u{233c3}"),
+    ('\u{2f8de}', false, "\u{3b49}"),
+    ('\u{2f8df}', false, "\u{67fa}"),
+    ('\u{2f8e0}', false, "\u{6785}"),
+    ('\u{2f8e1}', false, "\u{6852}"),
+    ('\u{2f8e2}', false, "\u{6885}"),
+    ('\u{2f8e3}', false, "\u{2346d}"),
+    ('\u{2f8e4}', false, "\u{688e}"),
+    ('\u{2f8e5}', false, "\u{681f}"),
+    ('\u{2f8e6}', false, "\u{6914}"),
+    ('\u{2f8e7}', false, "\u{3b9d}"),
+    ('\u{2f8e8}', false, "\u{6942}"),
+    ('\u{2f8e9}', false, "\u{69a3}"),
+    ('\u{2f8ea}', false, "\u{69ea}"),
+    ('\u{2f8eb}', false, "\u{6aa8}"),
+    ('\u{2f8ec}', false, "\u{236a3}"),
+    ('\u{2f8ed}', false, "\u{6adb}"),
+    ('\u{2f8ee}', false, "\u{3c18}"),
+    ('\u{2f8ef}', false, "\u{6b21}"),
+    ('\u{2f8f0}', false, "\u{238a7}"),
+    ('\u{2f8f1}', false, "\u{6b54}"),
+    ('\u{2f8f2}', false, "\u{3c4e}"),
+    ('\u{2f8f3}', false, "\u{6b72}"),
+    ('\u{2f8f4}', false, "\u{6b9f}"),
+    ('\u{2f8f5}', false, "\u{6bba}"),
+    ('\u{2f8f6}', false, "\u{6bbb}"),
+    ('\u{2f8f7}', false, "\u{23a8d}"),
+    ('\u{2f8f8}', false, "\u{21d0b}"),
+    ('\u{2f8f9}', false, "\u{23afa}"),
+    ('\u{2f8fa}', false, "\u{6c4e}"),
+    ('\u{2f8fb}', false, "\u{23cbc}"),
+    ('\u{2f8fc}', false, "\u{6cbf}"),
+    ('\u{2f8fd}', false, "\u{6ccd}"),
+    ('\u{2f8fe}', false, "\u{6c67}"),
+    ('\u{2f8ff}', false, "\u{6d16}"),
+    ('\u{2f900}', false, "\u{6d3e}"),
+    ('\u{2f901}', false, "\u{6d77}"),
+    ('\u{2f902}', false, "\u{6d41}"),
+    ('\u{2f903}', false, "\u{6d69}"),
+    ('\u{2f904}', false, "\u{6d78}"),
+    ('\u{2f905}', false, "\u{6d85}"),
+    ('\u{2f906}', false, "\u{23d1e}"),
+    ('\u{2f907}', false, "\u{6d34}"),
+    ('\u{2f908}', false, "\u{6e2f}"),
+    ('\u{2f909}', false, "\u{6e6e}"),
+    ('\u{2f90a}', false, "\u{3d33}"),
+    ('\u{2f90b}', false, "\u{6ecb}"),
+    ('\u{2f90c}', false, "\u{6ec7}"),
+    ('\u{2f90d}', false, "\u{23ed1}"),
+    ('\u{2f90e}', false, "\u{6df9}"),
+    ('\u{2f90f}', false, "\u{6f6e}"),
+    ('\u{2f910}', false, "\u{23f5e}"),
+    ('\u{2f911}', false, "\u{23f8e}"),
+    ('\u{2f912}', false, "\u{6fc6}"),
+    ('\u{2f913}', false, "\u{7039}"),
+    ('\u{2f914}', false, "\u{701e}"),
+    ('\u{2f915}', false, "\u{701b}"),
+    ('\u{2f916}', false, "\u{3d96}"),
+    ('\u{2f917}', false, "\u{704a}"),
+    ('\u{2f918}', false, "\u{707d}"),
+    ('\u{2f919}', false, "\u{7077}"),
+    ('\u{2f91a}', false, "\u{70ad}"),
+    ('\u{2f91b}', false, "\u{20525}"),
+    ('\u{2f91c}', false, "\u{7145}"),
+    ('\u{2f91d}', false, "\u{24263}"),
+    ('\u{2f91e}', false, "\u{719c}"),
+    ('\u{2f91f}', false, "\u{243ab}"),
+    ('\u{2f920}', false, "\u{7228}"),
+    ('\u{2f921}', false, "\u{7235}"),
+    ('\u{2f922}', false, "\u{7250}"),
+    ('\u{2f923}', false, "\u{24608}"),
+    ('\u{2f924}', false, "\u{7280}"),
+    ('\u{2f925}', false, "\u{7295}"),
+    ('\u{2f926}', false, "\u{24735}"),
+    ('\u{2f927}', false, "\u{24814}"),
+    ('\u{2f928}', false, "\u{737a}"),
+    ('\u{2f929}', false, "\u{738b}"),
+    ('\u{2f92a}', false, "\u{3eac}"),
+    ('\u{2f92b}', false, "\u{73a5}"),
+    ('\u{2f92c}', false, "\u{3eb8}"),
+    ('\u{2f92d}', false, "\u{3eb8}"),
+    ('\u{2f92e}', false, "\u{7447}"),
+    ('\u{2f92f}', false, "\u{745c}"),
+    ('\u{2f930}', false, "\u{7471}"),
+    ('\u{2f931}', false, "\u{7485}"),
+    ('\u{2f932}', false, "\u{74ca}"),
+    ('\u{2f933}', false, "\u{3f1b}"),
+    ('\u{2f934}', false, "\u{7524}"),
+    ('\u{2f935}', false, "\u{24c36}"),
+    ('\u{2f936}', false, "\u{753e}"),
+    ('\u{2f937}', false, "\u{24c92}"),
+    ('\u{2f938}', false, "\u{7570}"),
+    ('\u{2f939}', false, "\u{2219f}"),
+    ('\u{2f93a}', false, "\u{7610}"),
+    ('\u{2f93b}', false, "\u{24fa1}"),
+    ('\u{2f93c}', false, "\u{24fb8}"),
+    ('\u{2f93d}', false, "\u{25044}"),
+    ('\u{2f93e}', false, "\u{3ffc}"),
+    ('\u{2f93f}', false, "\u{4008}"),
+    ('\u{2f940}', false, "\u{76f4}"),
+    ('\u{2f941}', false, "\u{250f3}"),
+    ('\u{2f942}', false, "\u{250f2}"),
+    ('\u{2f943}', false, "\u{25119}"),
+    ('\u{2f944}', false, "\u{25133}"),
+    ('\u{2f945}', false, "\u{771e}"),
+    ('\u{2f946}', false, "\u{771f}"),
+    ('\u{2f947}', false, "\u{771f}"),
+    ('\u{2f948}', false, "\u{774a}"),
+    ('\u{2f949}', false, "\u{4039}"),
+    ('\u{2f94a}', false, "\u{778b}"),
+    ('\u{2f94b}', false, "\u{4046}"),
+    ('\u{2f94c}', false, "\u{4096}"),
+    ('\u{2f94d}', false, "\u{2541d}"),
+    ('\u{2f94e}', false, "\u{784e}"),
+    ('\u{2f94f}', false, "\u{788c}"),
+    ('\u{2f950}', false, "\u{78cc}"),
+    ('\u{2f951}', false, "\u{40e3}"),
+    ('\u{2f952}', false, "\u{25626}"),
+    ('\u{2f953}', false, "\u{7956}"),
+    ('\u{2f954}', false, "\u{2569a}"),
+    ('\u{2f955}', false, "\u{256c5}"),
+    ('\u{2f956}', false, "\u{798f}"),
+    ('\u{2f957}', false, "\u{79eb}"),
+    ('\u{2f958}', false, "\u{412f}"),
+    ('\u{2f959}', false, "\u{7a40}"),
+    ('\u{2f95a}', false, "\u{7a4a}"),
+    ('\u{2f95b}', false, "\u{7a4f}"),
+    ('\u{2f95c}', false, "\u{2597c}"),
+    ('\u{2f95d}', false, "\u{25aa7}"),
+    ('\u{2f95e}', false, "\u{25aa7}"),
+    ('\u{2f95f}', false, "\u{7aee}"),
+    ('\u{2f960}', false, "\u{4202}"),
+    ('\u{2f961}', false, "\u{25bab}"),
+    ('\u{2f962}', false, "\u{7bc6}"),
+    ('\u{2f963}', false, "\u{7bc9}"),
+    ('\u{2f964}', false, "\u{4227}"),
+    ('\u{2f965}', false, "\u{25c80}"),
+    ('\u{2f966}', false, "\u{7cd2}"),
+    ('\u{2f967}', false, "\u{42a0}"),
+    ('\u{2f968}', false, "\u{7ce8}"),
+    ('\u{2f969}', false, "\u{7ce3}"),
+    ('\u{2f96a}', false, "\u{7d00}"),
+    ('\u{2f96b}', false, "\u{25f86}"),
+    ('\u{2f96c}', false, "\u{7d63}"),
+    ('\u{2f96d}', false, "\u{4301}"),
+    ('\u{2f96e}', false, "\u{7dc7}"),
+    ('\u{2f96f}', false, "\u{7e02}"),
+    ('\u{2f970}', false, "\u{7e45}"),
+    ('\u{2f971}', false, "\u{4334}"),
+    ('\u{2f972}', false, "\u{26228}"),
+    ('\u{2f973}', false, "\u{26247}"),
+    ('\u{2f974}', false, "\u{4359}"),
+    ('\u{2f975}', false, "\u{262d9}"),
+    ('\u{2f976}', false, "\u{7f7a}"),
+    ('\u{2f977}', false, "\u{2633e}"),
+    ('\u{2f978}', false, "\u{7f95}"),
+    ('\u{2f979}', false, "\u{7ffa}"),
+    ('\u{2f97a}', false, "\u{8005}"),
+    ('\u{2f97b}', false, "\u{264da}"),
+    ('\u{2f97c}', false, "\u{26523}"),
+    ('\u{2f97d}', false, "\u{8060}"),
+    ('\u{2f97e}', false, "\u{265a8}"),
+    ('\u{2f97f}', false, "\u{8070}"),
+    ('\u{2f980}', false, "\u{2335f}"),
+    ('\u{2f981}', false, "\u{43d5}"),
+    ('\u{2f982}', false, "\u{80b2}"),
+    ('\u{2f983}', false, "\u{8103}"),
+    ('\u{2f984}', false, "\u{440b}"),
+    ('\u{2f985}', false, "\u{813e}"),
+    ('\u{2f986}', false, "\u{5ab5}"),
+    ('\u{2f987}', false, "\u{267a7}"),
+    ('\u{2f988}', false, "\u{267b5}"),
+    ('\u{2f989}', false, "\u{23393}"),
+    ('\u{2f98a}', false, "\u{2339c}"),
+    ('\u{2f98b}', false, "\u{8201}"),
+    ('\u{2f98c}', false, "\u{8204}"),
+    ('\u{2f98d}', false, "\u{8f9e}"),
+    ('\u{2f98e}', false, "\u{446b}"),
+    ('\u{2f98f}', false, "\u{8291}"),
+    ('\u{2f990}', false, "\u{828b}"),
+    ('\u{2f991}', false, "\u{829d}"),
+    ('\u{2f992}', false, "\u{52b3}"),
+    ('\u{2f993}', false, "\u{82b1}"),
+    ('\u{2f994}', false, "\u{82b3}"),
+    ('\u{2f995}', false, "\u{82bd}"),
+    ('\u{2f996}', false, "\u{82e6}"),
+    ('\u{2f997}', false, "\u{26b3c}"),
+    ('\u{2f998}', false, "\u{82e5}"),
+    ('\u{2f999}', false, "\u{831d}"),
+    ('\u{2f99a}', false, "\u{8363}"),
+    ('\u{2f99b}', false, "\u{83ad}"),
+    ('\u{2f99c}', false, "\u{8323}"),
+    ('\u{2f99d}', false, "\u{83bd}"),
+    ('\u{2f99e}', false, "\u{83e7}"),
+    ('\u{2f99f}', false, "\u{8457}"),
+    ('\u{2f9a0}', false, "\u{8353}"),
+    ('\u{2f9a1}', false, "\u{83ca}"),
+    ('\u{2f9a2}', false, "\u{83cc}"),
+    ('\u{2f9a3}', false, "\u{83dc}"),
+    ('\u{2f9a4}', false, "\u{26c36}"),
+    ('\u{2f9a5}', false, "\u{26d6b}"),
+    ('\u{2f9a6}', false, "\u{26cd5}"),
+    ('\u{2f9a7}', false, "\u{452b}"),
+    ('\u{2f9a8}', false, "\u{84f1}"),
+    ('\u{2f9a9}', false, "\u{84f3}"),
+    ('\u{2f9aa}', false, "\u{8516}"),
+    ('\u{2f9ab}', false, "\u{273ca}"),
+    ('\u{2f9ac}', false, "\u{8564}"),
+    ('\u{2f9ad}', false, "\u{26f2c}"),
+    ('\u{2f9ae}', false, "\u{455d}"),
+    ('\u{2f9af}', false, "\u{4561}"),
+    ('\u{2f9b0}', false, "\u{26fb1}"),
+    ('\u{2f9b1}', false, "\u{270d2}"),
+    ('\u{2f9b2}', false, "\u{456b}"),
+    ('\u{2f9b3}', false, "\u{8650}"),
+    ('\u{2f9b4}', false, "\u{865c}"),
+    ('\u{2f9b5}', false, "\u{8667}"),
+    ('\u{2f9b6}', false, "\u{8669}"),
+    ('\u{2f9b7}', false, "\u{86a9}"),
+    ('\u{2f9b8}', false, "\u{8688}"),
+    ('\u{2f9b9}', false, "\u{870e}"),
+    ('\u{2f9ba}', false, "\u{86e2}"),
+    ('\u{2f9bb}', false, "\u{8779}"),
+    ('\u{2f9bc}', false, "\u{8728}"),
+    ('\u{2f9bd}', false, "\u{876b}"),
+    ('\u{2f9be}', false, "\u{8786}"),
+    ('\u{2f9bf}', false, "\u{45d7}"),
+    ('\u{2f9c0}', false, "\u{87e1}"),
+    ('\u{2f9c1}', false, "\u{8801}"),
+    ('\u{2f9c2}', false, "\u{45f9}"),
+    ('\u{2f9c3}', false, "\u{8860}"),
+    ('\u{2f9c4}', false, "\u{8863}"),
+    ('\u{2f9c5}', false, "\u{27667}"),
+    ('\u{2f9c6}', false, "\u{88d7}"),
+    ('\u{2f9c7}', false, "\u{88de}"),
+    ('\u{2f9c8}', false, "\u{4635}"),
+    ('\u{2f9c9}', false, "\u{88fa}"),
+    ('\u{2f9ca}', false, "\u{34bb}"),
+    ('\u{2f9cb}', false, "\u{278ae}"),
+    ('\u{2f9cc}', false, "\u{27966}"),
+    ('\u{2f9cd}', false, "\u{46be}"),
+    ('\u{2f9ce}', false, "\u{46c7}"),
+    ('\u{2f9cf}', false, "\u{8aa0}"),
+    ('\u{2f9d0}', false, "\u{8aed}"),
+    ('\u{2f9d1}', false, "\u{8b8a}"),
+    ('\u{2f9d2}', false, "\u{8c55}"),
+    ('\u{2f9d3}', false, "\u{27ca8}"),
+    ('\u{2f9d4}', false, "\u{8cab}"),
+    ('\u{2f9d5}', false, "\u{8cc1}"),
+    ('\u{2f9d6}', false, "\u{8d1b}"),
+    ('\u{2f9d7}', false, "\u{8d77}"),
+    ('\u{2f9d8}', false, "\u{27f2f}"),
+    ('\u{2f9d9}', false, "\u{20804}"),
+    ('\u{2f9da}', false, "\u{8dcb}"),
+    ('\u{2f9db}', false, "\u{8dbc}"),
+    ('\u{2f9dc}', false, "\u{8df0}"),
+    ('\u{2f9dd}', false, "\u{208de}"),
+    ('\u{2f9de}', false, "\u{8ed4}"),
+    ('\u{2f9df}', false, "\u{8f38}"),
+    ('\u{2f9e0}', false, "\u{285d2}"),
+    ('\u{2f9e1}', false, "\u{285ed}"),
+    ('\u{2f9e2}', false, "\u{9094}"),
+    ('\u{2f9e3}', false, "\u{90f1}"),
+    ('\u{2f9e4}', false, "\u{9111}"),
+    ('\u{2f9e5}', false, "\u{2872e}"),
+    ('\u{2f9e6}', false, "\u{911b}"),
+    ('\u{2f9e7}', false, "\u{9238}"),
+    ('\u{2f9e8}', false, "\u{92d7}"),
+    ('\u{2f9e9}', false, "\u{92d8}"),
+    ('\u{2f9ea}', false, "\u{927c}"),
+    ('\u{2f9eb}', false, "\u{93f9}"),
+    ('\u{2f9ec}', false, "\u{9415}"),
+    ('\u{2f9ed}', false, "\u{28bfa}"),
+    ('\u{2f9ee}', false, "\u{958b}"),
+    ('\u{2f9ef}', false, "\u{4995}"),
+    ('\u{2f9f0}', false, "\u{95b7}"),
+    ('\u{2f9f1}', false, "\u{28d77}"),
+    ('\u{2f9f2}', false, "\u{49e6}"),
+    ('\u{2f9f3}', false, "\u{96c3}"),
+    ('\u{2f9f4}', false, "\u{5db2}"),
+    ('\u{2f9f5}', false, "\u{9723}"),
+    ('\u{2f9f6}', false, "\u{29145}"),
+    ('\u{2f9f7}', false, "\u{2921a}"),
+    ('\u{2f9f8}', false, "\u{4a6e}"),
+    ('\u{2f9f9}', false, "\u{4a76}"),
+    ('\u{2f9fa}', false, "\u{97e0}"),
+    ('\u{2f9fb}', false, "\u{2940a}"),
+    ('\u{2f9fc}', false, "\u{4ab2}"),
+    ('\u{2f9fd}', false, "\u{29496}"),
+    ('\u{2f9fe}', false, "\u{980b}"),
+    ('\u{2f9ff}', false, "\u{980b}"),
+    ('\u{2fa00}', false, "\u{9829}"),
+    ('\u{2fa01}', false, "\u{295b6}"),
+    ('\u{2fa02}', false, "\u{98e2}"),
+    ('\u{2fa03}', false, "\u{4b33}"),
+    ('\u{2fa04}', false, "\u{9929}"),
+    ('\u{2fa05}', false, "\u{99a7}"),
+    ('\u{2fa06}', false, "\u{99c2}"),
+    ('\u{2fa07}', false, "\u{99fe}"),
+    ('\u{2fa08}', false, "\u{4bce}"),
+    ('\u{2fa09}', false, "\u{29b30}"),
+    ('\u{2fa0a}', false, "\u{9b12}"),
+    ('\u{2fa0b}', false, "\u{9c40}"),
+    ('\u{2fa0c}', false, "\u{9cfd}"),
+    ('\u{2fa0d}', false, "\u{4cce}"),
+    ('\u{2fa0e}', false, "\u{4ced}"),
+    ('\u{2fa0f}', false, "\u{9d67}"),
+    ('\u{2fa10}', false, "\u{2a0ce}"),
+    ('\u{2fa11}', false, "\u{4cf8}"),
+    ('\u{2fa12}', false, "\u{2a105}"),
+    ('\u{2fa13}', false, "\u{2a20e}"),
+    ('\u{2fa14}', false, "\u{2a291}"),
+    ('\u{2fa15}', false, "\u{9ebb}"),
+    ('\u{2fa16}', false, "\u{4d56}"),
+    ('\u{2fa17}', false, "\u{9ef9}"),
+    ('\u{2fa18}', false, "\u{9efe}"),
+    ('\u{2fa19}', false, "\u{9f05}"),
+    ('\u{2fa1a}', false, "\u{9f0f}"),
+    ('\u{2fa1b}', false, "\u{9f16}"),
+    ('\u{2fa1c}', false, "\u{9f3b}"),
+    ('\u{2fa1d}', false, "\u{2a600}"),
+];
+
+/// The pairs of characters that compose into a primary composite, sorted by the pair.
+pub static COMPOSITIONS: [(char, char, char); 941] = [
+    ('\u{3c}', '\u{338}', '\u{226e}'),
+    ('\u{3d}', '\u{338}', '\u{2260}'),
+    ('\u{3e}', '\u{338}', '\u{226f}'),
+    ('\u{41}', '\u{300}', '\u{c0}'),
+    ('\u{41}', '\u{301}', '\u{c1}'),
+    ('\u{41}', '\u{302}', '\u{c2}'),
+    ('\u{41}', '\u{303}', '\u{c3}'),
+    ('\u{41}', '\u{304}', '\u{100}'),
+    ('\u{41}', '\u{306}', '\u{102}'),
+    ('\u{41}', '\u{307}', '\u{226}'),
+    ('\u{41}', '\u{308}', '\u{c4}'),
+    ('\u{41}', '\u{309}', '\u{1ea2}'),
+    ('\u{41}', '\u{30a}', '\u{c5}'),
+    ('\u{41}', '\u{30c}', '\u{1cd}'),
+    ('\u{41}', '\u{30f}', '\u{200}'),
+    ('\u{41}', '\u{311}', '\u{202}'),
+    ('\u{41}', '\u{323}', '\u{1ea0}'),
+    ('\u{41}', '\u{325}', '\u{1e00}'),
+    ('\u{41}', '\u{328}', '\u{104}'),
+    ('\u{42}', '\u{307}', '\u{1e02}'),
+    ('\u{42}', '\u{323}', '\u{1e04}'),
+    ('\u{42}', '\u{331}', '\u{1e06}'),
+    ('\u{43}', '\u{301}', '\u{106}'),
+    ('\u{43}', '\u{302}', '\u{108}'),
+    ('\u{43}', '\u{307}', '\u{10a}'),
+    ('\u{43}', '\u{30c}', '\u{10c}'),
+    ('\u{43}', '\u{327}', '\u{c7}'),
+    ('\u{44}', '\u{307}', '\u{1e0a}'),
+    ('\u{44}', '\u{30c}', '\u{10e}'),
+    ('\u{44}', '\u{323}', '\u{1e0c}'),
+    ('\u{44}', '\u{327}', '\u{1e10}'),
+    ('\u{44}', '\u{32d}', '\u{1e12}'),
+    ('\u{44}', '\u{331}', '\u{1e0e}'),
+    ('\u{45}', '\u{300}', '\u{c8}'),
+    ('\u{45}', '\u{301}', '\u{c9}'),
+    ('\u{45}', '\u{302}', '\u{ca}'),
+    ('\u{45}', '\u{303}', '\u{1ebc}'),
+    ('\u{45}', '\u{304}', '\u{112}'),
+    ('\u{45}', '\u{306}', '\u{114}'),
+    ('\u{45}', '\u{307}', '\u{116}'),
+    ('\u{45}', '\u{308}', '\u{cb}'),
+    ('\u{45}', '\u{309}', '\u{1eba}'),
+    ('\u{45}', '\u{30c}', '\u{11a}'),
+    ('\u{45}', '\u{30f}', '\u{204}'),
+    ('\u{45}', '\u{311}', '\u{206}'),
+    ('\u{45}', '\u{323}', '\u{1eb8}'),
+    ('\u{45}', '\u{327}', '\u{228}'),
+    ('\u{45}', '\u{328}', '\u{118}'),
+    ('\u{45}', '\u{32d}', '\u{1e18}'),
+    ('\u{45}', '\u{330}', '\u{1e1a}'),
+    ('\u{46}', '\u{307}', '\u{1e1e}'),
+    ('\u{47}', '\u{301}', '\u{1f4}'),
+    ('\u{47}', '\u{302}', '\u{11c}'),
+    ('\u{47}', '\u{304}', '\u{1e20}'),
+    ('\u{47}', '\u{306}', '\u{11e}'),
+    ('\u{47}', '\u{307}', '\u{120}'),
+    ('\u{47}', '\u{30c}', '\u{1e6}'),
+    ('\u{47}', '\u{327}', '\u{122}'),
+    ('\u{48}', '\u{302}', '\u{124}'),
+    ('\u{48}', '\u{307}', '\u{1e22}'),
+    ('\u{48}', '\u{308}', '\u{1e26}'),
+    ('\u{48}', '\u{30c}', '\u{21e}'),
+    ('\u{48}', '\u{323}', '\u{1e24}'),
+    ('\u{48}', '\u{327}', '\u{1e28}'),
+    ('\u{48}', '\u{32e}', '\u{1e2a}'),
+    ('\u{49}', '\u{300}', '\u{cc}'),
+    ('\u{49}', '\u{301}', '\u{cd}'),
+    ('\u{49}', '\u{302}', '\u{ce}'),
+    ('\u{49}', '\u{303}', '\u{128}'),
+    ('\u{49}', '\u{304}', '\u{12a}'),
+    ('\u{49}', '\u{306}', '\u{12c}'),
+    ('\u{49}', '\u{307}', '\u{130}'),
+    ('\u{49}', '\u{308}', '\u{cf}'),
+    ('\u{49}', '\u{309}', '\u{1ec8}'),
+    ('\u{49}', '\u{30c}', '\u{1cf}'),
+    ('\u{49}', '\u{30f}', '\u{208}'),
+    ('\u{49}', '\u{311}', '\u{20a}'),
+    ('\u{49}', '\u{323}', '\u{1eca}'),
+    ('\u{49}', '\u{328}', '\u{12e}'),
+    ('\u{49}', '\u{330}', '\u{1e2c}'),
+    ('\u{4a}', '\u{302}', '\u{134}'),
+    ('\u{4b}', '\u{301}', '\u{1e30}'),
+    ('\u{4b}', '\u{30c}', '\u{1e8}'),
+    ('\u{4b}', '\u{323}', '\u{1e32}'),
+    ('\u{4b}', '\u{327}', '\u{136}'),
+    ('\u{4b}', '\u{331}', '\u{1e34}'),
+    ('\u{4c}', '\u{301}', '\u{139}'),
+    ('\u{4c}', '\u{30c}', '\u{13d}'),
+    ('\u{4c}', '\u{323}', '\u{1e36}'),
+    ('\u{4c}', '\u{327}', '\u{13b}'),
+    ('\u{4c}', '\u{32d}', '\u{1e3c}'),
+    ('\u{4c}', '\u{331}', '\u{1e3a}'),
+    ('\u{4d}', '\u{301}', '\u{1e3e}'),
+    ('\u{4d}', '\u{307}', '\u{1e40}'),
+    ('\u{4d}', '\u{323}', '\u{1e42}'),
+    ('\u{4e}', '\u{300}', '\u{1f8}'),
+    ('\u{4e}', '\u{301}', '\u{143}'),
+    ('\u{4e}', '\u{303}', '\u{d1}'),
+    ('\u{4e}', '\u{307}', '\u{1e44}'),
+    ('\u{4e}', '\u{30c}', '\u{147}'),
+    ('\u{4e}', '\u{323}', '\u{1e46}'),
+    ('\u{4e}', '\u{327}', '\u{145}'),
+    ('\u{4e}', '\u{32d}', '\u{1e4a}'),
+    ('\u{4e}', '\u{331}', '\u{1e48}'),
+    ('\u{4f}', '\u{300}', '\u{d2}'),
+    ('\u{4f}', '\u{301}', '\u{d3}'),
+    ('\u{4f}', '\u{302}', '\u{d4}'),
+    ('\u{4f}', '\u{303}', '\u{d5}'),
+    ('\u{4f}', '\u{304}', '\u{14c}'),
+    ('\u{4f}', '\u{306}', '\u{14e}'),
+    ('\u{4f}', '\u{307}', '\u{22e}'),
+    ('\u{4f}', '\u{308}', '\u{d6}'),
+    ('\u{4f}', '\u{309}', '\u{1ece}'),
+    ('\u{4f}', '\u{30b}', '\u{150}'),
+    ('\u{4f}', '\u{30c}', '\u{1d1}'),
+    ('\u{4f}', '\u{30f}', '\u{20c}'),
+    ('\u{4f}', '\u{311}', '\u{20e}'),
+    ('\u{4f}', '\u{31b}', '\u{1a0}'),
+    ('\u{4f}', '\u{323}', '\u{1ecc}'),
+    ('\u{4f}', '\u{328}', '\u{1ea}'),
+    ('\u{50}', '\u{301}', '\u{1e54}'),
+    ('\u{50}', '\u{307}', '\u{1e56}'),
+    ('\u{52}', '\u{301}', '\u{154}'),
+    ('\u{52}', '\u{307}', '\u{1e58}'),
+    ('\u{52}', '\u{30c}', '\u{158}'),
+    ('\u{52}', '\u{30f}', '\u{210}'),
+    ('\u{52}', '\u{311}', '\u{212}'),
+    ('\u{52}', '\u{323}', '\u{1e5a}'),
+    ('\u{52}', '\u{327}', '\u{156}'),
+    ('\u{52}', '\u{331}', '\u{1e5e}'),
+    ('\u{53}', '\u{301}', '\u{15a}'),
+    ('\u{53}', '\u{302}', '\u{15c}'),
+    ('\u{53}', '\u{307}', '\u{1e60}'),
+    ('\u{53}', '\u{30c}', '\u{160}'),
+    ('\u{53}', '\u{323}', '\u{1e62}'),
+    ('\u{53}', '\u{326}', '\u{218}'),
+    ('\u{53}', '\u{327}', '\u{15e}'),
+    ('\u{54}', '\u{307}', '\u{1e6a}'),
+    ('\u{54}', '\u{30c}', '\u{164}'),
+    ('\u{54}', '\u{323}', '\u{1e6c}'),
+    ('\u{54}', '\u{326}', '\u{21a}'),
+    ('\u{54}', '\u{327}', '\u{162}'),
+    ('\u{54}', '\u{32d}', '\u{1e70}'),
+    ('\u{54}', '\u{331}', '\u{1e6e}'),
+    ('\u{55}', '\u{300}', '\u{d9}'),
+    ('\u{55}', '\u{301}', '\u{da}'),
+    ('\u{55}', '\u{302}', '\u{db}'),
+    ('\u{55}', '\u{303}', '\u{168}'),
+    ('\u{55}', '\u{304}', '\u{16a}'),
+    ('\u{55}', '\u{306}', '\u{16c}'),
+    ('\u{55}', '\u{308}', '\u{dc}'),
+    ('\u{55}', '\u{309}', '\u{1ee6}'),
+    ('\u{55}', '\u{30a}', '\u{16e}'),
+    ('\u{55}', '\u{30b}', '\u{170}'),
+    ('\u{55}', '\u{30c}', '\u{1d3}'),
+    ('\u{55}', '\u{30f}', '\u{214}'),
+    ('\u{55}', '\u{311}', '\u{216}'),
+    ('\u{55}', '\u{31b}', '\u{1af}'),
+    ('\u{55}', '\u{323}', '\u{1ee4}'),
+    ('\u{55}', '\u{324}', '\u{1e72}'),
+    ('\u{55}', '\u{328}', '\u{172}'),
+    ('\u{55}', '\u{32d}', '\u{1e76}'),
+    ('\u{55}', '\u{330}', '\u{1e74}'),
+    ('\u{56}', '\u{303}', '\u{1e7c}'),
+    ('\u{56}', '\u{323}', '\u{1e7e}'),
+    ('\u{57}', '\u{300}', '\u{1e80}'),
+    ('\u{57}', '\u{301}', '\u{1e82}'),
+    ('\u{57}', '\u{302}', '\u{174}'),
+    ('\u{57}', '\u{307}', '\u{1e86}'),
+    ('\u{57}', '\u{308}', '\u{1e84}'),
+    ('\u{57}', '\u{323}', '\u{1e88}'),
+    ('\u{58}', '\u{307}', '\u{1e8a}'),
+    ('\u{58}', '\u{308}', '\u{1e8c}'),
+    ('\u{59}', '\u{300}', '\u{1ef2}'),
+    ('\u{59}', '\u{301}', '\u{dd}'),
+    ('\u{59}', '\u{302}', '\u{176}'),
+    ('\u{59}', '\u{303}', '\u{1ef8}'),
+    ('\u{59}', '\u{304}', '\u{232}'),
+    ('\u{59}', '\u{307}', '\u{1e8e}'),
+    ('\u{59}', '\u{308}', '\u{178}'),
+    ('\u{59}', '\u{309}', '\u{1ef6}'),
+    ('\u{59}', '\u{323}', '\u{1ef4}'),
+    ('\u{5a}', '\u{301}', '\u{179}'),
+    ('\u{5a}', '\u{302}', '\u{1e90}'),
+    ('\u{5a}', '\u{307}', '\u{17b}'),
+    ('\u{5a}', '\u{30c}', '\u{17d}'),
+    ('\u{5a}', '\u{323}', '\u{1e92}'),
+    ('\u{5a}', '\u{331}', '\u{1e94}'),
+    ('\u{61}', '\u{300}', '\u{e0}'),
+    ('\u{61}', '\u{301}', '\u{e1}'),
+    ('\u{61}', '\u{302}', '\u{e2}'),
+    ('\u{61}', '\u{303}', '\u{e3}'),
+    ('\u{61}', '\u{304}', '\u{101}'),
+    ('\u{61}', '\u{306}', '\u{103}'),
+    ('\u{61}', '\u{307}', '\u{227}'),
+    ('\u{61}', '\u{308}', '\u{e4}'),
+    ('\u{61}', '\u{309}', '\u{1ea3}'),
+    ('\u{61}', '\u{30a}', '\u{e5}'),
+    ('\u{61}', '\u{30c}', '\u{1ce}'),
+    ('\u{61}', '\u{30f}', '\u{201}'),
+    ('\u{61}', '\u{311}', '\u{203}'),
+    ('\u{61}', '\u{323}', '\u{1ea1}'),
+    ('\u{61}', '\u{325}', '\u{1e01}'),
+    ('\u{61}', '\u{328}', '\u{105}'),
+    ('\u{62}', '\u{307}', '\u{1e03}'),
+    ('\u{62}', '\u{323}', '\u{1e05}'),
+    ('\u{62}', '\u{331}', '\u{1e07}'),
+    ('\u{63}', '\u{301}', '\u{107}'),
+    ('\u{63}', '\u{302}', '\u{109}'),
+    ('\u{63}', '\u{307}', '\u{10b}'),
+    ('\u{63}', '\u{30c}', '\u{10d}'),
+    ('\u{63}', '\u{327}', '\u{e7}'),
+    ('\u{64}', '\u{307}', '\u{1e0b}'),
+    ('\u{64}', '\u{30c}', '\u{10f}'),
+    ('\u{64}', '\u{323}', '\u{1e0d}'),
+    ('\u{64}', '\u{327}', '\u{1e11}'),
+    ('\u{64}', '\u{32d}', '\u{1e13}'),
+    ('\u{64}', '\u{331}', '\u{1e0f}'),
+    ('\u{65}', '\u{300}', '\u{e8}'),
+    ('\u{65}', '\u{301}', '\u{e9}'),
+    ('\u{65}', '\u{302}', '\u{ea}'),
+    ('\u{65}', '\u{303}', '\u{1ebd}'),
+    ('\u{65}', '\u{304}', '\u{113}'),
+    ('\u{65}', '\u{306}', '\u{115}'),
+    ('\u{65}', '\u{307}', '\u{117}'),
+    ('\u{65}', '\u{308}', '\u{eb}'),
+    ('\u{65}', '\u{309}', '\u{1ebb}'),
+    ('\u{65}', '\u{30c}', '\u{11b}'),
+    ('\u{65}', '\u{30f}', '\u{205}'),
+    ('\u{65}', '\u{311}', '\u{207}'),
+    ('\u{65}', '\u{323}', '\u{1eb9}'),
+    ('\u{65}', '\u{327}', '\u{229}'),
+    ('\u{65}', '\u{328}', '\u{119}'),
+    ('\u{65}', '\u{32d}', '\u{1e19}'),
+    ('\u{65}', '\u{330}', '\u{1e1b}'),
+    ('\u{66}', '\u{307}', '\u{1e1f}'),
+    ('\u{67}', '\u{301}', '\u{1f5}'),
+    ('\u{67}', '\u{302}', '\u{11d}'),
+    ('\u{67}', '\u{304}', '\u{1e21}'),
+    ('\u{67}', '\u{306}', '\u{11f}'),
+    ('\u{67}', '\u{307}', '\u{121}'),
+    ('\u{67}', '\u{30c}', '\u{1e7}'),
+    ('\u{67}', '\u{327}', '\u{123}'),
+    ('\u{68}', '\u{302}', '\u{125}'),
+    ('\u{68}', '\u{307}', '\u{1e23}'),
+    ('\u{68}', '\u{308}', '\u{1e27}'),
+    ('\u{68}', '\u{30c}', '\u{21f}'),
+    ('\u{68}', '\u{323}', '\u{1e25}'),
+    ('\u{68}', '\u{327}', '\u{1e29}'),
+    ('\u{68}', '\u{32e}', '\u{1e2b}'),
+    ('\u{68}', '\u{331}', '\u{1e96}'),
+    ('\u{69}', '\u{300}', '\u{ec}'),
+    ('\u{69}', '\u{301}', '\u{ed}'),
+    ('\u{69}', '\u{302}', '\u{ee}'),
+    ('\u{69}', '\u{303}', '\u{129}'),
+    ('\u{69}', '\u{304}', '\u{12b}'),
+    ('\u{69}', '\u{306}', '\u{12d}'),
+    ('\u{69}', '\u{308}', '\u{ef}'),
+    ('\u{69}', '\u{309}', '\u{1ec9}'),
+    ('\u{69}', '\u{30c}', '\u{1d0}'),
+    ('\u{69}', '\u{30f}', '\u{209}'),
+    ('\u{69}', '\u{311}', '\u{20b}'),
+    ('\u{69}', '\u{323}', '\u{1ecb}'),
+    ('\u{69}', '\u{328}', '\u{12f}'),
+    ('\u{69}', '\u{330}', '\u{1e2d}'),
+    ('\u{6a}', '\u{302}', '\u{135}'),
+    ('\u{6a}', '\u{30c}', '\u{1f0}'),
+    ('\u{6b}', '\u{301}', '\u{1e31}'),
+    ('\u{6b}', '\u{30c}', '\u{1e9}'),
+    ('\u{6b}', '\u{323}', '\u{1e33}'),
+    ('\u{6b}', '\u{327}', '\u{137}'),
+    ('\u{6b}', '\u{331}', '\u{1e35}'),
+    ('\u{6c}', '\u{301}', '\u{13a}'),
+    ('\u{6c}', '\u{30c}', '\u{13e}'),
+    ('\u{6c}', '\u{323}', '\u{1e37}'),
+    ('\u{6c}', '\u{327}', '\u{13c}'),
+    ('\u{6c}', '\u{32d}', '\u{1e3d}'),
+    ('\u{6c}', '\u{331}', '\u{1e3b}'),
+    ('\u{6d}', '\u{301}', '\u{1e3f}'),
+    ('\u{6d}', '\u{307}', '\u{1e41}'),
+    ('\u{6d}', '\u{323}', '\u{1e43}'),
+    ('\u{6e}', '\u{300}', '\u{1f9}'),
+    ('\u{6e}', '\u{301}', '\u{144}'),
+    ('\u{6e}', '\u{303}', '\u{f1}'),
+    ('\u{6e}', '\u{307}', '\u{1e45}'),
+    ('\u{6e}', '\u{30c}', '\u{148}'),
+    ('\u{6e}', '\u{323}', '\u{1e47}'),
+    ('\u{6e}', '\u{327}', '\u{146}'),
+    ('\u{6e}', '\u{32d}', '\u{1e4b}'),
+    ('\u{6e}', '\u{331}', '\u{1e49}'),
+    ('\u{6f}', '\u{300}', '\u{f2}'),
+    ('\u{6f}', '\u{301}', '\u{f3}'),
+    ('\u{6f}', '\u{302}', '\u{f4}'),
+    ('\u{6f}', '\u{303}', '\u{f5}'),
+    ('\u{6f}', '\u{304}', '\u{14d}'),
+    ('\u{6f}', '\u{306}', '\u{14f}'),
+    ('\u{6f}', '\u{307}', '\u{22f}'),
+    ('\u{6f}', '\u{308}', '\u{f6}'),
+    ('\u{6f}', '\u{309}', '\u{1ecf}'),
+    ('\u{6f}', '\u{30b}', '\u{151}'),
+    ('\u{6f}', '\u{30c}', '\u{1d2}'),
+    ('\u{6f}', '\u{30f}', '\u{20d}'),
+    ('\u{6f}', '\u{311}', '\u{20f}'),
+    ('\u{6f}', '\u{31b}', '\u{1a1}'),
+    ('\u{6f}', '\u{323}', '\u{1ecd}'),
+    ('\u{6f}', '\u{328}', '\u{1eb}'),
+    ('\u{70}', '\u{301}', '\u{1e55}'),
+    ('\u{70}', '\u{307}', '\u{1e57}'),
+    ('\u{72}', '\u{301}', '\u{155}'),
+    ('\u{72}', '\u{307}', '\u{1e59}'),
+    ('\u{72}', '\u{30c}', '\u{159}'),
+    ('\u{72}', '\u{30f}', '\u{211}'),
+    ('\u{72}', '\u{311}', '\u{213}'),
+    ('\u{72}', '\u{323}', '\u{1e5b}'),
+    ('\u{72}', '\u{327}', '\u{157}'),
+    ('\u{72}', '\u{331}', '\u{1e5f}'),
+    ('\u{73}', '\u{301}', '\u{15b}'),
+    ('\u{73}', '\u{302}', '\u{15d}'),
+    ('\u{73}', '\u{307}', '\u{1e61}'),
+    ('\u{73}', '\u{30c}', '\u{161}'),
+    ('\u{73}', '\u{323}', '\u{1e63}'),
+    ('\u{73}', '\u{326}', '\u{219}'),
+    ('\u{73}', '\u{327}', '\u{15f}'),
+    ('\u{74}', '\u{307}', '\u{1e6b}'),
+    ('\u{74}', '\u{308}', '\u{1e97}'),
+    ('\u{74}', '\u{30c}', '\u{165}'),
+    ('\u{74}', '\u{323}', '\u{1e6d}'),
+    ('\u{74}', '\u{326}', '\u{21b}'),
+    ('\u{74}', '\u{327}', '\u{163}'),
+    ('\u{74}', '\u{32d}', '\u{1e71}'),
+    ('\u{74}', '\u{331}', '\u{1e6f}'),
+    ('\u{75}', '\u{300}', '\u{f9}'),
+    ('\u{75}', '\u{301}', '\u{fa}'),
+    ('\u{75}', '\u{302}', '\u{fb}'),
+    ('\u{75}', '\u{303}', '\u{169}'),
+    ('\u{75}', '\u{304}', '\u{16b}'),
+    ('\u{75}', '\u{306}', '\u{16d}'),
+    ('\u{75}', '\u{308}', '\u{fc}'),
+    ('\u{75}', '\u{309}', '\u{1ee7}'),
+    ('\u{75}', '\u{30a}', '\u{16f}'),
+    ('\u{75}', '\u{30b}', '\u{171}'),
+    ('\u{75}', '\u{30c}', '\u{1d4}'),
+    ('\u{75}', '\u{30f}', '\u{215}'),
+    ('\u{75}', '\u{311}', '\u{217}'),
+    ('\u{75}', '\u{31b}', '\u{1b0}'),
+    ('\u{75}', '\u{323}', '\u{1ee5}'),
+    ('\u{75}', '\u{324}', '\u{1e73}'),
+    ('\u{75}', '\u{328}', '\u{173}'),
+    ('\u{75}', '\u{32d}', '\u{1e77}'),
+    ('\u{75}', '\u{330}', '\u{1e75}'),
+    ('\u{76}', '\u{303}', '\u{1e7d}'),
+    ('\u{76}', '\u{323}', '\u{1e7f}'),
+    ('\u{77}', '\u{300}', '\u{1e81}'),
+    ('\u{77}', '\u{301}', '\u{1e83}'),
+    ('\u{77}', '\u{302}', '\u{175}'),
+    ('\u{77}', '\u{307}', '\u{1e87}'),
+    ('\u{77}', '\u{308}', '\u{1e85}'),
+    ('\u{77}', '\u{30a}', '\u{1e98}'),
+    ('\u{77}', '\u{323}', '\u{1e89}'),
+    ('\u{78}', '\u{307}', '\u{1e8b}'),
+    ('\u{78}', '\u{308}', '\u{1e8d}'),
+    ('\u{79}', '\u{300}', '\u{1ef3}'),
+    ('\u{79}', '\u{301}', '\u{fd}'),
+    ('\u{79}', '\u{302}', '\u{177}'),
+    ('\u{79}', '\u{303}', '\u{1ef9}'),
+    ('\u{79}', '\u{304}', '\u{233}'),
+    ('\u{79}', '\u{307}', '\u{1e8f}'),
+    ('\u{79}', '\u{308}', '\u{ff}'),
+    ('\u{79}', '\u{309}', '\u{1ef7}'),
+    ('\u{79}', '\u{30a}', '\u{1e99}'),
+    ('\u{79}', '\u{323}', '\u{1ef5}'),
+    ('\u{7a}', '\u{301}', '\u{17a}'),
+    ('\u{7a}', '\u{302}', '\u{1e91}'),
+    ('\u{7a}', '\u{307}', '\u{17c}'),
+    ('\u{7a}', '\u{30c}', '\u{17e}'),
+    ('\u{7a}', '\u{323}', '\u{1e93}'),
+    ('\u{7a}', '\u{331}', '\u{1e95}'),
+    ('\u{a8}', '\u{300}', '\u{1fed}'),
+    ('\u{a8}', '\u{301}', '\u{385}'),
+    ('\u{a8}', '\u{342}', '\u{1fc1}'),
+    ('\u{c2}', '\u{300}', '\u{1ea6}'),
+    ('\u{c2}', '\u{301}', '\u{1ea4}'),
+    ('\u{c2}', '\u{303}', '\u{1eaa}'),
+    ('\u{c2}', '\u{309}', '\u{1ea8}'),
+    ('\u{c4}', '\u{304}', '\u{1de}'),
+    ('\u{c5}', '\u{301}', '\u{1fa}'),
+    ('\u{c6}', '\u{301}', '\u{1fc}'),
+    ('\u{c6}', '\u{304}', '\u{1e2}'),
+    ('\u{c7}', '\u{301}', '\u{1e08}'),
+    ('\u{ca}', '\u{300}', '\u{1ec0}'),
+    ('\u{ca}', '\u{301}', '\u{1ebe}'),
+    ('\u{ca}', '\u{303}', '\u{1ec4}'),
+    ('\u{ca}', '\u{309}', '\u{1ec2}'),
+    ('\u{cf}', '\u{301}', '\u{1e2e}'),
+    ('\u{d4}', '\u{300}', '\u{1ed2}'),
+    ('\u{d4}', '\u{301}', '\u{1ed0}'),
+    ('\u{d4}', '\u{303}', '\u{1ed6}'),
+    ('\u{d4}', '\u{309}', '\u{1ed4}'),
+    ('\u{d5}', '\u{301}', '\u{1e4c}'),
+    ('\u{d5}', '\u{304}', '\u{22c}'),
+    ('\u{d5}', '\u{308}', '\u{1e4e}'),
+    ('\u{d6}', '\u{304}', '\u{22a}'),
+    ('\u{d8}', '\u{301}', '\u{1fe}'),
+    ('\u{dc}', '\u{300}', '\u{1db}'),
+    ('\u{dc}', '\u{301}', '\u{1d7}'),
+    ('\u{dc}', '\u{304}', '\u{1d5}'),
+    ('\u{dc}', '\u{30c}', '\u{1d9}'),
+    ('\u{e2}', '\u{300}', '\u{1ea7}'),
+    ('\u{e2}', '\u{301}', '\u{1ea5}'),
+    ('\u{e2}', '\u{303}', '\u{1eab}'),
+    ('\u{e2}', '\u{309}', '\u{1ea9}'),
+    ('\u{e4}', '\u{304}', '\u{1df}'),
+    ('\u{e5}', '\u{301}', '\u{1fb}'),
+    ('\u{e6}', '\u{301}', '\u{1fd}'),
+    ('\u{e6}', '\u{304}', '\u{1e3}'),
+    ('\u{e7}', '\u{301}', '\u{1e09}'),
+    ('\u{ea}', '\u{300}', '\u{1ec1}'),
+    ('\u{ea}', '\u{301}', '\u{1ebf}'),
+    ('\u{ea}', '\u{303}', '\u{1ec5}'),
+    ('\u{ea}', '\u{309}', '\u{1ec3}'),
+    ('\u{ef}', '\u{301}', '\u{1e2f}'),
+    ('\u{f4}', '\u{300}', '\u{1ed3}'),
+    ('\u{f4}', '\u{301}', '\u{1ed1}'),
+    ('\u{f4}', '\u{303}', '\u{1ed7}'),
+    ('\u{f4}', '\u{309}', '\u{1ed5}'),
+    ('\u{f5}', '\u{301}', '\u{1e4d}'),
+    ('\u{f5}', '\u{304}', '\u{22d}'),
+    ('\u{f5}', '\u{308}', '\u{1e4f}'),
+    ('\u{f6}', '\u{304}', '\u{22b}'),
+    ('\u{f8}', '\u{301}', '\u{1ff}'),
+    ('\u{fc}', '\u{300}', '\u{1dc}'),
+    ('\u{fc}', '\u{301}', '\u{1d8}'),
+    ('\u{fc}', '\u{304}', '\u{1d6}'),
+    ('\u{fc}', '\u{30c}', '\u{1da}'),
+    ('\u{102}', '\u{300}', '\u{1eb0}'),
+    ('\u{102}', '\u{301}', '\u{1eae}'),
+    ('\u{102}', '\u{303}', '\u{1eb4}'),
+    ('\u{102}', '\u{309}', '\u{1eb2}'),
+    ('\u{103}', '\u{300}', '\u{1eb1}'),
+    ('\u{103}', '\u{301}', '\u{1eaf}'),
+    ('\u{103}', '\u{303}', '\u{1eb5}'),
+    ('\u{103}', '\u{309}', '\u{1eb3}'),
+    ('\u{112}', '\u{300}', '\u{1e14}'),
+    ('\u{112}', '\u{301}', '\u{1e16}'),
+    ('\u{113}', '\u{300}', '\u{1e15}'),
+    ('\u{113}', '\u{301}', '\u{1e17}'),
+    ('\u{14c}', '\u{300}', '\u{1e50}'),
+    ('\u{14c}', '\u{301}', '\u{1e52}'),
+    ('\u{14d}', '\u{300}', '\u{1e51}'),
+    ('\u{14d}', '\u{301}', '\u{1e53}'),
+    ('\u{15a}', '\u{307}', '\u{1e64}'),
+    ('\u{15b}', '\u{307}', '\u{1e65}'),
+    ('\u{160}', '\u{307}', '\u{1e66}'),
+    ('\u{161}', '\u{307}', '\u{1e67}'),
+    ('\u{168}', '\u{301}', '\u{1e78}'),
+    ('\u{169}', '\u{301}', '\u{1e79}'),
+    ('\u{16a}', '\u{308}', '\u{1e7a}'),
+    ('\u{16b}', '\u{308}', '\u{1e7b}'),
+    ('\u{17f}', '\u{307}', '\u{1e9b}'),
+    ('\u{1a0}', '\u{300}', '\u{1edc}'),
+    ('\u{1a0}', '\u{301}', '\u{1eda}'),
+    ('\u{1a0}', '\u{303}', '\u{1ee0}'),
+    ('\u{1a0}', '\u{309}', '\u{1ede}'),
+    ('\u{1a0}', '\u{323}', '\u{1ee2}'),
+    ('\u{1a1}', '\u{300}', '\u{1edd}'),
+    ('\u{1a1}', '\u{301}', '\u{1edb}'),
+    ('\u{1a1}', '\u{303}', '\u{1ee1}'),
+    ('\u{1a1}', '\u{309}', '\u{1edf}'),
+    ('\u{1a1}', '\u{323}', '\u{1ee3}'),
+    ('\u{1af}', '\u{300}', '\u{1eea}'),
+    ('\u{1af}', '\u{301}', '\u{1ee8}'),
+    ('\u{1af}', '\u{303}', '\u{1eee}'),
+    ('\u{1af}', '\u{309}', '\u{1eec}'),
+    ('\u{1af}', '\u{323}', '\u{1ef0}'),
+    ('\u{1b0}', '\u{300}', '\u{1eeb}'),
+    ('\u{1b0}', '\u{301}', '\u{1ee9}'),
+    ('\u{1b0}', '\u{303}', '\u{1eef}'),
+    ('\u{1b0}', '\u{309}', '\u{1eed}'),
+    ('\u{1b0}', '\u{323}', '\u{1ef1}'),
+    ('\u{1b7}', '\u{30c}', '\u{1ee}'),
+    ('\u{1ea}', '\u{304}', '\u{1ec}'),
+    ('\u{1eb}', '\u{304}', '\u{1ed}'),
+    ('\u{226}', '\u{304}', '\u{1e0}'),
+    ('\u{227}', '\u{304}', '\u{1e1}'),
+    ('\u{228}', '\u{306}', '\u{1e1c}'),
+    ('\u{229}', '\u{306}', '\u{1e1d}'),
+    ('\u{22e}', '\u{304}', '\u{230}'),
+    ('\u{22f}', '\u{304}', '\u{231}'),
+    ('\u{292}', '\u{30c}', '\u{1ef}'),
+    ('\u{391}', '\u{300}', '\u{1fba}'),
+    ('\u{391}', '\u{301}', '\u{386}'),
+    ('\u{391}', '\u{304}', '\u{1fb9}'),
+    ('\u{391}', '\u{306}', '\u{1fb8}'),
+    ('\u{391}', '\u{313}', '\u{1f08}'),
+    ('\u{391}', '\u{314}', '\u{1f09}'),
+    ('\u{391}', '\u{345}', '\u{1fbc}'),
+    ('\u{395}', '\u{300}', '\u{1fc8}'),
+    ('\u{395}', '\u{301}', '\u{388}'),
+    ('\u{395}', '\u{313}', '\u{1f18}'),
+    ('\u{395}', '\u{314}', '\u{1f19}'),
+    ('\u{397}', '\u{300}', '\u{1fca}'),
+    ('\u{397}', '\u{301}', '\u{389}'),
+    ('\u{397}', '\u{313}', '\u{1f28}'),
+    ('\u{397}', '\u{314}', '\u{1f29}'),
+    ('\u{397}', '\u{345}', '\u{1fcc}'),
+    ('\u{399}', '\u{300}', '\u{1fda}'),
+    ('\u{399}', '\u{301}', '\u{38a}'),
+    ('\u{399}', '\u{304}', '\u{1fd9}'),
+    ('\u{399}', '\u{306}', '\u{1fd8}'),
+    ('\u{399}', '\u{308}', '\u{3aa}'),
+    ('\u{399}', '\u{313}', '\u{1f38}'),
+    ('\u{399}', '\u{314}', '\u{1f39}'),
+    ('\u{39f}', '\u{300}', '\u{1ff8}'),
+    ('\u{39f}', '\u{301}', '\u{38c}'),
+    ('\u{39f}', '\u{313}', '\u{1f48}'),
+    ('\u{39f}', '\u{314}', '\u{1f49}'),
+    ('\u{3a1}', '\u{314}', '\u{1fec}'),
+    ('\u{3a5}', '\u{300}', '\u{1fea}'),
+    ('\u{3a5}', '\u{301}', '\u{38e}'),
+    ('\u{3a5}', '\u{304}', '\u{1fe9}'),
+    ('\u{3a5}', '\u{306}', '\u{1fe8}'),
+    ('\u{3a5}', '\u{308}', '\u{3ab}'),
+    ('\u{3a5}', '\u{314}', '\u{1f59}'),
+    ('\u{3a9}', '\u{300}', '\u{1ffa}'),
+    ('\u{3a9}', '\u{301}', '\u{38f}'),
+    ('\u{3a9}', '\u{313}', '\u{1f68}'),
+    ('\u{3a9}', '\u{314}', '\u{1f69}'),
+    ('\u{3a9}', '\u{345}', '\u{1ffc}'),
+    ('\u{3ac}', '\u{345}', '\u{1fb4}'),
+    ('\u{3ae}', '\u{345}', '\u{1fc4}'),
+    ('\u{3b1}', '\u{300}', '\u{1f70}'),
+    ('\u{3b1}', '\u{301}', '\u{3ac}'),
+    ('\u{3b1}', '\u{304}', '\u{1fb1}'),
+    ('\u{3b1}', '\u{306}', '\u{1fb0}'),
+    ('\u{3b1}', '\u{313}', '\u{1f00}'),
+    ('\u{3b1}', '\u{314}', '\u{1f01}'),
+    ('\u{3b1}', '\u{342}', '\u{1fb6}'),
+    ('\u{3b1}', '\u{345}', '\u{1fb3}'),
+    ('\u{3b5}', '\u{300}', '\u{1f72}'),
+    ('\u{3b5}', '\u{301}', '\u{3ad}'),
+    ('\u{3b5}', '\u{313}', '\u{1f10}'),
+    ('\u{3b5}', '\u{314}', '\u{1f11}'),
+    ('\u{3b7}', '\u{300}', '\u{1f74}'),
+    ('\u{3b7}', '\u{301}', '\u{3ae}'),
+    ('\u{3b7}', '\u{313}', '\u{1f20}'),
+    ('\u{3b7}', '\u{314}', '\u{1f21}'),
+    ('\u{3b7}', '\u{342}', '\u{1fc6}'),
+    ('\u{3b7}', '\u{345}', '\u{1fc3}'),
+    ('\u{3b9}', '\u{300}', '\u{1f76}'),
+    ('\u{3b9}', '\u{301}', '\u{3af}'),
+    ('\u{3b9}', '\u{304}', '\u{1fd1}'),
+    ('\u{3b9}', '\u{306}', '\u{1fd0}'),
+    ('\u{3b9}', '\u{308}', '\u{3ca}'),
+    ('\u{3b9}', '\u{313}', '\u{1f30}'),
+    ('\u{3b9}', '\u{314}', '\u{1f31}'),
+    ('\u{3b9}', '\u{342}', '\u{1fd6}'),
+    ('\u{3bf}', '\u{300}', '\u{1f78}'),
+    ('\u{3bf}', '\u{301}', '\u{3cc}'),
+    ('\u{3bf}', '\u{313}', '\u{1f40}'),
+    ('\u{3bf}', '\u{314}', '\u{1f41}'),
+    ('\u{3c1}', '\u{313}', '\u{1fe4}'),
+    ('\u{3c1}', '\u{314}', '\u{1fe5}'),
+    ('\u{3c5}', '\u{300}', '\u{1f7a}'),
+    ('\u{3c5}', '\u{301}', '\u{3cd}'),
+    ('\u{3c5}', '\u{304}', '\u{1fe1}'),
+    ('\u{3c5}', '\u{306}', '\u{1fe0}'),
+    ('\u{3c5}', '\u{308}', '\u{3cb}'),
+    ('\u{3c5}', '\u{313}', '\u{1f50}'),
+    ('\u{3c5}', '\u{314}', '\u{1f51}'),
+    ('\u{3c5}', '\u{342}', '\u{1fe6}'),
+    ('\u{3c9}', '\u{300}', '\u{1f7c}'),
+    ('\u{3c9}', '\u{301}', '\u{3ce}'),
+    ('\u{3c9}', '\u{313}', '\u{1f60}'),
+    ('\u{3c9}', '\u{314}', '\u{1f61}'),
+    ('\u{3c9}', '\u{342}', '\u{1ff6}'),
+    ('\u{3c9}', '\u{345}', '\u{1ff3}'),
+    ('\u{3ca}', '\u{300}', '\u{1fd2}'),
+    ('\u{3ca}', '\u{301}', '\u{390}'),
+    ('\u{3ca}', '\u{342}', '\u{1fd7}'),
+    ('\u{3cb}', '\u{300}', '\u{1fe2}'),
+    ('\u{3cb}', '\u{301}', '\u{3b0}'),
+    ('\u{3cb}', '\u{342}', '\u{1fe7}'),
+    ('\u{3ce}', '\u{345}', '\u{1ff4}'),
+    ('\u{3d2}', '\u{301}', '\u{3d3}'),
+    ('\u{3d2}', '\u{308}', '\u{3d4}'),
+    ('\u{406}', '\u{308}', '\u{407}'),
+    ('\u{410}', '\u{306}', '\u{4d0}'),
+    ('\u{410}', '\u{308}', '\u{4d2}'),
+    ('\u{413}', '\u{301}', '\u{403}'),
+    ('\u{415}', '\u{300}', '\u{400}'),
+    ('\u{415}', '\u{306}', '\u{4d6}'),
+    ('\u{415}', '\u{308}', '\u{401}'),
+    ('\u{416}', '\u{306}', '\u{4c1}'),
+    ('\u{416}', '\u{308}', '\u{4dc}'),
+    ('\u{417}', '\u{308}', '\u{4de}'),
+    ('\u{418}', '\u{300}', '\u{40d}'),
+    ('\u{418}', '\u{304}', '\u{4e2}'),
+    ('\u{418}', '\u{306}', '\u{419}'),
+    ('\u{418}', '\u{308}', '\u{4e4}'),
+    ('\u{41a}', '\u{301}', '\u{40c}'),
+    ('\u{41e}', '\u{308}', '\u{4e6}'),
+    ('\u{423}', '\u{304}', '\u{4ee}'),
+    ('\u{423}', '\u{306}', '\u{40e}'),
+    ('\u{423}', '\u{308}', '\u{4f0}'),
+    ('\u{423}', '\u{30b}', '\u{4f2}'),
+    ('\u{427}', '\u{308}', '\u{4f4}'),
+    ('\u{42b}', '\u{308}', '\u{4f8}'),
+    ('\u{42d}', '\u{308}', '\u{4ec}'),
+    ('\u{430}', '\u{306}', '\u{4d1}'),
+    ('\u{430}', '\u{308}', '\u{4d3}'),
+    ('\u{433}', '\u{301}', '\u{453}'),
+    ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4d7}'),
+    ('\u{435}', '\u{308}', '\u{451}'),
+    ('\u{436}', '\u{306}', '\u{4c2}'),
+    ('\u{436}', '\u{308}', '\u{4dd}'),
+    ('\u{437}', '\u{308}', '\u{4df}'),
+    ('\u{438}', '\u{300}', '\u{45d}'),
+    ('\u{438}', '\u{304}', '\u{4e3}'),
+    ('\u{438}', '\u{306}', '\u{439}'),
+    ('\u{438}', '\u{308}', '\u{4e5}'),
+    ('\u{43a}', '\u{301}', '\u{45c}'),
+    ('\u{43e}', '\u{308}', '\u{4e7}'),
+    ('\u{443}', '\u{304}', '\u{4ef}'),
+    ('\u{443}', '\u{306}', '\u{45e}'),
+    ('\u{443}', '\u{308}', '\u{4f1}'),
+    ('\u{443}', '\u{30b}', '\u{4f3}'),
+    ('\u{447}', '\u{308}', '\u{4f5}'),
+    ('\u{44b}', '\u{308}', '\u{4f9}'),
+    ('\u{44d}', '\u{308}', '\u{4ed}'),
+    ('\u{456}', '\u{308}', '\u{457}'),
+    ('\u{474}', '\u{30f}', '\u{476}'),
+    ('\u{475}', '\u{30f}', '\u{477}'),
+    ('\u{4d8}', '\u{308}', '\u{4da}'),
+    ('\u{4d9}', '\u{308}', '\u{4db}'),
+    ('\u{4e8}', '\u{308}', '\u{4ea}'),
+    ('\u{4e9}', '\u{308}', '\u{4eb}'),
+    ('\u{627}', '\u{653}', '\u{622}'),
+    ('\u{627}', '\u{654}', '\u{623}'),
+    ('\u{627}', '\u{655}', '\u{625}'),
+    ('\u{648}', '\u{654}', '\u{624}'),
+    ('\u{64a}', '\u{654}', '\u{626}'),
+    ('\u{6c1}', '\u{654}', '\u{6c2}'),
+    ('\u{6d2}', '\u{654}', '\u{6d3}'),
+    ('\u{6d5}', '\u{654}', '\u{6c0}'),
+    ('\u{928}', '\u{93c}', '\u{929}'),
+    ('\u{930}', '\u{93c}', '\u{931}'),
+    ('\u{933}', '\u{93c}', '\u{934}'),
+    ('\u{9c7}', '\u{9be}', '\u{9cb}'),
+    ('\u{9c7}', '\u{9d7}', '\u{9cc}'),
+    ('\u{b47}', '\u{b3e}', '\u{b4b}'),
+    ('\u{b47}', '\u{b56}', '\u{b48}'),
+    ('\u{b47}', '\u{b57}', '\u{b4c}'),
+    ('\u{b92}', '\u{bd7}', '\u{b94}'),
+    ('\u{bc6}', '\u{bbe}', '\u{bca}'),
+    ('\u{bc6}', '\u{bd7}', '\u{bcc}'),
+    ('\u{bc7}', '\u{bbe}', '\u{bcb}'),
+    ('\u{c46}', '\u{c56}', '\u{c48}'),
+    ('\u{cbf}', '\u{cd5}', '\u{cc0}'),
+    ('\u{cc6}', '\u{cc2}', '\u{cca}'),
+    ('\u{cc6}', '\u{cd5}', '\u{cc7}'),
+    ('\u{cc6}', '\u{cd6}', '\u{cc8}'),
+    ('\u{cca}', '\u{cd5}', '\u{ccb}'),
+    ('\u{d46}', '\u{d3e}', '\u{d4a}'),
+    ('\u{d46}', '\u{d57}', '\u{d4c}'),
+    ('\u{d47}', '\u{d3e}', '\u{d4b}'),
+    ('\u{dd9}', '\u{dca}', '\u{dda}'),
+    ('\u{dd9}', '\u{dcf}', '\u{ddc}'),
+    ('\u{dd9}', '\u{ddf}', '\u{dde}'),
+    ('\u{ddc}', '\u{dca}', '\u{ddd}'),
+    ('\u{1025}', '\u{102e}', '\u{1026}'),
+    ('\u{1b05}', '\u{1b35}', '\u{1b06}'),
+    ('\u{1b07}', '\u{1b35}', '\u{1b08}'),
+    ('\u{1b09}', '\u{1b35}', '\u{1b0a}'),
+    ('\u{1b0b}', '\u{1b35}', '\u{1b0c}'),
+    ('\u{1b0d}', '\u{1b35}', '\u{1b0e}'),
+    ('\u{1b11}', '\u{1b35}', '\u{1b12}'),
+    ('\u{1b3a}', '\u{1b35}', '\u{1b3b}'),
+    ('\u{1b3c}', '\u{1b35}', '\u{1b3d}'),
+    ('\u{1b3e}', '\u{1b35}', '\u{1b40}'),
+    ('\u{1b3f}', '\u{1b35}', '\u{1b41}'),
+    ('\u{1b42}', '\u{1b35}', '\u{1b43}'),
+    ('\u{1e36}', '\u{304}', '\u{1e38}'),
+    ('\u{1e37}', '\u{304}', '\u{1e39}'),
+    ('\u{1e5a}', '\u{304}', '\u{1e5c}'),
+    ('\u{1e5b}', '\u{304}', '\u{1e5d}'),
+    ('\u{1e62}', '\u{307}', '\u{1e68}'),
+    ('\u{1e63}', '\u{307}', '\u{1e69}'),
+    ('\u{1ea0}', '\u{302}', '\u{1eac}'),
+    ('\u{1ea0}', '\u{306}', '\u{1eb6}'),
+    ('\u{1ea1}', '\u{302}', '\u{1ead}'),
+    ('\u{1ea1}', '\u{306}', '\u{1eb7}'),
+    ('\u{1eb8}', '\u{302}', '\u{1ec6}'),
+    ('\u{1eb9}', '\u{302}', '\u{1ec7}'),
+    ('\u{1ecc}', '\u{302}', '\u{1ed8}'),
+    ('\u{1ecd}', '\u{302}', '\u{1ed9}'),
+    ('\u{1f00}', '\u{300}', '\u{1f02}'),
+    ('\u{1f00}', '\u{301}', '\u{1f04}'),
+    ('\u{1f00}', '\u{342}', '\u{1f06}'),
+    ('\u{1f00}', '\u{345}', '\u{1f80}'),
+    ('\u{1f01}', '\u{300}', '\u{1f03}'),
+    ('\u{1f01}', '\u{301}', '\u{1f05}'),
+    ('\u{1f01}', '\u{342}', '\u{1f07}'),
+    ('\u{1f01}', '\u{345}', '\u{1f81}'),
+    ('\u{1f02}', '\u{345}', '\u{1f82}'),
+    ('\u{1f03}', '\u{345}', '\u{1f83}'),
+    ('\u{1f04}', '\u{345}', '\u{1f84}'),
+    ('\u{1f05}', '\u{345}', '\u{1f85}'),
+    ('\u{1f06}', '\u{345}', '\u{1f86}'),
+    ('\u{1f07}', '\u{345}', '\u{1f87}'),
+    ('\u{1f08}', '\u{300}', '\u{1f0a}'),
+    ('\u{1f08}', '\u{301}', '\u{1f0c}'),
+    ('\u{1f08}', '\u{342}', '\u{1f0e}'),
+    ('\u{1f08}', '\u{345}', '\u{1f88}'),
+    ('\u{1f09}', '\u{300}', '\u{1f0b}'),
+    ('\u{1f09}', '\u{301}', '\u{1f0d}'),
+    ('\u{1f09}', '\u{342}', '\u{1f0f}'),
+    ('\u{1f09}', '\u{345}', '\u{1f89}'),
+    ('\u{1f0a}', '\u{345}', '\u{1f8a}'),
+    ('\u{1f0b}', '\u{345}', '\u{1f8b}'),
+    ('\u{1f0c}', '\u{345}', '\u{1f8c}'),
+    ('\u{1f0d}', '\u{345}', '\u{1f8d}'),
+    ('\u{1f0e}', '\u{345}', '\u{1f8e}'),
+    ('\u{1f0f}', '\u{345}', '\u{1f8f}'),
+    ('\u{1f10}', '\u{300}', '\u{1f12}'),
+    ('\u{1f10}', '\u{301}', '\u{1f14}'),
+    ('\u{1f11}', '\u{300}', '\u{1f13}'),
+    ('\u{1f11}', '\u{301}', '\u{1f15}'),
+    ('\u{1f18}', '\u{300}', '\u{1f1a}'),
+    ('\u{1f18}', '\u{301}', '\u{1f1c}'),
+    ('\u{1f19}', '\u{300}', '\u{1f1b}'),
+    ('\u{1f19}', '\u{301}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{300}', '\u{1f22}'),
+    ('\u{1f20}', '\u{301}', '\u{1f24}'),
+    ('\u{1f20}', '\u{342}', '\u{1f26}'),
+    ('\u{1f20}', '\u{345}', '\u{1f90}'),
+    ('\u{1f21}', '\u{300}', '\u{1f23}'),
+    ('\u{1f21}', '\u{301}', '\u{1f25}'),
+    ('\u{1f21}', '\u{342}', '\u{1f27}'),
+    ('\u{1f21}', '\u{345}', '\u{1f91}'),
+    ('\u{1f22}', '\u{345}', '\u{1f92}'),
+    ('\u{1f23}', '\u{345}', '\u{1f93}'),
+    ('\u{1f24}', '\u{345}', '\u{1f94}'),
+    ('\u{1f25}', '\u{345}', '\u{1f95}'),
+    ('\u{1f26}', '\u{345}', '\u{1f96}'),
+    ('\u{1f27}', '\u{345}', '\u{1f97}'),
+    ('\u{1f28}', '\u{300}', '\u{1f2a}'),
+    ('\u{1f28}', '\u{301}', '\u{1f2c}'),
+    ('\u{1f28}', '\u{342}', '\u{1f2e}'),
+    ('\u{1f28}', '\u{345}', '\u{1f98}'),
+    ('\u{1f29}', '\u{300}', '\u{1f2b}'),
+    ('\u{1f29}', '\u{301}', '\u{1f2d}'),
+    ('\u{1f29}', '\u{342}', '\u{1f2f}'),
+    ('\u{1f29}', '\u{345}', '\u{1f99}'),
+    ('\u{1f2a}', '\u{345}', '\u{1f9a}'),
+    ('\u{1f2b}', '\u{345}', '\u{1f9b}'),
+    ('\u{1f2c}', '\u{345}', '\u{1f9c}'),
+    ('\u{1f2d}', '\u{345}', '\u{1f9d}'),
+    ('\u{1f2e}', '\u{345}', '\u{1f9e}'),
+    ('\u{1f2f}', '\u{345}', '\u{1f9f}'),
+    ('\u{1f30}', '\u{300}', '\u{1f32}'),
+    ('\u{1f30}', '\u{301}', '\u{1f34}'),
+    ('\u{1f30}', '\u{342}', '\u{1f36}'),
+    ('\u{1f31}', '\u{300}', '\u{1f33}'),
+    ('\u{1f31}', '\u{301}', '\u{1f35}'),
+    ('\u{1f31}', '\u{342}', '\u{1f37}'),
+    ('\u{1f38}', '\u{300}', '\u{1f3a}'),
+    ('\u{1f38}', '\u{301}', '\u{1f3c}'),
+    ('\u{1f38}', '\u{342}', '\u{1f3e}'),
+    ('\u{1f39}', '\u{300}', '\u{1f3b}'),
+    ('\u{1f39}', '\u{301}', '\u{1f3d}'),
+    ('\u{1f39}', '\u{342}', '\u{1f3f}'),
+    ('\u{1f40}', '\u{300}', '\u{1f42}'),
+    ('\u{1f40}', '\u{301}', '\u{1f44}'),
+    ('\u{1f41}', '\u{300}', '\u{1f43}'),
+    ('\u{1f41}', '\u{301}', '\u{1f45}'),
+    ('\u{1f48}', '\u{300}', '\u{1f4a}'),
+    ('\u{1f48}', '\u{301}', '\u{1f4c}'),
+    ('\u{1f49}', '\u{300}', '\u{1f4b}'),
+    ('\u{1f49}', '\u{301}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{300}', '\u{1f52}'),
+    ('\u{1f50}', '\u{301}', '\u{1f54}'),
+    ('\u{1f50}', '\u{342}', '\u{1f56}'),
+    ('\u{1f51}', '\u{300}', '\u{1f53}'),
+    ('\u{1f51}', '\u{301}', '\u{1f55}'),
+    ('\u{1f51}', '\u{342}', '\u{1f57}'),
+    ('\u{1f59}', '\u{300}', '\u{1f5b}'),
+    ('\u{1f59}', '\u{301}', '\u{1f5d}'),
+    ('\u{1f59}', '\u{342}', '\u{1f5f}'),
+    ('\u{1f60}', '\u{300}', '\u{1f62}'),
+    ('\u{1f60}', '\u{301}', '\u{1f64}'),
+    ('\u{1f60}', '\u{342}', '\u{1f66}'),
+    ('\u{1f60}', '\u{345}', '\u{1fa0}'),
+    ('\u{1f61}', '\u{300}', '\u{1f63}'),
+    ('\u{1f61}', '\u{301}', '\u{1f65}'),
+    ('\u{1f61}', '\u{342}', '\u{1f67}'),
+    ('\u{1f61}', '\u{345}', '\u{1fa1}'),
+    ('\u{1f62}', '\u{345}', '\u{1fa2}'),
+    ('\u{1f63}', '\u{345}', '\u{1fa3}'),
+    ('\u{1f64}', '\u{345}', '\u{1fa4}'),
+    ('\u{1f65}', '\u{345}', '\u{1fa5}'),
+    ('\u{1f66}', '\u{345}', '\u{1fa6}'),
+    ('\u{1f67}', '\u{345}', '\u{1fa7}'),
+    ('\u{1f68}', '\u{300}', '\u{1f6a}'),
+    ('\u{1f68}', '\u{301}', '\u{1f6c}'),
+    ('\u{1f68}', '\u{342}', '\u{1f6e}'),
+    ('\u{1f68}', '\u{345}', '\u{1fa8}'),
+    ('\u{1f69}', '\u{300}', '\u{1f6b}'),
+    ('\u{1f69}', '\u{301}', '\u{1f6d}'),
+    ('\u{1f69}', '\u{342}', '\u{1f6f}'),
+    ('\u{1f69}', '\u{345}', '\u{1fa9}'),
+    ('\u{1f6a}', '\u{345}', '\u{1faa}'),
+    ('\u{1f6b}', '\u{345}', '\u{1fab}'),
+    ('\u{1f6c}', '\u{345}', '\u{1fac}'),
+    ('\u{1f6d}', '\u{345}', '\u{1fad}'),
+    ('\u{1f6e}', '\u{345}', '\u{1fae}'),
+    ('\u{1f6f}', '\u{345}', '\u{1faf}'),
+    ('\u{1f70}', '\u{345}', '\u{1fb2}'),
+    ('\u{1f74}', '\u{345}', '\u{1fc2}'),
+    ('\u{1f7c}', '\u{345}', '\u{1ff2}'),
+    ('\u{1fb6}', '\u{345}', '\u{1fb7}'),
+    ('\u{1fbf}', '\u{300}', '\u{1fcd}'),
+    ('\u{1fbf}', '\u{301}', '\u{1fce}'),
+    ('\u{1fbf}', '\u{342}', '\u{1fcf}'),
+    ('\u{1fc6}', '\u{345}', '\u{1fc7}'),
+    ('\u{1ff6}', '\u{345}', '\u{1ff7}'),
+    ('\u{1ffe}', '\u{300}', '\u{1fdd}'),
+    ('\u{1ffe}', '\u{301}', '\u{1fde}'),
+    ('\u{1ffe}', '\u{342}', '\u{1fdf}'),
+    ('\u{2190}', '\u{338}', '\u{219a}'),
+    ('\u{2192}', '\u{338}', '\u{219b}'),
+    ('\u{2194}', '\u{338}', '\u{21ae}'),
+    ('\u{21d0}', '\u{338}', '\u{21cd}'),
+    ('\u{21d2}', '\u{338}', '\u{21cf}'),
+    ('\u{21d4}', '\u{338}', '\u{21ce}'),
+    ('\u{2203}', '\u{338}', '\u{2204}'),
+    ('\u{2208}', '\u{338}', '\u{2209}'),
+    ('\u{220b}', '\u{338}', '\u{220c}'),
+    ('\u{2223}', '\u{338}', '\u{2224}'),
+    ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223c}', '\u{338}', '\u{2241}'),
+    ('\u{2243}', '\u{338}', '\u{2244}'),
+    ('\u{2245}', '\u{338}', '\u{2247}'),
+    ('\u{2248}', '\u{338}', '\u{2249}'),
+    ('\u{224d}', '\u{338}', '\u{226d}'),
+    ('\u{2261}', '\u{338}', '\u{2262}'),
+    ('\u{2264}', '\u{338}', '\u{2270}'),
+    ('\u{2265}', '\u{338}', '\u{2271}'),
+    ('\u{2272}', '\u{338}', '\u{2274}'),
+    ('\u{2273}', '\u{338}', '\u{2275}'),
+    ('\u{2276}', '\u{338}', '\u{2278}'),
+    ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227a}', '\u{338}', '\u{2280}'),
+    ('\u{227b}', '\u{338}', '\u{2281}'),
+    ('\u{227c}', '\u{338}', '\u{22e0}'),
+    ('\u{227d}', '\u{338}', '\u{22e1}'),
+    ('\u{2282}', '\u{338}', '\u{2284}'),
+    ('\u{2283}', '\u{338}', '\u{2285}'),
+    ('\u{2286}', '\u{338}', '\u{2288}'),
+    ('\u{2287}', '\u{338}', '\u{2289}'),
+    ('\u{2291}', '\u{338}', '\u{22e2}'),
+    ('\u{2292}', '\u{338}', '\u{22e3}'),
+    ('\u{22a2}', '\u{338}', '\u{22ac}'),
+    ('\u{22a8}', '\u{338}', '\u{22ad}'),
+    ('\u{22a9}', '\u{338}', '\u{22ae}'),
+    ('\u{22ab}', '\u{338}', '\u{22af}'),
+    ('\u{22b2}', '\u{338}', '\u{22ea}'),
+    ('\u{22b3}', '\u{338}', '\u{22eb}'),
+    ('\u{22b4}', '\u{338}', '\u{22ec}'),
+    ('\u{22b5}', '\u{338}', '\u{22ed}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'),
+    ('\u{304b}', '\u{3099}', '\u{304c}'),
+    ('\u{304d}', '\u{3099}', '\u{304e}'),
+    ('\u{304f}', '\u{3099}', '\u{3050}'),
+    ('\u{3051}', '\u{3099}', '\u{3052}'),
+    ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'),
+    ('\u{3057}', '\u{3099}', '\u{3058}'),
+    ('\u{3059}', '\u{3099}', '\u{305a}'),
+    ('\u{305b}', '\u{3099}', '\u{305c}'),
+    ('\u{305d}', '\u{3099}', '\u{305e}'),
+    ('\u{305f}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'),
+    ('\u{3064}', '\u{3099}', '\u{3065}'),
+    ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'),
+    ('\u{306f}', '\u{3099}', '\u{3070}'),
+    ('\u{306f}', '\u{309a}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'),
+    ('\u{3072}', '\u{309a}', '\u{3074}'),
+    ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309a}', '\u{3077}'),
+    ('\u{3078}', '\u{3099}', '\u{3079}'),
+    ('\u{3078}', '\u{309a}', '\u{307a}'),
+    ('\u{307b}', '\u{3099}', '\u{307c}'),
+    ('\u{307b}', '\u{309a}', '\u{307d}'),
+    ('\u{309d}', '\u{3099}', '\u{309e}'),
+    ('\u{30a6}', '\u{3099}', '\u{30f4}'),
+    ('\u{30ab}', '\u{3099}', '\u{30ac}'),
+    ('\u{30ad}', '\u{3099}', '\u{30ae}'),
+    ('\u{30af}', '\u{3099}', '\u{30b0}'),
+    ('\u{30b1}', '\u{3099}', '\u{30b2}'),
+    ('\u{30b3}', '\u{3099}', '\u{30b4}'),
+    ('\u{30b5}', '\u{3099}', '\u{30b6}'),
+    ('\u{30b7}', '\u{3099}', '\u{30b8}'),
+    ('\u{30b9}', '\u{3099}', '\u{30ba}'),
+    ('\u{30bb}', '\u{3099}', '\u{30bc}'),
+    ('\u{30bd}', '\u{3099}', '\u{30be}'),
+    ('\u{30bf}', '\u{3099}', '\u{30c0}'),
+    ('\u{30c1}', '\u{3099}', '\u{30c2}'),
+    ('\u{30c4}', '\u{3099}', '\u{30c5}'),
+    ('\u{30c6}', '\u{3099}', '\u{30c7}'),
+    ('\u{30c8}', '\u{3099}', '\u{30c9}'),
+    ('\u{30cf}', '\u{3099}', '\u{30d0}'),
+    ('\u{30cf}', '\u{309a}', '\u{30d1}'),
+    ('\u{30d2}', '\u{3099}', '\u{30d3}'),
+    ('\u{30d2}', '\u{309a}', '\u{30d4}'),
+    ('\u{30d5}', '\u{3099}', '\u{30d6}'),
+    ('\u{30d5}', '\u{309a}', '\u{30d7}'),
+    ('\u{30d8}', '\u{3099}', '\u{30d9}'),
+    ('\u{30d8}', '\u{309a}', '\u{30da}'),
+    ('\u{30db}', '\u{3099}', '\u{30dc}'),
+    ('\u{30db}', '\u{309a}', '\u{30dd}'),
+    ('\u{30ef}', '\u{3099}', '\u{30f7}'),
+    ('\u{30f0}', '\u{3099}', '\u{30f8}'),
+    ('\u{30f1}', '\u{3099}', '\u{30f9}'),
+    ('\u{30f2}', '\u{3099}', '\u{30fa}'),
+    ('\u{30fd}', '\u{3099}', '\u{30fe}'),
+    ('\u{11099}', '\u{110ba}', '\u{1109a}'),
+    ('\u{1109b}', '\u{110ba}', '\u{1109c}'),
+    ('\u{110a5}', '\u{110ba}', '\u{110ab}'),
+    ('\u{11131}', '\u{11127}', '\u{1112e}'),
+    ('\u{11132}', '\u{11127}', '\u{1112f}'),
+    ('\u{11347}', '\u{1133e}', '\u{1134b}'),
+    ('\u{11347}', '\u{11357}', '\u{1134c}'),
+    ('\u{114b9}', '\u{114b0}', '\u{114bc}'),
+    ('\u{114b9}', '\u{114ba}', '\u{114bb}'),
+    ('\u{114b9}', '\u{114bd}', '\u{114be}'),
+    ('\u{115b8}', '\u{115af}', '\u{115ba}'),
+    ('\u{115b9}', '\u{115af}', '\u{115bb}'),
+    ('\u{11935}', '\u{11930}', '\u{11938}'),
+];
@@ -0,0 +1,72 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::{Property, Symbol};
+use crate::scope::Scope;
+use crate::types::Value;
+use super::{argument, define_getter, define_method, define_to_string_tag, link_constructor, make_native_function, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let prototype = &intrinsics.symbol_prototype;
+    let function_prototype = &intrinsics.function_prototype;
+
+    let constructor = make_native_function(function_prototype, "Symbol", 0, Rc::new(|interpreter, _, args| {
+        if interpreter.new_target().is_some() {
+            return Err(interpreter.throw_error(ErrorKind::TypeError, "Symbol is not a constructor"));
+        }
+
+        let description = match argument(args, 0) {
+            Value::None => None,
+            description => Some(interpreter.to_js_string(&description)?.to_string()),
+        };
+
+        Ok(Value::Symbol(Symbol::new(description)))
+    }));
+
+    link_constructor(&constructor, prototype);
+
+    let well_known = [
+        ("iterator", &intrinsics.symbol_iterator),
+        ("asyncIterator", &intrinsics.symbol_async_iterator),
+        ("toStringTag", &intrinsics.symbol_to_string_tag),
+    ];
+
+    for (name, symbol) in well_known {
+        let property = Property { writable: false, configurable: false, ..Property::hidden(Value::Symbol(symbol.clone())) };
+        constructor.borrow_mut().properties.insert(name, property);
+    }
+
+    define_method(prototype, function_prototype, "toString", 0, |interpreter, this, _| {
+        let symbol = this_symbol(interpreter, this, "Symbol.prototype.toString")?;
+        Ok(Value::StringLiteral(symbol.to_string().into()))
+    });
+
+    define_method(prototype, function_prototype, "valueOf", 0, |interpreter, this, _| {
+        let symbol = this_symbol(interpreter, this, "Symbol.prototype.valueOf")?;
+        Ok(Value::Symbol(symbol))
+    });
+
+    define_getter(prototype, function_prototype, "description", |interpreter, this, _| {
+        let symbol = this_symbol(interpreter, this, "Symbol.prototype.description")?;
+
+        match symbol.description() {
+            Some(description) => Ok(Value::StringLiteral(description.into())),
+            None => Ok(Value::None),
+        }
+    });
+
+    define_to_string_tag(intrinsics, prototype, "Symbol");
+
+    scope.borrow_mut().define("Symbol".to_string(), Value::Object(constructor));
+}
+
+fn this_symbol(interpreter: &mut Interpreter, this: &Value, method: &str) -> EvalResult<Symbol> {
+    match this {
+        Value::Symbol(symbol) => Ok(symbol.clone()),
+        _ => {
+            let message = format!("{} requires that 'this' be a Symbol", method);
+            Err(interpreter.throw_error(ErrorKind::TypeError, message))
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::NativeFunction;
+use crate::scope::Scope;
+use crate::types::Value;
+use super::{argument, make_native_function, Intrinsics};
+
+pub fn install(scope: &Rc<RefCell<Scope>>, intrinsics: &Intrinsics) {
+    let functions: [(&str, usize, NativeFunction); 5] = [
+        ("setTimeout", 2, Rc::new(|interpreter, _, args| set_timer(interpreter, args, false))),
+        ("setInterval", 2, Rc::new(|interpreter, _, args| set_timer(interpreter, args, true))),
+        ("clearTimeout", 1, Rc::new(clear_timer)),
+        ("clearInterval", 1, Rc::new(clear_timer)),
+        ("queueMicrotask", 1, Rc::new(|interpreter, _, args| {
+            let callback = argument(args, 0);
+            interpreter.check_callback(&callback)?;
+            interpreter.queue_microtask(callback);
+            Ok(Value::None)
+        })),
+    ];
+
+    let mut scope = scope.borrow_mut();
+
+    for (name, length, function) in functions {
+        let function = make_native_function(&intrinsics.function_prototype, name, length, function);
+        scope.define(name.to_string(), Value::Object(function));
+    }
+}
+
+/// `setTimeout(callback, delay, ...args)` and `setInterval`, returning the id of the timer.
+fn set_timer(interpreter: &mut Interpreter, args: &[Value], repeat: bool) -> EvalResult {
+    let callback = argument(args, 0);
+    interpreter.check_callback(&callback)?;
+
+    let delay = interpreter.to_timer_delay(&argument(args, 1))?;
+    let args = args.iter().skip(2).cloned().collect();
+
+    Ok(Value::Float(interpreter.set_timer(callback, delay, args, repeat) as f64))
+}
+
+/// `clearTimeout(id)` and `clearInterval(id)`, which both cancel any kind of timer.
+fn clear_timer(interpreter: &mut Interpreter, _: &Value, args: &[Value]) -> EvalResult {
+    if let Value::Float(id) = argument(args, 0) {
+        if id.fract() == 0.0 && id >= 1.0 && id <= u32::MAX as f64 {
+            interpreter.clear_timer(id as u32);
+        }
+    }
+
+    Ok(Value::None)
+}
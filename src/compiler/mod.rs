@@ -0,0 +1,1366 @@
+use std::cell::OnceCell;
+use std::fmt;
+use std::rc::Rc;
+use crate::interpreter::{InlineCache, Interpreter};
+use crate::object::PropertyKey;
+use crate::regexp::Regex;
+use crate::scope::{Scope, Slot};
+use crate::types::{DeclarationKind, Expr, ForBinding, FunctionDef, ObjectMember, Pattern, PropertyName, Stmt, Token, Value, intern};
+
+mod resolver;
+
+pub use resolver::{Resolution, Resolver};
+
+/// An operation of the virtual machine, which works on a stack of operands. Operands are indices
+/// into the tables of the chunk the operation belongs to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Constant(u32),
+    Undefined,
+    Null,
+    True,
+    False,
+    This,
+    Closure(u32),
+    // creates a new object for a regular expression literal
+    RegExp(u32),
+    // a function expression named after the binding or property it is assigned to
+    NamedClosure(u32, u32),
+    // collects that many values into an array
+    Array(u32),
+    // creates an object with room for that many properties, which the next operations define
+    Object(u32),
+    DefineField(u32),
+    DefineComputedField,
+
+    Pop,
+    Dup,
+    // duplicates the top two values, keeping their order
+    Dup2,
+    // drops the value below the top one
+    Nip,
+
+    GetName(u32),
+    // assigns to an existing binding, leaving the value on the stack
+    SetName(u32),
+    // the same for bindings the resolver found a slot for, which fall back to the name
+    GetSlot(Slot, u32),
+    SetSlot(Slot, u32),
+    TypeOfName(u32),
+    // binds a declared identifier, declaring `let` and `const` in the current scope
+    Declare(DeclarationKind, u32),
+    BindPattern(u32, Option<DeclarationKind>),
+    // creates a hoisted function declaration in the current scope
+    DefineFunction(u32),
+    // defines a binding without checking for an existing one, as catch parameters are
+    DefineName(u32),
+    HoistVars(u32),
+    UpdateName(u32, Update),
+    UpdateSlot(Slot, u32, Update),
+
+    // accesses by name remember where they found the property in the inline cache they are given
+    GetProperty(u32, u32),
+    GetComputed,
+    // assigns to a property, leaving the value on the stack
+    SetProperty(u32, u32),
+    SetComputed,
+    // converts an object used as a key right away, as its `toString` may have side effects
+    ToPropertyKey,
+    // replaces an object with the method it has under the key and the object as `this`
+    GetMethod(u32, u32),
+    GetMethodComputed,
+    UpdateProperty(u32, Update),
+    UpdateComputed(Update),
+
+    Binary(BinaryOperator),
+    Not,
+    Negate,
+    Plus,
+    BitwiseNot,
+    TypeOf,
+    Void,
+
+    Jump(u32),
+    JumpIfFalse(u32),
+    // jump keeping the value when it decides the outcome of `&&`, `||` and `??`, and pop it otherwise
+    JumpIfFalseOrPop(u32),
+    JumpIfTrueOrPop(u32),
+    JumpIfNotNullishOrPop(u32),
+    // calls the function below `this` and that many arguments, with a description for errors
+    Call(u32, u32),
+    // a call in tail position, which runs the callee in the frame of the function making it
+    TailCall(u32, u32),
+    New(u32, u32),
+    Return,
+    Throw,
+
+    EnterScope,
+    ExitScope,
+    CopyIterationScope(u32),
+    // catches exceptions thrown until the handler is popped, continuing at the target with the
+    // exception on the stack
+    PushHandler(u32),
+    PopHandler,
+    // replaces an iterable with its iterator and `next` method, which `for...of` keeps on the stack
+    GetIterator,
+    // pushes the next value of the iterator on top, or jumps once it is done
+    IteratorNext(u32),
+    // lets the iterator on top end early, or the one below the exception on top, which it rethrows
+    CloseIterator,
+    CloseIteratorAndThrow,
+    // replaces what `for...in` goes through with the object, its keys and where the loop is in them
+    ForInKeys,
+    // pushes the next key the object still has, or jumps once there are none left
+    ForInNext(u32),
+
+    // charges a statement against the limits
+    Step,
+    // the completion value of a script, which is what `eval` returns
+    SetCompletion,
+    ClearCompletion,
+    ReturnCompletion,
+
+    // evaluates an expression or runs a statement the compiler leaves to the tree-walker
+    Eval(u32),
+    Exec(u32),
+}
+
+/// How an increment or decrement applies.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Update {
+    pub increment: bool,
+    // whether the new value is the result, as in `++x`, rather than the old one
+    pub prefix: bool,
+}
+
+impl Update {
+    pub fn delta(self) -> f64 {
+        match self.increment {
+            true => 1.0,
+            false => -1.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOperator {
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+    Modulo,
+    Exponentiation,
+    Equals,
+    TypeCheckEquals,
+    NotEquals,
+    TypeNotEquals,
+    GreaterThan,
+    GreaterThanEquals,
+    LessThan,
+    LessThanEquals,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    LeftShift,
+    RightShift,
+    UnsignedRightShift,
+    InstanceOf,
+    In,
+}
+
+impl BinaryOperator {
+    fn from_token(token: &Token) -> Option<Self> {
+        let operator = match token {
+            Token::Addition => BinaryOperator::Addition,
+            Token::Subtraction => BinaryOperator::Subtraction,
+            Token::Multiplication => BinaryOperator::Multiplication,
+            Token::Division => BinaryOperator::Division,
+            Token::Modulo => BinaryOperator::Modulo,
+            Token::Exponentiation => BinaryOperator::Exponentiation,
+            Token::Equals => BinaryOperator::Equals,
+            Token::TypeCheckEquals => BinaryOperator::TypeCheckEquals,
+            Token::NotEquals => BinaryOperator::NotEquals,
+            Token::TypeNotEquals => BinaryOperator::TypeNotEquals,
+            Token::GreaterThan => BinaryOperator::GreaterThan,
+            Token::GreaterThanEquals => BinaryOperator::GreaterThanEquals,
+            Token::LessThan => BinaryOperator::LessThan,
+            Token::LessThanEquals => BinaryOperator::LessThanEquals,
+            Token::BitwiseAnd => BinaryOperator::BitwiseAnd,
+            Token::BitwiseOr => BinaryOperator::BitwiseOr,
+            Token::BitwiseXor => BinaryOperator::BitwiseXor,
+            Token::LeftShift => BinaryOperator::LeftShift,
+            Token::RightShift => BinaryOperator::RightShift,
+            Token::UnsignedRightShift => BinaryOperator::UnsignedRightShift,
+            Token::Instanceof => BinaryOperator::InstanceOf,
+            Token::In => BinaryOperator::In,
+            _ => return None,
+        };
+
+        Some(operator)
+    }
+
+    /// The token `Interpreter::apply_operator` knows the operator by.
+    pub fn token(self) -> Token {
+        match self {
+            BinaryOperator::Addition => Token::Addition,
+            BinaryOperator::Subtraction => Token::Subtraction,
+            BinaryOperator::Multiplication => Token::Multiplication,
+            BinaryOperator::Division => Token::Division,
+            BinaryOperator::Modulo => Token::Modulo,
+            BinaryOperator::Exponentiation => Token::Exponentiation,
+            BinaryOperator::Equals => Token::Equals,
+            BinaryOperator::TypeCheckEquals => Token::TypeCheckEquals,
+            BinaryOperator::NotEquals => Token::NotEquals,
+            BinaryOperator::TypeNotEquals => Token::TypeNotEquals,
+            BinaryOperator::GreaterThan => Token::GreaterThan,
+            BinaryOperator::GreaterThanEquals => Token::GreaterThanEquals,
+            BinaryOperator::LessThan => Token::LessThan,
+            BinaryOperator::LessThanEquals => Token::LessThanEquals,
+            BinaryOperator::BitwiseAnd => Token::BitwiseAnd,
+            BinaryOperator::BitwiseOr => Token::BitwiseOr,
+            BinaryOperator::BitwiseXor => Token::BitwiseXor,
+            BinaryOperator::LeftShift => Token::LeftShift,
+            BinaryOperator::RightShift => Token::RightShift,
+            BinaryOperator::UnsignedRightShift => Token::UnsignedRightShift,
+            BinaryOperator::InstanceOf => Token::Instanceof,
+            BinaryOperator::In => Token::In,
+        }
+    }
+}
+
+/// A statement run by the tree-walker, with where to go when it breaks out of or continues the
+/// loop around it.
+#[derive(Debug)]
+pub struct ExecSite {
+    pub stmt: Stmt,
+    pub on_break: Option<u32>,
+    pub on_continue: Option<u32>,
+}
+
+/// A script or function body compiled to operations, with the tables they refer to.
+#[derive(Debug, Default)]
+pub struct Chunk {
+    pub code: Vec<Op>,
+    pub constants: Vec<Value>,
+    pub names: Vec<Rc<str>>,
+    pub keys: Vec<PropertyKey>,
+    pub caches: Vec<InlineCache>,
+    pub functions: Vec<Rc<FunctionDef>>,
+    pub regexps: Vec<Rc<Regex>>,
+    pub patterns: Vec<Pattern>,
+    pub name_lists: Vec<Vec<Rc<str>>>,
+    // how callees are described when they turn out not to be functions
+    pub descriptions: Vec<String>,
+    pub exprs: Vec<Expr>,
+    pub execs: Vec<ExecSite>,
+    // references to names no scope declares, including in the functions compiled along with it
+    pub warnings: Vec<String>,
+}
+
+/// The compiled body of a function, created the first time the virtual machine calls it.
+#[derive(Clone, Default)]
+pub struct Bytecode(OnceCell<Rc<Chunk>>);
+
+impl Bytecode {
+    /// The compiled body of `def`, compiling it on its own if it wasn't along with its surroundings.
+    pub fn get_or_compile(&self, def: &FunctionDef) -> Rc<Chunk> {
+        self.0.get_or_init(|| Rc::new(Compiler::compile_function(def))).clone()
+    }
+}
+
+impl fmt::Debug for Bytecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.get() {
+            Some(chunk) => write!(f, "Bytecode({} ops)", chunk.code.len()),
+            None => write!(f, "Bytecode(not compiled)"),
+        }
+    }
+}
+
+// the compiled body is derived from the source, so it doesn't tell functions apart
+impl PartialEq for Bytecode {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+/// What the statements being compiled are nested in, for `break` and `continue` to unwind.
+enum Control {
+    Scope,
+    Handler,
+    // the iterator of a `for...of` loop and its current value, which leaving the loop closes
+    Iterator,
+    // values kept on the stack, such as the keys of a `for...in` loop
+    Operands(usize),
+    // a `finally` block, which runs before a jump out of its `try` leaves, and how many scopes the
+    // resolver had around the `try`
+    Finally(Stmt, usize),
+    // the jumps to patch once the targets of the loop are known
+    Loop { breaks: Vec<usize>, continues: Vec<usize> },
+}
+
+/// Compiles statements and expressions to a `Chunk`. Whatever isn't compiled, such as spreads and
+/// `delete`, is left to the tree-walker, which runs it in the same scope.
+pub struct Compiler {
+    chunk: Chunk,
+    controls: Vec<Control>,
+    resolver: Resolver,
+    // whether the functions compiled along the way are kept for when they're called
+    keep: bool,
+}
+
+impl Compiler {
+    fn new(resolver: Resolver, keep: bool) -> Self {
+        Compiler { chunk: Chunk::default(), controls: Vec::new(), resolver, keep }
+    }
+
+    /// Compiles a script that runs in `global`, which evaluates to the value of the last statement
+    /// that has one. The functions it defines are compiled along with it, resolving what they
+    /// refer to in the scopes around them.
+    pub fn compile_script(stmts: &[Stmt], global: &Scope) -> Chunk {
+        let mut compiler = Compiler::new(Resolver::for_script(global, stmts), true);
+        compiler.compile_body(stmts, true);
+        compiler.emit(Op::ReturnCompletion);
+        compiler.chunk
+    }
+
+    /// The warnings compiling a script produces, without keeping any of its code, for scripts that
+    /// run some other way.
+    pub fn check_script(stmts: &[Stmt], global: &Scope) -> Vec<String> {
+        let mut compiler = Compiler::new(Resolver::for_script(global, stmts), false);
+        compiler.compile_body(stmts, true);
+        compiler.chunk.warnings
+    }
+
+    /// Compiles a function without the code around it, so only its own bindings get slots.
+    pub fn compile_function(def: &FunctionDef) -> Chunk {
+        Self::compile_function_in(def, Resolver::default(), true)
+    }
+
+    fn compile_function_in(def: &FunctionDef, mut resolver: Resolver, keep: bool) -> Chunk {
+        resolver.enter_function(def);
+
+        let mut compiler = Compiler::new(resolver, keep);
+        compiler.compile_body(&def.body, false);
+        compiler.emit(Op::Undefined);
+        compiler.emit(Op::Return);
+        compiler.chunk
+    }
+
+    /// Compiles a function defined here, unless it is a generator or async function, which run
+    /// their lowered bodies instead.
+    fn compile_nested(&mut self, def: &FunctionDef) {
+        if def.generator.is_some() || def.is_async || def.bytecode.0.get().is_some() {
+            return;
+        }
+
+        let mut chunk = Self::compile_function_in(def, self.resolver.clone(), self.keep);
+
+        for warning in std::mem::take(&mut chunk.warnings) {
+            self.warn(warning);
+        }
+
+        if self.keep {
+            let _ = def.bytecode.0.set(Rc::new(chunk));
+        }
+    }
+
+    fn warn(&mut self, warning: String) {
+        if !self.chunk.warnings.contains(&warning) {
+            self.chunk.warnings.push(warning);
+        }
+    }
+
+    fn resolve(&mut self, name: &str) -> Option<Slot> {
+        match self.resolver.resolve(name) {
+            Resolution::Slot(slot) => Some(slot),
+            Resolution::Dynamic => None,
+            Resolution::Undefined => {
+                self.warn(format!("{} is not defined", name));
+                None
+            },
+        }
+    }
+
+    fn emit_get(&mut self, name: &str) {
+        let slot = self.resolve(name);
+        let name = self.add_name(name);
+
+        match slot {
+            Some(slot) => self.emit(Op::GetSlot(slot, name)),
+            None => self.emit(Op::GetName(name)),
+        };
+    }
+
+    /// The operation that assigns to `name`.
+    fn set_op(&mut self, name: &str) -> Op {
+        let slot = self.resolve(name);
+        let name = self.add_name(name);
+
+        match slot {
+            Some(slot) => Op::SetSlot(slot, name),
+            None => Op::SetName(name),
+        }
+    }
+
+    fn compile_body(&mut self, stmts: &[Stmt], value: bool) {
+        let mut names = Vec::new();
+
+        for stmt in stmts {
+            Interpreter::collect_var_names(stmt, &mut names);
+        }
+
+        if !names.is_empty() {
+            let names = self.add_name_list(names);
+            self.emit(Op::HoistVars(names));
+        }
+
+        self.compile_statements(stmts, value);
+    }
+
+    fn emit(&mut self, op: Op) -> usize {
+        self.chunk.code.push(op);
+        self.chunk.code.len() - 1
+    }
+
+    fn here(&self) -> u32 {
+        self.chunk.code.len() as u32
+    }
+
+    /// Points the jump at `at` to the next operation.
+    fn patch(&mut self, at: usize) {
+        let target = self.here();
+
+        self.chunk.code[at] = match self.chunk.code[at] {
+            Op::Jump(_) => Op::Jump(target),
+            Op::JumpIfFalse(_) => Op::JumpIfFalse(target),
+            Op::JumpIfFalseOrPop(_) => Op::JumpIfFalseOrPop(target),
+            Op::JumpIfTrueOrPop(_) => Op::JumpIfTrueOrPop(target),
+            Op::JumpIfNotNullishOrPop(_) => Op::JumpIfNotNullishOrPop(target),
+            Op::PushHandler(_) => Op::PushHandler(target),
+            Op::IteratorNext(_) => Op::IteratorNext(target),
+            Op::ForInNext(_) => Op::ForInNext(target),
+            op => unreachable!("{:?} is not a jump", op),
+        };
+    }
+
+    fn add_constant(&mut self, value: Value) -> u32 {
+        self.chunk.constants.push(value);
+        self.chunk.constants.len() as u32 - 1
+    }
+
+    fn add_name(&mut self, name: &str) -> u32 {
+        match self.chunk.names.iter().position(|existing| &**existing == name) {
+            Some(index) => index as u32,
+            None => {
+                self.chunk.names.push(intern(name));
+                self.chunk.names.len() as u32 - 1
+            },
+        }
+    }
+
+    fn add_key(&mut self, name: &str) -> u32 {
+        match self.chunk.keys.iter().position(|existing| existing.as_str() == Some(name)) {
+            Some(index) => index as u32,
+            None => {
+                self.chunk.keys.push(PropertyKey::String(intern(name)));
+                self.chunk.keys.len() as u32 - 1
+            },
+        }
+    }
+
+    fn add_cache(&mut self) -> u32 {
+        self.chunk.caches.push(InlineCache::default());
+        self.chunk.caches.len() as u32 - 1
+    }
+
+    fn add_function(&mut self, def: &Rc<FunctionDef>) -> u32 {
+        self.compile_nested(def);
+        self.chunk.functions.push(def.clone());
+        self.chunk.functions.len() as u32 - 1
+    }
+
+    fn add_pattern(&mut self, pattern: &Pattern) -> u32 {
+        self.chunk.patterns.push(pattern.clone());
+        self.chunk.patterns.len() as u32 - 1
+    }
+
+    fn add_name_list(&mut self, names: Vec<Rc<str>>) -> u32 {
+        self.chunk.name_lists.push(names);
+        self.chunk.name_lists.len() as u32 - 1
+    }
+
+    fn add_description(&mut self, callee: &Expr) -> u32 {
+        self.chunk.descriptions.push(Interpreter::describe(callee));
+        self.chunk.descriptions.len() as u32 - 1
+    }
+
+    /// Compiles statements the way `Interpreter::eval_statements` runs them, with the function
+    /// declarations among them hoisted first. With `value`, the completion value is recorded.
+    fn compile_statements(&mut self, stmts: &[Stmt], value: bool) {
+        for stmt in stmts {
+            if let Stmt::Function(def) = stmt {
+                let function = self.add_function(def);
+                self.emit(Op::DefineFunction(function));
+            }
+        }
+
+        for stmt in stmts {
+            let has_value = !matches!(stmt, Stmt::Declaration(_, _) | Stmt::Function(_) | Stmt::None);
+            self.compile_stmt(stmt, value && has_value);
+        }
+    }
+
+    fn compile_stmt(&mut self, stmt: &Stmt, value: bool) {
+        let delegated = match stmt {
+            Stmt::ForAwaitOf(..) => true,
+            Stmt::Break | Stmt::Continue => !self.in_loop(),
+            _ => false,
+        };
+
+        if delegated {
+            return self.compile_exec(stmt, value);
+        }
+
+        self.emit(Op::Step);
+
+        match stmt {
+            Stmt::Declaration(kind, declarations) => {
+                for (pattern, init) in declarations {
+                    self.compile_declaration(*kind, pattern, init.as_ref());
+                }
+
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::ControlFlow(condition, consequent, alternate) => {
+                self.compile_expr(condition);
+                let to_alternate = self.emit(Op::JumpIfFalse(0));
+                self.compile_stmt(consequent, value);
+                let to_end = self.emit(Op::Jump(0));
+                self.patch(to_alternate);
+                self.compile_stmt(alternate, value);
+                self.patch(to_end);
+            },
+            Stmt::CodeBlock(stmts) => self.compile_block(stmts, value),
+            Stmt::Function(_) | Stmt::None => {
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::Return(expr) => {
+                match expr {
+                    Some(expr) => self.compile_expr(expr),
+                    None => {
+                        self.emit(Op::Undefined);
+                    },
+                }
+
+                // the value waits as the completion while iterators are closed and `finally` runs
+                match self.controls.iter().any(|control| matches!(control, Control::Iterator | Control::Finally(..))) {
+                    true => {
+                        self.emit(Op::SetCompletion);
+                        self.leave_controls(0);
+                        self.emit(Op::ReturnCompletion);
+                    },
+                    false => {
+                        self.emit(Op::Return);
+                    },
+                }
+            },
+            Stmt::ForLoop(init, condition, increment, body) => {
+                self.compile_for_loop(init, condition.as_ref(), increment.as_ref(), body);
+
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::Loop(body) => {
+                let start = self.here();
+                self.controls.push(Control::Loop { breaks: Vec::new(), continues: Vec::new() });
+                self.compile_stmt(body, false);
+                self.emit(Op::Jump(start));
+                self.end_loop(start);
+
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::While(condition, body) => {
+                let start = self.here();
+                self.compile_expr(condition);
+                let to_end = self.emit(Op::JumpIfFalse(0));
+                self.controls.push(Control::Loop { breaks: vec![to_end], continues: Vec::new() });
+                self.compile_stmt(body, false);
+                self.emit(Op::Jump(start));
+                self.end_loop(start);
+
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::Expression(expr) => {
+                self.compile_expr(expr);
+
+                match value {
+                    true => self.emit(Op::SetCompletion),
+                    false => self.emit(Op::Pop),
+                };
+            },
+            Stmt::Throw(expr) => {
+                self.compile_expr(expr);
+                self.emit(Op::Throw);
+            },
+            Stmt::ForOf(binding, iterable, body) => {
+                self.compile_for_of(binding, iterable, body);
+
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::ForIn(binding, object, body) => {
+                self.compile_for_in(binding, object, body);
+
+                if value {
+                    self.emit(Op::ClearCompletion);
+                }
+            },
+            Stmt::Try(block, handler, finalizer) => self.compile_try(block, handler.as_ref(), finalizer.as_deref(), value),
+            Stmt::Break => self.compile_break(false),
+            Stmt::Continue => self.compile_break(true),
+            Stmt::ForAwaitOf(..) => unreachable!("delegated to the tree-walker"),
+        }
+    }
+
+    /// Leaves a statement to the tree-walker. Its `break` and `continue` land on code that leaves
+    /// the scopes and handlers in between, as a compiled one would.
+    fn compile_exec(&mut self, stmt: &Stmt, value: bool) {
+        let site = self.chunk.execs.len();
+        self.chunk.execs.push(ExecSite { stmt: stmt.clone(), on_break: None, on_continue: None });
+        self.emit(Op::Exec(site as u32));
+
+        match value {
+            true => self.emit(Op::SetCompletion),
+            false => self.emit(Op::Pop),
+        };
+
+        if !self.in_loop() {
+            return;
+        }
+
+        let to_end = self.emit(Op::Jump(0));
+
+        self.chunk.execs[site].on_break = Some(self.here());
+        self.compile_break(false);
+        self.chunk.execs[site].on_continue = Some(self.here());
+        self.compile_break(true);
+
+        self.patch(to_end);
+    }
+
+    fn compile_declaration(&mut self, kind: DeclarationKind, pattern: &Pattern, init: Option<&Expr>) {
+        match (init, pattern) {
+            (Some(expr), Pattern::Identifier(name)) => self.compile_named(expr, name),
+            (Some(expr), _) => self.compile_expr(expr),
+            // `var x;` leaves the hoisted binding as it is
+            (None, _) if kind == DeclarationKind::Var => return,
+            (None, _) => {
+                self.emit(Op::Undefined);
+            },
+        }
+
+        match pattern {
+            Pattern::Identifier(name) => {
+                let name = self.add_name(name);
+                self.emit(Op::Declare(kind, name));
+            },
+            pattern => {
+                let pattern = self.add_pattern(pattern);
+                self.emit(Op::BindPattern(pattern, Some(kind)));
+            },
+        }
+    }
+
+    fn compile_block(&mut self, stmts: &[Stmt], value: bool) {
+        // blocks that declare nothing share the enclosing scope, as in `Interpreter::eval_code_block`
+        let declares = |stmt: &Stmt| matches!(stmt, Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, _) | Stmt::Function(_));
+        let scoped = stmts.iter().any(declares);
+
+        if value {
+            self.emit(Op::ClearCompletion);
+        }
+
+        if scoped {
+            self.emit(Op::EnterScope);
+            self.controls.push(Control::Scope);
+            self.resolver.push(Resolver::block_names(stmts));
+        }
+
+        self.compile_statements(stmts, value);
+
+        if scoped {
+            self.resolver.pop();
+            self.controls.pop();
+            self.emit(Op::ExitScope);
+        }
+    }
+
+    fn compile_for_loop(&mut self, init: &Stmt, condition: Option<&Expr>, increment: Option<&Expr>, body: &Stmt) {
+        let names: Vec<Rc<str>> = match init {
+            Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, declarations) => {
+                declarations.iter().flat_map(|(pattern, _)| pattern.bound_names()).collect()
+            },
+            _ => Vec::new(),
+        };
+
+        self.resolver.push(names.clone());
+
+        let names = match names.is_empty() {
+            true => None,
+            false => Some(self.add_name_list(names)),
+        };
+
+        self.emit(Op::EnterScope);
+        self.controls.push(Control::Scope);
+        self.compile_stmt(init, false);
+
+        if let Some(names) = names {
+            self.emit(Op::CopyIterationScope(names));
+        }
+
+        let start = self.here();
+
+        let to_end = condition.map(|condition| {
+            self.compile_expr(condition);
+            self.emit(Op::JumpIfFalse(0))
+        });
+
+        self.controls.push(Control::Loop { breaks: to_end.into_iter().collect(), continues: Vec::new() });
+        self.compile_stmt(body, false);
+
+        let next = self.here();
+
+        if let Some(names) = names {
+            self.emit(Op::CopyIterationScope(names));
+        }
+
+        if let Some(increment) = increment {
+            self.compile_expr(increment);
+            self.emit(Op::Pop);
+        }
+
+        self.emit(Op::Jump(start));
+        self.end_loop(next);
+
+        self.resolver.pop();
+        self.controls.pop();
+        self.emit(Op::ExitScope);
+    }
+
+    /// Compiles `for...of` with the iterator on the stack below the value of each iteration. A
+    /// throw from the binding or the body closes the iterator, one from `next` doesn't.
+    fn compile_for_of(&mut self, binding: &ForBinding, iterable: &Expr, body: &Stmt) {
+        self.compile_expr(iterable);
+        self.emit(Op::GetIterator);
+
+        let start = self.here();
+        let to_end = self.emit(Op::IteratorNext(0));
+        let to_close = self.emit(Op::PushHandler(0));
+
+        self.controls.push(Control::Iterator);
+        self.controls.push(Control::Loop { breaks: Vec::new(), continues: Vec::new() });
+        self.controls.push(Control::Handler);
+        self.emit(Op::Dup);
+        let scoped = self.compile_for_binding(binding);
+        self.compile_stmt(body, false);
+        self.end_for_binding(scoped);
+        self.controls.pop();
+        self.emit(Op::PopHandler);
+
+        let next = self.here();
+        self.emit(Op::Pop);
+        self.emit(Op::Jump(start));
+        self.end_loop(next);
+
+        self.emit(Op::Pop);
+        self.emit(Op::CloseIterator);
+        let to_exit = self.emit(Op::Jump(0));
+
+        self.patch(to_close);
+        self.emit(Op::Nip);
+        self.emit(Op::CloseIteratorAndThrow);
+
+        self.patch(to_end);
+        self.patch(to_exit);
+        self.controls.pop();
+        self.emit(Op::Pop);
+        self.emit(Op::Pop);
+    }
+
+    fn compile_for_in(&mut self, binding: &ForBinding, object: &Expr, body: &Stmt) {
+        self.compile_expr(object);
+        self.emit(Op::ForInKeys);
+        self.controls.push(Control::Operands(3));
+
+        let start = self.here();
+        let to_end = self.emit(Op::ForInNext(0));
+
+        self.controls.push(Control::Loop { breaks: Vec::new(), continues: Vec::new() });
+        let scoped = self.compile_for_binding(binding);
+        self.compile_stmt(body, false);
+        self.end_for_binding(scoped);
+        self.emit(Op::Jump(start));
+        self.end_loop(start);
+
+        self.patch(to_end);
+        self.controls.pop();
+
+        for _ in 0..3 {
+            self.emit(Op::Pop);
+        }
+    }
+
+    /// Binds the value on the stack for an iteration of `for...of` or `for...in`, the way
+    /// `Interpreter::eval_for_iteration` does, returning whether it entered a scope for it.
+    fn compile_for_binding(&mut self, binding: &ForBinding) -> bool {
+        let (kind, pattern) = match binding {
+            ForBinding::Declaration(kind @ (DeclarationKind::Let | DeclarationKind::Const), pattern) => (Some(*kind), pattern),
+            // `var` bindings are hoisted, so they are assigned like any other target
+            ForBinding::Declaration(DeclarationKind::Var, pattern) | ForBinding::Target(pattern) => (None, pattern),
+        };
+
+        if kind.is_some() {
+            self.emit(Op::EnterScope);
+            self.controls.push(Control::Scope);
+            self.resolver.push(pattern.bound_names());
+        }
+
+        match (kind, pattern) {
+            (Some(kind), Pattern::Identifier(name)) => {
+                let name = self.add_name(name);
+                self.emit(Op::Declare(kind, name));
+            },
+            (None, Pattern::Identifier(name)) => {
+                let op = self.set_op(name);
+                self.emit(op);
+                self.emit(Op::Pop);
+            },
+            (kind, pattern) => {
+                let pattern = self.add_pattern(pattern);
+                self.emit(Op::BindPattern(pattern, kind));
+            },
+        }
+
+        kind.is_some()
+    }
+
+    fn end_for_binding(&mut self, scoped: bool) {
+        if scoped {
+            self.resolver.pop();
+            self.controls.pop();
+            self.emit(Op::ExitScope);
+        }
+    }
+
+    /// Patches the jumps out of the innermost loop, now that its end is the next operation.
+    fn end_loop(&mut self, continue_target: u32) {
+        let Some(Control::Loop { breaks, continues }) = self.controls.pop() else {
+            unreachable!("not in a loop");
+        };
+
+        for at in breaks {
+            self.patch(at);
+        }
+
+        for at in continues {
+            self.chunk.code[at] = Op::Jump(continue_target);
+        }
+    }
+
+    fn in_loop(&self) -> bool {
+        self.controls.iter().any(|control| matches!(control, Control::Loop { .. }))
+    }
+
+    /// Jumps out of or to the next iteration of the innermost loop, leaving what was entered
+    /// inside it.
+    fn compile_break(&mut self, is_continue: bool) {
+        let inner = self.controls.iter().rposition(|control| matches!(control, Control::Loop { .. }));
+        self.leave_controls(inner.map_or(0, |index| index + 1));
+
+        let jump = self.emit(Op::Jump(0));
+
+        if let Some(Control::Loop { breaks, continues }) = self.controls.iter_mut().rev().find(|control| matches!(control, Control::Loop { .. })) {
+            match is_continue {
+                true => continues.push(jump),
+                false => breaks.push(jump),
+            }
+        }
+    }
+
+    /// Leaves the scopes, handlers and loops over iterators entered since there were `depth`
+    /// controls, running the `finally` blocks in between.
+    fn leave_controls(&mut self, depth: usize) {
+        for index in (depth..self.controls.len()).rev() {
+            match &self.controls[index] {
+                Control::Scope => {
+                    self.emit(Op::ExitScope);
+                },
+                Control::Handler => {
+                    self.emit(Op::PopHandler);
+                },
+                Control::Iterator => {
+                    self.emit(Op::Pop);
+                    self.emit(Op::CloseIterator);
+                    self.emit(Op::Pop);
+                    self.emit(Op::Pop);
+                },
+                Control::Operands(count) => {
+                    for _ in 0..*count {
+                        self.emit(Op::Pop);
+                    }
+                },
+                Control::Finally(finalizer, scopes) => {
+                    // the block runs where the `try` is, outside of what was entered inside it
+                    let finalizer = finalizer.clone();
+                    let inner_scopes = self.resolver.split_off(*scopes);
+                    let inner = self.controls.split_off(index);
+                    self.compile_stmt(&finalizer, false);
+                    self.controls.extend(inner);
+                    self.resolver.extend(inner_scopes);
+                },
+                Control::Loop { .. } => (),
+            }
+        }
+    }
+
+    /// Compiles `try` with a `finally` block, which a throw reaches with the exception on the
+    /// stack to throw again once the block is done.
+    fn compile_try(&mut self, block: &Stmt, handler: Option<&(Option<Rc<str>>, Box<Stmt>)>, finalizer: Option<&Stmt>, value: bool) {
+        let Some(finalizer) = finalizer else {
+            return self.compile_catch(block, handler, value);
+        };
+
+        let to_finally = self.emit(Op::PushHandler(0));
+        self.controls.push(Control::Finally(finalizer.clone(), self.resolver.depth()));
+        self.controls.push(Control::Handler);
+        self.compile_catch(block, handler, value);
+        self.controls.pop();
+        self.controls.pop();
+        self.emit(Op::PopHandler);
+        self.compile_stmt(finalizer, false);
+        let to_end = self.emit(Op::Jump(0));
+
+        self.patch(to_finally);
+        self.controls.push(Control::Operands(1));
+        self.compile_stmt(finalizer, false);
+        self.controls.pop();
+        self.emit(Op::Throw);
+        self.patch(to_end);
+    }
+
+    fn compile_catch(&mut self, block: &Stmt, handler: Option<&(Option<Rc<str>>, Box<Stmt>)>, value: bool) {
+        let Some((param, body)) = handler else {
+            // a try without catch runs like a block around its `finally`
+            return self.compile_stmt(block, value);
+        };
+
+        let to_catch = self.emit(Op::PushHandler(0));
+        self.controls.push(Control::Handler);
+        self.compile_stmt(block, value);
+        self.controls.pop();
+        self.emit(Op::PopHandler);
+        let to_end = self.emit(Op::Jump(0));
+
+        self.patch(to_catch);
+        self.emit(Op::EnterScope);
+        self.controls.push(Control::Scope);
+        self.resolver.push(param.iter().cloned().collect());
+
+        match param {
+            Some(param) => {
+                let name = self.add_name(param);
+                self.emit(Op::DefineName(name));
+            },
+            None => {
+                self.emit(Op::Pop);
+            },
+        }
+
+        self.compile_stmt(body, value);
+        self.resolver.pop();
+        self.controls.pop();
+        self.emit(Op::ExitScope);
+        self.patch(to_end);
+    }
+
+    /// A call with the callee and `this` below the arguments, where a method call gets its object.
+    fn compile_call(&mut self, callee: &Expr, args: &[Expr], tail: bool) {
+        match callee {
+            Expr::Member(object, name) => {
+                self.compile_expr(object);
+                let key = self.add_key(name);
+                let cache = self.add_cache();
+                self.emit(Op::GetMethod(key, cache));
+            },
+            Expr::ComputedMember(object, key) => {
+                self.compile_expr(object);
+                self.compile_expr(key);
+                self.emit(Op::GetMethodComputed);
+            },
+            callee => {
+                self.compile_expr(callee);
+                self.emit(Op::Undefined);
+            },
+        }
+
+        for arg in args {
+            self.compile_expr(arg);
+        }
+
+        let description = self.add_description(callee);
+
+        match tail {
+            true => self.emit(Op::TailCall(args.len() as u32, description)),
+            false => self.emit(Op::Call(args.len() as u32, description)),
+        };
+    }
+
+    /// Leaves an expression to the tree-walker.
+    fn compile_eval(&mut self, expr: &Expr) {
+        self.chunk.exprs.push(expr.clone());
+        let index = self.chunk.exprs.len() as u32 - 1;
+        self.emit(Op::Eval(index));
+    }
+
+    /// Compiles an initializer, naming anonymous functions after the binding they are assigned to.
+    fn compile_named(&mut self, expr: &Expr, name: &str) {
+        match expr {
+            Expr::Function(def) if def.name.is_none() => {
+                let function = self.add_function(def);
+                let name = self.add_constant(Value::StringLiteral(name.into()));
+                self.emit(Op::NamedClosure(function, name));
+            },
+            _ => self.compile_expr(expr),
+        }
+    }
+
+    fn compile_binary(&mut self, operator: BinaryOperator, left: &Expr, right: &Expr) {
+        self.compile_expr(left);
+        self.compile_expr(right);
+        self.emit(Op::Binary(operator));
+    }
+
+    fn compile_short_circuit(&mut self, jump: Op, left: &Expr, right: &Expr) {
+        self.compile_expr(left);
+        let to_end = self.emit(jump);
+        self.compile_expr(right);
+        self.patch(to_end);
+    }
+
+    fn compile_unary(&mut self, op: Op, operand: &Expr) {
+        self.compile_expr(operand);
+        self.emit(op);
+    }
+
+    fn compile_expr(&mut self, expr: &Expr) {
+        let has_spread = |exprs: &[Expr]| exprs.iter().any(|expr| matches!(expr, Expr::Spread(_)));
+
+        match expr {
+            Expr::Identifier(name) => self.emit_get(name),
+            Expr::Float(value) => {
+                let constant = self.add_constant(Value::Float(*value));
+                self.emit(Op::Constant(constant));
+            },
+            Expr::StringLiteral(value) => {
+                let constant = self.add_constant(Value::StringLiteral(value.clone()));
+                self.emit(Op::Constant(constant));
+            },
+            Expr::RegExp(regex) => {
+                self.chunk.regexps.push(regex.clone());
+                let regex = self.chunk.regexps.len() as u32 - 1;
+                self.emit(Op::RegExp(regex));
+            },
+            Expr::Boolean(true) => {
+                self.emit(Op::True);
+            },
+            Expr::Boolean(false) => {
+                self.emit(Op::False);
+            },
+            Expr::Null => {
+                self.emit(Op::Null);
+            },
+            Expr::This => {
+                self.emit(Op::This);
+            },
+            Expr::Function(def) if def.binds_name => {
+                // `Op::Closure` defines the name in a scope between the function's and this one
+                self.resolver.push(def.name.iter().cloned().collect());
+                let function = self.add_function(def);
+                self.resolver.pop();
+                self.emit(Op::Closure(function));
+            },
+            Expr::Function(def) => {
+                let function = self.add_function(def);
+                self.emit(Op::Closure(function));
+            },
+            Expr::Array(elements) if elements.iter().flatten().any(|element| matches!(element, Expr::Spread(_))) => self.compile_eval(expr),
+            Expr::Array(elements) => {
+                for element in elements {
+                    match element {
+                        Some(element) => self.compile_expr(element),
+                        None => {
+                            self.emit(Op::Undefined);
+                        },
+                    }
+                }
+
+                self.emit(Op::Array(elements.len() as u32));
+            },
+            Expr::Object(members) => self.compile_object_literal(expr, members),
+            Expr::Addition(left, right) => self.compile_binary(BinaryOperator::Addition, left, right),
+            Expr::Subtraction(left, right) => self.compile_binary(BinaryOperator::Subtraction, left, right),
+            Expr::Multiplication(left, right) => self.compile_binary(BinaryOperator::Multiplication, left, right),
+            Expr::Division(left, right) => self.compile_binary(BinaryOperator::Division, left, right),
+            Expr::Modulo(left, right) => self.compile_binary(BinaryOperator::Modulo, left, right),
+            Expr::Exponentiation(left, right) => self.compile_binary(BinaryOperator::Exponentiation, left, right),
+            Expr::Equals(left, right) => self.compile_binary(BinaryOperator::Equals, left, right),
+            Expr::TypeCheckEquals(left, right) => self.compile_binary(BinaryOperator::TypeCheckEquals, left, right),
+            Expr::NotEquals(left, right) => self.compile_binary(BinaryOperator::NotEquals, left, right),
+            Expr::TypeNotEquals(left, right) => self.compile_binary(BinaryOperator::TypeNotEquals, left, right),
+            Expr::GreaterThan(left, right) => self.compile_binary(BinaryOperator::GreaterThan, left, right),
+            Expr::GreaterThanEquals(left, right) => self.compile_binary(BinaryOperator::GreaterThanEquals, left, right),
+            Expr::LessThan(left, right) => self.compile_binary(BinaryOperator::LessThan, left, right),
+            Expr::LessThanEquals(left, right) => self.compile_binary(BinaryOperator::LessThanEquals, left, right),
+            Expr::BitwiseAnd(left, right) => self.compile_binary(BinaryOperator::BitwiseAnd, left, right),
+            Expr::BitwiseOr(left, right) => self.compile_binary(BinaryOperator::BitwiseOr, left, right),
+            Expr::BitwiseXor(left, right) => self.compile_binary(BinaryOperator::BitwiseXor, left, right),
+            Expr::LeftShift(left, right) => self.compile_binary(BinaryOperator::LeftShift, left, right),
+            Expr::RightShift(left, right) => self.compile_binary(BinaryOperator::RightShift, left, right),
+            Expr::UnsignedRightShift(left, right) => self.compile_binary(BinaryOperator::UnsignedRightShift, left, right),
+            Expr::InstanceOf(left, right) => self.compile_binary(BinaryOperator::InstanceOf, left, right),
+            Expr::In(left, right) => self.compile_binary(BinaryOperator::In, left, right),
+            Expr::LogicalAnd(left, right) => self.compile_short_circuit(Op::JumpIfFalseOrPop(0), left, right),
+            Expr::LogicalOr(left, right) => self.compile_short_circuit(Op::JumpIfTrueOrPop(0), left, right),
+            Expr::NullishCoalescing(left, right) => self.compile_short_circuit(Op::JumpIfNotNullishOrPop(0), left, right),
+            Expr::LogicalNot(operand) => self.compile_unary(Op::Not, operand),
+            Expr::Negation(operand) => self.compile_unary(Op::Negate, operand),
+            Expr::UnaryPlus(operand) => self.compile_unary(Op::Plus, operand),
+            Expr::BitwiseNot(operand) => self.compile_unary(Op::BitwiseNot, operand),
+            // typeof is the one place an undeclared identifier is not an error
+            Expr::TypeOf(operand) => match operand.as_ref() {
+                Expr::Identifier(name) => {
+                    let name = self.add_name(name);
+                    self.emit(Op::TypeOfName(name));
+                },
+                operand => self.compile_unary(Op::TypeOf, operand),
+            },
+            Expr::Void(operand) => self.compile_unary(Op::Void, operand),
+            Expr::PreIncrement(target) => self.compile_update(expr, target, Update { increment: true, prefix: true }),
+            Expr::PreDecrement(target) => self.compile_update(expr, target, Update { increment: false, prefix: true }),
+            Expr::PostIncrement(target) => self.compile_update(expr, target, Update { increment: true, prefix: false }),
+            Expr::PostDecrement(target) => self.compile_update(expr, target, Update { increment: false, prefix: false }),
+            Expr::Assignment(target, value) => self.compile_assignment(expr, target, value),
+            Expr::DestructuringAssignment(pattern, value) => {
+                self.compile_expr(value);
+                self.emit(Op::Dup);
+                let pattern = self.add_pattern(pattern);
+                self.emit(Op::BindPattern(pattern, None));
+            },
+            Expr::CompoundAssignment(operator, target, value) => self.compile_compound_assignment(expr, operator, target, value),
+            Expr::Conditional(condition, consequent, alternate) => {
+                self.compile_expr(condition);
+                let to_alternate = self.emit(Op::JumpIfFalse(0));
+                self.compile_expr(consequent);
+                let to_end = self.emit(Op::Jump(0));
+                self.patch(to_alternate);
+                self.compile_expr(alternate);
+                self.patch(to_end);
+            },
+            Expr::Sequence(exprs) if exprs.is_empty() => {
+                self.emit(Op::Undefined);
+            },
+            Expr::Sequence(exprs) => {
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i > 0 {
+                        self.emit(Op::Pop);
+                    }
+
+                    self.compile_expr(expr);
+                }
+            },
+            Expr::FunctionCall(_, args) | Expr::New(_, args) if has_spread(args) => self.compile_eval(expr),
+            Expr::FunctionCall(callee, args) => self.compile_call(callee, args, false),
+            Expr::TailCall(call) => match call.as_ref() {
+                Expr::FunctionCall(callee, args) if !has_spread(args) => self.compile_call(callee, args, true),
+                call => self.compile_expr(call),
+            },
+            Expr::New(callee, args) => {
+                self.compile_expr(callee);
+
+                for arg in args {
+                    self.compile_expr(arg);
+                }
+
+                let description = self.add_description(callee);
+                self.emit(Op::New(args.len() as u32, description));
+            },
+            Expr::Member(object, name) => {
+                self.compile_expr(object);
+                let key = self.add_key(name);
+                let cache = self.add_cache();
+                self.emit(Op::GetProperty(key, cache));
+            },
+            Expr::ComputedMember(object, key) => {
+                self.compile_expr(object);
+                self.compile_expr(key);
+                self.emit(Op::GetComputed);
+            },
+            // the tree-walker reports the errors for what can only appear in lowered bodies
+            Expr::Delete(_) | Expr::Spread(_) | Expr::Yield(_) | Expr::YieldDelegate(_) | Expr::Await(_) => self.compile_eval(expr),
+        }
+    }
+
+    fn compile_object_literal(&mut self, expr: &Expr, members: &[ObjectMember]) {
+        // accessors, spreads and functions under computed keys are rare enough to leave as they are
+        let simple = members.iter().all(|member| match member {
+            ObjectMember::Property(PropertyName::Literal(_), _) => true,
+            ObjectMember::Property(PropertyName::Computed(_), value) => !matches!(value, Expr::Function(_)),
+            _ => false,
+        });
+
+        if !simple {
+            return self.compile_eval(expr);
+        }
+
+        self.emit(Op::Object(members.len() as u32));
+
+        for member in members {
+            match member {
+                ObjectMember::Property(PropertyName::Literal(name), value) => {
+                    self.compile_named(value, name);
+                    let key = self.add_key(name);
+                    self.emit(Op::DefineField(key));
+                },
+                ObjectMember::Property(PropertyName::Computed(key), value) => {
+                    self.compile_expr(key);
+                    self.emit(Op::ToPropertyKey);
+                    self.compile_expr(value);
+                    self.emit(Op::DefineComputedField);
+                },
+                _ => unreachable!("only simple members are compiled"),
+            }
+        }
+    }
+
+    fn compile_assignment(&mut self, expr: &Expr, target: &Expr, value: &Expr) {
+        match target {
+            Expr::Identifier(name) => {
+                self.compile_named(value, name);
+                let store = self.set_op(name);
+                self.emit(store);
+            },
+            Expr::Member(object, name) => {
+                self.compile_expr(object);
+                self.compile_expr(value);
+                let key = self.add_key(name);
+                let cache = self.add_cache();
+                self.emit(Op::SetProperty(key, cache));
+            },
+            Expr::ComputedMember(object, key) => {
+                self.compile_expr(object);
+                self.compile_expr(key);
+                self.emit(Op::ToPropertyKey);
+                self.compile_expr(value);
+                self.emit(Op::SetComputed);
+            },
+            _ => self.compile_eval(expr),
+        }
+    }
+
+    fn compile_compound_assignment(&mut self, expr: &Expr, operator: &Token, target: &Expr, value: &Expr) {
+        let short_circuit = match operator {
+            Token::LogicalAnd => Some(Op::JumpIfFalseOrPop(0)),
+            Token::LogicalOr => Some(Op::JumpIfTrueOrPop(0)),
+            Token::NullishCoalescing => Some(Op::JumpIfNotNullishOrPop(0)),
+            _ => None,
+        };
+
+        let (store, operands) = match target {
+            Expr::Identifier(name) => {
+                self.emit_get(name);
+                (self.set_op(name), 0)
+            },
+            Expr::Member(object, name) => {
+                self.compile_expr(object);
+                self.emit(Op::Dup);
+                let key = self.add_key(name);
+                let (get, set) = (self.add_cache(), self.add_cache());
+                self.emit(Op::GetProperty(key, get));
+                (Op::SetProperty(key, set), 1)
+            },
+            Expr::ComputedMember(object, key) => {
+                self.compile_expr(object);
+                self.compile_expr(key);
+                self.emit(Op::ToPropertyKey);
+                self.emit(Op::Dup2);
+                self.emit(Op::GetComputed);
+                (Op::SetComputed, 2)
+            },
+            _ => return self.compile_eval(expr),
+        };
+
+        match short_circuit {
+            Some(jump) => {
+                let to_short_circuit = self.emit(jump);
+                self.compile_expr(value);
+                self.emit(store);
+                let to_end = self.emit(Op::Jump(0));
+
+                // the current value is the result, without the target below it
+                self.patch(to_short_circuit);
+
+                for _ in 0..operands {
+                    self.emit(Op::Nip);
+                }
+
+                self.patch(to_end);
+            },
+            None => {
+                let Some(operator) = BinaryOperator::from_token(operator) else {
+                    return self.compile_eval(expr);
+                };
+
+                self.compile_expr(value);
+                self.emit(Op::Binary(operator));
+                self.emit(store);
+            },
+        }
+    }
+
+    fn compile_update(&mut self, expr: &Expr, target: &Expr, update: Update) {
+        match target {
+            Expr::Identifier(name) => {
+                let slot = self.resolve(name);
+                let name = self.add_name(name);
+
+                match slot {
+                    Some(slot) => self.emit(Op::UpdateSlot(slot, name, update)),
+                    None => self.emit(Op::UpdateName(name, update)),
+                };
+            },
+            Expr::Member(object, name) => {
+                self.compile_expr(object);
+                let key = self.add_key(name);
+                self.emit(Op::UpdateProperty(key, update));
+            },
+            Expr::ComputedMember(object, key) => {
+                self.compile_expr(object);
+                self.compile_expr(key);
+                self.emit(Op::ToPropertyKey);
+                self.emit(Op::UpdateComputed(update));
+            },
+            _ => self.compile_eval(expr),
+        }
+    }
+}
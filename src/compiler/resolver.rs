@@ -0,0 +1,142 @@
+use std::rc::Rc;
+use crate::interpreter::Interpreter;
+use crate::scope::{Scope, Slot};
+use crate::types::{intern, DeclarationKind, FunctionDef, Stmt};
+
+/// Where an identifier refers to, as far as the compiler can tell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Resolution {
+    Slot(Slot),
+    // declared somewhere the compiler can't see, so it's looked up by name
+    Dynamic,
+    // declared nowhere, which will throw a `ReferenceError` unless the host defines it first
+    Undefined,
+}
+
+/// The scopes around the code being compiled, each with the bindings it will have in the order
+/// they are defined at runtime, which gives their slots.
+///
+/// Only scopes the virtual machine creates itself are known. Functions compiled on their own,
+/// without the code around them, see the rest of the chain as unknown and look it up by name.
+#[derive(Clone, Debug, Default)]
+pub struct Resolver {
+    // innermost last
+    scopes: Vec<Vec<Rc<str>>>,
+    // whether the outermost scope is the global one, so that names no scope has are undefined
+    complete: bool,
+}
+
+impl Resolver {
+    /// The global scope of a script, with the bindings it already has and those the script
+    /// declares at its top level.
+    pub fn for_script(global: &Scope, stmts: &[Stmt]) -> Self {
+        let mut resolver = Resolver {
+            scopes: vec![global.bindings().map(|(name, _)| name.clone()).collect()],
+            complete: true,
+        };
+
+        for name in Self::var_names(stmts).into_iter().chain(Self::block_names(stmts)) {
+            resolver.declare(name);
+        }
+
+        resolver
+    }
+
+    /// Enters the scope of a call of `def`, as `Interpreter::enter_function` sets it up.
+    pub fn enter_function(&mut self, def: &FunctionDef) {
+        let mut names = Vec::new();
+
+        if def.uses_arguments {
+            names.push(intern("arguments"));
+        }
+
+        for param in def.params.iter().chain(&def.rest) {
+            names.extend(param.bound_names());
+        }
+
+        names.extend(Self::var_names(&def.body));
+        names.extend(Self::block_names(&def.body));
+        self.push(names);
+    }
+
+    /// Enters a scope with these bindings, with any repeated names keeping their first slot.
+    pub fn push(&mut self, names: Vec<Rc<str>>) {
+        self.scopes.push(Vec::new());
+
+        for name in names {
+            self.declare(name);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// How many scopes are around the code being compiled.
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Takes off the scopes past the first `depth`, for code that runs outside of them, until
+    /// they are put back with `extend`.
+    pub fn split_off(&mut self, depth: usize) -> Vec<Vec<Rc<str>>> {
+        self.scopes.split_off(depth)
+    }
+
+    pub fn extend(&mut self, scopes: Vec<Vec<Rc<str>>>) {
+        self.scopes.extend(scopes);
+    }
+
+    fn declare(&mut self, name: Rc<str>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            if !scope.contains(&name) {
+                scope.push(name);
+            }
+        }
+    }
+
+    pub fn resolve(&self, name: &str) -> Resolution {
+        for (depth, scope) in self.scopes.iter().rev().enumerate() {
+            if let Some(index) = scope.iter().position(|declared| &**declared == name) {
+                // chains too deep or scopes too big for a slot are left to lookups by name
+                return match (u16::try_from(depth), u16::try_from(index)) {
+                    (Ok(depth), Ok(index)) => Resolution::Slot(Slot { depth, index }),
+                    _ => Resolution::Dynamic,
+                };
+            }
+        }
+
+        match self.complete {
+            true => Resolution::Undefined,
+            false => Resolution::Dynamic,
+        }
+    }
+
+    fn var_names(stmts: &[Stmt]) -> Vec<Rc<str>> {
+        let mut names = Vec::new();
+
+        for stmt in stmts {
+            Interpreter::collect_var_names(stmt, &mut names);
+        }
+
+        names
+    }
+
+    /// The bindings a statement list creates in its own scope: function declarations, which are
+    /// hoisted, then `let` and `const` in the order they run.
+    pub fn block_names(stmts: &[Stmt]) -> Vec<Rc<str>> {
+        let functions = stmts.iter().filter_map(|stmt| match stmt {
+            Stmt::Function(def) => Some(def.name.clone().unwrap_or_default()),
+            _ => None,
+        });
+
+        let declarations = stmts.iter().flat_map(|stmt| match stmt {
+            Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, declarations) => {
+                declarations.iter().flat_map(|(pattern, _)| pattern.bound_names()).collect()
+            },
+            _ => Vec::new(),
+        });
+
+        functions.chain(declarations).collect()
+    }
+}
@@ -0,0 +1,88 @@
+use std::rc::Rc;
+use crate::builtins::{define_getter, define_method};
+use crate::error::JsError;
+use crate::interpreter::{EvalResult, Interpreter};
+use crate::object::ObjectRef;
+use crate::types::Value;
+
+/// A function the host exposes to scripts. The `JsError` it returns is thrown into the script.
+pub type HostFunction = Rc<dyn Fn(&mut Interpreter, &[Value]) -> Result<Value, JsError>>;
+
+/// A getter the host exposes to scripts, computed each time the property is read.
+pub type HostGetter = Rc<dyn Fn(&mut Interpreter) -> Result<Value, JsError>>;
+
+/// Describes an object the host installs into scripts, see `Context::register_object`.
+#[derive(Default)]
+pub struct HostObject {
+    members: Vec<(String, Member)>,
+}
+
+enum Member {
+    Function(HostFunction),
+    Getter(HostGetter),
+    Property(Value),
+    Object(HostObject),
+}
+
+impl HostObject {
+    pub fn new() -> Self {
+        HostObject { members: Vec::new() }
+    }
+
+    /// Adds a method, which like built-in methods isn't enumerable.
+    pub fn function(mut self, name: &str, function: impl Fn(&mut Interpreter, &[Value]) -> Result<Value, JsError> + 'static) -> Self {
+        self.members.push((name.to_string(), Member::Function(Rc::new(function))));
+        self
+    }
+
+    /// Adds a read-only accessor property.
+    pub fn getter(mut self, name: &str, getter: impl Fn(&mut Interpreter) -> Result<Value, JsError> + 'static) -> Self {
+        self.members.push((name.to_string(), Member::Getter(Rc::new(getter))));
+        self
+    }
+
+    /// Adds an ordinary data property.
+    pub fn property(mut self, name: &str, value: Value) -> Self {
+        self.members.push((name.to_string(), Member::Property(value)));
+        self
+    }
+
+    /// Adds a nested object.
+    pub fn object(mut self, name: &str, object: HostObject) -> Self {
+        self.members.push((name.to_string(), Member::Object(object)));
+        self
+    }
+
+    /// Creates the object in the realm of `interpreter`.
+    pub fn build(self, interpreter: &mut Interpreter) -> ObjectRef {
+        let object = interpreter.create_object();
+        let function_prototype = interpreter.intrinsics().function_prototype.clone();
+
+        for (name, member) in self.members {
+            match member {
+                Member::Function(function) => {
+                    define_method(&object, &function_prototype, &name, 0, move |interpreter, _, args| {
+                        call_host(interpreter, &function, args)
+                    });
+                },
+                Member::Getter(getter) => {
+                    define_getter(&object, &function_prototype, name, move |interpreter, _, _| {
+                        getter(interpreter).map_err(|error| interpreter.throw_js_error(error))
+                    });
+                },
+                Member::Property(value) => object.set(name, value),
+                Member::Object(nested) => {
+                    let nested = nested.build(interpreter);
+                    object.set(name, Value::Object(nested));
+                },
+            }
+        }
+
+        object
+    }
+}
+
+/// Calls a host function, throwing the error it returns into the script.
+pub(super) fn call_host(interpreter: &mut Interpreter, function: &HostFunction, args: &[Value]) -> EvalResult {
+    function(interpreter, args).map_err(|error| interpreter.throw_js_error(error))
+}
@@ -0,0 +1,363 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::time::Duration;
+use crate::builtins;
+use crate::convert::{FromJs, IntoJs};
+use crate::error::JsError;
+use crate::scope::Scope;
+use crate::optimizer::Optimizer;
+use crate::parser::Parser;
+use crate::interpreter::{CacheStats, Clock, Engine, GcStats, InterruptHandle, Interpreter, LogLevel, OutOfMemory};
+use crate::lexer::Lexer;
+use crate::object::{ObjectKind, PromiseState};
+use crate::types::{GeneratorCode, Token, Stmt, Signal, Value};
+
+mod host;
+
+pub use host::{HostFunction, HostGetter, HostObject};
+
+/// A global environment with its own built-ins, which any number of scripts can be evaluated
+/// against. Contexts are isolated realms: nothing one script does to its globals or built-in
+/// prototypes is visible in another context.
+pub struct Context {
+    tokens: Vec<Token>,
+    line_breaks: Vec<bool>,
+    ast: Vec<Stmt>,
+    // the lowered script when it uses top-level await
+    async_script: Option<Rc<GeneratorCode>>,
+    interpreter: Interpreter,
+    global: Rc<RefCell<Scope>>,
+    // told about promises that were rejected without a handler
+    rejection_handler: Box<dyn FnMut(&Value)>,
+    // told about what compiling scripts noticed, such as references to names nothing declares
+    warning_handler: Box<dyn FnMut(&str)>,
+    // whether scripts are optimized after they are parsed
+    optimize: bool,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        let scope = Scope::new(None).into_rc();
+        let intrinsics = Rc::new(builtins::install(&scope));
+
+        Context {
+            tokens: Vec::new(),
+            line_breaks: Vec::new(),
+            ast: Vec::new(),
+            async_script: None,
+            interpreter: Interpreter::new(scope.clone(), intrinsics),
+            global: scope,
+            rejection_handler: Box::new(|reason| eprintln!("Uncaught (in promise) {}", JsError::Thrown(reason.clone()))),
+            warning_handler: Box::new(|warning| eprintln!("Warning: {}", warning)),
+            optimize: true,
+        }
+    }
+
+    /// Replaces the clock timers are scheduled with, which is wall-clock time by default. Pass a
+    /// `VirtualClock` to control time with `advance_time`.
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        self.interpreter.set_clock(Box::new(clock));
+    }
+
+    /// Replaces what happens with promises that are rejected without a handler, which by default
+    /// are printed to stderr.
+    pub fn on_unhandled_rejection(&mut self, handler: impl FnMut(&Value) + 'static) {
+        self.rejection_handler = Box::new(handler);
+    }
+
+    /// Replaces what happens with the warnings compiling a script produces, which by default are
+    /// printed to stderr.
+    pub fn on_warning(&mut self, handler: impl FnMut(&str) + 'static) {
+        self.warning_handler = Box::new(handler);
+    }
+
+    /// Sends what scripts print with `console` to `handler`, instead of stdout and stderr.
+    pub fn on_console(&mut self, handler: impl FnMut(LogLevel, &str) + 'static) {
+        self.interpreter.set_console_sink(Box::new(handler));
+    }
+
+    /// Writes everything scripts print with `console` to `output`, a line per message.
+    pub fn set_console_output(&mut self, mut output: impl Write + 'static) {
+        self.on_console(move |_, message| {
+            // scripts can't do anything about the host's output failing
+            let _ = writeln!(output, "{}", message);
+        });
+    }
+
+    /// Selects how scripts are run from now on, which is the bytecode virtual machine by default.
+    /// Both engines give the same results.
+    pub fn set_engine(&mut self, engine: Engine) {
+        self.interpreter.set_engine(engine);
+    }
+
+    pub fn engine(&self) -> Engine {
+        self.interpreter.engine()
+    }
+
+    /// Turns the optimizer off or back on, which folds constants and removes dead code from scripts
+    /// before they run. Either way scripts give the same results.
+    pub fn set_optimize(&mut self, optimize: bool) {
+        self.optimize = optimize;
+    }
+
+    /// Seeds the generator behind `Math.random`, which is seeded differently for every context by
+    /// default, so that runs of a script get the same numbers.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.interpreter.set_random_seed(seed);
+    }
+
+    /// Limits how many steps scripts in this context may run, summed over every call into them.
+    /// Running out stops the script with `JsError::Terminated`, which it can't catch.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.interpreter.set_fuel(fuel);
+    }
+
+    /// The steps scripts may still run, if they are limited.
+    pub fn fuel(&self) -> Option<u64> {
+        self.interpreter.fuel()
+    }
+
+    /// Limits how long each call into scripts may run, such as `eval` or `run_event_loop`.
+    pub fn set_time_limit(&mut self, limit: Option<Duration>) {
+        self.interpreter.set_time_limit(limit);
+    }
+
+    /// Limits how deep calls may nest before a `RangeError` is thrown, 2000 by default.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.interpreter.set_max_call_depth(depth);
+    }
+
+    /// A handle another thread can stop the running script with.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interpreter.interrupt_handle()
+    }
+
+    /// Limits the memory held by the objects, arrays, strings and scopes scripts create, in
+    /// bytes. What happens when a script goes over it is up to `set_out_of_memory`.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.interpreter.set_memory_limit(limit);
+    }
+
+    /// Whether going over the memory limit throws a `RangeError` scripts can catch, or stops the
+    /// script outright, which is the default.
+    pub fn set_out_of_memory(&mut self, on_exceeded: OutOfMemory) {
+        self.interpreter.set_out_of_memory(on_exceeded);
+    }
+
+    /// An estimate of the bytes held by what scripts in this context created and still reference.
+    pub fn memory_usage(&self) -> usize {
+        self.interpreter.memory_usage()
+    }
+
+    /// Collects the objects and scopes scripts can no longer reach but that keep each other alive,
+    /// returning what was freed. Collections also happen on their own as scripts allocate.
+    pub fn gc(&mut self) -> GcStats {
+        self.interpreter.collect_garbage()
+    }
+
+    /// Everything garbage collection freed so far.
+    pub fn gc_stats(&self) -> GcStats {
+        self.interpreter.gc_stats()
+    }
+
+    /// How often property accesses by name found the property through their inline cache, which
+    /// only the bytecode engine has.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.interpreter.cache_stats()
+    }
+
+    /// Makes `function` callable from scripts as the global `name`.
+    pub fn register_function(&mut self, name: &str, function: impl Fn(&mut Interpreter, &[Value]) -> Result<Value, JsError> + 'static) {
+        let function: HostFunction = Rc::new(function);
+        let function = self.interpreter.create_native_function(name, 0, Rc::new(move |interpreter, _, args| {
+            host::call_host(interpreter, &function, args)
+        }));
+
+        self.set_global(name, function);
+    }
+
+    /// Installs the object described by `object` as the global `name`.
+    pub fn register_object(&mut self, name: &str, object: HostObject) {
+        let object = object.build(&mut self.interpreter);
+        self.set_global(name, Value::Object(object));
+    }
+
+    /// Converts a Rust value into one scripts can use.
+    pub fn to_js(&mut self, value: impl IntoJs) -> Result<Value, JsError> {
+        value.into_js(&mut self.interpreter)
+    }
+
+    /// Converts a script value, such as the result of `run`, into a Rust value.
+    pub fn from_js<T: FromJs>(&mut self, value: &Value) -> Result<T, JsError> {
+        T::from_js(&mut self.interpreter, value)
+    }
+
+    /// The value of the global variable `name`, if there is one.
+    pub fn get_global(&self, name: &str) -> Option<Value> {
+        self.global.borrow().get(name)
+    }
+
+    /// Defines or overwrites the global variable `name`.
+    pub fn set_global(&mut self, name: &str, value: Value) {
+        self.global.borrow_mut().define(name.to_string(), value);
+    }
+
+    /// Calls the global function `name`, followed by the microtasks it queued.
+    pub fn call_function(&mut self, name: &str, args: &[Value]) -> Result<Value, JsError> {
+        let function = self.get_global(name).ok_or_else(|| JsError::reference_error(format!("{} is not defined", name)))?;
+        self.interpreter.start_execution();
+        let result = self.interpreter.call_function(&function, Value::None, args).map_err(JsError::from);
+
+        self.perform_microtask_checkpoint()?;
+        result
+    }
+
+    pub fn repl (&mut self) {
+        loop {
+            print!("> ");
+            io::stdout().flush().unwrap();
+
+            let mut input = String::new();
+            let read = io::stdin().read_line(&mut input).unwrap();
+
+            if read == 0 || input.trim() == "exit" {
+                break;
+            }
+
+            match self.eval(&input) {
+                Ok(value) => println!("{}", self.interpreter.inspect(&value)),
+                Err(error) => println!("Uncaught {}", error),
+            }
+        }
+    }
+
+    pub fn parse_ast (&mut self, code: &str) -> Result<Vec<Stmt>, JsError> {
+        let mut lexer = Lexer::new(code);
+        self.tokens = lexer.tokenize()?;
+        self.line_breaks = lexer.line_breaks().to_vec();
+
+        let mut parser = Parser::new(&self.tokens, &self.line_breaks).with_lexer(&lexer);
+        self.ast = parser.parse()?;
+
+        if self.optimize {
+            Optimizer::optimize(&mut self.ast);
+        }
+
+        self.async_script = match parser.has_top_level_await() {
+            true => Some(Rc::new(Parser::lower_script(&self.ast)?)),
+            false => None,
+        };
+
+        Ok(self.ast.clone())
+    }
+
+    /// Runs a script in the global scope of this context, returning its completion value.
+    pub fn eval (&mut self, code: &str) -> Result<Value, JsError> {
+        self.interpreter.start_execution();
+        let ast = self.parse_ast(code)?;
+
+        if let Some(code) = self.async_script.take() {
+            return self.eval_async_script(&ast, code);
+        }
+
+        let result = self.interpreter.eval_script(&ast);
+
+        for warning in self.interpreter.take_warnings() {
+            (self.warning_handler)(&warning);
+        }
+
+        let result = match result {
+            Ok(value) | Err(Signal::Return(value)) => Ok(value),
+            Err(Signal::Break | Signal::Continue) => Ok(Value::None),
+            Err(Signal::Throw(value)) => Err(JsError::Thrown(value)),
+            Err(Signal::Terminate(termination)) => return Err(JsError::Terminated(termination)),
+        };
+
+        // the script is a macrotask, the microtasks it queued run as soon as it's done
+        self.perform_microtask_checkpoint()?;
+        result
+    }
+
+    /// Runs a script with top-level await, waiting for the promises it awaits to settle.
+    fn eval_async_script(&mut self, ast: &[Stmt], code: Rc<GeneratorCode>) -> Result<Value, JsError> {
+        self.interpreter.check_script(ast);
+
+        for warning in self.interpreter.take_warnings() {
+            (self.warning_handler)(&warning);
+        }
+
+        let promise = self.interpreter.eval_async(ast, code);
+
+        // the outcome is reported here, not as an unhandled rejection
+        self.interpreter.perform_then(&promise, Value::None, Value::None, None);
+
+        // the event loop keeps going until the script is done, which may take timers firing
+        loop {
+            self.perform_microtask_checkpoint()?;
+
+            let state = match &promise.borrow().kind {
+                ObjectKind::Promise(state, _) => state.clone(),
+                _ => unreachable!("eval_async returns a promise"),
+            };
+
+            match state {
+                PromiseState::Fulfilled(value) => return Ok(value),
+                PromiseState::Rejected(reason) => return Err(JsError::Thrown(reason)),
+                PromiseState::Pending(_) if self.interpreter.next_timer_deadline().is_some() => self.run_next_timer()?,
+                PromiseState::Pending(_) => return Err(JsError::error("top-level await did not settle")),
+            }
+        }
+    }
+
+    /// Drains the microtask queue, then reports the promises left rejected without a handler.
+    fn perform_microtask_checkpoint (&mut self) -> Result<(), JsError> {
+        self.interpreter.run_jobs().map_err(JsError::from)?;
+
+        for reason in self.interpreter.take_unhandled_rejections() {
+            (self.rejection_handler)(&reason);
+        }
+
+        Ok(())
+    }
+
+    /// Runs the next timer as a macrotask of its own, followed by the microtasks it queued.
+    fn run_next_timer (&mut self) -> Result<(), JsError> {
+        self.interpreter.run_next_timer().map_err(JsError::from)?;
+        self.perform_microtask_checkpoint()
+    }
+
+    /// Keeps running queued work, waiting for timers to come due, until there is none left. Stops
+    /// at the first exception nothing catches.
+    pub fn run_event_loop (&mut self) -> Result<(), JsError> {
+        self.interpreter.start_execution();
+        self.perform_microtask_checkpoint()?;
+
+        while self.interpreter.next_timer_deadline().is_some() {
+            self.run_next_timer()?;
+        }
+
+        Ok(())
+    }
+
+    /// Lets `ms` milliseconds pass, running the timers that come due in that time. With a
+    /// `VirtualClock` this happens instantly.
+    pub fn advance_time (&mut self, ms: u64) -> Result<(), JsError> {
+        let target = self.interpreter.now() + ms;
+        self.interpreter.start_execution();
+        self.perform_microtask_checkpoint()?;
+
+        while self.interpreter.next_timer_deadline().is_some_and(|deadline| deadline <= target) {
+            self.run_next_timer()?;
+        }
+
+        self.interpreter.wait_until(target);
+        Ok(())
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use crate::builtins::own_enumerable_keys;
+use crate::error::JsError;
+use crate::interpreter::Interpreter;
+use crate::object::{ObjectKind, ObjectRef};
+use crate::types::Value;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "serde")]
+pub use self::serde::{from_value, to_value, Serde};
+
+/// Rust values that can be handed to scripts.
+pub trait IntoJs {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError>;
+}
+
+/// Rust values that can be read back from script values, failing with a `TypeError` when the
+/// value has the wrong type.
+pub trait FromJs: Sized {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError>;
+}
+
+/// What a value is called in conversion errors.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(object) if object.is_array() => "array",
+        value => value.type_of(),
+    }
+}
+
+fn mismatch(expected: &str, value: &Value) -> JsError {
+    JsError::type_error(format!("Expected {}, got {}", expected, type_name(value)))
+}
+
+impl IntoJs for Value {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(self)
+    }
+}
+
+impl FromJs for Value {
+    fn from_js(_: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        Ok(value.clone())
+    }
+}
+
+impl IntoJs for ObjectRef {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::Object(self))
+    }
+}
+
+impl FromJs for ObjectRef {
+    fn from_js(_: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        match value {
+            Value::Object(object) => Ok(object.clone()),
+            value => Err(mismatch("an object", value)),
+        }
+    }
+}
+
+impl IntoJs for () {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::None)
+    }
+}
+
+impl FromJs for () {
+    fn from_js(_: &mut Interpreter, _: &Value) -> Result<Self, JsError> {
+        Ok(())
+    }
+}
+
+impl IntoJs for bool {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::Boolean(self))
+    }
+}
+
+impl FromJs for bool {
+    fn from_js(_: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        match value {
+            Value::Boolean(boolean) => Ok(*boolean),
+            value => Err(mismatch("a boolean", value)),
+        }
+    }
+}
+
+impl IntoJs for f64 {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::Float(self))
+    }
+}
+
+impl FromJs for f64 {
+    fn from_js(_: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        match value {
+            Value::Float(number) => Ok(*number),
+            value => Err(mismatch("a number", value)),
+        }
+    }
+}
+
+impl IntoJs for f32 {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::Float(self as f64))
+    }
+}
+
+impl FromJs for f32 {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        f64::from_js(interpreter, value).map(|number| number as f32)
+    }
+}
+
+/// The first number past the largest value of an integer type, which unlike `MAX` is exact as a
+/// number for every type.
+macro_rules! upper_bound {
+    ($integer:ty) => {
+        <$integer>::MIN as f64 + 2f64.powi(<$integer>::BITS as i32)
+    };
+}
+
+/// Integers convert to numbers when a number holds them exactly, and back only from numbers
+/// without a fraction that fit.
+macro_rules! integer_conversions {
+    ($($integer:ty),*) => {
+        $(
+            impl IntoJs for $integer {
+                fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+                    let number = self as f64;
+
+                    // `MAX` as a number rounds up to the bound for the 64-bit types, and casting
+                    // back saturates, so the bound is checked first
+                    if number >= upper_bound!($integer) || number as $integer != self {
+                        return Err(JsError::range_error(format!("{} can't be represented exactly as a number", self)));
+                    }
+
+                    Ok(Value::Float(number))
+                }
+            }
+
+            impl FromJs for $integer {
+                fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+                    let number = f64::from_js(interpreter, value)?;
+
+                    if number.fract() != 0.0 || number < <$integer>::MIN as f64 || number >= upper_bound!($integer) || number.is_nan() {
+                        let message = format!("Expected an integer between {} and {}, got {}", <$integer>::MIN, <$integer>::MAX, value.to_display_string());
+                        return Err(JsError::range_error(message));
+                    }
+
+                    Ok(number as $integer)
+                }
+            }
+        )*
+    };
+}
+
+integer_conversions!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl IntoJs for String {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::StringLiteral(self.into()))
+    }
+}
+
+impl IntoJs for &str {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::StringLiteral(self.to_string().into()))
+    }
+}
+
+impl FromJs for String {
+    fn from_js(_: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        match value {
+            Value::StringLiteral(string) => Ok(string.to_string()),
+            value => Err(mismatch("a string", value)),
+        }
+    }
+}
+
+impl IntoJs for char {
+    fn into_js(self, _: &mut Interpreter) -> Result<Value, JsError> {
+        Ok(Value::StringLiteral(self.to_string().into()))
+    }
+}
+
+impl FromJs for char {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        let string = String::from_js(interpreter, value)?;
+        let mut chars = string.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(JsError::type_error(format!("Expected a single character, got {:?}", string))),
+        }
+    }
+}
+
+/// `None` is `undefined`, and both `undefined` and `null` read back as `None`.
+impl<T: IntoJs> IntoJs for Option<T> {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+        match self {
+            Some(value) => value.into_js(interpreter),
+            None => Ok(Value::None),
+        }
+    }
+}
+
+impl<T: FromJs> FromJs for Option<T> {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        match value {
+            Value::None | Value::Null => Ok(None),
+            value => T::from_js(interpreter, value).map(Some),
+        }
+    }
+}
+
+/// An `Err` is thrown into the script.
+impl<T: IntoJs, E: Into<JsError>> IntoJs for Result<T, E> {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+        self.map_err(Into::into)?.into_js(interpreter)
+    }
+}
+
+/// Reading never fails, a value of the wrong type becomes the `Err`.
+impl<T: FromJs> FromJs for Result<T, JsError> {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        Ok(T::from_js(interpreter, value))
+    }
+}
+
+impl<T: IntoJs> IntoJs for Vec<T> {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+        let elements = self.into_iter().map(|element| element.into_js(interpreter)).collect::<Result<_, _>>()?;
+        Ok(interpreter.create_array(elements))
+    }
+}
+
+impl<T: FromJs> FromJs for Vec<T> {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        array_elements(value)?.iter().map(|element| T::from_js(interpreter, element)).collect()
+    }
+}
+
+/// The elements of an array, which the conversions from arrays start with.
+fn array_elements(value: &Value) -> Result<Vec<Value>, JsError> {
+    match value {
+        Value::Object(object) => match &object.borrow().kind {
+            ObjectKind::Array(elements) => Ok(elements.clone()),
+            _ => Err(mismatch("an array", value)),
+        },
+        value => Err(mismatch("an array", value)),
+    }
+}
+
+/// Maps become plain objects keyed by their keys, and are read back from an object's own
+/// enumerable properties.
+fn entries_into_js<V: IntoJs>(interpreter: &mut Interpreter, entries: impl Iterator<Item = (String, V)>) -> Result<Value, JsError> {
+    let object = interpreter.create_object();
+
+    for (key, value) in entries {
+        let value = value.into_js(interpreter)?;
+        object.set(key, value);
+    }
+
+    Ok(Value::Object(object))
+}
+
+fn entries_from_js<V: FromJs>(interpreter: &mut Interpreter, value: &Value) -> Result<Vec<(String, V)>, JsError> {
+    if !matches!(value, Value::Object(object) if !object.is_array()) {
+        return Err(mismatch("an object", value));
+    }
+
+    let mut entries = Vec::new();
+
+    for key in own_enumerable_keys(interpreter, value)? {
+        let property = interpreter.get_property(value, key.clone())?;
+        entries.push((key.to_string(), V::from_js(interpreter, &property)?));
+    }
+
+    Ok(entries)
+}
+
+impl<K: Into<String>, V: IntoJs, S> IntoJs for HashMap<K, V, S> {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+        entries_into_js(interpreter, self.into_iter().map(|(key, value)| (key.into(), value)))
+    }
+}
+
+impl<K: From<String> + Eq + Hash, V: FromJs, S: BuildHasher + Default> FromJs for HashMap<K, V, S> {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        let entries = entries_from_js(interpreter, value)?;
+        Ok(entries.into_iter().map(|(key, value)| (K::from(key), value)).collect())
+    }
+}
+
+impl<K: Into<String>, V: IntoJs> IntoJs for BTreeMap<K, V> {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+        entries_into_js(interpreter, self.into_iter().map(|(key, value)| (key.into(), value)))
+    }
+}
+
+impl<K: From<String> + Ord, V: FromJs> FromJs for BTreeMap<K, V> {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        let entries = entries_from_js(interpreter, value)?;
+        Ok(entries.into_iter().map(|(key, value)| (K::from(key), value)).collect())
+    }
+}
+
+/// Tuples are arrays with an element per field. Reading one needs at least as many elements.
+macro_rules! tuple_conversions {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(
+            impl<$($name: IntoJs),+> IntoJs for ($($name,)+) {
+                fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+                    let elements = vec![$(self.$index.into_js(interpreter)?),+];
+                    Ok(interpreter.create_array(elements))
+                }
+            }
+
+            impl<$($name: FromJs),+> FromJs for ($($name,)+) {
+                fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+                    let elements = array_elements(value)?;
+                    let length = [$($index),+].len();
+
+                    if elements.len() < length {
+                        return Err(JsError::type_error(format!("Expected an array of {} elements, got {}", length, elements.len())));
+                    }
+
+                    Ok(($($name::from_js(interpreter, &elements[$index])?,)+))
+                }
+            }
+        )*
+    };
+}
+
+tuple_conversions!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+);
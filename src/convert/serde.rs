@@ -0,0 +1,593 @@
+use std::fmt;
+use ::serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor};
+use ::serde::ser::{self, Serialize};
+use ::serde::{forward_to_deserialize_any, Deserializer};
+use crate::builtins::own_enumerable_keys;
+use crate::error::JsError;
+use crate::interpreter::Interpreter;
+use crate::object::{ObjectKind, ObjectRef};
+use crate::types::Value;
+use super::{FromJs, IntoJs};
+
+/// Converts any `Serialize` type into script values the way `serde_json` would into JSON: structs
+/// and maps become objects, sequences and tuples arrays and `None` and `()` become `null`.
+pub fn to_value<T: Serialize + ?Sized>(interpreter: &mut Interpreter, value: &T) -> Result<Value, JsError> {
+    value.serialize(ValueSerializer { interpreter })
+}
+
+/// Reads a `Deserialize` type back from a script value, failing with a `TypeError` that says
+/// where the value doesn't fit.
+pub fn from_value<T: DeserializeOwned>(interpreter: &mut Interpreter, value: &Value) -> Result<T, JsError> {
+    T::deserialize(ValueDeserializer { interpreter, value: value.clone() })
+}
+
+/// Converts its contents with serde, wherever `IntoJs` and `FromJs` are expected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Serde<T>(pub T);
+
+impl<T: Serialize> IntoJs for Serde<T> {
+    fn into_js(self, interpreter: &mut Interpreter) -> Result<Value, JsError> {
+        to_value(interpreter, &self.0)
+    }
+}
+
+impl<T: DeserializeOwned> FromJs for Serde<T> {
+    fn from_js(interpreter: &mut Interpreter, value: &Value) -> Result<Self, JsError> {
+        from_value(interpreter, value).map(Serde)
+    }
+}
+
+impl ser::Error for JsError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        JsError::type_error(message.to_string())
+    }
+}
+
+impl de::Error for JsError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        JsError::type_error(message.to_string())
+    }
+}
+
+struct ValueSerializer<'a> {
+    interpreter: &'a mut Interpreter,
+}
+
+/// Collects the elements of sequences and tuples, wrapped in `{ variant: [...] }` for tuple variants.
+struct ArraySerializer<'a> {
+    interpreter: &'a mut Interpreter,
+    elements: Vec<Value>,
+    variant: Option<&'static str>,
+}
+
+/// Fills in an object for maps and structs, wrapped in `{ variant: {...} }` for struct variants.
+struct ObjectSerializer<'a> {
+    interpreter: &'a mut Interpreter,
+    object: ObjectRef,
+    key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl ValueSerializer<'_> {
+    fn wrap(interpreter: &mut Interpreter, variant: Option<&'static str>, value: Value) -> Value {
+        match variant {
+            Some(variant) => {
+                let wrapper = interpreter.create_object();
+                wrapper.set(variant, value);
+                Value::Object(wrapper)
+            },
+            None => value,
+        }
+    }
+}
+
+impl<'a> ser::Serializer for ValueSerializer<'a> {
+    type Ok = Value;
+    type Error = JsError;
+    type SerializeSeq = ArraySerializer<'a>;
+    type SerializeTuple = ArraySerializer<'a>;
+    type SerializeTupleStruct = ArraySerializer<'a>;
+    type SerializeTupleVariant = ArraySerializer<'a>;
+    type SerializeMap = ObjectSerializer<'a>;
+    type SerializeStruct = ObjectSerializer<'a>;
+    type SerializeStructVariant = ObjectSerializer<'a>;
+
+    fn serialize_bool(self, value: bool) -> Result<Value, JsError> {
+        Ok(Value::Boolean(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Value, JsError> {
+        value.into_js(self.interpreter)
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value, JsError> {
+        value.into_js(self.interpreter)
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value, JsError> {
+        Ok(Value::Float(value as f64))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value, JsError> {
+        Ok(Value::Float(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value, JsError> {
+        Ok(Value::StringLiteral(value.to_string().into()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value, JsError> {
+        Ok(Value::StringLiteral(value.into()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value, JsError> {
+        let elements = value.iter().map(|byte| Value::Float(*byte as f64)).collect();
+        Ok(self.interpreter.create_array(elements))
+    }
+
+    fn serialize_none(self) -> Result<Value, JsError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, JsError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, JsError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, JsError> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<Value, JsError> {
+        Ok(Value::StringLiteral(variant.into()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<Value, JsError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, JsError> {
+        let value = value.serialize(ValueSerializer { interpreter: &mut *self.interpreter })?;
+        Ok(Self::wrap(self.interpreter, Some(variant), value))
+    }
+
+    fn serialize_seq(self, length: Option<usize>) -> Result<ArraySerializer<'a>, JsError> {
+        Ok(ArraySerializer { interpreter: self.interpreter, elements: Vec::with_capacity(length.unwrap_or(0)), variant: None })
+    }
+
+    fn serialize_tuple(self, length: usize) -> Result<ArraySerializer<'a>, JsError> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, length: usize) -> Result<ArraySerializer<'a>, JsError> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        length: usize,
+    ) -> Result<ArraySerializer<'a>, JsError> {
+        Ok(ArraySerializer { interpreter: self.interpreter, elements: Vec::with_capacity(length), variant: Some(variant) })
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<ObjectSerializer<'a>, JsError> {
+        let object = self.interpreter.create_object();
+        Ok(ObjectSerializer { interpreter: self.interpreter, object, key: None, variant: None })
+    }
+
+    fn serialize_struct(self, _: &'static str, length: usize) -> Result<ObjectSerializer<'a>, JsError> {
+        self.serialize_map(Some(length))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<ObjectSerializer<'a>, JsError> {
+        let object = self.interpreter.create_object();
+        Ok(ObjectSerializer { interpreter: self.interpreter, object, key: None, variant: Some(variant) })
+    }
+}
+
+impl ArraySerializer<'_> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsError> {
+        let value = value.serialize(ValueSerializer { interpreter: &mut *self.interpreter })?;
+        self.elements.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, JsError> {
+        let array = self.interpreter.create_array(self.elements);
+        Ok(ValueSerializer::wrap(self.interpreter, self.variant, array))
+    }
+}
+
+impl ser::SerializeSeq for ArraySerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for ArraySerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for ArraySerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for ArraySerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+impl ObjectSerializer<'_> {
+    fn set<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<(), JsError> {
+        let value = value.serialize(ValueSerializer { interpreter: &mut *self.interpreter })?;
+        self.object.set(key, value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, JsError> {
+        Ok(ValueSerializer::wrap(self.interpreter, self.variant, Value::Object(self.object)))
+    }
+}
+
+impl ser::SerializeMap for ObjectSerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), JsError> {
+        // keys become property names, so only those that have a string form will do
+        let key = match key.serialize(ValueSerializer { interpreter: &mut *self.interpreter })? {
+            Value::StringLiteral(key) => key.to_string(),
+            key @ (Value::Float(_) | Value::Boolean(_)) => key.to_display_string(),
+            _ => return Err(JsError::type_error("Map keys must be strings, numbers or booleans")),
+        };
+
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JsError> {
+        let key = self.key.take().expect("serialize_value is called after serialize_key");
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for ObjectSerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), JsError> {
+        self.set(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for ObjectSerializer<'_> {
+    type Ok = Value;
+    type Error = JsError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), JsError> {
+        self.set(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value, JsError> {
+        self.finish()
+    }
+}
+
+struct ValueDeserializer<'a> {
+    interpreter: &'a mut Interpreter,
+    value: Value,
+}
+
+impl ValueDeserializer<'_> {
+    /// What the value is called when it doesn't fit, in serde's terms.
+    fn unexpected(&self) -> de::Unexpected<'_> {
+        match &self.value {
+            Value::Float(number) => de::Unexpected::Float(*number),
+            Value::StringLiteral(string) => de::Unexpected::Str(string),
+            Value::Boolean(boolean) => de::Unexpected::Bool(*boolean),
+            Value::Object(object) if object.is_array() => de::Unexpected::Seq,
+            Value::Object(object) if object.is_callable() => de::Unexpected::Other("function"),
+            Value::Object(_) => de::Unexpected::Map,
+            Value::Symbol(_) => de::Unexpected::Other("symbol"),
+            Value::Null | Value::None => de::Unexpected::Unit,
+        }
+    }
+
+    /// The own enumerable properties of an object, or `None` for anything else.
+    fn entries(&mut self) -> Result<Option<Vec<(String, Value)>>, JsError> {
+        match &self.value {
+            Value::Object(object) if !object.is_callable() && !object.is_array() => (),
+            _ => return Ok(None),
+        }
+
+        let mut entries = Vec::new();
+
+        for key in own_enumerable_keys(self.interpreter, &self.value)? {
+            let value = self.interpreter.get_property(&self.value, key.clone())?;
+            entries.push((key.to_string(), value));
+        }
+
+        Ok(Some(entries))
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = JsError;
+
+    fn deserialize_any<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, JsError> {
+        match &self.value {
+            // integral numbers are offered as integers, so that they fit integer fields
+            Value::Float(number) if number.fract() == 0.0 && number.abs() <= 9007199254740991.0 => match *number >= 0.0 {
+                true => visitor.visit_u64(*number as u64),
+                false => visitor.visit_i64(*number as i64),
+            },
+            Value::Float(number) => visitor.visit_f64(*number),
+            Value::StringLiteral(string) => visitor.visit_str(string),
+            Value::Boolean(boolean) => visitor.visit_bool(*boolean),
+            Value::Null | Value::None => visitor.visit_unit(),
+            Value::Object(object) if object.is_array() => {
+                let elements = match &object.borrow().kind {
+                    ObjectKind::Array(elements) => elements.clone(),
+                    _ => unreachable!("arrays hold their elements"),
+                };
+
+                visitor.visit_seq(ArrayAccess { interpreter: self.interpreter, elements: elements.into_iter() })
+            },
+            _ => match self.entries()? {
+                Some(entries) => visitor.visit_map(ObjectAccess { interpreter: self.interpreter, entries: entries.into_iter(), value: None }),
+                None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+            },
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, JsError> {
+        match self.value {
+            Value::Null | Value::None => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, JsError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Enums are read from a string for unit variants, or from `{ variant: value }`.
+    fn deserialize_enum<V: Visitor<'de>>(
+        mut self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, JsError> {
+        if let Value::StringLiteral(variant) = &self.value {
+            return visitor.visit_enum(variant.clone().into_deserializer());
+        }
+
+        match self.entries()? {
+            Some(entries) if entries.len() == 1 => {
+                let (variant, value) = entries.into_iter().next().expect("there is one entry");
+                visitor.visit_enum(EnumAccess { interpreter: self.interpreter, variant, value })
+            },
+            _ => Err(de::Error::invalid_type(self.unexpected(), &"a string or an object with a single key")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ArrayAccess<'a> {
+    interpreter: &'a mut Interpreter,
+    elements: std::vec::IntoIter<Value>,
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess<'_> {
+    type Error = JsError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, JsError> {
+        match self.elements.next() {
+            Some(value) => seed.deserialize(ValueDeserializer { interpreter: &mut *self.interpreter, value }).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+struct ObjectAccess<'a> {
+    interpreter: &'a mut Interpreter,
+    entries: std::vec::IntoIter<(String, Value)>,
+    // the value of the entry whose key was just read
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'_> {
+    type Error = JsError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, JsError> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(KeyDeserializer(key)).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, JsError> {
+        let value = self.value.take().expect("next_value_seed is called after next_key_seed");
+        seed.deserialize(ValueDeserializer { interpreter: &mut *self.interpreter, value })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct EnumAccess<'a> {
+    interpreter: &'a mut Interpreter,
+    variant: String,
+    value: Value,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for EnumAccess<'a> {
+    type Error = JsError;
+    type Variant = ValueDeserializer<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, ValueDeserializer<'a>), JsError> {
+        let variant = seed.deserialize(KeyDeserializer(self.variant))?;
+        Ok((variant, ValueDeserializer { interpreter: self.interpreter, value: self.value }))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ValueDeserializer<'_> {
+    type Error = JsError;
+
+    fn unit_variant(self) -> Result<(), JsError> {
+        match self.value {
+            Value::Null | Value::None => Ok(()),
+            _ => Err(de::Error::invalid_type(self.unexpected(), &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, JsError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, JsError> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, JsError> {
+        self.deserialize_any(visitor)
+    }
+}
+
+/// Property names, which are strings but may be read as numbers for maps with numeric keys.
+struct KeyDeserializer(String);
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, JsError> {
+                match self.0.parse() {
+                    Ok(number) => visitor.$visit(number),
+                    Err(_) => Err(de::Error::invalid_type(de::Unexpected::Str(&self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for KeyDeserializer {
+    type Error = JsError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, JsError> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_bool => visit_bool
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+use crate::types::{Signal, Value};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Error,
+    TypeError,
+    RangeError,
+    ReferenceError,
+    SyntaxError,
+    AggregateError,
+}
+
+impl ErrorKind {
+    pub const ALL: [ErrorKind; 6] = [
+        ErrorKind::Error,
+        ErrorKind::TypeError,
+        ErrorKind::RangeError,
+        ErrorKind::ReferenceError,
+        ErrorKind::SyntaxError,
+        ErrorKind::AggregateError,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorKind::Error => "Error",
+            ErrorKind::TypeError => "TypeError",
+            ErrorKind::RangeError => "RangeError",
+            ErrorKind::ReferenceError => "ReferenceError",
+            ErrorKind::SyntaxError => "SyntaxError",
+            ErrorKind::AggregateError => "AggregateError",
+        }
+    }
+}
+
+/// Why a script was stopped before it finished, see `Context::set_fuel` and friends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    OutOfFuel,
+    DeadlineExceeded,
+    Interrupted,
+    OutOfMemory,
+}
+
+/// An error crossing the boundary between scripts and the host.
+///
+/// `Native` errors are turned into the matching built-in error object when they are thrown into a
+/// script, `Thrown` carries whatever value a script threw and nobody caught. `Terminated` means
+/// the host's limits stopped the script.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsError {
+    Native(ErrorKind, String),
+    Thrown(Value),
+    Terminated(Termination),
+}
+
+impl JsError {
+    pub fn error(message: impl Into<String>) -> Self {
+        JsError::Native(ErrorKind::Error, message.into())
+    }
+
+    pub fn type_error(message: impl Into<String>) -> Self {
+        JsError::Native(ErrorKind::TypeError, message.into())
+    }
+
+    pub fn range_error(message: impl Into<String>) -> Self {
+        JsError::Native(ErrorKind::RangeError, message.into())
+    }
+
+    pub fn reference_error(message: impl Into<String>) -> Self {
+        JsError::Native(ErrorKind::ReferenceError, message.into())
+    }
+
+    pub fn syntax_error(message: impl Into<String>) -> Self {
+        JsError::Native(ErrorKind::SyntaxError, message.into())
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsError::Native(kind, message) if message.is_empty() => write!(f, "{}", kind.name()),
+            JsError::Native(kind, message) => write!(f, "{}: {}", kind.name(), message),
+            JsError::Thrown(Value::Object(object)) => {
+                let name = object.get("name").map(|name| name.to_display_string());
+                let message = object.get("message").map(|message| message.to_display_string());
+
+                match (name, message) {
+                    (Some(name), Some(message)) if message.is_empty() => write!(f, "{}", name),
+                    (Some(name), Some(message)) => write!(f, "{}: {}", name, message),
+                    _ => write!(f, "[object Object]"),
+                }
+            }
+            JsError::Thrown(value) => write!(f, "{}", value.to_display_string()),
+            JsError::Terminated(Termination::OutOfFuel) => write!(f, "Script ran out of fuel"),
+            JsError::Terminated(Termination::DeadlineExceeded) => write!(f, "Script exceeded its time limit"),
+            JsError::Terminated(Termination::Interrupted) => write!(f, "Script was interrupted"),
+            JsError::Terminated(Termination::OutOfMemory) => write!(f, "Script ran out of memory"),
+        }
+    }
+}
+
+impl std::error::Error for JsError {}
+
+/// An exception a call threw that nothing caught, so that host functions can use `?` on the
+/// interpreter's results.
+impl From<Signal> for JsError {
+    fn from(signal: Signal) -> Self {
+        match signal {
+            Signal::Throw(value) => JsError::Thrown(value),
+            Signal::Terminate(termination) => JsError::Terminated(termination),
+            // calls complete with returns, breaks and continues before they get this far
+            signal => unreachable!("{:?} escaped a call", signal),
+        }
+    }
+}
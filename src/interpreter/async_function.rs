@@ -0,0 +1,71 @@
+use std::rc::Rc;
+use crate::builtins::argument;
+use crate::object::{Closure, Object, ObjectKind, ObjectRef};
+use crate::types::{GeneratorCode, Signal, Stmt, Value};
+use super::generator::{GeneratorFrame, Outcome};
+use super::{EvalResult, GeneratorState, Interpreter, ResumeMode};
+
+impl Interpreter {
+    /// Calls an async function: runs the body up to its first `await` and returns a promise for
+    /// what it eventually returns.
+    pub(super) fn start_async_function(&mut self, closure: &Closure, this: Value, args: &[Value]) -> EvalResult {
+        let promise = self.create_promise();
+
+        match self.create_frame(closure, this, args) {
+            Ok(frame) => self.start_async_body(frame, &promise),
+            // binding the arguments happens inside the function, so its errors reject the promise
+            Err(Signal::Throw(error)) => self.reject_promise(&promise, error),
+            Err(signal) => return Err(signal),
+        }
+
+        Ok(Value::Object(promise))
+    }
+
+    /// Runs a script that uses top-level await as the body of an async function in the current
+    /// scope, returning the promise for its completion value.
+    pub fn eval_async(&mut self, stmts: &[Stmt], code: Rc<GeneratorCode>) -> ObjectRef {
+        let promise = self.create_promise();
+
+        self.prepare_lowered_body(stmts, &code);
+        let frame = GeneratorFrame::new(code, None, self.scope.clone());
+        self.start_async_body(frame, &promise);
+
+        promise
+    }
+
+    fn start_async_body(&mut self, frame: GeneratorFrame, promise: &ObjectRef) {
+        // the suspended body is kept in a generator object that scripts never get to see
+        let state = GeneratorState::SuspendedStart(Box::new(frame));
+        let body = self.allocate_object(Object::new(None, ObjectKind::Generator(state)));
+
+        self.continue_async(&body, promise, ResumeMode::Next, Value::None);
+    }
+
+    /// Resumes the body until it awaits something, or until it completes and settles `promise`.
+    fn continue_async(&mut self, body: &ObjectRef, promise: &ObjectRef, mode: ResumeMode, value: Value) {
+        match self.resume(body, mode, value) {
+            Ok(Outcome::Await(awaited)) => self.await_value(body, promise, awaited),
+            Ok(Outcome::Return(value)) => self.resolve_promise(promise, value),
+            Ok(Outcome::Yield(_) | Outcome::Delegate(_)) => unreachable!("async functions can't contain yield"),
+            Err(Signal::Throw(error)) => self.reject_promise(promise, error),
+            Err(_) => self.resolve_promise(promise, Value::None),
+        }
+    }
+
+    /// `Await`: continues the body with the value `value` fulfills with, or throws what it
+    /// rejects with into it.
+    fn await_value(&mut self, body: &ObjectRef, promise: &ObjectRef, value: Value) {
+        let awaited = self.promise_resolve(value);
+
+        let [on_fulfilled, on_rejected] = [ResumeMode::Next, ResumeMode::Throw].map(|mode| {
+            let (body, promise) = (body.clone(), promise.clone());
+
+            self.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+                interpreter.continue_async(&body, &promise, mode, argument(args, 0));
+                Ok(Value::None)
+            }))
+        });
+
+        self.perform_then(&awaited, on_fulfilled, on_rejected, None);
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use std::rc::Rc;
+use crate::builtins::argument;
+use crate::error::ErrorKind;
+use crate::object::{Closure, Object, ObjectKind, ObjectRef};
+use crate::types::{Signal, Value};
+use super::generator::Outcome;
+use super::heap::{trace_value, Visit};
+use super::{EvalResult, GeneratorState, Interpreter, ResumeMode};
+
+/// An async generator: its suspended body and the `next`, `throw` and `return` calls waiting on
+/// it, which it answers one at a time.
+#[derive(Clone)]
+pub struct AsyncGeneratorState {
+    // the body is kept in a generator object that scripts never get to see
+    body: ObjectRef,
+    // how each call resumes the body and the promise it returned, oldest first
+    queue: VecDeque<(ResumeMode, Value, ObjectRef)>,
+    // whether the body is running or awaiting something for the oldest call
+    running: bool,
+}
+
+/// What the body is waiting on to settle.
+#[derive(Clone, Copy)]
+enum Awaiting {
+    Await,
+    Yield,
+    Return,
+}
+
+impl AsyncGeneratorState {
+    /// Tells the collector about the body and the calls waiting on it.
+    pub(super) fn trace(&self, visit: &mut Visit) {
+        visit(self.body.as_ptr() as usize);
+
+        for (_, value, promise) in &self.queue {
+            trace_value(value, visit);
+            visit(promise.as_ptr() as usize);
+        }
+    }
+}
+
+impl Interpreter {
+    /// Calls an async generator function: binds the arguments and returns the async generator,
+    /// without running any of the body yet.
+    pub(super) fn start_async_generator(&mut self, function: &Value, closure: &Closure, this: Value, args: &[Value]) -> EvalResult {
+        let frame = self.create_frame(closure, this, args)?;
+
+        let prototype = match self.get_property(function, "prototype")? {
+            Value::Object(prototype) => prototype,
+            _ => self.intrinsics.async_generator_prototype.clone(),
+        };
+
+        let body = self.allocate_object(Object::new(None, ObjectKind::Generator(GeneratorState::SuspendedStart(Box::new(frame)))));
+        let state = AsyncGeneratorState { body, queue: VecDeque::new(), running: false };
+        Ok(Value::Object(self.allocate_object(Object::new(Some(prototype), ObjectKind::AsyncGenerator(state)))))
+    }
+
+    /// Queues a `next`, `throw` or `return` call on an async generator, returning the promise for
+    /// the iterator result it produces.
+    pub fn resume_async_generator(&mut self, generator: &Value, mode: ResumeMode, value: Value) -> Value {
+        let promise = self.create_promise();
+
+        match generator {
+            Value::Object(object) if matches!(object.borrow().kind, ObjectKind::AsyncGenerator(_)) => {
+                if let ObjectKind::AsyncGenerator(state) = &mut object.borrow_mut().kind {
+                    state.queue.push_back((mode, value, promise.clone()));
+                }
+
+                self.drain_async_generator(object);
+            },
+            _ => {
+                let message = format!("{} method called on incompatible receiver {}", mode.method_name(), generator.to_display_string());
+                let error = self.create_error(ErrorKind::TypeError, message);
+                self.reject_promise(&promise, error);
+            },
+        }
+
+        Value::Object(promise)
+    }
+
+    /// Resumes the body for the oldest waiting call, unless it is already busy with one.
+    fn drain_async_generator(&mut self, generator: &ObjectRef) {
+        let (body, mode, value) = match &mut generator.borrow_mut().kind {
+            ObjectKind::AsyncGenerator(state) if !state.running => match state.queue.front() {
+                Some((mode, value, _)) => {
+                    state.running = true;
+                    (state.body.clone(), *mode, value.clone())
+                },
+                None => return,
+            },
+            _ => return,
+        };
+
+        self.continue_async_generator(generator, &body, mode, value);
+    }
+
+    /// Runs the body until it awaits, yields or completes, and waits for what it produced.
+    fn continue_async_generator(&mut self, generator: &ObjectRef, body: &ObjectRef, mode: ResumeMode, value: Value) {
+        match self.resume(body, mode, value) {
+            Ok(Outcome::Await(value)) => self.await_async_generator(generator, body, Awaiting::Await, value),
+            // the values yielded and returned are awaited before they are handed out
+            Ok(Outcome::Yield(value)) => self.await_async_generator(generator, body, Awaiting::Yield, value),
+            Ok(Outcome::Return(value)) => self.await_async_generator(generator, body, Awaiting::Return, value),
+            Ok(Outcome::Delegate(_)) => unreachable!("async generators can't contain yield*"),
+            Err(Signal::Throw(error)) => self.complete_async_generator(generator, Err(error)),
+            Err(_) => {
+                let result = self.create_iter_result(Value::None, true);
+                self.complete_async_generator(generator, Ok(result));
+            },
+        }
+    }
+
+    fn await_async_generator(&mut self, generator: &ObjectRef, body: &ObjectRef, awaiting: Awaiting, value: Value) {
+        let awaited = self.promise_resolve(value);
+
+        let [on_fulfilled, on_rejected] = [ResumeMode::Next, ResumeMode::Throw].map(|mode| {
+            let (generator, body) = (generator.clone(), body.clone());
+
+            self.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+                let value = argument(args, 0);
+
+                match (awaiting, mode) {
+                    (Awaiting::Await, mode) | (Awaiting::Yield, mode @ ResumeMode::Throw) => {
+                        interpreter.continue_async_generator(&generator, &body, mode, value);
+                    },
+                    (Awaiting::Yield, _) => {
+                        let result = interpreter.create_iter_result(value, false);
+                        interpreter.complete_async_generator(&generator, Ok(result));
+                    },
+                    (Awaiting::Return, ResumeMode::Throw) => interpreter.complete_async_generator(&generator, Err(value)),
+                    (Awaiting::Return, _) => {
+                        let result = interpreter.create_iter_result(value, true);
+                        interpreter.complete_async_generator(&generator, Ok(result));
+                    },
+                }
+
+                Ok(Value::None)
+            }))
+        });
+
+        self.perform_then(&awaited, on_fulfilled, on_rejected, None);
+    }
+
+    /// Settles the promise of the oldest waiting call and moves on to the next one.
+    fn complete_async_generator(&mut self, generator: &ObjectRef, result: Result<Value, Value>) {
+        let request = match &mut generator.borrow_mut().kind {
+            ObjectKind::AsyncGenerator(state) => {
+                state.running = false;
+                state.queue.pop_front()
+            },
+            _ => None,
+        };
+
+        if let Some((_, _, promise)) = request {
+            match result {
+                Ok(result) => self.resolve_promise(&promise, result),
+                Err(error) => self.reject_promise(&promise, error),
+            }
+        }
+
+        self.drain_async_generator(generator);
+    }
+}
@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use crate::object::{Object, ObjectKind, ObjectRef, Property, PropertyKey, Shape, WeakObjectRef};
+use crate::types::Value;
+
+/// Sites that saw objects of more shapes than this stop remembering new ones.
+const MAX_CACHED_SHAPES: usize = 4;
+
+/// How often property accesses by name found what they were looking for in their inline cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Where objects of a shape keep the property a site reads or writes.
+enum Location {
+    Own(usize),
+    // on the prototype, while it has the same shape
+    Prototype(WeakObjectRef, Rc<Shape>, usize),
+    // nowhere on the object or its prototypes, each with the shape it had, so assigning adds it
+    // and moves the object to the shape after
+    Absent(Vec<(WeakObjectRef, Rc<Shape>)>, Rc<Shape>),
+}
+
+struct Entry {
+    shape: Rc<Shape>,
+    location: Location,
+}
+
+/// What a property access site in compiled code found for the shapes of the objects it saw, so
+/// that the next object with one of those shapes skips the lookup by key.
+#[derive(Default)]
+pub struct InlineCache(RefCell<Vec<Entry>>);
+
+impl InlineCache {
+    /// The property the site finds on `object`, if its shape was seen before.
+    pub fn get(&self, object: &ObjectRef) -> Option<Property> {
+        let object = object.borrow();
+        let entries = self.0.borrow();
+        let entry = entries.iter().find(|entry| has_shape(&object, &entry.shape))?;
+
+        match &entry.location {
+            Location::Own(position) => object.properties.get_at(*position).cloned(),
+            Location::Prototype(holder, shape, position) => {
+                let prototype = object.prototype.as_ref().filter(|prototype| prototype.as_ptr() == holder.as_ptr())?;
+                let prototype = prototype.borrow();
+
+                match Rc::ptr_eq(shape, prototype.properties.shape()) {
+                    true => prototype.properties.get_at(*position).cloned(),
+                    false => None,
+                }
+            },
+            Location::Absent(..) => None,
+        }
+    }
+
+    /// Assigns `value` to the writable data property the site finds on `object` itself, if its
+    /// shape was seen before, handing it back otherwise.
+    pub fn set(&self, object: &ObjectRef, value: Value) -> Result<(), Value> {
+        let mut object = object.borrow_mut();
+        let entries = self.0.borrow();
+
+        let position = entries.iter().find_map(|entry| match entry.location {
+            Location::Own(position) if has_shape(&object, &entry.shape) => Some(position),
+            _ => None,
+        });
+
+        match position.and_then(|position| object.properties.get_at_mut(position)) {
+            Some(property) if property.writable && !property.is_accessor() => {
+                property.value = value;
+                Ok(())
+            },
+            _ => Err(value),
+        }
+    }
+
+    /// The shape `object` moves to when the site adds the property to it, if objects of its shape
+    /// had it added before and nothing it inherits from changed since.
+    pub fn transition(&self, object: &ObjectRef) -> Option<Rc<Shape>> {
+        let object = object.borrow();
+        let entries = self.0.borrow();
+
+        entries.iter().find_map(|entry| match &entry.location {
+            Location::Absent(chain, next) if has_shape(&object, &entry.shape) && object.extensible => {
+                same_prototypes(&object, chain).then(|| next.clone())
+            },
+            _ => None,
+        })
+    }
+
+    /// Remembers where `object` keeps `key`, either itself or on its prototype.
+    pub fn update(&self, object: &ObjectRef, key: &PropertyKey) {
+        let object = object.borrow();
+        let shape = object.properties.shape();
+
+        if !is_cacheable(&object) {
+            return;
+        }
+
+        let location = match shape.find(key) {
+            Some(position) => Location::Own(position),
+            None => {
+                let Some(prototype) = &object.prototype else { return };
+                let holder = prototype.borrow();
+
+                // keys are only ever added to a shape in place, so the position stays valid as
+                // long as the prototype has the same one
+                match holder.properties.shape().find(key) {
+                    Some(position) if is_cacheable(&holder) => Location::Prototype(prototype.downgrade(), holder.properties.shape().clone(), position),
+                    _ => return,
+                }
+            },
+        };
+
+        self.remember(&object, shape.clone(), location);
+    }
+
+    /// Remembers that assigning `key` added it to `object`, which had the shape `before`.
+    pub fn update_added(&self, object: &ObjectRef, key: &PropertyKey, before: Rc<Shape>) {
+        let object = object.borrow();
+        let after = object.properties.shape();
+
+        let added = after.keys().len() == before.keys().len() + 1 && after.keys().last() == Some(key);
+
+        if !added || !after.is_shared() {
+            return;
+        }
+
+        let mut chain = Vec::new();
+        let mut current = object.prototype.clone();
+
+        while let Some(prototype) = current {
+            let holder = prototype.borrow();
+
+            if !is_cacheable(&holder) || holder.properties.shape().find(key).is_some() {
+                return;
+            }
+
+            chain.push((prototype.downgrade(), holder.properties.shape().clone()));
+            current = holder.prototype.clone();
+        }
+
+        self.remember(&object, before, Location::Absent(chain, after.clone()));
+    }
+
+    fn remember(&self, object: &Object, shape: Rc<Shape>, location: Location) {
+        // a shape no other object has may change in place, and caching it would make it copy itself
+        if !is_cacheable(object) || !shape.is_shared() {
+            return;
+        }
+
+        let mut entries = self.0.borrow_mut();
+        entries.retain(|entry| !Rc::ptr_eq(&entry.shape, &shape));
+
+        if entries.len() < MAX_CACHED_SHAPES {
+            entries.push(Entry { shape, location });
+        }
+    }
+}
+
+/// Arrays keep their elements and `length` outside of their properties, so their shape doesn't
+/// say where those are.
+fn is_cacheable(object: &Object) -> bool {
+    !matches!(object.kind, ObjectKind::Array(_))
+}
+
+fn has_shape(object: &Object, shape: &Rc<Shape>) -> bool {
+    Rc::ptr_eq(shape, object.properties.shape()) && is_cacheable(object)
+}
+
+/// Whether the prototypes of `object` are the ones in `chain`, still with the same shapes.
+fn same_prototypes(object: &Object, chain: &[(WeakObjectRef, Rc<Shape>)]) -> bool {
+    let mut current = object.prototype.clone();
+
+    for (holder, shape) in chain {
+        let Some(prototype) = current.filter(|prototype| prototype.as_ptr() == holder.as_ptr()) else { return false };
+        let prototype = prototype.borrow();
+
+        if !Rc::ptr_eq(shape, prototype.properties.shape()) {
+            return false;
+        }
+
+        current = prototype.prototype.clone();
+    }
+
+    current.is_none()
+}
+
+impl fmt::Debug for InlineCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InlineCache({} shapes)", self.0.borrow().len())
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use super::Interpreter;
+
+/// Which `console` method printed a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Log,
+    Info,
+    Debug,
+    Warn,
+    Error,
+}
+
+/// Receives each message printed with `console`.
+pub type ConsoleSink = Box<dyn FnMut(LogLevel, &str)>;
+
+/// Where `console` output goes, along with the state its methods keep between calls.
+pub struct Console {
+    sink: ConsoleSink,
+    // the indentation `console.group` adds to everything printed inside the group
+    indentation: usize,
+    counts: HashMap<String, u64>,
+    // when each `console.time` label was started
+    timers: HashMap<String, u64>,
+}
+
+impl Console {
+    /// Prints warnings and errors to stderr and everything else to stdout.
+    pub fn new() -> Self {
+        Console::with_sink(Box::new(|level, message| match level {
+            LogLevel::Warn | LogLevel::Error => eprintln!("{}", message),
+            LogLevel::Log | LogLevel::Info | LogLevel::Debug => println!("{}", message),
+        }))
+    }
+
+    pub fn with_sink(sink: ConsoleSink) -> Self {
+        Console { sink, indentation: 0, counts: HashMap::new(), timers: HashMap::new() }
+    }
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interpreter {
+    /// Sends `message` to the console sink, indented by the groups it is printed in.
+    pub fn print(&mut self, level: LogLevel, message: &str) {
+        let console = &mut self.console;
+
+        match console.indentation {
+            0 => (console.sink)(level, message),
+            indentation => {
+                let indentation = " ".repeat(indentation);
+                let message = message.lines().map(|line| format!("{}{}", indentation, line)).collect::<Vec<_>>().join("\n");
+                (console.sink)(level, &message)
+            },
+        }
+    }
+
+    pub fn set_console_sink(&mut self, sink: ConsoleSink) {
+        self.console.sink = sink;
+    }
+
+    pub fn start_group(&mut self) {
+        self.console.indentation += 2;
+    }
+
+    pub fn end_group(&mut self) {
+        self.console.indentation = self.console.indentation.saturating_sub(2);
+    }
+
+    /// Counts another call to `console.count(label)`, returning how many there have been.
+    pub fn count(&mut self, label: &str) -> u64 {
+        let count = self.console.counts.entry(label.to_string()).or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    /// Returns `false` if nothing was counted under `label`.
+    pub fn reset_count(&mut self, label: &str) -> bool {
+        self.console.counts.remove(label).is_some()
+    }
+
+    /// Returns `false` if a timer is already running under `label`.
+    pub fn start_console_timer(&mut self, label: &str) -> bool {
+        let now = self.now();
+
+        match self.console.timers.contains_key(label) {
+            true => false,
+            false => {
+                self.console.timers.insert(label.to_string(), now);
+                true
+            },
+        }
+    }
+
+    /// The milliseconds since the timer `label` was started, stopping it with `end`.
+    pub fn console_timer_elapsed(&mut self, label: &str, end: bool) -> Option<u64> {
+        let started = match end {
+            true => self.console.timers.remove(label)?,
+            false => *self.console.timers.get(label)?,
+        };
+
+        Some(self.now().saturating_sub(started))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::error::ErrorKind;
+use crate::object::PropertyKey;
+use crate::types::{DeclarationKind, FunctionDef, Pattern, PropertyName, Value};
+use super::{EvalResult, Interpreter, IteratorRecord, Reference};
+
+impl Interpreter {
+    /// Binds `value` to a pattern. `let` and `const` declare the identifiers in the current scope,
+    /// `var` and plain assignments (`None`) assign to existing bindings.
+    pub(super) fn bind_pattern(&mut self, pattern: &Pattern, value: Value, kind: Option<DeclarationKind>) -> EvalResult<()> {
+        match pattern {
+            Pattern::Identifier(name) => match kind {
+                Some(kind @ (DeclarationKind::Let | DeclarationKind::Const)) => self.declare(kind, name, value),
+                _ => self.put_reference(&Reference::Binding(name.clone()), value),
+            },
+            Pattern::Expression(target) => {
+                let reference = self.eval_reference(target)?;
+                self.put_reference(&reference, value)
+            },
+            Pattern::Default(pattern, default) => {
+                let value = match (value, pattern.as_ref()) {
+                    (Value::None, Pattern::Identifier(name)) => self.eval_named(default, name)?,
+                    (Value::None, _) => self.eval_expr(default)?,
+                    (value, _) => value,
+                };
+
+                self.bind_pattern(pattern, value, kind)
+            },
+            Pattern::Array(elements, rest) => {
+                let record = self.get_iterator(&value)?;
+                let mut done = false;
+                let result = self.bind_array_elements(&record, &mut done, elements, rest.as_deref(), kind);
+
+                match done {
+                    true => result,
+                    false => self.iterator_close(&record, result),
+                }
+            },
+            Pattern::Object(properties, rest) => self.bind_object_properties(&value, properties, rest.as_deref(), kind),
+        }
+    }
+
+    /// Binds the parameters of a function call from left to right, so defaults can refer to the
+    /// parameters before them. Missing arguments are undefined.
+    pub(super) fn bind_parameters(&mut self, def: &FunctionDef, args: &[Value]) -> EvalResult<()> {
+        for (i, param) in def.params.iter().enumerate() {
+            let value = args.get(i).cloned().unwrap_or(Value::None);
+
+            match param {
+                // repeated parameter names are allowed, the last one wins
+                Pattern::Identifier(name) => self.scope.borrow_mut().define(name.clone(), value),
+                pattern => self.bind_pattern(pattern, value, Some(DeclarationKind::Let))?,
+            }
+        }
+
+        if let Some(rest) = &def.rest {
+            let values = args.get(def.params.len()..).unwrap_or_default().to_vec();
+            let array = self.create_array(values);
+            self.bind_pattern(rest, array, Some(DeclarationKind::Let))?;
+        }
+
+        Ok(())
+    }
+
+    fn bind_array_elements(
+        &mut self,
+        record: &IteratorRecord,
+        done: &mut bool,
+        elements: &[Option<Pattern>],
+        rest: Option<&Pattern>,
+        kind: Option<DeclarationKind>,
+    ) -> EvalResult<()> {
+        for element in elements {
+            let value = self.step_pattern_iterator(record, done)?.unwrap_or(Value::None);
+
+            if let Some(element) = element {
+                self.bind_pattern(element, value, kind)?;
+            }
+        }
+
+        if let Some(rest) = rest {
+            let mut values = Vec::new();
+
+            while let Some(value) = self.step_pattern_iterator(record, done)? {
+                values.push(value);
+            }
+
+            let array = self.create_array(values);
+            self.bind_pattern(rest, array, kind)?;
+        }
+
+        Ok(())
+    }
+
+    /// Steps the iterator of an array pattern until it is done, after which it yields nothing.
+    fn step_pattern_iterator(&mut self, record: &IteratorRecord, done: &mut bool) -> EvalResult<Option<Value>> {
+        if *done {
+            return Ok(None);
+        }
+
+        // an iterator whose `next()` throws is not closed afterwards
+        *done = true;
+        let value = self.iterator_step(record)?;
+        *done = value.is_none();
+
+        Ok(value)
+    }
+
+    fn bind_object_properties(
+        &mut self,
+        value: &Value,
+        properties: &[(PropertyName, Pattern)],
+        rest: Option<&Pattern>,
+        kind: Option<DeclarationKind>,
+    ) -> EvalResult<()> {
+        if value.is_nullish() {
+            let message = format!("Cannot destructure '{0}' as it is {0}.", value.to_display_string());
+            return Err(self.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let mut used_keys: Vec<PropertyKey> = Vec::new();
+
+        for (name, pattern) in properties {
+            let key = self.eval_property_name(name)?;
+            let property = self.get_property(value, &key)?;
+            self.bind_pattern(pattern, property, kind)?;
+            used_keys.push(key);
+        }
+
+        if let Some(rest) = rest {
+            let object = self.create_object();
+            self.copy_data_properties(&object, value, &used_keys)?;
+            self.bind_pattern(rest, Value::Object(object), kind)?;
+        }
+
+        Ok(())
+    }
+}
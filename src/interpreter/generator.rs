@@ -0,0 +1,577 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::object::{Closure, Object, ObjectKind, ObjectRef};
+use crate::scope::Scope;
+use crate::types::{GeneratorCode, GeneratorOp, Signal, Stmt, Value};
+use super::heap::{trace_scope, trace_value, Visit};
+use super::{EvalResult, Interpreter, IteratorRecord};
+
+/// A suspended generator, async function or async generator: where it is in its lowered body and the state
+/// around that point.
+#[derive(Clone)]
+pub struct GeneratorFrame {
+    code: Rc<GeneratorCode>,
+    // the name shown in stack traces, none for the top level of a script
+    name: Option<Rc<str>>,
+    pc: usize,
+    // the scopes entered by the body, innermost last
+    scopes: Vec<Rc<RefCell<Scope>>>,
+    regions: Vec<Region>,
+    // the temporary the value of the next resumption goes to
+    resume_into: Option<String>,
+    // the iterator a `yield*` is forwarding to, and the temporary its result goes to
+    delegate: Option<(IteratorRecord, Option<String>)>,
+    // the value of the last statement, which is what a script completes with
+    completion: Value,
+}
+
+#[derive(Clone)]
+pub enum GeneratorState {
+    SuspendedStart(Box<GeneratorFrame>),
+    SuspendedYield(Box<GeneratorFrame>),
+    Executing,
+    Completed,
+}
+
+/// How a generator is resumed: by `next()`, `throw()` or `return()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResumeMode {
+    Next,
+    Throw,
+    Return,
+}
+
+/// The statements enclosing the current operation that unwinding has to pass through. `depth` is
+/// the number of scopes entered when the statement started.
+#[derive(Clone)]
+enum Region {
+    Loop { break_target: usize, continue_target: usize, depth: usize },
+    Try { catch: Option<(usize, Option<Rc<str>>)>, finally: Option<usize>, depth: usize },
+    // a running finally block, and what to do once it completes
+    Finally(Completion),
+    // a for...of loop, its iterator is closed unless it is already done
+    Iterator { record: IteratorRecord, done: bool },
+}
+
+#[derive(Clone)]
+enum Completion {
+    Jump(usize),
+    Abrupt(Signal),
+}
+
+pub(super) enum Outcome {
+    Yield(Value),
+    // the value of an `await`, which the body is resumed with once it settles
+    Await(Value),
+    // a result of the iterator a `yield*` forwards to, which is handed out as it is
+    Delegate(Value),
+    Return(Value),
+}
+
+impl ResumeMode {
+    /// The generator method that resumes this way.
+    pub(super) fn method_name(self) -> &'static str {
+        match self {
+            ResumeMode::Next => "next",
+            ResumeMode::Throw => "throw",
+            ResumeMode::Return => "return",
+        }
+    }
+}
+
+impl GeneratorFrame {
+    /// A frame at the start of `code`, running in `scope`.
+    pub(super) fn new(code: Rc<GeneratorCode>, name: Option<Rc<str>>, scope: Rc<RefCell<Scope>>) -> Self {
+        GeneratorFrame {
+            code,
+            name,
+            pc: 0,
+            scopes: vec![scope],
+            regions: Vec::new(),
+            resume_into: None,
+            delegate: None,
+            completion: Value::None,
+        }
+    }
+
+    /// Tells the collector about everything the suspended body holds on to.
+    pub(super) fn trace(&self, visit: &mut Visit) {
+        self.scopes.iter().for_each(|scope| trace_scope(scope, visit));
+        trace_value(&self.completion, visit);
+
+        if let Some((record, _)) = &self.delegate {
+            trace_value(&record.iterator, visit);
+            trace_value(&record.next, visit);
+        }
+
+        for region in &self.regions {
+            match region {
+                Region::Finally(Completion::Abrupt(Signal::Return(value) | Signal::Throw(value))) => trace_value(value, visit),
+                Region::Iterator { record, .. } => {
+                    trace_value(&record.iterator, visit);
+                    trace_value(&record.next, visit);
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+impl Interpreter {
+    /// Calls a generator function: binds the arguments and returns the generator object, without
+    /// running any of the body yet.
+    pub(super) fn start_generator(&mut self, function: &Value, closure: &Closure, this: Value, args: &[Value]) -> EvalResult {
+        let frame = self.create_frame(closure, this, args)?;
+
+        let prototype = match self.get_property(function, "prototype")? {
+            Value::Object(prototype) => prototype,
+            _ => self.intrinsics.generator_prototype.clone(),
+        };
+
+        let state = GeneratorState::SuspendedStart(Box::new(frame));
+        Ok(Value::Object(self.allocate_object(Object::new(Some(prototype), ObjectKind::Generator(state)))))
+    }
+
+    /// Sets up the scope of a call to a generator or async function, binding the arguments.
+    pub(super) fn create_frame(&mut self, closure: &Closure, this: Value, args: &[Value]) -> EvalResult<GeneratorFrame> {
+        let code = closure.def.generator.clone().expect("generator and async functions are lowered");
+        let name = closure.def.name.clone().unwrap_or_else(|| "<anonymous>".into());
+        let scope = self.function_scope(closure, this);
+
+        self.call_stack.push(name.clone());
+
+        let result = self.with_scope(scope.clone(), |interpreter| {
+            if closure.def.uses_arguments {
+                let arguments = interpreter.create_arguments_object(args);
+                interpreter.scope.borrow_mut().define("arguments".to_string(), arguments);
+            }
+
+            interpreter.bind_parameters(&closure.def, args)?;
+            interpreter.prepare_lowered_body(&closure.def.body, &code);
+            Ok(())
+        });
+
+        self.call_stack.pop();
+        result?;
+
+        Ok(GeneratorFrame::new(code, Some(name), scope))
+    }
+
+    /// Hoists the declarations of a lowered body into the current scope and creates its temporaries.
+    pub(super) fn prepare_lowered_body(&mut self, body: &[Stmt], code: &GeneratorCode) {
+        self.hoist_var_declarations(body);
+        self.hoist_declarations(body);
+
+        let mut scope = self.scope.borrow_mut();
+
+        for i in 0..code.temporaries {
+            scope.define(format!("%{}", i), Value::None);
+        }
+    }
+
+    /// Resumes a generator object, returning the iterator result it produces.
+    pub fn resume_generator(&mut self, generator: &Value, mode: ResumeMode, value: Value) -> EvalResult {
+        let object = match generator {
+            Value::Object(object) if matches!(object.borrow().kind, ObjectKind::Generator(_)) => object.clone(),
+            _ => {
+                let message = format!("{} method called on incompatible receiver {}", mode.method_name(), generator.to_display_string());
+                return Err(self.throw_error(ErrorKind::TypeError, message));
+            },
+        };
+
+        match self.resume(&object, mode, value)? {
+            Outcome::Yield(value) => Ok(self.create_iter_result(value, false)),
+            Outcome::Delegate(result) => Ok(result),
+            Outcome::Return(value) => Ok(self.create_iter_result(value, true)),
+            Outcome::Await(_) => unreachable!("generators can't contain await"),
+        }
+    }
+
+    /// Runs the suspended body held by `object` until it suspends again or completes.
+    pub(super) fn resume(&mut self, object: &ObjectRef, mode: ResumeMode, value: Value) -> EvalResult<Outcome> {
+        let state = match &mut object.borrow_mut().kind {
+            ObjectKind::Generator(state) => std::mem::replace(state, GeneratorState::Executing),
+            _ => GeneratorState::Executing,
+        };
+
+        let frame = match (state, mode) {
+            (GeneratorState::Executing, _) => return Err(self.throw_error(ErrorKind::TypeError, "Generator is already running")),
+            (GeneratorState::SuspendedYield(frame), _) | (GeneratorState::SuspendedStart(frame), ResumeMode::Next) => frame,
+            // a generator that never started or already finished has nothing left to run
+            (GeneratorState::SuspendedStart(_) | GeneratorState::Completed, mode) => {
+                Self::set_generator_state(object, GeneratorState::Completed);
+
+                return match mode {
+                    ResumeMode::Next => Ok(Outcome::Return(Value::None)),
+                    ResumeMode::Return => Ok(Outcome::Return(value)),
+                    ResumeMode::Throw => Err(Signal::Throw(value)),
+                };
+            },
+        };
+
+        let mut frame = *frame;
+        let previous = self.scope.clone();
+
+        if let Some(name) = &frame.name {
+            self.call_stack.push(name.clone());
+        }
+
+        let outcome = self.run_generator(&mut frame, mode, value);
+
+        if frame.name.is_some() {
+            self.call_stack.pop();
+        }
+
+        self.scope = previous;
+
+        let state = match outcome {
+            Ok(Outcome::Yield(_) | Outcome::Await(_) | Outcome::Delegate(_)) => GeneratorState::SuspendedYield(Box::new(frame)),
+            _ => GeneratorState::Completed,
+        };
+
+        Self::set_generator_state(object, state);
+        outcome
+    }
+
+    fn set_generator_state(object: &ObjectRef, state: GeneratorState) {
+        if let ObjectKind::Generator(slot) = &mut object.borrow_mut().kind {
+            *slot = state;
+        }
+    }
+
+    /// Runs the body from where it was suspended until it yields or completes.
+    fn run_generator(&mut self, frame: &mut GeneratorFrame, mode: ResumeMode, value: Value) -> EvalResult<Outcome> {
+        self.scope = Self::current_scope(frame);
+
+        let mut pending = match frame.delegate.is_some() {
+            true => match self.resume_delegate(frame, mode, value) {
+                Ok(Some(result)) => return Ok(Outcome::Delegate(result)),
+                Ok(None) => None,
+                Err(signal) => Some(signal),
+            },
+            false => match mode {
+                ResumeMode::Next => {
+                    match frame.resume_into.take() {
+                        Some(temporary) => self.set_temporary(&temporary, value),
+                        None => frame.completion = value,
+                    }
+
+                    None
+                },
+                ResumeMode::Throw => Some(Signal::Throw(value)),
+                ResumeMode::Return => Some(Signal::Return(value)),
+            },
+        };
+
+        let code = frame.code.clone();
+
+        loop {
+            if let Some(signal) = pending.take() {
+                match self.unwind(frame, signal) {
+                    Ok(()) => (),
+                    Err(Signal::Return(value)) => return Ok(Outcome::Return(value)),
+                    Err(Signal::Break | Signal::Continue) => return Ok(Outcome::Return(Value::None)),
+                    Err(signal) => return Err(signal),
+                }
+            }
+
+            self.scope = Self::current_scope(frame);
+
+            // functions without a return statement return undefined, scripts their completion value
+            let op = match (code.ops.get(frame.pc), &frame.name) {
+                (Some(op), _) => op,
+                (None, Some(_)) => return Ok(Outcome::Return(Value::None)),
+                (None, None) => return Ok(Outcome::Return(std::mem::replace(&mut frame.completion, Value::None))),
+            };
+
+            frame.pc += 1;
+
+            match self.step_generator(frame, op) {
+                Ok(Some(outcome)) => return Ok(outcome),
+                Ok(None) => (),
+                Err(signal) => pending = Some(signal),
+            }
+        }
+    }
+
+    fn step_generator(&mut self, frame: &mut GeneratorFrame, op: &GeneratorOp) -> EvalResult<Option<Outcome>> {
+        self.step()?;
+
+        match op {
+            GeneratorOp::Exec(stmt) => {
+                let value = self.eval_stmt(stmt)?;
+
+                if !matches!(stmt, Stmt::Declaration(_, _)) {
+                    frame.completion = value;
+                }
+            },
+            GeneratorOp::Yield(argument, into) => {
+                let value = match argument {
+                    Some(argument) => self.eval_expr(argument)?,
+                    None => Value::None,
+                };
+
+                frame.resume_into = into.clone();
+                return Ok(Some(Outcome::Yield(value)));
+            },
+            GeneratorOp::Await(argument, into) => {
+                let value = self.eval_expr(argument)?;
+                frame.resume_into = into.clone();
+                return Ok(Some(Outcome::Await(value)));
+            },
+            GeneratorOp::YieldDelegate(iterable, into) => {
+                let iterable = self.eval_expr(iterable)?;
+                let record = self.get_iterator(&iterable)?;
+                frame.delegate = Some((record, into.clone()));
+
+                if let Some(result) = self.resume_delegate(frame, ResumeMode::Next, Value::None)? {
+                    return Ok(Some(Outcome::Delegate(result)));
+                }
+            },
+            GeneratorOp::Jump(target) => frame.pc = *target,
+            GeneratorOp::JumpIfFalse(condition, target) => {
+                if !self.eval_expr(condition)?.is_truthy() {
+                    frame.pc = *target;
+                }
+            },
+            GeneratorOp::PushScope(functions) => {
+                let scope = self.allocate_scope(Scope::new(Some(self.scope.clone())));
+                frame.scopes.push(scope.clone());
+                self.scope = scope;
+                self.hoist_declarations(functions);
+            },
+            GeneratorOp::PopScope => {
+                frame.scopes.pop();
+            },
+            GeneratorOp::CopyIterationScope(names) => {
+                self.copy_iteration_scope(names);
+                frame.scopes.pop();
+                frame.scopes.push(self.scope.clone());
+            },
+            GeneratorOp::EnterLoop { break_target, continue_target } => {
+                let depth = frame.scopes.len();
+                frame.regions.push(Region::Loop { break_target: *break_target, continue_target: *continue_target, depth });
+            },
+            GeneratorOp::ExitLoop | GeneratorOp::ExitIterator => {
+                frame.regions.pop();
+            },
+            GeneratorOp::EnterTry { catch, finally } => {
+                let depth = frame.scopes.len();
+                frame.regions.push(Region::Try { catch: catch.clone(), finally: *finally, depth });
+            },
+            GeneratorOp::LeaveTry(target) => match frame.regions.pop() {
+                Some(Region::Try { finally: Some(finally), .. }) => {
+                    frame.regions.push(Region::Finally(Completion::Jump(*target)));
+                    frame.pc = finally;
+                },
+                _ => frame.pc = *target,
+            },
+            GeneratorOp::EndFinally => match frame.regions.pop() {
+                Some(Region::Finally(Completion::Jump(target))) => frame.pc = target,
+                Some(Region::Finally(Completion::Abrupt(signal))) => return Err(signal),
+                _ => (),
+            },
+            GeneratorOp::ForInKeys(object, into) => {
+                let keys = match self.eval_expr(object)? {
+                    Value::Object(object) => Self::enumerable_keys(&object).into_iter().map(|key| Value::StringLiteral(key.into())).collect(),
+                    Value::StringLiteral(string) => (0..string.utf16_len()).map(|i| Value::StringLiteral(i.to_string().into())).collect(),
+                    _ => Vec::new(),
+                };
+
+                let keys = self.create_array(keys);
+                self.set_temporary(into, keys);
+            },
+            GeneratorOp::GetIterator(iterable, iterator, next) => {
+                let iterable = self.eval_expr(iterable)?;
+                let record = self.get_iterator(&iterable)?;
+                self.set_temporary(iterator, record.iterator);
+                self.set_temporary(next, record.next);
+            },
+            GeneratorOp::EnterIterator(iterator, next) => {
+                let record = self.temporary_record(iterator, next)?;
+                frame.regions.push(Region::Iterator { record, done: false });
+            },
+            GeneratorOp::IteratorStep(iterator, next, into, exit) => {
+                let record = self.temporary_record(iterator, next)?;
+
+                match self.iterator_step(&record) {
+                    Ok(Some(value)) => self.set_temporary(into, value),
+                    Ok(None) => frame.pc = *exit,
+                    Err(signal) => return Err(Self::abandon_iterator(frame, signal)),
+                }
+            },
+            GeneratorOp::GetAsyncIterator(iterable, iterator, next) => {
+                let iterable = self.eval_expr(iterable)?;
+                let record = self.get_async_iterator(&iterable)?;
+                self.set_temporary(iterator, record.iterator);
+                self.set_temporary(next, record.next);
+            },
+            GeneratorOp::IteratorNext(iterator, next, into) => {
+                let record = self.temporary_record(iterator, next)?;
+
+                match self.call_function(&record.next, record.iterator, &[]) {
+                    Ok(result) => self.set_temporary(into, result),
+                    Err(signal) => return Err(Self::abandon_iterator(frame, signal)),
+                }
+            },
+            GeneratorOp::IteratorComplete(result, into, exit) => {
+                let result = self.lookup(result)?;
+
+                if !matches!(result, Value::Object(_)) {
+                    let message = format!("Iterator result {} is not an object", result.to_display_string());
+                    let signal = self.throw_error(ErrorKind::TypeError, message);
+                    return Err(Self::abandon_iterator(frame, signal));
+                }
+
+                match self.get_property(&result, "done")?.is_truthy() {
+                    true => frame.pc = *exit,
+                    false => {
+                        let value = self.get_property(&result, "value")?;
+                        self.set_temporary(into, value);
+                    },
+                }
+            },
+            GeneratorOp::CloseIterator => {
+                if let Some(Region::Iterator { record, .. }) = frame.regions.pop() {
+                    self.iterator_close(&record, Ok(()))?;
+                }
+            },
+        }
+
+        Ok(None)
+    }
+
+    /// Passes an abrupt completion through the enclosing regions until a loop, catch clause or
+    /// finally block takes it over. Returns the signal if it leaves the generator body.
+    fn unwind(&mut self, frame: &mut GeneratorFrame, mut signal: Signal) -> Result<(), Signal> {
+        while let Some(region) = frame.regions.pop() {
+            match (region, &signal) {
+                (Region::Loop { break_target, depth, .. }, Signal::Break) => {
+                    frame.scopes.truncate(depth);
+                    frame.pc = break_target;
+                    return Ok(());
+                },
+                (region @ Region::Loop { continue_target, depth, .. }, Signal::Continue) => {
+                    frame.regions.push(region);
+                    frame.scopes.truncate(depth);
+                    frame.pc = continue_target;
+                    return Ok(());
+                },
+                (Region::Try { catch: Some((target, param)), finally, depth }, Signal::Throw(exception)) => {
+                    // a throw from the catch clause only runs the finally block
+                    frame.regions.push(Region::Try { catch: None, finally, depth });
+                    frame.scopes.truncate(depth);
+
+                    let mut scope = Scope::new(Some(Self::current_scope(frame)));
+
+                    if let Some(param) = param {
+                        scope.define(param, exception.clone());
+                    }
+
+                    let scope = self.allocate_scope(scope);
+                    frame.scopes.push(scope);
+                    frame.pc = target;
+                    return Ok(());
+                },
+                (Region::Try { finally: Some(finally), depth, .. }, _) => {
+                    frame.scopes.truncate(depth);
+                    frame.regions.push(Region::Finally(Completion::Abrupt(signal)));
+                    frame.pc = finally;
+                    return Ok(());
+                },
+                (Region::Iterator { record, done: false }, _) => {
+                    self.scope = Self::current_scope(frame);
+                    signal = self.iterator_close::<()>(&record, Err(signal)).expect_err("closing after an abrupt completion stays abrupt");
+                },
+                // an abrupt completion of a finally block replaces the one it was running for
+                _ => (),
+            }
+        }
+
+        Err(signal)
+    }
+
+    /// Forwards a resumption to the iterator of a `yield*`. Returns the inner result to hand out
+    /// while it is not done, otherwise stores its value and ends the delegation.
+    fn resume_delegate(&mut self, frame: &mut GeneratorFrame, mode: ResumeMode, value: Value) -> EvalResult<Option<Value>> {
+        let (record, into) = match frame.delegate.clone() {
+            Some(delegate) => delegate,
+            None => return Ok(None),
+        };
+
+        let result = match mode {
+            ResumeMode::Next => self.call_function(&record.next, record.iterator.clone(), &[value]),
+            ResumeMode::Throw => match self.get_property(&record.iterator, "throw") {
+                Ok(method) if method.is_callable() => self.call_function(&method, record.iterator.clone(), &[value]),
+                Ok(_) => {
+                    // the iterator can't handle the exception, so it is closed and the protocol violation reported
+                    frame.delegate = None;
+                    self.iterator_close(&record, Ok(()))?;
+                    Err(self.throw_error(ErrorKind::TypeError, "The iterator does not provide a 'throw' method"))
+                },
+                Err(signal) => Err(signal),
+            },
+            ResumeMode::Return => match self.get_property(&record.iterator, "return") {
+                Ok(method) if method.is_nullish() => Err(Signal::Return(value)),
+                Ok(method) => self.call_function(&method, record.iterator.clone(), &[value]),
+                Err(signal) => Err(signal),
+            },
+        };
+
+        let result = match result {
+            Ok(result) => result,
+            Err(signal) => {
+                frame.delegate = None;
+                return Err(signal);
+            },
+        };
+
+        if !matches!(result, Value::Object(_)) {
+            frame.delegate = None;
+            let message = format!("Iterator result {} is not an object", result.to_display_string());
+            return Err(self.throw_error(ErrorKind::TypeError, message));
+        }
+
+        if !self.get_property(&result, "done")?.is_truthy() {
+            return Ok(Some(result));
+        }
+
+        frame.delegate = None;
+        let value = self.get_property(&result, "value")?;
+
+        match mode {
+            ResumeMode::Return => Err(Signal::Return(value)),
+            _ => {
+                if let Some(into) = into {
+                    self.set_temporary(&into, value);
+                }
+
+                Ok(None)
+            },
+        }
+    }
+
+    /// An iterator that failed to step is not closed when the error unwinds its loop.
+    fn abandon_iterator(frame: &mut GeneratorFrame, signal: Signal) -> Signal {
+        let region = frame.regions.iter_mut().rev().find(|region| matches!(region, Region::Iterator { .. }));
+
+        if let Some(Region::Iterator { done, .. }) = region {
+            *done = true;
+        }
+
+        signal
+    }
+
+    fn current_scope(frame: &GeneratorFrame) -> Rc<RefCell<Scope>> {
+        frame.scopes.last().cloned().expect("a generator always has its function scope")
+    }
+
+    fn set_temporary(&mut self, name: &str, value: Value) {
+        self.scope.borrow_mut().assign(name, value);
+    }
+
+    fn temporary_record(&mut self, iterator: &str, next: &str) -> EvalResult<IteratorRecord> {
+        Ok(IteratorRecord { iterator: self.lookup(iterator)?, next: self.lookup(next)? })
+    }
+}
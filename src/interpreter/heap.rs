@@ -0,0 +1,475 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::mem::size_of;
+use std::rc::{Rc, Weak};
+use crate::error::{ErrorKind, Termination};
+use crate::object::{IteratorState, Object, ObjectKind, ObjectRef, PromiseState, Property, PropertyKey, WeakObjectRef};
+use super::GeneratorState;
+use crate::scope::{Binding, Scope};
+use crate::types::Value;
+use super::{EvalResult, Interpreter};
+
+/// A collection runs once this many objects and scopes were allocated, or twice as many as
+/// survived the last one if that's more.
+const MIN_COLLECTION_THRESHOLD: usize = 4096;
+
+/// What happens when a script goes over its memory limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutOfMemory {
+    /// Throws a `RangeError` the script can catch.
+    Throw,
+    /// Stops the script with `Termination::OutOfMemory`.
+    Terminate,
+}
+
+/// What garbage collection freed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GcStats {
+    pub objects: usize,
+    pub scopes: usize,
+    pub bytes: usize,
+}
+
+/// What an object or scope held when it was freed, handed to `tear_down`.
+pub enum Teardown {
+    Object(Option<ObjectRef>, Vec<Property>, ObjectKind),
+    Scope(Option<Rc<RefCell<Scope>>>, Vec<Binding>, Option<Value>),
+}
+
+thread_local! {
+    // whether a teardown is already freeing things, and what is left for it to free
+    static TEARDOWN: RefCell<(bool, Vec<Teardown>)> = const { RefCell::new((false, Vec::new())) };
+}
+
+/// Frees what a freed object or scope held. Whatever that frees in turn is queued up for the
+/// outermost call to free in a loop, like the worklist of a collection, so that freeing a long
+/// chain of objects doesn't overflow the stack.
+pub fn tear_down(contents: Teardown) {
+    let mut next = TEARDOWN.try_with(|teardown| {
+        let mut teardown = teardown.borrow_mut();
+
+        match teardown.0 {
+            true => {
+                teardown.1.push(contents);
+                None
+            },
+            false => {
+                teardown.0 = true;
+                Some(contents)
+            },
+        }
+    }).ok().flatten();
+
+    while let Some(contents) = next {
+        match contents {
+            Teardown::Object(prototype, properties, kind) => drop((prototype, properties, kind)),
+            Teardown::Scope(parent, bindings, this) => drop((parent, bindings, this)),
+        }
+
+        next = TEARDOWN.with(|teardown| {
+            let mut teardown = teardown.borrow_mut();
+            let next = teardown.1.pop();
+            teardown.0 = next.is_some();
+            next
+        });
+    }
+}
+
+/// Called with the address of each object and scope something on the heap refers to.
+pub type Visit<'a> = dyn FnMut(usize) + 'a;
+
+/// The objects and scopes the interpreter created, which it collects when they are only kept
+/// alive by cycles among themselves.
+///
+/// They are reference counted, which frees everything else as soon as it's dropped. A collection
+/// finds the roots by counting how many references to each object come from the heap itself:
+/// those referenced more often than that are held by something outside of it, such as the
+/// context, the call stack or the host. Whatever isn't reachable from a root is only held by
+/// garbage, and is cleared out so that the reference counts drop to zero.
+pub struct Heap {
+    objects: Vec<WeakObjectRef>,
+    scopes: Vec<Weak<RefCell<Scope>>>,
+    // the usage last measured, plus an estimate of everything allocated since
+    usage: usize,
+    limit: Option<usize>,
+    on_exceeded: OutOfMemory,
+    collection_threshold: usize,
+    // everything collected so far
+    collected: GcStats,
+}
+
+/// An object or scope taking part in a collection.
+enum Node {
+    Object(ObjectRef),
+    Scope(Rc<RefCell<Scope>>),
+}
+
+impl Node {
+    fn address(&self) -> usize {
+        match self {
+            Node::Object(object) => object.as_ptr() as usize,
+            Node::Scope(scope) => Rc::as_ptr(scope) as usize,
+        }
+    }
+
+    fn strong_count(&self) -> usize {
+        match self {
+            Node::Object(object) => object.strong_count(),
+            Node::Scope(scope) => Rc::strong_count(scope),
+        }
+    }
+
+    /// Visits what the node refers to. One being changed can't be looked into, what it refers
+    /// to then counts as referenced from outside the heap, and is kept alive.
+    fn trace(&self, visit: &mut Visit) {
+        match self {
+            Node::Object(object) => {
+                if let Some(object) = object.try_borrow() {
+                    trace_object(&object, visit);
+                }
+            },
+            Node::Scope(scope) => {
+                if let Ok(scope) = scope.try_borrow() {
+                    trace_scope_contents(&scope, visit);
+                }
+            },
+        }
+    }
+
+    fn size(&self, counted: &mut Counted) -> usize {
+        match self {
+            Node::Object(object) => object.try_borrow().map_or(size_of::<RefCell<Object>>(), |object| object_size(&object, counted)),
+            Node::Scope(scope) => scope.try_borrow().map_or(size_of::<RefCell<Scope>>(), |scope| scope_size(&scope, counted)),
+        }
+    }
+}
+
+impl Heap {
+    pub fn new() -> Self {
+        Heap {
+            objects: Vec::new(),
+            scopes: Vec::new(),
+            usage: 0,
+            limit: None,
+            on_exceeded: OutOfMemory::Terminate,
+            collection_threshold: MIN_COLLECTION_THRESHOLD,
+            collected: GcStats::default(),
+        }
+    }
+
+    pub(super) fn track_scope(&mut self, scope: &Rc<RefCell<Scope>>) {
+        self.scopes.push(Rc::downgrade(scope));
+    }
+
+    /// Adds up the memory held by the objects and scopes that are still alive.
+    fn measure(&self) -> usize {
+        let objects = self.objects.iter().filter_map(WeakObjectRef::upgrade).map(Node::Object);
+        let scopes = self.scopes.iter().filter_map(Weak::upgrade).map(Node::Scope);
+        let mut counted = Counted::new();
+        objects.chain(scopes).map(|node| node.size(&mut counted)).sum()
+    }
+
+    /// Collects once enough was allocated since the last collection.
+    fn maybe_collect(&mut self) {
+        if self.objects.len() + self.scopes.len() >= self.collection_threshold {
+            self.collect();
+        }
+    }
+
+    /// Frees the objects and scopes that are only kept alive by cycles, and measures the memory
+    /// held by the rest.
+    fn collect(&mut self) -> GcStats {
+        let objects = self.objects.iter().filter_map(WeakObjectRef::upgrade).map(Node::Object);
+        let scopes = self.scopes.iter().filter_map(Weak::upgrade).map(Node::Scope);
+        let nodes: Vec<Node> = objects.chain(scopes).collect();
+        let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, node)| (node.address(), i)).collect();
+
+        // the references each node gets from the heap, and the ones it makes
+        let mut internal = vec![0; nodes.len()];
+        let mut edges = vec![Vec::new(); nodes.len()];
+
+        for (i, node) in nodes.iter().enumerate() {
+            node.trace(&mut |address| {
+                if let Some(&j) = index.get(&address) {
+                    internal[j] += 1;
+                    edges[i].push(j);
+                }
+            });
+        }
+
+        // `nodes` holds a reference to each of them as well
+        let mut marked: Vec<bool> = nodes.iter().zip(&internal).map(|(node, internal)| node.strong_count() - 1 > *internal).collect();
+        let mut pending: Vec<usize> = (0..nodes.len()).filter(|i| marked[*i]).collect();
+
+        while let Some(i) = pending.pop() {
+            for &j in &edges[i] {
+                if !marked[j] {
+                    marked[j] = true;
+                    pending.push(j);
+                }
+            }
+        }
+
+        let mut stats = GcStats::default();
+        let mut live = 0;
+        // cleared out contents are only dropped once nothing is borrowed anymore
+        let mut garbage_objects = Vec::new();
+        let mut garbage_scopes = Vec::new();
+        let mut counted = Counted::new();
+
+        for (node, marked) in nodes.iter().zip(&mut marked) {
+            let size = node.size(&mut counted);
+
+            // garbage is never borrowed, but if it were its contents stay where they are
+            let cleared = !*marked && match node {
+                Node::Object(object) => object.try_borrow_mut().map(|mut object| {
+                    garbage_objects.push(std::mem::replace(&mut *object, Object::new(None, ObjectKind::Ordinary)));
+                }).is_some(),
+                Node::Scope(scope) => scope.try_borrow_mut().map(|mut scope| garbage_scopes.push(std::mem::take(&mut *scope))).is_ok(),
+            };
+
+            if !cleared {
+                *marked = true;
+                live += size;
+                continue;
+            }
+
+            stats.bytes += size;
+
+            match node {
+                Node::Object(_) => stats.objects += 1,
+                Node::Scope(_) => stats.scopes += 1,
+            }
+        }
+
+        drop(garbage_objects);
+        drop(garbage_scopes);
+
+        self.objects = nodes.iter().zip(&marked).filter_map(|(node, marked)| match node {
+            Node::Object(object) if *marked => Some(object.downgrade()),
+            _ => None,
+        }).collect();
+
+        self.scopes = nodes.iter().zip(&marked).filter_map(|(node, marked)| match node {
+            Node::Scope(scope) if *marked => Some(Rc::downgrade(scope)),
+            _ => None,
+        }).collect();
+
+        self.usage = live;
+        self.collection_threshold = ((self.objects.len() + self.scopes.len()) * 2).max(MIN_COLLECTION_THRESHOLD);
+        self.collected.objects += stats.objects;
+        self.collected.scopes += stats.scopes;
+        self.collected.bytes += stats.bytes;
+        stats
+    }
+}
+
+impl Default for Heap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn trace_value(value: &Value, visit: &mut Visit) {
+    if let Value::Object(object) = value {
+        visit(object.as_ptr() as usize);
+    }
+}
+
+pub fn trace_scope(scope: &Rc<RefCell<Scope>>, visit: &mut Visit) {
+    visit(Rc::as_ptr(scope) as usize);
+}
+
+fn trace_object(object: &Object, visit: &mut Visit) {
+    if let Some(prototype) = &object.prototype {
+        visit(prototype.as_ptr() as usize);
+    }
+
+    for (_, property) in object.properties.iter() {
+        [Some(&property.value), property.getter.as_ref(), property.setter.as_ref()].into_iter().flatten().for_each(|value| trace_value(value, visit));
+    }
+
+    // what native functions capture can't be seen, and stays alive as long as they do
+    match &object.kind {
+        ObjectKind::Function(closure) => trace_scope(&closure.scope, visit),
+        ObjectKind::Array(elements) => elements.iter().for_each(|element| trace_value(element, visit)),
+        ObjectKind::Map(entries) | ObjectKind::Set(entries) => entries.iter().for_each(|(key, value)| {
+            trace_value(key, visit);
+            trace_value(value, visit);
+        }),
+        ObjectKind::Iterator(IteratorState::Array(value, ..)) => trace_value(value, visit),
+        ObjectKind::Iterator(IteratorState::Map(object, ..) | IteratorState::Set(object, ..) | IteratorState::RegExp(object, ..)) => visit(object.as_ptr() as usize),
+        ObjectKind::Generator(GeneratorState::SuspendedStart(frame) | GeneratorState::SuspendedYield(frame)) => frame.trace(visit),
+        ObjectKind::AsyncGenerator(state) => state.trace(visit),
+        ObjectKind::Promise(PromiseState::Pending(reactions), _) => for reaction in reactions {
+            trace_value(&reaction.on_fulfilled, visit);
+            trace_value(&reaction.on_rejected, visit);
+
+            if let Some(result) = &reaction.result {
+                visit(result.as_ptr() as usize);
+            }
+        },
+        ObjectKind::Promise(PromiseState::Fulfilled(value) | PromiseState::Rejected(value), _) => trace_value(value, visit),
+        _ => (),
+    }
+}
+
+fn trace_scope_contents(scope: &Scope, visit: &mut Visit) {
+    if let Some(parent) = &scope.parent() {
+        trace_scope(parent, visit);
+    }
+
+    scope.own_this().into_iter().chain(scope.bindings().map(|(_, value)| value)).for_each(|value| trace_value(value, visit));
+}
+
+/// The memory held by a value besides the value itself, which is counted by what holds it.
+pub fn value_size(value: &Value) -> usize {
+    match value {
+        Value::StringLiteral(string) => string.size(),
+        _ => 0,
+    }
+}
+
+fn key_size(key: &PropertyKey) -> usize {
+    key.as_str().map_or(0, str::len)
+}
+
+/// The strings already counted while measuring, by address. Values share the text of a string
+/// when it's copied, so it's only counted for the first of them.
+type Counted = HashSet<usize>;
+
+/// The size of `value` like `value_size`, unless its string was counted already.
+fn counted_size(value: &Value, counted: &mut Counted) -> usize {
+    match value {
+        Value::StringLiteral(string) if counted.insert(string.as_ptr() as usize) => string.size(),
+        _ => 0,
+    }
+}
+
+/// The memory a property takes up in an object, where its key is stored twice.
+pub fn property_size(key: &PropertyKey, property: &Property) -> usize {
+    property_values(property).map(value_size).sum::<usize>() + property_overhead(key)
+}
+
+fn property_overhead(key: &PropertyKey) -> usize {
+    size_of::<(PropertyKey, Property)>() + size_of::<(PropertyKey, usize)>() + key_size(key) * 2
+}
+
+fn property_values(property: &Property) -> impl Iterator<Item = &Value> {
+    [Some(&property.value), property.getter.as_ref(), property.setter.as_ref()].into_iter().flatten()
+}
+
+/// The memory an entry takes up in a `Map` or `Set`, which keeps it both in order and indexed.
+pub fn entry_size(key: &Value, value: &Value) -> usize {
+    2 * size_of::<(Value, Value)>() + value_size(key) + value_size(value)
+}
+
+fn object_size(object: &Object, counted: &mut Counted) -> usize {
+    let properties = object.properties.iter()
+        .map(|(key, property)| property_overhead(key) + property_values(property).map(|value| counted_size(value, counted)).sum::<usize>())
+        .sum::<usize>();
+
+    let contents = match &object.kind {
+        ObjectKind::Array(elements) => {
+            elements.capacity() * size_of::<Value>() + elements.iter().map(|element| counted_size(element, counted)).sum::<usize>()
+        },
+        ObjectKind::Map(entries) | ObjectKind::Set(entries) => {
+            entries.iter().map(|(key, value)| 2 * size_of::<(Value, Value)>() + counted_size(key, counted) + counted_size(value, counted)).sum()
+        },
+        _ => 0,
+    };
+
+    size_of::<RefCell<Object>>() + properties + contents
+}
+
+fn scope_size(scope: &Scope, counted: &mut Counted) -> usize {
+    let bindings = scope.bindings()
+        .map(|(name, value)| size_of::<(Rc<str>, Value)>() + name.len() + counted_size(value, counted))
+        .sum::<usize>();
+
+    size_of::<RefCell<Scope>>() + bindings
+}
+
+impl Interpreter {
+    /// Puts `object` on the heap, where it counts towards the memory limit once usage is measured.
+    /// Objects scripts ask for have their memory reserved first.
+    pub fn allocate_object(&mut self, object: Object) -> ObjectRef {
+        let object = ObjectRef::new(object);
+        self.heap.objects.push(object.downgrade());
+        self.heap.maybe_collect();
+        object
+    }
+
+    pub fn allocate_scope(&mut self, scope: Scope) -> Rc<RefCell<Scope>> {
+        let scope = scope.into_rc();
+        self.heap.track_scope(&scope);
+        self.heap.maybe_collect();
+        scope
+    }
+
+    /// Makes room for an object with nothing in it yet.
+    pub fn reserve_object(&mut self) -> EvalResult<()> {
+        self.reserve(size_of::<RefCell<Object>>())
+    }
+
+    /// Makes room for `bytes` more, such as a string about to be built, failing instead of going
+    /// over the memory limit. The running estimate is only trusted while it's under the limit,
+    /// above it garbage is collected and usage measured again.
+    pub fn reserve(&mut self, bytes: usize) -> EvalResult<()> {
+        self.reserve_held(0, bytes)
+    }
+
+    /// Like `reserve`, for something built up a piece at a time where the collector can't see it,
+    /// with `held` the bytes reserved for it so far, which measuring again would miss.
+    pub fn reserve_held(&mut self, held: usize, bytes: usize) -> EvalResult<()> {
+        let Some(limit) = self.heap.limit else {
+            return Ok(());
+        };
+
+        self.heap.usage = self.heap.usage.saturating_add(bytes);
+
+        if self.heap.usage <= limit {
+            return Ok(());
+        }
+
+        self.heap.collect();
+        let usage = self.heap.usage;
+        self.heap.usage = usage.saturating_add(held).saturating_add(bytes);
+
+        if self.heap.usage <= limit {
+            return Ok(());
+        }
+
+        self.heap.usage = usage;
+
+        Err(match self.heap.on_exceeded {
+            OutOfMemory::Throw => self.throw_error(ErrorKind::RangeError, "Out of memory"),
+            OutOfMemory::Terminate => self.terminate(Termination::OutOfMemory),
+        })
+    }
+
+    /// The bytes held by the objects, arrays, strings and scopes scripts created that are alive.
+    pub fn memory_usage(&self) -> usize {
+        self.heap.measure()
+    }
+
+    /// Frees the objects and scopes only kept alive by cycles among themselves. This also happens
+    /// on its own as scripts allocate.
+    pub fn collect_garbage(&mut self) -> GcStats {
+        self.heap.collect()
+    }
+
+    /// Everything garbage collection freed so far.
+    pub fn gc_stats(&self) -> GcStats {
+        self.heap.collected
+    }
+
+    /// Limits the memory scripts may hold on to, or lifts the limit with `None`.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.heap.limit = limit;
+    }
+
+    pub fn set_out_of_memory(&mut self, on_exceeded: OutOfMemory) {
+        self.heap.on_exceeded = on_exceeded;
+    }
+}
@@ -0,0 +1,429 @@
+use crate::object::{ObjectKind, ObjectRef, PromiseState, Property, PropertyKey};
+use crate::types::{number_to_string, Value};
+use super::Interpreter;
+
+/// How many levels of nested objects are shown before they are abbreviated to `[Object]`.
+const DEPTH: usize = 2;
+/// The width objects are kept under before their entries get a line each.
+const BREAK_LENGTH: usize = 80;
+/// How many levels of nesting may still share a single line.
+const COMPACT: usize = 3;
+const MAX_ARRAY_LENGTH: usize = 100;
+
+impl Interpreter {
+    /// Formats `value` for humans the way Node's `util.inspect` does. Never runs script code, so
+    /// getters show up as `[Getter]` rather than being called.
+    pub fn inspect(&self, value: &Value) -> String {
+        let mut inspector = Inspector { interpreter: self, seen: Vec::new(), circular: Vec::new(), current_depth: 0 };
+        inspector.format(value, 0)
+    }
+}
+
+struct Inspector<'a> {
+    interpreter: &'a Interpreter,
+    // the objects being formatted, outermost first
+    seen: Vec<ObjectRef>,
+    // the objects found inside themselves, numbered in the order they were found
+    circular: Vec<ObjectRef>,
+    // the depth of the object formatted last, which decides whether its parents fit on a line
+    current_depth: usize,
+}
+
+/// How an object is shown: the text before its braces, the braces and what goes between them.
+struct Layout {
+    base: String,
+    braces: (&'static str, &'static str),
+    items: Vec<Item>,
+    // the elements of an array, which may be grouped into columns
+    elements: Option<Vec<Value>>,
+    // objects that show nothing but `base` when they have no entries, such as functions
+    bare: bool,
+}
+
+/// An entry shown between the braces besides the enumerable properties.
+enum Item {
+    Text(&'static str),
+    Value(Value),
+    Pair(Value, Value),
+    Rejected(Value),
+}
+
+impl Inspector<'_> {
+    fn format(&mut self, value: &Value, depth: usize) -> String {
+        match value {
+            Value::Float(number) if *number == 0.0 && number.is_sign_negative() => "-0".to_string(),
+            Value::Float(number) => number_to_string(*number),
+            Value::StringLiteral(string) => quote(string),
+            Value::Object(object) => self.format_object(object, depth),
+            value => value.to_display_string(),
+        }
+    }
+
+    fn format_object(&mut self, object: &ObjectRef, depth: usize) -> String {
+        if self.seen.iter().any(|seen| seen.ptr_eq(object)) {
+            let index = match self.circular.iter().position(|circular| circular.ptr_eq(object)) {
+                Some(index) => index,
+                None => {
+                    self.circular.push(object.clone());
+                    self.circular.len() - 1
+                },
+            };
+
+            return format!("[Circular *{}]", index + 1);
+        }
+
+        let layout = self.layout(object);
+        let keys = enumerable_keys(object, layout.elements.is_some());
+
+        if layout.items.is_empty() && keys.is_empty() && layout.elements.as_ref().is_none_or(Vec::is_empty) {
+            return match (layout.bare, layout.base.is_empty()) {
+                (true, _) => layout.base.replace('\n', &format!("\n{}", " ".repeat(depth * 2))),
+                (false, true) => format!("{}{}", layout.braces.0, layout.braces.1),
+                (false, false) => format!("{} {}{}", layout.base, layout.braces.0, layout.braces.1),
+            };
+        }
+
+        if depth > DEPTH {
+            return match self.constructor_name(object) {
+                Some(name) => format!("[{}]", name),
+                None => "[Object: null prototype]".to_string(),
+            };
+        }
+
+        self.seen.push(object.clone());
+        self.current_depth = depth;
+
+        let mut entries = Vec::new();
+
+        for item in &layout.items {
+            entries.push(match item {
+                Item::Text(text) => text.to_string(),
+                Item::Value(value) => self.format(value, depth + 1),
+                Item::Pair(key, value) => format!("{} => {}", self.format(key, depth + 1), self.format(value, depth + 1)),
+                Item::Rejected(reason) => format!("<rejected> {}", self.format(reason, depth + 1)),
+            });
+        }
+
+        if let Some(elements) = &layout.elements {
+            for element in elements.iter().take(MAX_ARRAY_LENGTH) {
+                entries.push(self.format(element, depth + 1));
+            }
+
+            if elements.len() > MAX_ARRAY_LENGTH {
+                let more = elements.len() - MAX_ARRAY_LENGTH;
+                entries.push(format!("... {} more item{}", more, if more > 1 { "s" } else { "" }));
+            }
+        }
+
+        for key in keys {
+            let property = object.borrow().get_own_property(&key);
+
+            if let Some(property) = property {
+                entries.push(format!("{}: {}", format_key(&key), self.format_property(&property, depth)));
+            }
+        }
+
+        self.seen.pop();
+
+        let mut output = self.reduce_to_single_string(&layout, entries, depth);
+
+        if let Some(index) = self.circular.iter().position(|circular| circular.ptr_eq(object)) {
+            output = format!("<ref *{}> {}", index + 1, output);
+        }
+
+        output
+    }
+
+    fn format_property(&mut self, property: &Property, depth: usize) -> String {
+        match (&property.getter, &property.setter) {
+            (Some(_), Some(_)) => "[Getter/Setter]".to_string(),
+            (Some(_), None) => "[Getter]".to_string(),
+            (None, Some(_)) => "[Setter]".to_string(),
+            (None, None) => self.format(&property.value, depth + 1),
+        }
+    }
+
+    /// Works out how to show `object` apart from its enumerable properties.
+    fn layout(&self, object: &ObjectRef) -> Layout {
+        let mut layout = Layout { base: String::new(), braces: ("{", "}"), items: Vec::new(), elements: None, bare: false };
+        let kind = match &object.borrow().kind {
+            ObjectKind::Function(closure) => Some((closure.def.is_async, closure.def.is_generator)),
+            ObjectKind::NativeFunction(_) => Some((false, false)),
+            _ => None,
+        };
+
+        if let Some((is_async, is_generator)) = kind {
+            let kind = match (is_async, is_generator) {
+                (true, true) => "AsyncGeneratorFunction",
+                (true, false) => "AsyncFunction",
+                (false, true) => "GeneratorFunction",
+                (false, false) => "Function",
+            };
+
+            layout.base = match object.get_own("name") {
+                Some(Value::StringLiteral(name)) if !name.is_empty() => format!("[{}: {}]", kind, name),
+                _ => format!("[{} (anonymous)]", kind),
+            };
+
+            layout.bare = true;
+            return layout;
+        }
+
+        let prefix = self.prefix(object);
+        let object = object.borrow();
+
+        match &object.kind {
+            ObjectKind::Array(elements) => {
+                layout.base = prefix.filter(|prefix| prefix != "Array").unwrap_or_default();
+                layout.braces = ("[", "]");
+                layout.elements = Some(elements.clone());
+            },
+            ObjectKind::Arguments => {
+                let elements = object.own_keys().into_iter()
+                    .filter(|key| key.as_array_index().is_some())
+                    .filter_map(|key| object.get_own_property(&key).map(|property| property.value))
+                    .collect();
+
+                layout.base = "[Arguments]".to_string();
+                layout.braces = ("[", "]");
+                layout.elements = Some(elements);
+            },
+            ObjectKind::Error => {
+                let name = object.prototype.as_ref().and_then(|prototype| prototype.get("name"));
+                let name = name.map(|name| name.to_display_string()).unwrap_or_else(|| "Error".to_string());
+
+                layout.base = match (object.get_own_property(&"stack".into()), object.get_own_property(&"message".into())) {
+                    (Some(Property { value: Value::StringLiteral(stack), .. }), _) => stack.to_string(),
+                    (_, Some(Property { value: Value::StringLiteral(message), .. })) if !message.is_empty() => format!("[{}: {}]", name, message),
+                    _ => format!("[{}]", name),
+                };
+
+                layout.bare = true;
+            },
+            ObjectKind::Map(entries) => {
+                layout.base = format!("{}({})", prefix.unwrap_or_default(), entries.len());
+                layout.items = entries.iter().map(|(key, value)| Item::Pair(key.clone(), value.clone())).collect();
+            },
+            ObjectKind::Set(entries) => {
+                layout.base = format!("{}({})", prefix.unwrap_or_default(), entries.len());
+                layout.items = entries.iter().map(|(key, _)| Item::Value(key.clone())).collect();
+            },
+            ObjectKind::RegExp(regex) => {
+                layout.base = format!("{:?}", regex);
+                layout.bare = true;
+            },
+            ObjectKind::Promise(state, _) => {
+                layout.base = prefix.unwrap_or_default();
+                layout.items.push(match state {
+                    PromiseState::Pending(_) => Item::Text("<pending>"),
+                    PromiseState::Fulfilled(value) => Item::Value(value.clone()),
+                    PromiseState::Rejected(reason) => Item::Rejected(reason.clone()),
+                });
+            },
+            _ => {
+                layout.base = match prefix {
+                    Some(prefix) if prefix == "Object" => String::new(),
+                    Some(prefix) => prefix,
+                    None => "[Object: null prototype]".to_string(),
+                };
+            },
+        }
+
+        layout
+    }
+
+    /// The name of the constructor followed by the `Symbol.toStringTag` if it differs, as in
+    /// `Object [Generator]`.
+    fn prefix(&self, object: &ObjectRef) -> Option<String> {
+        let name = self.constructor_name(object);
+        let tag = match object.get(self.interpreter.intrinsics.symbol_to_string_tag.clone()) {
+            Some(Value::StringLiteral(tag)) if !tag.is_empty() && Some(&*tag) != name.as_deref() => Some(tag),
+            _ => None,
+        };
+
+        match (name, tag) {
+            (Some(name), Some(tag)) => Some(format!("{} [{}]", name, tag)),
+            (None, Some(tag)) => Some(format!("[Object: null prototype] [{}]", tag)),
+            (name, None) => name,
+        }
+    }
+
+    /// The name of the nearest `constructor` on the prototype chain.
+    fn constructor_name(&self, object: &ObjectRef) -> Option<String> {
+        let mut current = object.prototype();
+
+        while let Some(prototype) = current {
+            if let Some(Value::Object(constructor)) = prototype.get_own("constructor") {
+                if let Some(Value::StringLiteral(name)) = constructor.get_own("name") {
+                    if !name.is_empty() {
+                        return Some(name.to_string());
+                    }
+                }
+            }
+
+            current = prototype.prototype();
+        }
+
+        None
+    }
+
+    /// Puts the entries on one line if they fit, or on a line each.
+    fn reduce_to_single_string(&self, layout: &Layout, mut entries: Vec<String>, depth: usize) -> String {
+        let indentation = depth * 2;
+        let count = entries.len();
+
+        if let Some(elements) = layout.elements.as_ref().filter(|_| count > 6) {
+            entries = group_array_elements(&entries, elements, indentation);
+        }
+
+        let base = match layout.base.is_empty() {
+            true => String::new(),
+            false => format!("{} ", layout.base.replace('\n', &format!("\n{}", " ".repeat(indentation)))),
+        };
+
+        if self.current_depth - depth < COMPACT && count == entries.len() {
+            let start = entries.len() + indentation + layout.braces.0.len() + layout.base.chars().count() + 10;
+            let length: usize = entries.iter().map(|entry| entry.chars().count()).sum::<usize>() + entries.len() + start;
+            let joined = entries.join(", ");
+
+            if length <= BREAK_LENGTH && !layout.base.contains('\n') && !joined.contains('\n') {
+                return format!("{}{} {} {}", base, layout.braces.0, joined, layout.braces.1);
+            }
+        }
+
+        let newline = format!("\n{}", " ".repeat(indentation));
+        format!("{}{}{}  {}{}{}", base, layout.braces.0, newline, entries.join(&format!(",{}  ", newline)), newline, layout.braces.1)
+    }
+}
+
+/// Arranges the entries of long arrays into columns, so that they take up fewer lines.
+fn group_array_elements(entries: &[String], elements: &[Value], indentation: usize) -> Vec<String> {
+    let mut output_length = entries.len();
+
+    // the "... more items" entry is left on a line of its own
+    if elements.len() > MAX_ARRAY_LENGTH {
+        output_length -= 1;
+    }
+
+    let separator_space = 2;
+    let lengths: Vec<usize> = entries[..output_length].iter().map(|entry| entry.chars().count()).collect();
+    let total_length: usize = lengths.iter().map(|length| length + separator_space).sum();
+    let max_length = lengths.iter().copied().max().unwrap_or(0);
+    let actual_max = max_length + separator_space;
+
+    if entries.iter().any(|entry| entry.contains('\n')) {
+        return entries.to_vec();
+    }
+
+    if actual_max * 3 + indentation >= BREAK_LENGTH || (total_length as f64 / actual_max as f64 <= 5.0 && max_length > 6) {
+        return entries.to_vec();
+    }
+
+    // aim for a block that looks roughly square, given characters are about 2.5 times as high as wide
+    let average_bias = (actual_max as f64 - total_length as f64 / entries.len() as f64).sqrt();
+    let biased_max = (actual_max as f64 - 3.0 - average_bias).max(1.0);
+    let columns = ((2.5 * biased_max * output_length as f64).sqrt() / biased_max).round() as usize;
+    let columns = columns.min((BREAK_LENGTH - indentation) / actual_max).min(COMPACT * 4).min(15);
+
+    if columns <= 1 {
+        return entries.to_vec();
+    }
+
+    let max_line_lengths: Vec<usize> = (0..columns)
+        .map(|column| lengths.iter().skip(column).step_by(columns).copied().max().unwrap_or(0) + separator_space)
+        .collect();
+
+    // numbers are aligned to the right, everything else to the left
+    let pad_start = elements.iter().all(|element| matches!(element, Value::Float(_)));
+    let mut grouped = Vec::new();
+
+    for start in (0..output_length).step_by(columns) {
+        let end = (start + columns).min(output_length);
+        let mut line = String::new();
+
+        for index in start..end {
+            let is_last = index == end - 1;
+            let entry = match is_last {
+                true => entries[index].clone(),
+                false => format!("{}, ", entries[index]),
+            };
+
+            let width = match is_last {
+                true => max_line_lengths[index - start] - separator_space,
+                false => max_line_lengths[index - start],
+            };
+
+            match (pad_start, is_last) {
+                (true, _) => line.push_str(&format!("{:>width$}", entry, width = width)),
+                (false, false) => line.push_str(&format!("{:<width$}", entry, width = width)),
+                (false, true) => line.push_str(&entry),
+            }
+        }
+
+        grouped.push(line);
+    }
+
+    if output_length < entries.len() {
+        grouped.push(entries[output_length].clone());
+    }
+
+    grouped
+}
+
+/// The keys `inspect` shows: own enumerable properties, apart from the elements of array-likes.
+fn enumerable_keys(object: &ObjectRef, skip_indices: bool) -> Vec<PropertyKey> {
+    let object = object.borrow();
+
+    object.own_keys().into_iter()
+        .filter(|key| !(skip_indices && key.as_array_index().is_some()))
+        .filter(|key| object.get_own_property(key).is_some_and(|property| property.enumerable))
+        .collect()
+}
+
+fn format_key(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::String(key) if is_identifier(key) => key.to_string(),
+        PropertyKey::String(key) => quote(key),
+        PropertyKey::Symbol(symbol) => format!("[{}]", symbol),
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Quotes a string with single quotes, or with whichever other quotes it doesn't contain.
+fn quote(string: &str) -> String {
+    let quote = match (string.contains('\''), string.contains('"'), string.contains('`')) {
+        (false, _, _) => '\'',
+        (true, false, _) => '"',
+        (true, true, false) => '`',
+        (true, true, true) => '\'',
+    };
+
+    let mut quoted = String::from(quote);
+
+    for c in string.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\u{b}' => quoted.push_str("\\v"),
+            '\\' => quoted.push_str("\\\\"),
+            c if c == quote => {
+                quoted.push('\\');
+                quoted.push(c);
+            },
+            c if (c as u32) < 0x20 || c as u32 == 0x7f => quoted.push_str(&format!("\\x{:02X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push(quote);
+    quoted
+}
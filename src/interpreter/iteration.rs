@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+use crate::builtins::argument;
+use crate::error::ErrorKind;
+use crate::object::{ObjectRef, PropertyKey};
+use crate::scope::Scope;
+use crate::types::{DeclarationKind, Expr, ForBinding, Signal, Stmt, Value};
+use super::{EvalResult, Interpreter};
+
+/// An iterator together with its `next` method, looked up once when iteration starts.
+#[derive(Clone, Debug)]
+pub struct IteratorRecord {
+    pub iterator: Value,
+    pub next: Value,
+}
+
+impl Interpreter {
+    /// `GetIterator`: calls `value[Symbol.iterator]()` and checks that it returned an object.
+    pub fn get_iterator(&mut self, value: &Value) -> EvalResult<IteratorRecord> {
+        let symbol = self.intrinsics.symbol_iterator.clone();
+        let method = match value {
+            Value::Null | Value::None => Value::None,
+            _ => self.get_property(value, symbol)?,
+        };
+
+        if !method.is_callable() {
+            let message = format!("{} is not iterable", Self::describe_value(value));
+            return Err(self.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let iterator = self.call_function(&method, value.clone(), &[])?;
+
+        if !matches!(iterator, Value::Object(_)) {
+            return Err(self.throw_error(ErrorKind::TypeError, "Result of the Symbol.iterator method is not an object"));
+        }
+
+        let next = self.get_property(&iterator, "next")?;
+        Ok(IteratorRecord { iterator, next })
+    }
+
+    /// The iterator `for await` loops over: `value[Symbol.asyncIterator]()`, or else the sync
+    /// iterator of the value wrapped so that its results and their values are awaited.
+    pub fn get_async_iterator(&mut self, value: &Value) -> EvalResult<IteratorRecord> {
+        let symbol = self.intrinsics.symbol_async_iterator.clone();
+        let method = match value {
+            Value::Null | Value::None => Value::None,
+            _ => self.get_property(value, symbol)?,
+        };
+
+        if method.is_nullish() {
+            let record = self.get_iterator(value)?;
+            return Ok(self.create_async_from_sync_iterator(record));
+        }
+
+        if !method.is_callable() {
+            let message = format!("{} is not async iterable", Self::describe_value(value));
+            return Err(self.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let iterator = self.call_function(&method, value.clone(), &[])?;
+
+        if !matches!(iterator, Value::Object(_)) {
+            return Err(self.throw_error(ErrorKind::TypeError, "Result of the Symbol.asyncIterator method is not an object"));
+        }
+
+        let next = self.get_property(&iterator, "next")?;
+        Ok(IteratorRecord { iterator, next })
+    }
+
+    /// `CreateAsyncFromSyncIterator`: `next` and `return` forward to the sync iterator and return
+    /// promises for its results, fulfilled once the values in them are.
+    fn create_async_from_sync_iterator(&mut self, record: IteratorRecord) -> IteratorRecord {
+        let iterator = self.create_object();
+
+        for (name, is_return) in [("next", false), ("return", true)] {
+            let record = record.clone();
+
+            let method = self.create_native_function(name, 1, Rc::new(move |interpreter, _, args| {
+                let result = match is_return {
+                    true => match interpreter.get_property(&record.iterator, "return")? {
+                        method if method.is_nullish() => interpreter.create_iter_result(argument(args, 0), true),
+                        method => interpreter.call_function(&method, record.iterator.clone(), args)?,
+                    },
+                    false => interpreter.call_function(&record.next, record.iterator.clone(), args)?,
+                };
+
+                if !matches!(result, Value::Object(_)) {
+                    let message = format!("Iterator result {} is not an object", result.to_display_string());
+                    return Err(interpreter.throw_error(ErrorKind::TypeError, message));
+                }
+
+                let done = interpreter.get_property(&result, "done")?.is_truthy();
+                let value = interpreter.get_property(&result, "value")?;
+                let value = interpreter.promise_resolve(value);
+
+                let unwrap = interpreter.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+                    Ok(interpreter.create_iter_result(argument(args, 0), done))
+                }));
+
+                let promise = interpreter.create_promise();
+                interpreter.perform_then(&value, unwrap, Value::None, Some(promise.clone()));
+                Ok(Value::Object(promise))
+            }));
+
+            iterator.set_hidden(name, method.clone());
+        }
+
+        let next = iterator.get("next").unwrap_or(Value::None);
+        IteratorRecord { iterator: Value::Object(iterator), next }
+    }
+
+    /// Advances the iterator, returning `None` once it reports that it is done.
+    pub fn iterator_step(&mut self, record: &IteratorRecord) -> EvalResult<Option<Value>> {
+        if !record.next.is_callable() {
+            let message = format!("{} is not a function", record.next.to_display_string());
+            return Err(self.throw_error(ErrorKind::TypeError, message));
+        }
+
+        let result = self.call_function(&record.next, record.iterator.clone(), &[])?;
+
+        if !matches!(result, Value::Object(_)) {
+            let message = format!("Iterator result {} is not an object", result.to_display_string());
+            return Err(self.throw_error(ErrorKind::TypeError, message));
+        }
+
+        match self.get_property(&result, "done")?.is_truthy() {
+            true => Ok(None),
+            false => Ok(Some(self.get_property(&result, "value")?)),
+        }
+    }
+
+    /// `IteratorClose`: lets the iterator clean up when a loop exits before it is exhausted.
+    ///
+    /// `completion` is how the loop ended. A throw wins over anything `return()` does, otherwise
+    /// errors from `return()` replace the completion.
+    pub fn iterator_close<T>(&mut self, record: &IteratorRecord, completion: EvalResult<T>) -> EvalResult<T> {
+        let method = match self.get_property(&record.iterator, "return") {
+            Ok(method) => method,
+            Err(signal) => return completion.and(Err(signal)),
+        };
+
+        if method.is_nullish() {
+            return completion;
+        }
+
+        let result = match method.is_callable() {
+            true => self.call_function(&method, record.iterator.clone(), &[]),
+            false => Err(self.throw_error(ErrorKind::TypeError, "Iterator return is not a function")),
+        };
+
+        if matches!(completion, Err(Signal::Throw(_))) {
+            return completion;
+        }
+
+        match result? {
+            Value::Object(_) => completion,
+            _ => Err(self.throw_error(ErrorKind::TypeError, "Iterator result is not an object")),
+        }
+    }
+
+    /// Drains an iterable into a vector, the way spreading it into an array would.
+    pub fn iterate_to_vec(&mut self, iterable: &Value) -> EvalResult<Vec<Value>> {
+        let record = self.get_iterator(iterable)?;
+        let mut values = Vec::new();
+
+        while let Some(value) = self.iterator_step(&record)? {
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    /// Creates the `{ value, done }` objects iterators return from `next()`.
+    pub fn create_iter_result(&mut self, value: Value, done: bool) -> Value {
+        let result = self.create_object();
+        result.set("value", value);
+        result.set("done", Value::Boolean(done));
+        Value::Object(result)
+    }
+
+    pub(super) fn eval_for_of(&mut self, binding: &ForBinding, iterable: &Expr, stmts: &Stmt) -> EvalResult {
+        let iterable = self.eval_expr(iterable)?;
+        let record = self.get_iterator(&iterable)?;
+
+        while let Some(value) = self.iterator_step(&record)? {
+            match self.eval_for_iteration(binding, value, stmts) {
+                Ok(_) | Err(Signal::Continue) => (),
+                Err(Signal::Break) => return self.iterator_close(&record, Ok(Value::None)),
+                Err(signal) => return self.iterator_close(&record, Err(signal)),
+            }
+        }
+
+        Ok(Value::None)
+    }
+
+    pub(super) fn eval_for_in(&mut self, binding: &ForBinding, object: &Expr, stmts: &Stmt) -> EvalResult {
+        let object = self.eval_expr(object)?;
+        let (object, keys) = Self::for_in_keys(object);
+
+        for key in keys {
+            if Self::for_in_skips(object.as_ref(), &key) {
+                continue;
+            }
+
+            match self.eval_for_iteration(binding, key, stmts) {
+                Ok(_) | Err(Signal::Continue) => (),
+                Err(Signal::Break) => break,
+                Err(signal) => return Err(signal),
+            }
+        }
+
+        Ok(Value::None)
+    }
+
+    /// The keys `for...in` visits in a value, along with the object they are checked against as
+    /// the loop goes. Strings have their indices, and other primitives none at all.
+    pub(super) fn for_in_keys(value: Value) -> (Option<ObjectRef>, Vec<Value>) {
+        match value {
+            Value::Object(object) => {
+                let keys = Self::enumerable_keys(&object).into_iter().map(|key| Value::StringLiteral(key.into())).collect();
+                (Some(object), keys)
+            },
+            Value::StringLiteral(string) => (None, (0..string.utf16_len()).map(|i| Value::StringLiteral(i.to_string().into())).collect()),
+            _ => (None, Vec::new()),
+        }
+    }
+
+    /// Properties deleted by an earlier iteration of `for...in` are skipped.
+    pub(super) fn for_in_skips(object: Option<&ObjectRef>, key: &Value) -> bool {
+        match (object, key) {
+            (Some(object), Value::StringLiteral(name)) => !object.has_property(name),
+            _ => false,
+        }
+    }
+
+    /// The enumerable string keys of an object and its prototypes, without duplicates, in the
+    /// order `for...in` visits them.
+    pub fn enumerable_keys(object: &ObjectRef) -> Vec<Rc<str>> {
+        let mut visited: HashSet<PropertyKey> = HashSet::new();
+        let mut keys = Vec::new();
+        let mut current = Some(object.clone());
+
+        while let Some(object) = current {
+            let borrowed = object.borrow();
+
+            for key in borrowed.own_keys() {
+                if visited.contains(&key) {
+                    continue;
+                }
+
+                let enumerable = borrowed.get_own_property(&key).is_some_and(|property| property.enumerable);
+
+                // a non-enumerable property still shadows enumerable ones further up the chain
+                visited.insert(key.clone());
+
+                if let (true, PropertyKey::String(key)) = (enumerable, key) {
+                    keys.push(key);
+                }
+            }
+
+            current = borrowed.prototype.clone();
+        }
+
+        keys
+    }
+
+    /// Binds the value for one iteration of a `for...in` or `for...of` loop and runs the body.
+    fn eval_for_iteration(&mut self, binding: &ForBinding, value: Value, stmts: &Stmt) -> EvalResult {
+        match binding {
+            ForBinding::Declaration(kind @ (DeclarationKind::Let | DeclarationKind::Const), pattern) => {
+                let scope = self.allocate_scope(Scope::new(Some(self.scope.clone())));
+
+                self.with_scope(scope, |interpreter| {
+                    interpreter.bind_pattern(pattern, value, Some(*kind))?;
+                    interpreter.eval_stmt(stmts)
+                })
+            },
+            // `var` bindings are hoisted, so they are assigned like any other target
+            ForBinding::Declaration(DeclarationKind::Var, pattern) | ForBinding::Target(pattern) => {
+                self.bind_pattern(pattern, value, None)?;
+                self.eval_stmt(stmts)
+            },
+        }
+    }
+
+    /// Short rendering of a value for error messages such as "x is not iterable".
+    fn describe_value(value: &Value) -> String {
+        match value {
+            Value::StringLiteral(string) => format!("\"{}\"", string),
+            Value::Object(object) if object.is_callable() => "function".to_string(),
+            Value::Object(_) => "object".to_string(),
+            value => value.to_display_string(),
+        }
+    }
+}
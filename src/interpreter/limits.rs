@@ -0,0 +1,196 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::error::{ErrorKind, Termination};
+use crate::types::Signal;
+use super::{EvalResult, Interpreter};
+
+/// How deep calls may nest by default.
+const DEFAULT_MAX_CALL_DEPTH: usize = 2000;
+/// How many steps go by between looking at the time, which is slower than counting.
+const STEPS_PER_DEADLINE_CHECK: u64 = 256;
+/// How many iterations a long loop in a native function goes through between calls to
+/// `check_limits`.
+pub const ITERATIONS_PER_CHECK: usize = 4096;
+
+/// Stops a running script from another thread, see `Context::interrupt_handle`.
+#[derive(Clone, Debug, Default)]
+pub struct InterruptHandle(Arc<AtomicBool>);
+
+impl InterruptHandle {
+    /// Makes the script stop at its next step. Does nothing if no script is running then.
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// What an untrusted script is allowed to use up.
+pub struct Limits {
+    // steps left to run, where a step is a statement or an operation of a generator
+    fuel: Option<u64>,
+    time_limit: Option<Duration>,
+    deadline: Option<Instant>,
+    steps_since_deadline_check: u64,
+    max_call_depth: usize,
+    call_depth: usize,
+    interrupt: InterruptHandle,
+    // set once a limit was hit, so that the script keeps stopping even where a signal is dropped
+    terminated: Option<Termination>,
+}
+
+impl Limits {
+    pub fn new() -> Self {
+        Limits {
+            fuel: None,
+            time_limit: None,
+            deadline: None,
+            steps_since_deadline_check: 0,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            call_depth: 0,
+            interrupt: InterruptHandle::default(),
+            terminated: None,
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interpreter {
+    /// Charges a step against the limits, stopping the script once one of them is hit.
+    pub fn step(&mut self) -> EvalResult<()> {
+        self.step_by(1)
+    }
+
+    /// Charges `steps` steps at once, for native functions that do work in proportion to the size
+    /// of what they are given, such as searching the elements of an array. Those that loop for
+    /// long also call `check_limits` as they go.
+    pub fn step_by(&mut self, steps: usize) -> EvalResult<()> {
+        let limits = &mut self.limits;
+        let steps = steps as u64;
+
+        if let Some(termination) = limits.terminated {
+            return Err(Signal::Terminate(termination));
+        }
+
+        // a plain load is much cheaper than the swap, and the flag is almost never set
+        let interrupted = limits.interrupt.0.load(Ordering::Relaxed) && limits.interrupt.0.swap(false, Ordering::Relaxed);
+
+        let termination = if interrupted {
+            Some(Termination::Interrupted)
+        } else if limits.fuel.is_some_and(|fuel| fuel < steps) {
+            Some(Termination::OutOfFuel)
+        } else {
+            None
+        };
+
+        if let Some(fuel) = &mut limits.fuel {
+            *fuel = fuel.saturating_sub(steps);
+        }
+
+        let termination = termination.or_else(|| {
+            let deadline = limits.deadline?;
+            limits.steps_since_deadline_check = limits.steps_since_deadline_check.saturating_add(steps);
+
+            if limits.steps_since_deadline_check < STEPS_PER_DEADLINE_CHECK {
+                return None;
+            }
+
+            limits.steps_since_deadline_check = 0;
+            (Instant::now() >= deadline).then_some(Termination::DeadlineExceeded)
+        });
+
+        match termination {
+            Some(termination) => Err(self.terminate(termination)),
+            None => Ok(()),
+        }
+    }
+
+    /// Looks at the interrupt flag and the deadline without charging any steps, for native
+    /// functions that charged for a loop up front to call every `ITERATIONS_PER_CHECK` iterations.
+    pub fn check_limits(&mut self) -> EvalResult<()> {
+        let limits = &mut self.limits;
+
+        if let Some(termination) = limits.terminated {
+            return Err(Signal::Terminate(termination));
+        }
+
+        let interrupted = limits.interrupt.0.load(Ordering::Relaxed) && limits.interrupt.0.swap(false, Ordering::Relaxed);
+
+        let termination = if interrupted {
+            Some(Termination::Interrupted)
+        } else if limits.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            Some(Termination::DeadlineExceeded)
+        } else {
+            None
+        };
+
+        match termination {
+            Some(termination) => Err(self.terminate(termination)),
+            None => Ok(()),
+        }
+    }
+
+    /// Stops the script, dropping the microtasks and timers it had queued.
+    pub fn terminate(&mut self, termination: Termination) -> Signal {
+        self.limits.terminated = Some(termination);
+        self.jobs.clear();
+        self.timers.clear();
+        Signal::Terminate(termination)
+    }
+
+    /// Why the script was stopped, if it was.
+    pub fn termination(&self) -> Option<Termination> {
+        self.limits.terminated
+    }
+
+    /// Gets ready for the host to run more code after a script was stopped, and restarts the
+    /// clock on the time limit. An interrupt from while no script was running is dropped.
+    pub fn start_execution(&mut self) {
+        self.limits.terminated = None;
+        self.limits.interrupt.0.store(false, Ordering::Relaxed);
+        self.limits.call_depth = 0;
+        self.limits.deadline = self.limits.time_limit.map(|limit| Instant::now() + limit);
+    }
+
+    /// Counts a call against the maximum depth, throwing a `RangeError` when it is exceeded.
+    pub(super) fn enter_call(&mut self) -> EvalResult<()> {
+        if self.limits.call_depth >= self.limits.max_call_depth {
+            return Err(self.throw_error(ErrorKind::RangeError, "Maximum call stack size exceeded"));
+        }
+
+        self.limits.call_depth += 1;
+        Ok(())
+    }
+
+    pub(super) fn exit_call(&mut self) {
+        self.limits.call_depth -= 1;
+    }
+
+    /// Limits how many steps scripts may run in total, or lifts the limit with `None`.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.limits.fuel = fuel;
+    }
+
+    /// The steps scripts may still run, if they are limited.
+    pub fn fuel(&self) -> Option<u64> {
+        self.limits.fuel
+    }
+
+    /// Limits how long each call into scripts by the host may run, or lifts the limit with `None`.
+    pub fn set_time_limit(&mut self, limit: Option<Duration>) {
+        self.limits.time_limit = limit;
+        self.limits.deadline = limit.map(|limit| Instant::now() + limit);
+    }
+
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.limits.max_call_depth = depth;
+    }
+
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.limits.interrupt.clone()
+    }
+}
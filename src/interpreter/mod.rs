@@ -1,576 +1,1295 @@
-use crate::scope::Scope;
-use crate::types::{Expr, Stmt, Value, Signal};
-use std::rc::Rc;
+use crate::builtins::Intrinsics;
+use crate::error::{ErrorKind, JsError};
+use crate::object::{Closure, NativeFunction, Object, ObjectKind, ObjectRef, Property, PropertyKey};
+use crate::regexp::Regex;
+use crate::scope::{Assignment, Scope};
+use crate::types::{DeclarationKind, Expr, ForBinding, FunctionDef, ObjectMember, Pattern, PropertyName, Signal, Stmt, Token, Value};
 use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+mod destructuring;
+mod generator;
+mod async_function;
+mod async_generator;
+mod timers;
+mod console;
+mod inspect;
+mod iteration;
+mod operations;
+mod promise;
+mod limits;
+mod heap;
+mod cache;
+mod random;
+mod vm;
+
+pub use generator::{GeneratorState, ResumeMode};
+pub use async_generator::AsyncGeneratorState;
+pub use timers::{Clock, SystemClock, Timers, VirtualClock};
+pub use console::{Console, ConsoleSink, LogLevel};
+pub use iteration::IteratorRecord;
+pub use operations::PreferredType;
+pub use promise::Job;
+pub use limits::{InterruptHandle, Limits, ITERATIONS_PER_CHECK};
+pub use heap::{entry_size, property_size, value_size, GcStats, Heap, OutOfMemory};
+pub(crate) use heap::{tear_down, Teardown};
+pub use cache::{CacheStats, InlineCache};
+pub use random::Random;
+pub use vm::Engine;
+
+pub type EvalResult<T = Value> = Result<T, Signal>;
+
+/// Something that can be assigned to, with its base and key already evaluated.
+enum Reference {
+    Binding(Rc<str>),
+    Property(Value, PropertyKey),
+}
+
+/// How much stack must be left before a call, and how much more is allocated when it isn't.
+pub(crate) const STACK_RED_ZONE: usize = 128 * 1024;
+pub(crate) const STACK_GROWTH: usize = 2 * 1024 * 1024;
 
 pub struct Interpreter {
-    scope: Option<Rc<RefCell<Scope>>>,
+    scope: Rc<RefCell<Scope>>,
+    intrinsics: Rc<Intrinsics>,
+    // names of the script functions currently executing, innermost last
+    call_stack: Vec<Rc<str>>,
+    new_target: Option<Value>,
+    // the microtask queue
+    jobs: VecDeque<Job>,
+    // promises rejected without a handler, reported unless one is attached before the queue drains
+    rejections: Vec<ObjectRef>,
+    timers: Timers,
+    console: Console,
+    limits: Limits,
+    heap: Heap,
+    engine: Engine,
+    // what the compiler noticed about the scripts run since they were last taken
+    warnings: Vec<String>,
+    cache_stats: CacheStats,
+    random: Random,
+    // a call in tail position that the function making it returned to run in its place
+    tail_call: Option<(Closure, Value, Vec<Value>)>,
 }
 
 impl Interpreter {
-    pub fn new(scope: Option<Scope>) -> Self {
-        match scope {
-            Some(scope) => Interpreter {
-                scope: Some(Rc::new(RefCell::new(scope))),
-            },
-            None => Interpreter {
-                scope: None,
-            }
+    pub fn new(scope: Rc<RefCell<Scope>>, intrinsics: Rc<Intrinsics>) -> Self {
+        // the global scope holds on to what scripts create too
+        let mut heap = Heap::new();
+        heap.track_scope(&scope);
+
+        Interpreter {
+            scope,
+            intrinsics,
+            call_stack: Vec::new(),
+            new_target: None,
+            jobs: VecDeque::new(),
+            rejections: Vec::new(),
+            timers: Timers::new(Box::new(SystemClock::new())),
+            console: Console::new(),
+            limits: Limits::new(),
+            heap,
+            engine: Engine::default(),
+            warnings: Vec::new(),
+            cache_stats: CacheStats::default(),
+            random: Random::from_entropy(),
+            tail_call: None,
         }
     }
 
-    pub fn eval(&mut self, stmts: Vec<Stmt>) -> (Value, Signal) {
+    pub fn intrinsics(&self) -> &Intrinsics {
+        &self.intrinsics
+    }
+
+    /// The constructor `new` was applied to, while a native constructor is running.
+    pub fn new_target(&self) -> Option<Value> {
+        self.new_target.clone()
+    }
+
+    /// Runs the body of a script or function.
+    pub fn eval(&mut self, stmts: &[Stmt]) -> EvalResult {
+        self.hoist_var_declarations(stmts);
+        self.eval_statements(stmts)
+    }
+
+    fn eval_statements(&mut self, stmts: &[Stmt]) -> EvalResult {
+        self.hoist_declarations(stmts);
+
         let mut return_value = Value::None;
-        let mut signal_value = Signal::None;
 
         for stmt in stmts {
-            match stmt {
-                Stmt::Let(name, value) => self.eval_let(name, value),
-                Stmt::Log(expr) => self.eval_log(expr),
-                Stmt::Assignment(name, value) => self.eval_assignment(name, value),
-                Stmt::ControlFlow(condition, stmts, else_stmt) => {
-                    let (value, signal) = self.eval_if(condition, stmts, else_stmt);
-                    return_value = value;
-                    signal_value = signal;
-                },
-                Stmt::CodeBlock(stmts) => {
-                    let (value, signal) = self.eval_code_block(stmts);
-                    return_value = value;
-                    signal_value = signal;
-                },
-                Stmt::Function(function_name, args, function_body) => self.eval_let_function(function_name, args, function_body),
-                Stmt::FunctionCall(name, arguments) => {
-                    let (value, signal) = self.eval_function_call(name, arguments);
-                    return_value = value;
-                    signal_value = signal;
-                },
-                Stmt::Break => {
-                    return_value = Value::Break;
-                    signal_value = Signal::Break;
-                },
-                Stmt::Return(expr) => {
-                    match *expr {
-                        expr => {
-                            let (value, _) = self.eval_expr_with_signal(expr);
-                            return_value = value;
-                            signal_value = Signal::Return;
-                        },
-                    }
-                },
-                Stmt::ForLoop(init, condition, increment, stmts) => self.eval_for_loop(init, condition, increment, stmts),
-                Stmt::Loop(stmts) => self.eval_loop(stmts),
-                Stmt::While(condition, stmts) => self.eval_while(condition, stmts),
-                Stmt::Expression(expr) => {
-                    let (value, signal) = self.eval_expr_with_signal(*expr);
-                    return_value = value;
-                    signal_value = signal;
-                },
-                Stmt::None => (),
+            let value = self.eval_stmt(stmt)?;
+
+            if !matches!(stmt, Stmt::Declaration(_, _) | Stmt::Function(_) | Stmt::None) {
+                return_value = value;
             }
+        }
 
-            match signal_value {
-                Signal::Return => {
-                    break;
-                },
-                Signal::Break => {
-                    break;
-                },
-                Signal::None => (),
+        Ok(return_value)
+    }
+
+    fn eval_stmt(&mut self, stmt: &Stmt) -> EvalResult {
+        self.step()?;
+
+        match stmt {
+            Stmt::Declaration(kind, declarations) => {
+                self.eval_declaration(*kind, declarations)?;
+                Ok(Value::None)
+            },
+            Stmt::ControlFlow(condition, stmts, else_stmt) => self.eval_if(condition, stmts, else_stmt),
+            Stmt::CodeBlock(stmts) => self.eval_code_block(stmts),
+            // declarations are hoisted when the enclosing block is entered
+            Stmt::Function(_) => Ok(Value::None),
+            Stmt::Return(expr) => {
+                let value = match expr {
+                    Some(expr) => self.eval_expr(expr)?,
+                    None => Value::None,
+                };
+
+                Err(Signal::Return(value))
+            },
+            Stmt::ForLoop(init, condition, increment, stmts) => self.eval_for_loop(init, condition.as_ref(), increment.as_ref(), stmts),
+            Stmt::Loop(stmts) => self.eval_loop(stmts),
+            Stmt::While(condition, stmts) => self.eval_while(condition, stmts),
+            Stmt::ForOf(binding, iterable, stmts) => self.eval_for_of(binding, iterable, stmts),
+            Stmt::ForIn(binding, object, stmts) => self.eval_for_in(binding, object, stmts),
+            // async bodies are lowered, so this only remains where the parser rejects it
+            Stmt::ForAwaitOf(_, _, _) => Err(self.throw_error(ErrorKind::SyntaxError, "Unexpected for await")),
+            Stmt::Expression(expr) => self.eval_expr(expr),
+            Stmt::Throw(expr) => {
+                let value = self.eval_expr(expr)?;
+                Err(Signal::Throw(value))
+            },
+            Stmt::Try(block, handler, finalizer) => self.eval_try(block, handler.as_ref(), finalizer.as_deref()),
+            Stmt::Break => Err(Signal::Break),
+            Stmt::Continue => Err(Signal::Continue),
+            Stmt::None => Ok(Value::None),
+        }
+    }
+
+    fn hoist_declarations(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            if let Stmt::Function(def) = stmt {
+                let function = self.create_function(def.clone());
+                let name = def.name.clone().unwrap_or_default();
+
+                self.scope.borrow_mut().define(name, function);
             }
         }
+    }
 
-        (return_value, signal_value)
+    /// `var` bindings belong to the enclosing function or script, wherever in it they are declared,
+    /// so they are all created up front.
+    fn hoist_var_declarations(&mut self, stmts: &[Stmt]) {
+        let mut names = Vec::new();
+
+        for stmt in stmts {
+            Self::collect_var_names(stmt, &mut names);
+        }
+
+        self.define_vars(&names);
     }
 
-    fn eval_code_block(&mut self, stmts: Vec<Stmt>) -> (Value, Signal) {
-        let scope = Scope::with_rc(self.scope.clone());
-        let mut interpreter = Interpreter::new(Some(scope));
-        interpreter.eval(stmts)
+    /// Creates the hoisted `var` bindings that don't exist yet, as undefined.
+    fn define_vars(&mut self, names: &[Rc<str>]) {
+        let mut scope = self.scope.borrow_mut();
+
+        for name in names {
+            if !scope.contains_key_local(name) {
+                scope.define(name.clone(), Value::None);
+            }
+        }
     }
 
-    fn eval_let(&mut self, name: String, value: Expr) {
-        let (value, _) = self.eval_expr_with_signal(value);
-        match &mut self.scope {
-            Some(scope) => {
-                if scope.borrow().contains_key_local(&name) {
-                    panic!("Variable already defined: {}", name);
+    pub(crate) fn collect_var_names(stmt: &Stmt, names: &mut Vec<Rc<str>>) {
+        match stmt {
+            Stmt::Declaration(DeclarationKind::Var, declarations) => {
+                for (pattern, _) in declarations {
+                    names.extend(pattern.bound_names());
                 }
-                
-                scope.borrow_mut().define(name, value);
             },
-            None => (),
-        }
-    }
+            Stmt::ForOf(ForBinding::Declaration(DeclarationKind::Var, pattern), _, body)
+            | Stmt::ForIn(ForBinding::Declaration(DeclarationKind::Var, pattern), _, body)
+            | Stmt::ForAwaitOf(ForBinding::Declaration(DeclarationKind::Var, pattern), _, body) => {
+                names.extend(pattern.bound_names());
+                Self::collect_var_names(body, names);
+            },
+            Stmt::ForOf(_, _, body)
+            | Stmt::ForIn(_, _, body)
+            | Stmt::ForAwaitOf(_, _, body)
+            | Stmt::Loop(body)
+            | Stmt::While(_, body) => {
+                Self::collect_var_names(body, names);
+            },
+            Stmt::ForLoop(init, _, _, body) => {
+                Self::collect_var_names(init, names);
+                Self::collect_var_names(body, names);
+            },
+            Stmt::ControlFlow(_, consequent, alternate) => {
+                Self::collect_var_names(consequent, names);
+                Self::collect_var_names(alternate, names);
+            },
+            Stmt::CodeBlock(stmts) => {
+                for stmt in stmts {
+                    Self::collect_var_names(stmt, names);
+                }
+            },
+            Stmt::Try(block, handler, finalizer) => {
+                Self::collect_var_names(block, names);
 
-    fn eval_let_function (&mut self, name: String, args: Vec<String>, value: Box<Stmt>) {
-        match &mut self.scope {
-            Some(scope) => {
-                if scope.borrow().contains_key_local(&name) {
-                    panic!("Variable already defined: {}", name);
+                if let Some((_, body)) = handler {
+                    Self::collect_var_names(body, names);
+                }
+
+                if let Some(finalizer) = finalizer {
+                    Self::collect_var_names(finalizer, names);
                 }
-                
-                scope.borrow_mut().define(name, Value::FunctionDef(args, value));
             },
-            None => (),
+            _ => (),
         }
     }
 
-    fn eval_function_call (&mut self, name: String, args: Vec<Expr>) -> (Value, Signal) {
-        let (value, _) = self.eval_expr_with_signal(Expr::Identifier(name));
+    /// Runs `f` with `scope` as the current scope, restoring the previous one afterwards.
+    fn with_scope<T>(&mut self, scope: Rc<RefCell<Scope>>, f: impl FnOnce(&mut Self) -> T) -> T {
+        let previous = std::mem::replace(&mut self.scope, scope);
+        let result = f(self);
+        self.scope = previous;
+        result
+    }
 
-        match value {
-            Value::FunctionDef(params, body) => {
-                let mut stmts = Vec::new();
+    fn eval_code_block(&mut self, stmts: &[Stmt]) -> EvalResult {
+        // blocks that declare nothing can share the enclosing scope
+        let declares = |stmt: &Stmt| matches!(stmt, Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, _) | Stmt::Function(_));
 
-                let mut i = 0;
+        if !stmts.iter().any(declares) {
+            return self.eval_statements(stmts);
+        }
 
-                for param in &params {
-                    stmts.push(Stmt::Let(param.to_string(), args.get(i).unwrap().clone() ));
-                    i += 1;
-                };
+        let scope = self.allocate_scope(Scope::new(Some(self.scope.clone())));
+        self.with_scope(scope, |interpreter| interpreter.eval_statements(stmts))
+    }
+
+    fn eval_declaration(&mut self, kind: DeclarationKind, declarations: &[(Pattern, Option<Expr>)]) -> EvalResult<()> {
+        for (pattern, value) in declarations {
+            let value = match (value, pattern) {
+                (Some(expr), Pattern::Identifier(name)) => self.eval_named(expr, name)?,
+                (Some(expr), _) => self.eval_expr(expr)?,
+                // `var x;` leaves the hoisted binding as it is
+                (None, _) if kind == DeclarationKind::Var => continue,
+                (None, _) => Value::None,
+            };
+
+            self.bind_pattern(pattern, value, Some(kind))?;
+        }
+
+        Ok(())
+    }
 
-                match *body {
-                    Stmt::CodeBlock(function_body) => {
-                        for stmt in function_body {
-                            stmts.push(stmt);
-                        }
-                    },
-                    _ => panic!("Expected function body")
+    /// Creates a `let` or `const` binding in the current scope.
+    fn declare(&mut self, kind: DeclarationKind, name: &str, value: Value) -> EvalResult<()> {
+        if self.scope.borrow().contains_key_local(name) {
+            return Err(self.throw_error(ErrorKind::SyntaxError, format!("Identifier '{}' has already been declared", name)));
+        }
+
+        match kind {
+            DeclarationKind::Let => self.scope.borrow_mut().define(name.to_string(), value),
+            DeclarationKind::Const => self.scope.borrow_mut().define_constant(name.to_string(), value),
+            DeclarationKind::Var => self.scope.borrow_mut().define(name.to_string(), value),
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates an initializer, naming anonymous functions after the binding they are assigned to.
+    fn eval_named(&mut self, expr: &Expr, name: &str) -> EvalResult {
+        match expr {
+            Expr::Function(def) if def.name.is_none() => {
+                let function = self.create_function(def.clone());
+
+                if let Value::Object(object) = &function {
+                    object.borrow_mut().properties.insert("name", Property::readonly(Value::StringLiteral(name.into())));
                 }
 
-                self.eval_code_block(stmts)
-            },
-            _ => panic!("Expected function body")
+                Ok(function)
+            }
+            _ => self.eval_expr(expr),
         }
     }
 
-    fn eval_assignment(&mut self, name: String, value: Expr) {
-        let (value, _) = self.eval_expr_with_signal(value);
+    pub fn create_function(&mut self, def: Rc<FunctionDef>) -> Value {
+        let name = def.name.clone().unwrap_or_default();
+        let length = def.length();
+        let (is_generator, is_async) = (def.is_generator, def.is_async);
+        let is_constructor = !is_async && !def.is_arrow && !is_generator;
+
+        let function = self.allocate_object(Object::new(
+            Some(self.intrinsics.function_prototype.clone()),
+            ObjectKind::Function(Closure { def, scope: self.scope.clone() }),
+        ));
 
-        match &mut self.scope {
-            Some(scope) => {
-                scope.borrow_mut().assign(name, value);
+        // generator functions aren't constructors, their prototype is the one of the generators they
+        // create. Arrows and async functions have none at all
+        let prototype = match (is_generator, is_async, is_constructor) {
+            (true, false, _) => Some(self.allocate_object(Object::new(Some(self.intrinsics.generator_prototype.clone()), ObjectKind::Ordinary))),
+            (true, true, _) => Some(self.allocate_object(Object::new(Some(self.intrinsics.async_generator_prototype.clone()), ObjectKind::Ordinary))),
+            (false, _, true) => {
+                let prototype = self.allocate_object(Object::new(Some(self.intrinsics.object_prototype.clone()), ObjectKind::Ordinary));
+                prototype.set_hidden("constructor", Value::Object(function.clone()));
+                Some(prototype)
             },
-            None => (),
+            (false, _, false) => None,
+        };
+
+        {
+            let mut object = function.borrow_mut();
+            object.properties.insert("name", Property::readonly(Value::StringLiteral(name.into())));
+            object.properties.insert("length", Property::readonly(Value::Float(length as f64)));
+
+            if let Some(prototype) = prototype {
+                object.properties.insert(
+                    "prototype",
+                    Property { configurable: false, ..Property::hidden(Value::Object(prototype)) },
+                );
+            }
         }
+
+        Value::Object(function)
+    }
+
+    /// Creates the function of a function expression, which with a name is defined in a scope
+    /// of its own that binds the name to it.
+    pub fn create_function_expression(&mut self, def: Rc<FunctionDef>) -> Value {
+        let Some(name) = def.name.clone().filter(|_| def.binds_name) else {
+            return self.create_function(def);
+        };
+
+        let scope = self.allocate_scope(Scope::new(Some(self.scope.clone())));
+        let function = self.with_scope(scope.clone(), |interpreter| interpreter.create_function(def));
+        scope.borrow_mut().define_constant(name, function.clone());
+        function
     }
 
-    fn eval_log(&mut self, expr: Expr) {
-        let (value, _) = self.eval_expr_with_signal(expr);
-        println!("{:#?}", value);
+    pub fn create_native_function(&mut self, name: &str, length: usize, function: NativeFunction) -> Value {
+        let function = crate::builtins::make_native_function(&self.intrinsics.function_prototype, name, length, function);
+        Value::Object(function)
     }
 
-    fn eval_if(&mut self, condition: Box<Expr>, stmts: Box<Stmt>, else_stmt: Box<Stmt>) -> (Value, Signal) {
-        let (result, _) = self.eval_expr_with_signal(*condition);
-        
-        let result_coerced = match result {
-            Value::Boolean(value) => value,
-            Value::Float(value) => value != 0.0,
-            Value::StringLiteral(value) => value.len() != 0,
-            Value::FunctionDef(_, _) => true,
-            Value::None => false,
-            _ => panic!("Expected a boolean expression"),
+    pub fn create_object(&mut self) -> ObjectRef {
+        self.allocate_object(Object::new(Some(self.intrinsics.object_prototype.clone()), ObjectKind::Ordinary))
+    }
+
+    pub fn create_array(&mut self, elements: Vec<Value>) -> Value {
+        Value::Object(self.allocate_object(Object::new(Some(self.intrinsics.array_prototype.clone()), ObjectKind::Array(elements))))
+    }
+
+    /// Creates a `RegExp` object matching with `regex`, with its `lastIndex` at zero.
+    pub fn create_regexp(&mut self, regex: Rc<Regex>) -> Value {
+        let object = self.allocate_object(Object::new(Some(self.intrinsics.regexp_prototype.clone()), ObjectKind::RegExp(regex)));
+        object.borrow_mut().properties.insert("lastIndex", Property { enumerable: false, configurable: false, ..Property::new(Value::Float(0.0)) });
+        Value::Object(object)
+    }
+
+    /// Evaluates an object literal, defining its members in source order.
+    fn eval_object_literal(&mut self, members: &[ObjectMember]) -> EvalResult {
+        self.reserve_object()?;
+        self.reserve(members.len() * std::mem::size_of::<(PropertyKey, Property)>())?;
+        let object = self.create_object();
+
+        for member in members {
+            match member {
+                ObjectMember::Property(name, value) => {
+                    let key = self.eval_property_name(name)?;
+
+                    let value = match key.as_str() {
+                        Some(name) => self.eval_named(value, name)?,
+                        None => self.eval_expr(value)?,
+                    };
+
+                    object.borrow_mut().define_own_property(key, Property::new(value));
+                },
+                ObjectMember::Getter(name, def) | ObjectMember::Setter(name, def) => {
+                    let key = self.eval_property_name(name)?;
+                    let function = self.create_function(def.clone());
+
+                    // a getter and a setter for the same key combine into one accessor
+                    let (mut getter, mut setter) = match object.borrow().properties.get(&key) {
+                        Some(existing) => (existing.getter.clone(), existing.setter.clone()),
+                        None => (None, None),
+                    };
+
+                    match member {
+                        ObjectMember::Getter(_, _) => getter = Some(function),
+                        _ => setter = Some(function),
+                    }
+
+                    object.borrow_mut().define_own_property(key, Property::accessor(getter, setter, true));
+                },
+                ObjectMember::Spread(source) => {
+                    let source = self.eval_expr(source)?;
+                    self.copy_data_properties(&object, &source, &[])?;
+                },
+            }
+        }
+
+        Ok(Value::Object(object))
+    }
+
+    /// Copies the own enumerable properties of `source` onto `target`, skipping `excluded` keys,
+    /// as object spread and rest do.
+    fn copy_data_properties(&mut self, target: &ObjectRef, source: &Value, excluded: &[PropertyKey]) -> EvalResult<()> {
+        let keys = match source {
+            Value::Object(source) => source.borrow().own_keys(),
+            Value::StringLiteral(string) => (0..string.utf16_len()).map(PropertyKey::from).collect(),
+            _ => Vec::new(),
         };
 
-        match result_coerced {
-            true => {
-                match *stmts {
-                    Stmt::CodeBlock(code_block_stmts) => {
-                        self.eval_code_block(code_block_stmts)
-                    },
-                    _ => panic!("Expected a code block"),
-                }
-            },
-            false => {
-                match *else_stmt {
-                    Stmt::ControlFlow(condition, stmts, nested_else_stmt) => {
-                        self.eval_if(condition, stmts, nested_else_stmt)
-                    },
-                    _ => (Value::None, Signal::None),
-                }
+        for key in keys.into_iter().filter(|key| !excluded.contains(key)) {
+            let enumerable = match source {
+                Value::Object(source) => source.borrow().get_own_property(&key).is_some_and(|property| property.enumerable),
+                _ => true,
+            };
+
+            if enumerable {
+                let value = self.get_property(source, &key)?;
+                target.borrow_mut().define_own_property(key, Property::new(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn eval_property_name(&mut self, name: &PropertyName) -> EvalResult<PropertyKey> {
+        match name {
+            PropertyName::Literal(name) => Ok(PropertyKey::from(name)),
+            PropertyName::Computed(expr) => {
+                let key = self.eval_expr(expr)?;
+                self.to_property_key(&key)
             },
-            _ => panic!("Expected a boolean expression"),
         }
     }
 
-    fn eval_loop(&mut self, stmts: Box<Stmt>) {
-        let code_block = match *stmts {
-            Stmt::CodeBlock(code_block_stmts) => code_block_stmts,
-            _ => panic!("Expected a code block"),
+    pub fn call_function(&mut self, function: &Value, this: Value, args: &[Value]) -> EvalResult {
+        enum Callee {
+            Closure(Closure),
+            Native(NativeFunction),
+            None,
+        }
+
+        let callee = match function {
+            Value::Object(object) => match &object.borrow().kind {
+                ObjectKind::Function(closure) => Callee::Closure(closure.clone()),
+                ObjectKind::NativeFunction(native) => Callee::Native(native.clone()),
+                _ => Callee::None,
+            },
+            _ => Callee::None,
         };
 
-        loop {
-            let scope = Scope::with_rc(self.scope.clone());
-            let mut interpreter = Interpreter::new(Some(scope));
-            let (_, signal) = interpreter.eval(code_block.clone());
+        self.enter_call()?;
 
-            match signal {
-                Signal::Break => break,
-                Signal::Return => break,
-                _ => (),
-            }
+        // a script call takes many Rust frames, so the stack grows on the heap instead of
+        // overflowing before the call depth limit is reached
+        let result = stacker::maybe_grow(STACK_RED_ZONE, STACK_GROWTH, || match callee {
+            Callee::Closure(closure) if closure.def.is_async && closure.def.is_generator => self.start_async_generator(function, &closure, this, args),
+            Callee::Closure(closure) if closure.def.is_async => self.start_async_function(&closure, this, args),
+            Callee::Closure(closure) if closure.def.generator.is_some() => self.start_generator(function, &closure, this, args),
+            Callee::Closure(closure) => self.call_closure(&closure, this, args),
+            Callee::Native(native) => {
+                let new_target = self.new_target.take();
+                let result = native(self, &this, args);
+                self.new_target = new_target;
+                result
+            },
+            Callee::None => {
+                let message = format!("{} is not a function", function.to_display_string());
+                Err(self.throw_error(ErrorKind::TypeError, message))
+            },
+        });
+
+        self.exit_call();
+        result
+    }
+
+    /// The scope a call runs in. Arrow functions don't bind `this`, they see the one around them.
+    fn function_scope(&mut self, closure: &Closure, this: Value) -> Rc<RefCell<Scope>> {
+        let scope = match closure.def.is_arrow {
+            true => Scope::new(Some(closure.scope.clone())),
+            false => Scope::new_function(Some(closure.scope.clone()), this),
         };
+
+        self.allocate_scope(scope)
+    }
+
+    fn call_closure(&mut self, closure: &Closure, this: Value, args: &[Value]) -> EvalResult {
+        if self.engine == Engine::Bytecode {
+            return self.run_closure(closure, this, args);
+        }
+
+        let mut result = self.run_body(closure, this, args);
+
+        // a tail call runs once the function that made it has returned, in the same call
+        while let Err(Signal::Return(_)) = result {
+            let Some((closure, this, args)) = self.tail_call.take() else { break };
+            result = self.run_body(&closure, this, &args);
+        }
+
+        match result {
+            Ok(_) | Err(Signal::Break | Signal::Continue) => Ok(Value::None),
+            Err(Signal::Return(value)) => Ok(value),
+            Err(signal) => Err(signal),
+        }
+    }
+
+    fn run_body(&mut self, closure: &Closure, this: Value, args: &[Value]) -> EvalResult {
+        let scope = self.function_scope(closure, this);
+
+        self.call_stack.push(closure.def.name.clone().unwrap_or_else(|| "<anonymous>".into()));
+
+        let result = self.with_scope(scope, |interpreter| {
+            if closure.def.uses_arguments {
+                let arguments = interpreter.create_arguments_object(args);
+                interpreter.scope.borrow_mut().define("arguments".to_string(), arguments);
+            }
+
+            interpreter.bind_parameters(&closure.def, args)?;
+            interpreter.eval(&closure.def.body)
+        });
+
+        self.call_stack.pop();
+        result
+    }
+
+    /// Creates the `arguments` object of a call, an array-like copy of the arguments.
+    fn create_arguments_object(&mut self, args: &[Value]) -> Value {
+        let object = self.allocate_object(Object::new(Some(self.intrinsics.object_prototype.clone()), ObjectKind::Arguments));
+        let symbol = self.intrinsics.symbol_iterator.clone();
+        let values = self.intrinsics.array_prototype.get(symbol.clone()).unwrap_or(Value::None);
+
+        {
+            let mut borrowed = object.borrow_mut();
+
+            for (i, arg) in args.iter().enumerate() {
+                borrowed.properties.insert(i, Property::new(arg.clone()));
+            }
+
+            borrowed.properties.insert("length", Property::hidden(Value::Float(args.len() as f64)));
+            borrowed.properties.insert(symbol, Property::hidden(values));
+        }
+
+        Value::Object(object)
     }
 
-    fn eval_while(&mut self, condition: Box<Expr>, stmts: Box<Stmt>) {
-        let mut code_block = match *stmts {
-            Stmt::CodeBlock(code_block_stmts) => code_block_stmts,
-            _ => panic!("Expected a code block"),
+    pub fn construct(&mut self, constructor: &Value, args: &[Value]) -> EvalResult {
+        // only plain functions can be constructed, not generators, async functions or arrows
+        let not_constructor = match constructor {
+            Value::Object(object) => match &object.borrow().kind {
+                ObjectKind::Function(closure) if closure.def.generator.is_some() || closure.def.is_arrow => {
+                    Some(closure.def.name.clone().unwrap_or_else(|| "anonymous".into()))
+                },
+                _ => None,
+            },
+            _ => None,
         };
 
-        code_block.insert(0, Stmt::ControlFlow(
-            Box::new(Expr::LogicalNot(Box::new(*condition))),
-            Box::new(
-                Stmt::CodeBlock(
-                    vec![Stmt::Break]
-                )
-            ),
-            Box::new(Stmt::None),
-        ));
+        if let Some(name) = not_constructor {
+            return Err(self.throw_error(ErrorKind::TypeError, format!("{} is not a constructor", name)));
+        }
+
+        let prototype = match self.get_property(constructor, "prototype")? {
+            Value::Object(prototype) => prototype,
+            _ => self.intrinsics.object_prototype.clone(),
+        };
+
+        self.reserve_object()?;
+        let this = Value::Object(self.allocate_object(Object::new(Some(prototype), ObjectKind::Ordinary)));
+
+        let native = match constructor {
+            Value::Object(object) => match &object.borrow().kind {
+                ObjectKind::NativeFunction(native) => Some(native.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let result = match native {
+            Some(native) => {
+                let previous = self.new_target.replace(constructor.clone());
+                let result = native(self, &this, args);
+                self.new_target = previous;
+                result
+            },
+            None => self.call_function(constructor, this.clone(), args),
+        };
+
+        match result? {
+            value @ Value::Object(_) => Ok(value),
+            _ => Ok(this),
+        }
+    }
+
+    fn eval_assignment(&mut self, target: &Expr, value: &Expr) -> EvalResult {
+        let reference = self.eval_reference(target)?;
+
+        let value = match &reference {
+            Reference::Binding(name) => self.eval_named(value, name)?,
+            Reference::Property(_, _) => self.eval_expr(value)?,
+        };
 
-        self.eval_code_block(vec![
-            Stmt::Loop(Box::new(
-                Stmt::CodeBlock(code_block)
-            ))
-        ]);
+        self.put_reference(&reference, value.clone())?;
+        Ok(value)
     }
 
-    fn eval_for_loop(&mut self, init: Box<Stmt>, condition: Box<Stmt>, increment: Box<Stmt>, stmts: Box<Stmt>) {
-        let mut code_block = match *stmts {
-            Stmt::CodeBlock(code_block_stmts) => code_block_stmts,
-            _ => panic!("Expected a code block"),
+    fn eval_compound_assignment(&mut self, operator: &Token, target: &Expr, value: &Expr) -> EvalResult {
+        let reference = self.eval_reference(target)?;
+        let current = self.get_reference(&reference)?;
+
+        let short_circuits = match operator {
+            Token::LogicalAnd => Some(!current.is_truthy()),
+            Token::LogicalOr => Some(current.is_truthy()),
+            Token::NullishCoalescing => Some(!current.is_nullish()),
+            _ => None,
         };
 
-        let condition_expr = match *condition {
-            Stmt::Expression(expr) => expr,
-            _ => panic!("Expected an expression"),
+        let result = match short_circuits {
+            Some(true) => return Ok(current),
+            Some(false) => self.eval_expr(value)?,
+            None => {
+                let value = self.eval_expr(value)?;
+                self.apply_operator(operator, current, value)?
+            },
         };
 
-        code_block.insert(0, Stmt::ControlFlow(
-            Box::new(Expr::LogicalNot(Box::new(*condition_expr))),
-            Box::new(
-                Stmt::CodeBlock(
-                    vec![Stmt::Break]
-                )
-            ),
-            Box::new(Stmt::None),
-        ));
+        self.put_reference(&reference, result.clone())?;
+        Ok(result)
+    }
+
+    fn eval_update(&mut self, target: &Expr, delta: f64, prefix: bool) -> EvalResult {
+        let reference = self.eval_reference(target)?;
+        let current = self.get_reference(&reference)?;
+        let old_value = self.to_number(&current)?;
+        let new_value = old_value + delta;
 
-        code_block.push(*increment);
+        self.put_reference(&reference, Value::Float(new_value))?;
 
-        self.eval_code_block(vec![
-            *init,
-            Stmt::Loop(Box::new(
-                Stmt::CodeBlock(code_block)
-            ))
-        ]);
+        match prefix {
+            true => Ok(Value::Float(new_value)),
+            false => Ok(Value::Float(old_value)),
+        }
     }
 
-    fn eval_expr_with_signal (&mut self, expr: Expr) -> (Value, Signal) {
-        match expr {
-            Expr::FunctionCall(args, value) => self.eval_function_call(args, value),
-            _ => (self.eval_expr(expr), Signal::None),
+    fn eval_reference(&mut self, target: &Expr) -> EvalResult<Reference> {
+        match target {
+            Expr::Identifier(name) => Ok(Reference::Binding(name.clone())),
+            Expr::Member(object, name) => {
+                let object = self.eval_expr(object)?;
+                Ok(Reference::Property(object, PropertyKey::from(name)))
+            },
+            Expr::ComputedMember(object, key) => {
+                let object = self.eval_expr(object)?;
+                let key = self.eval_expr(key)?;
+                let key = self.to_property_key(&key)?;
+                Ok(Reference::Property(object, key))
+            },
+            _ => Err(self.throw_error(ErrorKind::SyntaxError, "Invalid left-hand side in assignment")),
         }
     }
 
-    fn eval_expr(&mut self, expr: Expr) -> Value {
-        match expr {
-            Expr::Identifier(name) => {
-                match &mut self.scope {
-                    Some(scope) => {
-                        match scope.borrow().get(&name) {
-                            Some(value) => value.clone(),
-                            None => panic!("Variable not found: {}", name),
-                        }
-                    },
-                    None => Value::Boolean(false),
-                }
+    fn get_reference(&mut self, reference: &Reference) -> EvalResult {
+        match reference {
+            Reference::Binding(name) => self.lookup(name),
+            Reference::Property(object, key) => self.get_property(object, key),
+        }
+    }
+
+    fn put_reference(&mut self, reference: &Reference, value: Value) -> EvalResult<()> {
+        match reference {
+            Reference::Binding(name) => self.assign_binding(name, value),
+            Reference::Property(object, key) => self.set_property(object, key, value),
+        }
+    }
+
+    fn assign_binding(&mut self, name: &str, value: Value) -> EvalResult<()> {
+        let assignment = self.scope.borrow_mut().assign(name, value);
+
+        match assignment {
+            Assignment::Assigned => Ok(()),
+            Assignment::Constant => Err(self.throw_error(ErrorKind::TypeError, "Assignment to constant variable.")),
+            Assignment::Undeclared => Err(self.throw_error(ErrorKind::ReferenceError, format!("{} is not defined", name))),
+        }
+    }
+
+    fn lookup(&mut self, name: &str) -> EvalResult {
+        let value = self.scope.borrow().get(name);
+
+        match value {
+            Some(value) => Ok(value),
+            None => Err(self.throw_error(ErrorKind::ReferenceError, format!("{} is not defined", name))),
+        }
+    }
+
+    /// The object whose properties a value exposes: itself for objects, the matching prototype
+    /// for primitives.
+    fn property_holder(&mut self, target: &Value, key: &PropertyKey) -> EvalResult<ObjectRef> {
+        match target {
+            Value::Object(object) => Ok(object.clone()),
+            Value::StringLiteral(_) => Ok(self.intrinsics.string_prototype.clone()),
+            Value::Symbol(_) => Ok(self.intrinsics.symbol_prototype.clone()),
+            Value::Null | Value::None => {
+                let message = format!("Cannot read properties of {} (reading '{}')", target.to_display_string(), key);
+                Err(self.throw_error(ErrorKind::TypeError, message))
             },
-            Expr::Float(num) => Value::Float(num),
-            Expr::StringLiteral(literal) => Value::StringLiteral(literal),
-            Expr::Boolean(bool) => Value::Boolean(bool),
-            Expr::Equals(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match left {
-                    Value::Float(left) => {
-                        let corced_right = match right {
-                            Value::Float(right) => Some(right),
-                            Value::StringLiteral(right) => right.parse::<f64>().ok(),
-                            Value::Boolean(right) => Some(right as i32 as f64),
-                            _ => panic!("Expected a number or string"),
-                        };
-
-                        match corced_right {
-                            Some(right) => Value::Boolean(left == right),
-                            None => Value::Boolean(false),
-                        }
-                    },
-                    Value::StringLiteral(left) => {
-                        let corced_right = match right {
-                            Value::Float(right) => Some(right.to_string()),
-                            Value::StringLiteral(right) => Some(right),
-                            Value::Boolean(right) => Some(right.to_string()),
-                            _ => panic!("Expected a number or string"),
-                        };
-
-                        Value::Boolean(left == corced_right.unwrap())
-                    }
-                    Value::Boolean(left) => {
-                        let corced_right = match right {
-                            Value::Float(right) => Some(right as i32 != 0),
-                            Value::StringLiteral(right) => {
-                                let value = right.parse::<i32>().ok();
-
-                                match value {
-                                    Some(value) => Some(value != 0),
-                                    None => Some(false),
-                                }
-                            }
-                            Value::Boolean(right) => Some(right),
-                            _ => panic!("Expected a number or string"),
-                        };
-
-                        Value::Boolean(left == corced_right.unwrap())
-                    },
-                    _ => panic!("Expected a number or string"),
+            _ => Ok(self.intrinsics.object_prototype.clone()),
+        }
+    }
+
+    pub fn get_property(&mut self, target: &Value, key: impl Into<PropertyKey>) -> EvalResult {
+        let key = key.into();
+
+        if let Value::StringLiteral(string) = target {
+            if key.as_str() == Some("length") {
+                return Ok(Value::Float(string.utf16_len() as f64));
+            }
+
+            // indexes are of code units, so the halves of a pair come out separately
+            if let Some(index) = key.as_array_index() {
+                if index < string.utf16_len() {
+                    return Ok(Value::StringLiteral(string.substring(index, index + 1)));
                 }
             }
-            Expr::TypeCheckEquals(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Boolean(left == right),
-                    (Value::StringLiteral(left), Value::StringLiteral(right)) => Value::Boolean(left == right),
-                    (Value::Boolean(left), Value::Boolean(right)) => Value::Boolean(left == right),
-                    _ => Value::Boolean(false),
+        }
+
+        let object = self.property_holder(target, &key)?;
+
+        match object.find_property(&key) {
+            Some(Property { getter: Some(getter), .. }) => self.call_function(&getter, target.clone(), &[]),
+            Some(property) => Ok(property.value),
+            None => Ok(Value::None),
+        }
+    }
+
+    pub fn set_property(&mut self, target: &Value, key: impl Into<PropertyKey>, mut value: Value) -> EvalResult<()> {
+        let key = key.into();
+
+        let object = match target {
+            Value::Object(object) => object,
+            Value::Null | Value::None => {
+                let message = format!("Cannot set properties of {} (setting '{}')", target.to_display_string(), key);
+                return Err(self.throw_error(ErrorKind::TypeError, message));
+            },
+            // writes to primitives are silently dropped
+            _ => return Ok(()),
+        };
+
+        // an array can only be given a length that's a whole number in the range of indices
+        if object.is_array() && key.as_str() == Some("length") {
+            let length = self.to_number(&value)?;
+
+            if self.to_uint32(&Value::Float(length))? as f64 != length {
+                return Err(self.throw_error(ErrorKind::RangeError, "Invalid array length"));
+            }
+
+            value = Value::Float(length);
+        }
+
+        let existing = object.find_property(&key);
+
+        match existing {
+            Some(property) if property.is_accessor() => {
+                if let Some(setter) = property.setter {
+                    self.call_function(&setter, target.clone(), &[value])?;
+                }
+            },
+            Some(property) if !property.writable => (),
+            _ => {
+                let own = object.borrow().get_own_property(&key);
+                let growth = object.borrow().elements_growth(&key, &value);
+
+                if own.is_none() {
+                    self.reserve(property_size(&key, &Property::new(value.clone())))?;
                 }
+
+                self.reserve(growth.saturating_mul(std::mem::size_of::<Value>()))?;
+
+                let mut object = object.borrow_mut();
+
+                match own {
+                    Some(property) => object.define_own_property(key, Property { value, ..property }),
+                    None if object.extensible => object.define_own_property(key, Property::new(value)),
+                    None => (),
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn delete_property(&mut self, target: &Value, key: &PropertyKey) -> EvalResult {
+        match target {
+            Value::Object(object) => Ok(Value::Boolean(object.borrow_mut().delete_own_property(key))),
+            Value::Null | Value::None => {
+                let message = format!("Cannot convert {} to object", target.to_display_string());
+                Err(self.throw_error(ErrorKind::TypeError, message))
+            },
+            _ => Ok(Value::Boolean(true)),
+        }
+    }
+
+    fn eval_if(&mut self, condition: &Expr, stmts: &Stmt, else_stmt: &Stmt) -> EvalResult {
+        let result = self.eval_expr(condition)?;
+
+        match result.is_truthy() {
+            true => self.eval_stmt(stmts),
+            false => self.eval_stmt(else_stmt),
+        }
+    }
+
+    /// Runs one iteration of a loop body, returning `false` when the loop should stop.
+    fn eval_loop_body(&mut self, stmts: &Stmt) -> EvalResult<bool> {
+        match self.eval_stmt(stmts) {
+            Ok(_) | Err(Signal::Continue) => Ok(true),
+            Err(Signal::Break) => Ok(false),
+            Err(signal) => Err(signal),
+        }
+    }
+
+    fn eval_loop(&mut self, stmts: &Stmt) -> EvalResult {
+        while self.eval_loop_body(stmts)? {}
+
+        Ok(Value::None)
+    }
+
+    fn eval_while(&mut self, condition: &Expr, stmts: &Stmt) -> EvalResult {
+        while self.eval_expr(condition)?.is_truthy() {
+            if !self.eval_loop_body(stmts)? {
+                break;
             }
-            Expr::NotEquals(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match left {
-                    Value::Float(left) => {
-                        let corced_right = match right {
-                            Value::Float(right) => Some(right),
-                            Value::StringLiteral(right) => right.parse::<f64>().ok(),
-                            Value::Boolean(right) => Some(right as i32 as f64),
-                            _ => panic!("Expected a number or string"),
-                        };
-
-                        match corced_right {
-                            Some(right) => Value::Boolean(left != right),
-                            None => Value::Boolean(false),
-                        }
-                    },
-                    Value::StringLiteral(left) => {
-                        let corced_right = match right {
-                            Value::Float(right) => Some(right.to_string()),
-                            Value::StringLiteral(right) => Some(right),
-                            Value::Boolean(right) => Some(right.to_string()),
-                            _ => panic!("Expected a number or string"),
-                        };
-
-                        Value::Boolean(left != corced_right.unwrap())
-                    }
-                    Value::Boolean(left) => {
-                        let corced_right = match right {
-                            Value::Float(right) => Some(right as i32 == 0),
-                            Value::StringLiteral(right) => {
-                                let value = right.parse::<i32>().ok();
-
-                                match value {
-                                    Some(value) => Some(value == 0),
-                                    None => Some(false),
-                                }
-                            }
-                            Value::Boolean(right) => Some(right),
-                            _ => panic!("Expected a number or string"),
-                        };
-
-                        Value::Boolean(left != corced_right.unwrap())
+        }
+
+        Ok(Value::None)
+    }
+
+    fn eval_for_loop(&mut self, init: &Stmt, condition: Option<&Expr>, increment: Option<&Expr>, stmts: &Stmt) -> EvalResult {
+        let names: Vec<Rc<str>> = match init {
+            Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, declarations) => {
+                declarations.iter().flat_map(|(pattern, _)| pattern.bound_names()).collect()
+            },
+            _ => Vec::new(),
+        };
+
+        let scope = self.allocate_scope(Scope::new(Some(self.scope.clone())));
+
+        self.with_scope(scope, |interpreter| {
+            interpreter.eval_stmt(init)?;
+            interpreter.copy_iteration_scope(&names);
+
+            loop {
+                if let Some(condition) = condition {
+                    if !interpreter.eval_expr(condition)?.is_truthy() {
+                        break;
                     }
-                    _ => panic!("Expected a number or string"),
                 }
-            }
-            Expr::TypeNotEquals(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Boolean(left != right),
-                    (Value::StringLiteral(left), Value::StringLiteral(right)) => Value::Boolean(left != right),
-                    (Value::Boolean(left), Value::Boolean(right)) => Value::Boolean(left != right),
-                    _ => Value::Boolean(false),
+
+                if !interpreter.eval_loop_body(stmts)? {
+                    break;
                 }
-            }
-            Expr::Addition(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Float(left + right),
-                    (Value::Float(left), Value::StringLiteral(right)) => Value::StringLiteral(format!("{}{}", left, right)),
-                    (Value::StringLiteral(left), Value::Float(right)) => Value::StringLiteral(format!("{}{}", left, right)),
-                    (Value::StringLiteral(left), Value::StringLiteral(right)) => Value::StringLiteral(format!("{}{}", left, right)),
-                    _ => panic!("Expected a number or string"),
+
+                interpreter.copy_iteration_scope(&names);
+
+                if let Some(increment) = increment {
+                    interpreter.eval_expr(increment)?;
                 }
+            }
+
+            Ok(Value::None)
+        })
+    }
+
+    /// Gives each loop iteration its own copy of the `let` bindings declared in a for loop head,
+    /// so closures created in the body capture that iteration's values.
+    fn copy_iteration_scope(&mut self, names: &[Rc<str>]) {
+        if names.is_empty() {
+            return;
+        }
+
+        let mut scope = Scope::new(self.scope.borrow().parent());
+
+        for name in names {
+            let value = self.scope.borrow().get(name).unwrap_or(Value::None);
+
+            match self.scope.borrow().is_constant_local(name) {
+                true => scope.define_constant(name.clone(), value),
+                false => scope.define(name.clone(), value),
+            }
+        }
+
+        self.scope = self.allocate_scope(scope);
+    }
+
+    fn eval_try(&mut self, block: &Stmt, handler: Option<&(Option<Rc<str>>, Box<Stmt>)>, finalizer: Option<&Stmt>) -> EvalResult {
+        let mut result = self.eval_stmt(block);
+
+        if let (Err(Signal::Throw(exception)), Some((param, body))) = (&result, handler) {
+            let mut scope = Scope::new(Some(self.scope.clone()));
+
+            if let Some(param) = param {
+                scope.define(param.clone(), exception.clone());
+            }
+
+            let scope = self.allocate_scope(scope);
+            result = self.with_scope(scope, |interpreter| interpreter.eval_stmt(body));
+        }
+
+        if let Some(finalizer) = finalizer {
+            // an abrupt completion of the finally block overrides the outcome of try and catch
+            self.eval_stmt(finalizer)?;
+        }
+
+        result
+    }
+
+    fn eval_binary(&mut self, operator: &Token, left: &Expr, right: &Expr) -> EvalResult {
+        let left = self.eval_expr(left)?;
+        let right = self.eval_expr(right)?;
+
+        self.apply_operator(operator, left, right)
+    }
+
+    /// Evaluates the arguments of a call or the elements of an array literal, expanding spreads.
+    fn eval_arguments(&mut self, args: &[Expr]) -> EvalResult<Vec<Value>> {
+        let mut values = Vec::with_capacity(args.len());
+
+        for arg in args {
+            match arg {
+                Expr::Spread(iterable) => {
+                    let iterable = self.eval_expr(iterable)?;
+                    values.extend(self.iterate_to_vec(&iterable)?);
+                },
+                arg => values.push(self.eval_expr(arg)?),
+            }
+        }
+
+        Ok(values)
+    }
+
+    fn eval_function_call(&mut self, callee: &Expr, args: &[Expr]) -> EvalResult {
+        let (function, this) = self.eval_callee(callee)?;
+        let args = self.eval_arguments(args)?;
+
+        if !function.is_callable() {
+            return Err(self.throw_error(ErrorKind::TypeError, format!("{} is not a function", Self::describe(callee))));
+        }
+
+        self.call_function(&function, this, &args)
+    }
+
+    /// Evaluates a call in tail position. Calls of plain script functions are left for the
+    /// function returning to make, so that tail recursion doesn't get deeper.
+    fn eval_tail_call(&mut self, call: &Expr) -> EvalResult {
+        let Expr::FunctionCall(callee, args) = call else { return self.eval_expr(call) };
+
+        if self.engine != Engine::TreeWalker {
+            return self.eval_function_call(callee, args);
+        }
+
+        let (function, this) = self.eval_callee(callee)?;
+        let args = self.eval_arguments(args)?;
+
+        let closure = match &function {
+            Value::Object(object) => match &object.borrow().kind {
+                ObjectKind::Function(closure) if !closure.def.is_async && closure.def.generator.is_none() => Some(closure.clone()),
+                _ => None,
             },
-            Expr::Subtraction(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            _ => None,
+        };
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Float(left - right),
-                    _ => panic!("Expected two numbers"),
-                }
+        match closure {
+            Some(closure) => {
+                self.tail_call = Some((closure, this, args));
+                Ok(Value::None)
             },
-            Expr::Multiplication(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            None if function.is_callable() => self.call_function(&function, this, &args),
+            None => Err(self.throw_error(ErrorKind::TypeError, format!("{} is not a function", Self::describe(callee)))),
+        }
+    }
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Float(left * right),
-                    _ => panic!("Expected two numbers"),
-                }
+    /// The function a call expression calls, and the `this` it gets.
+    fn eval_callee(&mut self, callee: &Expr) -> EvalResult<(Value, Value)> {
+        let callee = match callee {
+            Expr::Member(object, name) => {
+                let object = self.eval_expr(object)?;
+                let function = self.get_property(&object, name)?;
+                (function, object)
+            },
+            Expr::ComputedMember(object, key) => {
+                let object = self.eval_expr(object)?;
+                let key = self.eval_expr(key)?;
+                let key = self.to_property_key(&key)?;
+                let function = self.get_property(&object, key)?;
+                (function, object)
             },
-            Expr::Division(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            _ => (self.eval_expr(callee)?, Value::None),
+        };
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Float(left / right),
-                    _ => panic!("Expected two numbers"),
-                }
+        Ok(callee)
+    }
+
+    fn eval_new(&mut self, callee: &Expr, args: &[Expr]) -> EvalResult {
+        let constructor = self.eval_expr(callee)?;
+        let args = self.eval_arguments(args)?;
+
+        if !constructor.is_callable() {
+            return Err(self.throw_error(ErrorKind::TypeError, format!("{} is not a constructor", Self::describe(callee))));
+        }
+
+        self.construct(&constructor, &args)
+    }
+
+    /// Source-like rendering of an expression for error messages.
+    pub(crate) fn describe(expr: &Expr) -> String {
+        match expr {
+            Expr::Identifier(name) => name.to_string(),
+            Expr::This => "this".to_string(),
+            Expr::Member(object, name) => format!("{}.{}", Self::describe(object), name),
+            Expr::ComputedMember(object, _) => format!("{}[...]", Self::describe(object)),
+            Expr::FunctionCall(callee, _) => format!("{}(...)", Self::describe(callee)),
+            Expr::TailCall(call) => Self::describe(call),
+            _ => "expression".to_string(),
+        }
+    }
+
+    fn eval_expr(&mut self, expr: &Expr) -> EvalResult {
+        match expr {
+            Expr::Identifier(name) => self.lookup(name),
+            Expr::Float(num) => Ok(Value::Float(*num)),
+            Expr::StringLiteral(literal) => Ok(Value::StringLiteral(literal.clone())),
+            Expr::RegExp(regex) => {
+                self.reserve_object()?;
+                Ok(self.create_regexp(regex.clone()))
+            },
+            Expr::Boolean(bool) => Ok(Value::Boolean(*bool)),
+            Expr::Null => Ok(Value::Null),
+            Expr::This => Ok(self.scope.borrow().this()),
+            Expr::Function(def) => {
+                self.reserve_object()?;
+                Ok(self.create_function_expression(def.clone()))
             },
-            Expr::GreaterThan(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            Expr::Array(elements) => {
+                let mut values = Vec::with_capacity(elements.len());
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Boolean(left > right),
-                    _ => panic!("Expected two numbers"),
+                for element in elements {
+                    match element {
+                        Some(Expr::Spread(iterable)) => {
+                            let iterable = self.eval_expr(iterable)?;
+                            values.extend(self.iterate_to_vec(&iterable)?);
+                        },
+                        Some(element) => values.push(self.eval_expr(element)?),
+                        None => values.push(Value::None),
+                    }
                 }
+
+                self.reserve_object()?;
+                self.reserve(values.capacity() * std::mem::size_of::<Value>())?;
+                Ok(self.create_array(values))
             },
-            Expr::GreaterThanEquals(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            Expr::Object(members) => self.eval_object_literal(members),
+            Expr::Addition(left, right) => self.eval_binary(&Token::Addition, left, right),
+            Expr::Subtraction(left, right) => self.eval_binary(&Token::Subtraction, left, right),
+            Expr::Multiplication(left, right) => self.eval_binary(&Token::Multiplication, left, right),
+            Expr::Division(left, right) => self.eval_binary(&Token::Division, left, right),
+            Expr::Modulo(left, right) => self.eval_binary(&Token::Modulo, left, right),
+            Expr::Exponentiation(left, right) => self.eval_binary(&Token::Exponentiation, left, right),
+            Expr::Equals(left, right) => self.eval_binary(&Token::Equals, left, right),
+            Expr::TypeCheckEquals(left, right) => self.eval_binary(&Token::TypeCheckEquals, left, right),
+            Expr::NotEquals(left, right) => self.eval_binary(&Token::NotEquals, left, right),
+            Expr::TypeNotEquals(left, right) => self.eval_binary(&Token::TypeNotEquals, left, right),
+            Expr::GreaterThan(left, right) => self.eval_binary(&Token::GreaterThan, left, right),
+            Expr::GreaterThanEquals(left, right) => self.eval_binary(&Token::GreaterThanEquals, left, right),
+            Expr::LessThan(left, right) => self.eval_binary(&Token::LessThan, left, right),
+            Expr::LessThanEquals(left, right) => self.eval_binary(&Token::LessThanEquals, left, right),
+            Expr::BitwiseAnd(left, right) => self.eval_binary(&Token::BitwiseAnd, left, right),
+            Expr::BitwiseOr(left, right) => self.eval_binary(&Token::BitwiseOr, left, right),
+            Expr::BitwiseXor(left, right) => self.eval_binary(&Token::BitwiseXor, left, right),
+            Expr::LeftShift(left, right) => self.eval_binary(&Token::LeftShift, left, right),
+            Expr::RightShift(left, right) => self.eval_binary(&Token::RightShift, left, right),
+            Expr::UnsignedRightShift(left, right) => self.eval_binary(&Token::UnsignedRightShift, left, right),
+            Expr::InstanceOf(left, right) => self.eval_binary(&Token::Instanceof, left, right),
+            Expr::In(left, right) => self.eval_binary(&Token::In, left, right),
+            Expr::LogicalAnd(left, right) => {
+                let left = self.eval_expr(left)?;
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Boolean(left >= right),
-                    _ => panic!("Expected two numbers"),
+                match left.is_truthy() {
+                    true => self.eval_expr(right),
+                    false => Ok(left),
                 }
             },
-            Expr::LessThan(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            Expr::LogicalOr(left, right) => {
+                let left = self.eval_expr(left)?;
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Boolean(left < right),
-                    _ => panic!("Expected two numbers"),
+                match left.is_truthy() {
+                    true => Ok(left),
+                    false => self.eval_expr(right),
                 }
             },
-            Expr::LessThanEquals(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
+            Expr::NullishCoalescing(left, right) => {
+                let left = self.eval_expr(left)?;
 
-                match (left, right) {
-                    (Value::Float(left), Value::Float(right)) => Value::Boolean(left <= right),
-                    _ => panic!("Expected two numbers"),
+                match left.is_nullish() {
+                    true => self.eval_expr(right),
+                    false => Ok(left),
                 }
-            }
-            Expr::LogicalOr(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match left {
-                    Value::Float(left) => {
-                        if left == 0.0 {
-                            return right
-                        } else {
-                            return Value::Float(left)
-                        }
-                    },
-                    Value::StringLiteral(left) => {
-                        if left.len() == 0 {
-                            return right
-                        } else {
-                            return Value::StringLiteral(left)
-                        }
-                    },
-                    Value::Boolean(left) => {
-                        if !left {
-                            return right
-                        } else {
-                            return Value::Boolean(left)
-                        }
+            },
+            Expr::LogicalNot(expr) => {
+                let value = self.eval_expr(expr)?;
+                Ok(Value::Boolean(!value.is_truthy()))
+            },
+            Expr::Negation(expr) => {
+                let value = self.eval_expr(expr)?;
+                Ok(Value::Float(-self.to_number(&value)?))
+            },
+            Expr::UnaryPlus(expr) => {
+                let value = self.eval_expr(expr)?;
+                Ok(Value::Float(self.to_number(&value)?))
+            },
+            Expr::BitwiseNot(expr) => {
+                let value = self.eval_expr(expr)?;
+                Ok(Value::Float(!self.to_int32(&value)? as f64))
+            },
+            Expr::TypeOf(expr) => {
+                // typeof is the one place an undeclared identifier is not an error
+                if let Expr::Identifier(name) = expr.as_ref() {
+                    if !self.scope.borrow().contains_key(name) {
+                        return Ok(Value::StringLiteral("undefined".into()));
                     }
-                    _ => panic!("Expected a valid logical expression")
                 }
+
+                let value = self.eval_expr(expr)?;
+                Ok(Value::StringLiteral(value.type_of().into()))
             },
-            Expr::LogicalAnd(left, right) => {
-                let (left, _) = self.eval_expr_with_signal(*left);
-                let (right, _) = self.eval_expr_with_signal(*right);
-
-                match left {
-                    Value::Float(left) => {
-                        if left != 0.0 {
-                            return right
-                        } else {
-                            return Value::Float(left)
-                        }
-                    },
-                    Value::StringLiteral(left) => {
-                        if left.len() != 0 {
-                            return right
-                        } else {
-                            return Value::StringLiteral(left)
-                        }
-                    },
-                    Value::Boolean(left) => {
-                        if left {
-                            return right
-                        } else {
-                            return Value::Boolean(left)
-                        }
-                    }
-                    _ => panic!("Expected a valid logical expression")
+            Expr::Void(expr) => {
+                self.eval_expr(expr)?;
+                Ok(Value::None)
+            },
+            Expr::Delete(expr) => match expr.as_ref() {
+                Expr::Member(_, _) | Expr::ComputedMember(_, _) => match self.eval_reference(expr)? {
+                    Reference::Property(object, key) => self.delete_property(&object, &key),
+                    Reference::Binding(_) => Ok(Value::Boolean(false)),
+                },
+                Expr::Identifier(_) => Ok(Value::Boolean(false)),
+                _ => {
+                    self.eval_expr(expr)?;
+                    Ok(Value::Boolean(true))
+                },
+            },
+            Expr::PreIncrement(target) => self.eval_update(target, 1.0, true),
+            Expr::PreDecrement(target) => self.eval_update(target, -1.0, true),
+            Expr::PostIncrement(target) => self.eval_update(target, 1.0, false),
+            Expr::PostDecrement(target) => self.eval_update(target, -1.0, false),
+            Expr::Assignment(target, value) => self.eval_assignment(target, value),
+            Expr::DestructuringAssignment(pattern, value) => {
+                let value = self.eval_expr(value)?;
+                self.bind_pattern(pattern, value.clone(), None)?;
+                Ok(value)
+            },
+            Expr::CompoundAssignment(operator, target, value) => self.eval_compound_assignment(operator, target, value),
+            Expr::Conditional(condition, consequent, alternate) => {
+                match self.eval_expr(condition)?.is_truthy() {
+                    true => self.eval_expr(consequent),
+                    false => self.eval_expr(alternate),
                 }
             },
-            Expr::LogicalNot(expr) => {
-                let (right, _) = self.eval_expr_with_signal(*expr);
-
-                match right {
-                    Value::Float(right) => {
-                        if right == 0.0 {
-                            return Value::Boolean(true)
-                        } else {
-                            return Value::Boolean(false)
-                        }
-                    },
-                    Value::StringLiteral(right) => {
-                        if right.len() == 0 {
-                            return Value::Boolean(true)
-                        } else {
-                            return Value::Boolean(false)
-                        }
-                    },
-                    Value::Boolean(right) => {
-                        if !right {
-                            return Value::Boolean(true)
-                        } else {
-                            return Value::Boolean(false)
-                        }
-                    }
-                    _ => panic!("Expected a valid logical expression")
+            Expr::Sequence(exprs) => {
+                let mut value = Value::None;
+
+                for expr in exprs {
+                    value = self.eval_expr(expr)?;
                 }
+
+                Ok(value)
             },
-            _ => {
-                panic!("Expected an expression")
+            Expr::FunctionCall(callee, args) => self.eval_function_call(callee, args),
+            Expr::TailCall(call) => self.eval_tail_call(call),
+            Expr::New(callee, args) => self.eval_new(callee, args),
+            // the parser only produces spreads where the enclosing expression expands them
+            Expr::Spread(_) => Err(self.throw_error(ErrorKind::SyntaxError, "Unexpected token '...'")),
+            // generator and async bodies are lowered, so these only remain where the parser rejects them
+            Expr::Yield(_) | Expr::YieldDelegate(_) => Err(self.throw_error(ErrorKind::SyntaxError, "Unexpected yield")),
+            Expr::Await(_) => Err(self.throw_error(ErrorKind::SyntaxError, "Unexpected await")),
+            Expr::Member(object, name) => {
+                let object = self.eval_expr(object)?;
+                self.get_property(&object, name)
+            },
+            Expr::ComputedMember(object, key) => {
+                let object = self.eval_expr(object)?;
+                let key = self.eval_expr(key)?;
+                self.get_computed(&object, key)
             },
         }
     }
+
+    /// Reads `object[key]` with the key not yet converted.
+    fn get_computed(&mut self, object: &Value, key: Value) -> EvalResult {
+        // fast path for indexing arrays with numbers, which skips building a string key
+        if let (Value::Object(array), Value::Float(index)) = (object, &key) {
+            if let ObjectKind::Array(elements) = &array.borrow().kind {
+                if index.fract() == 0.0 && *index >= 0.0 && (*index as usize) < elements.len() {
+                    return Ok(elements[*index as usize].clone());
+                }
+            }
+        }
+
+        let key = self.to_property_key(&key)?;
+        self.get_property(object, key)
+    }
+
+    /// Formats the current call stack the way `Error.prototype.stack` shows it, below `header`.
+    pub fn stack_trace(&self, header: &str) -> String {
+        let mut stack = header.to_string();
+
+        for name in self.call_stack.iter().rev() {
+            stack.push_str(&format!("\n    at {}", name));
+        }
+
+        stack.push_str("\n    at <anonymous>");
+        stack
+    }
+
+    /// Creates an error object inheriting from `prototype`, filling in `message` and `stack`.
+    pub fn create_error_object(&mut self, prototype: ObjectRef, message: Option<String>) -> ObjectRef {
+        let error = self.allocate_object(Object::new(Some(prototype), ObjectKind::Error));
+
+        if let Some(message) = &message {
+            error.set_hidden("message", Value::StringLiteral(message.clone().into()));
+        }
+
+        let name = match error.get("name") {
+            Some(Value::StringLiteral(name)) => name,
+            _ => "Error".into(),
+        };
+
+        let header = match message {
+            Some(message) if !message.is_empty() => format!("{}: {}", name, message),
+            _ => name.to_string(),
+        };
+
+        error.set_hidden("stack", Value::StringLiteral(self.stack_trace(&header).into()));
+        error
+    }
+
+    pub fn create_error(&mut self, kind: ErrorKind, message: impl Into<String>) -> Value {
+        let prototype = self.intrinsics.error_prototype(kind).clone();
+        Value::Object(self.create_error_object(prototype, Some(message.into())))
+    }
+
+    /// Builds the signal that throws a new built-in error of the given kind.
+    pub fn throw_error(&mut self, kind: ErrorKind, message: impl Into<String>) -> Signal {
+        Signal::Throw(self.create_error(kind, message))
+    }
+
+    pub fn throw_js_error(&mut self, error: JsError) -> Signal {
+        match error {
+            JsError::Native(kind, message) => self.throw_error(kind, message),
+            JsError::Thrown(value) => Signal::Throw(value),
+            JsError::Terminated(termination) => self.terminate(termination),
+        }
+    }
 }
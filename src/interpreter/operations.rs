@@ -0,0 +1,258 @@
+use crate::error::ErrorKind;
+use crate::object::PropertyKey;
+use crate::types::{exponentiate, string_to_number, JsString, Token, Value};
+use super::{EvalResult, Interpreter};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PreferredType {
+    Default,
+    Number,
+    String,
+}
+
+impl Interpreter {
+    pub fn to_primitive(&mut self, value: &Value, hint: PreferredType) -> EvalResult {
+        if !matches!(value, Value::Object(_)) {
+            return Ok(value.clone());
+        }
+
+        let methods = match hint {
+            PreferredType::String => ["toString", "valueOf"],
+            _ => ["valueOf", "toString"],
+        };
+
+        for method in methods {
+            let function = self.get_property(value, method)?;
+
+            if function.is_callable() {
+                let result = self.call_function(&function, value.clone(), &[])?;
+
+                if !matches!(result, Value::Object(_)) {
+                    return Ok(result);
+                }
+            }
+        }
+
+        Err(self.throw_error(ErrorKind::TypeError, "Cannot convert object to primitive value"))
+    }
+
+    pub fn to_number(&mut self, value: &Value) -> EvalResult<f64> {
+        match value {
+            Value::Float(value) => Ok(*value),
+            Value::StringLiteral(value) => Ok(string_to_number(value)),
+            Value::Boolean(value) => Ok(if *value { 1.0 } else { 0.0 }),
+            Value::Null => Ok(0.0),
+            Value::None => Ok(f64::NAN),
+            Value::Symbol(_) => Err(self.throw_error(ErrorKind::TypeError, "Cannot convert a Symbol value to a number")),
+            Value::Object(_) => {
+                let primitive = self.to_primitive(value, PreferredType::Number)?;
+                self.to_number(&primitive)
+            },
+        }
+    }
+
+    pub fn to_int32(&mut self, value: &Value) -> EvalResult<i32> {
+        Ok(self.to_uint32(value)? as i32)
+    }
+
+    pub fn to_uint32(&mut self, value: &Value) -> EvalResult<u32> {
+        let number = self.to_number(value)?;
+
+        if !number.is_finite() {
+            return Ok(0);
+        }
+
+        Ok(number.trunc().rem_euclid(4294967296.0) as u32)
+    }
+
+    pub fn to_js_string(&mut self, value: &Value) -> EvalResult<JsString> {
+        match value {
+            Value::StringLiteral(string) => Ok(string.clone()),
+            Value::Object(_) => {
+                let primitive = self.to_primitive(value, PreferredType::String)?;
+                self.to_js_string(&primitive)
+            },
+            Value::Symbol(_) => Err(self.throw_error(ErrorKind::TypeError, "Cannot convert a Symbol value to a string")),
+            _ => Ok(value.to_display_string().into()),
+        }
+    }
+
+    pub fn to_property_key(&mut self, value: &Value) -> EvalResult<PropertyKey> {
+        match value {
+            Value::StringLiteral(key) => Ok(PropertyKey::from(key)),
+            Value::Symbol(symbol) => Ok(PropertyKey::Symbol(symbol.clone())),
+            Value::Object(_) => {
+                let primitive = self.to_primitive(value, PreferredType::String)?;
+                self.to_property_key(&primitive)
+            },
+            _ => Ok(PropertyKey::String(value.to_display_string().into())),
+        }
+    }
+
+    pub fn strict_equals(left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Float(left), Value::Float(right)) => left == right,
+            (Value::StringLiteral(left), Value::StringLiteral(right)) => left == right,
+            (Value::Boolean(left), Value::Boolean(right)) => left == right,
+            (Value::Object(left), Value::Object(right)) => left.ptr_eq(right),
+            (Value::Symbol(left), Value::Symbol(right)) => left == right,
+            (Value::Null, Value::Null) | (Value::None, Value::None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn loose_equals(&mut self, left: &Value, right: &Value) -> EvalResult<bool> {
+        match (left, right) {
+            (Value::Null | Value::None, Value::Null | Value::None) => Ok(true),
+            (Value::Null | Value::None, _) | (_, Value::Null | Value::None) => Ok(false),
+            (Value::Float(left), Value::StringLiteral(right)) => Ok(*left == string_to_number(right)),
+            (Value::StringLiteral(left), Value::Float(right)) => Ok(string_to_number(left) == *right),
+            (Value::Boolean(left), _) => {
+                let left = Value::Float(if *left { 1.0 } else { 0.0 });
+                self.loose_equals(&left, right)
+            },
+            (_, Value::Boolean(right)) => {
+                let right = Value::Float(if *right { 1.0 } else { 0.0 });
+                self.loose_equals(left, &right)
+            },
+            (Value::Object(_), Value::Float(_) | Value::StringLiteral(_)) => {
+                let left = self.to_primitive(left, PreferredType::Default)?;
+                self.loose_equals(&left, right)
+            },
+            (Value::Float(_) | Value::StringLiteral(_), Value::Object(_)) => {
+                let right = self.to_primitive(right, PreferredType::Default)?;
+                self.loose_equals(left, &right)
+            },
+            _ => Ok(Self::strict_equals(left, right)),
+        }
+    }
+
+    /// Abstract relational comparison `left < right`, `None` when either side is NaN.
+    fn less_than(&mut self, left: &Value, right: &Value, left_first: bool) -> EvalResult<Option<bool>> {
+        let (left, right) = match left_first {
+            true => {
+                let left = self.to_primitive(left, PreferredType::Number)?;
+                (left, self.to_primitive(right, PreferredType::Number)?)
+            },
+            false => {
+                let right = self.to_primitive(right, PreferredType::Number)?;
+                (self.to_primitive(left, PreferredType::Number)?, right)
+            },
+        };
+
+        if let (Value::StringLiteral(left), Value::StringLiteral(right)) = (&left, &right) {
+            return Ok(Some(left < right));
+        }
+
+        let left = self.to_number(&left)?;
+        let right = self.to_number(&right)?;
+
+        match left.is_nan() || right.is_nan() {
+            true => Ok(None),
+            false => Ok(Some(left < right)),
+        }
+    }
+
+    pub fn instance_of(&mut self, value: &Value, constructor: &Value) -> EvalResult<bool> {
+        if !constructor.is_callable() {
+            return Err(self.throw_error(ErrorKind::TypeError, "Right-hand side of 'instanceof' is not callable"));
+        }
+
+        let object = match value {
+            Value::Object(object) => object.clone(),
+            _ => return Ok(false),
+        };
+
+        let prototype = match self.get_property(constructor, "prototype")? {
+            Value::Object(prototype) => prototype,
+            _ => {
+                return Err(self.throw_error(ErrorKind::TypeError, "Function has non-object prototype in instanceof check"));
+            },
+        };
+
+        let mut current = object.prototype();
+
+        while let Some(object) = current {
+            if object.ptr_eq(&prototype) {
+                return Ok(true);
+            }
+
+            current = object.prototype();
+        }
+
+        Ok(false)
+    }
+
+    fn has_property(&mut self, key: &Value, target: &Value) -> EvalResult<bool> {
+        let object = match target {
+            Value::Object(object) => object.clone(),
+            _ => {
+                let key = self.to_property_key(key)?;
+                let message = format!("Cannot use 'in' operator to search for '{}' in {}", key, target.to_display_string());
+                return Err(self.throw_error(ErrorKind::TypeError, message));
+            },
+        };
+
+        let key = self.to_property_key(key)?;
+        Ok(object.has_property(key))
+    }
+
+    /// Applies a non short-circuiting binary operator to two evaluated operands.
+    pub fn apply_operator(&mut self, operator: &Token, left: Value, right: Value) -> EvalResult {
+        let value = match operator {
+            Token::Addition => {
+                let left = self.to_primitive(&left, PreferredType::Default)?;
+                let right = self.to_primitive(&right, PreferredType::Default)?;
+
+                if matches!(left, Value::StringLiteral(_)) || matches!(right, Value::StringLiteral(_)) {
+                    let left = self.to_js_string(&left)?;
+                    let right = self.to_js_string(&right)?;
+                    self.reserve(left.len() + right.len())?;
+                    return Ok(Value::StringLiteral(left.concat(&right)));
+                }
+
+                Value::Float(self.to_number(&left)? + self.to_number(&right)?)
+            },
+            Token::Subtraction => Value::Float(self.to_number(&left)? - self.to_number(&right)?),
+            Token::Multiplication => Value::Float(self.to_number(&left)? * self.to_number(&right)?),
+            Token::Division => Value::Float(self.to_number(&left)? / self.to_number(&right)?),
+            Token::Modulo => Value::Float(self.to_number(&left)? % self.to_number(&right)?),
+            Token::Exponentiation => {
+                let base = self.to_number(&left)?;
+                let exponent = self.to_number(&right)?;
+                Value::Float(exponentiate(base, exponent))
+            },
+            Token::Equals => Value::Boolean(self.loose_equals(&left, &right)?),
+            Token::NotEquals => Value::Boolean(!self.loose_equals(&left, &right)?),
+            Token::TypeCheckEquals => Value::Boolean(Self::strict_equals(&left, &right)),
+            Token::TypeNotEquals => Value::Boolean(!Self::strict_equals(&left, &right)),
+            Token::LessThan => Value::Boolean(self.less_than(&left, &right, true)? == Some(true)),
+            Token::GreaterThan => Value::Boolean(self.less_than(&right, &left, false)? == Some(true)),
+            Token::LessThanEquals => Value::Boolean(self.less_than(&right, &left, false)? == Some(false)),
+            Token::GreaterThanEquals => Value::Boolean(self.less_than(&left, &right, true)? == Some(false)),
+            Token::BitwiseAnd => Value::Float((self.to_int32(&left)? & self.to_int32(&right)?) as f64),
+            Token::BitwiseOr => Value::Float((self.to_int32(&left)? | self.to_int32(&right)?) as f64),
+            Token::BitwiseXor => Value::Float((self.to_int32(&left)? ^ self.to_int32(&right)?) as f64),
+            Token::LeftShift => {
+                let left = self.to_int32(&left)?;
+                let shift = self.to_uint32(&right)? & 31;
+                Value::Float(left.wrapping_shl(shift) as f64)
+            },
+            Token::RightShift => {
+                let left = self.to_int32(&left)?;
+                let shift = self.to_uint32(&right)? & 31;
+                Value::Float((left >> shift) as f64)
+            },
+            Token::UnsignedRightShift => {
+                let left = self.to_uint32(&left)?;
+                let shift = self.to_uint32(&right)? & 31;
+                Value::Float((left >> shift) as f64)
+            },
+            Token::Instanceof => Value::Boolean(self.instance_of(&left, &right)?),
+            Token::In => Value::Boolean(self.has_property(&left, &right)?),
+            _ => unreachable!("{:?} is not a binary operator", operator),
+        };
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,221 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use crate::error::ErrorKind;
+use crate::object::{Object, ObjectKind, ObjectRef, PromiseReaction, PromiseState};
+use crate::types::{Signal, Value};
+use super::{EvalResult, Interpreter};
+
+/// Work queued to run once the current script or job has finished.
+pub enum Job {
+    // runs a reaction with the value its promise settled with, and whether it was rejected
+    Reaction(PromiseReaction, Value, bool),
+    // resolves the promise with a thenable by calling the given `then` method on it
+    ResolveThenable(ObjectRef, Value, Value),
+    // a function passed to `queueMicrotask`
+    Callback(Value),
+}
+
+impl Interpreter {
+    pub fn create_promise(&mut self) -> ObjectRef {
+        let prototype = self.intrinsics.promise_prototype.clone();
+        self.allocate_object(Object::new(Some(prototype), ObjectKind::Promise(PromiseState::Pending(Vec::new()), false)))
+    }
+
+    pub fn is_promise(value: &Value) -> bool {
+        matches!(value, Value::Object(object) if matches!(object.borrow().kind, ObjectKind::Promise(..)))
+    }
+
+    /// Settles `promise` with `resolution`, or makes it follow `resolution` if that is a thenable.
+    pub fn resolve_promise(&mut self, promise: &ObjectRef, resolution: Value) {
+        let thenable = match &resolution {
+            Value::Object(object) if object.ptr_eq(promise) => {
+                let error = self.create_error(ErrorKind::TypeError, "Chaining cycle detected for promise #<Promise>");
+                return self.reject_promise(promise, error);
+            },
+            Value::Object(object) => object.clone(),
+            _ => return self.settle_promise(promise, PromiseState::Fulfilled(resolution)),
+        };
+
+        let then = match self.get_property(&Value::Object(thenable), "then") {
+            Ok(then) => then,
+            Err(Signal::Throw(error)) => return self.reject_promise(promise, error),
+            Err(_) => Value::None,
+        };
+
+        match then.is_callable() {
+            true => self.jobs.push_back(Job::ResolveThenable(promise.clone(), resolution, then)),
+            false => self.settle_promise(promise, PromiseState::Fulfilled(resolution)),
+        }
+    }
+
+    pub fn reject_promise(&mut self, promise: &ObjectRef, reason: Value) {
+        self.settle_promise(promise, PromiseState::Rejected(reason));
+    }
+
+    fn settle_promise(&mut self, promise: &ObjectRef, state: PromiseState) {
+        let (reactions, handled) = match &mut promise.borrow_mut().kind {
+            ObjectKind::Promise(current @ PromiseState::Pending(_), handled) => match std::mem::replace(current, state.clone()) {
+                PromiseState::Pending(reactions) => (reactions, *handled),
+                _ => return,
+            },
+            _ => return,
+        };
+
+        let (value, rejected) = match state {
+            PromiseState::Fulfilled(value) => (value, false),
+            PromiseState::Rejected(value) => (value, true),
+            PromiseState::Pending(_) => return,
+        };
+
+        if rejected && !handled {
+            self.rejections.push(promise.clone());
+        }
+
+        for reaction in reactions {
+            self.jobs.push_back(Job::Reaction(reaction, value.clone(), rejected));
+        }
+    }
+
+    /// The `resolve` and `reject` functions handed to a promise executor. Only the first call to
+    /// either of them has an effect.
+    pub fn create_resolving_functions(&mut self, promise: &ObjectRef) -> (Value, Value) {
+        let already_resolved = Rc::new(Cell::new(false));
+
+        let resolve = {
+            let (promise, already_resolved) = (promise.clone(), already_resolved.clone());
+
+            self.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+                if !already_resolved.replace(true) {
+                    interpreter.resolve_promise(&promise, args.first().cloned().unwrap_or(Value::None));
+                }
+
+                Ok(Value::None)
+            }))
+        };
+
+        let reject = {
+            let promise = promise.clone();
+
+            self.create_native_function("", 1, Rc::new(move |interpreter, _, args| {
+                if !already_resolved.replace(true) {
+                    interpreter.reject_promise(&promise, args.first().cloned().unwrap_or(Value::None));
+                }
+
+                Ok(Value::None)
+            }))
+        };
+
+        (resolve, reject)
+    }
+
+    /// Registers callbacks for when `promise` settles, which settle `result` with what they return.
+    pub fn perform_then(&mut self, promise: &ObjectRef, on_fulfilled: Value, on_rejected: Value, result: Option<ObjectRef>) {
+        let reaction = PromiseReaction { on_fulfilled, on_rejected, result };
+
+        let settled = match &mut promise.borrow_mut().kind {
+            ObjectKind::Promise(state, handled) => {
+                let was_handled = std::mem::replace(handled, true);
+
+                match state {
+                    PromiseState::Pending(reactions) => {
+                        reactions.push(reaction.clone());
+                        None
+                    },
+                    PromiseState::Fulfilled(value) => Some((value.clone(), false, was_handled)),
+                    PromiseState::Rejected(value) => Some((value.clone(), true, was_handled)),
+                }
+            },
+            _ => None,
+        };
+
+        if let Some((value, rejected, was_handled)) = settled {
+            // a handler attached in time means the rejection isn't reported after all
+            if rejected && !was_handled {
+                self.rejections.retain(|rejection| !rejection.ptr_eq(promise));
+            }
+
+            self.jobs.push_back(Job::Reaction(reaction, value, rejected));
+        }
+    }
+
+    /// `PromiseResolve`: returns promises as they are, other values wrapped in a fulfilled promise.
+    pub fn promise_resolve(&mut self, value: Value) -> ObjectRef {
+        if let Value::Object(object) = &value {
+            let prototype = object.prototype();
+
+            if Self::is_promise(&value) && prototype.is_some_and(|prototype| prototype.ptr_eq(&self.intrinsics.promise_prototype)) {
+                return object.clone();
+            }
+        }
+
+        let promise = self.create_promise();
+        self.resolve_promise(&promise, value);
+        promise
+    }
+
+    /// Runs queued jobs, including the ones they queue in turn, until none are left. Stops at a
+    /// job that throws, leaving the rest queued.
+    pub fn run_jobs(&mut self) -> EvalResult<()> {
+        while let Some(job) = self.jobs.pop_front() {
+            self.run_job(job)?;
+        }
+
+        // a job that was stopped may have been settling a promise, which doesn't pass the signal on
+        match self.termination() {
+            Some(termination) => Err(Signal::Terminate(termination)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn has_pending_jobs(&self) -> bool {
+        !self.jobs.is_empty()
+    }
+
+    /// The reasons of promises that were rejected without a handler since the last call.
+    pub fn take_unhandled_rejections(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.rejections)
+            .into_iter()
+            .filter_map(|promise| match &promise.borrow().kind {
+                ObjectKind::Promise(PromiseState::Rejected(reason), false) => Some(reason.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn run_job(&mut self, job: Job) -> EvalResult<()> {
+        match job {
+            Job::Reaction(reaction, argument, rejected) => {
+                let handler = match rejected {
+                    true => &reaction.on_rejected,
+                    false => &reaction.on_fulfilled,
+                };
+
+                // without a handler the outcome passes through to the next promise in the chain
+                let outcome: EvalResult = match (handler.is_callable(), rejected) {
+                    (true, _) => self.call_function(handler, Value::None, &[argument]),
+                    (false, true) => Err(Signal::Throw(argument)),
+                    (false, false) => Ok(argument),
+                };
+
+                match (reaction.result, outcome) {
+                    (Some(result), Ok(value)) => self.resolve_promise(&result, value),
+                    (Some(result), Err(Signal::Throw(error))) => self.reject_promise(&result, error),
+                    _ => (),
+                }
+            },
+            Job::ResolveThenable(promise, thenable, then) => {
+                let (resolve, reject) = self.create_resolving_functions(&promise);
+
+                if let Err(Signal::Throw(error)) = self.call_function(&then, thenable, &[resolve, reject.clone()]) {
+                    let _ = self.call_function(&reject, Value::None, &[error]);
+                }
+            },
+            // nothing catches what these throw
+            Job::Callback(callback) => {
+                self.call_function(&callback, Value::None, &[])?;
+            },
+        }
+
+        Ok(())
+    }
+}
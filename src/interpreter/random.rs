@@ -0,0 +1,62 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use super::Interpreter;
+
+/// The xorshift128+ generator behind `Math.random`, which gives the same numbers for the same seed.
+pub struct Random {
+    state: [u64; 2],
+}
+
+impl Random {
+    pub fn new(mut seed: u64) -> Self {
+        // splitmix64 spreads the seed over both halves of the state, which must not both be zero
+        let mut next = || {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+
+        let state = [next(), next()];
+
+        match state {
+            [0, 0] => Random { state: [1, 0] },
+            state => Random { state },
+        }
+    }
+
+    /// A generator seeded differently every time.
+    pub fn from_entropy() -> Self {
+        Random::new(RandomState::new().build_hasher().finish())
+    }
+
+    /// A number in `[0, 1)`.
+    pub fn next_float(&mut self) -> f64 {
+        let [mut s1, s0] = self.state;
+        s1 ^= s1 << 23;
+        s1 ^= s1 >> 17;
+        s1 ^= s0 ^ (s0 >> 26);
+        self.state = [s0, s1];
+
+        // the top 53 bits are as many as a double holds
+        (s0.wrapping_add(s1) >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+impl Interpreter {
+    /// Restarts `Math.random` from `seed`, so that it gives the same numbers again.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.random = Random::new(seed);
+    }
+
+    pub fn random(&mut self) -> f64 {
+        self.random.next_float()
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use crate::error::ErrorKind;
+use crate::types::Value;
+use super::{EvalResult, Interpreter, Job};
+
+/// Where the event loop gets the time from, in milliseconds since some fixed point.
+pub trait Clock {
+    fn now(&self) -> u64;
+
+    /// Returns once `now()` has reached `deadline`.
+    fn wait_until(&mut self, deadline: u64);
+}
+
+/// Wall-clock time, sleeping until timers are due.
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        SystemClock { start: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+
+    fn wait_until(&mut self, deadline: u64) {
+        let now = self.now();
+
+        if deadline > now {
+            std::thread::sleep(Duration::from_millis(deadline - now));
+        }
+    }
+}
+
+/// Time that only passes when the event loop waits for it, which it then does instantly. Makes
+/// scripts using timers deterministic, see `Context::advance_time`.
+#[derive(Default)]
+pub struct VirtualClock {
+    now: u64,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        VirtualClock { now: 0 }
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> u64 {
+        self.now
+    }
+
+    fn wait_until(&mut self, deadline: u64) {
+        self.now = self.now.max(deadline);
+    }
+}
+
+/// The timers scheduled by `setTimeout` and `setInterval`.
+pub struct Timers {
+    clock: Box<dyn Clock>,
+    // keyed by deadline and then by the order they were scheduled in, which is the order they run in
+    queue: BTreeMap<(u64, u64), Timer>,
+    next_id: u32,
+    next_sequence: u64,
+    // the interval whose callback is running, unless it was cleared by it
+    running: Option<u32>,
+}
+
+struct Timer {
+    id: u32,
+    callback: Value,
+    args: Vec<Value>,
+    // the delay of an interval, which is scheduled again after each run
+    repeat: Option<u64>,
+}
+
+impl Timers {
+    pub fn new(clock: Box<dyn Clock>) -> Self {
+        Timers { clock, queue: BTreeMap::new(), next_id: 1, next_sequence: 0, running: None }
+    }
+
+    /// Cancels every timer.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.running = None;
+    }
+
+    fn schedule(&mut self, timer: Timer, delay: u64) {
+        let deadline = self.clock.now() + delay;
+        self.queue.insert((deadline, self.next_sequence), timer);
+        self.next_sequence += 1;
+    }
+}
+
+impl Interpreter {
+    /// Schedules `callback` to be called with `args` after `delay` milliseconds, and again every
+    /// `delay` milliseconds with `repeat`. Returns the id that cancels it.
+    pub fn set_timer(&mut self, callback: Value, delay: u64, args: Vec<Value>, repeat: bool) -> u32 {
+        let id = self.timers.next_id;
+        self.timers.next_id += 1;
+
+        let repeat = repeat.then_some(delay);
+        self.timers.schedule(Timer { id, callback, args, repeat }, delay);
+        id
+    }
+
+    pub fn clear_timer(&mut self, id: u32) {
+        self.timers.queue.retain(|_, timer| timer.id != id);
+
+        if self.timers.running == Some(id) {
+            self.timers.running = None;
+        }
+    }
+
+    /// When the next timer is due, if there is one.
+    pub fn next_timer_deadline(&self) -> Option<u64> {
+        self.timers.queue.keys().next().map(|(deadline, _)| *deadline)
+    }
+
+    /// Waits until the next timer is due and runs it.
+    pub fn run_next_timer(&mut self) -> EvalResult<()> {
+        let ((deadline, _), timer) = match self.timers.queue.pop_first() {
+            Some(entry) => entry,
+            None => return Ok(()),
+        };
+
+        self.timers.clock.wait_until(deadline);
+        self.timers.running = timer.repeat.map(|_| timer.id);
+
+        let result = self.call_function(&timer.callback, Value::None, &timer.args);
+
+        // an interval is only scheduled again once its callback has run, unless that cleared it
+        if let (Some(delay), Some(_)) = (timer.repeat, self.timers.running.take()) {
+            self.timers.schedule(timer, delay);
+        }
+
+        result.map(|_| ())
+    }
+
+    /// Queues `callback` to run as a microtask.
+    pub fn queue_microtask(&mut self, callback: Value) {
+        self.jobs.push_back(Job::Callback(callback));
+    }
+
+    /// Converts the delay argument of `setTimeout`, which is at least a millisecond as in Node, so
+    /// that an interval can't fire over and over without time passing.
+    pub fn to_timer_delay(&mut self, delay: &Value) -> EvalResult<u64> {
+        let delay = self.to_number(delay)?;
+
+        match delay.is_nan() || delay < 1.0 {
+            true => Ok(1),
+            false => Ok(delay.min(u32::MAX as f64) as u64),
+        }
+    }
+
+    /// Throws unless `callback` can be scheduled.
+    pub fn check_callback(&mut self, callback: &Value) -> EvalResult<()> {
+        if callback.is_callable() {
+            return Ok(());
+        }
+
+        let message = format!("The \"callback\" argument must be of type function. Received {}", callback.to_display_string());
+        Err(self.throw_error(ErrorKind::TypeError, message))
+    }
+
+    pub fn now(&self) -> u64 {
+        self.timers.clock.now()
+    }
+
+    pub fn wait_until(&mut self, deadline: u64) {
+        self.timers.clock.wait_until(deadline);
+    }
+
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.timers.clock = clock;
+    }
+}
@@ -0,0 +1,773 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::compiler::{BinaryOperator, Chunk, Compiler, Op, Update};
+use crate::error::ErrorKind;
+use crate::object::{Closure, ObjectKind, Property, PropertyKey};
+use crate::scope::{Assignment, Scope, Slot};
+use crate::types::{DeclarationKind, Signal, Stmt, Value};
+use super::{property_size, CacheStats, EvalResult, InlineCache, Interpreter, IteratorRecord};
+
+/// How scripts and the functions they define are run.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Engine {
+    /// Walks the syntax tree of each statement and expression as it is run.
+    TreeWalker,
+    /// Compiles scripts and functions to bytecode for a stack-based virtual machine. Generator
+    /// and async functions still run their lowered bodies.
+    #[default]
+    Bytecode,
+}
+
+/// A running script or function call.
+struct Frame {
+    chunk: Rc<Chunk>,
+    ip: usize,
+    // the height of the operand stack below the frame
+    base: usize,
+    // the scope to go back to when the frame is left
+    caller_scope: Rc<RefCell<Scope>>,
+    handlers: Vec<Handler>,
+    completion: Value,
+    // whether the frame is on the call stack, and whether its call counts against the depth
+    // limit here rather than in `call_function`
+    is_call: bool,
+    counted: bool,
+}
+
+/// Where an exception thrown inside a `try` block goes, and the state to restore for it.
+struct Handler {
+    target: usize,
+    stack: usize,
+    scope: Rc<RefCell<Scope>>,
+}
+
+enum Flow {
+    Next,
+    Call(Frame),
+    Return(Value),
+}
+
+fn pop(stack: &mut Vec<Value>) -> Value {
+    stack.pop().expect("operand stack underflow")
+}
+
+/// The iterator of a `for...of` loop and its `next` method, on top of the stack.
+fn iterator_on_top(stack: &[Value]) -> IteratorRecord {
+    IteratorRecord { iterator: stack[stack.len() - 2].clone(), next: stack[stack.len() - 1].clone() }
+}
+
+/// The script function a call runs as a frame, `Some(None)` for functions the interpreter calls
+/// and `None` for values that can't be called.
+fn frame_callee(function: &Value) -> Option<Option<Closure>> {
+    match function {
+        Value::Object(object) => match &object.borrow().kind {
+            ObjectKind::Function(closure) if closure.def.generator.is_none() && !closure.def.is_async => Some(Some(closure.clone())),
+            ObjectKind::Function(_) | ObjectKind::NativeFunction(_) => Some(None),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Binary operations on two numbers, which don't need the conversions of `apply_operator`.
+fn apply_to_numbers(operator: BinaryOperator, left: f64, right: f64) -> Option<Value> {
+    let value = match operator {
+        BinaryOperator::Addition => Value::Float(left + right),
+        BinaryOperator::Subtraction => Value::Float(left - right),
+        BinaryOperator::Multiplication => Value::Float(left * right),
+        BinaryOperator::Division => Value::Float(left / right),
+        BinaryOperator::Modulo => Value::Float(left % right),
+        BinaryOperator::Equals | BinaryOperator::TypeCheckEquals => Value::Boolean(left == right),
+        BinaryOperator::NotEquals | BinaryOperator::TypeNotEquals => Value::Boolean(left != right),
+        BinaryOperator::LessThan => Value::Boolean(left < right),
+        BinaryOperator::LessThanEquals => Value::Boolean(left <= right),
+        BinaryOperator::GreaterThan => Value::Boolean(left > right),
+        BinaryOperator::GreaterThanEquals => Value::Boolean(left >= right),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+impl Interpreter {
+    pub fn set_engine(&mut self, engine: Engine) {
+        self.engine = engine;
+    }
+
+    pub fn engine(&self) -> Engine {
+        self.engine
+    }
+
+    /// How often property accesses in compiled code hit their inline caches.
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache_stats
+    }
+
+    /// Takes the warnings compiling scripts produced, such as references to names nothing declares.
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Collects the warnings compiling a script would produce, for one with top-level await, which
+    /// runs lowered instead.
+    pub fn check_script(&mut self, stmts: &[Stmt]) {
+        if let Engine::Bytecode = self.engine {
+            let warnings = Compiler::check_script(stmts, &self.scope.borrow());
+            self.warnings.extend(warnings);
+        }
+    }
+
+    /// Runs a script in the current scope with the selected engine, returning its completion value.
+    pub fn eval_script(&mut self, stmts: &[Stmt]) -> EvalResult {
+        match self.engine {
+            Engine::TreeWalker => self.eval(stmts),
+            Engine::Bytecode => {
+                let chunk = Rc::new(Compiler::compile_script(stmts, &self.scope.borrow()));
+                self.warnings.extend(chunk.warnings.iter().cloned());
+
+                let frame = Frame {
+                    chunk,
+                    ip: 0,
+                    base: 0,
+                    caller_scope: self.scope.clone(),
+                    handlers: Vec::new(),
+                    completion: Value::None,
+                    is_call: false,
+                    counted: false,
+                };
+
+                self.execute(frame)
+            },
+        }
+    }
+
+    /// Calls a plain script function on the virtual machine.
+    pub(super) fn run_closure(&mut self, closure: &Closure, this: Value, args: &[Value]) -> EvalResult {
+        let frame = self.enter_function(closure, this, args, false)?;
+        self.execute(frame)
+    }
+
+    /// Sets up the scope of a call the way `call_closure` does, compiling the function on its
+    /// first call.
+    fn enter_function(&mut self, closure: &Closure, this: Value, args: &[Value], counted: bool) -> EvalResult<Frame> {
+        let chunk = closure.def.bytecode.get_or_compile(&closure.def);
+        let scope = self.function_scope(closure, this);
+
+        self.call_stack.push(closure.def.name.clone().unwrap_or_else(|| "<anonymous>".into()));
+        let caller_scope = std::mem::replace(&mut self.scope, scope);
+
+        if closure.def.uses_arguments {
+            let arguments = self.create_arguments_object(args);
+            self.scope.borrow_mut().define("arguments".to_string(), arguments);
+        }
+
+        if let Err(signal) = self.bind_parameters(&closure.def, args) {
+            self.scope = caller_scope;
+            self.call_stack.pop();
+            return Err(signal);
+        }
+
+        Ok(Frame { chunk, ip: 0, base: 0, caller_scope, handlers: Vec::new(), completion: Value::None, is_call: true, counted })
+    }
+
+    fn leave_frame(&mut self, frame: &Frame) {
+        self.scope = frame.caller_scope.clone();
+
+        if frame.is_call {
+            self.call_stack.pop();
+        }
+
+        if frame.counted {
+            self.exit_call();
+        }
+    }
+
+    /// Runs frames until the one it was given returns. Calls of script functions push frames
+    /// instead of recursing, everything else goes through the interpreter.
+    fn execute(&mut self, frame: Frame) -> EvalResult {
+        let mut frame = frame;
+        let mut callers: Vec<Frame> = Vec::new();
+        let mut stack: Vec<Value> = Vec::new();
+
+        loop {
+            let op = frame.chunk.code[frame.ip];
+            frame.ip += 1;
+
+            let signal = match self.run_op(op, &mut frame, &mut stack) {
+                Ok(Flow::Next) => continue,
+                Ok(Flow::Call(callee)) => {
+                    callers.push(std::mem::replace(&mut frame, callee));
+                    continue;
+                },
+                Ok(Flow::Return(value)) => {
+                    stack.truncate(frame.base);
+                    self.leave_frame(&frame);
+
+                    match callers.pop() {
+                        Some(caller) => {
+                            frame = caller;
+                            stack.push(value);
+                            continue;
+                        },
+                        None => return Ok(value),
+                    }
+                },
+                Err(signal) => signal,
+            };
+
+            let Signal::Throw(exception) = signal else {
+                self.leave_frame(&frame);
+
+                while let Some(caller) = callers.pop() {
+                    self.leave_frame(&caller);
+                }
+
+                return Err(signal);
+            };
+
+            // unwinds to the innermost handler, leaving the frames without one
+            loop {
+                if let Some(handler) = frame.handlers.pop() {
+                    stack.truncate(handler.stack);
+                    self.scope = handler.scope;
+                    stack.push(exception);
+                    frame.ip = handler.target;
+                    break;
+                }
+
+                stack.truncate(frame.base);
+                self.leave_frame(&frame);
+
+                match callers.pop() {
+                    Some(caller) => frame = caller,
+                    None => return Err(Signal::Throw(exception)),
+                }
+            }
+        }
+    }
+
+    fn run_op(&mut self, op: Op, frame: &mut Frame, stack: &mut Vec<Value>) -> EvalResult<Flow> {
+        let chunk = &frame.chunk;
+
+        match op {
+            Op::Constant(index) => stack.push(chunk.constants[index as usize].clone()),
+            Op::Undefined => stack.push(Value::None),
+            Op::Null => stack.push(Value::Null),
+            Op::True => stack.push(Value::Boolean(true)),
+            Op::False => stack.push(Value::Boolean(false)),
+            Op::This => stack.push(self.scope.borrow().this()),
+            Op::Closure(function) => {
+                self.reserve_object()?;
+                stack.push(self.create_function_expression(chunk.functions[function as usize].clone()));
+            },
+            Op::RegExp(regex) => {
+                self.reserve_object()?;
+                stack.push(self.create_regexp(chunk.regexps[regex as usize].clone()));
+            },
+            Op::NamedClosure(function, name) => {
+                let function = self.create_function(chunk.functions[function as usize].clone());
+
+                if let Value::Object(object) = &function {
+                    object.borrow_mut().properties.insert("name", Property::readonly(chunk.constants[name as usize].clone()));
+                }
+
+                stack.push(function);
+            },
+            Op::Array(count) => {
+                let values = stack.split_off(stack.len() - count as usize);
+                self.reserve_object()?;
+                self.reserve(values.len() * std::mem::size_of::<Value>())?;
+                stack.push(self.create_array(values));
+            },
+            Op::Object(count) => {
+                self.reserve_object()?;
+                self.reserve(count as usize * std::mem::size_of::<(PropertyKey, Property)>())?;
+                stack.push(Value::Object(self.create_object()));
+            },
+            Op::DefineField(key) => {
+                let value = pop(stack);
+
+                if let Some(Value::Object(object)) = stack.last() {
+                    object.borrow_mut().define_own_property(chunk.keys[key as usize].clone(), Property::new(value));
+                }
+            },
+            Op::DefineComputedField => {
+                let value = pop(stack);
+                let key = pop(stack);
+                let key = self.to_property_key(&key)?;
+
+                if let Some(Value::Object(object)) = stack.last() {
+                    object.borrow_mut().define_own_property(key, Property::new(value));
+                }
+            },
+
+            Op::Pop => {
+                pop(stack);
+            },
+            Op::Dup => stack.push(stack.last().expect("operand stack underflow").clone()),
+            Op::Dup2 => {
+                let (below, top) = (stack[stack.len() - 2].clone(), stack[stack.len() - 1].clone());
+                stack.push(below);
+                stack.push(top);
+            },
+            Op::Nip => {
+                let value = pop(stack);
+                pop(stack);
+                stack.push(value);
+            },
+
+            Op::GetName(name) => stack.push(self.lookup(&chunk.names[name as usize])?),
+            Op::SetName(name) => {
+                let value = stack.last().expect("operand stack underflow").clone();
+                self.assign_binding(&chunk.names[name as usize], value)?;
+            },
+            Op::GetSlot(slot, name) => {
+                let value = self.scope.borrow().get_slot(slot, &chunk.names[name as usize]);
+
+                match value {
+                    Some(value) => stack.push(value),
+                    None => stack.push(self.lookup(&chunk.names[name as usize])?),
+                }
+            },
+            Op::SetSlot(slot, name) => {
+                let value = stack.last().expect("operand stack underflow").clone();
+                self.assign_slot(slot, &chunk.names[name as usize], value)?;
+            },
+            Op::TypeOfName(name) => {
+                let name = &chunk.names[name as usize];
+
+                let type_of = match self.scope.borrow().contains_key(name) {
+                    true => None,
+                    false => Some("undefined"),
+                };
+
+                let type_of = match type_of {
+                    Some(type_of) => type_of,
+                    None => self.lookup(name)?.type_of(),
+                };
+
+                stack.push(Value::StringLiteral(type_of.into()));
+            },
+            Op::Declare(kind, name) => {
+                let value = pop(stack);
+                let name = &chunk.names[name as usize];
+
+                match kind {
+                    DeclarationKind::Let | DeclarationKind::Const => self.declare(kind, name, value)?,
+                    DeclarationKind::Var => self.assign_binding(name, value)?,
+                }
+            },
+            Op::BindPattern(pattern, kind) => {
+                let value = pop(stack);
+                self.bind_pattern(&chunk.patterns[pattern as usize], value, kind)?;
+            },
+            Op::DefineFunction(function) => {
+                let def = chunk.functions[function as usize].clone();
+                let name = def.name.clone().unwrap_or_default();
+                let function = self.create_function(def);
+
+                self.scope.borrow_mut().define(name, function);
+            },
+            Op::DefineName(name) => {
+                let value = pop(stack);
+                self.scope.borrow_mut().define(chunk.names[name as usize].clone(), value);
+            },
+            Op::HoistVars(names) => self.define_vars(&chunk.name_lists[names as usize]),
+            Op::UpdateName(name, update) => {
+                let name = &chunk.names[name as usize];
+                let current = self.lookup(name)?;
+                let (old_value, new_value) = self.apply_update(&current, update)?;
+
+                self.assign_binding(name, Value::Float(new_value))?;
+                stack.push(Value::Float(if update.prefix { new_value } else { old_value }));
+            },
+
+            Op::UpdateSlot(slot, name, update) => {
+                let name = &chunk.names[name as usize];
+
+                let current = self.scope.borrow().get_slot(slot, name);
+
+                let current = match current {
+                    Some(value) => value,
+                    None => self.lookup(name)?,
+                };
+
+                let (old_value, new_value) = self.apply_update(&current, update)?;
+
+                self.assign_slot(slot, name, Value::Float(new_value))?;
+                stack.push(Value::Float(if update.prefix { new_value } else { old_value }));
+            },
+
+            Op::GetProperty(key, cache) => {
+                let object = pop(stack);
+                stack.push(self.get_cached(&object, &chunk.keys[key as usize], &chunk.caches[cache as usize])?);
+            },
+            Op::GetComputed => {
+                let key = pop(stack);
+                let object = pop(stack);
+                stack.push(self.get_computed(&object, key)?);
+            },
+            Op::SetProperty(key, cache) => {
+                let value = pop(stack);
+                let object = pop(stack);
+                self.set_cached(&object, &chunk.keys[key as usize], &chunk.caches[cache as usize], value.clone())?;
+                stack.push(value);
+            },
+            Op::SetComputed => {
+                let value = pop(stack);
+                let key = pop(stack);
+                let object = pop(stack);
+                let key = self.to_property_key(&key)?;
+                self.set_property(&object, key, value.clone())?;
+                stack.push(value);
+            },
+            Op::ToPropertyKey => {
+                if let Some(key @ Value::Object(_)) = stack.last() {
+                    let key = match self.to_property_key(&key.clone())? {
+                        PropertyKey::String(key) => Value::StringLiteral(key.into()),
+                        PropertyKey::Symbol(symbol) => Value::Symbol(symbol),
+                    };
+
+                    *stack.last_mut().expect("operand stack underflow") = key;
+                }
+            },
+            Op::GetMethod(key, cache) => {
+                let object = pop(stack);
+                let function = self.get_cached(&object, &chunk.keys[key as usize], &chunk.caches[cache as usize])?;
+                stack.push(function);
+                stack.push(object);
+            },
+            Op::GetMethodComputed => {
+                let key = pop(stack);
+                let object = pop(stack);
+                let key = self.to_property_key(&key)?;
+                let function = self.get_property(&object, key)?;
+                stack.push(function);
+                stack.push(object);
+            },
+            Op::UpdateProperty(key, update) => {
+                let object = pop(stack);
+                let key = &chunk.keys[key as usize];
+                let current = self.get_property(&object, key)?;
+                let (old_value, new_value) = self.apply_update(&current, update)?;
+
+                self.set_property(&object, key, Value::Float(new_value))?;
+                stack.push(Value::Float(if update.prefix { new_value } else { old_value }));
+            },
+            Op::UpdateComputed(update) => {
+                let key = pop(stack);
+                let object = pop(stack);
+                let key = self.to_property_key(&key)?;
+                let current = self.get_property(&object, &key)?;
+                let (old_value, new_value) = self.apply_update(&current, update)?;
+
+                self.set_property(&object, key, Value::Float(new_value))?;
+                stack.push(Value::Float(if update.prefix { new_value } else { old_value }));
+            },
+
+            Op::Binary(operator) => {
+                let right = pop(stack);
+                let left = pop(stack);
+
+                let value = match (&left, &right) {
+                    (Value::Float(l), Value::Float(r)) => apply_to_numbers(operator, *l, *r),
+                    _ => None,
+                };
+
+                let value = match value {
+                    Some(value) => value,
+                    None => self.apply_operator(&operator.token(), left, right)?,
+                };
+
+                stack.push(value);
+            },
+            Op::Not => {
+                let value = pop(stack);
+                stack.push(Value::Boolean(!value.is_truthy()));
+            },
+            Op::Negate => {
+                let value = pop(stack);
+                stack.push(Value::Float(-self.to_number(&value)?));
+            },
+            Op::Plus => {
+                let value = pop(stack);
+                stack.push(Value::Float(self.to_number(&value)?));
+            },
+            Op::BitwiseNot => {
+                let value = pop(stack);
+                stack.push(Value::Float(!self.to_int32(&value)? as f64));
+            },
+            Op::TypeOf => {
+                let value = pop(stack);
+                stack.push(Value::StringLiteral(value.type_of().into()));
+            },
+            Op::Void => {
+                pop(stack);
+                stack.push(Value::None);
+            },
+
+            Op::Jump(target) => frame.ip = target as usize,
+            Op::JumpIfFalse(target) => {
+                if !pop(stack).is_truthy() {
+                    frame.ip = target as usize;
+                }
+            },
+            Op::JumpIfFalseOrPop(target) | Op::JumpIfTrueOrPop(target) | Op::JumpIfNotNullishOrPop(target) => {
+                let value = stack.last().expect("operand stack underflow");
+
+                let jumps = match op {
+                    Op::JumpIfFalseOrPop(_) => !value.is_truthy(),
+                    Op::JumpIfTrueOrPop(_) => value.is_truthy(),
+                    _ => !value.is_nullish(),
+                };
+
+                match jumps {
+                    true => frame.ip = target as usize,
+                    false => {
+                        pop(stack);
+                    },
+                }
+            },
+            Op::Call(count, description) => {
+                // the arguments are passed straight from the stack, and popped with the callee after
+                let start = stack.len() - count as usize;
+                let function = &stack[start - 2];
+                let this = stack[start - 1].clone();
+
+                let Some(closure) = frame_callee(function) else {
+                    let message = format!("{} is not a function", chunk.descriptions[description as usize]);
+                    return Err(self.throw_error(ErrorKind::TypeError, message));
+                };
+
+                let Some(closure) = closure else {
+                    let result = self.call_function(function, this, &stack[start..]);
+                    stack.truncate(start - 2);
+                    stack.push(result?);
+                    return Ok(Flow::Next);
+                };
+
+                self.enter_call()?;
+                let callee = self.enter_function(&closure, this, &stack[start..], true);
+                stack.truncate(start - 2);
+
+                return match callee {
+                    Ok(callee) => Ok(Flow::Call(Frame { base: stack.len(), ..callee })),
+                    Err(signal) => {
+                        self.exit_call();
+                        Err(signal)
+                    },
+                };
+            },
+            Op::TailCall(count, description) => {
+                let start = stack.len() - count as usize;
+
+                // a frame that catches or runs a `finally` has to stay until the callee returns
+                let closure = match frame.is_call && frame.handlers.is_empty() {
+                    true => frame_callee(&stack[start - 2]).flatten(),
+                    false => None,
+                };
+
+                let Some(closure) = closure else { return self.run_op(Op::Call(count, description), frame, stack) };
+
+                // the callee takes over the frame, in place of the function on the call stack
+                let this = stack[start - 1].clone();
+                let callee = self.enter_function(&closure, this, &stack[start..], frame.counted)?;
+                self.call_stack.remove(self.call_stack.len() - 2);
+                stack.truncate(frame.base);
+
+                *frame = Frame { base: frame.base, caller_scope: frame.caller_scope.clone(), ..callee };
+            },
+            Op::New(count, description) => {
+                let args = stack.split_off(stack.len() - count as usize);
+                let constructor = pop(stack);
+
+                if !constructor.is_callable() {
+                    let message = format!("{} is not a constructor", chunk.descriptions[description as usize]);
+                    return Err(self.throw_error(ErrorKind::TypeError, message));
+                }
+
+                stack.push(self.construct(&constructor, &args)?);
+            },
+            Op::Return => return Ok(Flow::Return(pop(stack))),
+            Op::Throw => return Err(Signal::Throw(pop(stack))),
+
+            Op::EnterScope => self.scope = self.allocate_scope(Scope::new(Some(self.scope.clone()))),
+            Op::ExitScope => {
+                let parent = self.scope.borrow().parent().expect("a block scope has a parent");
+                self.scope = parent;
+            },
+            Op::CopyIterationScope(names) => self.copy_iteration_scope(&chunk.name_lists[names as usize]),
+            Op::PushHandler(target) => frame.handlers.push(Handler { target: target as usize, stack: stack.len(), scope: self.scope.clone() }),
+            Op::PopHandler => {
+                frame.handlers.pop();
+            },
+            Op::GetIterator => {
+                let iterable = pop(stack);
+                let record = self.get_iterator(&iterable)?;
+                stack.push(record.iterator);
+                stack.push(record.next);
+            },
+            Op::IteratorNext(target) => {
+                match self.iterator_step(&iterator_on_top(stack))? {
+                    Some(value) => stack.push(value),
+                    None => frame.ip = target as usize,
+                }
+            },
+            Op::CloseIterator => self.iterator_close(&iterator_on_top(stack), Ok(()))?,
+            Op::CloseIteratorAndThrow => {
+                let exception = pop(stack);
+                return self.iterator_close(&iterator_on_top(stack), Err(Signal::Throw(exception)));
+            },
+            Op::ForInKeys => {
+                let (object, keys) = Self::for_in_keys(pop(stack));
+                self.reserve_object()?;
+                self.reserve(keys.len() * std::mem::size_of::<Value>())?;
+
+                stack.push(object.map_or(Value::None, Value::Object));
+                stack.push(self.create_array(keys));
+                stack.push(Value::Float(0.0));
+            },
+            Op::ForInNext(target) => {
+                let top = stack.len();
+                let Value::Float(index) = stack[top - 1] else { unreachable!("for...in keys below") };
+                let Value::Object(keys) = &stack[top - 2] else { unreachable!("for...in keys below") };
+
+                let object = match &stack[top - 3] {
+                    Value::Object(object) => Some(object.clone()),
+                    _ => None,
+                };
+
+                let mut index = index as usize;
+
+                let key = loop {
+                    let key = match &keys.borrow().kind {
+                        ObjectKind::Array(keys) => keys.get(index).cloned(),
+                        _ => None,
+                    };
+
+                    index += 1;
+
+                    match key {
+                        Some(key) if Self::for_in_skips(object.as_ref(), &key) => continue,
+                        key => break key,
+                    }
+                };
+
+                stack[top - 1] = Value::Float(index as f64);
+
+                match key {
+                    Some(key) => stack.push(key),
+                    None => frame.ip = target as usize,
+                }
+            },
+
+            Op::Step => self.step()?,
+            Op::SetCompletion => frame.completion = pop(stack),
+            Op::ClearCompletion => frame.completion = Value::None,
+            Op::ReturnCompletion => return Ok(Flow::Return(std::mem::replace(&mut frame.completion, Value::None))),
+
+            Op::Eval(expr) => stack.push(self.eval_expr(&chunk.exprs[expr as usize])?),
+            Op::Exec(site) => {
+                let site = &chunk.execs[site as usize];
+
+                // a `break` or `continue` with no loop around it ends the function, as in `call_closure`
+                let target = match self.eval_stmt(&site.stmt) {
+                    Ok(value) => {
+                        stack.push(value);
+                        return Ok(Flow::Next);
+                    },
+                    Err(Signal::Return(value)) => return Ok(Flow::Return(value)),
+                    Err(Signal::Break) => site.on_break,
+                    Err(Signal::Continue) => site.on_continue,
+                    Err(signal) => return Err(signal),
+                };
+
+                match target {
+                    Some(target) => frame.ip = target as usize,
+                    None => return Ok(Flow::Return(Value::None)),
+                }
+            },
+        }
+
+        Ok(Flow::Next)
+    }
+
+    fn assign_slot(&mut self, slot: Slot, name: &str, value: Value) -> EvalResult<()> {
+        let assignment = self.scope.borrow_mut().assign_slot(slot, name, value.clone());
+
+        match assignment {
+            Some(Assignment::Assigned) => Ok(()),
+            Some(Assignment::Constant) => Err(self.throw_error(ErrorKind::TypeError, "Assignment to constant variable.")),
+            _ => self.assign_binding(name, value),
+        }
+    }
+
+    /// Reads a property by name, from where `cache` says objects of the same shape keep it.
+    fn get_cached(&mut self, target: &Value, key: &PropertyKey, cache: &InlineCache) -> EvalResult {
+        let Value::Object(object) = target else {
+            return self.get_property(target, key);
+        };
+
+        match cache.get(object) {
+            Some(Property { getter: Some(getter), .. }) => {
+                self.cache_stats.hits += 1;
+                self.call_function(&getter, target.clone(), &[])
+            },
+            Some(Property { setter: Some(_), .. }) => {
+                self.cache_stats.hits += 1;
+                Ok(Value::None)
+            },
+            Some(property) => {
+                self.cache_stats.hits += 1;
+                Ok(property.value)
+            },
+            None => {
+                self.cache_stats.misses += 1;
+                cache.update(object, key);
+                self.get_property(target, key)
+            },
+        }
+    }
+
+    /// Assigns a property by name, straight to where `cache` says it is if the object already has
+    /// it as a writable data property, or adding it as it did to objects of the same shape.
+    fn set_cached(&mut self, target: &Value, key: &PropertyKey, cache: &InlineCache, value: Value) -> EvalResult<()> {
+        let Value::Object(object) = target else {
+            return self.set_property(target, key, value);
+        };
+
+        let value = match cache.set(object, value) {
+            Ok(()) => {
+                self.cache_stats.hits += 1;
+                return Ok(());
+            },
+            Err(value) => value,
+        };
+
+        if let Some(shape) = cache.transition(object) {
+            self.cache_stats.hits += 1;
+
+            let property = Property::new(value);
+            self.reserve(property_size(key, &property))?;
+            object.borrow_mut().properties.push_shaped(shape, property);
+            return Ok(());
+        }
+
+        self.cache_stats.misses += 1;
+
+        let before = object.borrow().properties.shape().clone();
+        self.set_property(target, key, value)?;
+
+        match before.is_shared() && !Rc::ptr_eq(&before, object.borrow().properties.shape()) {
+            true => cache.update_added(object, key, before),
+            false => cache.update(object, key),
+        }
+
+        Ok(())
+    }
+
+    fn apply_update(&mut self, current: &Value, update: Update) -> EvalResult<(f64, f64)> {
+        let old_value = self.to_number(current)?;
+        Ok((old_value, old_value + update.delta()))
+    }
+}
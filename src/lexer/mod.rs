@@ -1,322 +1,510 @@
-use crate::types::Token;
+use crate::error::{ErrorKind, JsError};
+use crate::types::{JsString, Token};
 
 #[derive(Debug, PartialEq)]
 
-pub struct Lexer <'a> {
-    code: &'a str,
-    pos: usize
+pub struct Lexer {
+    code: Vec<char>,
+    pos: usize,
+    // whether the token last read by `next_token` is preceded by a line terminator
+    line_break: bool,
+    // `line_break` for each token read by `tokenize`, which automatic semicolon insertion relies on
+    line_breaks: Vec<bool>,
+    // where the token last read by `next_token` starts, and the same for each token `tokenize` read
+    start: usize,
+    starts: Vec<usize>,
+    // whether a `/` starts a regular expression rather than a division, which depends on the token before
+    regex_allowed: bool,
 }
 
-impl<'a> Lexer<'a> {
-    pub fn new(code: &'a str) -> Self {
+impl Lexer {
+    pub fn new(code: &str) -> Self {
         Lexer {
-            code,
-            pos: 0
+            code: code.chars().collect(),
+            pos: 0,
+            line_break: false,
+            line_breaks: Vec::new(),
+            start: 0,
+            starts: Vec::new(),
+            regex_allowed: true,
         }
     }
 
-    pub fn next_token(&mut self) -> Option<Token> {
-        while self.pos < self.code.len() {
-            let c = self.code.chars().nth(self.pos)?;
+    pub fn tokenize(&mut self) -> Result<Vec<Token>, JsError> {
+        let mut tokens = Vec::new();
 
-            match c {
-                ' ' | '\t' | '\n' => {
-                    self.pos += 1;
-                }
-                'a'..='z' | 'A'..='Z' => {
-                    let token = self.read_identifier();
-                    return token;
-                }
-                '0'..='9' => {
-                    let token = self.read_float();
-                    return token;
-                }
-                '\'' => {
-                    let token = self.read_string_literal('\'');
-                    return token;
-                }
-                '"' => {
-                    let token = self.read_string_literal('\"');
-                    return token;
-                },
-                '&' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '&' => {
-                            self.pos += 2;
-                            return Some(Token::LogicalAnd)
-                        },
-                        _ => panic!("Expected &")
-                    }
-                },
-                '|' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '|' => {
-                            self.pos += 2;
-                            return Some(Token::LogicalOr)
-                        },
-                        _ => panic!("Expected |")
-                    }
-                },
-                '!' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '=' => {
-                            let char = self.code.chars().nth(self.pos + 2)?;
-
-                            match char {
-                                '=' => {
-                                    self.pos += 3;
-                                    return Some(Token::TypeNotEquals);
-                                },
-                                _ => {
-                                    self.pos += 2;
-                                    return Some(Token::NotEquals);
-                                }
-                            }
-                        },
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::LogicalNot);
-                        }
-                    }
-                }
-                '=' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '=' => {
-                            let char = self.code.chars().nth(self.pos + 2)?;
-
-                            match char {
-                                '=' => {
-                                    self.pos += 3;
-                                    return Some(Token::TypeCheckEquals);
-                                },
-                                _ => {
-                                    self.pos += 2;
-                                    return Some(Token::Equals);
-                                }
-                            }
-                        },
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::Assign);
-                        }
-                    }
-                },
-                '<' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '=' => {
-                            self.pos += 2;
-                            return Some(Token::LessThanEquals)
-                        },
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::LessThan)
-                        }
-                    }
-                },
-                '>' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '=' => {
-                            self.pos += 2;
-                            return Some(Token::GreaterThanEquals)
-                        },
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::GreaterThan)
-                        }
-                    }
-                },
-                '+' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '+' => {
-                            self.pos += 2;
-                            return Some(Token::Increment)
-                        },
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::Addition)
-                        }
-                    }
-                }
-                '-' => {
-                    let char = self.code.chars().nth(self.pos + 1)?;
-
-                    match char {
-                        '-' => {
-                            self.pos += 2;
-                            return Some(Token::Decrement)
-                        },
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::Subtraction)
-                        }
-                    }
-                }
-                '*' => {
-                    self.pos += 1;
-                    return Some(Token::Multiplication);
-                }
-                ';' => {
-                    self.pos += 1;
-                    return Some(Token::Semicolon);
-                }
-                '{' => {
-                    self.pos += 1;
-                    return Some(Token::BraceOpen);
-                }
-                '}' => {
-                    self.pos += 1;
-                    return Some(Token::BraceClose);
-                }
-                '(' => {
+        while let Some(token) = self.next_token()? {
+            tokens.push(token);
+            self.line_breaks.push(self.line_break);
+            self.starts.push(self.start);
+        }
+
+        Ok(tokens)
+    }
+
+    /// Whether each token returned by `tokenize` is preceded by a line terminator.
+    pub fn line_breaks(&self) -> &[bool] {
+        &self.line_breaks
+    }
+
+    /// Where each token returned by `tokenize` starts in the code, counted in characters.
+    pub fn starts(&self) -> &[usize] {
+        &self.starts
+    }
+
+    /// Tokenizes the code again from `start`, where a `/` read as division turned out to start a
+    /// regular expression. The lexer returned with the tokens knows their line breaks and starts.
+    pub fn rescan_regex(&self, start: usize) -> Result<(Vec<Token>, Lexer), JsError> {
+        let mut lexer = Lexer { code: self.code.clone(), pos: start, ..Lexer::new("") };
+        let tokens = lexer.tokenize()?;
+        Ok((tokens, lexer))
+    }
+
+    pub fn next_token(&mut self) -> Result<Option<Token>, JsError> {
+        self.line_break = false;
+
+        while let Some(c) = self.peek(0) {
+            self.start = self.pos;
+
+            let token = match c {
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                    self.line_break = true;
                     self.pos += 1;
-                    return Some(Token::ParenOpen);
+                    continue;
                 }
-                ')' => {
+                ' ' | '\t' | '\u{a0}' | '\u{feff}' => {
                     self.pos += 1;
-                    return Some(Token::ParenClose);
+                    continue;
                 }
-                '/' =>{
-                    let next_char = self.code.chars().nth(self.pos + 1)?;
-                    let token = match next_char {
-                        '/' => self.read_comment(),
-                        _ => {
-                            self.pos += 1;
-                            return Some(Token::Division);
-                        }
-                    };
-
-                    return token;
+                '/' if self.peek(1) == Some('/') => {
+                    self.skip_line_comment();
+                    continue;
                 }
-                ',' => {
-                    self.pos += 1;
-                    return Some(Token::Comma);
-                },
-                _ => {
-                    self.pos += 1;
+                '/' if self.peek(1) == Some('*') => {
+                    self.skip_block_comment()?;
+                    continue;
                 }
+                '/' if self.regex_allowed => self.read_regex_literal()?,
+                c if Self::is_identifier_start(c) => self.read_identifier(),
+                '0'..='9' => self.read_float()?,
+                '.' if matches!(self.peek(1), Some('0'..='9')) => self.read_float()?,
+                '\'' | '"' => self.read_string_literal(c)?,
+                _ => self.read_punctuator()?,
+            };
+
+            self.regex_allowed = !Self::ends_expression(&token);
+            return Ok(Some(token));
+        }
+
+        Ok(None)
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.code.get(self.pos + offset).copied()
+    }
+
+    /// Consumes the longest operator in `candidates` (listed longest first) that matches the input.
+    fn read_operator(&mut self, candidates: &[(&str, Token)]) -> Option<Token> {
+        for (text, token) in candidates {
+            let matches = text.chars().enumerate().all(|(i, c)| self.peek(i) == Some(c));
+
+            if matches {
+                self.pos += text.chars().count();
+                return Some(token.clone());
             }
         }
 
         None
     }
 
-    fn is_valid_variable_char (&mut self, c: char) -> bool {
-        c.is_alphabetic() || c == '_'
+    fn read_punctuator(&mut self) -> Result<Token, JsError> {
+        let c = self.peek(0).unwrap_or_default();
+
+        let candidates: &[(&str, Token)] = match c {
+            '&' => &[("&&=", Token::LogicalAndAssign), ("&&", Token::LogicalAnd), ("&=", Token::BitwiseAndAssign), ("&", Token::BitwiseAnd)],
+            '|' => &[("||=", Token::LogicalOrAssign), ("||", Token::LogicalOr), ("|=", Token::BitwiseOrAssign), ("|", Token::BitwiseOr)],
+            '^' => &[("^=", Token::BitwiseXorAssign), ("^", Token::BitwiseXor)],
+            '~' => &[("~", Token::BitwiseNot)],
+            '!' => &[("!==", Token::TypeNotEquals), ("!=", Token::NotEquals), ("!", Token::LogicalNot)],
+            '=' => &[("===", Token::TypeCheckEquals), ("==", Token::Equals), ("=>", Token::Arrow), ("=", Token::Assign)],
+            '<' => &[("<<=", Token::LeftShiftAssign), ("<<", Token::LeftShift), ("<=", Token::LessThanEquals), ("<", Token::LessThan)],
+            '>' => &[
+                (">>>=", Token::UnsignedRightShiftAssign),
+                (">>>", Token::UnsignedRightShift),
+                (">>=", Token::RightShiftAssign),
+                (">>", Token::RightShift),
+                (">=", Token::GreaterThanEquals),
+                (">", Token::GreaterThan),
+            ],
+            '+' => &[("++", Token::Increment), ("+=", Token::AdditionAssign), ("+", Token::Addition)],
+            '-' => &[("--", Token::Decrement), ("-=", Token::SubtractionAssign), ("-", Token::Subtraction)],
+            '*' => &[("**=", Token::ExponentiationAssign), ("**", Token::Exponentiation), ("*=", Token::MultiplicationAssign), ("*", Token::Multiplication)],
+            '/' => &[("/=", Token::DivisionAssign), ("/", Token::Division)],
+            '%' => &[("%=", Token::ModuloAssign), ("%", Token::Modulo)],
+            '?' => &[("??=", Token::NullishCoalescingAssign), ("??", Token::NullishCoalescing), ("?", Token::QuestionMark)],
+            ';' => &[(";", Token::Semicolon)],
+            '{' => &[("{", Token::BraceOpen)],
+            '}' => &[("}", Token::BraceClose)],
+            '(' => &[("(", Token::ParenOpen)],
+            ')' => &[(")", Token::ParenClose)],
+            '[' => &[("[", Token::BracketOpen)],
+            ']' => &[("]", Token::BracketClose)],
+            ',' => &[(",", Token::Comma)],
+            '.' => &[("...", Token::Ellipsis), (".", Token::Dot)],
+            ':' => &[(":", Token::Colon)],
+            _ => &[],
+        };
+
+        match self.read_operator(candidates) {
+            Some(token) => Ok(token),
+            None => Err(self.error(format!("Invalid or unexpected token '{}'", c))),
+        }
+    }
+
+    /// Whether `token` can end an expression, after which a `/` is a division. This guesses wrong
+    /// for a regular expression right after a block or the condition of an `if`, which the parser
+    /// scans again with `rescan_regex`.
+    fn ends_expression(token: &Token) -> bool {
+        matches!(
+            token,
+            Token::Identifier(_)
+                | Token::Float(_)
+                | Token::StringLiteral(_)
+                | Token::RegExp(..)
+                | Token::Boolean(_)
+                | Token::Null
+                | Token::This
+                | Token::ParenClose
+                | Token::BracketClose
+                | Token::BraceClose
+                | Token::Increment
+                | Token::Decrement
+        )
+    }
+
+    fn is_identifier_start(c: char) -> bool {
+        c.is_alphabetic() || c == '_' || c == '$'
+    }
+
+    fn is_valid_variable_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_' || c == '$'
     }
 
-    fn read_identifier(&mut self) -> Option<Token> {
+    fn read_identifier(&mut self) -> Token {
         let start = self.pos;
-        while self.pos < self.code.len() {
-            let c = self.code.chars().nth(self.pos)?;
 
-            if !self.is_valid_variable_char(c) {
+        while let Some(c) = self.peek(0) {
+            if !Self::is_valid_variable_char(c) {
                 break;
             }
 
             self.pos += 1;
         }
 
-        let ident = &self.code[start..self.pos];
-
-        match ident {
-            "let" => Some(Token::Let),
-            "log" => Some(Token::Log),
-            "return" => Some(Token::Return),
-            "function" => Some(Token::Function),
-            "true" => Some(Token::Boolean(true)),
-            "false" => Some(Token::Boolean(false)),
-            "if" => Some(Token::If),
-            "for" => Some(Token::ForLoop),
-            "loop" => Some(Token::Loop),
-            "break" => Some(Token::Break),
-            "while" => Some(Token::While),
-            "else" => {
-                self.pos += 1;
+        let ident: String = self.code[start..self.pos].iter().collect();
+
+        match ident.as_str() {
+            "let" => Token::Let,
+            "const" => Token::Const,
+            "var" => Token::Var,
+            "return" => Token::Return,
+            "function" => Token::Function,
+            "true" => Token::Boolean(true),
+            "false" => Token::Boolean(false),
+            "null" => Token::Null,
+            "if" => Token::If,
+            "else" => Token::Else,
+            "for" => Token::ForLoop,
+            "loop" => Token::Loop,
+            "break" => Token::Break,
+            "continue" => Token::Continue,
+            "while" => Token::While,
+            "new" => Token::New,
+            "this" => Token::This,
+            "typeof" => Token::Typeof,
+            "instanceof" => Token::Instanceof,
+            "in" => Token::In,
+            "void" => Token::Void,
+            "delete" => Token::Delete,
+            "throw" => Token::Throw,
+            "try" => Token::Try,
+            "catch" => Token::Catch,
+            "finally" => Token::Finally,
+            "yield" => Token::Yield,
+            "await" => Token::Await,
+            _ => Token::Identifier(ident),
+        }
+    }
+
+    fn read_float(&mut self) -> Result<Token, JsError> {
+        if self.peek(0) == Some('0') {
+            let radix = match self.peek(1) {
+                Some('x' | 'X') => Some(16),
+                Some('o' | 'O') => Some(8),
+                Some('b' | 'B') => Some(2),
+                _ => None,
+            };
+
+            if let Some(radix) = radix {
+                self.pos += 2;
+                let start = self.pos;
+
+                while matches!(self.peek(0), Some(c) if c.is_digit(radix)) {
+                    self.pos += 1;
+                }
 
-                let next_token = self.next_token();
+                let digits: String = self.code[start..self.pos].iter().collect();
 
-                match next_token {
-                    Some(Token::If) => Some(Token::ElseIf),
-                    Some(Token::BraceOpen) => {
-                        self.pos -= 1;
-                        Some(Token::Else)
-                    },
-                    _ => panic!("Unexpected token")
+                if digits.is_empty() {
+                    return Err(self.error("Invalid or unexpected token".to_string()));
                 }
-            },
-            _ => Some(Token::Identifier(ident.to_string())),
+
+                let value = digits
+                    .chars()
+                    .fold(0.0, |acc, c| acc * radix as f64 + c.to_digit(radix).unwrap() as f64);
+
+                return Ok(Token::Float(value));
+            }
         }
-    }
 
-    fn read_float(&mut self) -> Option<Token> {
         let start = self.pos;
-        while self.pos < self.code.len() {
-            let c = self.code.chars().nth(self.pos)?;
 
-            if !c.is_numeric() {
-                break;
-            }
+        while matches!(self.peek(0), Some('0'..='9')) {
+            self.pos += 1;
+        }
 
+        if self.peek(0) == Some('.') {
             self.pos += 1;
+
+            while matches!(self.peek(0), Some('0'..='9')) {
+                self.pos += 1;
+            }
         }
 
-        let num = &self.code[start..self.pos];
-        Some(Token::Float(num.parse().unwrap()))
-    }
+        if matches!(self.peek(0), Some('e' | 'E')) {
+            let sign = usize::from(matches!(self.peek(1), Some('+' | '-')));
 
-    fn read_string_literal(&mut self, delimiter: char) -> Option<Token> {
-        let start: usize = self.pos;
+            if matches!(self.peek(1 + sign), Some('0'..='9')) {
+                self.pos += 1 + sign;
 
+                while matches!(self.peek(0), Some('0'..='9')) {
+                    self.pos += 1;
+                }
+            }
+        }
+
+        if matches!(self.peek(0), Some(c) if Self::is_identifier_start(c)) {
+            return Err(self.error("Invalid or unexpected token".to_string()));
+        }
+
+        let num: String = self.code[start..self.pos].iter().collect();
+
+        match num.parse() {
+            Ok(value) => Ok(Token::Float(value)),
+            Err(_) => Err(self.error(format!("Invalid number literal {}", num))),
+        }
+    }
+
+    fn read_string_literal(&mut self, delimiter: char) -> Result<Token, JsError> {
         // Skip the opening quote
         self.pos += 1;
 
-        while self.pos < self.code.len() {
-            let c = self.code.chars().nth(self.pos)?;
+        // code units rather than a `String`, which can't hold the lone surrogates escapes can make
+        let mut literal: Vec<u16> = Vec::new();
+        let push = |literal: &mut Vec<u16>, c: char| literal.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+
+        loop {
+            let c = match self.peek(0) {
+                Some('\n') | None => return Err(self.error("Invalid or unexpected token".to_string())),
+                Some(c) => c,
+            };
+
+            self.pos += 1;
 
             if c == delimiter {
                 break;
             }
 
+            if c != '\\' {
+                push(&mut literal, c);
+                continue;
+            }
+
+            let escaped = self.peek(0).ok_or_else(|| self.error("Invalid or unexpected token".to_string()))?;
             self.pos += 1;
+
+            match escaped {
+                'n' => push(&mut literal, '\n'),
+                't' => push(&mut literal, '\t'),
+                'r' => push(&mut literal, '\r'),
+                'b' => push(&mut literal, '\u{8}'),
+                'f' => push(&mut literal, '\u{c}'),
+                'v' => push(&mut literal, '\u{b}'),
+                '0' if !matches!(self.peek(0), Some('0'..='9')) => push(&mut literal, '\0'),
+                'x' => {
+                    let code = self.read_hex_digits(2)?;
+                    push(&mut literal, char::from_u32(code).unwrap_or('\u{fffd}'));
+                }
+                'u' => {
+                    match self.read_unicode_escape()? {
+                        code @ 0xD800..=0xDFFF => literal.push(code as u16),
+                        code => push(&mut literal, char::from_u32(code).unwrap_or('\u{fffd}')),
+                    }
+                }
+                '\r' => {
+                    if self.peek(0) == Some('\n') {
+                        self.pos += 1;
+                    }
+                }
+                '\n' | '\u{2028}' | '\u{2029}' => (),
+                other => push(&mut literal, other),
+            }
         }
 
-        let literal = &self.code[start + 1..self.pos];
+        Ok(Token::StringLiteral(JsString::from_utf16(&literal)))
+    }
 
-        // Skip the closing quote
+    /// Reads a regular expression literal such as `/[/]+/g` into its pattern and flags, which are
+    /// checked when it's parsed.
+    fn read_regex_literal(&mut self) -> Result<Token, JsError> {
+        // Skip the opening slash
         self.pos += 1;
 
-        Some(Token::StringLiteral(literal.to_string()))
+        let mut pattern = String::new();
+        let mut in_class = false;
+
+        loop {
+            let c = match self.peek(0) {
+                Some('\n' | '\r' | '\u{2028}' | '\u{2029}') | None => {
+                    return Err(self.error("Invalid regular expression: missing /".to_string()));
+                }
+                Some(c) => c,
+            };
+
+            self.pos += 1;
+
+            match c {
+                '/' if !in_class => break,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '\\' => match self.peek(0) {
+                    Some('\n' | '\r' | '\u{2028}' | '\u{2029}') | None => {
+                        return Err(self.error("Invalid regular expression: missing /".to_string()));
+                    }
+                    Some(escaped) => {
+                        pattern.push(c);
+                        pattern.push(escaped);
+                        self.pos += 1;
+                        continue;
+                    }
+                },
+                _ => (),
+            }
+
+            pattern.push(c);
+        }
+
+        let mut flags = String::new();
+
+        while let Some(c) = self.peek(0).filter(|c| Self::is_valid_variable_char(*c)) {
+            flags.push(c);
+            self.pos += 1;
+        }
+
+        Ok(Token::RegExp(pattern, flags))
     }
 
-    fn read_comment(&mut self) -> Option<Token> {
-        let start = self.pos;
+    fn read_hex_digits(&mut self, count: usize) -> Result<u32, JsError> {
+        let mut value = 0;
+
+        for _ in 0..count {
+            let digit = self
+                .peek(0)
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("Invalid hexadecimal escape sequence".to_string()))?;
+
+            value = value * 16 + digit;
+            self.pos += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn read_unicode_escape(&mut self) -> Result<u32, JsError> {
+        if self.peek(0) != Some('{') {
+            let code = self.read_hex_digits(4)?;
+
+            // join an escaped surrogate pair back into a single code point
+            if (0xD800..0xDC00).contains(&code) && self.peek(0) == Some('\\') && self.peek(1) == Some('u') {
+                let saved = self.pos;
+                self.pos += 2;
+
+                if let Ok(low) = self.read_hex_digits(4) {
+                    if (0xDC00..0xE000).contains(&low) {
+                        return Ok(0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00));
+                    }
+                }
+
+                self.pos = saved;
+            }
+
+            return Ok(code);
+        }
+
         self.pos += 1;
+        let start = self.pos;
+        let mut value: u32 = 0;
 
-        while self.pos < self.code.len() {
-            let c = self.code.chars().nth(self.pos)?;
+        while let Some(c) = self.peek(0) {
+            self.pos += 1;
 
+            match c {
+                '}' if self.pos - 1 == start => break,
+                '}' if value > 0x10FFFF => return Err(self.error("Undefined Unicode code-point".to_string())),
+                '}' => return Ok(value),
+                _ => (),
+            }
+
+            match c.to_digit(16) {
+                Some(digit) if value <= 0x10FFFF => value = value * 16 + digit,
+                _ => break,
+            }
+        }
+
+        Err(self.error("Invalid Unicode escape sequence".to_string()))
+    }
+
+    fn skip_line_comment(&mut self) {
+        while let Some(c) = self.peek(0) {
             if c == '\n' {
                 break;
             }
 
             self.pos += 1;
         }
+    }
+
+    fn skip_block_comment(&mut self) -> Result<(), JsError> {
+        self.pos += 2;
+
+        while self.pos < self.code.len() {
+            if self.peek(0) == Some('*') && self.peek(1) == Some('/') {
+                self.pos += 2;
+                return Ok(());
+            }
+
+            // a comment spanning lines counts as a line terminator
+            if matches!(self.peek(0), Some('\n' | '\r' | '\u{2028}' | '\u{2029}')) {
+                self.line_break = true;
+            }
+
+            self.pos += 1;
+        }
 
-        let comment = &self.code[start..self.pos];
-        Some(Token::Comment(comment.to_string()))
+        Err(self.error("Invalid or unexpected token".to_string()))
     }
-}
\ No newline at end of file
+
+    fn error(&self, message: String) -> JsError {
+        JsError::Native(ErrorKind::SyntaxError, message)
+    }
+}
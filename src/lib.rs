@@ -1,15 +1,25 @@
-mod parser;
-mod types;
-mod interpreter;
-mod lexer;
-mod scope;
-mod runtime;
+pub mod parser;
+pub mod types;
+pub mod interpreter;
+pub mod lexer;
+pub mod scope;
+pub mod runtime;
+pub mod context;
+pub mod object;
+pub mod builtins;
+pub mod error;
+pub mod convert;
+pub mod compiler;
+pub mod optimizer;
+pub mod regexp;
 
-use runtime::Runtime;
+pub use context::Context;
+pub use runtime::Runtime;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
     #[test]
     fn assignment() {
         let code = r#"
@@ -113,8 +123,28 @@ mod tests {
             return main(10, 20);
         "#;
 
+        let mut runtime = Runtime::new(code);
+        let _output = runtime.execute();
+    }
+
+    #[test]
+    fn nested_function_result () {
+        let code: &str = r#"
+            function add (a, b) {
+                return a + b;
+            }
+
+            function main (x, y) {
+                return add(x, y);
+            }
+
+            return main(10, 20);
+        "#;
+
         let mut runtime = Runtime::new(code);
         let output = runtime.execute();
+
+        assert_eq!(output, types::Value::Float(30.0));
     }
 
     #[test]
@@ -231,4 +261,2144 @@ mod tests {
 
         assert_eq!(output, types::Value::Float(10.0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn throw_and_catch () {
+        let code = r#"
+            try {
+                throw 42;
+            } catch (e) {
+                return e + 1;
+            }
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::Float(43.0));
+    }
+
+    #[test]
+    fn catch_error_object () {
+        let code = r#"
+            try {
+                throw new TypeError("bad value");
+            } catch (e) {
+                if (e instanceof TypeError && e instanceof Error) {
+                    return e.name + ": " + e.message;
+                }
+            }
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("TypeError: bad value".into()));
+    }
+
+    #[test]
+    fn finally_runs_after_return () {
+        let code = r#"
+            let steps = "";
+
+            function run () {
+                try {
+                    steps = steps + "try,";
+                    return 1;
+                } finally {
+                    steps = steps + "finally";
+                }
+            }
+
+            return run() + ":" + steps;
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("1:try,finally".into()));
+    }
+
+    #[test]
+    fn optional_catch_binding () {
+        let code = r#"
+            let result = "not caught";
+
+            try {
+                throw "anything";
+            } catch {
+                result = "caught";
+            }
+
+            return result;
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("caught".into()));
+    }
+
+    #[test]
+    fn runtime_errors_are_catchable () {
+        let code = r#"
+            let kinds = "";
+
+            try {
+                missing();
+            } catch (e) {
+                kinds = kinds + e.name + ",";
+            }
+
+            try {
+                let a = null;
+                a.b;
+            } catch (e) {
+                kinds = kinds + e.message + ",";
+            }
+
+            try {
+                let b = 1;
+                b();
+            } catch (e) {
+                kinds = kinds + e.message;
+            }
+
+            return kinds;
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(
+            output,
+            types::Value::StringLiteral("ReferenceError,Cannot read properties of null (reading 'b'),b is not a function".into())
+        );
+    }
+
+    #[test]
+    fn error_stack () {
+        let code = r#"
+            function inner () {
+                throw Error("boom");
+            }
+
+            function outer () {
+                inner();
+            }
+
+            try {
+                outer();
+            } catch (e) {
+                return e.stack;
+            }
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(
+            output,
+            types::Value::StringLiteral("Error: boom\n    at inner\n    at outer\n    at <anonymous>".into())
+        );
+    }
+
+    #[test]
+    fn uncaught_exception () {
+        let code = r#"
+            let limit = 10;
+            throw new RangeError("limit is " + limit);
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let error = runtime.run().unwrap_err();
+
+        assert_eq!(error.to_string(), "RangeError: limit is 10");
+    }
+
+    #[test]
+    fn for_of_iterables () {
+        let code = r#"
+            let parts = [];
+
+            for (const n of [1, 2, 3]) {
+                parts.push(n * 2);
+            }
+
+            for (const c of "a😀") {
+                parts.push(c);
+            }
+
+            for (const entry of new Map([["k", "v"]])) {
+                parts.push(entry[0] + entry[1]);
+            }
+
+            for (const v of new Set([1, 1, 2])) {
+                parts.push(v);
+            }
+
+            return parts.join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("2,4,6,a,😀,kv,1,2".into()));
+    }
+
+    #[test]
+    fn iterator_protocol () {
+        let code = r#"
+            let steps = [];
+
+            let counter = {
+                [Symbol.iterator]() {
+                    let i = 0;
+
+                    return {
+                        next() {
+                            i++;
+                            return { value: i, done: i > 10 };
+                        },
+                        return() {
+                            steps.push("return");
+                            return {};
+                        }
+                    };
+                }
+            };
+
+            for (const n of counter) {
+                if (n == 3) break;
+                if (n == 1) continue;
+                steps.push(n);
+            }
+
+            return steps.join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("2,return".into()));
+    }
+
+    #[test]
+    fn for_in_enumerates_prototype_chain () {
+        let code = r#"
+            function Point () {
+                this.x = 1;
+                this.y = 2;
+            }
+
+            Point.prototype.z = 3;
+
+            let keys = [];
+
+            for (const key in new Point()) {
+                keys.push(key);
+            }
+
+            return keys.join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("x,y,z".into()));
+    }
+
+    #[test]
+    fn not_iterable () {
+        let code = r#"
+            for (const x of 42) {}
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let error = runtime.run().unwrap_err();
+
+        assert_eq!(error.to_string(), "TypeError: 42 is not iterable");
+    }
+
+    #[test]
+    fn array_length () {
+        let code = r#"
+            let truncated = [1, 2, 3];
+            truncated.length = "1";
+
+            let far = [];
+            far[4294967294] = "last";
+
+            let sparse = [1];
+            sparse.length = 4294967295;
+            sparse[5000000] = 2;
+            let grown = sparse.length;
+            sparse.length = 3;
+
+            let errors = [2 ** 32, 1.5, -1].map(length => {
+                try { [].length = length; } catch (e) { return e.name; }
+            });
+
+            [truncated.length, truncated[1], far.length, far[4294967294], grown, sparse.length, sparse[5000000], Object.keys(sparse).length, errors.join(" ")].join()
+        "#;
+
+        let expected = "1,,4294967295,last,4294967295,3,,1,RangeError RangeError RangeError";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+        }
+    }
+
+    #[test]
+    fn sparse_array_methods () {
+        let code = r#"
+            let huge = [];
+            huge.length = 4294967295;
+            let out = [huge.pop(), huge.length];
+
+            huge[4000000000] = "far";
+            let visited = [];
+            huge.forEach((value, index) => visited.push(index));
+            out.push(huge.indexOf("far"), huge.lastIndexOf("far"), huge.includes(undefined), visited.join(" "), huge.reduce((a, b) => a + b));
+
+            let mapped = huge.map(value => value + "!");
+            out.push(mapped.length, mapped[4000000000], huge.push("end"), huge.length);
+
+            let wide = [1];
+            wide.length = 3000000;
+            wide[2000000] = 2;
+            out.push(wide.join(""), wide.join("-").length);
+
+            let like = { length: 3, 0: "a", 2: "c", join: Array.prototype.join, pop: Array.prototype.pop };
+            out.push(like.join("+"), like.pop(), like.length, 2 in like);
+
+            try { huge.join(); } catch (e) { out.push(e.name); }
+            out.join()
+        "#;
+
+        let expected = ",4294967294,4000000000,4000000000,true,4000000000,far,4294967294,far!,4294967295,4294967295,12,3000001,a++c,c,2,false,RangeError";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+        }
+    }
+
+    #[test]
+    fn destructuring () {
+        let code = r#"
+            let {a, b: [c, ...rest] = [], ...others} = {a: 1, b: [2, 3, 4], x: 5};
+
+            let x = 1, y = 2;
+            [x, y] = [y, x];
+
+            function describe ({name, age = 30}, [first]) {
+                return name + age + first;
+            }
+
+            let pairs = [];
+
+            for (const [key, value] of new Map([["k", 1]])) {
+                pairs.push(key + value);
+            }
+
+            return [a, c, rest.join("-"), Object.keys(others), x, y, describe({name: "n"}, "xy"), pairs].join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("1,2,3-4,x,2,1,n30x,k1".into()));
+    }
+
+    #[test]
+    fn var_is_function_scoped () {
+        let code = r#"
+            function f () {
+                if (true) {
+                    var x = 1;
+                }
+
+                for (var i = 0; i < 3; i++) {}
+
+                return x + i;
+            }
+
+            return f();
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::Float(4.0));
+    }
+
+    #[test]
+    fn destructure_null () {
+        let code = r#"
+            let {a} = null;
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let error = runtime.run().unwrap_err();
+
+        assert_eq!(error.to_string(), "TypeError: Cannot destructure 'null' as it is null.");
+    }
+
+    #[test]
+    fn default_and_rest_parameters () {
+        let code = r#"
+            function f (a, b = a * 2, ...rest) {
+                return [a, b, rest.length, arguments.length].join("-");
+            }
+
+            return [f(1), f(1, 5, 6, 7), f.length].join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("1-2-0-1,1-5-2-4,1".into()));
+    }
+
+    #[test]
+    fn spread () {
+        let code = r#"
+            function sum (x, y, z) {
+                return x + y + z;
+            }
+
+            let xs = [1, 2];
+            let merged = {...{a: 1, b: 2}, b: 3};
+
+            return [sum(...xs, 3), [0, ...xs, ...new Set([3])].join(""), merged.a + merged.b].join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("6,0123,4".into()));
+    }
+
+    #[test]
+    fn automatic_semicolon_insertion () {
+        let code = r#"
+            let a = 1
+            let b = a
+
+            function f () {
+                return
+                42
+            }
+
+            a
+            ++b
+
+            return [a, b, f()].join(",")
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("1,2,".into()));
+    }
+
+    #[test]
+    fn missing_semicolon () {
+        let code = r#"
+            let a = 1 let b = 2
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let error = runtime.run().unwrap_err();
+
+        assert_eq!(error.to_string(), "SyntaxError: Unexpected token Let");
+    }
+
+    #[test]
+    fn deep_nesting () {
+        let nest = |open: &str, inner: &str, close: &str, depth: usize| format!("{}{}{}", open.repeat(depth), inner, close.repeat(depth));
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+
+            assert_eq!(context.eval(&nest("(", "1", ")", 100)).unwrap(), types::Value::Float(1.0));
+            assert_eq!(context.eval(&nest("1 + ", "1", "", 400)).unwrap(), types::Value::Float(401.0));
+            assert_eq!(context.eval(&nest("{", "2", "}", 200)).unwrap(), types::Value::Float(2.0));
+
+            // far deeper than that is a SyntaxError rather than a stack overflow
+            for code in [
+                nest("(", "1", ")", 100000),
+                nest("-", "1", "", 100000),
+                nest("[", "", "]", 100000),
+                nest("{", "", "}", 100000),
+                nest("1 + ", "1", "", 100000),
+                nest("2 ** ", "1", "", 100000),
+                nest("", "a", ".b", 100000),
+                nest("", "f", "()", 100000),
+                nest("if (true) ", "1", "", 100000),
+                nest("() => ", "1", "", 100000),
+            ] {
+                assert_eq!(context.eval(&code).unwrap_err().to_string(), "SyntaxError: Code is too deeply nested");
+            }
+        }
+    }
+
+    #[test]
+    fn generators () {
+        let code = r#"
+            function* counter (start) {
+                let received = yield start;
+                yield received + 1;
+                return "done";
+            }
+
+            let it = counter(1);
+            let results = [it.next(), it.next(10), it.next(), it.next()];
+
+            return results.map(function (result) { return result.value + ":" + result.done; }).join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("1:false,11:false,done:true,undefined:true".into()));
+    }
+
+    #[test]
+    fn yield_delegation () {
+        let code = r#"
+            function* inner () {
+                yield 1;
+                yield 2;
+                return 3;
+            }
+
+            function* outer () {
+                let result = yield* inner();
+                yield result;
+                yield* [4, 5];
+            }
+
+            return [...outer()].join("");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("12345".into()));
+    }
+
+    #[test]
+    fn generator_return_and_throw () {
+        let code = r#"
+            let events = [];
+
+            function* resource () {
+                try {
+                    yield 1;
+                    yield 2;
+                } finally {
+                    events.push("closed");
+                }
+            }
+
+            let it = resource();
+            it.next();
+            let returned = it.return(9);
+
+            function* guarded () {
+                try {
+                    yield 1;
+                } catch (e) {
+                    yield "caught " + e;
+                }
+            }
+
+            let other = guarded();
+            other.next();
+
+            for (let x of resource()) {
+                break;
+            }
+
+            return [returned.value, returned.done, other.throw("boom").value, events.join(" ")].join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("9,true,caught boom,closed closed".into()));
+    }
+
+    #[test]
+    fn lazy_generator_pipeline () {
+        let code = r#"
+            function* naturals () {
+                let n = 0;
+
+                while (true) {
+                    yield n++;
+                }
+            }
+
+            function* map (iterable, f) {
+                for (const x of iterable) {
+                    yield f(x);
+                }
+            }
+
+            function* take (iterable, count) {
+                for (const x of iterable) {
+                    if (count-- <= 0) {
+                        return;
+                    }
+
+                    yield x;
+                }
+            }
+
+            let squares = map(naturals(), function (x) { return x * x; });
+
+            return [...take(squares, 5)].join(",");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let output = runtime.execute();
+
+        assert_eq!(output, types::Value::StringLiteral("0,1,4,9,16".into()));
+    }
+
+    #[test]
+    fn promise_job_ordering () {
+        let code = r#"
+            var events = [];
+
+            Promise.resolve(1)
+                .then(function (value) { events.push("a" + value); return value + 1; })
+                .then(function (value) { events.push("b" + value); });
+
+            Promise.resolve().then(function () { events.push("c"); });
+            new Promise(function (resolve) { events.push("executor"); resolve(); });
+            events.push("sync");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        runtime.execute();
+        runtime.run_event_loop().unwrap();
+
+        let output = runtime.eval("events.join()").unwrap();
+
+        assert_eq!(output, types::Value::StringLiteral("executor,sync,a1,c,b2".into()));
+    }
+
+    #[test]
+    fn promise_combinators () {
+        let code = r#"
+            var results = [];
+
+            Promise.all([1, Promise.resolve(2), new Promise(function (resolve) { resolve(3); })])
+                .then(function (values) { results.push("all " + values.join("")); });
+
+            Promise.allSettled([Promise.resolve(1), Promise.reject(2)])
+                .then(function (outcomes) { results.push("allSettled " + outcomes.map(function (o) { return o.status; }).join(" ")); });
+
+            Promise.race([new Promise(function () {}), Promise.resolve("fast")])
+                .then(function (value) { results.push("race " + value); });
+
+            Promise.any([Promise.reject(1), Promise.reject(2)])
+                .catch(function (error) { results.push(error.name + " " + error.errors.join("")); });
+
+            Promise.reject(new Error("failed"))
+                .finally(function () { results.push("finally"); })
+                .catch(function (error) { results.push("catch " + error.message); });
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        runtime.execute();
+        runtime.run_event_loop().unwrap();
+
+        let output = runtime.eval("results.sort().join()").unwrap();
+
+        assert_eq!(
+            output,
+            types::Value::StringLiteral("AggregateError 12,all 123,allSettled fulfilled rejected,catch failed,finally,race fast".into()),
+        );
+    }
+
+    #[test]
+    fn unhandled_rejections () {
+        let code = r#"
+            Promise.reject("ignored");
+
+            let handled = Promise.reject("handled later");
+            Promise.resolve().then(function () { handled.catch(function () {}); });
+
+            Promise.resolve().then(function () { throw "thrown in a reaction"; });
+        "#;
+
+        let rejections = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let reported = rejections.clone();
+
+        let mut runtime = Runtime::new(code);
+        runtime.on_unhandled_rejection(move |reason| reported.borrow_mut().push(reason.clone()));
+        runtime.execute();
+        runtime.run_event_loop().unwrap();
+
+        assert_eq!(*rejections.borrow(), vec![
+            types::Value::StringLiteral("ignored".into()),
+            types::Value::StringLiteral("thrown in a reaction".into()),
+        ]);
+    }
+
+    #[test]
+    fn arrow_functions () {
+        let code = r#"
+            const add = (a, b) => a + b;
+            const counter = { step: 2, run() { return [1, 2, 3].map(x => x * this.step); } };
+            function count() { return (() => arguments.length)(); }
+
+            [add(1, 2), counter.run().join(""), count(1, 2, 3), (() => ({ a: 4 }))().a].join()
+        "#;
+
+        let output = Runtime::new(code).execute();
+
+        assert_eq!(output, types::Value::StringLiteral("3,246,3,4".into()));
+    }
+
+    #[test]
+    fn named_function_expressions () {
+        let code = r#"
+            let g = 1;
+            const h = function g () { return typeof g; };
+            const method = { m () { return typeof m; } };
+
+            [
+                (function f (n) { return n ? f(n - 1) : "ok"; })(3),
+                h(),
+                g,
+                typeof f,
+                method.m(),
+                (function f () { var f = 2; return f; })(),
+                (function* gen () { yield typeof gen; })().next().value,
+            ].join()
+        "#;
+
+        let expected = types::Value::StringLiteral("ok,function,1,undefined,undefined,2,function".into());
+
+        for engine in [interpreter::Engine::TreeWalker, interpreter::Engine::Bytecode] {
+            let mut runtime = Runtime::new(code);
+            runtime.set_engine(engine);
+
+            assert_eq!(runtime.execute(), expected);
+        }
+    }
+
+    #[test]
+    fn async_functions () {
+        let code = r#"
+            var events = [];
+
+            async function double(x) {
+                events.push("start");
+                const value = await x;
+                events.push("resumed");
+                return value * 2;
+            }
+
+            const service = { async fetch(x) { return x + 1; } };
+            const fetchTwice = async x => (await service.fetch(x)) + (await service.fetch(x));
+            async function fails() { await null; throw new Error("failed"); }
+
+            double(Promise.resolve(2)).then(value => events.push("double " + value));
+            fetchTwice(1).then(value => events.push("fetched " + value));
+            fails().catch(error => events.push(error.message));
+            events.push("sync");
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        runtime.execute();
+        runtime.run_event_loop().unwrap();
+
+        let output = runtime.eval("events.join()").unwrap();
+
+        assert_eq!(output, types::Value::StringLiteral("start,sync,resumed,double 4,failed,fetched 4".into()));
+    }
+
+    #[test]
+    fn top_level_await () {
+        let code = r#"
+            const base = await Promise.resolve(40);
+            let result;
+
+            try {
+                await Promise.reject(2);
+            } catch (error) {
+                result = base + error;
+            }
+
+            result
+        "#;
+
+        let output = Runtime::new(code).execute();
+
+        assert_eq!(output, types::Value::Float(42.0));
+    }
+
+    #[test]
+    fn top_level_await_outcomes () {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let warnings = Rc::new(RefCell::new(Vec::new()));
+        let mut context = Context::new();
+
+        let sink = warnings.clone();
+        context.on_warning(move |warning| sink.borrow_mut().push(warning.to_string()));
+
+        // compiling the script reports what it refers to that nothing declares, as without await
+        let code = "function later () { return missing; } await Promise.resolve(1)";
+        assert_eq!(context.eval(code).unwrap(), types::Value::Float(1.0));
+        assert_eq!(*warnings.borrow(), vec!["missing is not defined".to_string()]);
+
+        // awaiting something that never settles is an error rather than a result
+        let error = context.eval("await new Promise(() => {}); 1").unwrap_err();
+        assert_eq!(error.to_string(), "Error: top-level await did not settle");
+    }
+
+    #[test]
+    fn for_await_of () {
+        let code = r#"
+            const source = {
+                [Symbol.asyncIterator]() {
+                    let i = 0;
+                    return { next() { i++; return Promise.resolve({ value: i, done: i > 3 }); } };
+                }
+            };
+
+            let seen = [];
+
+            for await (const value of source) {
+                seen.push(value);
+            }
+
+            // sync iterables work too, with the promises in them awaited
+            for await (const value of [4, Promise.resolve(5)]) {
+                seen.push(value);
+            }
+
+            seen.join()
+        "#;
+
+        let output = Runtime::new(code).execute();
+
+        assert_eq!(output, types::Value::StringLiteral("1,2,3,4,5".into()));
+    }
+
+    #[test]
+    fn async_generators () {
+        let code = r#"
+            let seen = [];
+
+            async function* count(limit) {
+                try {
+                    for (let i = 1; i <= limit; i++) {
+                        const received = yield await Promise.resolve(i);
+                        if (received) seen.push("received " + received);
+                    }
+                    return Promise.resolve("done");
+                } finally {
+                    seen.push("cleanup");
+                }
+            }
+
+            for await (const value of count(5)) {
+                seen.push(value);
+                if (value == 2) break;
+            }
+
+            // calls made before the first one settles wait their turn
+            const numbers = count(2);
+            const results = await Promise.all([numbers.next(), numbers.next("x"), numbers.next(), numbers.next()]);
+            seen.push(results.map(result => result.value + ":" + result.done).join(" "));
+
+            const source = { async *values() { yield Promise.resolve("awaited"); throw new Error("thrown"); } };
+            const values = source.values();
+            seen.push((await values.next()).value);
+            await values.next().catch(error => seen.push(error.message));
+
+            const stopped = count(3);
+            await stopped.next();
+            const early = await stopped.return("early");
+            seen.push(early.value + ":" + early.done);
+            await stopped.throw(new Error("after")).catch(error => seen.push(error.message));
+
+            seen.push(numbers[Symbol.asyncIterator]() === numbers, numbers[Symbol.toStringTag], typeof count.prototype.next);
+            seen.join()
+        "#;
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut runtime = Runtime::new(code);
+            runtime.set_engine(engine);
+
+            let expected = "1,2,cleanup,received x,cleanup,1:false 2:false done:true undefined:true,awaited,thrown,cleanup,early:true,after,true,AsyncGenerator,function";
+            assert_eq!(runtime.execute(), types::Value::StringLiteral(expected.into()));
+        }
+
+        let mut context = Context::new();
+        let error = context.eval("async function* f() { yield* [1]; }").unwrap_err();
+        assert!(error.to_string().contains("yield* is not supported in async generators"));
+    }
+
+    #[test]
+    fn timers () {
+        let code = r#"
+            var events = [];
+
+            setTimeout(function (name) { events.push(name); }, 20, "late");
+            setTimeout(function () { events.push("first"); }, 10);
+            setTimeout(function () { events.push("second"); }, 10);
+            clearTimeout(setTimeout(function () { events.push("cleared"); }, 5));
+
+            setTimeout(function () {
+                events.push("zero");
+                Promise.resolve().then(function () { events.push("microtask"); });
+            }, 0);
+
+            queueMicrotask(function () { events.push("queued"); });
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        runtime.set_clock(interpreter::VirtualClock::new());
+        runtime.execute();
+
+        runtime.advance_time(10).unwrap();
+        let output = runtime.eval("events.join()").unwrap();
+        assert_eq!(output, types::Value::StringLiteral("queued,zero,microtask,first,second".into()));
+
+        runtime.run_event_loop().unwrap();
+        let output = runtime.eval("events.join()").unwrap();
+        assert_eq!(output, types::Value::StringLiteral("queued,zero,microtask,first,second,late".into()));
+    }
+
+    #[test]
+    fn intervals () {
+        let code = r#"
+            var ticks = [];
+            let count = 0;
+
+            const id = setInterval(function () {
+                ticks.push(count);
+                if (++count == 3) clearInterval(id);
+            }, 100);
+
+            setTimeout(function () { ticks.push("timeout"); }, 150);
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        runtime.set_clock(interpreter::VirtualClock::new());
+        runtime.execute();
+        runtime.run_event_loop().unwrap();
+
+        let output = runtime.eval("ticks.join()").unwrap();
+
+        assert_eq!(output, types::Value::StringLiteral("0,timeout,1,2".into()));
+
+        // a delay of 0 waits a millisecond, so the interval fires once for each
+        runtime.eval("var zero = 0; const every = setInterval(() => zero++, 0);").unwrap();
+        runtime.advance_time(10).unwrap();
+
+        assert_eq!(runtime.eval("clearInterval(every); zero").unwrap(), types::Value::Float(10.0));
+    }
+
+    #[test]
+    fn awaiting_timers () {
+        let code = r#"
+            const sleep = ms => new Promise(resolve => setTimeout(resolve, ms));
+            let slept = 0;
+
+            for (const ms of [30, 20]) {
+                await sleep(ms);
+                slept += ms;
+            }
+
+            slept
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        runtime.set_clock(interpreter::VirtualClock::new());
+
+        assert_eq!(runtime.execute(), types::Value::Float(50.0));
+    }
+
+    #[test]
+    fn host_functions () {
+        let code = r#"
+            let caught;
+
+            try {
+                divide(1, 0);
+            } catch (error) {
+                caught = error instanceof RangeError && error.message;
+            }
+
+            [divide(6, 3), caught].join()
+        "#;
+
+        let mut runtime = Runtime::new(code);
+
+        runtime.register_function("divide", |interpreter, args| {
+            let a = interpreter.to_number(&builtins::argument(args, 0))?;
+            let b = interpreter.to_number(&builtins::argument(args, 1))?;
+
+            match b {
+                0.0 => Err(error::JsError::range_error("Division by zero")),
+                b => Ok(types::Value::Float(a / b)),
+            }
+        });
+
+        assert_eq!(runtime.execute(), types::Value::StringLiteral("2,Division by zero".into()));
+    }
+
+    #[test]
+    fn host_objects () {
+        let code = r#"
+            counter.increment();
+            counter.increment();
+            [counter.count, counter.name, counter.limits.max, Object.keys(counter).join(" ")].join()
+        "#;
+
+        let count = std::rc::Rc::new(std::cell::Cell::new(0.0));
+        let (incremented, read) = (count.clone(), count.clone());
+
+        let counter = context::HostObject::new()
+            .function("increment", move |_, _| {
+                incremented.set(incremented.get() + 1.0);
+                Ok(types::Value::None)
+            })
+            .getter("count", move |_| Ok(types::Value::Float(read.get())))
+            .property("name", types::Value::StringLiteral("clicks".into()))
+            .object("limits", context::HostObject::new().property("max", types::Value::Float(10.0)));
+
+        let mut runtime = Runtime::new(code);
+        runtime.register_object("counter", counter);
+
+        assert_eq!(runtime.execute(), types::Value::StringLiteral("2,clicks,10,name limits".into()));
+        assert_eq!(count.get(), 2.0);
+    }
+
+    /// Runs `code`, returning what it printed with `console` along with the level of each message.
+    fn console_output (code: &str) -> Vec<(interpreter::LogLevel, String)> {
+        let output = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let sink = output.clone();
+
+        let mut runtime = Runtime::new(code);
+        runtime.set_clock(interpreter::VirtualClock::new());
+        runtime.on_console(move |level, message| sink.borrow_mut().push((level, message.to_string())));
+        runtime.execute();
+        runtime.run_event_loop().unwrap();
+
+        output.take()
+    }
+
+    #[test]
+    fn console_log () {
+        let code = r#"
+            const log = "not a keyword";
+            const point = { x: 1, label: "origin", tags: ["a", "b"], nested: { deeper: { deepest: { x: 1 } } } };
+            point.self = point;
+
+            console.log(log, 42, -0, null, undefined);
+            console.log(point);
+            console.log("%s has %d items costing %f%%", "cart", "3", 9.5, [true]);
+            console.info(new Map([["key", function named () {}]]), new Set([1]), Promise.resolve(2));
+            console.warn([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]);
+            console.error(new RangeError("out of range"));
+        "#;
+
+        let expected = [
+            (interpreter::LogLevel::Log, "not a keyword 42 -0 null undefined"),
+            (interpreter::LogLevel::Log, "<ref *1> {\n  x: 1,\n  label: 'origin',\n  tags: [ 'a', 'b' ],\n  nested: { deeper: { deepest: [Object] } },\n  self: [Circular *1]\n}"),
+            (interpreter::LogLevel::Log, "cart has 3 items costing 9.5% [ true ]"),
+            (interpreter::LogLevel::Info, "Map(1) { 'key' => [Function: named] } Set(1) { 1 } Promise { 2 }"),
+            (interpreter::LogLevel::Warn, "[\n   1,  2,  3,  4,  5,  6,  7,\n   8,  9, 10, 11, 12, 13, 14,\n  15, 16, 17, 18, 19, 20\n]"),
+            (interpreter::LogLevel::Error, "RangeError: out of range\n    at <anonymous>"),
+        ];
+
+        let output = console_output(code);
+        let output: Vec<_> = output.iter().map(|(level, message)| (*level, message.as_str())).collect();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn console_methods () {
+        let code = r#"
+            console.group("request");
+            console.count();
+            console.count("retries");
+            console.count();
+            console.assert(1 + 1 == 2, "never shown");
+            console.assert(false, "expected %d", 2);
+            console.groupEnd();
+
+            console.time("fetch");
+            setTimeout(() => console.timeEnd("fetch"), 1500);
+            console.table([{ id: 1, name: "a" }, { id: 2 }]);
+        "#;
+
+        let expected = [
+            (interpreter::LogLevel::Log, "request"),
+            (interpreter::LogLevel::Log, "  default: 1"),
+            (interpreter::LogLevel::Log, "  retries: 1"),
+            (interpreter::LogLevel::Log, "  default: 2"),
+            (interpreter::LogLevel::Warn, "  Assertion failed: expected 2"),
+            (
+                interpreter::LogLevel::Log,
+                "┌─────────┬────┬──────┐\n│ (index) │ id │ name │\n├─────────┼────┼──────┤\n│ 0       │ 1  │ 'a'  │\n│ 1       │ 2  │      │\n└─────────┴────┴──────┘",
+            ),
+            (interpreter::LogLevel::Log, "fetch: 1.5s"),
+        ];
+
+        let output = console_output(code);
+        let output: Vec<_> = output.iter().map(|(level, message)| (*level, message.as_str())).collect();
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn value_conversions () {
+        use std::collections::HashMap;
+        use convert::{FromJs, IntoJs};
+
+        let code = r#"
+            const [name, scores] = entry;
+            const total = scores.reduce((sum, score) => sum + score, 0);
+            ({ name, total, missing: null, tags: { a: "x" } })
+        "#;
+
+        let mut runtime = Runtime::new(code);
+        let entry = runtime.to_js(("ada", vec![3u32, 9, 4])).unwrap();
+        runtime.set_global("entry", entry);
+
+        runtime.register_function("maybe_half", |interpreter, args| {
+            let number = Option::<i32>::from_js(interpreter, &builtins::argument(args, 0))?;
+            let half: Result<Option<f64>, error::JsError> = match number {
+                Some(number) if number % 2 == 0 => Ok(Some(number as f64 / 2.0)),
+                Some(number) => Err(error::JsError::range_error(format!("{} is odd", number))),
+                None => Ok(None),
+            };
+
+            half.into_js(interpreter)
+        });
+
+        let output = runtime.run().unwrap();
+
+        let object = runtime.from_js::<HashMap<String, types::Value>>(&output).unwrap();
+        assert_eq!(runtime.from_js::<String>(&object["name"]).unwrap(), "ada");
+        assert_eq!(runtime.from_js::<u8>(&object["total"]).unwrap(), 16);
+        assert_eq!(runtime.from_js::<Option<bool>>(&object["missing"]).unwrap(), None);
+        assert_eq!(runtime.from_js::<HashMap<String, char>>(&object["tags"]).unwrap(), HashMap::from([("a".to_string(), 'x')]));
+
+        let errors = [
+            runtime.from_js::<bool>(&object["name"]).unwrap_err(),
+            runtime.from_js::<i8>(&types::Value::Float(1.5)).unwrap_err(),
+            runtime.from_js::<(u8, String)>(&object["name"]).unwrap_err(),
+        ];
+
+        assert_eq!(errors.map(|error| error.to_string()), [
+            "TypeError: Expected a boolean, got string",
+            "RangeError: Expected an integer between -128 and 127, got 1.5",
+            "TypeError: Expected an array, got string",
+        ]);
+
+        let halves = runtime.eval(r#"
+            let odd;
+            try { maybe_half(3) } catch (error) { odd = error.message }
+            [maybe_half(8), maybe_half(), odd]
+        "#).unwrap();
+
+        let halves = runtime.from_js::<(Option<f64>, Option<f64>, String)>(&halves).unwrap();
+        assert_eq!(halves, (Some(4.0), None, "3 is odd".to_string()));
+    }
+
+    #[test]
+    fn integer_conversion_bounds () {
+        let mut runtime = Runtime::new("");
+        let two_to_63 = types::Value::Float(2f64.powi(63));
+        let two_to_64 = types::Value::Float(2f64.powi(64));
+
+        assert!(runtime.from_js::<i64>(&two_to_63).is_err());
+        assert!(runtime.from_js::<u64>(&two_to_64).is_err());
+        assert!(runtime.from_js::<usize>(&two_to_64).is_err());
+        assert_eq!(runtime.from_js::<u64>(&two_to_63).unwrap(), 1 << 63);
+        assert_eq!(runtime.from_js::<i64>(&types::Value::Float(-(2f64.powi(63)))).unwrap(), i64::MIN);
+        assert!(runtime.from_js::<i32>(&types::Value::Float(2147483648.0)).is_err());
+        assert_eq!(runtime.from_js::<i32>(&types::Value::Float(2147483647.0)).unwrap(), i32::MAX);
+
+        assert_eq!(runtime.to_js(1u64 << 53).unwrap(), types::Value::Float(2f64.powi(53)));
+        assert_eq!(runtime.to_js(i64::MIN).unwrap(), types::Value::Float(-(2f64.powi(63))));
+        assert!(runtime.to_js((1u64 << 53) + 1).unwrap_err().to_string().contains("RangeError"));
+        assert!(runtime.to_js(i64::MAX).is_err());
+        assert!(runtime.to_js(u64::MAX).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_conversions () {
+        use std::collections::BTreeMap;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        enum Role {
+            Admin,
+            Guest { until: u32 },
+        }
+
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct User {
+            name: String,
+            age: Option<u8>,
+            roles: Vec<Role>,
+            limits: BTreeMap<u32, f64>,
+        }
+
+        let mut runtime = Runtime::new(r#"
+            user.age = 37;
+            user.roles.push({ Guest: { until: 2030 } });
+            [user.roles[0], user.limits["10"], user]
+        "#);
+
+        let user = User { name: "ada".to_string(), age: None, roles: vec![Role::Admin], limits: BTreeMap::from([(10, 0.5)]) };
+        let value = runtime.to_js(convert::Serde(&user)).unwrap();
+        runtime.set_global("user", value);
+
+        let output = runtime.run().unwrap();
+        let convert::Serde((role, limit, user)) = runtime.from_js::<convert::Serde<(String, f64, User)>>(&output).unwrap();
+
+        assert_eq!((role.as_str(), limit), ("Admin", 0.5));
+        assert_eq!(user, User {
+            name: "ada".to_string(),
+            age: Some(37),
+            roles: vec![Role::Admin, Role::Guest { until: 2030 }],
+            limits: BTreeMap::from([(10, 0.5)]),
+        });
+
+        let output = runtime.eval("({ name: 1 })").unwrap();
+        let error = runtime.from_js::<convert::Serde<User>>(&output).unwrap_err();
+        assert_eq!(error.to_string(), "TypeError: invalid type: integer `1`, expected a string");
+    }
+
+    #[test]
+    fn contexts () {
+        let mut library = Context::new();
+
+        library.eval(r#"
+            let calls = 0;
+            Array.prototype.first = function () { return this[0]; };
+
+            function greet (name) {
+                calls++;
+                if (!name) throw new TypeError("name is required");
+                return prefix + name + " " + [calls].first();
+            }
+        "#).unwrap();
+
+        library.set_global("prefix", types::Value::StringLiteral("hello ".into()));
+
+        let greetings: Vec<_> = ["ada", "alan"].iter()
+            .map(|name| library.call_function("greet", &[types::Value::StringLiteral((*name).into())]).unwrap())
+            .collect();
+
+        assert_eq!(greetings, [
+            types::Value::StringLiteral("hello ada 1".into()),
+            types::Value::StringLiteral("hello alan 2".into()),
+        ]);
+
+        assert_eq!(library.call_function("greet", &[]).unwrap_err().to_string(), "TypeError: name is required");
+        assert_eq!(library.call_function("missing", &[]).unwrap_err().to_string(), "ReferenceError: missing is not defined");
+        assert_eq!(library.get_global("calls"), Some(types::Value::Float(3.0)));
+
+        // another context has globals and built-ins of its own
+        let mut other = Context::new();
+
+        assert_eq!(other.get_global("greet"), None);
+        assert_eq!(other.eval("typeof [].first").unwrap(), types::Value::StringLiteral("undefined".into()));
+        assert_eq!(library.eval("typeof [].first").unwrap(), types::Value::StringLiteral("function".into()));
+    }
+
+    #[test]
+    fn execution_limits () {
+        let mut runtime = Runtime::new(r#"
+            let steps = 0;
+            try { loop { steps++; } } catch (e) { steps = -1; } finally { steps = -2; }
+        "#);
+
+        // running out of fuel can't be caught, and stops the script for good
+        runtime.set_fuel(Some(1000));
+        assert_eq!(runtime.run().unwrap_err().to_string(), "Script ran out of fuel");
+        assert!(matches!(runtime.get_global("steps"), Some(types::Value::Float(steps)) if steps > 0.0));
+        assert_eq!(runtime.fuel(), Some(0));
+
+        runtime.set_fuel(None);
+        runtime.set_time_limit(Some(Duration::from_millis(50)));
+        assert_eq!(runtime.eval("setTimeout(() => console.log('never'), 0); while (true) {}").unwrap_err().to_string(), "Script exceeded its time limit");
+
+        // the timer was dropped along with the script, and the next call gets a fresh deadline
+        runtime.run_event_loop().unwrap();
+        assert_eq!(runtime.eval("1 + 1").unwrap(), types::Value::Float(2.0));
+        runtime.set_time_limit(None);
+
+        // recursion throws a RangeError the script can catch
+        runtime.set_max_call_depth(100);
+        assert_eq!(runtime.eval(r#"
+            function depth (n) { return depth(n + 1); }
+            let message;
+            try { depth(0); } catch (e) { message = e.name + ": " + e.message; }
+            message
+        "#).unwrap(), types::Value::StringLiteral("RangeError: Maximum call stack size exceeded".into()));
+
+        // within the limit, deep recursion doesn't overflow the Rust stack
+        runtime.set_max_call_depth(1500);
+        assert_eq!(runtime.eval("function count (n) { return n == 0 ? 0 : 1 + count(n - 1); } count(1400)").unwrap(), types::Value::Float(1400.0));
+
+        assert_eq!(runtime.call_function("depth", &[types::Value::Float(0.0)]).unwrap_err().to_string(), "RangeError: Maximum call stack size exceeded");
+
+        // another thread can interrupt a script that would never finish
+        let handle = runtime.interrupt_handle();
+        let interrupter = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            handle.interrupt();
+        });
+
+        assert_eq!(runtime.eval("async function spin () { while (true) await null; } spin()").unwrap_err().to_string(), "Script was interrupted");
+        interrupter.join().unwrap();
+
+        assert_eq!(runtime.eval("'still usable'").unwrap(), types::Value::StringLiteral("still usable".into()));
+    }
+
+    #[test]
+    fn native_time_limits () {
+        // builtins that go through whole arrays and strings count a step for each element
+        let slow = [
+            "let a = new Array(1e6).fill(1); while (true) a.indexOf(2);",
+            "let a = new Array(1e6).fill(1); while (true) a.reverse();",
+            "let s = 'ab'.repeat(5e5); while (true) s.split('');",
+            "let s = 'ab'.repeat(5e5); while (true) s.indexOf('c');",
+            "let s = 'a'.repeat(30) + 'b', r = /^(a|a)*$/; while (true) r.test(s);",
+        ];
+
+        for code in slow {
+            let mut runtime = Runtime::new("");
+            runtime.set_time_limit(Some(Duration::from_millis(100)));
+
+            let start = std::time::Instant::now();
+            assert_eq!(runtime.eval(code).unwrap_err().to_string(), "Script exceeded its time limit");
+            assert!(start.elapsed() < Duration::from_secs(2), "{:?} for {}", start.elapsed(), code);
+        }
+
+        // and so use up fuel as they go
+        let mut runtime = Runtime::new("");
+        runtime.set_fuel(Some(100_000));
+        assert_eq!(runtime.eval("let a = new Array(1e4).fill(0); for (let i = 0; i < 100; i++) a.indexOf(1);").unwrap_err().to_string(), "Script ran out of fuel");
+    }
+
+    #[test]
+    fn long_native_calls () {
+        // a single call that would run for minutes still stops at the deadline
+        let slow = [
+            "'a'.repeat(1e6).indexOf('a'.repeat(1e5) + 'b')",
+            "'a'.repeat(1e6).lastIndexOf('a'.repeat(1e5) + 'b')",
+            "'a'.repeat(1e6).split('a'.repeat(1e5) + 'b')",
+            "let o = { length: 2e6, fill: [].fill }; o.fill(0)",
+        ];
+
+        for code in slow {
+            let mut runtime = Runtime::new("");
+            runtime.set_time_limit(Some(Duration::from_millis(100)));
+
+            let start = std::time::Instant::now();
+            assert_eq!(runtime.eval(code).unwrap_err().to_string(), "Script exceeded its time limit");
+            assert!(start.elapsed() < Duration::from_secs(2), "{:?} for {}", start.elapsed(), code);
+        }
+
+        // and so does one interrupted from another thread
+        let mut runtime = Runtime::new("");
+        let handle = runtime.interrupt_handle();
+        let interrupter = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            handle.interrupt();
+        });
+
+        assert_eq!(runtime.eval("'a'.repeat(1e6).includes('a'.repeat(1e5) + 'b')").unwrap_err().to_string(), "Script was interrupted");
+        interrupter.join().unwrap();
+    }
+
+    #[test]
+    fn idle_interrupts () {
+        let mut runtime = Runtime::new("");
+
+        // an interrupt while no script is running doesn't stop the next one
+        runtime.interrupt_handle().interrupt();
+        assert_eq!(runtime.eval("1 + 1").unwrap(), types::Value::Float(2.0));
+    }
+
+    #[test]
+    fn memory_limits () {
+        let mut runtime = Runtime::new("let s = 'x'; while (true) s = s + s;");
+        let baseline = runtime.memory_usage();
+
+        // by default going over the limit stops the script
+        runtime.set_memory_limit(Some(1 << 20));
+        assert_eq!(runtime.run().unwrap_err().to_string(), "Script ran out of memory");
+
+        let usage = runtime.memory_usage();
+        assert!(usage > baseline + (1 << 18) && usage <= 1 << 20, "{} bytes in use", usage);
+
+        // dropping what's held frees it up again
+        runtime.eval("s = ''").unwrap();
+        assert!(runtime.memory_usage() < baseline + 1024);
+
+        // or it can throw an error scripts can catch
+        runtime.set_out_of_memory(interpreter::OutOfMemory::Throw);
+        assert_eq!(runtime.eval(r#"
+            let list = [];
+            let message;
+            try {
+                while (true) list.push({ name: "item " + list.length });
+            } catch (e) {
+                list = null;
+                message = e.name + ": " + e.message;
+            }
+            message
+        "#).unwrap(), types::Value::StringLiteral("RangeError: Out of memory".into()));
+
+        // garbage doesn't count against the limit
+        assert_eq!(runtime.eval("let total = 0; for (let i = 0; i < 20000; i++) total += [i, { i }, 'item ' + i].length; total").unwrap(), types::Value::Float(60000.0));
+    }
+
+    #[test]
+    fn shared_string_memory () {
+        let mut runtime = Runtime::new("");
+        runtime.set_memory_limit(Some(1 << 22));
+        runtime.set_out_of_memory(interpreter::OutOfMemory::Throw);
+
+        // a string held in many places is only counted once
+        let code = r#"
+            let big = 'x'.repeat(1 << 20);
+            let list = [], holder = {}, map = new Map(), closures = [];
+
+            for (let i = 0; i < 200; i++) {
+                list.push(big);
+                holder['copy' + i] = big;
+                map.set(i, big);
+                const copy = big;
+                closures.push(() => copy);
+            }
+
+            list.length + map.size + closures.length
+        "#;
+
+        assert_eq!(runtime.eval(code).unwrap(), types::Value::Float(600.0));
+        assert!(runtime.memory_usage() < 1 << 21, "{} bytes in use", runtime.memory_usage());
+    }
+
+    #[test]
+    fn string_indexing () {
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+
+            // indexing doesn't count its way through the string, whatever it holds
+            let code = r#"
+                let sum = 0;
+                for (const text of ['a'.repeat(2e5), 'é'.repeat(1e5) + '😀'.repeat(5e4)]) {
+                    for (let i = 0; i < text.length; i++) sum += text.charCodeAt(i) > 0x7f;
+                }
+                sum + ',' + '😀x'.codePointAt(0) + ',' + '\u{10FFFF}'.length
+            "#;
+
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral("200000,128512,2".into()));
+
+            for code in [r#""\u{110000}""#, r#""\u{FFFFFFFFF}""#, r#""\u{}""#] {
+                assert!(context.eval(code).unwrap_err().to_string().starts_with("SyntaxError"), "{code}");
+            }
+        }
+    }
+
+    #[test]
+    fn array_memory_limits () {
+        let growing = [
+            "for (let i = 0; i < 50; i++) { let a = []; a[1048000] = 1; all.push(a); }",
+            "for (let i = 0; i < 50; i++) { let a = []; a.length = 1e6; all.push(a); }",
+            "let big = new Array(1e5).fill(0); while (true) all.push(all.length ? all[all.length - 1].concat(big) : big);",
+            "for (let i = 0; i < 50; i++) all.push(new Array(1e6).fill(0));",
+            "let big = []; big[99999] = 0; while (true) all.push(big.slice());",
+            "let big = []; big[99999] = 0; while (true) all.push(big.map(x => x));",
+            "let big = []; big[99999] = 0; while (true) all.push(Array.from(big));",
+            "let big = []; big[99999] = 0; while (true) { let copy = []; copy.push(0); copy.unshift(...big); all.push(copy); }",
+        ];
+
+        for code in growing {
+            let mut runtime = Runtime::new("");
+            runtime.set_memory_limit(Some(1 << 22));
+            runtime.set_out_of_memory(interpreter::OutOfMemory::Throw);
+
+            let code = format!("let all = []; let message; try {{ {} }} catch (e) {{ all = null; message = e.message; }} message", code);
+            assert_eq!(runtime.eval(&code).unwrap(), types::Value::StringLiteral("Out of memory".into()), "{}", code);
+            assert!(runtime.memory_usage() <= 1 << 22, "{} bytes in use after {}", runtime.memory_usage(), code);
+        }
+    }
+
+    #[test]
+    fn collection_memory_limits () {
+        let growing = [
+            "let set = new Set(); for (let i = 0; ; i++) set.add(i);",
+            "let map = new Map(); for (let i = 0; ; i++) map.set(i, i);",
+            "let big = Array.from({ length: 1e4 }, (_, i) => i); while (true) all.push(new Set(big));",
+            "let pairs = Array.from({ length: 1e4 }, (_, i) => [i, i]); let map = new Map(pairs); while (true) all.push(new Map(map));",
+            "let object = {}; for (let i = 0; i < 1e4; i++) object['key' + i] = i; while (true) all.push(Object.keys(object));",
+        ];
+
+        for code in growing {
+            let mut runtime = Runtime::new("");
+            runtime.set_memory_limit(Some(1 << 22));
+            runtime.set_out_of_memory(interpreter::OutOfMemory::Throw);
+
+            let code = format!("let all = []; let message; try {{ {} }} catch (e) {{ all = null; message = e.message; }} message", code);
+            assert_eq!(runtime.eval(&code).unwrap(), types::Value::StringLiteral("Out of memory".into()), "{}", code);
+            assert!(runtime.memory_usage() <= 1 << 22, "{} bytes in use after {}", runtime.memory_usage(), code);
+        }
+    }
+
+    #[test]
+    fn split_memory_limits () {
+        let growing = [
+            "let big = 'x'.repeat(1 << 20); all.push(big.split(''));",
+            "let big = 'x,'.repeat(1 << 19); all.push(big.split(','));",
+        ];
+
+        for code in growing {
+            let mut runtime = Runtime::new("");
+            runtime.set_memory_limit(Some(1 << 22));
+            runtime.set_out_of_memory(interpreter::OutOfMemory::Throw);
+
+            let code = format!("let all = []; let message; try {{ {} }} catch (e) {{ all = null; message = e.message; }} message", code);
+            assert_eq!(runtime.eval(&code).unwrap(), types::Value::StringLiteral("Out of memory".into()), "{}", code);
+            assert!(runtime.memory_usage() <= 1 << 22, "{} bytes in use after {}", runtime.memory_usage(), code);
+        }
+    }
+
+    #[test]
+    fn match_memory_limits () {
+        let growing = [
+            "let big = 'x'.repeat(1 << 20); all.push(big.match(/x/g));",
+            "let big = 'x,'.repeat(1 << 19); all.push(big.split(/,/));",
+            "let big = 'x,'.repeat(1 << 18); all.push(big.split(/(,)/));",
+        ];
+
+        for code in growing {
+            let mut runtime = Runtime::new("");
+            runtime.set_memory_limit(Some(1 << 22));
+            runtime.set_out_of_memory(interpreter::OutOfMemory::Throw);
+
+            let code = format!("let all = []; let message; try {{ {} }} catch (e) {{ all = null; message = e.message; }} message", code);
+            assert_eq!(runtime.eval(&code).unwrap(), types::Value::StringLiteral("Out of memory".into()), "{}", code);
+            assert!(runtime.memory_usage() <= 1 << 22, "{} bytes in use after {}", runtime.memory_usage(), code);
+        }
+    }
+
+    #[test]
+    fn garbage_collection () {
+        let mut runtime = Runtime::new(r#"
+            // each call leaves behind an object and a closure that refer to each other
+            function leak (i) {
+                let node = { i };
+                node.self = node;
+                node.get = () => node.i;
+            }
+
+            const kept = { list: [] };
+            kept.list.push(kept);
+            const counter = (() => { let count = 0; return () => ++count; })();
+            function* numbers () { let n = 0; while (true) yield n++; }
+            const iterator = numbers();
+            iterator.next();
+
+            for (let i = 0; i < 500; i++) leak(i);
+        "#);
+
+        runtime.run().unwrap();
+        let before = runtime.memory_usage();
+        let stats = runtime.gc();
+
+        assert!(stats.objects >= 1000 && stats.scopes >= 500, "{:?}", stats);
+        assert!(stats.bytes > 0 && runtime.memory_usage() <= before - stats.bytes);
+        assert_eq!(runtime.gc(), interpreter::GcStats::default());
+
+        // what is still reachable survives, along with the cycles it holds
+        assert_eq!(runtime.eval("[kept.list[0] === kept, counter(), counter(), iterator.next().value].join()").unwrap(), types::Value::StringLiteral("true,1,2,1".into()));
+
+        // garbage is collected on its own as well, which keeps cycles within the memory limit
+        runtime.set_memory_limit(Some(1 << 20));
+        runtime.eval("for (let i = 0; i < 20000; i++) leak(i);").unwrap();
+        assert!(runtime.gc_stats().objects > 30000);
+    }
+
+    #[test]
+    fn deep_chains_drop () {
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+
+            // each of these is freed one link at a time rather than by recursing down the chain
+            context.eval("let list = null; for (let i = 0; i < 100000; i++) list = { next: list }; list = null;").unwrap();
+            context.eval("let nested = []; for (let i = 0; i < 100000; i++) nested = [nested]; nested = null;").unwrap();
+            context.eval("let f = null; for (let i = 0; i < 100000; i++) { const g = f; f = () => g; } f = null;").unwrap();
+
+            // and so is whatever is still around when the context goes
+            context.eval("for (let i = 0; i < 100000; i++) list = { next: list };").unwrap();
+            assert_eq!(context.eval("list.next.next === null").unwrap(), types::Value::Boolean(false));
+            drop(context);
+        }
+    }
+
+    #[test]
+    fn bytecode_engine () {
+        let code = r#"
+            function fib (n) { return n < 2 ? n : fib(n - 1) + fib(n - 2); }
+
+            function describe (values) {
+                let out = [];
+
+                for (let i = 0; i < values.length; i++) {
+                    if (i == 1) continue;
+                    if (i == 4) break;
+
+                    try {
+                        if (values[i] === null) null.x;
+                        out.push(typeof values[i]);
+                    } catch (e) {
+                        out.push(e.name);
+                    }
+
+                    for (const key in { a: 1, b: 2 }) {
+                        if (key == "b") break;
+                    }
+                }
+
+                return out.join();
+            }
+
+            const point = { x: 1, y: 2 };
+            point.x += 10;
+            point["y"] ||= 5;
+            let [first, ...rest] = [fib(15), point.x, point.y];
+
+            describe([1, "a", null, {}, 5]) + " " + first + " " + rest;
+        "#;
+
+        let expected = types::Value::StringLiteral("number,TypeError,object 610 11,2".into());
+
+        for engine in [interpreter::Engine::TreeWalker, interpreter::Engine::Bytecode] {
+            let mut runtime = Runtime::new(code);
+            runtime.set_engine(engine);
+
+            assert_eq!(runtime.execute(), expected);
+            assert_eq!(runtime.eval("1; { let unused = 2; }").unwrap(), types::Value::None);
+            assert_eq!(runtime.eval("[1, 2, 3].map(x => x * 2).join()").unwrap(), types::Value::StringLiteral("2,4,6".into()));
+            assert_eq!(runtime.eval("undefinedFunction()").unwrap_err().to_string(), "ReferenceError: undefinedFunction is not defined");
+            assert_eq!(runtime.eval("point.missing()").unwrap_err().to_string(), "TypeError: point.missing is not a function");
+        }
+    }
+
+    #[test]
+    fn compiled_loops_and_finally () {
+        let code = r#"
+            let log = [];
+
+            function counted (n, fail) {
+                let i = 0;
+                return {
+                    [Symbol.iterator]() { return this; },
+                    next() {
+                        if (i === fail) throw "next";
+                        return i < n ? { value: i++, done: false } : { value: undefined, done: true };
+                    },
+                    return() { log.push("closed"); return {}; },
+                };
+            }
+
+            const later = [];
+            for (const x of [1, 2, 3]) later.push(() => x);
+            log.push(later.map(f => f()).join(""));
+
+            for (const x of counted(5)) {
+                if (x == 1) continue;
+                if (x == 3) break;
+                log.push(x);
+            }
+
+            for (let [a, b] of [[1, 2]]) log.push(a + b);
+
+            function first (iterable) {
+                for (const x of iterable) return x;
+            }
+
+            log.push(first(counted(3)));
+
+            try { for (const x of counted(3)) throw "body"; } catch (e) { log.push(e); }
+            try { for (const x of counted(3, 1)) ; } catch (e) { log.push(e); }
+
+            const object = { a: 1, b: 2, c: 3 };
+            for (const key in object) {
+                if (key == "a") delete object.b;
+                log.push(key);
+            }
+
+            var key;
+            for (key in "xyz") if (key == 1) break;
+            log.push(key);
+
+            function finished () {
+                let x = 1;
+                try { return x; } finally { x = 2; log.push("finally " + x); }
+            }
+
+            function overridden () {
+                try { throw "lost"; } finally { return "override"; }
+            }
+
+            log.push(finished(), overridden());
+
+            for (let i = 0; i < 3; i++) {
+                try {
+                    if (i == 0) continue;
+                    if (i == 2) break;
+                    log.push("try " + i);
+                } finally {
+                    log.push("after " + i);
+                }
+            }
+
+            try {
+                try { throw "inner"; } finally { log.push("inner finally"); }
+            } catch (e) {
+                log.push(e);
+            }
+
+            function nested () {
+                for (const x of counted(2)) {
+                    try {
+                        for (const y of counted(2)) return x + y;
+                    } finally {
+                        log.push("nested finally");
+                    }
+                }
+            }
+
+            log.push(nested());
+            log.join();
+        "#;
+
+        let expected = types::Value::StringLiteral(
+            "123,0,2,closed,3,closed,0,closed,body,next,a,c,1,finally 2,1,override,after 0,try 1,after 1,after 2,inner finally,inner,closed,nested finally,closed,0".into()
+        );
+
+        for engine in [interpreter::Engine::TreeWalker, interpreter::Engine::Bytecode] {
+            let mut runtime = Runtime::new(code);
+            runtime.set_engine(engine);
+
+            assert_eq!(runtime.execute(), expected);
+        }
+    }
+
+    #[test]
+    fn optimizer () {
+        use types::{DeclarationKind, Expr, Pattern, Stmt};
+
+        let code = r#"
+            let size = 2 * 3 + "px";
+            if (false) { var unused = 1; }
+            while (true) { break; size = ""; }
+            return size;
+            size += "!";
+        "#;
+
+        let expected = vec![
+            Stmt::Declaration(DeclarationKind::Let, vec![(Pattern::Identifier("size".into()), Some(Expr::StringLiteral("6px".into())))]),
+            Stmt::CodeBlock(vec![Stmt::Declaration(DeclarationKind::Var, vec![(Pattern::Identifier("unused".into()), None)])]),
+            Stmt::Loop(Box::new(Stmt::CodeBlock(vec![Stmt::Break]))),
+            Stmt::Return(Some(Expr::Identifier("size".into()))),
+        ];
+
+        let mut context = Context::new();
+        assert_eq!(context.parse_ast(code).unwrap(), expected);
+
+        // folding keeps what `this` is and which references are checked
+        let code = r#"
+            let o = { m () { return this === o; } };
+            1; if (false) var hoisted = 1;
+            let reference = () => { try { return typeof (true && missing); } catch (e) { return e.name; } };
+            [(0 || o.m)(), o.m(), reference(), typeof hoisted].join();
+        "#;
+
+        for optimize in [false, true] {
+            let mut runtime = Runtime::new(code);
+            runtime.set_optimize(optimize);
+            assert_eq!(runtime.execute(), types::Value::StringLiteral("false,true,ReferenceError,undefined".into()));
+        }
+    }
+
+    #[test]
+    fn scope_resolution () {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let code = r#"
+            let x = "global";
+
+            function counter () {
+                let count = 0;
+                return () => ++count;
+            }
+
+            function shadow () {
+                let out = [x];
+                { let x = "block"; out.push(x); }
+
+                // the outer binding until the inner one is declared, until the temporal dead zone
+                // on the roadmap makes this throw
+                { out.push(x); let x = "late"; out.push(x); }
+
+                for (let i = 0; i < 2; i++) { let x = i; out.push(x); }
+                try { throw "caught"; } catch (x) { out.push(x); }
+                return out.join();
+            }
+
+            function assign () {
+                const fixed = 1;
+                try { fixed = 2; } catch (e) { return e.name; }
+            }
+
+            const next = counter();
+            next(); next();
+            shadow() + " " + next() + " " + assign() + " " + typeof undeclared;
+        "#;
+
+        let warnings = Rc::new(RefCell::new(Vec::new()));
+        let mut context = Context::new();
+
+        let sink = warnings.clone();
+        context.on_warning(move |warning| sink.borrow_mut().push(warning.to_string()));
+
+        let expected = types::Value::StringLiteral("global,block,global,late,0,1,caught 3 TypeError undefined".into());
+        assert_eq!(context.eval(code).unwrap(), expected);
+        assert!(warnings.borrow().is_empty());
+
+        // globals defined by the host or earlier scripts resolve, anything else is reported
+        context.set_global("host", types::Value::Float(1.0));
+        assert_eq!(context.eval("function later () { return missing; } x + host").unwrap(), types::Value::StringLiteral("global1".into()));
+        assert_eq!(*warnings.borrow(), vec!["missing is not defined".to_string()]);
+    }
+
+    #[test]
+    fn shared_strings() {
+        use std::rc::Rc;
+        use types::{intern, Expr, Stmt};
+
+        // names are interned, so every mention of one is the same string
+        assert!(Rc::ptr_eq(&intern("total"), &intern("total")));
+
+        let mut context = Context::new();
+        let ast = context.parse_ast("total + total;").unwrap();
+        let Stmt::Expression(expr) = &ast[0] else { panic!("unexpected {:?}", ast) };
+        let Expr::Addition(left, right) = expr.as_ref() else { panic!("unexpected {:?}", ast) };
+        let (Expr::Identifier(left), Expr::Identifier(right)) = (left.as_ref(), right.as_ref()) else { panic!("unexpected {:?}", ast) };
+        assert!(Rc::ptr_eq(left, right));
+
+        // passing a string around shares it instead of copying it
+        let mut context = Context::new();
+        context.eval("let text = ''; for (let i = 0; i < 1000; i++) text += 'abc'; let same = text; function id (s) { return s; }").unwrap();
+        let (types::Value::StringLiteral(text), types::Value::StringLiteral(same)) = (context.eval("id(text)").unwrap(), context.eval("same").unwrap()) else { panic!("expected strings") };
+        assert!(Rc::ptr_eq(&text.to_rc_str(), &same.to_rc_str()));
+
+        assert_eq!(context.eval("[text.length, 'héllo😀'.length].join()").unwrap(), types::Value::StringLiteral("3000,7".into()));
+    }
+
+    #[test]
+    fn inline_caches() {
+        let code = r#"
+            function Point (x, y) { this.x = x; this.y = y; }
+            Point.prototype.sum = function () { return this.x + this.y; };
+
+            let total = 0;
+            for (let i = 0; i < 100; i++) {
+                let point = new Point(i, 1);
+                point.x = point.x * 2;
+                total += point.sum();
+            }
+            total;
+        "#;
+
+        let mut context = Context::new();
+        assert_eq!(context.eval(code).unwrap(), types::Value::Float(10000.0));
+
+        // objects built the same way share a shape, so after the first few accesses every one hits
+        let stats = context.cache_stats();
+        assert!(stats.hits > 500 && stats.misses < 20, "{:?}", stats);
+
+        // what the caches remember doesn't outlive changes to the objects
+        let code = r#"
+            function read (o) { return o.value; }
+            function write (o, value) { o.value = value; }
+
+            let out = [];
+            let shapes = [{ value: 1 }, { other: 0, value: 2 }, { value: 3, other: 0 }, { a: 0, b: 0, value: 4 }, { value: 5, z: 0 }];
+            for (let i = 0; i < 3; i++) for (const o of shapes) out.push(read(o));
+
+            function Base () {}
+            Base.prototype.value = "inherited";
+            let child = new Base();
+            out.push(read(child));
+            Base.prototype.value = "replaced";
+            out.push(read(child));
+            delete Base.prototype.value;
+            out.push(read(child));
+            Base.prototype.value = "again";
+            out.push(read(child));
+            child.value = "own";
+            out.push(read(child));
+
+            let counted = 0;
+            let accessor = { get value () { return ++counted; } };
+            read(accessor); read(accessor);
+            out.push(counted);
+
+            let target = { value: 0 };
+            write(target, 1);
+            write(target, 2);
+            delete target.value;
+            write(target, 3);
+            out.push(target.value);
+
+            let f = function named () {};
+            f.name = "changed";
+            f.name = "changed";
+            out.push(f.name);
+
+            let array = [1, 2];
+            out.push(read({ value: array.length }), array.length, (array.length = 1, array.length));
+
+            function resize (o) { o.length = 3; }
+            resize({}); resize({});
+            resize(array);
+            out.push(array.length, Object.keys(array).length);
+            out.join();
+        "#;
+
+        let expected = "1,2,3,4,5,1,2,3,4,5,1,2,3,4,5,inherited,replaced,,again,own,2,3,named,2,2,1,3,3";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+        }
+    }
+
+    #[test]
+    fn tail_calls () {
+        let code = r#"
+            "use strict";
+            const out = [];
+
+            function count (n, total) {
+                if (n == 0) return total;
+                return count(n - 1, total + 1);
+            }
+            out.push(count(100000, 0));
+
+            function even (n) { return n == 0 || odd(n - 1); }
+            function odd (n) { return n != 0 && even(n - 1); }
+            out.push(even(100000), odd(100001));
+
+            const down = (n) => n == 0 ? "arrow" : down(n - 1);
+            out.push(down(100000));
+
+            const counter = { n: 0, run (k) { if (k == 0) return this.n; this.n++; return this.run(k - 1); } };
+            out.push(counter.run(100000));
+
+            // a call that a try block is waiting on still takes a frame
+            function guarded (n) { try { return n == 0 ? 0 : guarded(n - 1); } catch (e) { throw e; } }
+            try { guarded(100000); } catch (e) { out.push(e.name); }
+
+            function fails (n) { if (n == 0) throw new Error("deep"); return fails(n - 1); }
+            try { fails(100000); } catch (e) { out.push(e.stack); }
+
+            out.join();
+        "#;
+
+        let expected = "100000,true,true,arrow,100000,RangeError,Error: deep\n    at fails\n    at <anonymous>";
+
+        // calls in sloppy functions keep their frames
+        let sloppy = "function count (n) { return n == 0 ? 0 : count(n - 1); } try { count(100000); } catch (e) { e.name }";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+            assert_eq!(context.eval(sloppy).unwrap(), types::Value::StringLiteral("RangeError".into()));
+
+            // the directive at the start of a function makes just that function strict
+            assert_eq!(context.eval("function local (n) { 'use strict'; return n == 0 ? 'done' : local(n - 1); } local(100000)").unwrap(), types::Value::StringLiteral("done".into()));
+        }
+    }
+
+    #[test]
+    fn math () {
+        let code = r#"
+            [
+                Math.floor(-1.5), Math.ceil(1.2), Math.round(2.5), Math.round(-2.5), 1 / Math.round(-0.2), Math.trunc(-4.7),
+                Math.sign(-3), Math.abs(-2), Math.min(3, 1, 2), Math.max(1, "5", 2), Math.max(), Math.min(1, NaN),
+                1 / Math.max(-0, 0), Math.pow(2, 10), Math.pow(1, Infinity), Math.sqrt(16), Math.cbrt(-27),
+                Math.hypot(3, 4), Math.hypot(NaN, -Infinity), Math.exp(0), Math.log(Math.E), Math.log2(8), Math.log10(1000),
+                Math.sin(0), Math.cos(Math.PI), Math.atan2(1, 1) == Math.PI / 4, Math.tanh(Infinity),
+                Math.clz32(1), Math.clz32(0), Math.imul(0xffffffff, 5), Math.fround(5.5), Math.fround(5.05) == 5.05,
+                Math.PI, Math.SQRT2 * Math.SQRT1_2, String(Math), Object.keys(Math).length, Math.max.length,
+            ].join()
+        "#;
+
+        let expected = "-2,2,3,-2,-Infinity,-4,-1,2,1,5,-Infinity,NaN,Infinity,1024,NaN,4,-3,5,Infinity,1,1,3,3,0,-1,true,1,31,32,-5,5.5,false,3.141592653589793,1.0000000000000002,[object Math],0,2";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+        }
+
+        // the constants can't be changed
+        let mut context = Context::new();
+        assert_eq!(context.eval("Math.PI = 3; Math.PI").unwrap(), types::Value::Float(std::f64::consts::PI));
+
+        // the same seed gives the same numbers, all in [0, 1)
+        let numbers = "var numbers = []; for (let i = 0; i < 1000; i++) numbers.push(Math.random()); numbers";
+        let mut runtime = Runtime::new(numbers);
+        let mut run = |seed| {
+            runtime.set_random_seed(seed);
+            let numbers = runtime.run().unwrap();
+            runtime.from_js::<Vec<f64>>(&numbers).unwrap()
+        };
+
+        let first = run(42);
+        assert_eq!(run(42), first);
+        assert_ne!(run(7), first);
+        assert!(first.iter().all(|number| (0.0..1.0).contains(number)));
+        assert!(first.iter().any(|number| *number < 0.1) && first.iter().any(|number| *number > 0.9));
+    }
+
+    #[test]
+    fn strings () {
+        let code = r#"
+            [
+                "😀".length, "😀".charCodeAt(0), "😀"[1] == "\uDE00", "😀".slice(0, 1) + "😀".slice(1) == "😀", "😀".codePointAt(0),
+                "\uD83D".length, String.fromCharCode(0xD83D, 0xDE00) == "😀", String.fromCharCode(0x1D800).length, [..."a😀\uD800"].length,
+                "abc".at(-1), "abc".charAt(5) == "", "abc".substring(2, 0), "abcb".slice(-2), "abcb".indexOf("b", 2), "abcb".lastIndexOf("b", 2),
+                "abc".includes("bc"), "abc".startsWith("bc", 1), "abc".endsWith("ab", 2), "a,b,,c".split(",").length, "a😀".split("").length,
+                "a,b,c".split(",", 2).join(" "), " \n hi﻿".trim().length, " hi ".trimEnd() + "|", "5".padStart(3, "0"), "ab".padEnd(5, "xy"),
+                "ab".repeat(3), "abcabc".replace("b", "[$&$`$'$$]"), "abcabc".replaceAll("b", (match, index) => index), "ab".replaceAll("", "-"),
+                "ß".toUpperCase(), "ÀB".toLowerCase(), "\uD800a".toUpperCase().charCodeAt(1), "é".normalize() == "é",
+                "é".normalize("NFD").length, "ﬁ²".normalize("NFKC"), "한".normalize("NFD").length, String.raw({ raw: ["a", "c"] }, "b", "x"),
+            ].join()
+        "#;
+
+        let expected = "2,55357,true,true,128512,1,true,1,3,c,true,ab,cb,3,1,true,true,true,4,3,a b,2, hi|,005,abxyx,ababab,a[bacabc$]cabc,a1ca4c,-a-b-,SS,àb,65,true,2,fi2,3,abc";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+        }
+
+        let mut context = Context::new();
+        assert!(context.eval("'ab'.repeat(-1)").unwrap_err().to_string().contains("RangeError"));
+        assert!(context.eval("'ab'.normalize('NFX')").unwrap_err().to_string().contains("RangeError"));
+    }
+
+    #[test]
+    fn normalize_forms() {
+        let code = r#"
+            [
+                "ṩ".normalize("NFD").length, "ǅ".normalize("NFKD").length, "ǅ".normalize("NFD").length, "ṩ".normalize() == "ṩ",
+                "Å".normalize().charCodeAt(0), "क़".normalize().length, "①".normalize("NFKC"), "각".normalize() == "각",
+            ].join()
+        "#;
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral("3,3,1,true,197,2,1,true".into()));
+        }
+    }
+
+    #[test]
+    fn regexp() {
+        let code = r#"
+            let a = 8, b = 2, c = 2, x = 4 / 2 / 1;
+            let r = /(\d+)/g;
+            let date = /(?<year>\d{4})-(?<month>\d\d)/d.exec("on 2024-05!");
+            let sticky = /a/y;
+
+            [
+                a / b / c, x, r.exec("a1b22")[1], r.lastIndex, r.exec("a1b22").index, r.exec("a1b22"), r.lastIndex,
+                /ab/gi.flags, /ab/gi.ignoreCase, String(new RegExp("a/b", "m")), new RegExp("").source, RegExp(r) === r,
+                date.groups.year, date.groups.month, date.index, date.indices[2].join("-"), date.indices.groups.year.join("-"),
+                "cost $42".match(/(?<=\$)\d+/)[0], /(a)\1/i.test("xaA"), /^(?:a+)*$/.test("aaaaaaaaab"),
+                sticky.test("ba"), (sticky.lastIndex = 1, sticky.test("ba")), sticky.lastIndex,
+                "a1b22c333".match(/\d+/g).join(" "), "abc".match(/x/g), [..."a1b22".matchAll(/\d+/g)].map(m => m[0] + "@" + m.index).join(" "),
+                "John Smith".replace(/(\w+)\s(\w+)/, "$2 $1"), "2024-05".replace(/(?<y>\d+)-(?<m>\d+)/, "$<m>/$<y>"),
+                "aaa".replace(/a/g, (match, index) => index), "abc".replace(/(?<l>b)/, (m, l, index, s, groups) => groups.l.toUpperCase()),
+                "abc".search(/c/), "abc".search(/x/), "a1b2c3".split(/(\d)/, 4).join(" "), "abc".split(/(?:)/).join(" "), "".split(/a*/).length,
+            ].join()
+        "#;
+
+        let expected = "2,2,1,2,3,,0,gi,true,/a\\/b/m,(?:),true,2024,05,3,8-10,3-7,42,true,false,false,true,2,1 22 333,,1@1 22@3,Smith John,05/2024,012,aBc,2,-1,a 1 b 2,a b c,0";
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral(expected.into()));
+        }
+
+        let mut context = Context::new();
+        assert!(context.eval("new RegExp('(')").unwrap_err().to_string().contains("SyntaxError"));
+        assert!(context.eval("new RegExp('a', 'gg')").unwrap_err().to_string().contains("SyntaxError"));
+        assert!(context.eval("/a{2,1}/").unwrap_err().to_string().contains("Invalid regular expression"));
+        assert!(context.eval("'a'.replaceAll(/a/, '')").unwrap_err().to_string().contains("TypeError"));
+        assert!(context.eval("'a'.startsWith(/a/)").unwrap_err().to_string().contains("TypeError"));
+    }
+
+    #[test]
+    fn regexp_after_blocks() {
+        let code = r#"
+            let s = "xa", out = [];
+            if (true) {}
+            /a/.test(s) && out.push("block");
+            for (const x of [s]) /x/.test(x) && out.push("loop");
+            if (s) /=a/.test("=a") && out.push("assign");
+            function f () {}
+            /a/g.flags && out.push("function");
+            out.push([1, 2][1] / 2, (4) / 2);
+            out.join()
+        "#;
+
+        for engine in [interpreter::Engine::Bytecode, interpreter::Engine::TreeWalker] {
+            let mut context = Context::new();
+            context.set_engine(engine);
+            assert_eq!(context.eval(code).unwrap(), types::Value::StringLiteral("block,loop,assign,function,1,2".into()));
+        }
+    }
+}
@@ -1,11 +1,4 @@
-mod interpreter;
-mod lexer;
-mod parser;
-mod runtime;
-mod scope;
-mod types;
-
-use runtime::Runtime;
+use js_compiler::runtime::Runtime;
 
 fn main() {
     // let code = r#"
@@ -17,7 +10,7 @@ fn main() {
     //         return fib(n - 1);
     //     }
 
-    //     log(fib(100))
+    //     console.log(fib(100))
     // "#;
 
     let mut runtime = Runtime::new("");
@@ -0,0 +1,711 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::{Rc, Weak};
+use crate::interpreter::{tear_down, AsyncGeneratorState, GeneratorState, Interpreter, Teardown};
+use crate::scope::Scope;
+use crate::regexp::Regex;
+use crate::types::{FunctionDef, JsString, Signal, Value};
+
+mod ordered_map;
+mod shape;
+
+pub use ordered_map::OrderedMap;
+pub use shape::Shape;
+
+pub type NativeFunction = Rc<dyn Fn(&mut Interpreter, &Value, &[Value]) -> Result<Value, Signal>>;
+
+/// Arrays grow to hold an element assigned past their end only up to this many holes, beyond
+/// that the element is stored as an ordinary property.
+const MAX_ARRAY_GAP: usize = 1 << 20;
+
+/// A script function together with the scope it closes over.
+#[derive(Clone)]
+pub struct Closure {
+    pub def: Rc<FunctionDef>,
+    pub scope: Rc<RefCell<Scope>>,
+}
+
+/// A unique value usable as a property key, compared by identity.
+#[derive(Clone)]
+pub struct Symbol(Rc<Option<String>>);
+
+impl Symbol {
+    pub fn new(description: Option<String>) -> Self {
+        Symbol(Rc::new(description))
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+
+    pub fn as_ptr(&self) -> *const Option<String> {
+        Rc::as_ptr(&self.0)
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ptr().hash(state);
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({})", self.description().unwrap_or_default())
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyKey {
+    String(Rc<str>),
+    Symbol(Symbol),
+}
+
+impl PropertyKey {
+    /// The array index this key names, if it is the canonical form of one.
+    pub fn as_array_index(&self) -> Option<usize> {
+        let PropertyKey::String(key) = self else {
+            return None;
+        };
+
+        if key.is_empty() || (key.len() > 1 && key.starts_with('0')) || !key.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        key.parse::<u32>().ok().filter(|index| *index != u32::MAX).map(|index| index as usize)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyKey::String(key) => Some(key),
+            PropertyKey::Symbol(_) => None,
+        }
+    }
+}
+
+impl From<&str> for PropertyKey {
+    fn from(key: &str) -> Self {
+        PropertyKey::String(key.into())
+    }
+}
+
+impl From<&String> for PropertyKey {
+    fn from(key: &String) -> Self {
+        PropertyKey::String(key.as_str().into())
+    }
+}
+
+impl From<String> for PropertyKey {
+    fn from(key: String) -> Self {
+        PropertyKey::String(key.into())
+    }
+}
+
+impl From<Rc<str>> for PropertyKey {
+    fn from(key: Rc<str>) -> Self {
+        PropertyKey::String(key)
+    }
+}
+
+impl From<&Rc<str>> for PropertyKey {
+    fn from(key: &Rc<str>) -> Self {
+        PropertyKey::String(key.clone())
+    }
+}
+
+impl From<&JsString> for PropertyKey {
+    fn from(key: &JsString) -> Self {
+        PropertyKey::String(key.to_rc_str())
+    }
+}
+
+impl From<usize> for PropertyKey {
+    fn from(index: usize) -> Self {
+        PropertyKey::String(index.to_string().into())
+    }
+}
+
+impl From<Symbol> for PropertyKey {
+    fn from(symbol: Symbol) -> Self {
+        PropertyKey::Symbol(symbol)
+    }
+}
+
+impl From<&PropertyKey> for PropertyKey {
+    fn from(key: &PropertyKey) -> Self {
+        key.clone()
+    }
+}
+
+impl fmt::Display for PropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyKey::String(key) => write!(f, "{}", key),
+            PropertyKey::Symbol(symbol) => write!(f, "{}", symbol),
+        }
+    }
+}
+
+/// Which part of each entry a built-in iterator produces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IterationKind {
+    Keys,
+    Values,
+    Entries,
+}
+
+/// Progress of one of the built-in iterators over arrays, strings, maps, sets and matches.
+#[derive(Clone)]
+pub enum IteratorState {
+    Array(Value, usize, IterationKind),
+    // where the next code point starts, see `JsString::code_point_from`
+    String(JsString, usize),
+    Map(ObjectRef, usize, IterationKind),
+    Set(ObjectRef, usize, IterationKind),
+    // the matches `matchAll` goes through: the regular expression, the string, and whether it's global
+    RegExp(ObjectRef, JsString, bool),
+    Done,
+}
+
+/// Whether a promise has settled yet, and how.
+#[derive(Clone)]
+pub enum PromiseState {
+    // the reactions registered by `then`, run once the promise settles
+    Pending(Vec<PromiseReaction>),
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// Callbacks registered with `then`, along with the promise that their result settles.
+#[derive(Clone)]
+pub struct PromiseReaction {
+    pub on_fulfilled: Value,
+    pub on_rejected: Value,
+    pub result: Option<ObjectRef>,
+}
+
+#[derive(Clone)]
+pub enum ObjectKind {
+    Ordinary,
+    Function(Closure),
+    NativeFunction(NativeFunction),
+    Error,
+    // the `arguments` object of a function call, an ordinary object apart from its tag
+    Arguments,
+    Array(Vec<Value>),
+    Map(OrderedMap),
+    Set(OrderedMap),
+    Iterator(IteratorState),
+    Generator(GeneratorState),
+    AsyncGenerator(AsyncGeneratorState),
+    RegExp(Rc<Regex>),
+    // the state and whether a handler was ever attached, to report unhandled rejections
+    Promise(PromiseState, bool),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    pub value: Value,
+    // accessor properties have a getter, a setter or both, and ignore `value` and `writable`
+    pub getter: Option<Value>,
+    pub setter: Option<Value>,
+    pub writable: bool,
+    pub enumerable: bool,
+    pub configurable: bool,
+}
+
+impl Property {
+    pub fn new(value: Value) -> Self {
+        Property { value, getter: None, setter: None, writable: true, enumerable: true, configurable: true }
+    }
+
+    /// Builtin methods and internal slots exposed as properties are not enumerable.
+    pub fn hidden(value: Value) -> Self {
+        Property { enumerable: false, ..Property::new(value) }
+    }
+
+    pub fn readonly(value: Value) -> Self {
+        Property { writable: false, enumerable: false, ..Property::new(value) }
+    }
+
+    pub fn accessor(getter: Option<Value>, setter: Option<Value>, enumerable: bool) -> Self {
+        Property { value: Value::None, getter, setter, writable: false, enumerable, configurable: true }
+    }
+
+    pub fn is_accessor(&self) -> bool {
+        self.getter.is_some() || self.setter.is_some()
+    }
+}
+
+/// Own properties in insertion order, with their keys kept in a shape.
+#[derive(Clone)]
+pub struct PropertyMap {
+    shape: Rc<Shape>,
+    values: Vec<Property>,
+}
+
+impl Default for PropertyMap {
+    fn default() -> Self {
+        PropertyMap { shape: Shape::root(), values: Vec::new() }
+    }
+}
+
+impl PropertyMap {
+    pub fn get(&self, key: &PropertyKey) -> Option<&Property> {
+        self.shape.find(key).map(|i| &self.values[i])
+    }
+
+    pub fn get_mut(&mut self, key: &PropertyKey) -> Option<&mut Property> {
+        match self.shape.find(key) {
+            Some(i) => Some(&mut self.values[i]),
+            None => None,
+        }
+    }
+
+    pub fn insert(&mut self, key: impl Into<PropertyKey>, property: Property) {
+        let key = key.into();
+
+        match self.shape.find(&key) {
+            Some(i) => self.values[i] = property,
+            None => {
+                Shape::with_key(&mut self.shape, key);
+                self.values.push(property);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &PropertyKey) -> Option<Property> {
+        let i = self.shape.find(key)?;
+        Shape::without(&mut self.shape, i);
+        Some(self.values.remove(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PropertyKey, &Property)> {
+        self.shape.keys().iter().zip(&self.values)
+    }
+
+    pub fn keys(&self) -> &[PropertyKey] {
+        self.shape.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn shape(&self) -> &Rc<Shape> {
+        &self.shape
+    }
+
+    /// Takes the values out, for freeing them. The shape is left as it is.
+    fn take_values(&mut self) -> Vec<Property> {
+        std::mem::take(&mut self.values)
+    }
+
+    /// The property at a position of the shape, as inline caches find it.
+    pub fn get_at(&self, position: usize) -> Option<&Property> {
+        self.values.get(position)
+    }
+
+    pub fn get_at_mut(&mut self, position: usize) -> Option<&mut Property> {
+        self.values.get_mut(position)
+    }
+
+    /// Adds a property under the last key of `shape`, which must be the current one with that key
+    /// added, as an inline cache found it.
+    pub fn push_shaped(&mut self, shape: Rc<Shape>, property: Property) {
+        debug_assert_eq!(shape.keys().len(), self.values.len() + 1);
+        self.shape = shape;
+        self.values.push(property);
+    }
+}
+
+pub struct Object {
+    pub prototype: Option<ObjectRef>,
+    pub kind: ObjectKind,
+    pub properties: PropertyMap,
+    pub extensible: bool,
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        let kind = std::mem::replace(&mut self.kind, ObjectKind::Ordinary);
+        tear_down(Teardown::Object(self.prototype.take(), self.properties.take_values(), kind));
+    }
+}
+
+impl Object {
+    pub fn new(prototype: Option<ObjectRef>, kind: ObjectKind) -> Self {
+        Object {
+            prototype,
+            kind,
+            properties: PropertyMap::default(),
+            extensible: true,
+        }
+    }
+
+    /// Own property lookup, including the elements and `length` of arrays.
+    pub fn get_own_property(&self, key: &PropertyKey) -> Option<Property> {
+        if let ObjectKind::Array(elements) = &self.kind {
+            if let Some(index) = key.as_array_index() {
+                if let Some(element) = elements.get(index) {
+                    return Some(Property::new(element.clone()));
+                }
+            } else if key.as_str() == Some("length") {
+                let length = Value::Float(self.array_length().unwrap_or_default() as f64);
+                return Some(Property { writable: true, configurable: false, ..Property::readonly(length) });
+            }
+        }
+
+        self.properties.get(key).cloned()
+    }
+
+    /// The `length` of an array, which goes past its elements once it's sparse.
+    pub fn array_length(&self) -> Option<usize> {
+        let ObjectKind::Array(elements) = &self.kind else {
+            return None;
+        };
+
+        // a sparse array keeps its length among its properties
+        let length = match self.properties.get(&PropertyKey::from("length")) {
+            Some(Property { value: Value::Float(length), .. }) => *length as usize,
+            _ => 0,
+        };
+
+        Some(length.max(elements.len()))
+    }
+
+    /// The elements of an array that isn't sparse, which are then all of them.
+    pub fn dense_elements(&self) -> Option<&Vec<Value>> {
+        match &self.kind {
+            ObjectKind::Array(elements) if self.array_length() == Some(elements.len()) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Creates or replaces an own property. Array elements and `length` are routed to the elements.
+    pub fn define_own_property(&mut self, key: impl Into<PropertyKey>, property: Property) {
+        let key = key.into();
+
+        if let ObjectKind::Array(elements) = &mut self.kind {
+            let is_plain = property.writable && property.enumerable && property.configurable && !property.is_accessor();
+
+            if is_plain {
+                if let Some(index) = key.as_array_index() {
+                    if index < elements.len() {
+                        elements[index] = property.value;
+                        return;
+                    }
+
+                    if fits_densely(elements, &self.properties, index + 1) {
+                        elements.reserve_exact(index + 1 - elements.len());
+                        elements.resize(index, Value::None);
+                        elements.push(property.value);
+                        return;
+                    }
+                }
+            }
+
+            if key.as_str() == Some("length") {
+                if let Value::Float(length) = property.value {
+                    self.set_array_length(length as usize);
+                }
+
+                return;
+            }
+
+            // an element stored as a property past the end makes the array longer
+            if let Some(index) = key.as_array_index() {
+                if Some(index) >= self.array_length() {
+                    self.properties.insert("length", Property::hidden(Value::Float(index as f64 + 1.0)));
+                }
+            }
+        }
+
+        self.properties.insert(key, property);
+    }
+
+    /// Sets the `length` of an array, which removes the elements past it. Growing an array by
+    /// more than its elements can hold leaves it sparse.
+    fn set_array_length(&mut self, length: usize) {
+        let ObjectKind::Array(elements) = &mut self.kind else {
+            return;
+        };
+
+        if fits_densely(elements, &self.properties, length) {
+            elements.resize(length, Value::None);
+        }
+
+        let removed: Vec<PropertyKey> = self.properties.keys().iter()
+            .filter(|key| key.as_array_index().is_some_and(|index| index >= length) || key.as_str() == Some("length"))
+            .cloned()
+            .collect();
+
+        for key in removed {
+            self.properties.remove(&key);
+        }
+
+        if length > elements.len() {
+            self.properties.insert("length", Property::hidden(Value::Float(length as f64)));
+        }
+    }
+
+    /// How many elements defining `key` as `value` adds to an array, which grows to hold an index
+    /// past its end or a longer `length`.
+    pub fn elements_growth(&self, key: &PropertyKey, value: &Value) -> usize {
+        let ObjectKind::Array(elements) = &self.kind else {
+            return 0;
+        };
+
+        let length = match (key.as_array_index(), value) {
+            (Some(index), _) => index + 1,
+            (None, Value::Float(length)) if key.as_str() == Some("length") => *length as usize,
+            _ => return 0,
+        };
+
+        match fits_densely(elements, &self.properties, length) {
+            true => length.saturating_sub(elements.len()),
+            false => 0,
+        }
+    }
+
+    /// Removes an own property, returning `false` if it exists but is not configurable.
+    pub fn delete_own_property(&mut self, key: &PropertyKey) -> bool {
+        if let ObjectKind::Array(elements) = &mut self.kind {
+            // elements past the end of a sparse array are kept as properties
+            if let Some(index) = key.as_array_index().filter(|index| *index < elements.len()) {
+                match index + 1 == elements.len() {
+                    true => {
+                        elements.pop();
+                    }
+                    // elements are stored densely, so a deleted element in the middle becomes undefined
+                    false => elements[index] = Value::None,
+                }
+
+                return true;
+            }
+
+            if key.as_str() == Some("length") {
+                return false;
+            }
+        }
+
+        match self.properties.get(key) {
+            Some(property) if !property.configurable => false,
+            Some(_) => {
+                self.properties.remove(key);
+                true
+            }
+            None => true,
+        }
+    }
+
+    /// Own property keys in the order the spec requires: array indices ascending, then other
+    /// strings in insertion order, then symbols in insertion order.
+    pub fn own_keys(&self) -> Vec<PropertyKey> {
+        let mut indices: Vec<usize> = Vec::new();
+        let mut strings = Vec::new();
+        let mut symbols = Vec::new();
+
+        if let ObjectKind::Array(elements) = &self.kind {
+            indices.extend(0..elements.len());
+        }
+
+        for (key, _) in self.properties.iter() {
+            match (key, key.as_array_index()) {
+                (_, Some(index)) => indices.push(index),
+                // the length of a sparse array is listed along with those of the others
+                (_, None) if key.as_str() == Some("length") && self.array_length().is_some() => (),
+                (PropertyKey::String(_), None) => strings.push(key.clone()),
+                (PropertyKey::Symbol(_), None) => symbols.push(key.clone()),
+            }
+        }
+
+        indices.sort_unstable();
+
+        if let ObjectKind::Array(_) = &self.kind {
+            strings.insert(0, PropertyKey::from("length"));
+        }
+
+        indices.into_iter().map(PropertyKey::from).chain(strings).chain(symbols).collect()
+    }
+}
+
+/// Whether an array can grow to `length` by filling `elements` with holes, rather than storing
+/// what goes past them as properties.
+fn fits_densely(elements: &[Value], properties: &PropertyMap, length: usize) -> bool {
+    length <= elements.len() || (length - elements.len() <= MAX_ARRAY_GAP && !properties.keys().iter().any(|key| key.as_array_index().is_some()))
+}
+
+/// Handle to a heap object that may have been dropped, see `ObjectRef::downgrade`.
+#[derive(Clone)]
+pub struct WeakObjectRef(Weak<RefCell<Object>>);
+
+impl WeakObjectRef {
+    pub fn upgrade(&self) -> Option<ObjectRef> {
+        self.0.upgrade().map(ObjectRef)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+
+    pub fn as_ptr(&self) -> *const RefCell<Object> {
+        self.0.as_ptr()
+    }
+}
+
+/// Shared handle to a heap object. Two handles are equal when they point to the same object.
+#[derive(Clone)]
+pub struct ObjectRef(Rc<RefCell<Object>>);
+
+impl ObjectRef {
+    pub fn new(object: Object) -> Self {
+        ObjectRef(Rc::new(RefCell::new(object)))
+    }
+
+    pub fn borrow(&self) -> Ref<'_, Object> {
+        self.0.borrow()
+    }
+
+    pub fn borrow_mut(&self) -> RefMut<'_, Object> {
+        self.0.borrow_mut()
+    }
+
+    /// Borrows the object unless it is being changed.
+    pub fn try_borrow(&self) -> Option<Ref<'_, Object>> {
+        self.0.try_borrow().ok()
+    }
+
+    pub fn try_borrow_mut(&self) -> Option<RefMut<'_, Object>> {
+        self.0.try_borrow_mut().ok()
+    }
+
+    pub fn ptr_eq(&self, other: &ObjectRef) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    pub fn as_ptr(&self) -> *const RefCell<Object> {
+        Rc::as_ptr(&self.0)
+    }
+
+    /// How many handles keep the object alive.
+    pub fn strong_count(&self) -> usize {
+        Rc::strong_count(&self.0)
+    }
+
+    /// A handle that doesn't keep the object alive.
+    pub fn downgrade(&self) -> WeakObjectRef {
+        WeakObjectRef(Rc::downgrade(&self.0))
+    }
+
+    pub fn is_callable(&self) -> bool {
+        matches!(self.borrow().kind, ObjectKind::Function(_) | ObjectKind::NativeFunction(_))
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self.borrow().kind, ObjectKind::Array(_))
+    }
+
+    pub fn prototype(&self) -> Option<ObjectRef> {
+        self.borrow().prototype.clone()
+    }
+
+    /// Looks up a data property on the object or its prototype chain without running any script code.
+    pub fn get(&self, key: impl Into<PropertyKey>) -> Option<Value> {
+        let key = key.into();
+        let mut current = Some(self.clone());
+
+        while let Some(object) = current {
+            let object = object.borrow();
+
+            if let Some(property) = object.get_own_property(&key) {
+                return Some(property.value);
+            }
+
+            current = object.prototype.clone();
+        }
+
+        None
+    }
+
+    pub fn get_own(&self, key: impl Into<PropertyKey>) -> Option<Value> {
+        self.borrow().get_own_property(&key.into()).map(|property| property.value)
+    }
+
+    /// Looks up a property on the object or its prototype chain, returning the full descriptor.
+    pub fn find_property(&self, key: &PropertyKey) -> Option<Property> {
+        let mut current = Some(self.clone());
+
+        while let Some(object) = current {
+            let object = object.borrow();
+
+            if let Some(property) = object.get_own_property(key) {
+                return Some(property);
+            }
+
+            current = object.prototype.clone();
+        }
+
+        None
+    }
+
+    pub fn has_property(&self, key: impl Into<PropertyKey>) -> bool {
+        self.find_property(&key.into()).is_some()
+    }
+
+    pub fn set(&self, key: impl Into<PropertyKey>, value: Value) {
+        self.borrow_mut().define_own_property(key, Property::new(value));
+    }
+
+    pub fn set_hidden(&self, key: impl Into<PropertyKey>, value: Value) {
+        self.borrow_mut().define_own_property(key, Property::hidden(value));
+    }
+}
+
+impl PartialEq for ObjectRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+impl fmt::Debug for ObjectRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let object = match self.0.try_borrow() {
+            Ok(object) => object,
+            Err(_) => return write!(f, "[object]"),
+        };
+
+        match &object.kind {
+            ObjectKind::Function(closure) => {
+                write!(f, "[Function: {}]", closure.def.name.as_deref().unwrap_or("(anonymous)"))
+            }
+            ObjectKind::NativeFunction(_) => write!(f, "[Function (native)]"),
+            ObjectKind::Array(elements) => f.debug_list().entries(elements).finish(),
+            ObjectKind::Map(map) => write!(f, "Map({})", map.len()),
+            ObjectKind::Set(set) => write!(f, "Set({})", set.len()),
+            _ => {
+                let keys: Vec<String> = object.properties.iter().map(|(key, _)| key.to_string()).collect();
+                write!(f, "[object {:?}]", keys)
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use crate::types::{JsString, Value};
+
+/// Hashable form of a value under SameValueZero, the equality `Map` and `Set` use for keys.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum MapKey {
+    Number(u64),
+    String(JsString),
+    Boolean(bool),
+    Null,
+    Undefined,
+    Object(usize),
+    Symbol(usize),
+}
+
+impl MapKey {
+    fn new(value: &Value) -> Self {
+        match value {
+            // NaN equals itself and -0 equals +0
+            Value::Float(number) if number.is_nan() => MapKey::Number(f64::NAN.to_bits()),
+            Value::Float(number) if *number == 0.0 => MapKey::Number(0),
+            Value::Float(number) => MapKey::Number(number.to_bits()),
+            Value::StringLiteral(string) => MapKey::String(string.clone()),
+            Value::Boolean(boolean) => MapKey::Boolean(*boolean),
+            Value::Null => MapKey::Null,
+            Value::None => MapKey::Undefined,
+            Value::Object(object) => MapKey::Object(object.as_ptr() as usize),
+            Value::Symbol(symbol) => MapKey::Symbol(symbol.as_ptr() as usize),
+        }
+    }
+}
+
+/// Insertion ordered entries of a `Map` or `Set`.
+///
+/// Deleted entries leave a hole behind instead of shifting the ones after them, so iterators
+/// can keep walking by position while the collection is modified underneath them.
+#[derive(Clone, Default)]
+pub struct OrderedMap {
+    entries: Vec<Option<(Value, Value)>>,
+    index: HashMap<MapKey, usize>,
+}
+
+impl OrderedMap {
+    pub fn get(&self, key: &Value) -> Option<&Value> {
+        let i = self.index.get(&MapKey::new(key))?;
+        self.entries[*i].as_ref().map(|(_, value)| value)
+    }
+
+    pub fn has(&self, key: &Value) -> bool {
+        self.index.contains_key(&MapKey::new(key))
+    }
+
+    pub fn set(&mut self, key: Value, value: Value) {
+        // -0 is normalized, adding +0 turns it into +0, so that iterating the keys never produces it
+        let key = match key {
+            Value::Float(number) => Value::Float(number + 0.0),
+            key => key,
+        };
+
+        match self.index.get(&MapKey::new(&key)) {
+            Some(&i) => self.entries[i] = Some((key, value)),
+            None => {
+                self.index.insert(MapKey::new(&key), self.entries.len());
+                self.entries.push(Some((key, value)));
+            }
+        }
+    }
+
+    pub fn delete(&mut self, key: &Value) -> bool {
+        match self.index.remove(&MapKey::new(key)) {
+            Some(i) => {
+                self.entries[i] = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.index.clear();
+
+        for entry in self.entries.iter_mut() {
+            *entry = None;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// The first live entry at or after `position`, with the position following it.
+    pub fn entry_from(&self, position: usize) -> Option<(usize, &Value, &Value)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .skip(position)
+            .find_map(|(i, entry)| entry.as_ref().map(|(key, value)| (i + 1, key, value)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Value, &Value)> {
+        self.entries.iter().flatten().map(|(key, value)| (key, value))
+    }
+}
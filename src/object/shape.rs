@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::{Rc, Weak};
+use super::PropertyKey;
+
+/// Shapes with more keys than this find them through a hash map instead of a search.
+const INDEXED_SHAPE_SIZE: usize = 8;
+
+/// Objects with more properties than this are likely used as dictionaries, so they get a shape of
+/// their own instead of one more in a chain of shapes nothing else will share.
+const MAX_SHARED_SHAPE_SIZE: usize = 64;
+
+thread_local! {
+    static ROOT: Rc<Shape> = Rc::new(Shape { shared: true, ..Shape::default() });
+}
+
+/// The keys of an object's own properties, in the order they were added, which is also where
+/// their values are stored.
+///
+/// Objects that get the same keys in the same order share their shape, so checking that an
+/// object has a shape seen before is enough to know where to find one of its properties. Adding a
+/// key moves an object to the next shape, deleting one gives it a shape that isn't shared.
+#[derive(Clone, Default)]
+pub struct Shape {
+    keys: Vec<PropertyKey>,
+    // positions by key, once the shape has grown too big to search
+    index: Option<HashMap<PropertyKey, usize>>,
+    // the shapes adding each key leads to, for as long as objects have them, and the one this one
+    // was reached from, which stays around for the next object to take the same path
+    transitions: RefCell<HashMap<PropertyKey, Weak<Shape>>>,
+    parent: Option<Rc<Shape>>,
+    shared: bool,
+}
+
+impl Shape {
+    /// The shape of objects without properties.
+    pub fn root() -> Rc<Shape> {
+        ROOT.with(|root| root.clone())
+    }
+
+    pub fn keys(&self) -> &[PropertyKey] {
+        &self.keys
+    }
+
+    pub fn find(&self, key: &PropertyKey) -> Option<usize> {
+        match &self.index {
+            Some(index) => index.get(key).copied(),
+            None => self.keys.iter().position(|existing| existing == key),
+        }
+    }
+
+    /// Whether other objects may have this shape too. Only the object it belongs to changes a
+    /// shape that isn't shared, and it does so in place.
+    pub fn is_shared(&self) -> bool {
+        self.shared
+    }
+
+    /// Moves `shape` on to the one with `key` added, which must not be one of its keys already.
+    pub(super) fn with_key(shape: &mut Rc<Shape>, key: PropertyKey) {
+        if !shape.shared || shape.keys.len() >= MAX_SHARED_SHAPE_SIZE {
+            let unshared = Rc::make_mut(shape);
+            unshared.shared = false;
+            unshared.transitions = RefCell::default();
+            unshared.parent = None;
+            unshared.push(key);
+            return;
+        }
+
+        let existing = shape.transitions.borrow().get(&key).and_then(Weak::upgrade);
+
+        let next = match existing {
+            Some(next) => next,
+            None => {
+                let mut next = Shape {
+                    keys: shape.keys.clone(),
+                    index: shape.index.clone(),
+                    transitions: RefCell::default(),
+                    parent: Some(shape.clone()),
+                    shared: true,
+                };
+                next.push(key.clone());
+
+                let next = Rc::new(next);
+                shape.transitions.borrow_mut().insert(key, Rc::downgrade(&next));
+                next
+            },
+        };
+
+        *shape = next;
+    }
+
+    /// Moves `shape` on to one without the key at `position`.
+    pub(super) fn without(shape: &mut Rc<Shape>, position: usize) {
+        let mut keys = shape.keys.clone();
+        keys.remove(position);
+
+        if keys.is_empty() {
+            *shape = Shape::root();
+            return;
+        }
+
+        let mut unshared = Shape::default();
+
+        for key in keys {
+            unshared.push(key);
+        }
+
+        *shape = Rc::new(unshared);
+    }
+
+    fn push(&mut self, key: PropertyKey) {
+        match &mut self.index {
+            Some(index) => {
+                index.insert(key.clone(), self.keys.len());
+            },
+            None if self.keys.len() >= INDEXED_SHAPE_SIZE => {
+                let mut index: HashMap<PropertyKey, usize> = self.keys.iter().cloned().enumerate().map(|(i, key)| (key, i)).collect();
+                index.insert(key.clone(), self.keys.len());
+                self.index = Some(index);
+            },
+            None => (),
+        }
+
+        self.keys.push(key);
+    }
+}
+
+impl fmt::Debug for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shape").field("keys", &self.keys).field("shared", &self.shared).finish()
+    }
+}
@@ -0,0 +1,526 @@
+use std::rc::Rc;
+use crate::interpreter::Interpreter;
+use crate::types::{exponentiate, string_to_number, DeclarationKind, Expr, ForBinding, FunctionDef, ObjectMember, Pattern, PropertyName, Stmt, Value};
+
+/// Rewrites a parsed script into one that behaves the same but does less work when it runs: it
+/// folds operators applied to literals, drops branches that can never be taken and statements
+/// that follow a `return`, `break`, `continue` or `throw`, and turns loops whose condition is a
+/// literal or that have nothing to set up into plain `loop` and `while` statements.
+///
+/// Loop-invariant code is only moved out of loops when it is made of literals, by folding it.
+/// Anything else could call a getter, a `valueOf` or throw, which would then happen a different
+/// number of times.
+///
+/// Code that is removed still declares its `var` bindings and hoisted functions, and statements
+/// keep their completion values, so that `eval` gives the same result.
+pub struct Optimizer;
+
+impl Optimizer {
+    pub fn optimize(stmts: &mut Vec<Stmt>) {
+        Self::statements(stmts);
+    }
+
+    fn statements(stmts: &mut Vec<Stmt>) {
+        for stmt in stmts.iter_mut() {
+            Self::stmt(stmt);
+        }
+
+        let Some(end) = stmts.iter().position(always_jumps) else {
+            return;
+        };
+
+        let unreachable = stmts.split_off(end + 1);
+        let names = var_names(&unreachable);
+
+        stmts.extend(unreachable.into_iter().filter(|stmt| matches!(stmt, Stmt::Function(_))));
+
+        if let Some(declaration) = var_declaration(names) {
+            stmts.push(declaration);
+        }
+    }
+
+    fn stmt(stmt: &mut Stmt) {
+        match stmt {
+            Stmt::Declaration(_, declarations) => {
+                for (pattern, init) in declarations {
+                    Self::pattern(pattern);
+
+                    if let Some(init) = init {
+                        Self::expr(init);
+                    }
+                }
+            },
+            Stmt::ControlFlow(condition, consequent, alternate) => {
+                Self::expr(condition);
+                Self::stmt(consequent);
+                Self::stmt(alternate);
+
+                if let Some(condition) = literal(condition) {
+                    let (taken, skipped) = match condition.is_truthy() {
+                        true => (consequent, alternate),
+                        false => (alternate, consequent),
+                    };
+
+                    let taken = std::mem::replace(taken.as_mut(), Stmt::None);
+                    *stmt = branch(taken, var_names(std::slice::from_ref(skipped.as_ref())));
+                }
+            },
+            Stmt::CodeBlock(stmts) => Self::statements(stmts),
+            Stmt::Function(def) => Self::function(def),
+            Stmt::Return(Some(expr)) | Stmt::Throw(expr) => Self::expr(expr),
+            Stmt::Expression(expr) => Self::expr(expr),
+            Stmt::ForLoop(init, condition, increment, body) => {
+                Self::stmt(init);
+                Self::stmt(body);
+
+                for expr in condition.iter_mut().chain(increment.iter_mut()) {
+                    Self::expr(expr);
+                }
+
+                match condition.as_ref().and_then(literal).map(|value| value.is_truthy()) {
+                    // the condition is checked for nothing
+                    Some(true) => *condition = None,
+                    // only the initializer ever runs
+                    Some(false) => {
+                        let names = var_names(std::slice::from_ref(body.as_ref()));
+                        **body = Stmt::CodeBlock(var_declaration(names).into_iter().collect());
+                        *increment = None;
+                    },
+                    None => {},
+                }
+
+                // without bindings of its own to copy per iteration or anything to run after each
+                // one, the loop needs no scope
+                if matches!(init.as_ref(), Stmt::None) && increment.is_none() {
+                    let body = std::mem::replace(body.as_mut(), Stmt::None);
+
+                    *stmt = match condition.take() {
+                        Some(condition) if literal(&condition).is_some() => body,
+                        Some(condition) => Stmt::While(Box::new(condition), Box::new(body)),
+                        None => Stmt::Loop(Box::new(body)),
+                    };
+                }
+            },
+            Stmt::ForOf(binding, iterable, body) | Stmt::ForIn(binding, iterable, body) | Stmt::ForAwaitOf(binding, iterable, body) => {
+                match binding {
+                    ForBinding::Declaration(_, pattern) | ForBinding::Target(pattern) => Self::pattern(pattern),
+                }
+
+                Self::expr(iterable);
+                Self::stmt(body);
+            },
+            Stmt::Loop(body) => Self::stmt(body),
+            Stmt::While(condition, body) => {
+                Self::expr(condition);
+                Self::stmt(body);
+
+                match literal(condition).map(|value| value.is_truthy()) {
+                    Some(true) => {
+                        let body = std::mem::replace(body.as_mut(), Stmt::None);
+                        *stmt = Stmt::Loop(Box::new(body));
+                    },
+                    Some(false) => {
+                        let names = var_names(std::slice::from_ref(body.as_ref()));
+                        *stmt = Stmt::CodeBlock(var_declaration(names).into_iter().collect());
+                    },
+                    None => {},
+                }
+            },
+            Stmt::Try(block, handler, finalizer) => {
+                Self::stmt(block);
+
+                if let Some((_, body)) = handler {
+                    Self::stmt(body);
+                }
+
+                if let Some(finalizer) = finalizer {
+                    Self::stmt(finalizer);
+                }
+            },
+            Stmt::Return(None) | Stmt::None | Stmt::Break | Stmt::Continue => {},
+        }
+    }
+
+    /// Optimizes the body of a function. Generators and async functions are left alone, as they
+    /// run the body the parser lowered instead.
+    fn function(def: &mut Rc<FunctionDef>) {
+        if def.generator.is_some() || def.is_async {
+            return;
+        }
+
+        let def = Rc::make_mut(def);
+
+        for param in def.params.iter_mut().chain(def.rest.as_mut()) {
+            Self::pattern(param);
+        }
+
+        Self::statements(&mut def.body);
+    }
+
+    fn pattern(pattern: &mut Pattern) {
+        match pattern {
+            Pattern::Identifier(_) => {},
+            Pattern::Expression(expr) => Self::expr(expr),
+            Pattern::Array(elements, rest) => {
+                for element in elements.iter_mut().flatten() {
+                    Self::pattern(element);
+                }
+
+                if let Some(rest) = rest {
+                    Self::pattern(rest);
+                }
+            },
+            Pattern::Object(properties, rest) => {
+                for (name, pattern) in properties {
+                    Self::property_name(name);
+                    Self::pattern(pattern);
+                }
+
+                if let Some(rest) = rest {
+                    Self::pattern(rest);
+                }
+            },
+            Pattern::Default(pattern, default) => {
+                Self::pattern(pattern);
+                Self::expr(default);
+            },
+        }
+    }
+
+    fn property_name(name: &mut PropertyName) {
+        if let PropertyName::Computed(key) = name {
+            Self::expr(key);
+        }
+    }
+
+    fn expr(expr: &mut Expr) {
+        Self::operands(expr);
+
+        if let Some(folded) = fold(expr) {
+            *expr = folded;
+        }
+    }
+
+    /// Optimizes an operand that must not turn into a reference it wasn't, such as the callee of
+    /// `(0 || obj.method)()`, which is called without `obj` as `this`.
+    fn value_operand(expr: &mut Expr, is_reference: fn(&Expr) -> bool) {
+        Self::operands(expr);
+
+        if let Some(folded) = fold(expr) {
+            if is_reference(expr) || !is_reference(&folded) {
+                *expr = folded;
+            }
+        }
+    }
+
+    fn operands(expr: &mut Expr) {
+        match expr {
+            Expr::Identifier(_) | Expr::Float(_) | Expr::StringLiteral(_) | Expr::RegExp(_) | Expr::Boolean(_) | Expr::Null | Expr::This => {},
+            Expr::Function(def) => Self::function(def),
+            Expr::Array(elements) => {
+                for element in elements.iter_mut().flatten() {
+                    Self::expr(element);
+                }
+            },
+            Expr::Object(members) => {
+                for member in members {
+                    match member {
+                        ObjectMember::Property(name, value) => {
+                            Self::property_name(name);
+                            Self::expr(value);
+                        },
+                        ObjectMember::Getter(name, def) | ObjectMember::Setter(name, def) => {
+                            Self::property_name(name);
+                            Self::function(def);
+                        },
+                        ObjectMember::Spread(source) => Self::expr(source),
+                    }
+                }
+            },
+            Expr::Addition(left, right)
+            | Expr::Subtraction(left, right)
+            | Expr::Multiplication(left, right)
+            | Expr::Division(left, right)
+            | Expr::Modulo(left, right)
+            | Expr::Exponentiation(left, right)
+            | Expr::Equals(left, right)
+            | Expr::TypeCheckEquals(left, right)
+            | Expr::NotEquals(left, right)
+            | Expr::TypeNotEquals(left, right)
+            | Expr::GreaterThan(left, right)
+            | Expr::GreaterThanEquals(left, right)
+            | Expr::LessThan(left, right)
+            | Expr::LessThanEquals(left, right)
+            | Expr::BitwiseAnd(left, right)
+            | Expr::BitwiseOr(left, right)
+            | Expr::BitwiseXor(left, right)
+            | Expr::LeftShift(left, right)
+            | Expr::RightShift(left, right)
+            | Expr::UnsignedRightShift(left, right)
+            | Expr::InstanceOf(left, right)
+            | Expr::In(left, right)
+            | Expr::LogicalAnd(left, right)
+            | Expr::LogicalOr(left, right)
+            | Expr::NullishCoalescing(left, right)
+            | Expr::Assignment(left, right)
+            | Expr::CompoundAssignment(_, left, right) => {
+                Self::expr(left);
+                Self::expr(right);
+            },
+            Expr::LogicalNot(operand)
+            | Expr::Negation(operand)
+            | Expr::UnaryPlus(operand)
+            | Expr::BitwiseNot(operand)
+            | Expr::Void(operand)
+            | Expr::PreIncrement(operand)
+            | Expr::PreDecrement(operand)
+            | Expr::PostIncrement(operand)
+            | Expr::PostDecrement(operand)
+            | Expr::Spread(operand)
+            | Expr::YieldDelegate(operand)
+            | Expr::Await(operand) => Self::expr(operand),
+            // `typeof missing` and `delete obj.x` act on the reference, not its value
+            Expr::TypeOf(operand) | Expr::Delete(operand) => Self::value_operand(operand, is_reference),
+            Expr::Yield(operand) => {
+                if let Some(operand) = operand {
+                    Self::expr(operand);
+                }
+            },
+            Expr::DestructuringAssignment(pattern, value) => {
+                Self::pattern(pattern);
+                Self::expr(value);
+            },
+            Expr::Conditional(condition, consequent, alternate) => {
+                Self::expr(condition);
+                Self::expr(consequent);
+                Self::expr(alternate);
+            },
+            Expr::Sequence(exprs) => {
+                for expr in exprs {
+                    Self::expr(expr);
+                }
+            },
+            Expr::FunctionCall(callee, args) => {
+                Self::value_operand(callee, is_member);
+
+                for arg in args {
+                    Self::expr(arg);
+                }
+            },
+            Expr::TailCall(call) => Self::operands(call),
+            Expr::New(callee, args) => {
+                Self::expr(callee);
+
+                for arg in args {
+                    Self::expr(arg);
+                }
+            },
+            Expr::Member(object, _) => Self::expr(object),
+            Expr::ComputedMember(object, key) => {
+                Self::expr(object);
+                Self::expr(key);
+            },
+        }
+    }
+}
+
+/// Whether a statement always completes abruptly, so that nothing after it in the same list runs.
+fn always_jumps(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Return(_) | Stmt::Throw(_) | Stmt::Break | Stmt::Continue => true,
+        Stmt::CodeBlock(stmts) => stmts.iter().any(always_jumps),
+        Stmt::ControlFlow(_, consequent, alternate) => always_jumps(consequent) && always_jumps(alternate),
+        _ => false,
+    }
+}
+
+fn var_names(stmts: &[Stmt]) -> Vec<Rc<str>> {
+    let mut names = Vec::new();
+
+    for stmt in stmts {
+        Interpreter::collect_var_names(stmt, &mut names);
+    }
+
+    names
+}
+
+/// `var` declarations without initializers, which only create the bindings of code that was
+/// removed.
+fn var_declaration(names: Vec<Rc<str>>) -> Option<Stmt> {
+    if names.is_empty() {
+        return None;
+    }
+
+    let declarations = names.into_iter().map(|name| (Pattern::Identifier(name), None)).collect();
+    Some(Stmt::Declaration(DeclarationKind::Var, declarations))
+}
+
+/// What an `if` statement becomes when it always takes the same branch. Like the `if`, it has a
+/// completion value even when the branch is a declaration.
+fn branch(taken: Stmt, skipped_names: Vec<Rc<str>>) -> Stmt {
+    let declaration = var_declaration(skipped_names);
+
+    match (taken, declaration) {
+        (Stmt::CodeBlock(mut stmts), declaration) => {
+            stmts.extend(declaration);
+            Stmt::CodeBlock(stmts)
+        },
+        (Stmt::None, declaration) => Stmt::CodeBlock(declaration.into_iter().collect()),
+        (taken @ (Stmt::Declaration(_, _) | Stmt::Function(_)), declaration) => {
+            Stmt::CodeBlock(std::iter::once(taken).chain(declaration).collect())
+        },
+        (taken, None) => taken,
+        (taken, Some(declaration)) => Stmt::CodeBlock(vec![taken, declaration]),
+    }
+}
+
+fn is_reference(expr: &Expr) -> bool {
+    matches!(expr, Expr::Identifier(_)) || is_member(expr)
+}
+
+fn is_member(expr: &Expr) -> bool {
+    matches!(expr, Expr::Member(_, _) | Expr::ComputedMember(_, _))
+}
+
+/// The value of a literal. `undefined` isn't one, as scripts can shadow it.
+fn literal(expr: &Expr) -> Option<Value> {
+    match expr {
+        Expr::Float(value) => Some(Value::Float(*value)),
+        Expr::StringLiteral(value) => Some(Value::StringLiteral(value.clone())),
+        Expr::Boolean(value) => Some(Value::Boolean(*value)),
+        Expr::Null => Some(Value::Null),
+        _ => None,
+    }
+}
+
+fn to_literal(value: Value) -> Option<Expr> {
+    match value {
+        Value::Float(value) => Some(Expr::Float(value)),
+        Value::StringLiteral(value) => Some(Expr::StringLiteral(value)),
+        Value::Boolean(value) => Some(Expr::Boolean(value)),
+        Value::Null => Some(Expr::Null),
+        _ => None,
+    }
+}
+
+/// The value of an expression whose operands are already folded, if it can be worked out without
+/// running the script.
+fn fold(expr: &Expr) -> Option<Expr> {
+    match expr {
+        Expr::LogicalAnd(left, right) => match literal(left)?.is_truthy() {
+            true => Some(right.as_ref().clone()),
+            false => Some(left.as_ref().clone()),
+        },
+        Expr::LogicalOr(left, right) => match literal(left)?.is_truthy() {
+            true => Some(left.as_ref().clone()),
+            false => Some(right.as_ref().clone()),
+        },
+        Expr::NullishCoalescing(left, right) => match literal(left)?.is_nullish() {
+            true => Some(right.as_ref().clone()),
+            false => Some(left.as_ref().clone()),
+        },
+        Expr::Conditional(condition, consequent, alternate) => match literal(condition)?.is_truthy() {
+            true => Some(consequent.as_ref().clone()),
+            false => Some(alternate.as_ref().clone()),
+        },
+        Expr::LogicalNot(operand) => Some(Expr::Boolean(!literal(operand)?.is_truthy())),
+        Expr::Negation(operand) => Some(Expr::Float(-to_number(&literal(operand)?))),
+        Expr::UnaryPlus(operand) => Some(Expr::Float(to_number(&literal(operand)?))),
+        Expr::BitwiseNot(operand) => Some(Expr::Float(!to_int32(to_number(&literal(operand)?)) as f64)),
+        Expr::TypeOf(operand) => Some(Expr::StringLiteral(literal(operand)?.type_of().into())),
+        _ => fold_binary(expr),
+    }
+}
+
+fn fold_binary(expr: &Expr) -> Option<Expr> {
+    let (left, right) = match expr {
+        Expr::Addition(left, right)
+        | Expr::Subtraction(left, right)
+        | Expr::Multiplication(left, right)
+        | Expr::Division(left, right)
+        | Expr::Modulo(left, right)
+        | Expr::Exponentiation(left, right)
+        | Expr::Equals(left, right)
+        | Expr::TypeCheckEquals(left, right)
+        | Expr::NotEquals(left, right)
+        | Expr::TypeNotEquals(left, right)
+        | Expr::GreaterThan(left, right)
+        | Expr::GreaterThanEquals(left, right)
+        | Expr::LessThan(left, right)
+        | Expr::LessThanEquals(left, right)
+        | Expr::BitwiseAnd(left, right)
+        | Expr::BitwiseOr(left, right)
+        | Expr::BitwiseXor(left, right)
+        | Expr::LeftShift(left, right)
+        | Expr::RightShift(left, right)
+        | Expr::UnsignedRightShift(left, right) => (literal(left)?, literal(right)?),
+        _ => return None,
+    };
+
+    let is_string = matches!(left, Value::StringLiteral(_)) || matches!(right, Value::StringLiteral(_));
+    let same_type = std::mem::discriminant(&left) == std::mem::discriminant(&right);
+    let numbers = || (to_number(&left), to_number(&right));
+
+    let value = match expr {
+        Expr::Addition(_, _) if is_string => {
+            let [left, right] = [&left, &right].map(|value| match value {
+                Value::StringLiteral(string) => string.clone(),
+                value => value.to_display_string().into(),
+            });
+
+            Value::StringLiteral(left.concat(&right))
+        },
+        Expr::Addition(_, _) => Value::Float(numbers().0 + numbers().1),
+        Expr::Subtraction(_, _) => Value::Float(numbers().0 - numbers().1),
+        Expr::Multiplication(_, _) => Value::Float(numbers().0 * numbers().1),
+        Expr::Division(_, _) => Value::Float(numbers().0 / numbers().1),
+        Expr::Modulo(_, _) => Value::Float(numbers().0 % numbers().1),
+        Expr::Exponentiation(_, _) => {
+            let (base, exponent) = numbers();
+            Value::Float(exponentiate(base, exponent))
+        },
+        // loose equality only means strict equality between values of the same type
+        Expr::Equals(_, _) if same_type => Value::Boolean(Interpreter::strict_equals(&left, &right)),
+        Expr::NotEquals(_, _) if same_type => Value::Boolean(!Interpreter::strict_equals(&left, &right)),
+        Expr::TypeCheckEquals(_, _) => Value::Boolean(Interpreter::strict_equals(&left, &right)),
+        Expr::TypeNotEquals(_, _) => Value::Boolean(!Interpreter::strict_equals(&left, &right)),
+        // strings compare by code units rather than as numbers
+        Expr::GreaterThan(_, _) | Expr::GreaterThanEquals(_, _) | Expr::LessThan(_, _) | Expr::LessThanEquals(_, _) if is_string => return None,
+        Expr::GreaterThan(_, _) => Value::Boolean(numbers().0 > numbers().1),
+        Expr::GreaterThanEquals(_, _) => Value::Boolean(numbers().0 >= numbers().1),
+        Expr::LessThan(_, _) => Value::Boolean(numbers().0 < numbers().1),
+        Expr::LessThanEquals(_, _) => Value::Boolean(numbers().0 <= numbers().1),
+        Expr::BitwiseAnd(_, _) => Value::Float((to_int32(numbers().0) & to_int32(numbers().1)) as f64),
+        Expr::BitwiseOr(_, _) => Value::Float((to_int32(numbers().0) | to_int32(numbers().1)) as f64),
+        Expr::BitwiseXor(_, _) => Value::Float((to_int32(numbers().0) ^ to_int32(numbers().1)) as f64),
+        Expr::LeftShift(_, _) => Value::Float(to_int32(numbers().0).wrapping_shl(to_uint32(numbers().1) & 31) as f64),
+        Expr::RightShift(_, _) => Value::Float((to_int32(numbers().0) >> (to_uint32(numbers().1) & 31)) as f64),
+        Expr::UnsignedRightShift(_, _) => Value::Float((to_uint32(numbers().0) >> (to_uint32(numbers().1) & 31)) as f64),
+        _ => return None,
+    };
+
+    to_literal(value)
+}
+
+/// The number a literal converts to, which unlike objects can't run any code.
+fn to_number(value: &Value) -> f64 {
+    match value {
+        Value::Float(value) => *value,
+        Value::StringLiteral(value) => string_to_number(value),
+        Value::Boolean(value) => if *value { 1.0 } else { 0.0 },
+        Value::Null => 0.0,
+        _ => f64::NAN,
+    }
+}
+
+fn to_uint32(number: f64) -> u32 {
+    if !number.is_finite() {
+        return 0;
+    }
+
+    number.trunc().rem_euclid(4294967296.0) as u32
+}
+
+fn to_int32(number: f64) -> i32 {
+    to_uint32(number) as i32
+}
@@ -0,0 +1,773 @@
+use crate::error::JsError;
+use crate::types::{DeclarationKind, Expr, ForBinding, GeneratorCode, GeneratorOp, ObjectMember, Pattern, PropertyName, Stmt, Token, intern};
+use super::{ParseResult, Parser};
+
+type BinaryConstructor = fn(Box<Expr>, Box<Expr>) -> Expr;
+type UnaryConstructor = fn(Box<Expr>) -> Expr;
+
+/// Lowers the body of a generator or async function, see `GeneratorCode`.
+pub fn lower(body: &[Stmt]) -> ParseResult<GeneratorCode> {
+    let mut lowering = Lowering { ops: Vec::new(), temporaries: 0 };
+
+    // function declarations of the body itself are hoisted when the generator is called
+    for stmt in body {
+        lowering.lower_stmt(stmt)?;
+    }
+
+    Ok(GeneratorCode { ops: lowering.ops, temporaries: lowering.temporaries })
+}
+
+struct Lowering {
+    ops: Vec<GeneratorOp>,
+    temporaries: usize,
+}
+
+impl Lowering {
+    fn lower_stmt(&mut self, stmt: &Stmt) -> ParseResult<()> {
+        if !stmt_contains_yield(stmt) {
+            if !matches!(stmt, Stmt::Function(_) | Stmt::None) {
+                self.emit(GeneratorOp::Exec(stmt.clone()));
+            }
+
+            return Ok(());
+        }
+
+        match stmt {
+            Stmt::Expression(expr) => match expr.as_ref() {
+                // a yield whose result is unused needs no temporary
+                Expr::Yield(argument) => {
+                    let argument = self.lower_optional(argument.as_deref())?;
+                    self.emit(GeneratorOp::Yield(argument, None));
+                },
+                Expr::YieldDelegate(argument) => {
+                    let argument = self.lower_expr(argument)?;
+                    self.emit(GeneratorOp::YieldDelegate(argument, None));
+                },
+                Expr::Await(argument) => {
+                    let argument = self.lower_expr(argument)?;
+                    self.emit(GeneratorOp::Await(argument, None));
+                },
+                expr => {
+                    let expr = self.lower_expr(expr)?;
+                    self.emit(GeneratorOp::Exec(Stmt::Expression(Box::new(expr))));
+                },
+            },
+            Stmt::Declaration(kind, declarations) => {
+                for (pattern, value) in declarations {
+                    check_pattern(pattern)?;
+
+                    let value = self.lower_optional(value.as_ref())?;
+                    self.emit(GeneratorOp::Exec(Stmt::Declaration(*kind, vec![(pattern.clone(), value)])));
+                }
+            },
+            Stmt::Return(expr) => {
+                let expr = self.lower_optional(expr.as_ref())?;
+                self.emit(GeneratorOp::Exec(Stmt::Return(expr)));
+            },
+            Stmt::Throw(expr) => {
+                let expr = self.lower_expr(expr)?;
+                self.emit(GeneratorOp::Exec(Stmt::Throw(expr)));
+            },
+            Stmt::ControlFlow(condition, consequent, alternate) => {
+                let condition = self.lower_expr(condition)?;
+                let to_alternate = self.emit(GeneratorOp::JumpIfFalse(condition, 0));
+                self.lower_stmt(consequent)?;
+                let to_end = self.emit(GeneratorOp::Jump(0));
+                self.patch(to_alternate);
+                self.lower_stmt(alternate)?;
+                self.patch(to_end);
+            },
+            Stmt::CodeBlock(stmts) => self.lower_block(stmts)?,
+            Stmt::Loop(body) => {
+                let enter = self.emit(GeneratorOp::EnterLoop { break_target: 0, continue_target: 0 });
+                let top = self.ops.len();
+                self.lower_stmt(body)?;
+                self.emit(GeneratorOp::Jump(top));
+                self.exit_loop(enter, top);
+            },
+            Stmt::While(condition, body) => {
+                let enter = self.emit(GeneratorOp::EnterLoop { break_target: 0, continue_target: 0 });
+                let top = self.ops.len();
+                let condition = self.lower_expr(condition)?;
+                let to_exit = self.emit(GeneratorOp::JumpIfFalse(condition, 0));
+                self.lower_stmt(body)?;
+                self.emit(GeneratorOp::Jump(top));
+                self.patch(to_exit);
+                self.exit_loop(enter, top);
+            },
+            Stmt::ForLoop(init, condition, increment, body) => self.lower_for_loop(init, condition.as_ref(), increment.as_ref(), body)?,
+            Stmt::ForOf(binding, iterable, body) => self.lower_for_in_of(binding, iterable, body, false)?,
+            Stmt::ForIn(binding, object, body) => self.lower_for_in_of(binding, object, body, true)?,
+            Stmt::ForAwaitOf(binding, iterable, body) => self.lower_for_await_of(binding, iterable, body)?,
+            Stmt::Try(block, handler, finalizer) => {
+                let enter = self.emit(GeneratorOp::EnterTry { catch: None, finally: None });
+                self.lower_stmt(block)?;
+                let mut leaves = vec![self.emit(GeneratorOp::LeaveTry(0))];
+
+                let catch = match handler {
+                    Some((param, body)) => {
+                        let target = self.ops.len();
+                        self.lower_stmt(body)?;
+                        // the scope of the catch binding is entered when the exception is caught
+                        self.emit(GeneratorOp::PopScope);
+                        leaves.push(self.emit(GeneratorOp::LeaveTry(0)));
+                        Some((target, param.clone()))
+                    },
+                    None => None,
+                };
+
+                let finally = match finalizer {
+                    Some(finalizer) => {
+                        let target = self.ops.len();
+                        self.lower_stmt(finalizer)?;
+                        self.emit(GeneratorOp::EndFinally);
+                        Some(target)
+                    },
+                    None => None,
+                };
+
+                for leave in leaves {
+                    self.patch(leave);
+                }
+
+                self.ops[enter] = GeneratorOp::EnterTry { catch, finally };
+            },
+            Stmt::Function(_) | Stmt::None | Stmt::Break | Stmt::Continue => (),
+        }
+
+        Ok(())
+    }
+
+    fn lower_block(&mut self, stmts: &[Stmt]) -> ParseResult<()> {
+        // like in the interpreter, blocks that declare nothing share the enclosing scope
+        let functions: Vec<Stmt> = stmts.iter().filter(|stmt| matches!(stmt, Stmt::Function(_))).cloned().collect();
+        let scoped = !functions.is_empty()
+            || stmts.iter().any(|stmt| matches!(stmt, Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, _)));
+
+        if scoped {
+            self.emit(GeneratorOp::PushScope(functions));
+        }
+
+        for stmt in stmts {
+            self.lower_stmt(stmt)?;
+        }
+
+        if scoped {
+            self.emit(GeneratorOp::PopScope);
+        }
+
+        Ok(())
+    }
+
+    fn lower_for_loop(&mut self, init: &Stmt, condition: Option<&Expr>, increment: Option<&Expr>, body: &Stmt) -> ParseResult<()> {
+        let names = match init {
+            Stmt::Declaration(DeclarationKind::Let | DeclarationKind::Const, declarations) => {
+                declarations.iter().flat_map(|(pattern, _)| pattern.bound_names()).collect()
+            },
+            _ => Vec::new(),
+        };
+
+        let scoped = !names.is_empty();
+
+        if scoped {
+            self.emit(GeneratorOp::PushScope(Vec::new()));
+        }
+
+        self.lower_stmt(init)?;
+
+        if scoped {
+            self.emit(GeneratorOp::CopyIterationScope(names.clone()));
+        }
+
+        let enter = self.emit(GeneratorOp::EnterLoop { break_target: 0, continue_target: 0 });
+        let top = self.ops.len();
+
+        let to_exit = match condition {
+            Some(condition) => {
+                let condition = self.lower_expr(condition)?;
+                Some(self.emit(GeneratorOp::JumpIfFalse(condition, 0)))
+            },
+            None => None,
+        };
+
+        self.lower_stmt(body)?;
+        let next = self.ops.len();
+
+        if scoped {
+            self.emit(GeneratorOp::CopyIterationScope(names));
+        }
+
+        if let Some(increment) = increment {
+            let increment = self.lower_expr(increment)?;
+            self.emit(GeneratorOp::Exec(Stmt::Expression(Box::new(increment))));
+        }
+
+        self.emit(GeneratorOp::Jump(top));
+
+        if let Some(to_exit) = to_exit {
+            self.patch(to_exit);
+        }
+
+        self.exit_loop(enter, next);
+
+        if scoped {
+            self.emit(GeneratorOp::PopScope);
+        }
+
+        Ok(())
+    }
+
+    fn lower_for_in_of(&mut self, binding: &ForBinding, iterable: &Expr, body: &Stmt, is_for_in: bool) -> ParseResult<()> {
+        let mut iterable = self.lower_expr(iterable)?;
+
+        // for...in walks over an array of the keys
+        if is_for_in {
+            let keys = self.temporary();
+            self.emit(GeneratorOp::ForInKeys(iterable, keys.clone()));
+            iterable = Expr::Identifier(intern(&keys));
+        }
+
+        let (iterator, next, value) = (self.temporary(), self.temporary(), self.temporary());
+        self.emit(GeneratorOp::GetIterator(iterable, iterator.clone(), next.clone()));
+        self.emit(GeneratorOp::EnterIterator(iterator.clone(), next.clone()));
+
+        let enter = self.emit(GeneratorOp::EnterLoop { break_target: 0, continue_target: 0 });
+        let top = self.ops.len();
+        let step = self.emit(GeneratorOp::IteratorStep(iterator, next, value.clone(), 0));
+        self.lower_iteration(binding, value, body)?;
+        self.exit_iteration(enter, top, step);
+
+        Ok(())
+    }
+
+    /// A for await...of loop awaits each result of the iterator before unpacking it.
+    fn lower_for_await_of(&mut self, binding: &ForBinding, iterable: &Expr, body: &Stmt) -> ParseResult<()> {
+        let iterable = self.lower_expr(iterable)?;
+
+        let (iterator, next, result, value) = (self.temporary(), self.temporary(), self.temporary(), self.temporary());
+        self.emit(GeneratorOp::GetAsyncIterator(iterable, iterator.clone(), next.clone()));
+        self.emit(GeneratorOp::EnterIterator(iterator.clone(), next.clone()));
+
+        let enter = self.emit(GeneratorOp::EnterLoop { break_target: 0, continue_target: 0 });
+        let top = self.ops.len();
+        self.emit(GeneratorOp::IteratorNext(iterator, next, result.clone()));
+        self.emit(GeneratorOp::Await(Expr::Identifier(intern(&result)), Some(result.clone())));
+        let step = self.emit(GeneratorOp::IteratorComplete(result, value.clone(), 0));
+        self.lower_iteration(binding, value, body)?;
+        self.exit_iteration(enter, top, step);
+
+        Ok(())
+    }
+
+    /// Binds the value of an iteration of a for...in or for...of loop and runs the body.
+    fn lower_iteration(&mut self, binding: &ForBinding, value: String, body: &Stmt) -> ParseResult<()> {
+        let value = Expr::Identifier(intern(&value));
+
+        match binding {
+            ForBinding::Declaration(kind @ (DeclarationKind::Let | DeclarationKind::Const), pattern) => {
+                check_pattern(pattern)?;
+                self.emit(GeneratorOp::PushScope(Vec::new()));
+                self.emit(GeneratorOp::Exec(Stmt::Declaration(*kind, vec![(pattern.clone(), Some(value))])));
+                self.lower_stmt(body)?;
+                self.emit(GeneratorOp::PopScope);
+            },
+            ForBinding::Declaration(DeclarationKind::Var, pattern) => {
+                check_pattern(pattern)?;
+                self.emit(GeneratorOp::Exec(Stmt::Declaration(DeclarationKind::Var, vec![(pattern.clone(), Some(value))])));
+                self.lower_stmt(body)?;
+            },
+            ForBinding::Target(pattern) => {
+                check_pattern(pattern)?;
+                let assignment = Expr::DestructuringAssignment(Box::new(pattern.clone()), Box::new(value));
+                self.emit(GeneratorOp::Exec(Stmt::Expression(Box::new(assignment))));
+                self.lower_stmt(body)?;
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Ends the loop over an iterator, where `step` is the operation jumping out once it's done.
+    fn exit_iteration(&mut self, enter: usize, top: usize, step: usize) {
+        self.emit(GeneratorOp::Jump(top));
+
+        // running out of values leaves the iterator as it is, breaking out of the loop closes it
+        self.patch(step);
+        self.emit(GeneratorOp::ExitLoop);
+        self.emit(GeneratorOp::ExitIterator);
+        let to_end = self.emit(GeneratorOp::Jump(0));
+        let break_target = self.ops.len();
+        self.emit(GeneratorOp::CloseIterator);
+        self.patch(to_end);
+
+        self.ops[enter] = GeneratorOp::EnterLoop { break_target, continue_target: top };
+    }
+
+    /// Lowers an expression containing a `yield` into the operations leading up to it, returning
+    /// the expression that computes the rest once the generator resumes.
+    fn lower_expr(&mut self, expr: &Expr) -> ParseResult<Expr> {
+        if !expr_contains_yield(expr) {
+            return Ok(expr.clone());
+        }
+
+        if let Some((constructor, left, right)) = binary_parts(expr) {
+            let [left, right] = self.lower_pair(left, right)?;
+            return Ok(constructor(Box::new(left), Box::new(right)));
+        }
+
+        if let Some((constructor, operand, is_reference)) = unary_parts(expr) {
+            let operand = match is_reference {
+                true => self.lower_reference(operand, false)?,
+                false => self.lower_expr(operand)?,
+            };
+
+            return Ok(constructor(Box::new(operand)));
+        }
+
+        match expr {
+            Expr::Yield(argument) => {
+                let argument = self.lower_optional(argument.as_deref())?;
+                let result = self.temporary();
+                self.emit(GeneratorOp::Yield(argument, Some(result.clone())));
+                Ok(Expr::Identifier(intern(&result)))
+            },
+            Expr::YieldDelegate(argument) => {
+                let argument = self.lower_expr(argument)?;
+                let result = self.temporary();
+                self.emit(GeneratorOp::YieldDelegate(argument, Some(result.clone())));
+                Ok(Expr::Identifier(intern(&result)))
+            },
+            Expr::Await(argument) => {
+                let argument = self.lower_expr(argument)?;
+                let result = self.temporary();
+                self.emit(GeneratorOp::Await(argument, Some(result.clone())));
+                Ok(Expr::Identifier(intern(&result)))
+            },
+            Expr::LogicalAnd(left, right) | Expr::LogicalOr(left, right) | Expr::NullishCoalescing(left, right) => {
+                let constructor: BinaryConstructor = match expr {
+                    Expr::LogicalAnd(_, _) => Expr::LogicalAnd,
+                    Expr::LogicalOr(_, _) => Expr::LogicalOr,
+                    _ => Expr::NullishCoalescing,
+                };
+
+                if !expr_contains_yield(right) {
+                    return Ok(constructor(Box::new(self.lower_expr(left)?), right.clone()));
+                }
+
+                let result = self.temporary();
+                let left = self.lower_expr(left)?;
+                self.assign(&result, left);
+
+                // the right operand only runs when the left one doesn't decide the result
+                let current = Box::new(Expr::Identifier(intern(&result)));
+
+                let evaluates_right = match expr {
+                    Expr::LogicalAnd(_, _) => *current,
+                    Expr::LogicalOr(_, _) => Expr::LogicalNot(current),
+                    _ => Expr::Equals(current, Box::new(Expr::Null)),
+                };
+
+                let skip = self.emit(GeneratorOp::JumpIfFalse(evaluates_right, 0));
+                let right = self.lower_expr(right)?;
+                self.assign(&result, right);
+                self.patch(skip);
+
+                Ok(Expr::Identifier(intern(&result)))
+            },
+            Expr::Conditional(condition, consequent, alternate) => {
+                if !expr_contains_yield(consequent) && !expr_contains_yield(alternate) {
+                    let condition = self.lower_expr(condition)?;
+                    return Ok(Expr::Conditional(Box::new(condition), consequent.clone(), alternate.clone()));
+                }
+
+                let result = self.temporary();
+                let condition = self.lower_expr(condition)?;
+                let to_alternate = self.emit(GeneratorOp::JumpIfFalse(condition, 0));
+                let consequent = self.lower_expr(consequent)?;
+                self.assign(&result, consequent);
+                let to_end = self.emit(GeneratorOp::Jump(0));
+                self.patch(to_alternate);
+                let alternate = self.lower_expr(alternate)?;
+                self.assign(&result, alternate);
+                self.patch(to_end);
+
+                Ok(Expr::Identifier(intern(&result)))
+            },
+            Expr::Sequence(exprs) => {
+                let mut lowered = Expr::Identifier(intern("undefined"));
+
+                for (i, expr) in exprs.iter().enumerate() {
+                    lowered = self.lower_expr(expr)?;
+
+                    if i + 1 < exprs.len() {
+                        self.emit(GeneratorOp::Exec(Stmt::Expression(Box::new(lowered.clone()))));
+                    }
+                }
+
+                Ok(lowered)
+            },
+            Expr::Assignment(target, value) => {
+                let target = self.lower_reference(target, expr_contains_yield(value))?;
+                let value = self.lower_expr(value)?;
+                Ok(Expr::Assignment(Box::new(target), Box::new(value)))
+            },
+            Expr::CompoundAssignment(operator, target, value) => match operator {
+                // `a ||= b` is `a || (a = b)`, apart from evaluating `a` twice
+                Token::LogicalAnd | Token::LogicalOr | Token::NullishCoalescing => {
+                    let assignment = Expr::Assignment(target.clone(), value.clone());
+                    self.lower_expr(&Parser::make_binary(operator, (**target).clone(), assignment))
+                },
+                _ if !expr_contains_yield(value) => {
+                    let target = self.lower_reference(target, false)?;
+                    Ok(Expr::CompoundAssignment(operator.clone(), Box::new(target), value.clone()))
+                },
+                _ => {
+                    // the current value is read before the yield
+                    let target = self.lower_reference(target, true)?;
+                    let current = self.spill(target.clone());
+                    let value = self.lower_expr(value)?;
+                    Ok(Expr::Assignment(Box::new(target), Box::new(Parser::make_binary(operator, current, value))))
+                },
+            },
+            Expr::DestructuringAssignment(pattern, value) => {
+                check_pattern(pattern)?;
+                let value = self.lower_expr(value)?;
+                Ok(Expr::DestructuringAssignment(pattern.clone(), Box::new(value)))
+            },
+            Expr::FunctionCall(callee, args) | Expr::New(callee, args) => {
+                // the callee is evaluated before the arguments, keeping the object of method calls
+                let callee = match (args.iter().any(expr_contains_yield), callee.as_ref()) {
+                    (true, Expr::Member(_, _) | Expr::ComputedMember(_, _)) => self.lower_reference(callee, true)?,
+                    (true, callee) => {
+                        let callee = self.lower_expr(callee)?;
+                        self.spill(callee)
+                    },
+                    (false, callee) => self.lower_expr(callee)?,
+                };
+
+                let args = self.lower_list(&args.iter().collect::<Vec<_>>())?;
+
+                match expr {
+                    Expr::FunctionCall(_, _) => Ok(Expr::FunctionCall(Box::new(callee), args)),
+                    _ => Ok(Expr::New(Box::new(callee), args)),
+                }
+            },
+            Expr::Array(elements) => {
+                let operands: Vec<&Expr> = elements.iter().flatten().collect();
+                let mut lowered = self.lower_list(&operands)?.into_iter();
+
+                Ok(Expr::Array(elements.iter().map(|element| element.as_ref().and_then(|_| lowered.next())).collect()))
+            },
+            Expr::Object(members) => {
+                let mut operands = Vec::new();
+
+                for member in members {
+                    match member {
+                        ObjectMember::Property(name, value) => {
+                            if let PropertyName::Computed(key) = name {
+                                operands.push(key.as_ref());
+                            }
+
+                            operands.push(value);
+                        },
+                        ObjectMember::Getter(name, _) | ObjectMember::Setter(name, _) => {
+                            if let PropertyName::Computed(key) = name {
+                                operands.push(key.as_ref());
+                            }
+                        },
+                        ObjectMember::Spread(value) => operands.push(value),
+                    }
+                }
+
+                let mut lowered = self.lower_list(&operands)?.into_iter();
+                let mut next = || lowered.next().expect("one lowered expression per operand");
+
+                let lower_name = |name: &PropertyName, next: &mut dyn FnMut() -> Expr| match name {
+                    PropertyName::Literal(name) => PropertyName::Literal(name.clone()),
+                    PropertyName::Computed(_) => PropertyName::Computed(Box::new(next())),
+                };
+
+                let members = members
+                    .iter()
+                    .map(|member| match member {
+                        ObjectMember::Property(name, _) => {
+                            let name = lower_name(name, &mut next);
+                            ObjectMember::Property(name, next())
+                        },
+                        ObjectMember::Getter(name, def) => ObjectMember::Getter(lower_name(name, &mut next), def.clone()),
+                        ObjectMember::Setter(name, def) => ObjectMember::Setter(lower_name(name, &mut next), def.clone()),
+                        ObjectMember::Spread(_) => ObjectMember::Spread(next()),
+                    })
+                    .collect();
+
+                Ok(Expr::Object(members))
+            },
+            Expr::Spread(iterable) => Ok(Expr::Spread(Box::new(self.lower_expr(iterable)?))),
+            Expr::Member(object, name) => Ok(Expr::Member(Box::new(self.lower_expr(object)?), name.clone())),
+            Expr::ComputedMember(object, key) => {
+                let [object, key] = self.lower_pair(object, key)?;
+                Ok(Expr::ComputedMember(Box::new(object), Box::new(key)))
+            },
+            _ => Ok(expr.clone()),
+        }
+    }
+
+    fn lower_optional(&mut self, expr: Option<&Expr>) -> ParseResult<Option<Expr>> {
+        expr.map(|expr| self.lower_expr(expr)).transpose()
+    }
+
+    fn lower_pair(&mut self, left: &Expr, right: &Expr) -> ParseResult<[Expr; 2]> {
+        let mut lowered = self.lower_list(&[left, right])?.into_iter();
+        let left = lowered.next().expect("two lowered operands");
+        let right = lowered.next().expect("two lowered operands");
+        Ok([left, right])
+    }
+
+    /// Lowers operands that are evaluated left to right. The ones before the last operand with a
+    /// `yield` are evaluated into temporaries first, so they still run before it.
+    fn lower_list(&mut self, operands: &[&Expr]) -> ParseResult<Vec<Expr>> {
+        let last = operands.iter().rposition(|operand| expr_contains_yield(operand));
+        let mut lowered = Vec::with_capacity(operands.len());
+
+        for (i, operand) in operands.iter().enumerate() {
+            let operand = self.lower_expr(operand)?;
+
+            lowered.push(match last {
+                Some(last) if i < last => self.spill(operand),
+                _ => operand,
+            });
+        }
+
+        Ok(lowered)
+    }
+
+    /// Lowers an assignment target. With `spill`, the object and key of a member expression are
+    /// evaluated into temporaries, because a `yield` runs before the assignment happens.
+    fn lower_reference(&mut self, target: &Expr, spill: bool) -> ParseResult<Expr> {
+        match target {
+            Expr::Member(object, name) => {
+                let object = self.lower_expr(object)?;
+
+                let object = match spill {
+                    true => self.spill(object),
+                    false => object,
+                };
+
+                Ok(Expr::Member(Box::new(object), name.clone()))
+            },
+            Expr::ComputedMember(object, key) => {
+                let [object, key] = self.lower_pair(object, key)?;
+
+                let (object, key) = match spill {
+                    true => (self.spill(object), self.spill(key)),
+                    false => (object, key),
+                };
+
+                Ok(Expr::ComputedMember(Box::new(object), Box::new(key)))
+            },
+            target => self.lower_expr(target),
+        }
+    }
+
+    /// Evaluates an expression into a temporary, unless evaluating it later gives the same result.
+    fn spill(&mut self, expr: Expr) -> Expr {
+        match expr {
+            Expr::Float(_) | Expr::StringLiteral(_) | Expr::Boolean(_) | Expr::Null | Expr::This | Expr::Function(_) => expr,
+            Expr::Identifier(ref name) if name.starts_with('%') => expr,
+            Expr::Spread(iterable) => Expr::Spread(Box::new(self.spill(*iterable))),
+            expr => {
+                let temporary = self.temporary();
+                self.assign(&temporary, expr);
+                Expr::Identifier(intern(&temporary))
+            },
+        }
+    }
+
+    fn assign(&mut self, temporary: &str, value: Expr) {
+        let assignment = Expr::Assignment(Box::new(Expr::Identifier(intern(temporary))), Box::new(value));
+        self.emit(GeneratorOp::Exec(Stmt::Expression(Box::new(assignment))));
+    }
+
+    fn temporary(&mut self) -> String {
+        self.temporaries += 1;
+        format!("%{}", self.temporaries - 1)
+    }
+
+    fn emit(&mut self, op: GeneratorOp) -> usize {
+        self.ops.push(op);
+        self.ops.len() - 1
+    }
+
+    /// Points the jump at `at` to the next operation.
+    fn patch(&mut self, at: usize) {
+        let next = self.ops.len();
+
+        match &mut self.ops[at] {
+            GeneratorOp::Jump(target)
+            | GeneratorOp::JumpIfFalse(_, target)
+            | GeneratorOp::LeaveTry(target)
+            | GeneratorOp::IteratorStep(_, _, _, target)
+            | GeneratorOp::IteratorComplete(_, _, target) => *target = next,
+            op => unreachable!("{:?} does not jump", op),
+        }
+    }
+
+    fn exit_loop(&mut self, enter: usize, continue_target: usize) {
+        self.emit(GeneratorOp::ExitLoop);
+        self.ops[enter] = GeneratorOp::EnterLoop { break_target: self.ops.len(), continue_target };
+    }
+}
+
+fn check_pattern(pattern: &Pattern) -> ParseResult<()> {
+    match pattern_contains_yield(pattern) {
+        true => Err(JsError::syntax_error("yield and await are not supported inside destructuring patterns")),
+        false => Ok(()),
+    }
+}
+
+fn stmt_contains_yield(stmt: &Stmt) -> bool {
+    let optional = |expr: &Option<Expr>| expr.as_ref().is_some_and(expr_contains_yield);
+
+    match stmt {
+        Stmt::Declaration(_, declarations) => {
+            declarations.iter().any(|(pattern, value)| pattern_contains_yield(pattern) || optional(value))
+        },
+        Stmt::ControlFlow(condition, consequent, alternate) => {
+            expr_contains_yield(condition) || stmt_contains_yield(consequent) || stmt_contains_yield(alternate)
+        },
+        Stmt::CodeBlock(stmts) => stmts.iter().any(stmt_contains_yield),
+        Stmt::Throw(expr) => expr_contains_yield(expr),
+        Stmt::Expression(expr) => expr_contains_yield(expr),
+        Stmt::Return(expr) => optional(expr),
+        Stmt::ForLoop(init, condition, increment, body) => {
+            stmt_contains_yield(init) || optional(condition) || optional(increment) || stmt_contains_yield(body)
+        },
+        Stmt::ForOf(binding, iterable, body) | Stmt::ForIn(binding, iterable, body) => {
+            let binding = match binding {
+                ForBinding::Declaration(_, pattern) | ForBinding::Target(pattern) => pattern_contains_yield(pattern),
+            };
+
+            binding || expr_contains_yield(iterable) || stmt_contains_yield(body)
+        },
+        // awaiting each step suspends even when nothing else in the loop does
+        Stmt::ForAwaitOf(_, _, _) => true,
+        Stmt::Loop(body) => stmt_contains_yield(body),
+        Stmt::While(condition, body) => expr_contains_yield(condition) || stmt_contains_yield(body),
+        Stmt::Try(block, handler, finalizer) => {
+            stmt_contains_yield(block)
+                || handler.as_ref().is_some_and(|(_, body)| stmt_contains_yield(body))
+                || finalizer.as_ref().is_some_and(|finalizer| stmt_contains_yield(finalizer))
+        },
+        Stmt::Function(_) | Stmt::None | Stmt::Break | Stmt::Continue => false,
+    }
+}
+
+fn expr_contains_yield(expr: &Expr) -> bool {
+    if let Some((_, left, right)) = binary_parts(expr) {
+        return expr_contains_yield(left) || expr_contains_yield(right);
+    }
+
+    if let Some((_, operand, _)) = unary_parts(expr) {
+        return expr_contains_yield(operand);
+    }
+
+    match expr {
+        Expr::Yield(_) | Expr::YieldDelegate(_) | Expr::Await(_) => true,
+        Expr::LogicalAnd(left, right)
+        | Expr::LogicalOr(left, right)
+        | Expr::NullishCoalescing(left, right)
+        | Expr::Assignment(left, right)
+        | Expr::CompoundAssignment(_, left, right)
+        | Expr::ComputedMember(left, right) => expr_contains_yield(left) || expr_contains_yield(right),
+        Expr::Conditional(condition, consequent, alternate) => {
+            expr_contains_yield(condition) || expr_contains_yield(consequent) || expr_contains_yield(alternate)
+        },
+        Expr::DestructuringAssignment(pattern, value) => pattern_contains_yield(pattern) || expr_contains_yield(value),
+        Expr::Sequence(exprs) => exprs.iter().any(expr_contains_yield),
+        Expr::FunctionCall(callee, args) | Expr::New(callee, args) => {
+            expr_contains_yield(callee) || args.iter().any(expr_contains_yield)
+        },
+        Expr::Array(elements) => elements.iter().flatten().any(expr_contains_yield),
+        Expr::Object(members) => members.iter().any(|member| match member {
+            ObjectMember::Property(name, value) => name_contains_yield(name) || expr_contains_yield(value),
+            ObjectMember::Getter(name, _) | ObjectMember::Setter(name, _) => name_contains_yield(name),
+            ObjectMember::Spread(value) => expr_contains_yield(value),
+        }),
+        Expr::Spread(expr) | Expr::Member(expr, _) => expr_contains_yield(expr),
+        // nested functions are not part of the generator body
+        _ => false,
+    }
+}
+
+fn pattern_contains_yield(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::Identifier(_) => false,
+        Pattern::Expression(expr) => expr_contains_yield(expr),
+        Pattern::Array(elements, rest) => {
+            elements.iter().flatten().any(pattern_contains_yield) || rest.as_deref().is_some_and(pattern_contains_yield)
+        },
+        Pattern::Object(properties, rest) => {
+            properties.iter().any(|(name, pattern)| name_contains_yield(name) || pattern_contains_yield(pattern))
+                || rest.as_deref().is_some_and(pattern_contains_yield)
+        },
+        Pattern::Default(pattern, default) => pattern_contains_yield(pattern) || expr_contains_yield(default),
+    }
+}
+
+fn name_contains_yield(name: &PropertyName) -> bool {
+    match name {
+        PropertyName::Literal(_) => false,
+        PropertyName::Computed(key) => expr_contains_yield(key),
+    }
+}
+
+/// Binary operators that always evaluate both operands.
+fn binary_parts(expr: &Expr) -> Option<(BinaryConstructor, &Expr, &Expr)> {
+    let (constructor, left, right): (BinaryConstructor, _, _) = match expr {
+        Expr::Addition(left, right) => (Expr::Addition, left, right),
+        Expr::Subtraction(left, right) => (Expr::Subtraction, left, right),
+        Expr::Multiplication(left, right) => (Expr::Multiplication, left, right),
+        Expr::Division(left, right) => (Expr::Division, left, right),
+        Expr::Modulo(left, right) => (Expr::Modulo, left, right),
+        Expr::Exponentiation(left, right) => (Expr::Exponentiation, left, right),
+        Expr::Equals(left, right) => (Expr::Equals, left, right),
+        Expr::TypeCheckEquals(left, right) => (Expr::TypeCheckEquals, left, right),
+        Expr::NotEquals(left, right) => (Expr::NotEquals, left, right),
+        Expr::TypeNotEquals(left, right) => (Expr::TypeNotEquals, left, right),
+        Expr::GreaterThan(left, right) => (Expr::GreaterThan, left, right),
+        Expr::GreaterThanEquals(left, right) => (Expr::GreaterThanEquals, left, right),
+        Expr::LessThan(left, right) => (Expr::LessThan, left, right),
+        Expr::LessThanEquals(left, right) => (Expr::LessThanEquals, left, right),
+        Expr::BitwiseAnd(left, right) => (Expr::BitwiseAnd, left, right),
+        Expr::BitwiseOr(left, right) => (Expr::BitwiseOr, left, right),
+        Expr::BitwiseXor(left, right) => (Expr::BitwiseXor, left, right),
+        Expr::LeftShift(left, right) => (Expr::LeftShift, left, right),
+        Expr::RightShift(left, right) => (Expr::RightShift, left, right),
+        Expr::UnsignedRightShift(left, right) => (Expr::UnsignedRightShift, left, right),
+        Expr::InstanceOf(left, right) => (Expr::InstanceOf, left, right),
+        Expr::In(left, right) => (Expr::In, left, right),
+        _ => return None,
+    };
+
+    Some((constructor, left, right))
+}
+
+/// Unary operators, and whether their operand is a reference rather than a value.
+fn unary_parts(expr: &Expr) -> Option<(UnaryConstructor, &Expr, bool)> {
+    let (constructor, operand, is_reference): (UnaryConstructor, _, _) = match expr {
+        Expr::LogicalNot(operand) => (Expr::LogicalNot, operand, false),
+        Expr::Negation(operand) => (Expr::Negation, operand, false),
+        Expr::UnaryPlus(operand) => (Expr::UnaryPlus, operand, false),
+        Expr::BitwiseNot(operand) => (Expr::BitwiseNot, operand, false),
+        Expr::TypeOf(operand) => (Expr::TypeOf, operand, false),
+        Expr::Void(operand) => (Expr::Void, operand, false),
+        Expr::Delete(operand) => (Expr::Delete, operand, true),
+        Expr::PreIncrement(operand) => (Expr::PreIncrement, operand, true),
+        Expr::PreDecrement(operand) => (Expr::PreDecrement, operand, true),
+        Expr::PostIncrement(operand) => (Expr::PostIncrement, operand, true),
+        Expr::PostDecrement(operand) => (Expr::PostDecrement, operand, true),
+        _ => return None,
+    };
+
+    Some((constructor, operand, is_reference))
+}
@@ -1,519 +1,1450 @@
-use std::ptr::null;
+use std::borrow::Cow;
+use std::rc::Rc;
 
-use crate::types::{Expr, Stmt, Token};
+use crate::compiler::Bytecode;
+use crate::error::JsError;
+use crate::interpreter::{STACK_GROWTH, STACK_RED_ZONE};
+use crate::lexer::Lexer;
+use crate::regexp::{Flags, Regex};
+use crate::types::{intern, number_to_string, DeclarationKind, Expr, ForBinding, FunctionDef, GeneratorCode, ObjectMember, Pattern, PropertyName, Stmt, Token};
+
+mod generator;
+mod tail_calls;
+
+type ParseResult<T> = Result<T, JsError>;
+
+/// How deeply statements and expressions may nest.
+const MAX_NESTING_DEPTH: usize = 500;
 
 pub struct Parser<'a> {
-    tokens: &'a Vec<Token>,
+    tokens: Cow<'a, [Token]>,
+    // whether a line terminator precedes each token, for automatic semicolon insertion
+    line_breaks: Cow<'a, [bool]>,
+    // the lexer the tokens came from and where each of them starts, to scan a `/` it read as
+    // division again where an expression starts
+    lexer: Option<&'a Lexer>,
+    starts: Cow<'a, [usize]>,
     pos: usize,
+    // set while parsing the head of a for loop, where `in` starts a for...in loop instead of
+    // being a binary operator
+    no_in: bool,
+    // set once the function being parsed refers to `arguments`
+    uses_arguments: bool,
+    // set while parsing the body of a generator, where `yield` is allowed
+    in_generator: bool,
+    // set while parsing the body of an async function or the top level, where `await` is allowed
+    in_async: bool,
+    // set once the function being parsed, or the script, uses `await`
+    uses_await: bool,
+    // set while parsing strict mode code, which is everything after a "use strict" directive at
+    // the start of the script or of a function and the functions nested in it
+    strict: bool,
+    // how many statements and expressions the one being parsed is nested in
+    depth: usize,
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(tokens: &'a Vec<Token>) -> Self {
-        Parser { tokens, pos: 0 }
+    pub fn new(tokens: &'a [Token], line_breaks: &'a [bool]) -> Self {
+        Parser {
+            tokens: Cow::Borrowed(tokens),
+            line_breaks: Cow::Borrowed(line_breaks),
+            lexer: None,
+            starts: Cow::Borrowed(&[]),
+            pos: 0,
+            no_in: false,
+            uses_arguments: false,
+            in_generator: false,
+            in_async: true,
+            uses_await: false,
+            strict: false,
+            depth: 0,
+        }
+    }
+
+    /// Lets the parser scan the tokens of `lexer` again, for regular expressions it read as division.
+    pub fn with_lexer(mut self, lexer: &'a Lexer) -> Self {
+        self.lexer = Some(lexer);
+        self.starts = Cow::Borrowed(lexer.starts());
+        self
+    }
+
+    /// Whether the script parsed uses `await` outside of any function, so that it has to run as
+    /// the body of an async function.
+    pub fn has_top_level_await(&self) -> bool {
+        self.uses_await
+    }
+
+    /// Lowers the body of a script with top-level await, see `has_top_level_await`.
+    pub fn lower_script(stmts: &[Stmt]) -> ParseResult<GeneratorCode> {
+        generator::lower(stmts)
     }
 
-    pub fn parse(&mut self) -> Vec<Stmt> {
+    /// Parses one level of nesting deeper. The stack grows on the heap like it does for calls,
+    /// and code nested deeper than `MAX_NESTING_DEPTH` is a SyntaxError, which keeps the tree
+    /// shallow enough for what walks it.
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        self.nested_by(1, parse)
+    }
+
+    /// Parses `levels` levels of nesting deeper, for chains like `a + b + c` or `a.b.c` that are
+    /// parsed in a loop, but where each link nests in the next.
+    fn nested_by<T>(&mut self, levels: usize, parse: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        if self.depth + levels > MAX_NESTING_DEPTH {
+            return Err(JsError::syntax_error("Code is too deeply nested"));
+        }
+
+        self.depth += levels;
+        let result = stacker::maybe_grow(STACK_RED_ZONE, STACK_GROWTH, || parse(self));
+        self.depth -= levels;
+        result
+    }
+
+    pub fn parse(&mut self) -> ParseResult<Vec<Stmt>> {
         let mut stmts = Vec::new();
+        self.parse_directives();
 
-        while let Some(token) = self.next_token() {
-            match token {
-                Token::Let => stmts.push(self.parse_let()),
-                Token::Log => stmts.push(self.parse_log()),
-                Token::If => stmts.push(self.parse_if()),
-                Token::Loop => stmts.push(self.parse_loop()),
-                Token::While => stmts.push(self.parse_while()),
-                Token::Comment(_) => (),
-                Token::BraceOpen => stmts.push(Stmt::CodeBlock(self.parse_scope())),
-                Token::Function => stmts.push(self.parse_function()),
-                Token::ForLoop => stmts.push(self.parse_for_loop()),
-                Token::Return => {
-                    stmts.push(self.parse_return());
-                    break;
-                },
-                Token::Semicolon => (),
-                Token::Comma => (),
-                Token::BraceClose => (),
-                Token::Break => stmts.push(Stmt::Break),
-                _ => {
-                    // roll back the position
-                    // because we are not consuming the token
-                    self.pos -= 1;
+        while self.peek().is_some() {
+            stmts.push(self.parse_statement()?);
+        }
 
-                    let expr = self.parse_expr();
+        Ok(stmts)
+    }
 
-                    stmts.push(
-                        match expr {
-                            Expr::Assignment(name, value) => Stmt::Assignment(name, *value),
-                            Expr::FunctionCall(name, args) => Stmt::FunctionCall(name, args),
-                            _ => Stmt::Expression(Box::new(expr)),
-                        }
-                    );
-                },
+    /// Statements nest through here, see `nested`.
+    fn parse_statement(&mut self) -> ParseResult<Stmt> {
+        self.nested(Self::parse_statement_unguarded)
+    }
+
+    fn parse_statement_unguarded(&mut self) -> ParseResult<Stmt> {
+        let token = match self.next_token() {
+            Some(token) => token,
+            None => return Err(self.unexpected()),
+        };
+
+        match token {
+            Token::Let => {
+                let stmt = self.parse_declaration(DeclarationKind::Let)?;
+                self.consume_semicolon()?;
+                Ok(stmt)
             }
-        }
+            Token::Const => {
+                let stmt = self.parse_declaration(DeclarationKind::Const)?;
+                self.consume_semicolon()?;
+                Ok(stmt)
+            }
+            Token::Var => {
+                let stmt = self.parse_declaration(DeclarationKind::Var)?;
+                self.consume_semicolon()?;
+                Ok(stmt)
+            }
+            Token::If => self.parse_if(),
+            Token::Loop => self.parse_loop(),
+            Token::While => self.parse_while(),
+            Token::BraceOpen => Ok(Stmt::CodeBlock(self.parse_scope()?)),
+            Token::Function => Ok(Stmt::Function(self.parse_function(true, false)?)),
+            Token::Identifier(name) if name == "async" && self.is_async_function() => {
+                self.pos += 1;
+                Ok(Stmt::Function(self.parse_function(true, true)?))
+            }
+            Token::ForLoop => self.parse_for_loop(),
+            Token::Return => self.parse_return(),
+            Token::Throw => self.parse_throw(),
+            Token::Try => self.parse_try(),
+            Token::Semicolon => Ok(Stmt::None),
+            Token::Break => {
+                self.consume_semicolon()?;
+                Ok(Stmt::Break)
+            }
+            Token::Continue => {
+                self.consume_semicolon()?;
+                Ok(Stmt::Continue)
+            }
+            _ => {
+                // roll back the position
+                // because we are not consuming the token
+                self.pos -= 1;
+
+                let expr = self.parse_expr()?;
+                self.consume_semicolon()?;
 
-        stmts
+                Ok(Stmt::Expression(Box::new(expr)))
+            }
+        }
     }
 
-    fn get_tokens(&mut self) -> Vec<Token> {
-        let mut scope_tokens = Vec::new();
+    /// Parses the statements of a block whose opening brace was already consumed, up to and
+    /// including the closing brace.
+    fn parse_scope(&mut self) -> ParseResult<Vec<Stmt>> {
+        let mut stmts = Vec::new();
 
-        while let Some(token) = self.next_token() {
-            match token {
-                Token::BraceClose => {
-                    scope_tokens.push(token);
-                    break;
-                }
-                Token::BraceOpen => {
-                    scope_tokens.push(token);
-                    scope_tokens.append(&mut self.get_tokens());
+        loop {
+            match self.peek() {
+                Some(Token::BraceClose) => {
+                    self.pos += 1;
+                    return Ok(stmts);
                 }
-                _ => scope_tokens.push(token),
+                Some(_) => stmts.push(self.parse_statement()?),
+                None => return Err(self.unexpected()),
             }
         }
+    }
 
-        scope_tokens
+    fn parse_block(&mut self) -> ParseResult<Stmt> {
+        self.expect(Token::BraceOpen)?;
+        Ok(Stmt::CodeBlock(self.parse_scope()?))
     }
 
-    fn parse_scope(&mut self) -> Vec<Stmt> {
-        let scope_tokens = self.get_tokens();
+    fn parse_return(&mut self) -> ParseResult<Stmt> {
+        // `return` followed by a line break returns undefined
+        if matches!(self.peek(), None | Some(Token::Semicolon) | Some(Token::BraceClose)) || self.line_break_before() {
+            self.consume_semicolon()?;
+            return Ok(Stmt::Return(None));
+        }
 
-        let mut parser: Parser = Parser::new(&scope_tokens);
-        let ast = parser.parse();
+        let expr_to_return = self.parse_expr()?;
+        self.consume_semicolon()?;
 
-        ast
+        Ok(Stmt::Return(Some(expr_to_return)))
     }
 
-    fn parse_return(&mut self) -> Stmt {
-        let expr_to_return = self.parse_expr();
-        Stmt::Return(Box::new(expr_to_return))
+    fn parse_throw(&mut self) -> ParseResult<Stmt> {
+        if self.line_break_before() {
+            return Err(JsError::syntax_error("Illegal newline after throw"));
+        }
+
+        let expr = self.parse_expr()?;
+        self.consume_semicolon()?;
+
+        Ok(Stmt::Throw(expr))
     }
 
-    fn parse_function(&mut self) -> Stmt {
-        let mut args: Vec<String> = Vec::new();
+    fn parse_try(&mut self) -> ParseResult<Stmt> {
+        let block = self.parse_block()?;
+        let mut handler = None;
+        let mut finalizer = None;
+
+        if self.eat(&Token::Catch) {
+            let mut param = None;
+
+            if self.eat(&Token::ParenOpen) {
+                param = Some(self.parse_identifier()?);
+                self.expect(Token::ParenClose)?;
+            }
+
+            handler = Some((param, Box::new(self.parse_block()?)));
+        }
+
+        if self.eat(&Token::Finally) {
+            finalizer = Some(Box::new(self.parse_block()?));
+        }
 
-        let function_name = match self.next_token() {
-            Some(Token::Identifier(name)) => name,
-            _ => panic!("Expected a function name"),
+        if handler.is_none() && finalizer.is_none() {
+            return Err(JsError::syntax_error("Missing catch or finally after try"));
+        }
+
+        Ok(Stmt::Try(Box::new(block), handler, finalizer))
+    }
+
+    /// Whether the `async` just consumed starts an async function, which it only does when
+    /// `function` follows on the same line.
+    fn is_async_function(&self) -> bool {
+        self.peek() == Some(&Token::Function) && !self.line_break_before()
+    }
+
+    /// Parses a function after the `function` keyword. Declarations must be named.
+    fn parse_function(&mut self, is_declaration: bool, is_async: bool) -> ParseResult<Rc<FunctionDef>> {
+        let is_generator = self.eat(&Token::Multiplication);
+
+        let name = match self.peek() {
+            Some(Token::Identifier(name)) => {
+                let name = intern(name);
+                self.pos += 1;
+                Some(name)
+            }
+            _ if is_declaration => return Err(JsError::syntax_error("Function statements require a function name")),
+            _ => None,
+        };
+
+        let mut def = self.parse_function_rest(name, is_generator, is_async)?;
+
+        // a declaration is bound in the scope around it instead
+        Rc::make_mut(&mut def).binds_name = !is_declaration && def.name.is_some();
+        Ok(def)
+    }
+
+    /// Parses the parameter list and body of a function or method.
+    fn parse_function_rest(&mut self, name: Option<Rc<str>>, is_generator: bool, is_async: bool) -> ParseResult<Rc<FunctionDef>> {
+        let outer_uses_arguments = std::mem::replace(&mut self.uses_arguments, false);
+        let result = self.with_function_context(|parser| {
+            parser.with_no_in(false, |parser| parser.parse_parameters_and_body(is_generator, is_async))
+        });
+        let uses_arguments = std::mem::replace(&mut self.uses_arguments, outer_uses_arguments);
+
+        let (params, rest, body) = result?;
+
+        let def = FunctionDef {
+            name,
+            params,
+            rest,
+            generator: Self::lower_function(&body, is_generator, is_async)?,
+            body,
+            uses_arguments,
+            is_generator,
+            is_async,
+            is_arrow: false,
+            binds_name: false,
+            bytecode: Bytecode::default(),
         };
 
-        if self.next_token() != Some(Token::ParenOpen) {
-            panic!(
-                "Expected opening paranthesis after function name {}",
-                function_name
-            )
+        Ok(Rc::new(def))
+    }
+
+    /// Generator and async bodies are lowered so that they can be suspended.
+    fn lower_function(body: &[Stmt], is_generator: bool, is_async: bool) -> ParseResult<Option<Rc<GeneratorCode>>> {
+        match is_generator || is_async {
+            true => Ok(Some(Rc::new(generator::lower(body)?))),
+            false => Ok(None),
         }
+    }
 
-        while let Some(token) = self.next_token() {
-            match token {
-                Token::ParenClose => break,
-                Token::Identifier(name) => args.push(name),
-                Token::Comma => (),
-                _ => panic!("Unexpected token in function defination"),
+    /// Runs `f` for a nested function, restoring where `yield` and `await` are allowed and whether
+    /// the code is strict afterwards.
+    fn with_function_context<T>(&mut self, f: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        let (in_generator, in_async, strict) = (self.in_generator, self.in_async, self.strict);
+        let outer_uses_await = std::mem::replace(&mut self.uses_await, false);
+        let result = f(self);
+        (self.in_generator, self.in_async, self.strict, self.uses_await) = (in_generator, in_async, strict, outer_uses_await);
+        result
+    }
+
+    /// Looks through the string literal statements a script or function body starts with for a
+    /// "use strict" directive, leaving them to be parsed as the statements they also are.
+    fn parse_directives(&mut self) {
+        let mut pos = self.pos;
+
+        while let Some(Token::StringLiteral(directive)) = self.tokens.get(pos) {
+            let end = match self.tokens.get(pos + 1) {
+                Some(Token::Semicolon) => pos + 2,
+                Some(Token::BraceClose) | None => pos + 1,
+                _ if self.line_breaks.get(pos + 1).copied().unwrap_or(false) => pos + 1,
+                _ => return,
+            };
+
+            if directive == "use strict" {
+                self.strict = true;
             }
+
+            pos = end;
         }
+    }
 
-        if self.next_token() != Some(Token::BraceOpen) {
-            panic!(
-                "Expected opening braces after function arguments {}",
-                function_name
-            )
+    fn parse_parameters_and_body(&mut self, is_generator: bool, is_async: bool) -> ParseResult<(Vec<Pattern>, Option<Pattern>, Vec<Stmt>)> {
+        let (params, rest) = self.parse_parameters()?;
+
+        self.expect(Token::BraceOpen)?;
+        self.in_generator = is_generator;
+        self.in_async = is_async;
+        self.parse_directives();
+        let mut body = self.parse_scope()?;
+
+        if self.strict && !is_generator && !is_async {
+            tail_calls::mark(&mut body);
         }
 
-        Stmt::Function(function_name, args, Box::new(Stmt::CodeBlock(self.parse_scope())))
+        Ok((params, rest, body))
     }
 
-    fn parse_function_call(&mut self, name: String) -> Expr {
-        let mut args = Vec::new();
-        let mut arg_expressions = Vec::new();
+    /// Parses a parenthesized parameter list.
+    fn parse_parameters(&mut self) -> ParseResult<(Vec<Pattern>, Option<Pattern>)> {
+        // neither `yield` nor `await` are allowed in parameter defaults, not even in generators
+        // and async functions
+        self.in_generator = false;
+        self.in_async = false;
+        self.expect(Token::ParenOpen)?;
+
+        let mut params = Vec::new();
+        let mut rest = None;
+
+        while !self.eat(&Token::ParenClose) {
+            // the rest parameter must be the last one, without a trailing comma
+            if self.eat(&Token::Ellipsis) {
+                rest = Some(self.parse_pattern(true)?);
+                self.expect(Token::ParenClose)?;
+                break;
+            }
 
-        arg_expressions.push(Vec::new());
+            params.push(self.parse_pattern_with_default(true)?);
 
-        while let Some(token) = self.next_token() {
-            match token {
-                Token::ParenClose => break,
-                Token::Comma => {
-                    let current_arg_tokens = Vec::new();
-                    arg_expressions.push(current_arg_tokens);
-                }
-                _ => arg_expressions.last_mut().unwrap().push(token),
+            if !self.eat(&Token::Comma) {
+                self.expect(Token::ParenClose)?;
+                break;
             }
         }
 
-        println!("{:?}", arg_expressions);
+        Ok((params, rest))
+    }
+
+    /// Parses an arrow function if one starts at the current token, otherwise leaves the position
+    /// as it is and returns `None`.
+    fn try_parse_arrow(&mut self) -> ParseResult<Option<Expr>> {
+        let is_async = matches!(self.peek(), Some(Token::Identifier(name)) if name == "async")
+            && matches!(self.tokens.get(self.pos + 1), Some(Token::Identifier(_) | Token::ParenOpen))
+            && !self.line_breaks.get(self.pos + 1).copied().unwrap_or(false);
+
+        let start = self.pos + is_async as usize;
+
+        // the parameters end right before the arrow
+        let arrow = match self.tokens.get(start) {
+            Some(Token::Identifier(_)) => start + 1,
+            Some(Token::ParenOpen) => match self.matching_paren(start) {
+                Some(close) => close + 1,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        if self.tokens.get(arrow) != Some(&Token::Arrow) || self.line_breaks.get(arrow).copied().unwrap_or(false) {
+            return Ok(None);
+        }
+
+        self.pos = start;
+
+        let result = self.with_function_context(|parser| {
+            let (params, rest) = match parser.peek() {
+                Some(Token::ParenOpen) => parser.with_no_in(false, |parser| parser.parse_parameters())?,
+                _ => (vec![Pattern::Identifier(parser.parse_identifier()?)], None),
+            };
 
-        for mut arg_tokens in arg_expressions {
-            arg_tokens.push(Token::Semicolon);
-            let mut parser: Parser = Parser::new(&arg_tokens);
+            parser.expect(Token::Arrow)?;
+            parser.in_generator = false;
+            parser.in_async = is_async;
 
-            let mut ast = parser.parse();
-            let expr = ast.pop().expect("Expected expression");
+            // a concise body is an expression, which is what the arrow returns
+            let mut body = match parser.eat(&Token::BraceOpen) {
+                true => {
+                    parser.parse_directives();
+                    parser.with_no_in(false, |parser| parser.parse_scope())?
+                },
+                false => vec![Stmt::Return(Some(parser.parse_assignment()?))],
+            };
 
-            match expr {
-                Stmt::Expression(expr) => args.push(*expr),
-                _ => panic!("Expected expression"),
+            if parser.strict && !is_async {
+                tail_calls::mark(&mut body);
+            }
+
+            Ok((params, rest, body))
+        });
+
+        let (params, rest, body) = result?;
+
+        // arrows see the `arguments` of the enclosing function, so their references to it count
+        // for that function instead
+        let def = FunctionDef {
+            name: None,
+            params,
+            rest,
+            generator: Self::lower_function(&body, false, is_async)?,
+            body,
+            uses_arguments: false,
+            is_generator: false,
+            is_async,
+            is_arrow: true,
+            binds_name: false,
+            bytecode: Bytecode::default(),
+        };
+
+        Ok(Some(Expr::Function(Rc::new(def))))
+    }
+
+    /// The position of the parenthesis closing the one at `open`.
+    fn matching_paren(&self, open: usize) -> Option<usize> {
+        let mut depth = 0;
+
+        for (i, token) in self.tokens.iter().enumerate().skip(open) {
+            match token {
+                Token::ParenOpen => depth += 1,
+                Token::ParenClose if depth == 1 => return Some(i),
+                Token::ParenClose => depth -= 1,
+                _ => (),
             }
         }
 
-        // while let Some(token) = self.next_token() {
-        //     match token {
-        //         Token::ParenClose => break,
-        //         Token::Comma => (),
-        //         _ => {
-        //             args.push(self.parse_expr());
-        //         },
-        //     }
-        // }
+        None
+    }
 
-        Expr::FunctionCall(name, args)
+    fn parse_arguments(&mut self) -> ParseResult<Vec<Expr>> {
+        self.with_no_in(false, |parser| parser.parse_argument_list())
     }
 
-    fn parse_for_loop(&mut self) -> Stmt {
-        let mut i = 0;
-        let mut initiation = Stmt::None;
-        let mut condition = Stmt::None;
-        let mut increment = Stmt::None;
+    fn parse_argument_list(&mut self) -> ParseResult<Vec<Expr>> {
+        let mut args = Vec::new();
 
-        match self.next_token() {
-            Some(Token::ParenOpen) => {
-                let mut tokens: Vec<Token> = Vec::new();
-                while let Some(token) = self.next_token() {
-                    match token {
-                        Token::ParenClose => break,
-                        _ => tokens.push(token),
+        while !self.eat(&Token::ParenClose) {
+            args.push(self.parse_spread_or_assignment()?);
+
+            if !self.eat(&Token::Comma) {
+                self.expect(Token::ParenClose)?;
+                break;
+            }
+        }
+
+        Ok(args)
+    }
+
+    fn parse_for_loop(&mut self) -> ParseResult<Stmt> {
+        let is_await = self.eat(&Token::Await);
+
+        if is_await {
+            if !self.in_async {
+                return Err(JsError::syntax_error("for await is only valid in async functions and the top level bodies of modules"));
+            }
+
+            self.uses_await = true;
+        }
+
+        self.expect(Token::ParenOpen)?;
+
+        let kind = match self.peek() {
+            Some(Token::Let) => Some(DeclarationKind::Let),
+            Some(Token::Const) => Some(DeclarationKind::Const),
+            Some(Token::Var) => Some(DeclarationKind::Var),
+            _ => None,
+        };
+
+        let initiation = match kind {
+            Some(kind) => {
+                self.pos += 1;
+                let start = self.pos;
+
+                if let Ok(pattern) = self.parse_pattern(true) {
+                    if self.peek().is_some_and(Self::is_for_in_of) {
+                        return self.parse_for_in_of(ForBinding::Declaration(kind, pattern), is_await);
                     }
                 }
 
-                let mut parser: Parser = Parser::new(&tokens);
-                let expr_list = parser.parse();
+                self.pos = start;
+                self.with_no_in(true, |parser| parser.parse_declaration(kind))?
+            }
+            None if self.peek() == Some(&Token::Semicolon) => Stmt::None,
+            None => {
+                if let Some(pattern) = self.try_parse_assignment_pattern(Self::is_for_in_of) {
+                    return self.parse_for_in_of(ForBinding::Target(pattern), is_await);
+                }
 
-                if expr_list.len() == 3 {
-                    initiation = expr_list[0].clone();
-                    condition = expr_list[1].clone();
-                    increment = expr_list[2].clone();
-                } else {
-                    panic!("Expected 3 expressions in for loop");
+                let expr = self.with_no_in(true, |parser| parser.parse_expr())?;
+
+                if self.peek().is_some_and(Self::is_for_in_of) {
+                    let target = match expr {
+                        Expr::Identifier(name) => Pattern::Identifier(name),
+                        expr if Self::is_assignment_target(&expr) => Pattern::Expression(Box::new(expr)),
+                        _ => return Err(JsError::syntax_error("Invalid left-hand side in for loop")),
+                    };
+
+                    return self.parse_for_in_of(ForBinding::Target(target), is_await);
                 }
+
+                Stmt::Expression(Box::new(expr))
             }
-            _ => panic!("Expected opening paranthesis after for"),
         };
 
+        // only for...of loops can be awaited
+        if is_await {
+            return Err(self.unexpected());
+        }
 
-        let code_block = match self.next_token() {
+        self.expect(Token::Semicolon)?;
 
-            Some(Token::BraceOpen) => self.parse_scope(),
-            _ => panic!("Expected for loop body")
+        let condition = match self.peek() {
+            Some(Token::Semicolon) => None,
+            _ => Some(self.parse_expr()?),
         };
 
-        Stmt::ForLoop(
+        self.expect(Token::Semicolon)?;
+
+        let increment = match self.peek() {
+            Some(Token::ParenClose) => None,
+            _ => Some(self.parse_expr()?),
+        };
+
+        self.expect(Token::ParenClose)?;
+
+        let code_block = self.parse_statement()?;
+
+        Ok(Stmt::ForLoop(
             Box::new(initiation),
-            Box::new(condition),
-            Box::new(increment),
-            Box::new(Stmt::CodeBlock(code_block))
-        )
+            condition,
+            increment,
+            Box::new(code_block)
+        ))
     }
 
-    fn parse_let(&mut self) -> Stmt {
-        let name = match self.next_token() {
-            Some(Token::Identifier(name)) => name,
-            _ => panic!("Expected identifier after let"),
-        };
+    /// `of` is not a reserved word, it only means something right after the binding of a for loop.
+    fn is_for_in_of(token: &Token) -> bool {
+        match token {
+            Token::In => true,
+            Token::Identifier(name) => name == "of",
+            _ => false,
+        }
+    }
 
-        let value = match self.next_token() {
-            Some(Token::Assign) => self.parse_expr(),
-            _ => panic!("Expected equals after identifier"),
+    /// Parses the rest of a `for...in` or `for...of` loop, starting at the `in` or `of`.
+    fn parse_for_in_of(&mut self, binding: ForBinding, is_await: bool) -> ParseResult<Stmt> {
+        let is_of = self.next_token() != Some(Token::In);
+
+        if is_await && !is_of {
+            self.pos -= 1;
+            return Err(self.unexpected());
+        }
+
+        // for...of takes a single assignment expression, for...in allows the comma operator
+        let object = match is_of {
+            true => self.parse_assignment()?,
+            false => self.parse_expr()?,
         };
 
-        Stmt::Let(name, value)
+        self.expect(Token::ParenClose)?;
+        let body = self.parse_statement()?;
+
+        match (is_of, is_await) {
+            (true, true) => Ok(Stmt::ForAwaitOf(binding, Box::new(object), Box::new(body))),
+            (true, false) => Ok(Stmt::ForOf(binding, Box::new(object), Box::new(body))),
+            (false, _) => Ok(Stmt::ForIn(binding, Box::new(object), Box::new(body))),
+        }
     }
 
-    fn parse_log(&mut self) -> Stmt {
-        match self.next_token() {
-            Some(Token::ParenOpen) => {
-                let expr = self.parse_expr();
+    fn parse_declaration(&mut self, kind: DeclarationKind) -> ParseResult<Stmt> {
+        let mut declarations = Vec::new();
+
+        loop {
+            let pattern = self.parse_pattern(true)?;
 
-                match self.next_token() {
-                    Some(Token::ParenClose) => Stmt::Log(expr),
-                    _ => panic!("Expected closing parenthesis"),
+            let value = match self.eat(&Token::Assign) {
+                true => Some(self.parse_assignment()?),
+                false if !matches!(pattern, Pattern::Identifier(_)) => {
+                    return Err(JsError::syntax_error("Missing initializer in destructuring declaration"));
+                }
+                false if kind == DeclarationKind::Const => {
+                    return Err(JsError::syntax_error("Missing initializer in const declaration"));
                 }
+                false => None,
+            };
+
+            declarations.push((pattern, value));
+
+            if !self.eat(&Token::Comma) {
+                break;
             }
-            _ => panic!("Expected opening parenthesis"),
         }
+
+        Ok(Stmt::Declaration(kind, declarations))
     }
 
-    fn parse_if(&mut self) -> Stmt {
-        let expr = match self.next_token() {
-            Some(Token::ParenOpen) => {
-                let condition = self.parse_expr();
+    fn parse_if(&mut self) -> ParseResult<Stmt> {
+        self.expect(Token::ParenOpen)?;
+        let condition = self.parse_expr()?;
+        self.expect(Token::ParenClose)?;
+
+        let consequent = self.parse_statement()?;
+
+        let alternate = match self.eat(&Token::Else) {
+            true => self.parse_statement()?,
+            false => Stmt::None,
+        };
+
+        Ok(Stmt::ControlFlow(Box::new(condition), Box::new(consequent), Box::new(alternate)))
+    }
+
+    fn parse_loop(&mut self) -> ParseResult<Stmt> {
+        let code_block = self.parse_statement()?;
+
+        Ok(Stmt::Loop(Box::new(code_block)))
+    }
+
+    fn parse_while(&mut self) -> ParseResult<Stmt> {
+        self.expect(Token::ParenOpen)?;
+        let condition = self.parse_expr()?;
+        self.expect(Token::ParenClose)?;
+
+        let code_block = self.parse_statement()?;
+
+        Ok(Stmt::While(Box::new(condition), Box::new(code_block)))
+    }
+
+    /// Expression including the comma operator.
+    fn parse_expr(&mut self) -> ParseResult<Expr> {
+        let expr = self.parse_assignment()?;
+
+        if self.peek() != Some(&Token::Comma) {
+            return Ok(expr);
+        }
+
+        let mut exprs = vec![expr];
+
+        while self.eat(&Token::Comma) {
+            exprs.push(self.parse_assignment()?);
+        }
+
+        Ok(Expr::Sequence(exprs))
+    }
+
+    /// Expressions nest through here, see `nested`.
+    fn parse_assignment(&mut self) -> ParseResult<Expr> {
+        self.nested(Self::parse_assignment_unguarded)
+    }
+
+    fn parse_assignment_unguarded(&mut self) -> ParseResult<Expr> {
+        if self.peek() == Some(&Token::Yield) {
+            return self.parse_yield();
+        }
+
+        if let Some(arrow) = self.try_parse_arrow()? {
+            return Ok(arrow);
+        }
+
+        if let Some(pattern) = self.try_parse_assignment_pattern(|token| *token == Token::Assign) {
+            self.pos += 1;
+            let value = self.parse_assignment()?;
+            return Ok(Expr::DestructuringAssignment(Box::new(pattern), Box::new(value)));
+        }
+
+        let left = self.parse_conditional()?;
+
+        let operator = match self.peek() {
+            Some(Token::Assign) => None,
+            Some(Token::AdditionAssign) => Some(Token::Addition),
+            Some(Token::SubtractionAssign) => Some(Token::Subtraction),
+            Some(Token::MultiplicationAssign) => Some(Token::Multiplication),
+            Some(Token::DivisionAssign) => Some(Token::Division),
+            Some(Token::ModuloAssign) => Some(Token::Modulo),
+            Some(Token::ExponentiationAssign) => Some(Token::Exponentiation),
+            Some(Token::LeftShiftAssign) => Some(Token::LeftShift),
+            Some(Token::RightShiftAssign) => Some(Token::RightShift),
+            Some(Token::UnsignedRightShiftAssign) => Some(Token::UnsignedRightShift),
+            Some(Token::BitwiseAndAssign) => Some(Token::BitwiseAnd),
+            Some(Token::BitwiseOrAssign) => Some(Token::BitwiseOr),
+            Some(Token::BitwiseXorAssign) => Some(Token::BitwiseXor),
+            Some(Token::LogicalAndAssign) => Some(Token::LogicalAnd),
+            Some(Token::LogicalOrAssign) => Some(Token::LogicalOr),
+            Some(Token::NullishCoalescingAssign) => Some(Token::NullishCoalescing),
+            _ => return Ok(left),
+        };
+
+        self.pos += 1;
+
+        if !Self::is_assignment_target(&left) {
+            return Err(JsError::syntax_error("Invalid left-hand side in assignment"));
+        }
+
+        let right = self.parse_assignment()?;
+
+        Ok(match operator {
+            None => Expr::Assignment(Box::new(left), Box::new(right)),
+            Some(operator) => Expr::CompoundAssignment(operator, Box::new(left), Box::new(right)),
+        })
+    }
+
+    fn parse_yield(&mut self) -> ParseResult<Expr> {
+        if !self.in_generator {
+            return Err(JsError::syntax_error("yield is only valid in generator functions"));
+        }
+
+        self.pos += 1;
+
+        if self.eat(&Token::Multiplication) {
+            if self.in_async {
+                return Err(JsError::syntax_error("yield* is not supported in async generators"));
+            }
+
+            return Ok(Expr::YieldDelegate(Box::new(self.parse_assignment()?)));
+        }
+
+        // the argument is optional, and can't start on the next line
+        let has_argument = !self.line_break_before() && !matches!(
+            self.peek(),
+            None | Some(Token::ParenClose | Token::BracketClose | Token::BraceClose | Token::Comma | Token::Semicolon | Token::Colon)
+        );
+
+        match has_argument {
+            true => Ok(Expr::Yield(Some(Box::new(self.parse_assignment()?)))),
+            false => Ok(Expr::Yield(None)),
+        }
+    }
+
+    fn is_assignment_target(expr: &Expr) -> bool {
+        matches!(expr, Expr::Identifier(_) | Expr::Member(_, _) | Expr::ComputedMember(_, _))
+    }
+
+    /// Array and object literals are parsed as patterns when they turn out to be followed by a
+    /// token accepted by `followed_by`, such as `=`. Otherwise this rewinds and returns `None`.
+    fn try_parse_assignment_pattern(&mut self, followed_by: impl Fn(&Token) -> bool) -> Option<Pattern> {
+        if !matches!(self.peek(), Some(Token::BracketOpen | Token::BraceOpen)) {
+            return None;
+        }
+
+        let start = self.pos;
+
+        match self.parse_pattern(false) {
+            Ok(pattern) if self.peek().is_some_and(followed_by) => Some(pattern),
+            _ => {
+                self.pos = start;
+                None
+            }
+        }
+    }
 
-                // skip closing parenthesis
+    /// Parses the target of a binding or an assignment. Binding patterns, as in declarations and
+    /// parameters, bind identifiers only, while assignment patterns may also target properties.
+    fn parse_pattern(&mut self, is_binding: bool) -> ParseResult<Pattern> {
+        self.nested(|parser| parser.parse_pattern_unguarded(is_binding))
+    }
+
+    fn parse_pattern_unguarded(&mut self, is_binding: bool) -> ParseResult<Pattern> {
+        match self.peek() {
+            Some(Token::BracketOpen) => {
+                self.pos += 1;
+                self.with_no_in(false, |parser| parser.parse_array_pattern(is_binding))
+            }
+            Some(Token::BraceOpen) => {
                 self.pos += 1;
+                self.with_no_in(false, |parser| parser.parse_object_pattern(is_binding))
+            }
+            _ if is_binding => Ok(Pattern::Identifier(self.parse_identifier()?)),
+            _ => match self.parse_call_member()? {
+                Expr::Identifier(name) => Ok(Pattern::Identifier(name)),
+                target if Self::is_assignment_target(&target) => Ok(Pattern::Expression(Box::new(target))),
+                _ => Err(JsError::syntax_error("Invalid destructuring assignment target")),
+            },
+        }
+    }
 
-                let _token = self.tokens.get(self.pos);
-
-                match self.next_token() {
-                    Some(Token::BraceOpen) => {
-                        let if_ast = self.parse_scope();
-
-                        let next_token = self.next_token();
-
-                        match next_token {
-                            Some(Token::ElseIf) => {
-                                let else_if_stmt = self.parse_if();
-                                Expr::ControlFlow(
-                                    Box::new(condition),
-                                    Box::new(Stmt::CodeBlock(if_ast)),
-                                    Box::new(else_if_stmt),
-                                )
-                            }
-                            Some(Token::Else) => {
-                                //
-                                self.pos += 1;
-
-                                let else_ast = self.parse_scope();
-
-                                let _token = self.tokens.get(self.pos);
-
-                                Expr::ControlFlow(
-                                    Box::new(condition),
-                                    Box::new(Stmt::CodeBlock(if_ast)),
-                                    Box::new(Stmt::ControlFlow(
-                                        Box::new(Expr::Boolean(true)),
-                                        Box::new(Stmt::CodeBlock(else_ast)),
-                                        Box::new(Stmt::None),
-                                    )),
-                                )
-                            }
-                            _ => {
-                                self.pos -= 1;
-                                Expr::ControlFlow(
-                                    Box::new(condition),
-                                    Box::new(Stmt::CodeBlock(if_ast)),
-                                    Box::new(Stmt::None),
-                                )
-                            }
-                        }
+    fn parse_pattern_with_default(&mut self, is_binding: bool) -> ParseResult<Pattern> {
+        let pattern = self.parse_pattern(is_binding)?;
+        self.parse_pattern_default(pattern)
+    }
+
+    fn parse_pattern_default(&mut self, pattern: Pattern) -> ParseResult<Pattern> {
+        match self.eat(&Token::Assign) {
+            true => Ok(Pattern::Default(Box::new(pattern), Box::new(self.parse_assignment()?))),
+            false => Ok(pattern),
+        }
+    }
+
+    /// Parses an array pattern after its opening bracket.
+    fn parse_array_pattern(&mut self, is_binding: bool) -> ParseResult<Pattern> {
+        let mut elements = Vec::new();
+
+        loop {
+            match self.peek() {
+                Some(Token::BracketClose) => {
+                    self.pos += 1;
+                    return Ok(Pattern::Array(elements, None));
+                }
+                Some(Token::Comma) => {
+                    self.pos += 1;
+                    elements.push(None);
+                }
+                Some(Token::Ellipsis) => {
+                    self.pos += 1;
+                    let rest = self.parse_pattern(is_binding)?;
+                    self.expect(Token::BracketClose)?;
+                    return Ok(Pattern::Array(elements, Some(Box::new(rest))));
+                }
+                _ => {
+                    elements.push(Some(self.parse_pattern_with_default(is_binding)?));
+
+                    if !self.eat(&Token::Comma) {
+                        self.expect(Token::BracketClose)?;
+                        return Ok(Pattern::Array(elements, None));
                     }
-                    _ => panic!("Expected opening brace"),
                 }
             }
-            _ => panic!("Expected opening parenthesis"),
-        };
+        }
+    }
+
+    /// Parses an object pattern after its opening brace.
+    fn parse_object_pattern(&mut self, is_binding: bool) -> ParseResult<Pattern> {
+        let mut properties = Vec::new();
 
-        match expr {
-            Expr::ControlFlow(condition, stmts, else_stmt) => {
-                Stmt::ControlFlow(condition, stmts, else_stmt)
+        while !self.eat(&Token::BraceClose) {
+            if self.eat(&Token::Ellipsis) {
+                // the rest object can't be destructured any further
+                if matches!(self.peek(), Some(Token::BracketOpen | Token::BraceOpen)) {
+                    return Err(self.unexpected());
+                }
+
+                let rest = self.parse_pattern(is_binding)?;
+                self.expect(Token::BraceClose)?;
+                return Ok(Pattern::Object(properties, Some(Box::new(rest))));
+            }
+
+            let shorthand = match self.peek() {
+                Some(Token::Identifier(name)) => Some(name.clone()),
+                _ => None,
+            };
+
+            let name = self.parse_property_name()?;
+
+            let pattern = match (self.eat(&Token::Colon), shorthand) {
+                (true, _) => self.parse_pattern_with_default(is_binding)?,
+                (false, Some(shorthand)) => self.parse_pattern_default(Pattern::Identifier(intern(&shorthand)))?,
+                (false, None) => return Err(self.unexpected()),
+            };
+
+            properties.push((name, pattern));
+
+            if !self.eat(&Token::Comma) {
+                self.expect(Token::BraceClose)?;
+                break;
             }
-            _ => panic!("Expected if"),
         }
+
+        Ok(Pattern::Object(properties, None))
     }
 
-    fn parse_loop(&mut self) -> Stmt {
-        let code_block = match self.next_token() {
-            Some(Token::BraceOpen) => self.parse_scope(),
-            _ => panic!("Expected loop body"),
+    fn parse_conditional(&mut self) -> ParseResult<Expr> {
+        let condition = self.parse_binary(0)?;
+
+        if !self.eat(&Token::QuestionMark) {
+            return Ok(condition);
+        }
+
+        let consequent = self.parse_assignment()?;
+        self.expect(Token::Colon)?;
+        let alternate = self.parse_assignment()?;
+
+        Ok(Expr::Conditional(Box::new(condition), Box::new(consequent), Box::new(alternate)))
+    }
+
+    /// Binding power of binary operators, higher binds tighter.
+    fn binary_precedence(token: &Token) -> Option<u8> {
+        let precedence = match token {
+            Token::NullishCoalescing => 1,
+            Token::LogicalOr => 2,
+            Token::LogicalAnd => 3,
+            Token::BitwiseOr => 4,
+            Token::BitwiseXor => 5,
+            Token::BitwiseAnd => 6,
+            Token::Equals | Token::NotEquals | Token::TypeCheckEquals | Token::TypeNotEquals => 7,
+            Token::LessThan
+            | Token::GreaterThan
+            | Token::LessThanEquals
+            | Token::GreaterThanEquals
+            | Token::Instanceof
+            | Token::In => 8,
+            Token::LeftShift | Token::RightShift | Token::UnsignedRightShift => 9,
+            Token::Addition | Token::Subtraction => 10,
+            Token::Multiplication | Token::Division | Token::Modulo => 11,
+            Token::Exponentiation => 12,
+            _ => return None,
         };
 
-        Stmt::Loop(Box::new(Stmt::CodeBlock(code_block)))
+        Some(precedence)
     }
 
-    fn parse_while(&mut self) -> Stmt {
-        let condition = match self.next_token() {
-            Some(Token::ParenOpen) => self.parse_expr(),
-            _ => panic!("Expected opening parenthesis"),
+    fn parse_binary(&mut self, min_precedence: u8) -> ParseResult<Expr> {
+        let mut left = self.parse_unary()?;
+        let mut links = 0;
+
+        while let Some(token) = self.peek().cloned() {
+            if token == Token::In && self.no_in {
+                break;
+            }
+
+            let precedence = match Self::binary_precedence(&token) {
+                Some(precedence) if precedence > min_precedence => precedence,
+                _ => break,
+            };
+
+            self.pos += 1;
+            links += 1;
+
+            // exponentiation is the only right associative binary operator
+            let right = self.nested_by(links, |parser| match token {
+                Token::Exponentiation => parser.parse_binary(precedence - 1),
+                _ => parser.parse_binary(precedence),
+            })?;
+
+            left = Self::make_binary(&token, left, right);
+        }
+
+        Ok(left)
+    }
+
+    fn make_binary(token: &Token, left: Expr, right: Expr) -> Expr {
+        let (left, right) = (Box::new(left), Box::new(right));
+
+        match token {
+            Token::NullishCoalescing => Expr::NullishCoalescing(left, right),
+            Token::LogicalOr => Expr::LogicalOr(left, right),
+            Token::LogicalAnd => Expr::LogicalAnd(left, right),
+            Token::BitwiseOr => Expr::BitwiseOr(left, right),
+            Token::BitwiseXor => Expr::BitwiseXor(left, right),
+            Token::BitwiseAnd => Expr::BitwiseAnd(left, right),
+            Token::Equals => Expr::Equals(left, right),
+            Token::NotEquals => Expr::NotEquals(left, right),
+            Token::TypeCheckEquals => Expr::TypeCheckEquals(left, right),
+            Token::TypeNotEquals => Expr::TypeNotEquals(left, right),
+            Token::LessThan => Expr::LessThan(left, right),
+            Token::GreaterThan => Expr::GreaterThan(left, right),
+            Token::LessThanEquals => Expr::LessThanEquals(left, right),
+            Token::GreaterThanEquals => Expr::GreaterThanEquals(left, right),
+            Token::Instanceof => Expr::InstanceOf(left, right),
+            Token::In => Expr::In(left, right),
+            Token::LeftShift => Expr::LeftShift(left, right),
+            Token::RightShift => Expr::RightShift(left, right),
+            Token::UnsignedRightShift => Expr::UnsignedRightShift(left, right),
+            Token::Addition => Expr::Addition(left, right),
+            Token::Subtraction => Expr::Subtraction(left, right),
+            Token::Multiplication => Expr::Multiplication(left, right),
+            Token::Division => Expr::Division(left, right),
+            Token::Modulo => Expr::Modulo(left, right),
+            Token::Exponentiation => Expr::Exponentiation(left, right),
+            _ => unreachable!("{:?} is not a binary operator", token),
+        }
+    }
+
+    /// Unary operators nest through here without going through `parse_assignment`, see `nested`.
+    fn parse_unary(&mut self) -> ParseResult<Expr> {
+        self.nested(Self::parse_unary_unguarded)
+    }
+
+    fn parse_unary_unguarded(&mut self) -> ParseResult<Expr> {
+        let constructor: fn(Box<Expr>) -> Expr = match self.peek() {
+            Some(Token::LogicalNot) => Expr::LogicalNot,
+            Some(Token::Subtraction) => Expr::Negation,
+            Some(Token::Addition) => Expr::UnaryPlus,
+            Some(Token::BitwiseNot) => Expr::BitwiseNot,
+            Some(Token::Typeof) => Expr::TypeOf,
+            Some(Token::Void) => Expr::Void,
+            Some(Token::Delete) => Expr::Delete,
+            Some(Token::Increment) => Expr::PreIncrement,
+            Some(Token::Decrement) => Expr::PreDecrement,
+            Some(Token::Await) => return self.parse_await(),
+            _ => return self.parse_postfix(),
         };
 
-        // skip closing parenthesis
+        let is_update = matches!(self.peek(), Some(Token::Increment | Token::Decrement));
         self.pos += 1;
 
-        let code_block = match self.next_token() {
-            Some(Token::BraceOpen) => self.parse_scope(),
-            _ => {
-                panic!("Expected while loop body")
-            },
+        let operand = self.parse_unary()?;
+
+        if is_update && !Self::is_assignment_target(&operand) {
+            return Err(JsError::syntax_error("Invalid left-hand side expression in prefix operation"));
+        }
+
+        Ok(constructor(Box::new(operand)))
+    }
+
+    fn parse_await(&mut self) -> ParseResult<Expr> {
+        if !self.in_async {
+            return Err(JsError::syntax_error("await is only valid in async functions and the top level bodies of modules"));
+        }
+
+        self.pos += 1;
+        self.uses_await = true;
+
+        Ok(Expr::Await(Box::new(self.parse_unary()?)))
+    }
+
+    fn parse_postfix(&mut self) -> ParseResult<Expr> {
+        let expr = self.parse_call_member()?;
+
+        // a line break before `++` or `--` makes them prefix operators of the next statement
+        if self.line_break_before() {
+            return Ok(expr);
+        }
+
+        let constructor: fn(Box<Expr>) -> Expr = match self.peek() {
+            Some(Token::Increment) => Expr::PostIncrement,
+            Some(Token::Decrement) => Expr::PostDecrement,
+            _ => return Ok(expr),
         };
 
-        Stmt::While(Box::new(condition), Box::new(Stmt::CodeBlock(code_block)))
+        if !Self::is_assignment_target(&expr) {
+            return Err(JsError::syntax_error("Invalid left-hand side expression in postfix operation"));
+        }
+
+        self.pos += 1;
+
+        Ok(constructor(Box::new(expr)))
     }
 
-    fn parse_expr(&mut self) -> Expr {
-        let mut expr = Vec::new();
+    fn parse_call_member(&mut self) -> ParseResult<Expr> {
+        let mut expr = match self.peek() {
+            Some(Token::New) => self.parse_new()?,
+            _ => self.parse_primary()?,
+        };
+
+        let mut links = 0;
 
         loop {
+            links += 1;
 
-            let token = self.next_token();
+            expr = match self.peek() {
+                Some(Token::ParenOpen) => {
+                    self.pos += 1;
+                    Expr::FunctionCall(Box::new(expr), self.nested_by(links, Self::parse_arguments)?)
+                }
+                Some(Token::Dot) | Some(Token::BracketOpen) => self.nested_by(links, |parser| parser.parse_member(expr))?,
+                _ => return Ok(expr),
+            };
+        }
+    }
 
-            if token == Some(Token::Semicolon) || token == Some(Token::ParenClose) {
-                break;
+    fn parse_member(&mut self, object: Expr) -> ParseResult<Expr> {
+        match self.next_token() {
+            Some(Token::Dot) => {
+                let name = match self.next_token().as_ref().and_then(Self::identifier_name) {
+                    Some(name) => name,
+                    None => {
+                        self.pos -= 1;
+                        return Err(self.unexpected());
+                    }
+                };
+
+                Ok(Expr::Member(Box::new(object), intern(&name)))
             }
+            Some(Token::BracketOpen) => {
+                let property = self.with_no_in(false, |parser| parser.parse_expr())?;
+                self.expect(Token::BracketClose)?;
 
-            let current_token_expr = match token {
-                Some(Token::Float(num)) => Expr::Float(num),
-                Some(Token::Identifier(name)) => {
-                    let token = self.next_token();
+                Ok(Expr::ComputedMember(Box::new(object), Box::new(property)))
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected())
+            }
+        }
+    }
 
-                    match token {
-                        Some(Token::ParenOpen) => self.parse_function_call(name),
-                        _ => {
-                            self.pos -= 1;
-                            Expr::Identifier(name)
-                        }
-                    }
-                }
-                Some(Token::StringLiteral(literal)) => Expr::StringLiteral(literal),
-                Some(Token::Boolean(bool)) => Expr::Boolean(bool),
-                Some(Token::Equals) => {
-                    let left = expr.pop().expect("Expected left side of equals");
-                    let right = self.parse_expr();
+    fn parse_new(&mut self) -> ParseResult<Expr> {
+        self.expect(Token::New)?;
 
-                    return Expr::Equals(Box::new(left), Box::new(right));
-                }
-                Some(Token::Assign) => {
-                    let left = expr.pop().expect("Expected left side of equals");
-                    let right = self.parse_expr();
-
-                    match left {
-                        Expr::Identifier(name) => {
-                            return Expr::Assignment(name, Box::new(right));
-                        }
-                        _ => panic!("Expected identifier on left side of assignment"),
-                    }
-                }
-                Some(Token::LogicalAnd) => {
-                    let left = expr.pop().expect("Expected left side of equals");
-                    let right = self.parse_expr();
+        let mut callee = match self.peek() {
+            Some(Token::New) => self.nested(Self::parse_new)?,
+            _ => self.parse_primary()?,
+        };
 
-                    return Expr::LogicalAnd(Box::new(left), Box::new(right));
-                }
-                Some(Token::LogicalOr) => {
-                    let left = expr.pop().expect("Expected left side of equals");
-                    let right = self.parse_expr();
+        let mut links = 0;
 
-                    return Expr::LogicalOr(Box::new(left), Box::new(right));
-                }
-                Some(Token::LogicalNot) => {
-                    let right = self.parse_expr();
-                    return Expr::LogicalNot(Box::new(right));
-                }
-                Some(Token::TypeCheckEquals) => {
-                    let left = expr.pop().expect("Expected left side of equals");
-                    let right = self.parse_expr();
+        while matches!(self.peek(), Some(Token::Dot) | Some(Token::BracketOpen)) {
+            links += 1;
+            callee = self.nested_by(links, |parser| parser.parse_member(callee))?;
+        }
 
-                    return Expr::TypeCheckEquals(Box::new(left), Box::new(right));
-                }
-                Some(Token::NotEquals) => {
-                    let left = expr.pop().expect("Expected left side of not equals");
-                    let right = self.parse_expr();
+        let args = match self.eat(&Token::ParenOpen) {
+            true => self.parse_arguments()?,
+            false => Vec::new(),
+        };
 
-                    return Expr::NotEquals(Box::new(left), Box::new(right));
-                }
-                Some(Token::TypeNotEquals) => {
-                    let left = expr.pop().expect("Expected left side of not equals");
-                    let right = self.parse_expr();
+        Ok(Expr::New(Box::new(callee), args))
+    }
 
-                    return Expr::TypeNotEquals(Box::new(left), Box::new(right));
-                }
-                Some(Token::Addition) => {
-                    let left = expr.pop().expect("Expected left side of addition");
-                    let right = self.parse_expr();
+    fn parse_primary(&mut self) -> ParseResult<Expr> {
+        let token = match self.next_token() {
+            Some(token) => token,
+            None => return Err(self.unexpected()),
+        };
 
-                    return Expr::Addition(Box::new(left), Box::new(right));
+        match token {
+            Token::Float(num) => Ok(Expr::Float(num)),
+            Token::StringLiteral(literal) => Ok(Expr::StringLiteral(literal)),
+            Token::RegExp(pattern, flags) => {
+                let flags = Flags::parse(&flags).ok_or_else(|| JsError::syntax_error("Invalid regular expression flags"))?;
+                let regex = Regex::new(pattern.as_str().into(), flags).map_err(JsError::syntax_error)?;
+                Ok(Expr::RegExp(Rc::new(regex)))
+            }
+            Token::Boolean(bool) => Ok(Expr::Boolean(bool)),
+            Token::Null => Ok(Expr::Null),
+            Token::This => Ok(Expr::This),
+            Token::Identifier(name) if name == "async" && self.is_async_function() => {
+                self.pos += 1;
+                Ok(Expr::Function(self.parse_function(false, true)?))
+            }
+            Token::Identifier(name) => Ok(self.identifier_reference(name)),
+            Token::Function => Ok(Expr::Function(self.parse_function(false, false)?)),
+            Token::ParenOpen => {
+                let expr = self.with_no_in(false, |parser| parser.parse_expr())?;
+                self.expect(Token::ParenClose)?;
+                Ok(expr)
+            }
+            Token::BracketOpen => self.with_no_in(false, |parser| parser.parse_array_literal()),
+            Token::BraceOpen => self.with_no_in(false, |parser| parser.parse_object_literal()),
+            Token::Division | Token::DivisionAssign if self.lexer.is_some() => {
+                self.pos -= 1;
+                self.rescan_regex()?;
+                self.parse_primary()
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected())
+            }
+        }
+    }
+
+    /// Parses an array literal after its opening bracket.
+    fn parse_array_literal(&mut self) -> ParseResult<Expr> {
+        let mut elements = Vec::new();
+
+        loop {
+            match self.peek() {
+                Some(Token::BracketClose) => {
+                    self.pos += 1;
+                    return Ok(Expr::Array(elements));
                 }
-                Some(Token::Increment) => {
-                    let left = expr.pop().expect("Expected left side of addition");
-                    let right = Expr::Float(1.0);
-
-                    match left {
-                        Expr::Identifier(name) => {
-                            let name_clone = name.clone();
-
-                            return Expr::Assignment(
-                                name,
-                                Box::new(Expr::Addition(
-                                    Box::new(Expr::Identifier(name_clone)),
-                                    Box::new(right),
-                                )),
-                            )
-                        }
-                        _ => panic!("Expected identifier on left side of increment"),
-                    }
+                Some(Token::Comma) => {
+                    self.pos += 1;
+                    elements.push(None);
                 }
-                Some(Token::Decrement) => {
-                    let left = expr.pop().expect("Expected left side of subtraction");
-                    let right = Expr::Float(1.0);
-
-                    match left {
-                        Expr::Identifier(name) => {
-                            let name_clone = name.clone();
-
-                            return Expr::Assignment(
-                                name,
-                                Box::new(Expr::Subtraction(
-                                    Box::new(Expr::Identifier(name_clone)),
-                                    Box::new(right),
-                                )),
-                            )
-                        }
-                        _ => panic!("Expected identifier on left side of decrement"),
+                _ => {
+                    elements.push(Some(self.parse_spread_or_assignment()?));
+
+                    if !self.eat(&Token::Comma) {
+                        self.expect(Token::BracketClose)?;
+                        return Ok(Expr::Array(elements));
                     }
                 }
-                Some(Token::Subtraction) => {
-                    let left = expr.pop().expect("Expected left side of subtraction");
-                    let right = self.parse_expr();
+            }
+        }
+    }
 
-                    return Expr::Subtraction(Box::new(left), Box::new(right));
-                }
-                Some(Token::Multiplication) => {
-                    let left = expr.pop().expect("Expected left side of multiplication");
-                    let right = self.parse_expr();
+    /// An argument or array element, which may spread an iterable.
+    fn parse_spread_or_assignment(&mut self) -> ParseResult<Expr> {
+        match self.eat(&Token::Ellipsis) {
+            true => Ok(Expr::Spread(Box::new(self.parse_assignment()?))),
+            false => self.parse_assignment(),
+        }
+    }
 
-                    return Expr::Multiplication(Box::new(left), Box::new(right));
-                }
-                Some(Token::Division) => {
-                    let left = expr.pop().expect("Expected left side of division");
-                    let right = self.parse_expr();
+    /// Parses an object literal after its opening brace.
+    fn parse_object_literal(&mut self) -> ParseResult<Expr> {
+        let mut members = Vec::new();
 
-                    return Expr::Division(Box::new(left), Box::new(right));
-                }
-                Some(Token::GreaterThan) => {
-                    let left = expr.pop().expect("Expected left side of greater than");
-                    let right = self.parse_expr();
+        while !self.eat(&Token::BraceClose) {
+            members.push(self.parse_object_member()?);
 
-                    return Expr::GreaterThan(Box::new(left), Box::new(right));
-                }
-                Some(Token::LessThan) => {
-                    let left = expr.pop().expect("Expected left side of less than");
-                    let right = self.parse_expr();
+            if !self.eat(&Token::Comma) {
+                self.expect(Token::BraceClose)?;
+                break;
+            }
+        }
 
-                    return Expr::LessThan(Box::new(left), Box::new(right));
-                }
-                Some(Token::GreaterThanEquals) => {
-                    let left = expr
-                        .pop()
-                        .expect("Expected left side of greater than equals");
-                    let right = self.parse_expr();
+        Ok(Expr::Object(members))
+    }
 
-                    return Expr::GreaterThanEquals(Box::new(left), Box::new(right));
-                }
-                Some(Token::LessThanEquals) => {
-                    let left = expr.pop().expect("Expected left side of less than equals");
-                    let right = self.parse_expr();
+    fn parse_object_member(&mut self) -> ParseResult<ObjectMember> {
+        if self.eat(&Token::Ellipsis) {
+            return Ok(ObjectMember::Spread(self.parse_assignment()?));
+        }
 
-                    return Expr::LessThanEquals(Box::new(left), Box::new(right));
-                }
-                _ => {
-                    panic!("Expected Float or identifier")
+        // `async`, `get` and `set` only modify a method when another property name follows them
+        let modifier = match self.peek() {
+            Some(Token::Identifier(word)) if matches!(word.as_str(), "async" | "get" | "set") => {
+                match self.tokens.get(self.pos + 1) {
+                    None | Some(Token::ParenOpen | Token::Colon | Token::Comma | Token::BraceClose) => None,
+                    _ => Some(word.clone()),
                 }
+            }
+            _ => None,
+        };
+
+        if modifier.is_some() {
+            self.pos += 1;
+        }
+
+        let is_async = modifier.as_deref() == Some("async");
+
+        if self.eat(&Token::Multiplication) {
+            let name = self.parse_property_name()?;
+            let method = self.parse_function_rest(Self::function_name(&name), true, is_async)?;
+            return Ok(ObjectMember::Property(name, Expr::Function(method)));
+        }
+
+        if let Some(modifier) = modifier {
+            let name = self.parse_property_name()?;
+            let function = self.parse_function_rest(Self::function_name(&name), false, is_async)?;
+
+            return match modifier.as_str() {
+                "get" => Ok(ObjectMember::Getter(name, function)),
+                "set" => Ok(ObjectMember::Setter(name, function)),
+                _ => Ok(ObjectMember::Property(name, Expr::Function(function))),
             };
+        }
+
+        let shorthand = match self.peek() {
+            Some(Token::Identifier(name)) => Some(name.clone()),
+            _ => None,
+        };
+
+        let name = self.parse_property_name()?;
+
+        match self.peek() {
+            Some(Token::Colon) => {
+                self.pos += 1;
+                Ok(ObjectMember::Property(name, self.parse_assignment()?))
+            }
+            Some(Token::ParenOpen) => {
+                let method = self.parse_function_rest(Self::function_name(&name), false, false)?;
+                Ok(ObjectMember::Property(name, Expr::Function(method)))
+            }
+            _ => match shorthand {
+                Some(shorthand) => Ok(ObjectMember::Property(name, self.identifier_reference(shorthand))),
+                None => Err(self.unexpected()),
+            },
+        }
+    }
+
+    fn parse_property_name(&mut self) -> ParseResult<PropertyName> {
+        let name = match self.next_token() {
+            Some(Token::StringLiteral(name)) => name.to_string(),
+            Some(Token::Float(number)) => number_to_string(number),
+            Some(Token::BracketOpen) => {
+                let key = self.parse_assignment()?;
+                self.expect(Token::BracketClose)?;
+                return Ok(PropertyName::Computed(Box::new(key)));
+            }
+            token => match token.as_ref().and_then(Self::identifier_name) {
+                Some(name) => name,
+                None => {
+                    self.pos -= 1;
+                    return Err(self.unexpected());
+                }
+            },
+        };
 
-            expr.push(current_token_expr)
+        Ok(PropertyName::Literal(intern(&name)))
+    }
+
+    /// Methods are named after their key, unless it is computed.
+    fn function_name(name: &PropertyName) -> Option<Rc<str>> {
+        match name {
+            PropertyName::Literal(name) => Some(name.clone()),
+            PropertyName::Computed(_) => None,
         }
+    }
+
+    /// An identifier used as an expression, noting references to `arguments` on the way.
+    fn identifier_reference(&mut self, name: String) -> Expr {
+        if name == "arguments" {
+            self.uses_arguments = true;
+        }
+
+        Expr::Identifier(intern(&name))
+    }
+
+    fn parse_identifier(&mut self) -> ParseResult<Rc<str>> {
+        match self.next_token() {
+            Some(Token::Identifier(name)) => Ok(intern(&name)),
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected())
+            }
+        }
+    }
 
-        // If we reached here, it means we have a single expression
-        // roll back the position for semicolon and closing parenthesis
-        self.pos -= 1;
+    /// Property names after a dot may be any identifier, including reserved words.
+    fn identifier_name(token: &Token) -> Option<String> {
+        let name = match token {
+            Token::Identifier(name) => return Some(name.clone()),
+            Token::Boolean(true) => "true",
+            Token::Boolean(false) => "false",
+            Token::Null => "null",
+            Token::If => "if",
+            Token::Else => "else",
+            Token::Let => "let",
+            Token::Const => "const",
+            Token::Var => "var",
+            Token::Function => "function",
+            Token::Return => "return",
+            Token::ForLoop => "for",
+            Token::Loop => "loop",
+            Token::Break => "break",
+            Token::Continue => "continue",
+            Token::While => "while",
+            Token::New => "new",
+            Token::This => "this",
+            Token::Typeof => "typeof",
+            Token::Instanceof => "instanceof",
+            Token::In => "in",
+            Token::Void => "void",
+            Token::Delete => "delete",
+            Token::Throw => "throw",
+            Token::Try => "try",
+            Token::Catch => "catch",
+            Token::Finally => "finally",
+            Token::Yield => "yield",
+            Token::Await => "await",
+            _ => return None,
+        };
+
+        Some(name.to_string())
+    }
+
+    /// Runs `f` with `in` treated as an operator or not, restoring the previous setting afterwards.
+    fn with_no_in<T>(&mut self, no_in: bool, f: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        let previous = std::mem::replace(&mut self.no_in, no_in);
+        let result = f(self);
+        self.no_in = previous;
+        result
+    }
+
+    /// Ends a statement. A missing semicolon is inserted before a line break, a closing brace or
+    /// the end of the input, anything else is a syntax error.
+    fn consume_semicolon(&mut self) -> ParseResult<()> {
+        if self.eat(&Token::Semicolon) || matches!(self.peek(), None | Some(Token::BraceClose)) || self.line_break_before() {
+            return Ok(());
+        }
+
+        Err(self.unexpected())
+    }
+
+    /// Whether a line terminator separates the next token from the previous one.
+    fn line_break_before(&self) -> bool {
+        self.line_breaks.get(self.pos).copied().unwrap_or(false)
+    }
+
+    fn expect(&mut self, expected: Token) -> ParseResult<()> {
+        match self.eat(&expected) {
+            true => Ok(()),
+            false => Err(self.unexpected()),
+        }
+    }
+
+    fn eat(&mut self, expected: &Token) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn unexpected(&self) -> JsError {
+        match self.peek() {
+            Some(token) => JsError::syntax_error(format!("Unexpected token {:?}", token)),
+            None => JsError::syntax_error("Unexpected end of input"),
+        }
+    }
+
+    /// Replaces the tokens from the current one, a `/` the lexer read as division after `)` or `}`,
+    /// with those it reads when a regular expression starts there.
+    fn rescan_regex(&mut self) -> ParseResult<()> {
+        let (Some(lexer), Some(&start)) = (self.lexer, self.starts.get(self.pos)) else {
+            return Err(self.unexpected());
+        };
+
+        let (tokens, rescanned) = lexer.rescan_regex(start)?;
+        let line_break = self.line_break_before();
+
+        self.tokens.to_mut().splice(self.pos.., tokens);
+        self.line_breaks.to_mut().splice(self.pos.., rescanned.line_breaks().iter().copied());
+        self.starts.to_mut().splice(self.pos.., rescanned.starts().iter().copied());
+        self.line_breaks.to_mut()[self.pos] = line_break;
+        Ok(())
+    }
 
-        return expr.pop().expect("Expected expression");
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
     }
 
     fn next_token(&mut self) -> Option<Token> {
@@ -0,0 +1,44 @@
+use crate::types::{Expr, Stmt};
+
+/// Marks the calls in tail position of the body of a strict function, so that they can run in
+/// place of the function instead of on top of it.
+pub fn mark(body: &mut [Stmt]) {
+    for stmt in body {
+        mark_stmt(stmt);
+    }
+}
+
+fn mark_stmt(stmt: &mut Stmt) {
+    match stmt {
+        Stmt::Return(Some(expr)) => mark_expr(expr),
+        Stmt::CodeBlock(stmts) => mark(stmts),
+        Stmt::ControlFlow(_, consequent, alternate) => {
+            mark_stmt(consequent);
+            mark_stmt(alternate);
+        },
+        Stmt::ForLoop(_, _, _, body) | Stmt::Loop(body) | Stmt::While(_, body) => mark_stmt(body),
+        // loops over iterators close them once the body returns, and `try` has to catch what the
+        // call throws or run `finally` after it, so neither can leave before the call is done
+        _ => (),
+    }
+}
+
+fn mark_expr(expr: &mut Expr) {
+    match expr {
+        Expr::FunctionCall(_, _) => {
+            let call = std::mem::replace(expr, Expr::Null);
+            *expr = Expr::TailCall(Box::new(call));
+        },
+        Expr::Conditional(_, consequent, alternate) => {
+            mark_expr(consequent);
+            mark_expr(alternate);
+        },
+        Expr::LogicalAnd(_, right) | Expr::LogicalOr(_, right) | Expr::NullishCoalescing(_, right) => mark_expr(right),
+        Expr::Sequence(exprs) => {
+            if let Some(last) = exprs.last_mut() {
+                mark_expr(last);
+            }
+        },
+        _ => (),
+    }
+}
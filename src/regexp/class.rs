@@ -0,0 +1,178 @@
+/// A set of characters, written in brackets such as `[a-z\d]` or as an escape such as `\w`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Class {
+    pub items: Vec<ClassItem>,
+    pub negated: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClassItem {
+    Range(u32, u32),
+    // a set such as `\d`, or everything outside it such as `\D`
+    Set(Set, bool),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Set {
+    Digit,
+    Word,
+    Space,
+    Property(Property),
+}
+
+/// The Unicode properties `\p{...}` can name. Letters are told apart with the alphabetic property
+/// and cases with the uppercase and lowercase properties, so a few symbols and marks that have
+/// those properties count as letters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Property {
+    Any,
+    Ascii,
+    AsciiHexDigit,
+    Alphabetic,
+    Letter,
+    UppercaseLetter,
+    LowercaseLetter,
+    Uppercase,
+    Lowercase,
+    Number,
+    WhiteSpace,
+}
+
+impl Property {
+    /// The property named `name`, which may be a general category given as `gc=L`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.strip_prefix("General_Category=").or_else(|| name.strip_prefix("gc=")).unwrap_or(name);
+
+        let property = match name {
+            "Any" => Property::Any,
+            "ASCII" => Property::Ascii,
+            "ASCII_Hex_Digit" | "AHex" => Property::AsciiHexDigit,
+            "Alphabetic" | "Alpha" => Property::Alphabetic,
+            "L" | "Letter" => Property::Letter,
+            "Lu" | "Uppercase_Letter" => Property::UppercaseLetter,
+            "Ll" | "Lowercase_Letter" => Property::LowercaseLetter,
+            "Uppercase" | "Upper" => Property::Uppercase,
+            "Lowercase" | "Lower" => Property::Lowercase,
+            "N" | "Number" => Property::Number,
+            "White_Space" | "space" => Property::WhiteSpace,
+            _ => return None,
+        };
+
+        Some(property)
+    }
+
+    fn contains(self, c: u32) -> bool {
+        let Some(c) = char::from_u32(c) else {
+            // lone surrogates have no properties besides being code points
+            return self == Property::Any;
+        };
+
+        match self {
+            Property::Any => true,
+            Property::Ascii => c.is_ascii(),
+            Property::AsciiHexDigit => c.is_ascii_hexdigit(),
+            Property::Alphabetic => c.is_alphabetic(),
+            // letter numbers such as roman numerals are alphabetic without being letters
+            Property::Letter => c.is_alphabetic() && !c.is_numeric(),
+            Property::UppercaseLetter => c.is_uppercase() && c.is_alphabetic(),
+            Property::LowercaseLetter => c.is_lowercase() && c.is_alphabetic(),
+            Property::Uppercase => c.is_uppercase(),
+            Property::Lowercase => c.is_lowercase(),
+            Property::Number => c.is_numeric(),
+            Property::WhiteSpace => c.is_whitespace(),
+        }
+    }
+}
+
+impl Set {
+    pub fn contains(self, c: u32) -> bool {
+        match self {
+            Set::Digit => (0x30..=0x39).contains(&c),
+            Set::Word => is_word_character(c),
+            Set::Space => is_space(c),
+            Set::Property(property) => property.contains(c),
+        }
+    }
+}
+
+impl Class {
+    pub fn new(items: Vec<ClassItem>, negated: bool) -> Self {
+        Class { items, negated }
+    }
+
+    /// Whether `c` is in the class, where with `ignore_case` it's enough for a character that
+    /// only differs from `c` in case to be in it.
+    pub fn matches(&self, c: u32, ignore_case: bool, unicode: bool) -> bool {
+        let found = match ignore_case {
+            true => {
+                let canonical = canonicalize(c, unicode);
+                case_variants(c).into_iter().flatten().any(|variant| canonicalize(variant, unicode) == canonical && self.contains(variant))
+            },
+            false => self.contains(c),
+        };
+
+        found != self.negated
+    }
+
+    fn contains(&self, c: u32) -> bool {
+        self.items.iter().any(|item| match item {
+            ClassItem::Range(start, end) => (*start..=*end).contains(&c),
+            ClassItem::Set(set, negated) => set.contains(c) != *negated,
+        })
+    }
+}
+
+pub fn is_word_character(c: u32) -> bool {
+    matches!(char::from_u32(c), Some('a'..='z' | 'A'..='Z' | '0'..='9' | '_'))
+}
+
+pub fn is_line_terminator(c: u32) -> bool {
+    matches!(c, 0x0A | 0x0D | 0x2028 | 0x2029)
+}
+
+/// White space and line terminators as `\s` matches them, which are those of the language itself.
+fn is_space(c: u32) -> bool {
+    matches!(c, 0x09..=0x0D | 0x20 | 0xA0 | 0x1680 | 0x2000..=0x200A | 0x2028 | 0x2029 | 0x202F | 0x205F | 0x3000 | 0xFEFF)
+}
+
+/// What case-insensitive matching compares characters by. Without the unicode flag that's their
+/// upper case, as long as it is a single code unit and doesn't turn a non-ASCII character into an
+/// ASCII one; with it, it's their simple case folding.
+pub fn canonicalize(c: u32, unicode: bool) -> u32 {
+    let Some(character) = char::from_u32(c) else {
+        return c;
+    };
+
+    if unicode {
+        // going through upper case folds characters such as the long s along with their letter
+        let upper = single(character.to_uppercase()).unwrap_or(character);
+        return single(upper.to_lowercase()).unwrap_or(upper) as u32;
+    }
+
+    match single(character.to_uppercase()) {
+        Some(upper) if (upper as u32) > 0xFFFF || (c >= 128 && (upper as u32) < 128) => c,
+        Some(upper) => upper as u32,
+        None => c,
+    }
+}
+
+/// `c` along with its upper case, its lower case and the lower case of its upper case, where those
+/// are single characters.
+fn case_variants(c: u32) -> [Option<u32>; 4] {
+    let Some(character) = char::from_u32(c) else {
+        return [Some(c), None, None, None];
+    };
+
+    let upper = single(character.to_uppercase());
+    let lower_of_upper = upper.and_then(|upper| single(upper.to_lowercase()));
+
+    [Some(character), upper, single(character.to_lowercase()), lower_of_upper].map(|variant| variant.map(|variant| variant as u32))
+}
+
+/// The only character of `chars`, if it has exactly one.
+fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
@@ -0,0 +1,199 @@
+use super::class::Class;
+use super::parser::Node;
+
+/// An instruction of the matcher. Those that read characters go backward inside lookbehinds.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Inst {
+    Char(u32, bool),
+    Any(bool),
+    Class(usize, bool),
+    BackReference(usize, bool),
+    LineStart,
+    LineEnd,
+    WordBoundary(bool),
+    // records the position in a capture slot, two of which make up each group
+    Save(usize),
+    // tries the first target, and the second one if matching fails from there
+    Split(usize, usize),
+    Jump(usize),
+    // starts a loop, counting its repetitions from zero
+    LoopInit(usize),
+    // decides whether to run the body of a loop again or leave it
+    LoopCheck(usize),
+    // starts a repetition of the body, clearing the captures inside it
+    LoopEnter(usize),
+    LoopEnd(usize),
+    // matches the instructions that follow up to a `Match` on their own, then continues at `end`
+    Look { negate: bool, end: usize },
+    Match,
+}
+
+/// A repetition compiled with a counter, for the ones that can't be a simple loop.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loop {
+    pub min: u32,
+    pub max: Option<u32>,
+    pub greedy: bool,
+    pub check: usize,
+    pub enter: usize,
+    pub exit: usize,
+    // the capture slots the body sets
+    pub slots: std::ops::Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Program {
+    pub insts: Vec<Inst>,
+    pub classes: Vec<Class>,
+    pub loops: Vec<Loop>,
+    pub slot_count: usize,
+}
+
+pub fn compile(node: &Node, group_count: usize) -> Program {
+    let mut compiler = Compiler { program: Program { insts: Vec::new(), classes: Vec::new(), loops: Vec::new(), slot_count: (group_count + 1) * 2 } };
+    compiler.compile(node, false);
+    compiler.emit(Inst::Match);
+    compiler.program
+}
+
+struct Compiler {
+    program: Program,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> usize {
+        self.program.insts.push(inst);
+        self.program.insts.len() - 1
+    }
+
+    fn next(&self) -> usize {
+        self.program.insts.len()
+    }
+
+    fn patch(&mut self, at: usize, inst: Inst) {
+        self.program.insts[at] = inst;
+    }
+
+    fn compile(&mut self, node: &Node, backward: bool) {
+        match node {
+            Node::Char(c) => {
+                self.emit(Inst::Char(*c, backward));
+            },
+            Node::Any => {
+                self.emit(Inst::Any(backward));
+            },
+            Node::Class(class) => {
+                self.program.classes.push(class.clone());
+                self.emit(Inst::Class(self.program.classes.len() - 1, backward));
+            },
+            Node::LineStart => {
+                self.emit(Inst::LineStart);
+            },
+            Node::LineEnd => {
+                self.emit(Inst::LineEnd);
+            },
+            Node::WordBoundary(negated) => {
+                self.emit(Inst::WordBoundary(*negated));
+            },
+            Node::BackReference(group) => {
+                self.emit(Inst::BackReference(*group, backward));
+            },
+            Node::Group(node, None) => self.compile(node, backward),
+            Node::Group(node, Some(index)) => {
+                // going backward, a group's end is reached before its start
+                let (first, second) = match backward {
+                    true => (index * 2 + 1, index * 2),
+                    false => (index * 2, index * 2 + 1),
+                };
+
+                self.emit(Inst::Save(first));
+                self.compile(node, backward);
+                self.emit(Inst::Save(second));
+            },
+            Node::Look { node, ahead, negate } => {
+                let look = self.emit(Inst::Match);
+                self.compile(node, !ahead);
+                self.emit(Inst::Match);
+                let end = self.next();
+                self.patch(look, Inst::Look { negate: *negate, end });
+            },
+            Node::Sequence(nodes) => match backward {
+                true => nodes.iter().rev().for_each(|node| self.compile(node, backward)),
+                false => nodes.iter().for_each(|node| self.compile(node, backward)),
+            },
+            Node::Alternation(alternatives) => {
+                let mut jumps = Vec::new();
+
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    if i + 1 == alternatives.len() {
+                        self.compile(alternative, backward);
+                        break;
+                    }
+
+                    let split = self.emit(Inst::Match);
+                    self.compile(alternative, backward);
+                    jumps.push(self.emit(Inst::Match));
+                    let next = self.next();
+                    self.patch(split, Inst::Split(split + 1, next));
+                }
+
+                let end = self.next();
+
+                for jump in jumps {
+                    self.patch(jump, Inst::Jump(end));
+                }
+            },
+            Node::Repeat { max: Some(0), .. } => (),
+            Node::Repeat { node, min, max, greedy, captures } => {
+                // a single character can't match the empty string, so it needs no counter to stop
+                // empty repetitions, and `*`, `+` and `?` need no counter at all
+                let single = matches!(**node, Node::Char(_) | Node::Any | Node::Class(_));
+
+                match (single, min, max) {
+                    (true, 0, None) => {
+                        let split = self.emit(Inst::Match);
+                        self.compile(node, backward);
+                        self.emit(Inst::Jump(split));
+                        let end = self.next();
+                        self.patch(split, self.split(*greedy, split + 1, end));
+                    },
+                    (true, 1, None) => {
+                        let start = self.next();
+                        self.compile(node, backward);
+                        let end = self.next() + 1;
+                        self.emit(self.split(*greedy, start, end));
+                    },
+                    (true, 0, Some(1)) => {
+                        let split = self.emit(Inst::Match);
+                        self.compile(node, backward);
+                        let end = self.next();
+                        self.patch(split, self.split(*greedy, split + 1, end));
+                    },
+                    _ => {
+                        let index = self.program.loops.len();
+                        let slots = captures.start * 2..captures.end * 2;
+                        self.program.loops.push(Loop { min: *min, max: *max, greedy: *greedy, check: 0, enter: 0, exit: 0, slots });
+
+                        self.emit(Inst::LoopInit(index));
+                        let check = self.emit(Inst::LoopCheck(index));
+                        let enter = self.emit(Inst::LoopEnter(index));
+                        self.compile(node, backward);
+                        self.emit(Inst::LoopEnd(index));
+                        let exit = self.next();
+
+                        let repeat = &mut self.program.loops[index];
+                        (repeat.check, repeat.enter, repeat.exit) = (check, enter, exit);
+                    },
+                }
+            },
+        }
+    }
+
+    /// A split that prefers going on to `more` when greedy, and to `done` otherwise.
+    fn split(&self, greedy: bool, more: usize, done: usize) -> Inst {
+        match greedy {
+            true => Inst::Split(more, done),
+            false => Inst::Split(done, more),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use super::class::{canonicalize, is_line_terminator, is_word_character};
+use super::compiler::{Inst, Program};
+use super::Flags;
+
+/// How many instructions run between calls to the check a match is given.
+const STEPS_PER_CHECK: u32 = 4096;
+
+/// What undoes a step when matching backtracks past it.
+enum Backtrack {
+    // a choice left to try, resuming at an instruction and position
+    Choice(usize, usize),
+    Slot(usize, Option<usize>),
+    Count(usize, u32),
+    Start(usize, usize),
+}
+
+pub struct Matcher<'a, E> {
+    program: &'a Program,
+    input: &'a [u16],
+    flags: Flags,
+    pub slots: Vec<Option<usize>>,
+    // the repetitions of each loop so far, and where the current one started
+    counts: Vec<u32>,
+    starts: Vec<usize>,
+    steps: u32,
+    check: &'a mut dyn FnMut(usize) -> Result<(), E>,
+}
+
+impl<'a, E> Matcher<'a, E> {
+    pub fn new(program: &'a Program, input: &'a [u16], flags: Flags, check: &'a mut dyn FnMut(usize) -> Result<(), E>) -> Self {
+        Matcher {
+            program,
+            input,
+            flags,
+            slots: vec![None; program.slot_count],
+            counts: vec![0; program.loops.len()],
+            starts: vec![0; program.loops.len()],
+            steps: 0,
+            check,
+        }
+    }
+
+    /// Runs the program from instruction `pc` at position `pos`, returning where the match ends.
+    /// The slots hold the captures of a match, and are left as they were otherwise.
+    pub fn run(&mut self, mut pc: usize, mut pos: usize) -> Result<Option<usize>, E> {
+        let mut stack = Vec::new();
+
+        loop {
+            self.step()?;
+
+            let matched = match self.program.insts[pc] {
+                Inst::Char(c, backward) => self.read(&mut pos, backward, |matcher, read| match matcher.flags.ignore_case {
+                    true => canonicalize(read, matcher.flags.unicode) == canonicalize(c, matcher.flags.unicode),
+                    false => read == c,
+                }),
+                Inst::Any(backward) => self.read(&mut pos, backward, |matcher, read| matcher.flags.dot_all || !is_line_terminator(read)),
+                Inst::Class(class, backward) => self.read(&mut pos, backward, |matcher, read| {
+                    matcher.program.classes[class].matches(read, matcher.flags.ignore_case, matcher.flags.unicode)
+                }),
+                Inst::BackReference(group, backward) => self.back_reference(&mut pos, group, backward),
+                Inst::LineStart => pos == 0 || (self.flags.multiline && is_line_terminator(self.input[pos - 1] as u32)),
+                Inst::LineEnd => pos == self.input.len() || (self.flags.multiline && is_line_terminator(self.input[pos] as u32)),
+                Inst::WordBoundary(negated) => {
+                    let before = pos > 0 && self.is_word_unit(pos - 1);
+                    let after = pos < self.input.len() && self.is_word_unit(pos);
+                    (before != after) != negated
+                },
+                Inst::Save(slot) => {
+                    stack.push(Backtrack::Slot(slot, self.slots[slot]));
+                    self.slots[slot] = Some(pos);
+                    true
+                },
+                Inst::Split(first, second) => {
+                    stack.push(Backtrack::Choice(second, pos));
+                    pc = first;
+                    continue;
+                },
+                Inst::Jump(target) => {
+                    pc = target;
+                    continue;
+                },
+                Inst::LoopInit(index) => {
+                    stack.push(Backtrack::Count(index, self.counts[index]));
+                    self.counts[index] = 0;
+                    true
+                },
+                Inst::LoopCheck(index) => {
+                    let repeat = &self.program.loops[index];
+                    let count = self.counts[index];
+
+                    pc = if repeat.max.is_some_and(|max| count >= max) {
+                        repeat.exit
+                    } else if count < repeat.min {
+                        repeat.enter
+                    } else if repeat.greedy {
+                        stack.push(Backtrack::Choice(repeat.exit, pos));
+                        repeat.enter
+                    } else {
+                        stack.push(Backtrack::Choice(repeat.enter, pos));
+                        repeat.exit
+                    };
+
+                    continue;
+                },
+                Inst::LoopEnter(index) => {
+                    stack.push(Backtrack::Start(index, self.starts[index]));
+                    self.starts[index] = pos;
+
+                    for slot in self.program.loops[index].slots.clone() {
+                        stack.push(Backtrack::Slot(slot, self.slots[slot]));
+                        self.slots[slot] = None;
+                    }
+
+                    true
+                },
+                Inst::LoopEnd(index) => {
+                    let repeat = &self.program.loops[index];
+
+                    // once the least number of repetitions is reached, an empty one ends the loop
+                    if self.counts[index] >= repeat.min && pos == self.starts[index] {
+                        false
+                    } else {
+                        stack.push(Backtrack::Count(index, self.counts[index]));
+                        self.counts[index] += 1;
+                        pc = repeat.check;
+                        continue;
+                    }
+                },
+                Inst::Look { negate, end } => {
+                    let saved = self.slots.clone();
+                    let found = self.run(pc + 1, pos)?.is_some();
+
+                    if found && !negate {
+                        // the captures made inside stay, until matching backtracks past them
+                        for (slot, value) in saved.into_iter().enumerate() {
+                            if self.slots[slot] != value {
+                                stack.push(Backtrack::Slot(slot, value));
+                            }
+                        }
+                    } else {
+                        self.slots = saved;
+                    }
+
+                    if found != negate {
+                        pc = end;
+                        continue;
+                    }
+
+                    false
+                },
+                Inst::Match => return Ok(Some(pos)),
+            };
+
+            if matched {
+                pc += 1;
+                continue;
+            }
+
+            loop {
+                match stack.pop() {
+                    None => return Ok(None),
+                    Some(Backtrack::Choice(target, position)) => {
+                        pc = target;
+                        pos = position;
+                        break;
+                    },
+                    Some(Backtrack::Slot(slot, value)) => self.slots[slot] = value,
+                    Some(Backtrack::Count(index, count)) => self.counts[index] = count,
+                    Some(Backtrack::Start(index, start)) => self.starts[index] = start,
+                }
+            }
+        }
+    }
+
+    /// Counts a step, which includes trying another position in the input, and calls the check
+    /// every so often.
+    pub fn step(&mut self) -> Result<(), E> {
+        self.steps = self.steps.wrapping_add(1);
+
+        match self.steps.is_multiple_of(STEPS_PER_CHECK) {
+            true => (self.check)(STEPS_PER_CHECK as usize),
+            false => Ok(()),
+        }
+    }
+
+    /// Reads the character after `pos`, or before it going backward, moving past it if `test`
+    /// accepts it.
+    fn read(&self, pos: &mut usize, backward: bool, test: impl Fn(&Self, u32) -> bool) -> bool {
+        let read = match backward {
+            true => self.char_before(*pos),
+            false => self.char_after(*pos),
+        };
+
+        match read {
+            Some((c, next)) if test(self, c) => {
+                *pos = next;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn char_after(&self, pos: usize) -> Option<(u32, usize)> {
+        let unit = *self.input.get(pos)? as u32;
+
+        if self.flags.unicode && (0xD800..0xDC00).contains(&unit) {
+            if let Some(low @ 0xDC00..=0xDFFF) = self.input.get(pos + 1).map(|low| *low as u32) {
+                return Some((0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00), pos + 2));
+            }
+        }
+
+        Some((unit, pos + 1))
+    }
+
+    fn char_before(&self, pos: usize) -> Option<(u32, usize)> {
+        let unit = *self.input.get(pos.checked_sub(1)?)? as u32;
+
+        if self.flags.unicode && (0xDC00..0xE000).contains(&unit) && pos >= 2 {
+            if let high @ 0xD800..=0xDBFF = self.input[pos - 2] as u32 {
+                return Some((0x10000 + ((high - 0xD800) << 10) + (unit - 0xDC00), pos - 2));
+            }
+        }
+
+        Some((unit, pos - 1))
+    }
+
+    fn is_word_unit(&self, pos: usize) -> bool {
+        let unit = self.input[pos] as u32;
+
+        // with both flags, the long s and the kelvin sign fold into word characters
+        is_word_character(unit) || (self.flags.unicode && self.flags.ignore_case && matches!(unit, 0x017F | 0x212A))
+    }
+
+    /// Matches what `group` captured again, which a group that captured nothing always does.
+    fn back_reference(&self, pos: &mut usize, group: usize, backward: bool) -> bool {
+        let (Some(start), Some(end)) = (self.slots[group * 2], self.slots[group * 2 + 1]) else {
+            return true;
+        };
+
+        let captured = &self.input[start..end];
+
+        let range = match backward {
+            true => pos.checked_sub(captured.len()).map(|start| start..*pos),
+            false => Some(*pos..*pos + captured.len()).filter(|range| range.end <= self.input.len()),
+        };
+
+        let Some(range) = range else {
+            return false;
+        };
+
+        let matches = self.input[range.clone()].iter().zip(captured).all(|(left, right)| match self.flags.ignore_case {
+            true => canonicalize(*left as u32, self.flags.unicode) == canonicalize(*right as u32, self.flags.unicode),
+            false => left == right,
+        });
+
+        if matches {
+            *pos = if backward { range.start } else { range.end };
+        }
+
+        matches
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+use std::ops::Range;
+use crate::types::JsString;
+
+mod class;
+mod compiler;
+mod matcher;
+mod parser;
+
+use compiler::{Inst, Program};
+use matcher::Matcher;
+use parser::Parser;
+
+/// The flags of a regular expression, such as the `g` and `i` of `/a/gi`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Flags {
+    pub has_indices: bool,
+    pub global: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+    pub dot_all: bool,
+    pub unicode: bool,
+    pub sticky: bool,
+}
+
+impl Flags {
+    /// Reads flags such as `"gi"`, which can't have unknown or repeated letters.
+    pub fn parse(flags: &str) -> Option<Self> {
+        let mut parsed = Flags::default();
+
+        for c in flags.chars() {
+            let flag = match c {
+                'd' => &mut parsed.has_indices,
+                'g' => &mut parsed.global,
+                'i' => &mut parsed.ignore_case,
+                'm' => &mut parsed.multiline,
+                's' => &mut parsed.dot_all,
+                'u' => &mut parsed.unicode,
+                'y' => &mut parsed.sticky,
+                _ => return None,
+            };
+
+            if *flag {
+                return None;
+            }
+
+            *flag = true;
+        }
+
+        Some(parsed)
+    }
+}
+
+/// The flags in the order `RegExp.prototype.flags` lists them.
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = [
+            (self.has_indices, 'd'),
+            (self.global, 'g'),
+            (self.ignore_case, 'i'),
+            (self.multiline, 'm'),
+            (self.dot_all, 's'),
+            (self.unicode, 'u'),
+            (self.sticky, 'y'),
+        ];
+
+        flags.into_iter().filter(|(set, _)| *set).try_for_each(|(_, flag)| write!(f, "{}", flag))
+    }
+}
+
+/// Where each group matched in the input, in code units, starting with the whole match. Groups
+/// that didn't take part in the match are `None`.
+pub type Captures = Vec<Option<Range<usize>>>;
+
+/// A compiled regular expression with the syntax and semantics of ECMAScript, which is matched by
+/// backtracking over the UTF-16 code units of the input.
+pub struct Regex {
+    source: JsString,
+    flags: Flags,
+    program: Program,
+    group_count: usize,
+    group_names: Vec<(String, usize)>,
+}
+
+impl Regex {
+    pub fn new(source: JsString, flags: Flags) -> Result<Self, String> {
+        let units = source.to_utf16();
+
+        // with the unicode flag a pattern is made of code points, and of code units otherwise
+        let pattern = match flags.unicode {
+            true => char::decode_utf16(units.iter().copied()).map(|c| c.map_or_else(|error| error.unpaired_surrogate() as u32, |c| c as u32)).collect(),
+            false => units.iter().map(|unit| *unit as u32).collect(),
+        };
+
+        match Parser::new(pattern, flags.unicode).parse() {
+            Ok(pattern) => Ok(Regex {
+                program: compiler::compile(&pattern.node, pattern.group_count),
+                source,
+                flags,
+                group_count: pattern.group_count,
+                group_names: pattern.group_names,
+            }),
+            Err(message) => Err(format!("Invalid regular expression: /{}/{}: {}", source, flags, message)),
+        }
+    }
+
+    pub fn source(&self) -> &JsString {
+        &self.source
+    }
+
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// The number of capturing groups, not counting the whole match.
+    pub fn group_count(&self) -> usize {
+        self.group_count
+    }
+
+    /// The named groups in the order they appear, along with their numbers.
+    pub fn group_names(&self) -> &[(String, usize)] {
+        &self.group_names
+    }
+
+    /// The source as `RegExp.prototype.source` shows it, which could be written back between
+    /// slashes: slashes and line terminators are escaped, and an empty pattern is `(?:)`.
+    pub fn escaped_source(&self) -> String {
+        if self.source.is_empty() {
+            return "(?:)".to_string();
+        }
+
+        let mut escaped = String::with_capacity(self.source.len());
+        let (mut in_class, mut after_backslash) = (false, false);
+
+        for c in self.source.chars() {
+            match c {
+                '\n' => escaped.push_str(if after_backslash { "n" } else { "\\n" }),
+                '\r' => escaped.push_str(if after_backslash { "r" } else { "\\r" }),
+                '\u{2028}' => escaped.push_str(if after_backslash { "u2028" } else { "\\u2028" }),
+                '\u{2029}' => escaped.push_str(if after_backslash { "u2029" } else { "\\u2029" }),
+                '/' if !after_backslash && !in_class => escaped.push_str("\\/"),
+                c => {
+                    if !after_backslash {
+                        in_class = match c {
+                            '[' => true,
+                            ']' => false,
+                            _ => in_class,
+                        };
+                    }
+
+                    escaped.push(c);
+                },
+            }
+
+            after_backslash = !after_backslash && c == '\\';
+        }
+
+        escaped
+    }
+
+    /// Looks for a match starting at `start` or, unless `sticky`, anywhere after it. `check` is
+    /// called every so often while matching with the number of steps taken since, and ends the
+    /// match when it fails.
+    pub fn find_from<E>(&self, input: &[u16], start: usize, sticky: bool, check: &mut dyn FnMut(usize) -> Result<(), E>) -> Result<Option<Captures>, E> {
+        // a pattern starting with a character can skip the places where that character isn't
+        let first = match self.program.insts.first() {
+            Some(Inst::Char(c, false)) if !self.flags.ignore_case && *c <= 0xFFFF => Some(*c as u16),
+            _ => None,
+        };
+
+        let mut matcher = Matcher::new(&self.program, input, self.flags, check);
+        let mut position = start;
+
+        while position <= input.len() {
+            matcher.step()?;
+
+            if first.is_none_or(|first| input.get(position) == Some(&first)) {
+                if let Some(end) = matcher.run(0, position)? {
+                    let mut captures: Captures = vec![Some(position..end)];
+
+                    for group in 1..=self.group_count {
+                        captures.push(match (matcher.slots[group * 2], matcher.slots[group * 2 + 1]) {
+                            (Some(start), Some(end)) => Some(start..end),
+                            _ => None,
+                        });
+                    }
+
+                    return Ok(Some(captures));
+                }
+            }
+
+            if sticky {
+                break;
+            }
+
+            position = advance(input, position, self.flags.unicode);
+        }
+
+        Ok(None)
+    }
+}
+
+/// The position after the one at `index`, which with `unicode` skips over both halves of a pair.
+pub fn advance(input: &[u16], index: usize, unicode: bool) -> usize {
+    let pair = unicode
+        && input.get(index).is_some_and(|high| (0xD800..0xDC00).contains(high))
+        && input.get(index + 1).is_some_and(|low| (0xDC00..0xE000).contains(low));
+
+    index + if pair { 2 } else { 1 }
+}
+
+/// Two regular expressions are the same if they were written the same way.
+impl PartialEq for Regex {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.flags == other.flags
+    }
+}
+
+impl fmt::Debug for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "/{}/{}", self.escaped_source(), self.flags)
+    }
+}
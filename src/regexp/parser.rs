@@ -0,0 +1,537 @@
+use std::ops::Range;
+use super::class::{Class, ClassItem, Property, Set};
+
+/// A parsed pattern.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    Char(u32),
+    // `.`, which leaves out line terminators unless the dotAll flag is set
+    Any,
+    Class(Class),
+    LineStart,
+    LineEnd,
+    // `\b`, or `\B` when negated
+    WordBoundary(bool),
+    // a group, along with the number of the capture it makes if it's a capturing one
+    Group(Box<Node>, Option<usize>),
+    BackReference(usize),
+    Look { node: Box<Node>, ahead: bool, negate: bool },
+    // `captures` are the groups inside, which are cleared before every repetition
+    Repeat { node: Box<Node>, min: u32, max: Option<u32>, greedy: bool, captures: Range<usize> },
+    Sequence(Vec<Node>),
+    Alternation(Vec<Node>),
+}
+
+/// What `Parser::parse` finds in a pattern.
+pub struct Pattern {
+    pub node: Node,
+    pub group_count: usize,
+    // the names of the named groups, along with their numbers
+    pub group_names: Vec<(String, usize)>,
+}
+
+/// Parses the code points of a pattern, or its code units without the unicode flag.
+pub struct Parser {
+    pattern: Vec<u32>,
+    pos: usize,
+    unicode: bool,
+    // the number of capturing groups opened so far
+    groups: usize,
+    group_count: usize,
+    group_names: Vec<(String, usize)>,
+}
+
+type ParseResult<T> = Result<T, String>;
+
+impl Parser {
+    pub fn new(pattern: Vec<u32>, unicode: bool) -> Self {
+        Parser { pattern, pos: 0, unicode, groups: 0, group_count: 0, group_names: Vec::new() }
+    }
+
+    pub fn parse(mut self) -> ParseResult<Pattern> {
+        // back references may come before the group they refer to, so the groups are counted first
+        self.scan_groups()?;
+
+        let node = self.parse_disjunction()?;
+
+        if self.pos < self.pattern.len() {
+            return Err("Unmatched ')'".to_string());
+        }
+
+        Ok(Pattern { node, group_count: self.group_count, group_names: self.group_names })
+    }
+
+    fn scan_groups(&mut self) -> ParseResult<()> {
+        let mut in_class = false;
+        let mut pos = 0;
+
+        while pos < self.pattern.len() {
+            match self.char_at(pos) {
+                Some('\\') => pos += 1,
+                Some('[') => in_class = true,
+                Some(']') => in_class = false,
+                Some('(') if !in_class && self.char_at(pos + 1) != Some('?') => self.group_count += 1,
+                Some('(') if !in_class && self.char_at(pos + 2) == Some('<') && !matches!(self.char_at(pos + 3), Some('=' | '!')) => {
+                    self.group_count += 1;
+                    self.pos = pos + 3;
+                    let name = self.parse_group_name()?;
+
+                    if self.group_names.iter().any(|(existing, _)| *existing == name) {
+                        return Err("Duplicate capture group name".to_string());
+                    }
+
+                    self.group_names.push((name, self.group_count));
+                },
+                _ => (),
+            }
+
+            pos += 1;
+        }
+
+        self.pos = 0;
+        Ok(())
+    }
+
+    fn char_at(&self, pos: usize) -> Option<char> {
+        self.pattern.get(pos).map(|c| char::from_u32(*c).unwrap_or('\u{fffd}'))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.char_at(self.pos)
+    }
+
+    fn next(&mut self) -> Option<u32> {
+        let c = self.pattern.get(self.pos).copied();
+        self.pos += usize::from(c.is_some());
+        c
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        self.pos += usize::from(found);
+        found
+    }
+
+    fn eat_str(&mut self, expected: &str) -> bool {
+        let found = expected.chars().enumerate().all(|(i, c)| self.char_at(self.pos + i) == Some(c));
+
+        if found {
+            self.pos += expected.chars().count();
+        }
+
+        found
+    }
+
+    fn parse_disjunction(&mut self) -> ParseResult<Node> {
+        let mut alternatives = vec![self.parse_alternative()?];
+
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+
+        match alternatives.len() {
+            1 => Ok(alternatives.remove(0)),
+            _ => Ok(Node::Alternation(alternatives)),
+        }
+    }
+
+    fn parse_alternative(&mut self) -> ParseResult<Node> {
+        let mut terms = Vec::new();
+
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+
+        match terms.len() {
+            1 => Ok(terms.remove(0)),
+            _ => Ok(Node::Sequence(terms)),
+        }
+    }
+
+    fn parse_term(&mut self) -> ParseResult<Node> {
+        let groups_before = self.groups;
+        let start = self.pos;
+        let c = self.next().unwrap_or_default();
+
+        let (node, quantifiable) = match char::from_u32(c).unwrap_or('\u{fffd}') {
+            '^' => (Node::LineStart, false),
+            '$' => (Node::LineEnd, false),
+            '\\' if self.eat('b') => (Node::WordBoundary(false), false),
+            '\\' if self.eat('B') => (Node::WordBoundary(true), false),
+            '\\' => (self.parse_atom_escape()?, true),
+            '(' => self.parse_group()?,
+            '.' => (Node::Any, true),
+            '[' => (Node::Class(self.parse_class()?), true),
+            '*' | '+' | '?' => return Err("Nothing to repeat".to_string()),
+            '{' if self.unicode => return Err("Nothing to repeat".to_string()),
+            '{' => {
+                // a brace that doesn't start a quantifier is an ordinary character
+                self.pos = start;
+
+                if self.parse_quantifier()?.is_some() {
+                    return Err("Nothing to repeat".to_string());
+                }
+
+                self.pos = start + 1;
+                (Node::Char(c), true)
+            },
+            '}' | ']' if self.unicode => return Err("Lone quantifier brackets".to_string()),
+            _ => (Node::Char(c), true),
+        };
+
+        let Some((min, max)) = self.parse_quantifier()? else {
+            return Ok(node);
+        };
+
+        if !quantifiable {
+            return Err("Nothing to repeat".to_string());
+        }
+
+        let greedy = !self.eat('?');
+        let captures = groups_before + 1..self.groups + 1;
+
+        Ok(Node::Repeat { node: Box::new(node), min, max, greedy, captures })
+    }
+
+    /// Reads a quantifier such as `*` or `{2,3}` as the least and most times it repeats.
+    fn parse_quantifier(&mut self) -> ParseResult<Option<(u32, Option<u32>)>> {
+        let quantifier = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let start = self.pos;
+                self.pos += 1;
+
+                let braces = self.parse_number().and_then(|min| match self.eat(',') {
+                    true => match self.peek() {
+                        Some('}') => Some((min, None)),
+                        _ => self.parse_number().map(|max| (min, Some(max))),
+                    },
+                    false => Some((min, Some(min))),
+                });
+
+                match braces {
+                    Some((min, max)) if self.peek() == Some('}') => {
+                        if max.is_some_and(|max| max < min) {
+                            return Err("numbers out of order in {} quantifier".to_string());
+                        }
+
+                        (min, max)
+                    },
+                    _ if self.unicode => return Err("Incomplete quantifier".to_string()),
+                    _ => {
+                        self.pos = start;
+                        return Ok(None);
+                    },
+                }
+            },
+            _ => return Ok(None),
+        };
+
+        self.pos += 1;
+        Ok(Some(quantifier))
+    }
+
+    /// Reads decimal digits, saturating at the largest count a quantifier can have.
+    fn parse_number(&mut self) -> Option<u32> {
+        let mut number: Option<u32> = None;
+
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            number = Some(number.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+            self.pos += 1;
+        }
+
+        number
+    }
+
+    fn parse_group(&mut self) -> ParseResult<(Node, bool)> {
+        let (node, quantifiable) = if self.eat_str("?:") {
+            (Node::Group(Box::new(self.parse_disjunction()?), None), true)
+        } else if self.eat_str("?=") || self.eat_str("?!") {
+            let negate = self.pattern[self.pos - 1] == '!' as u32;
+            // lookaheads can be repeated in old code, which hardly ever makes sense
+            (Node::Look { node: Box::new(self.parse_disjunction()?), ahead: true, negate }, !self.unicode)
+        } else if self.eat_str("?<=") || self.eat_str("?<!") {
+            let negate = self.pattern[self.pos - 1] == '!' as u32;
+            (Node::Look { node: Box::new(self.parse_disjunction()?), ahead: false, negate }, false)
+        } else if self.eat_str("?<") {
+            self.parse_group_name()?;
+            self.groups += 1;
+            let index = self.groups;
+            (Node::Group(Box::new(self.parse_disjunction()?), Some(index)), true)
+        } else if self.eat('?') {
+            return Err("Invalid group".to_string());
+        } else {
+            self.groups += 1;
+            let index = self.groups;
+            (Node::Group(Box::new(self.parse_disjunction()?), Some(index)), true)
+        };
+
+        match self.eat(')') {
+            true => Ok((node, quantifiable)),
+            false => Err("Unterminated group".to_string()),
+        }
+    }
+
+    /// Reads the name of a group after its `<`, along with the closing `>`.
+    fn parse_group_name(&mut self) -> ParseResult<String> {
+        let mut name = String::new();
+
+        loop {
+            let c = match self.next().and_then(char::from_u32) {
+                Some('>') if !name.is_empty() => return Ok(name),
+                Some('\\') if self.eat('u') => self.parse_unicode_escape(true).and_then(char::from_u32),
+                c => c,
+            };
+
+            match c {
+                Some(c) if c.is_alphabetic() || c == '_' || c == '$' || (!name.is_empty() && c.is_alphanumeric()) => name.push(c),
+                _ => return Err("Invalid capture group name".to_string()),
+            }
+        }
+    }
+
+    fn parse_atom_escape(&mut self) -> ParseResult<Node> {
+        let c = self.next().ok_or_else(|| "\\ at end of pattern".to_string())?;
+
+        match char::from_u32(c).unwrap_or('\u{fffd}') {
+            '1'..='9' => {
+                let start = self.pos - 1;
+                self.pos = start;
+                let number = self.parse_number().unwrap_or_default() as usize;
+
+                if number <= self.group_count {
+                    return Ok(Node::BackReference(number));
+                }
+
+                if self.unicode {
+                    return Err("Invalid escape".to_string());
+                }
+
+                // in old code a number with no group to refer to is an octal escape
+                self.pos = start + 1;
+                match c {
+                    0x38 | 0x39 => Ok(Node::Char(c)),
+                    _ => Ok(Node::Char(self.parse_octal(c))),
+                }
+            },
+            'k' if self.unicode || !self.group_names.is_empty() => {
+                if !self.eat('<') {
+                    return Err("Invalid named reference".to_string());
+                }
+
+                let name = self.parse_group_name()?;
+
+                match self.group_names.iter().find(|(existing, _)| *existing == name) {
+                    Some((_, index)) => Ok(Node::BackReference(*index)),
+                    None => Err("Invalid named capture referenced".to_string()),
+                }
+            },
+            _ => match self.parse_class_escape(c)? {
+                Some(item) => Ok(Node::Class(Class::new(vec![item], false))),
+                None => Ok(Node::Char(self.parse_character_escape(c, false)?)),
+            },
+        }
+    }
+
+    /// The set an escape such as `\d` or `\p{L}` stands for, if `c` starts one.
+    fn parse_class_escape(&mut self, c: u32) -> ParseResult<Option<ClassItem>> {
+        let item = match char::from_u32(c).unwrap_or('\u{fffd}') {
+            'd' => ClassItem::Set(Set::Digit, false),
+            'D' => ClassItem::Set(Set::Digit, true),
+            'w' => ClassItem::Set(Set::Word, false),
+            'W' => ClassItem::Set(Set::Word, true),
+            's' => ClassItem::Set(Set::Space, false),
+            'S' => ClassItem::Set(Set::Space, true),
+            escape @ ('p' | 'P') if self.unicode => {
+                if !self.eat('{') {
+                    return Err("Invalid property name".to_string());
+                }
+
+                let mut name = String::new();
+
+                loop {
+                    match self.next().and_then(char::from_u32) {
+                        Some('}') => break,
+                        Some(c) if c.is_ascii_alphanumeric() || c == '_' || c == '=' => name.push(c),
+                        _ => return Err("Invalid property name".to_string()),
+                    }
+                }
+
+                let property = Property::from_name(&name).ok_or_else(|| "Invalid property name".to_string())?;
+                ClassItem::Set(Set::Property(property), escape == 'P')
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(item))
+    }
+
+    /// The character an escape other than a class escape or back reference stands for.
+    fn parse_character_escape(&mut self, c: u32, in_class: bool) -> ParseResult<u32> {
+        let escaped = match char::from_u32(c).unwrap_or('\u{fffd}') {
+            't' => 0x09,
+            'n' => 0x0A,
+            'v' => 0x0B,
+            'f' => 0x0C,
+            'r' => 0x0D,
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.pos += 1;
+                    letter as u32 % 32
+                },
+                Some(letter) if in_class && !self.unicode && (letter.is_ascii_digit() || letter == '_') => {
+                    self.pos += 1;
+                    letter as u32 % 32
+                },
+                _ if self.unicode => return Err("Invalid unicode escape".to_string()),
+                // a `\c` that isn't a control escape is a backslash followed by a c
+                _ => {
+                    self.pos -= 1;
+                    '\\' as u32
+                },
+            },
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            '0'..='9' if self.unicode => return Err(match in_class {
+                true => "Invalid class escape",
+                false => "Invalid decimal escape",
+            }.to_string()),
+            '0'..='7' => self.parse_octal(c),
+            'x' => match self.parse_hex(2) {
+                Some(code) => code,
+                None if self.unicode => return Err("Invalid escape".to_string()),
+                None => c,
+            },
+            'u' => match self.parse_unicode_escape(self.unicode) {
+                Some(code) => code,
+                None if self.unicode => return Err("Invalid Unicode escape".to_string()),
+                None => c,
+            },
+            '-' if in_class => c,
+            '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '/' => c,
+            _ if self.unicode => return Err("Invalid escape".to_string()),
+            _ => c,
+        };
+
+        Ok(escaped)
+    }
+
+    /// Reads a legacy octal escape whose first digit, `first`, was already read.
+    fn parse_octal(&mut self, first: u32) -> u32 {
+        let mut value = first - '0' as u32;
+
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) {
+            if value * 8 + digit > 0o377 {
+                break;
+            }
+
+            value = value * 8 + digit;
+            self.pos += 1;
+        }
+
+        value
+    }
+
+    fn parse_hex(&mut self, count: usize) -> Option<u32> {
+        let digits: Option<Vec<u32>> = (0..count).map(|i| self.char_at(self.pos + i).and_then(|c| c.to_digit(16))).collect();
+        let value = digits?.into_iter().fold(0, |value, digit| value * 16 + digit);
+        self.pos += count;
+        Some(value)
+    }
+
+    /// Reads what follows `\u`: four hex digits, which with `unicode` may be a surrogate pair
+    /// written as two escapes or a code point in braces.
+    fn parse_unicode_escape(&mut self, unicode: bool) -> Option<u32> {
+        if unicode && self.peek() == Some('{') {
+            let start = self.pos;
+            self.pos += 1;
+            let mut value: u32 = 0;
+            let mut digits = 0;
+
+            while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                value = value.saturating_mul(16).saturating_add(digit);
+                digits += 1;
+                self.pos += 1;
+            }
+
+            if digits > 0 && value <= 0x10FFFF && self.eat('}') {
+                return Some(value);
+            }
+
+            self.pos = start;
+            return None;
+        }
+
+        let code = self.parse_hex(4)?;
+
+        if unicode && (0xD800..0xDC00).contains(&code) && self.char_at(self.pos) == Some('\\') && self.char_at(self.pos + 1) == Some('u') {
+            let start = self.pos;
+            self.pos += 2;
+
+            match self.parse_hex(4) {
+                Some(low @ 0xDC00..=0xDFFF) => return Some(0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)),
+                _ => self.pos = start,
+            }
+        }
+
+        Some(code)
+    }
+
+    fn parse_class(&mut self) -> ParseResult<Class> {
+        let negated = self.eat('^');
+        let mut items = Vec::new();
+
+        loop {
+            let c = self.next().ok_or_else(|| "Unterminated character class".to_string())?;
+
+            if c == ']' as u32 {
+                return Ok(Class::new(items, negated));
+            }
+
+            let first = self.parse_class_atom(c)?;
+
+            let is_range = self.peek() == Some('-') && !matches!(self.char_at(self.pos + 1), None | Some(']'));
+
+            if !is_range {
+                items.push(first);
+                continue;
+            }
+
+            self.pos += 1;
+            let c = self.next().unwrap_or_default();
+            let second = self.parse_class_atom(c)?;
+
+            match (first, second) {
+                (ClassItem::Range(start, _), ClassItem::Range(end, _)) if start > end => {
+                    return Err("Range out of order in character class".to_string());
+                },
+                (ClassItem::Range(start, _), ClassItem::Range(end, _)) => items.push(ClassItem::Range(start, end)),
+                _ if self.unicode => return Err("Invalid character class".to_string()),
+                // in old code a range with a set at either end is just the set, a dash and the other end
+                (first, second) => items.extend([first, ClassItem::Range('-' as u32, '-' as u32), second]),
+            }
+        }
+    }
+
+    /// Reads one character of a class, or a set such as `\d`, as a range or a set item.
+    fn parse_class_atom(&mut self, c: u32) -> ParseResult<ClassItem> {
+        if c != '\\' as u32 {
+            return Ok(ClassItem::Range(c, c));
+        }
+
+        let c = self.next().ok_or_else(|| "\\ at end of pattern".to_string())?;
+
+        if let Some(item) = self.parse_class_escape(c)? {
+            return Ok(item);
+        }
+
+        let c = match char::from_u32(c) {
+            Some('b') => 0x08,
+            Some('B' | 'k') if self.unicode => return Err("Invalid class escape".to_string()),
+            _ => self.parse_character_escape(c, true)?,
+        };
+
+        Ok(ClassItem::Range(c, c))
+    }
+}
@@ -1,47 +1,97 @@
 use std::{collections::HashMap, rc::Rc};
 use std::cell::RefCell;
+use crate::interpreter::{tear_down, Teardown};
 use crate::types::Value;
 
-#[derive(Clone, Debug, PartialEq)]
+/// Scopes with more bindings than this find them by name through a hash map instead of a search.
+const INDEXED_SCOPE_SIZE: usize = 16;
+
+#[derive(Debug)]
+pub struct Binding {
+    name: Rc<str>,
+    value: Value,
+    mutable: bool,
+}
+
+impl Binding {
+    fn is(&self, name: &str) -> bool {
+        // names from the same script are interned, so they are usually the same string
+        std::ptr::eq(&*self.name, name) || &*self.name == name
+    }
+}
+
+/// Outcome of assigning to a binding.
+#[derive(Debug, PartialEq)]
+pub enum Assignment {
+    Assigned,
+    Constant,
+    Undeclared,
+}
+
+/// Where the compiler expects a binding: how many scopes up from the current one, and its position
+/// among the bindings of that scope in the order they are defined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Slot {
+    pub depth: u16,
+    pub index: u16,
+}
+
+#[derive(Debug, Default)]
 pub struct Scope {
     parent: Option<Rc<RefCell<Scope>>>,
-    variables: RefCell<HashMap<String, Value>>,
+    // in the order they were defined, which makes a binding's position its slot
+    bindings: Vec<Binding>,
+    // positions by name, once the scope has grown too big to search
+    index: Option<HashMap<Rc<str>, usize>>,
+    // only function scopes bind `this`, lookups walk up to the nearest one
+    this: Option<Value>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        if self.parent.is_some() || !self.bindings.is_empty() || self.this.is_some() {
+            tear_down(Teardown::Scope(self.parent.take(), std::mem::take(&mut self.bindings), self.this.take()));
+        }
+    }
 }
 
 impl Scope {
-    pub fn new(parent: Option<Scope>) -> Self {
-        match parent {
-            Some(parent) => Scope {
-                parent: Some(Rc::new(RefCell::new(parent))),
-                variables: RefCell::new(HashMap::new()),
-            },
-            None => Scope {
-                parent: None,
-                variables: RefCell::new(HashMap::new()),
-            }
+    pub fn new(parent: Option<Rc<RefCell<Scope>>>) -> Self {
+        Scope {
+            parent,
+            bindings: Vec::new(),
+            index: None,
+            this: None,
         }
     }
 
-    pub fn with_rc(parent: Option<Rc<RefCell<Scope>>>) -> Self {
-        match parent {
-            Some(parent) => Scope {
-                parent: Some(parent),
-                variables: RefCell::new(HashMap::new()),
-            },
-            None => Scope {
-                parent: None,
-                variables: RefCell::new(HashMap::new()),
-            }
+    pub fn new_function(parent: Option<Rc<RefCell<Scope>>>, this: Value) -> Self {
+        Scope {
+            parent,
+            bindings: Vec::new(),
+            index: None,
+            this: Some(this),
+        }
+    }
+
+    pub fn into_rc(self) -> Rc<RefCell<Scope>> {
+        Rc::new(RefCell::new(self))
+    }
+
+    pub fn parent(&self) -> Option<Rc<RefCell<Scope>>> {
+        self.parent.clone()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        match &self.index {
+            Some(index) => index.get(name).copied(),
+            None => self.bindings.iter().position(|binding| binding.is(name)),
         }
     }
 
     pub fn get(&self, name: &str) -> Option<Value> {
-        let variables = self.variables.borrow();
-        if variables.contains_key(name) {
-            match variables.get(name) {
-                Some(value) => return Some(value.clone()),
-                None => (),
-            }
+        if let Some(position) = self.position(name) {
+            return Some(self.bindings[position].value.clone());
         }
 
         match &self.parent {
@@ -50,23 +100,107 @@ impl Scope {
         }
     }
 
+    /// Reads the binding in `slot` if it is the one called `name`. `None` means the binding has to
+    /// be looked up by name instead, such as when it isn't defined yet.
+    pub fn get_slot(&self, slot: Slot, name: &str) -> Option<Value> {
+        match slot.depth {
+            0 => self.bindings.get(slot.index as usize).filter(|binding| binding.is(name)).map(|binding| binding.value.clone()),
+            depth => self.parent.as_ref()?.borrow().get_slot(Slot { depth: depth - 1, ..slot }, name),
+        }
+    }
+
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.position(name).is_some()
+            || self.parent.as_ref().is_some_and(|parent| parent.borrow().contains_key(name))
+    }
+
     pub fn contains_key_local(&self, name: &str) -> bool {
-        self.variables.borrow().contains_key(name)
+        self.position(name).is_some()
     }
 
-    pub fn assign (&mut self, name: String, value: Value) {
-        if self.variables.borrow().contains_key(&name) {
-            self.variables.borrow_mut().insert(name, value);
-            return
+    /// Updates the nearest binding called `name`, unless it is a constant.
+    pub fn assign (&mut self, name: &str, value: Value) -> Assignment {
+        if let Some(position) = self.position(name) {
+            return Self::assign_binding(&mut self.bindings[position], value);
         }
 
-        match &mut self.parent {
+        match &self.parent {
             Some(parent) => parent.borrow_mut().assign(name, value),
-            None => panic!("Variable not found: {}", name)
+            None => Assignment::Undeclared,
         }
     }
 
-    pub fn define(&mut self, name: String, value: Value) {
-        self.variables.borrow_mut().insert(name, value);
+    /// Assigns to the binding in `slot` if it is the one called `name`, see `get_slot`.
+    pub fn assign_slot(&mut self, slot: Slot, name: &str, value: Value) -> Option<Assignment> {
+        match slot.depth {
+            0 => match self.bindings.get_mut(slot.index as usize) {
+                Some(binding) if binding.is(name) => Some(Self::assign_binding(binding, value)),
+                _ => None,
+            },
+            depth => self.parent.as_ref()?.borrow_mut().assign_slot(Slot { depth: depth - 1, ..slot }, name, value),
+        }
+    }
+
+    fn assign_binding(binding: &mut Binding, value: Value) -> Assignment {
+        if !binding.mutable {
+            return Assignment::Constant;
+        }
+
+        binding.value = value;
+        Assignment::Assigned
+    }
+
+    pub fn define(&mut self, name: impl Into<Rc<str>>, value: Value) {
+        self.insert(name.into(), value, true);
+    }
+
+    pub fn define_constant(&mut self, name: impl Into<Rc<str>>, value: Value) {
+        self.insert(name.into(), value, false);
+    }
+
+    /// Replaces the binding called `name`, keeping its slot, or adds it after the others.
+    fn insert(&mut self, name: Rc<str>, value: Value, mutable: bool) {
+        if let Some(position) = self.position(&name) {
+            self.bindings[position].value = value;
+            self.bindings[position].mutable = mutable;
+            return;
+        }
+
+        match &mut self.index {
+            Some(index) => {
+                index.insert(name.clone(), self.bindings.len());
+            },
+            None if self.bindings.len() >= INDEXED_SCOPE_SIZE => {
+                let mut index: HashMap<Rc<str>, usize> = self.bindings.iter().enumerate().map(|(i, binding)| (binding.name.clone(), i)).collect();
+                index.insert(name.clone(), self.bindings.len());
+                self.index = Some(index);
+            },
+            None => (),
+        }
+
+        self.bindings.push(Binding { name, value, mutable });
+    }
+
+    /// Whether the binding called `name` in this scope is a constant.
+    pub fn is_constant_local(&self, name: &str) -> bool {
+        self.position(name).is_some_and(|position| !self.bindings[position].mutable)
+    }
+
+    /// The names and values of the bindings in this scope, without its parents, in their slots.
+    pub fn bindings(&self) -> impl Iterator<Item = (&Rc<str>, &Value)> {
+        self.bindings.iter().map(|binding| (&binding.name, &binding.value))
+    }
+
+    /// The `this` bound by this scope itself, if it is a function scope.
+    pub fn own_this(&self) -> Option<&Value> {
+        self.this.as_ref()
     }
-}
\ No newline at end of file
+
+    pub fn this(&self) -> Value {
+        match (&self.this, &self.parent) {
+            (Some(this), _) => this.clone(),
+            (None, Some(parent)) => parent.borrow().this(),
+            (None, None) => Value::None,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+thread_local! {
+    static NAMES: RefCell<HashSet<Rc<str>>> = RefCell::new(HashSet::new());
+}
+
+/// The shared copy of a name, such as an identifier or a property key written in a script, so
+/// that every use of it points to the same string. Only names that appear in source code or the
+/// built-ins are interned, strings scripts compute at runtime aren't, or they would never be freed.
+pub fn intern(name: &str) -> Rc<str> {
+    NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+
+        match names.get(name) {
+            Some(interned) => interned.clone(),
+            None => {
+                let interned: Rc<str> = Rc::from(name);
+                names.insert(interned.clone());
+                interned
+            },
+        }
+    })
+}
@@ -1,19 +1,50 @@
+use std::rc::Rc;
+use crate::compiler::Bytecode;
+use crate::error::Termination;
+use crate::object::{ObjectRef, Symbol};
+use crate::regexp::Regex;
+
+mod interner;
+mod string;
+
+pub use interner::intern;
+pub use string::JsString;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Token {
 
     // literals
     Identifier(String),
     Float(f64),
-    StringLiteral(String),
+    StringLiteral(JsString),
+    // the pattern and flags of a regular expression
+    RegExp(String, String),
     Boolean(bool),
+    Null,
 
     // operators
     Assign,
+    AdditionAssign,
+    SubtractionAssign,
+    MultiplicationAssign,
+    DivisionAssign,
+    ModuloAssign,
+    ExponentiationAssign,
+    LeftShiftAssign,
+    RightShiftAssign,
+    UnsignedRightShiftAssign,
+    BitwiseAndAssign,
+    BitwiseOrAssign,
+    BitwiseXorAssign,
+    LogicalAndAssign,
+    LogicalOrAssign,
+    NullishCoalescingAssign,
 
     // logical operators
     LogicalAnd,
     LogicalOr,
     LogicalNot,
+    NullishCoalescing,
 
     // comparison operators
     Equals,
@@ -30,43 +61,247 @@ pub enum Token {
     Subtraction,
     Multiplication,
     Division,
+    Modulo,
+    Exponentiation,
     Increment,
     Decrement,
 
+    // bitwise operators
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseNot,
+    LeftShift,
+    RightShift,
+    UnsignedRightShift,
+
     // punctuation
     Semicolon,
     BraceOpen,
     BraceClose,
     ParenOpen,
     ParenClose,
+    BracketOpen,
+    BracketClose,
     Comma,
-    Comment(String),
+    Dot,
+    Ellipsis,
+    Colon,
+    QuestionMark,
+    Arrow,
 
     // keywords
     If,
-    ElseIf,
     Else,
-    Log,
     Let,
+    Const,
+    Var,
     Function,
     Return,
     ForLoop,
-    FunctionCall(String),
     Loop,
     Break,
-    While
+    Continue,
+    While,
+    New,
+    This,
+    Typeof,
+    Instanceof,
+    In,
+    Void,
+    Delete,
+    Throw,
+    Try,
+    Catch,
+    Finally,
+    Yield,
+    Await
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionDef {
+    pub name: Option<Rc<str>>,
+    pub params: Vec<Pattern>,
+    pub rest: Option<Pattern>,
+    pub body: Vec<Stmt>,
+    // whether the body refers to `arguments`, which is only created for functions that use it
+    pub uses_arguments: bool,
+    // the lowered body of a `function*` or an async function, which runs instead of `body`
+    pub generator: Option<Rc<GeneratorCode>>,
+    pub is_generator: bool,
+    pub is_async: bool,
+    // arrow functions take `this` and `arguments` from the enclosing function
+    pub is_arrow: bool,
+    // a named function expression, which sees its own name as a constant in a scope of its own
+    pub binds_name: bool,
+    // the body compiled for the virtual machine once the function is first called there
+    pub bytecode: Bytecode,
+}
+
+/// The body of a generator or async function lowered into a flat list of operations, so that it
+/// can be suspended at a `yield` or `await` and resumed later. Statements without either are kept
+/// as they are.
+///
+/// Values that must survive a `yield`, such as the operands evaluated before it, are kept in
+/// temporaries: bindings named `%0`, `%1`... in the function scope, which scripts can't refer to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratorCode {
+    pub ops: Vec<GeneratorOp>,
+    pub temporaries: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeneratorOp {
+    // runs a statement that contains no `yield`
+    Exec(Stmt),
+    // suspends with the value of the expression, the value resumed with goes to the temporary
+    Yield(Option<Expr>, Option<String>),
+    // suspends until the value of the expression settles, what it fulfills with goes to the temporary
+    Await(Expr, Option<String>),
+    // `yield*`, suspending for each value of the iterable
+    YieldDelegate(Expr, Option<String>),
+    Jump(usize),
+    JumpIfFalse(Expr, usize),
+    // enters a block scope, hoisting the function declarations of the block
+    PushScope(Vec<Stmt>),
+    PopScope,
+    // gives the next iteration of a for loop its own copy of these bindings
+    CopyIterationScope(Vec<Rc<str>>),
+    EnterLoop { break_target: usize, continue_target: usize },
+    ExitLoop,
+    // the catch clause with its binding and the finally block of a `try`
+    EnterTry { catch: Option<(usize, Option<Rc<str>>)>, finally: Option<usize> },
+    // leaves a try block or catch clause normally, running the finally block first
+    LeaveTry(usize),
+    // ends a finally block, resuming whatever caused it to run
+    EndFinally,
+    // stores the keys a for...in loop visits as an array in the temporary
+    ForInKeys(Expr, String),
+    // stores the iterator of the value and its `next` method in the temporaries
+    GetIterator(Expr, String, String),
+    // the iterator is closed if the loop over it is left early
+    EnterIterator(String, String),
+    // steps the iterator, storing the value in the last temporary or jumping once it's done
+    IteratorStep(String, String, String, usize),
+    // leaves the loop over an exhausted iterator
+    ExitIterator,
+    // leaves the loop over an iterator early, closing it
+    CloseIterator,
+    // stores the async iterator of the value, or a sync one wrapped as such, and its `next`
+    GetAsyncIterator(Expr, String, String),
+    // calls `next` on the iterator, storing what it returns in the last temporary
+    IteratorNext(String, String, String),
+    // unpacks an awaited iterator result, jumping if it's done or storing its value otherwise
+    IteratorComplete(String, String, usize),
+}
+
+impl FunctionDef {
+    /// The number of parameters before the first one with a default, used as `length`.
+    pub fn length(&self) -> usize {
+        self.params.iter().take_while(|param| !matches!(param, Pattern::Default(_, _))).count()
+    }
+}
+
+/// The target of a binding or assignment, possibly destructuring the value assigned to it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    Identifier(Rc<str>),
+    // a member expression such as `obj.x`, only allowed in assignments
+    Expression(Box<Expr>),
+    // elements, where `None` is a hole, and the rest element
+    Array(Vec<Option<Pattern>>, Option<Box<Pattern>>),
+    // properties and the rest element
+    Object(Vec<(PropertyName, Pattern)>, Option<Box<Pattern>>),
+    // a pattern with the default used when the value is undefined
+    Default(Box<Pattern>, Box<Expr>),
+}
+
+impl Pattern {
+    /// The names of the variables a binding pattern declares, in source order.
+    pub fn bound_names(&self) -> Vec<Rc<str>> {
+        let mut names = Vec::new();
+        self.collect_bound_names(&mut names);
+        names
+    }
+
+    fn collect_bound_names(&self, names: &mut Vec<Rc<str>>) {
+        match self {
+            Pattern::Identifier(name) => names.push(name.clone()),
+            Pattern::Expression(_) => (),
+            Pattern::Array(elements, rest) => {
+                for element in elements.iter().flatten() {
+                    element.collect_bound_names(names);
+                }
+
+                if let Some(rest) = rest {
+                    rest.collect_bound_names(names);
+                }
+            }
+            Pattern::Object(properties, rest) => {
+                for (_, pattern) in properties {
+                    pattern.collect_bound_names(names);
+                }
+
+                if let Some(rest) = rest {
+                    rest.collect_bound_names(names);
+                }
+            }
+            Pattern::Default(pattern, _) => pattern.collect_bound_names(names),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeclarationKind {
+    Let,
+    Const,
+    // function scoped and hoisted, see `Interpreter::hoist_var_declarations`
+    Var,
+}
+
+/// The left-hand side of a `for...in` or `for...of` loop.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForBinding {
+    // a fresh binding per iteration, as in `for (const x of xs)`
+    Declaration(DeclarationKind, Pattern),
+    // existing variables or properties, as in `for (x of xs)`
+    Target(Pattern),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyName {
+    Literal(Rc<str>),
+    Computed(Box<Expr>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectMember {
+    Property(PropertyName, Expr),
+    Getter(PropertyName, Rc<FunctionDef>),
+    Setter(PropertyName, Rc<FunctionDef>),
+    // `...source`, copying the own enumerable properties of source
+    Spread(Expr),
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expr {
-    Identifier(String),
+    Identifier(Rc<str>),
     Float(f64),
-    StringLiteral(String),
+    StringLiteral(JsString),
+    RegExp(Rc<Regex>),
     Boolean(bool),
+    Null,
+    This,
+    Function(Rc<FunctionDef>),
+    // `None` marks an elision such as the hole in `[1, , 3]`
+    Array(Vec<Option<Expr>>),
+    Object(Vec<ObjectMember>),
     Addition(Box<Expr>, Box<Expr>),
     Subtraction(Box<Expr>, Box<Expr>),
     Multiplication(Box<Expr>, Box<Expr>),
     Division(Box<Expr>, Box<Expr>),
+    Modulo(Box<Expr>, Box<Expr>),
+    Exponentiation(Box<Expr>, Box<Expr>),
     Equals(Box<Expr>, Box<Expr>),
     TypeCheckEquals(Box<Expr>, Box<Expr>),
     NotEquals(Box<Expr>, Box<Expr>),
@@ -75,46 +310,234 @@ pub enum Expr {
     GreaterThanEquals(Box<Expr>, Box<Expr>),
     LessThan(Box<Expr>, Box<Expr>),
     LessThanEquals(Box<Expr>, Box<Expr>),
-    ControlFlow(Box<Expr>, Box<Stmt>, Box<Stmt>),
-    FunctionCall(String, Vec<Expr>),
+    BitwiseAnd(Box<Expr>, Box<Expr>),
+    BitwiseOr(Box<Expr>, Box<Expr>),
+    BitwiseXor(Box<Expr>, Box<Expr>),
+    LeftShift(Box<Expr>, Box<Expr>),
+    RightShift(Box<Expr>, Box<Expr>),
+    UnsignedRightShift(Box<Expr>, Box<Expr>),
+    InstanceOf(Box<Expr>, Box<Expr>),
+    In(Box<Expr>, Box<Expr>),
     LogicalAnd(Box<Expr>, Box<Expr>),
     LogicalOr(Box<Expr>, Box<Expr>),
+    NullishCoalescing(Box<Expr>, Box<Expr>),
     LogicalNot(Box<Expr>),
-    Assignment(String, Box<Expr>),
-    Loop(Box<Stmt>),
+    Negation(Box<Expr>),
+    UnaryPlus(Box<Expr>),
+    BitwiseNot(Box<Expr>),
+    TypeOf(Box<Expr>),
+    Void(Box<Expr>),
+    Delete(Box<Expr>),
+    PreIncrement(Box<Expr>),
+    PreDecrement(Box<Expr>),
+    PostIncrement(Box<Expr>),
+    PostDecrement(Box<Expr>),
+    Assignment(Box<Expr>, Box<Expr>),
+    DestructuringAssignment(Box<Pattern>, Box<Expr>),
+    // the token is the binary operator applied before assigning, e.g. `Token::Addition` for `+=`
+    CompoundAssignment(Token, Box<Expr>, Box<Expr>),
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+    Sequence(Vec<Expr>),
+    FunctionCall(Box<Expr>, Vec<Expr>),
+    // a call in tail position of a strict function, which runs in place of the function
+    TailCall(Box<Expr>),
+    New(Box<Expr>, Vec<Expr>),
+    // `...iterable`, only valid as an argument or an array literal element
+    Spread(Box<Expr>),
+    Yield(Option<Box<Expr>>),
+    YieldDelegate(Box<Expr>),
+    Await(Box<Expr>),
+    Member(Box<Expr>, Rc<str>),
+    ComputedMember(Box<Expr>, Box<Expr>),
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Stmt {
-    Let(String, Expr),
-    Assignment(String, Expr),
+    Declaration(DeclarationKind, Vec<(Pattern, Option<Expr>)>),
     ControlFlow(Box<Expr>, Box<Stmt>, Box<Stmt>),
     CodeBlock(Vec<Stmt>),
-    Log(Expr),
-    Function(String, Vec<String>, Box<Stmt>),
-    Return(Box<Expr>),
-    FunctionCall(String, Vec<Expr>),
-    ForLoop(Box<Stmt>, Box<Stmt>, Box<Stmt>, Box<Stmt>),
+    Function(Rc<FunctionDef>),
+    Return(Option<Expr>),
+    ForLoop(Box<Stmt>, Option<Expr>, Option<Expr>, Box<Stmt>),
+    ForOf(ForBinding, Box<Expr>, Box<Stmt>),
+    ForIn(ForBinding, Box<Expr>, Box<Stmt>),
+    ForAwaitOf(ForBinding, Box<Expr>, Box<Stmt>),
     Expression(Box<Expr>),
     Loop(Box<Stmt>),
     While(Box<Expr>, Box<Stmt>),
+    Throw(Expr),
+    // try block, optional catch clause with its optional binding, optional finally block
+    Try(Box<Stmt>, Option<(Option<Rc<str>>, Box<Stmt>)>, Option<Box<Stmt>>),
     None,
-    Break
+    Break,
+    Continue
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     Float(f64),
-    StringLiteral(String),
+    StringLiteral(JsString),
     Boolean(bool),
-    FunctionDef(Vec<String>, Box<Stmt>),
-    Return(Box<Value>),
-    Break,
+    Object(ObjectRef),
+    Symbol(Symbol),
+    Null,
     None
 }
 
+impl Value {
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Value::Float(_) => "number",
+            Value::StringLiteral(_) => "string",
+            Value::Boolean(_) => "boolean",
+            Value::Object(object) if object.is_callable() => "function",
+            Value::Object(_) | Value::Null => "object",
+            Value::Symbol(_) => "symbol",
+            Value::None => "undefined",
+        }
+    }
+
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Value::Float(value) => *value != 0.0 && !value.is_nan(),
+            Value::StringLiteral(value) => !value.is_empty(),
+            Value::Boolean(value) => *value,
+            Value::Object(_) | Value::Symbol(_) => true,
+            Value::Null | Value::None => false,
+        }
+    }
+
+    pub fn is_nullish(&self) -> bool {
+        matches!(self, Value::Null | Value::None)
+    }
+
+    pub fn as_object(&self) -> Option<&ObjectRef> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Value::Object(object) if object.is_callable())
+    }
+
+    /// `ToString` for primitives. Objects render generically since running their `toString`
+    /// needs an interpreter, see `Interpreter::to_js_string`.
+    pub fn to_display_string(&self) -> String {
+        match self {
+            Value::Float(value) => number_to_string(*value),
+            Value::StringLiteral(value) => value.to_string(),
+            Value::Boolean(value) => value.to_string(),
+            Value::Object(object) if object.is_callable() => "function () { [native code] }".to_string(),
+            Value::Object(_) => "[object Object]".to_string(),
+            Value::Symbol(symbol) => symbol.to_string(),
+            Value::Null => "null".to_string(),
+            Value::None => "undefined".to_string(),
+        }
+    }
+}
+
+/// Abrupt completions that unwind the interpreter until something handles them.
+#[derive(Clone, Debug, PartialEq)]
 pub enum Signal {
-    Return,
+    Return(Value),
     Break,
-    None
-}
\ No newline at end of file
+    Continue,
+    Throw(Value),
+    // stops the script outright, nothing in it can catch this
+    Terminate(Termination),
+}
+
+/// Formats a number the way `Number.prototype.toString()` does.
+pub fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    if value.is_infinite() {
+        return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+
+    if value < 0.0 {
+        return format!("-{}", number_to_string(-value));
+    }
+
+    // Rust prints the shortest digits that round-trip, which is what the spec asks for
+    let scientific = format!("{:e}", value);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap() + 1;
+
+    if k <= n && n <= 21 {
+        return format!("{}{}", digits, "0".repeat((n - k) as usize));
+    }
+
+    if 0 < n && n <= 21 {
+        return format!("{}.{}", &digits[..n as usize], &digits[n as usize..]);
+    }
+
+    if -6 < n && n <= 0 {
+        return format!("0.{}{}", "0".repeat(-n as usize), digits);
+    }
+
+    let sign = if n - 1 < 0 { "-" } else { "+" };
+
+    match k {
+        1 => format!("{}e{}{}", digits, sign, (n - 1).abs()),
+        _ => format!("{}.{}e{}{}", &digits[..1], &digits[1..], sign, (n - 1).abs()),
+    }
+}
+
+/// `base ** exponent`, which unlike `powf` has no result when the exponent is NaN or when an
+/// infinite exponent is applied to 1 or -1.
+pub fn exponentiate(base: f64, exponent: f64) -> f64 {
+    match exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+        true => f64::NAN,
+        false => base.powf(exponent),
+    }
+}
+
+/// `ToNumber` applied to a string.
+pub fn string_to_number(value: &str) -> f64 {
+    let trimmed = value.trim_matches(|c: char| c.is_whitespace() || c == '\u{feff}');
+
+    if trimmed.is_empty() {
+        return 0.0;
+    }
+
+    let radix = match trimmed.get(..2) {
+        Some("0x") | Some("0X") => Some(16),
+        Some("0o") | Some("0O") => Some(8),
+        Some("0b") | Some("0B") => Some(2),
+        _ => None,
+    };
+
+    if let Some(radix) = radix {
+        let digits = &trimmed[2..];
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return f64::NAN;
+        }
+
+        return digits.chars().fold(0.0, |acc, c| acc * radix as f64 + c.to_digit(radix).unwrap() as f64);
+    }
+
+    match trimmed {
+        "Infinity" | "+Infinity" => return f64::INFINITY,
+        "-Infinity" => return f64::NEG_INFINITY,
+        _ => (),
+    }
+
+    // Rust also accepts spellings like "inf" and "nan" that JavaScript does not
+    if !trimmed.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')) {
+        return f64::NAN;
+    }
+
+    trimmed.parse().unwrap_or(f64::NAN)
+}
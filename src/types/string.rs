@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// A string as scripts see it, a sequence of UTF-16 code units.
+///
+/// ASCII strings, which are most of them, are kept as UTF-8 and shared like any `Rc<str>`, with
+/// each byte one code unit. Others keep their code units as well, so that indexing into them
+/// doesn't have to count, and so that taking half of a pair and joining it back up again gives
+/// the same string. As a `str`, a string has U+FFFD in place of its lone surrogates.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct JsString(Repr);
+
+// a string is always kept the same way for the same code units, which the derives rely on
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    Ascii(Rc<str>),
+    Utf16(Rc<[u16]>, Rc<str>),
+}
+
+impl JsString {
+    pub fn from_utf16(units: &[u16]) -> Self {
+        match units.iter().all(|unit| *unit < 0x80) {
+            true => JsString(Repr::Ascii(units.iter().map(|unit| *unit as u8 as char).collect::<String>().into())),
+            false => JsString(Repr::Utf16(units.into(), String::from_utf16_lossy(units).into())),
+        }
+    }
+
+    fn from_rc_str(string: Rc<str>) -> Self {
+        match string.is_ascii() {
+            true => JsString(Repr::Ascii(string)),
+            false => JsString(Repr::Utf16(string.encode_utf16().collect(), string)),
+        }
+    }
+
+    /// Whether the string is valid Unicode, without lone surrogates.
+    pub fn is_well_formed(&self) -> bool {
+        match &self.0 {
+            Repr::Ascii(_) => true,
+            Repr::Utf16(units, _) => char::decode_utf16(units.iter().copied()).all(|c| c.is_ok()),
+        }
+    }
+
+    /// The number of code units, which is the `length` scripts see.
+    pub fn utf16_len(&self) -> usize {
+        match &self.0 {
+            Repr::Ascii(string) => string.len(),
+            Repr::Utf16(units, _) => units.len(),
+        }
+    }
+
+    /// The memory the string takes up.
+    pub fn size(&self) -> usize {
+        match &self.0 {
+            Repr::Ascii(string) => string.len(),
+            Repr::Utf16(units, string) => units.len() * 2 + string.len(),
+        }
+    }
+
+    pub fn to_utf16(&self) -> Cow<'_, [u16]> {
+        match &self.0 {
+            Repr::Ascii(string) => Cow::Owned(string.bytes().map(u16::from).collect()),
+            Repr::Utf16(units, _) => Cow::Borrowed(units),
+        }
+    }
+
+    pub fn code_unit_at(&self, index: usize) -> Option<u16> {
+        match &self.0 {
+            Repr::Ascii(string) => string.as_bytes().get(index).map(|byte| *byte as u16),
+            Repr::Utf16(units, _) => units.get(index).copied(),
+        }
+    }
+
+    /// The code units from `start` up to `end`, which must be in order and within the string.
+    pub fn substring(&self, start: usize, end: usize) -> Self {
+        match &self.0 {
+            Repr::Ascii(string) => JsString(Repr::Ascii(string[start..end].into())),
+            Repr::Utf16(units, _) => Self::from_utf16(&units[start..end]),
+        }
+    }
+
+    /// The code point starting at the code unit at `position`, as a string of its own, and the
+    /// position of the one after it.
+    pub fn code_point_from(&self, position: usize) -> Option<(JsString, usize)> {
+        match &self.0 {
+            Repr::Ascii(string) => Some((string.get(position..position + 1)?.into(), position + 1)),
+            Repr::Utf16(units, _) => {
+                let high = *units.get(position)?;
+                let paired = (0xD800..0xDC00).contains(&high) && units.get(position + 1).is_some_and(|low| (0xDC00..0xE000).contains(low));
+                let end = position + if paired { 2 } else { 1 };
+                Some((Self::from_utf16(&units[position..end]), end))
+            },
+        }
+    }
+
+    pub fn concat(&self, other: &JsString) -> Self {
+        match (&self.0, &other.0) {
+            (Repr::Ascii(left), Repr::Ascii(right)) => {
+                let mut string = String::with_capacity(left.len() + right.len());
+                string.push_str(left);
+                string.push_str(right);
+                JsString(Repr::Ascii(string.into()))
+            },
+            // halves of a pair on either side join up into one code point
+            _ => Self::from_utf16(&[self.to_utf16(), other.to_utf16()].concat()),
+        }
+    }
+
+    pub fn repeat(&self, count: usize) -> Self {
+        match &self.0 {
+            Repr::Ascii(string) => JsString(Repr::Ascii(string.repeat(count).into())),
+            Repr::Utf16(units, _) => Self::from_utf16(&units.repeat(count)),
+        }
+    }
+
+    pub fn join(parts: &[JsString], separator: &JsString) -> Self {
+        if let Repr::Ascii(separator) = &separator.0 {
+            let strings: Option<Vec<&str>> = parts.iter().map(|part| match &part.0 {
+                Repr::Ascii(string) => Some(&**string),
+                Repr::Utf16(..) => None,
+            }).collect();
+
+            if let Some(strings) = strings {
+                return JsString(Repr::Ascii(strings.join(separator).into()));
+            }
+        }
+
+        let separator = separator.to_utf16();
+        let parts: Vec<Cow<[u16]>> = parts.iter().map(JsString::to_utf16).collect();
+        Self::from_utf16(&parts.join(&*separator))
+    }
+
+    /// The string as a shared `str`, with U+FFFD in place of lone surrogates.
+    pub fn to_rc_str(&self) -> Rc<str> {
+        match &self.0 {
+            Repr::Ascii(string) | Repr::Utf16(_, string) => string.clone(),
+        }
+    }
+}
+
+impl Default for JsString {
+    fn default() -> Self {
+        JsString(Repr::Ascii("".into()))
+    }
+}
+
+impl Deref for JsString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match &self.0 {
+            Repr::Ascii(string) | Repr::Utf16(_, string) => string,
+        }
+    }
+}
+
+impl From<&str> for JsString {
+    fn from(string: &str) -> Self {
+        Self::from_rc_str(string.into())
+    }
+}
+
+impl From<String> for JsString {
+    fn from(string: String) -> Self {
+        Self::from_rc_str(string.into())
+    }
+}
+
+impl From<Rc<str>> for JsString {
+    fn from(string: Rc<str>) -> Self {
+        Self::from_rc_str(string)
+    }
+}
+
+impl PartialEq<str> for JsString {
+    fn eq(&self, other: &str) -> bool {
+        match &self.0 {
+            Repr::Ascii(string) => &**string == other,
+            Repr::Utf16(_, string) => &**string == other && self.is_well_formed(),
+        }
+    }
+}
+
+impl PartialEq<&str> for JsString {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Strings are ordered by their code units, which isn't the order of their code points.
+impl Ord for JsString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Repr::Ascii(left), Repr::Ascii(right)) => left.cmp(right),
+            _ => self.to_utf16().cmp(&other.to_utf16()),
+        }
+    }
+}
+
+impl PartialOrd for JsString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl fmt::Debug for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}